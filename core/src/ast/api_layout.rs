@@ -0,0 +1,312 @@
+//! The layout of the function table a bridge exports when it's declared with an
+//! `apiname`, shared by the macro that builds the table and the tools that generate
+//! the `api.h` hosts read it through, so that both agree on where each function is.
+
+use std::fmt;
+
+use super::{CustomType, Method};
+
+/// The version of the header at the start of the table, which hosts compare before
+/// looking at anything else. It changes when the shape of the table itself changes,
+/// not when the API does.
+pub const API_LAYOUT_VERSION: u32 = 1;
+
+/// The comment at the top of a layout file
+const LAYOUT_FILE_HEADER: &str = "\
+# The layout of the API table, maintained by diplomat-tool.
+# Slots are only ever appended, so that hosts built against an older layout
+# keep working. Removed types and methods stay in as `retired` slots.
+";
+
+/// The types in the table, in order, each with the slots of its own table.
+///
+/// By default the layout follows the bridge: types sorted by name, then the methods of
+/// each type in declaration order followed by its destructor. In append-only mode
+/// ([`ApiLayout::append_only`]) it follows a layout file instead, and anything the
+/// file doesn't have yet goes at the end, so that the offsets older hosts were built
+/// with stay the same.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ApiLayout {
+    pub types: Vec<ApiTypeLayout>,
+    pub append_only: bool,
+}
+
+/// The slots of the table of one type
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ApiTypeLayout {
+    pub name: String,
+    /// Whether the type was removed from the bridge, in which case the table has a
+    /// null pointer in its place
+    pub retired: bool,
+    pub slots: Vec<ApiSlot>,
+}
+
+/// One function pointer in the table of a type
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ApiSlot {
+    /// The method name, or the name of the destructor
+    pub name: String,
+    /// The Rust signature of the method, or `None` for the destructor
+    pub signature: Option<String>,
+    /// Whether the method was removed from the bridge, in which case the slot is null
+    pub retired: bool,
+}
+
+impl ApiLayout {
+    /// The layout of the table for `types`, which are the types of one bridge module
+    /// in the order [`Module::declared_types`](super::Module) has them in
+    pub fn new<'a>(types: impl IntoIterator<Item = &'a CustomType>) -> Self {
+        Self {
+            types: types.into_iter().map(ApiTypeLayout::new).collect(),
+            append_only: false,
+        }
+    }
+
+    /// The layout of the table for `types` that keeps every slot of the layout file
+    /// `layout_file` where it is, and appends the ones it doesn't have.
+    ///
+    /// Fails with the slots whose signatures changed, since hosts built against the
+    /// file would call them with the old signature.
+    pub fn append_only<'a>(
+        types: impl IntoIterator<Item = &'a CustomType>,
+        layout_file: &str,
+    ) -> Result<Self, Vec<String>> {
+        let mut layout = Self::parse(layout_file).map_err(|e| vec![e])?;
+        let mut errors = Vec::new();
+        for ty in &mut layout.types {
+            ty.retired = true;
+        }
+        for custom_type in types {
+            let current = ApiTypeLayout::new(custom_type);
+            match layout.types.iter_mut().find(|ty| ty.name == current.name) {
+                Some(ty) => {
+                    ty.retired = false;
+                    ty.merge(current, &mut errors);
+                }
+                None => layout.types.push(current),
+            }
+        }
+        if errors.is_empty() {
+            Ok(layout)
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Read a layout file written by this type's `Display` impl
+    fn parse(layout_file: &str) -> Result<Self, String> {
+        let mut types: Vec<ApiTypeLayout> = Vec::new();
+        for (number, line) in layout_file.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (retired, line) = match line.strip_prefix("retired ") {
+                Some(rest) => (true, rest),
+                None => (false, line),
+            };
+            let (kind, rest) = line.split_once(' ').unwrap_or((line, ""));
+            let slot = match kind {
+                "type" => {
+                    types.push(ApiTypeLayout {
+                        name: rest.to_owned(),
+                        retired,
+                        slots: Vec::new(),
+                    });
+                    continue;
+                }
+                "fn" => ApiSlot {
+                    name: rest.split('(').next().unwrap_or_default().to_owned(),
+                    signature: Some(line.to_owned()),
+                    retired,
+                },
+                "drop" => ApiSlot {
+                    name: rest.to_owned(),
+                    signature: None,
+                    retired,
+                },
+                _ => return Err(format!("line {}: unknown entry `{line}`", number + 1)),
+            };
+            match types.last_mut() {
+                Some(ty) => ty.slots.push(slot),
+                None => return Err(format!("line {}: slot outside of a type", number + 1)),
+            }
+        }
+        Ok(Self {
+            types,
+            append_only: true,
+        })
+    }
+
+    /// A hash of the layout, including the signatures of the methods, that hosts
+    /// compare against the one they were built with.
+    ///
+    /// This is a 64-bit FNV-1a hash of the layout file, since that is the same for the
+    /// macro and the tools no matter which Rust version they are built with.
+    pub fn hash(&self) -> u64 {
+        self.to_string()
+            .bytes()
+            .fold(0xcbf29ce484222325, |hash, byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+            })
+    }
+}
+
+impl ApiTypeLayout {
+    fn new(custom_type: &CustomType) -> Self {
+        let methods = custom_type.methods().iter().map(|method| ApiSlot {
+            name: method.name.to_string(),
+            signature: Some(signature(method)),
+            retired: false,
+        });
+        let dtor = ApiSlot {
+            name: custom_type.dtor_name(),
+            signature: None,
+            retired: false,
+        };
+        Self {
+            name: custom_type.name().to_string(),
+            retired: false,
+            slots: methods.chain(std::iter::once(dtor)).collect(),
+        }
+    }
+
+    /// Retire the slots that aren't in `current` and append the ones that are new
+    fn merge(&mut self, current: ApiTypeLayout, errors: &mut Vec<String>) {
+        for slot in &mut self.slots {
+            slot.retired = true;
+        }
+        for new in current.slots {
+            match self.slots.iter_mut().find(|slot| slot.name == new.name) {
+                Some(slot) if slot.signature != new.signature => errors.push(format!(
+                    "the signature of `{}::{}` changed from `{}` to `{}`, which breaks hosts \
+                     built against the API layout; add a method with a new name instead",
+                    self.name,
+                    new.name,
+                    slot.signature.as_deref().unwrap_or("drop"),
+                    new.signature.as_deref().unwrap_or("drop"),
+                )),
+                Some(slot) => slot.retired = false,
+                None => self.slots.push(new),
+            }
+        }
+    }
+}
+
+/// The signature of `method` the way it's written in the layout file,
+/// like `fn get(self: &Foo, i: usize) -> u8`
+fn signature(method: &Method) -> String {
+    let params = method
+        .self_param
+        .iter()
+        .map(|param| format!("self: {}", param.to_typename()))
+        .chain(
+            method
+                .params
+                .iter()
+                .map(|param| format!("{}: {}", param.name, param.ty)),
+        )
+        .collect::<Vec<_>>()
+        .join(", ");
+    match &method.return_type {
+        Some(ty) => format!("fn {}({params}) -> {ty}", method.name),
+        None => format!("fn {}({params})", method.name),
+    }
+}
+
+impl fmt::Display for ApiLayout {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(LAYOUT_FILE_HEADER)?;
+        for ty in &self.types {
+            let retired = if ty.retired { "retired " } else { "" };
+            writeln!(f, "{retired}type {}", ty.name)?;
+            for slot in &ty.slots {
+                let retired = if slot.retired { "retired " } else { "" };
+                match &slot.signature {
+                    Some(signature) => writeln!(f, "  {retired}{signature}")?,
+                    None => writeln!(f, "  {retired}drop {}", slot.name)?,
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ApiLayout;
+    use crate::ast::Module;
+
+    fn types(module: &Module) -> impl Iterator<Item = &crate::ast::CustomType> {
+        module.declared_types.values()
+    }
+
+    #[test]
+    fn test_append_only() {
+        let old = Module::from_syn(
+            &syn::parse_quote! {
+                mod ffi {
+                    #[diplomat::opaque]
+                    struct Foo(u8);
+                    struct Bar { x: u8 }
+
+                    impl Foo {
+                        pub fn new(x: u8) -> Box<Foo> { unimplemented!() }
+                        pub fn get(&self) -> u8 { unimplemented!() }
+                    }
+                }
+            },
+            true,
+        );
+        let new = Module::from_syn(
+            &syn::parse_quote! {
+                mod ffi {
+                    #[diplomat::opaque]
+                    struct Foo(u8);
+                    #[diplomat::opaque]
+                    struct Baz(u8);
+
+                    impl Foo {
+                        pub fn set(&mut self, x: u8) { unimplemented!() }
+                        pub fn get(&self) -> u8 { unimplemented!() }
+                    }
+                }
+            },
+            true,
+        );
+
+        let file = ApiLayout::new(types(&old)).to_string();
+        assert_eq!(ApiLayout::parse(&file).unwrap().to_string(), file);
+
+        let layout = ApiLayout::append_only(types(&new), &file).unwrap();
+        insta::assert_snapshot!(layout.to_string());
+        assert_ne!(layout.hash(), ApiLayout::new(types(&old)).hash());
+        assert_eq!(
+            ApiLayout::append_only(types(&new), &layout.to_string()),
+            Ok(layout)
+        );
+
+        let changed = Module::from_syn(
+            &syn::parse_quote! {
+                mod ffi {
+                    #[diplomat::opaque]
+                    struct Foo(u8);
+
+                    impl Foo {
+                        pub fn get(&self) -> u16 { unimplemented!() }
+                    }
+                }
+            },
+            true,
+        );
+        assert_eq!(
+            ApiLayout::append_only(types(&changed), &file)
+                .unwrap_err()
+                .len(),
+            1
+        );
+    }
+}
@@ -6,6 +6,7 @@ use std::borrow::Cow;
 use std::convert::Infallible;
 use std::str::FromStr;
 use syn::parse::{Error as ParseError, Parse, ParseStream};
+use syn::punctuated::Punctuated;
 use syn::{Attribute, Expr, Ident, Lit, LitStr, Meta, MetaList, Token};
 
 /// The list of attributes on a type. All attributes except `attrs` (HIR attrs) are
@@ -53,6 +54,13 @@ pub struct Attrs {
     ///
     /// Inherited.
     pub abi_rename: RenameAttr,
+
+    /// Catch panics in the C ABI functions of methods, instead of unwinding into foreign code.
+    /// Set by `#[diplomat::catch_panics]` on methods, impls, and bridge modules, or by
+    /// `#[diplomat::bridge(catch_panics)]`.
+    ///
+    /// Inherited.
+    pub catch_panics: bool,
 }
 
 impl Attrs {
@@ -62,6 +70,7 @@ impl Attrs {
             Attr::DiplomatBackend(attr) => self.attrs.push(attr),
             Attr::SkipIfAst => self.skip_if_ast = true,
             Attr::CRename(rename) => self.abi_rename.extend(&rename),
+            Attr::CatchPanics => self.catch_panics = true,
         }
     }
 
@@ -87,6 +96,7 @@ impl Attrs {
             // HIR only, for methods only. not inherited
             skip_if_ast: false,
             abi_rename,
+            catch_panics: self.catch_panics,
         }
     }
 
@@ -113,6 +123,7 @@ enum Attr {
     DiplomatBackend(DiplomatBackendAttr),
     SkipIfAst,
     CRename(RenameAttr),
+    CatchPanics,
     // More goes here
 }
 
@@ -121,6 +132,8 @@ fn syn_attr_to_ast_attr(attrs: &[Attribute]) -> impl Iterator<Item = Attr> + '_
     let dattr_path: syn::Path = syn::parse_str("diplomat::attr").unwrap();
     let crename_attr: syn::Path = syn::parse_str("diplomat::abi_rename").unwrap();
     let skipast: syn::Path = syn::parse_str("diplomat::skip_if_ast").unwrap();
+    let catch_panics: syn::Path = syn::parse_str("diplomat::catch_panics").unwrap();
+    let bridge: syn::Path = syn::parse_str("diplomat::bridge").unwrap();
     attrs.iter().filter_map(move |a| {
         if a.path() == &cfg_path {
             Some(Attr::Cfg(a.clone()))
@@ -133,12 +146,21 @@ fn syn_attr_to_ast_attr(attrs: &[Attribute]) -> impl Iterator<Item = Attr> + '_
             Some(Attr::CRename(RenameAttr::from_meta(&a.meta).unwrap()))
         } else if a.path() == &skipast {
             Some(Attr::SkipIfAst)
+        } else if a.path() == &catch_panics || (a.path() == &bridge && bridge_catches_panics(a)) {
+            Some(Attr::CatchPanics)
         } else {
             None
         }
     })
 }
 
+/// Whether a `#[diplomat::bridge(...)]` attribute has a `catch_panics` argument
+fn bridge_catches_panics(attr: &Attribute) -> bool {
+    attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+        .map(|args| args.iter().any(|arg| arg.path().is_ident("catch_panics")))
+        .unwrap_or(false)
+}
+
 impl Serialize for Attrs {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -163,6 +185,9 @@ impl Serialize for Attrs {
         if !self.abi_rename.is_empty() {
             state.serialize_field("abi_rename", &self.abi_rename)?;
         }
+        if self.catch_panics {
+            state.serialize_field("catch_panics", &self.catch_panics)?;
+        }
         state.end()
     }
 }
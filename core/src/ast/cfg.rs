@@ -0,0 +1,229 @@
+//! Evaluating the regular `#[cfg]` attributes on bridge items, so that tools can
+//! leave out the items that aren't compiled into the library they generate bindings for.
+
+use std::collections::BTreeSet;
+use syn::punctuated::Punctuated;
+use syn::{Attribute, Expr, Fields, ImplItem, Item, ItemMod, Lit, Meta, Token, TraitItem};
+
+/// The active configuration `#[cfg(...)]` predicates are evaluated against: the names
+/// (like `unix`) and name-value pairs (like `feature = "serde"`) that are set.
+///
+/// Unlike rustc, nothing is set by default, so target cfgs like `target_os = "linux"`
+/// have to be inserted like any other.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct CfgSet {
+    names: BTreeSet<String>,
+    name_values: BTreeSet<(String, String)>,
+}
+
+impl CfgSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set `name`, or `name = "value"` if there is a value
+    pub fn insert(&mut self, name: impl Into<String>, value: Option<String>) {
+        match value {
+            Some(value) => self.name_values.insert((name.into(), value)),
+            None => self.names.insert(name.into()),
+        };
+    }
+
+    /// Enable the cargo feature `feature`
+    pub fn insert_feature(&mut self, feature: impl Into<String>) {
+        self.insert("feature", Some(feature.into()));
+    }
+
+    /// Set a cfg given the way `rustc --cfg` takes it, `name` or `name="value"`
+    pub fn insert_str(&mut self, cfg: &str) -> syn::Result<()> {
+        match syn::parse_str(cfg)? {
+            Meta::Path(path) => self.insert(path_name(&path)?, None),
+            Meta::NameValue(nv) => self.insert(path_name(&nv.path)?, Some(str_value(&nv.value)?)),
+            Meta::List(list) => {
+                return Err(syn::Error::new_spanned(
+                    list,
+                    "expected `name` or `name = \"value\"`",
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether the predicate of a `#[cfg(...)]` holds
+    pub fn eval(&self, predicate: &Meta) -> syn::Result<bool> {
+        match predicate {
+            Meta::Path(path) => Ok(self.names.contains(&path_name(path)?)),
+            Meta::NameValue(nv) => Ok(self
+                .name_values
+                .contains(&(path_name(&nv.path)?, str_value(&nv.value)?))),
+            Meta::List(list) => {
+                let operator = path_name(&list.path)?;
+                let operands =
+                    list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+                match &*operator {
+                    "all" => {
+                        for operand in &operands {
+                            if !self.eval(operand)? {
+                                return Ok(false);
+                            }
+                        }
+                        Ok(true)
+                    }
+                    "any" => {
+                        for operand in &operands {
+                            if self.eval(operand)? {
+                                return Ok(true);
+                            }
+                        }
+                        Ok(false)
+                    }
+                    "not" if operands.len() == 1 => Ok(!self.eval(&operands[0])?),
+                    "not" => Err(syn::Error::new_spanned(
+                        list,
+                        "`not` takes exactly one predicate",
+                    )),
+                    _ => Err(syn::Error::new_spanned(
+                        &list.path,
+                        format!("unknown cfg predicate `{operator}`"),
+                    )),
+                }
+            }
+        }
+    }
+
+    /// Whether all the `#[cfg(...)]`s in `attrs` hold
+    pub fn is_enabled(&self, attrs: &[Attribute]) -> syn::Result<bool> {
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("cfg")) {
+            if !self.eval(&attr.parse_args()?)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    /// Like [`Self::is_enabled`], but leaving items with malformed `#[cfg]`s out and
+    /// recording the error
+    fn keep(&self, attrs: &[Attribute], errors: &mut Vec<syn::Error>) -> bool {
+        self.is_enabled(attrs).unwrap_or_else(|e| {
+            errors.push(e);
+            false
+        })
+    }
+
+    /// A copy of the contents of `input` without the items, impl items, fields and
+    /// variants that are configured out. Submodules are kept whole, they get
+    /// stripped when they are constructed themselves.
+    pub(crate) fn strip_module(&self, input: &ItemMod, errors: &mut Vec<syn::Error>) -> ItemMod {
+        let mut module = input.clone();
+        let items = match &mut module.content {
+            Some((_, items)) => items,
+            None => return module,
+        };
+        items.retain(|item| self.keep(item_attrs(item), errors));
+        for item in items {
+            match item {
+                Item::Struct(strct) => self.strip_fields(&mut strct.fields, errors),
+                Item::Enum(enm) => {
+                    enm.variants = std::mem::take(&mut enm.variants)
+                        .into_iter()
+                        .filter(|variant| self.keep(&variant.attrs, errors))
+                        .collect();
+                    for variant in &mut enm.variants {
+                        self.strip_fields(&mut variant.fields, errors);
+                    }
+                }
+                Item::Impl(imp) => imp.items.retain(|item| match item {
+                    ImplItem::Fn(m) => self.keep(&m.attrs, errors),
+                    ImplItem::Const(c) => self.keep(&c.attrs, errors),
+                    ImplItem::Type(t) => self.keep(&t.attrs, errors),
+                    _ => true,
+                }),
+                Item::Trait(trt) => trt.items.retain(|item| match item {
+                    TraitItem::Fn(m) => self.keep(&m.attrs, errors),
+                    TraitItem::Const(c) => self.keep(&c.attrs, errors),
+                    TraitItem::Type(t) => self.keep(&t.attrs, errors),
+                    _ => true,
+                }),
+                _ => {}
+            }
+        }
+        module
+    }
+
+    fn strip_fields(&self, fields: &mut Fields, errors: &mut Vec<syn::Error>) {
+        let fields = match fields {
+            Fields::Named(fields) => &mut fields.named,
+            Fields::Unnamed(fields) => &mut fields.unnamed,
+            Fields::Unit => return,
+        };
+        *fields = std::mem::take(fields)
+            .into_iter()
+            .filter(|field| self.keep(&field.attrs, errors))
+            .collect();
+    }
+}
+
+/// The attributes of the kinds of items that can appear in a bridge module
+pub(crate) fn item_attrs(item: &Item) -> &[Attribute] {
+    match item {
+        Item::Const(i) => &i.attrs,
+        Item::Enum(i) => &i.attrs,
+        Item::Fn(i) => &i.attrs,
+        Item::Impl(i) => &i.attrs,
+        Item::Mod(i) => &i.attrs,
+        Item::Struct(i) => &i.attrs,
+        Item::Trait(i) => &i.attrs,
+        Item::Type(i) => &i.attrs,
+        Item::Use(i) => &i.attrs,
+        _ => &[],
+    }
+}
+
+fn path_name(path: &syn::Path) -> syn::Result<String> {
+    path.get_ident()
+        .map(ToString::to_string)
+        .ok_or_else(|| syn::Error::new_spanned(path, "expected a cfg name"))
+}
+
+fn str_value(value: &Expr) -> syn::Result<String> {
+    match value {
+        Expr::Lit(syn::ExprLit {
+            lit: Lit::Str(s), ..
+        }) => Ok(s.value()),
+        _ => Err(syn::Error::new_spanned(value, "expected a string literal")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CfgSet;
+
+    fn eval(cfg: &CfgSet, predicate: &str) -> bool {
+        cfg.eval(&syn::parse_str(predicate).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_eval() {
+        let mut cfg = CfgSet::new();
+        cfg.insert_feature("serde");
+        cfg.insert_str("unix").unwrap();
+        cfg.insert_str("target_os = \"linux\"").unwrap();
+
+        assert!(eval(&cfg, "feature = \"serde\""));
+        assert!(!eval(&cfg, "feature = \"std\""));
+        assert!(eval(&cfg, "unix"));
+        assert!(!eval(&cfg, "windows"));
+        assert!(eval(&cfg, "all(unix, target_os = \"linux\")"));
+        assert!(!eval(&cfg, "all(unix, feature = \"std\")"));
+        assert!(eval(&cfg, "any(windows, feature = \"serde\")"));
+        assert!(!eval(&cfg, "any()"));
+        assert!(eval(&cfg, "all()"));
+        assert!(eval(&cfg, "not(windows)"));
+
+        assert!(cfg.eval(&syn::parse_str("not(a, b)").unwrap()).is_err());
+        assert!(cfg
+            .eval(&syn::parse_str("feature = serde").unwrap())
+            .is_err());
+        assert!(cfg.insert_str("all(a)").is_err());
+    }
+}
@@ -0,0 +1,153 @@
+use serde::Serialize;
+
+use super::docs::Docs;
+use super::{Attrs, Ident, SourceSpan, TypeName};
+
+/// A `pub const` item in an FFI module, or an associated `pub const` in one of
+/// its `impl` blocks.
+///
+/// Only primitives and string slices with a literal value are supported, since
+/// the value is written out verbatim in the generated bindings.
+#[derive(Clone, Serialize, Debug, Hash, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Const {
+    pub name: Ident,
+    pub docs: Docs,
+    pub ty: TypeName,
+    pub value: ConstValue,
+    pub attrs: Attrs,
+    /// Where the const was declared, if known.
+    #[serde(skip)]
+    pub span: Option<SourceSpan>,
+}
+
+/// The literal value of a [`Const`].
+///
+/// Numbers are kept in their base-10 textual form so that they can be emitted
+/// without loss of precision, and so that the value can be hashed.
+#[derive(Clone, Serialize, Debug, Hash, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ConstValue {
+    Bool(bool),
+    /// An integer literal, including a leading `-` if negated.
+    Int(String),
+    /// A float literal, including a leading `-` if negated. Always contains a
+    /// `.` or an exponent.
+    Float(String),
+    Str(String),
+}
+
+impl Const {
+    /// Extract a [`Const`] from a `const` item in a module.
+    pub fn new(item: &syn::ItemConst, parent_attrs: &Attrs) -> syn::Result<Const> {
+        Self::from_parts(&item.ident, &item.ty, &item.expr, &item.attrs, parent_attrs)
+    }
+
+    /// Extract a [`Const`] from an associated `const` item in an `impl` block.
+    pub fn from_impl_item(item: &syn::ImplItemConst, parent_attrs: &Attrs) -> syn::Result<Const> {
+        Self::from_parts(&item.ident, &item.ty, &item.expr, &item.attrs, parent_attrs)
+    }
+
+    fn from_parts(
+        ident: &syn::Ident,
+        ty: &syn::Type,
+        expr: &syn::Expr,
+        item_attrs: &[syn::Attribute],
+        parent_attrs: &Attrs,
+    ) -> syn::Result<Const> {
+        let mut attrs = parent_attrs.clone();
+        attrs.add_attrs(item_attrs);
+
+        let value = ConstValue::from_expr(expr).ok_or_else(|| {
+            syn::Error::new_spanned(
+                expr,
+                format!("The value of const `{ident}` must be a bool, number, or string literal"),
+            )
+        })?;
+
+        Ok(Const {
+            name: ident.into(),
+            docs: Docs::from_attrs(item_attrs),
+            ty: TypeName::from_syn(ty, None),
+            value,
+            attrs,
+            span: SourceSpan::from_span(ident.span()),
+        })
+    }
+}
+
+impl ConstValue {
+    fn from_expr(expr: &syn::Expr) -> Option<ConstValue> {
+        match expr {
+            syn::Expr::Lit(syn::ExprLit { lit, .. }) => match lit {
+                syn::Lit::Bool(b) => Some(ConstValue::Bool(b.value)),
+                syn::Lit::Int(i) => Some(ConstValue::Int(i.base10_digits().into())),
+                syn::Lit::Float(f) => Some(ConstValue::Float(normalize_float(f.base10_digits()))),
+                syn::Lit::Str(s) => Some(ConstValue::Str(s.value())),
+                _ => None,
+            },
+            // Signed literals are represented as a negation expression
+            syn::Expr::Unary(syn::ExprUnary {
+                op: syn::UnOp::Neg(_),
+                expr,
+                ..
+            }) => match Self::from_expr(expr)? {
+                ConstValue::Int(i) if !i.starts_with('-') => Some(ConstValue::Int(format!("-{i}"))),
+                ConstValue::Float(f) if !f.starts_with('-') => {
+                    Some(ConstValue::Float(format!("-{f}")))
+                }
+                _ => None,
+            },
+            syn::Expr::Paren(p) => Self::from_expr(&p.expr),
+            _ => None,
+        }
+    }
+}
+
+/// Make sure a float literal like `1e3` or `2.` reads as a float in every target language.
+fn normalize_float(digits: &str) -> String {
+    if digits.ends_with('.') {
+        format!("{digits}0")
+    } else if !digits.contains(['.', 'e', 'E']) {
+        format!("{digits}.0")
+    } else {
+        digits.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use insta::{self, Settings};
+
+    use syn;
+
+    use super::Const;
+
+    #[test]
+    fn simple_consts() {
+        let mut settings = Settings::new();
+        settings.set_sort_maps(true);
+
+        settings.bind(|| {
+            let consts: Vec<Const> = [
+                syn::parse_quote! {
+                    /// The answer.
+                    pub const ANSWER: u8 = 42;
+                },
+                syn::parse_quote! {
+                    pub const MIN: i64 = -9_223_372_036_854_775_808;
+                },
+                syn::parse_quote! {
+                    pub const SCALE: f32 = -1e3;
+                },
+                syn::parse_quote! {
+                    pub const GREETING: &str = "hello \"world\"\n";
+                },
+            ]
+            .iter()
+            .map(|item| Const::new(item, &Default::default()).unwrap())
+            .collect();
+            insta::assert_yaml_snapshot!(consts);
+        });
+    }
+}
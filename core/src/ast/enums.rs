@@ -1,73 +1,144 @@
 use serde::Serialize;
 
 use super::docs::Docs;
-use super::{AttrInheritContext, Attrs, Ident, Method};
+use super::{AttrInheritContext, Attrs, Const, Ident, Method, SourceSpan, TypeName};
 use quote::ToTokens;
 
-/// A fieldless enum declaration in an FFI module.
+/// A variant of an [`Enum`].
+#[derive(Clone, Serialize, Debug, Hash, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct EnumVariant {
+    pub name: Ident,
+    pub discriminant: isize,
+    pub docs: Docs,
+    pub attrs: Attrs,
+    /// The fields the variant carries, empty for fieldless variants.
+    pub fields: Vec<(Ident, TypeName, Docs)>,
+}
+
+/// An enum declaration in an FFI module.
+///
+/// Variants may either be fieldless or carry named fields, in which case the enum
+/// crosses the FFI boundary as a `#[repr(C)]` tagged union.
 #[derive(Clone, Serialize, Debug, Hash, PartialEq, Eq)]
 #[non_exhaustive]
 pub struct Enum {
     pub name: Ident,
     pub docs: Docs,
-    /// A list of variants of the enum. (name, discriminant, docs, attrs)
-    pub variants: Vec<(Ident, isize, Docs, Attrs)>,
+    pub variants: Vec<EnumVariant>,
     pub methods: Vec<Method>,
+    pub consts: Vec<Const>,
     pub attrs: Attrs,
+    /// Where the type was declared, if known.
+    #[serde(skip)]
+    pub span: Option<SourceSpan>,
 }
 
 impl Enum {
     /// Extract an [`Enum`] metadata value from an AST node.
-    pub fn new(enm: &syn::ItemEnum, parent_attrs: &Attrs) -> Enum {
+    ///
+    /// Returns every problem found with the enum's declaration, combined into one error.
+    pub fn new(enm: &syn::ItemEnum, parent_attrs: &Attrs) -> syn::Result<Enum> {
+        let mut errors: Option<syn::Error> = None;
+        let mut push_error = |error: syn::Error| match errors {
+            Some(ref mut errors) => errors.combine(error),
+            None => errors = Some(error),
+        };
+
         let mut last_discriminant = -1;
         if !enm.generics.params.is_empty() {
             // Generic types are not allowed.
             // Assuming all enums cannot have lifetimes? We don't even have a
             // `lifetimes` field. If we change our minds we can adjust this later
             // and update the `CustomType::lifetimes` API accordingly.
-            panic!("Enums cannot have generic parameters");
+            push_error(syn::Error::new_spanned(
+                &enm.generics,
+                "Enums cannot have generic parameters",
+            ));
         }
 
         let mut attrs = parent_attrs.clone();
         attrs.add_attrs(&enm.attrs);
         let variant_parent_attrs = attrs.attrs_for_inheritance(AttrInheritContext::Variant);
 
-        Enum {
+        let mut variants = Vec::with_capacity(enm.variants.len());
+        for v in enm.variants.iter() {
+            let new_discriminant = match v.discriminant {
+                Some((_, ref expr)) => {
+                    // Reparsing, signed literals are represented
+                    // as a negation expression
+                    let lit: Result<syn::Lit, _> = syn::parse2(expr.to_token_stream());
+                    match lit {
+                        Ok(syn::Lit::Int(ref lit_int)) => match lit_int.base10_parse::<isize>() {
+                            Ok(discriminant) => discriminant,
+                            Err(e) => {
+                                push_error(e);
+                                last_discriminant + 1
+                            }
+                        },
+                        _ => {
+                            push_error(syn::Error::new_spanned(
+                                expr,
+                                "Expected a discriminant to be a constant integer",
+                            ));
+                            last_discriminant + 1
+                        }
+                    }
+                }
+                None => last_discriminant + 1,
+            };
+
+            last_discriminant = new_discriminant;
+            let mut v_attrs = variant_parent_attrs.clone();
+            v_attrs.add_attrs(&v.attrs);
+            let fields = match v.fields {
+                syn::Fields::Unit => vec![],
+                syn::Fields::Named(ref fields) => fields
+                    .named
+                    .iter()
+                    .map(|field| {
+                        (
+                            field.ident.as_ref().unwrap().into(),
+                            TypeName::from_syn(&field.ty, None),
+                            Docs::from_attrs(&field.attrs),
+                        )
+                    })
+                    .collect(),
+                syn::Fields::Unnamed(ref fields) => {
+                    push_error(syn::Error::new_spanned(
+                        fields,
+                        "Enum variants with payloads must use named fields",
+                    ));
+                    vec![]
+                }
+            };
+            variants.push(EnumVariant {
+                name: (&v.ident).into(),
+                discriminant: new_discriminant,
+                docs: Docs::from_attrs(&v.attrs),
+                attrs: v_attrs,
+                fields,
+            });
+        }
+
+        if let Some(errors) = errors {
+            return Err(errors);
+        }
+
+        Ok(Enum {
             name: (&enm.ident).into(),
             docs: Docs::from_attrs(&enm.attrs),
-            variants: enm
-                .variants
-                .iter()
-                .map(|v| {
-                    let new_discriminant = v
-                        .discriminant
-                        .as_ref()
-                        .map(|d| {
-                            // Reparsing, signed literals are represented
-                            // as a negation expression
-                            let lit: Result<syn::Lit, _> = syn::parse2(d.1.to_token_stream());
-                            if let Ok(syn::Lit::Int(ref lit_int)) = lit {
-                                lit_int.base10_parse::<isize>().unwrap()
-                            } else {
-                                panic!("Expected a discriminant to be a constant integer");
-                            }
-                        })
-                        .unwrap_or_else(|| last_discriminant + 1);
-
-                    last_discriminant = new_discriminant;
-                    let mut v_attrs = variant_parent_attrs.clone();
-                    v_attrs.add_attrs(&v.attrs);
-                    (
-                        (&v.ident).into(),
-                        new_discriminant,
-                        Docs::from_attrs(&v.attrs),
-                        v_attrs,
-                    )
-                })
-                .collect(),
+            variants,
             methods: vec![],
+            consts: vec![],
             attrs,
-        }
+            span: SourceSpan::from_span(enm.ident.span()),
+        })
+    }
+
+    /// Whether any variant of this enum carries fields.
+    pub fn is_data_carrying(&self) -> bool {
+        self.variants.iter().any(|v| !v.fields.is_empty())
     }
 }
 
@@ -96,7 +167,8 @@ mod tests {
                     }
                 },
                 &Default::default()
-            ));
+            )
+            .unwrap());
         });
     }
 
@@ -118,7 +190,32 @@ mod tests {
                     }
                 },
                 &Default::default()
-            ));
+            )
+            .unwrap());
+        });
+    }
+
+    #[test]
+    fn enum_with_payloads() {
+        let mut settings = Settings::new();
+        settings.set_sort_maps(true);
+
+        settings.bind(|| {
+            insta::assert_yaml_snapshot!(Enum::new(
+                &syn::parse_quote! {
+                    /// Some docs.
+                    enum Shape {
+                        Circle {
+                            /// The radius.
+                            r: f64
+                        },
+                        Rect { w: f64, h: f64 },
+                        Empty,
+                    }
+                },
+                &Default::default()
+            )
+            .unwrap());
         });
     }
 }
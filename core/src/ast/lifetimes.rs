@@ -79,9 +79,9 @@ pub struct LifetimeEnv {
 impl LifetimeEnv {
     /// Construct an empty [`LifetimeEnv`].
     ///
-    /// To create one outside of this module, use `LifetimeEnv::from_method_item`
+    /// To create one for a method or struct, use `LifetimeEnv::from_method_item`
     /// or `LifetimeEnv::from` on `&syn::Generics`.
-    fn new() -> Self {
+    pub(crate) fn new() -> Self {
         Self { nodes: vec![] }
     }
 
@@ -100,6 +100,18 @@ impl LifetimeEnv {
         self_param: Option<&SelfParam>,
         params: &[Param],
         return_type: Option<&TypeName>,
+    ) -> Self {
+        Self::from_method_sig(&method.sig, impl_generics, self_param, params, return_type)
+    }
+
+    /// Same as [`Self::from_method_item()`], but only requires the signature, so that it
+    /// can also be used for methods declared in traits.
+    pub fn from_method_sig(
+        sig: &syn::Signature,
+        impl_generics: Option<&syn::Generics>,
+        self_param: Option<&SelfParam>,
+        params: &[Param],
+        return_type: Option<&TypeName>,
     ) -> Self {
         let mut this = LifetimeEnv::new();
         // The impl generics _must_ be loaded into the env first, since the method
@@ -108,7 +120,7 @@ impl LifetimeEnv {
         if let Some(generics) = impl_generics {
             this.extend_generics(generics);
         }
-        this.extend_generics(&method.sig.generics);
+        this.extend_generics(&sig.generics);
 
         if let Some(self_param) = self_param {
             this.extend_implicit_lifetime_bounds(&self_param.to_typename(), None);
@@ -1,8 +1,9 @@
 use serde::Serialize;
 use std::ops::ControlFlow;
+use syn::spanned::Spanned;
 
 use super::docs::Docs;
-use super::{Attrs, Ident, Lifetime, LifetimeEnv, Mutability, PathType, TypeName};
+use super::{Attrs, Ident, Lifetime, LifetimeEnv, Mutability, PathType, SourceSpan, TypeName};
 
 /// A method declared in the `impl` associated with an FFI struct.
 /// Includes both static and non-static methods, which can be distinguished
@@ -36,6 +37,10 @@ pub struct Method {
     /// These are strings instead of `syn::Attribute` or `proc_macro2::TokenStream`
     /// because those types are not `PartialEq`, `Hash`, `Serialize`, etc.
     pub attrs: Attrs,
+
+    /// Where the signature of the method was declared, if known.
+    #[serde(skip)]
+    pub span: Option<SourceSpan>,
 }
 
 impl Method {
@@ -101,6 +106,7 @@ impl Method {
             return_type: return_ty,
             lifetime_env,
             attrs,
+            span: SourceSpan::from_span(m.sig.span()),
         }
     }
 
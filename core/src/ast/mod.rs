@@ -6,6 +6,12 @@ pub mod attrs;
 pub(crate) use attrs::AttrInheritContext;
 pub use attrs::Attrs;
 
+mod cfg;
+pub use cfg::CfgSet;
+
+mod api_layout;
+pub use api_layout::{ApiLayout, ApiSlot, ApiTypeLayout, API_LAYOUT_VERSION};
+
 mod methods;
 pub use methods::{BorrowedParams, Method, Param, SelfParam};
 
@@ -16,7 +22,16 @@ mod structs;
 pub use structs::{OpaqueStruct, Struct};
 
 mod enums;
-pub use enums::Enum;
+pub use enums::{Enum, EnumVariant};
+
+mod traits;
+pub use traits::{Trait, TraitMethod};
+
+mod consts;
+pub use consts::{Const, ConstValue};
+
+mod spans;
+pub use spans::SourceSpan;
 
 mod types;
 pub use types::{
@@ -5,9 +5,10 @@ use quote::ToTokens;
 use serde::Serialize;
 use syn::{ImplItem, Item, ItemMod, UseTree, Visibility};
 
+use super::cfg::item_attrs;
 use super::{
-    AttrInheritContext, Attrs, CustomType, Enum, Ident, Method, ModSymbol, Mutability,
-    OpaqueStruct, Path, PathType, RustLink, Struct,
+    AttrInheritContext, Attrs, CfgSet, Const, CustomType, Enum, Ident, Method, ModSymbol, Mutability,
+    OpaqueStruct, Path, PathType, RustLink, Struct, Trait,
 };
 use crate::environment::*;
 
@@ -65,11 +66,26 @@ pub struct Module {
     pub name: Ident,
     pub imports: Vec<(Path, Ident)>,
     pub declared_types: BTreeMap<Ident, CustomType>,
+    pub declared_traits: BTreeMap<Ident, Trait>,
+    pub declared_consts: BTreeMap<Ident, Const>,
     pub sub_modules: Vec<Module>,
     pub attrs: Attrs,
+    /// Problems found in items of this module. The offending items are left out
+    /// of the module so that the rest of it can still be used.
+    #[serde(skip)]
+    pub errors: Vec<syn::Error>,
 }
 
 impl Module {
+    /// All errors found in this module and its submodules.
+    pub fn all_errors(&self) -> Vec<&syn::Error> {
+        let mut errors = self.errors.iter().collect::<Vec<_>>();
+        self.sub_modules
+            .iter()
+            .for_each(|m| errors.extend(m.all_errors()));
+        errors
+    }
+
     pub fn all_rust_links(&self) -> HashSet<&RustLink> {
         let mut rust_links = self
             .declared_types
@@ -99,6 +115,16 @@ impl Module {
             }
         });
 
+        // Names were checked to be unique in the module when it was built, with
+        // duplicates reported as errors and left out
+        self.declared_traits.iter().for_each(|(k, v)| {
+            mod_symbols.insert(k.clone(), ModSymbol::Trait(v.clone()));
+        });
+
+        self.declared_consts.iter().for_each(|(k, v)| {
+            mod_symbols.insert(k.clone(), ModSymbol::Const(v.clone()));
+        });
+
         let path_to_self = in_path.sub_path(self.name.clone());
         self.sub_modules.iter().for_each(|m| {
             m.insert_all_types(path_to_self.clone(), out);
@@ -109,9 +135,33 @@ impl Module {
     }
 
     pub fn from_syn(input: &ItemMod, force_analyze: bool) -> Module {
+        Self::from_syn_inner(input, force_analyze, None)
+    }
+
+    /// Like [`Self::from_syn`], but leaving out the items, and submodules, whose
+    /// `#[cfg]`s don't hold for `cfg`
+    pub fn from_syn_with_cfg(input: &ItemMod, force_analyze: bool, cfg: &CfgSet) -> Module {
+        Self::from_syn_inner(input, force_analyze, Some(cfg))
+    }
+
+    fn from_syn_inner(input: &ItemMod, force_analyze: bool, cfg: Option<&CfgSet>) -> Module {
         let mut custom_types_by_name = BTreeMap::new();
+        let mut traits_by_name = BTreeMap::new();
+        let mut consts_by_name = BTreeMap::new();
         let mut sub_modules = Vec::new();
         let mut imports = Vec::new();
+        let mut errors = Vec::new();
+        // Types that failed to be constructed, whose impl blocks are ignored
+        let mut failed_types = HashSet::new();
+
+        let stripped;
+        let input = match cfg {
+            Some(cfg) => {
+                stripped = cfg.strip_module(input, &mut errors);
+                &stripped
+            }
+            None => input,
+        };
 
         let analyze_types = force_analyze
             || input
@@ -134,48 +184,112 @@ impl Module {
             .for_each(|a| match a {
                 Item::Use(u) => {
                     if analyze_types {
-                        extract_imports(&Path::empty(), &u.tree, &mut imports);
+                        let mut new_imports = Vec::new();
+                        extract_imports(&Path::empty(), &u.tree, &mut new_imports);
+                        for (path, name) in new_imports {
+                            if custom_types_by_name.contains_key(&name) || traits_by_name.contains_key(&name) || consts_by_name.contains_key(&name) {
+                                errors.push(duplicate_name_error(u, &name));
+                            } else {
+                                imports.push((path, name));
+                            }
+                        }
                     }
                 }
                 Item::Struct(strct) => {
                     if analyze_types {
+                        let ident = Ident::from(&strct.ident);
                         let custom_type = match DiplomatStructAttribute::parse(&strct.attrs[..]) {
-                            Ok(None) => CustomType::Struct(Struct::new(strct, false, &type_parent_attrs)),
+                            Ok(None) => Struct::new(strct, false, &type_parent_attrs).map(CustomType::Struct),
                             Ok(Some(DiplomatStructAttribute::Out)) => {
-                                CustomType::Struct(Struct::new(strct, true, &type_parent_attrs))
+                                Struct::new(strct, true, &type_parent_attrs).map(CustomType::Struct)
                             }
                             Ok(Some(DiplomatStructAttribute::Opaque)) => {
-                                CustomType::Opaque(OpaqueStruct::new(strct, Mutability::Immutable, &type_parent_attrs))
+                                Ok(CustomType::Opaque(OpaqueStruct::new(strct, Mutability::Immutable, &type_parent_attrs)))
                             }
                             Ok(Some(DiplomatStructAttribute::OpaqueMut)) => {
-                                CustomType::Opaque(OpaqueStruct::new(strct, Mutability::Mutable, &type_parent_attrs))
-                            }
-                            Err(errors) => {
-                                panic!("Multiple conflicting Diplomat struct attributes, there can be at most one: {errors:?}");
+                                Ok(CustomType::Opaque(OpaqueStruct::new(strct, Mutability::Mutable, &type_parent_attrs)))
                             }
+                            Err(attrs) => Err(syn::Error::new_spanned(
+                                &strct.ident,
+                                format!("Multiple conflicting Diplomat struct attributes, there can be at most one: {attrs:?}"),
+                            )),
                         };
 
-                        custom_types_by_name.insert(Ident::from(&strct.ident), custom_type);
+                        match custom_type {
+                            Ok(custom_type) if check_unique_name(&strct.ident, &custom_types_by_name, &traits_by_name, &consts_by_name, &imports, &mut errors) => {
+                                custom_types_by_name.insert(ident, custom_type);
+                            }
+                            Ok(_) => {}
+                            Err(e) => {
+                                errors.push(e);
+                                failed_types.insert(ident);
+                            }
+                        }
                     }
                 }
 
                 Item::Enum(enm) => {
                     if analyze_types {
-                        let ident = (&enm.ident).into();
-                        let enm = Enum::new(enm, &type_parent_attrs);
-                        custom_types_by_name
-                            .insert(ident, CustomType::Enum(enm));
+                        let ident = Ident::from(&enm.ident);
+                        match Enum::new(enm, &type_parent_attrs) {
+                            Ok(e) if check_unique_name(&enm.ident, &custom_types_by_name, &traits_by_name, &consts_by_name, &imports, &mut errors) => {
+                                custom_types_by_name.insert(ident, CustomType::Enum(e));
+                            }
+                            Ok(_) => {}
+                            Err(e) => {
+                                errors.push(e);
+                                failed_types.insert(ident);
+                            }
+                        }
+                    }
+                }
+
+                Item::Trait(trt) if analyze_types => {
+                    match Trait::new(trt, &type_parent_attrs) {
+                        Ok(t) if check_unique_name(&trt.ident, &custom_types_by_name, &traits_by_name, &consts_by_name, &imports, &mut errors) => {
+                            traits_by_name.insert(Ident::from(&trt.ident), t);
+                        }
+                        Ok(_) => {}
+                        Err(e) => errors.push(e),
+                    }
+                }
+
+                Item::Const(cnst) if analyze_types && matches!(cnst.vis, Visibility::Public(_)) => {
+                    match Const::new(cnst, &type_parent_attrs) {
+                        Ok(c) if check_unique_name(&cnst.ident, &custom_types_by_name, &traits_by_name, &consts_by_name, &imports, &mut errors) => {
+                            consts_by_name.insert(Ident::from(&cnst.ident), c);
+                        }
+                        Ok(_) => {}
+                        Err(e) => errors.push(e),
                     }
                 }
 
                 Item::Impl(imp) => {
                     if analyze_types {
-                        assert!(imp.trait_.is_none());
+                        if let Some((_, ref trait_path, _)) = imp.trait_ {
+                            errors.push(syn::Error::new_spanned(
+                                trait_path,
+                                "Trait impls are not supported in Diplomat bridge modules",
+                            ));
+                            return;
+                        }
 
                         let self_path = match imp.self_ty.as_ref() {
                             syn::Type::Path(s) => PathType::from(s),
-                            _ => panic!("Self type not found"),
+                            other => {
+                                errors.push(syn::Error::new_spanned(
+                                    other,
+                                    "Expected the Self type of an impl block to be a path",
+                                ));
+                                return;
+                            }
                         };
+                        let self_ident = self_path.path.elements.last().unwrap().clone();
+                        if failed_types.contains(&self_ident) {
+                            // The type itself has already been reported
+                            return;
+                        }
+
                         let mut impl_attrs = impl_parent_attrs.clone();
                         impl_attrs.add_attrs(&imp.attrs);
                         let method_parent_attrs = impl_attrs.attrs_for_inheritance(AttrInheritContext::MethodFromImpl);
@@ -190,23 +304,39 @@ impl Module {
                             .map(|m| Method::from_syn(m, self_path.clone(), Some(&imp.generics), &method_parent_attrs))
                             .collect();
 
-                        let self_ident = self_path.path.elements.last().unwrap();
+                        let mut new_consts = imp
+                            .items
+                            .iter()
+                            .filter_map(|i| match i {
+                                ImplItem::Const(c) => Some(c),
+                                _ => None,
+                            })
+                            .filter(|c| matches!(c.vis, Visibility::Public(_)))
+                            .filter_map(|c| Const::from_impl_item(c, &method_parent_attrs).map_err(|e| errors.push(e)).ok())
+                            .collect();
 
-                        match custom_types_by_name.get_mut(self_ident).unwrap() {
-                            CustomType::Struct(strct) => {
+                        match custom_types_by_name.get_mut(&self_ident) {
+                            Some(CustomType::Struct(strct)) => {
                                 strct.methods.append(&mut new_methods);
+                                strct.consts.append(&mut new_consts);
                             }
-                            CustomType::Opaque(strct) => {
+                            Some(CustomType::Opaque(strct)) => {
                                 strct.methods.append(&mut new_methods);
+                                strct.consts.append(&mut new_consts);
                             }
-                            CustomType::Enum(enm) => {
+                            Some(CustomType::Enum(enm)) => {
                                 enm.methods.append(&mut new_methods);
+                                enm.consts.append(&mut new_consts);
                             }
+                            None => errors.push(syn::Error::new_spanned(
+                                &imp.self_ty,
+                                format!("Found an impl block for `{self_ident}`, which must be declared in this module before it"),
+                            )),
                         }
                     }
                 }
                 Item::Mod(item_mod) => {
-                    sub_modules.push(Module::from_syn(item_mod, false));
+                    sub_modules.push(Module::from_syn_inner(item_mod, false, cfg));
                 }
                 _ => {}
             });
@@ -215,12 +345,45 @@ impl Module {
             name: (&input.ident).into(),
             imports,
             declared_types: custom_types_by_name,
+            declared_traits: traits_by_name,
+            declared_consts: consts_by_name,
             sub_modules,
             attrs: mod_attrs,
+            errors,
         }
     }
 }
 
+/// Check that no other type, trait, const, or import in the module is named `ident`,
+/// recording an error if there is one.
+fn check_unique_name(
+    ident: &syn::Ident,
+    types: &BTreeMap<Ident, CustomType>,
+    traits: &BTreeMap<Ident, Trait>,
+    consts: &BTreeMap<Ident, Const>,
+    imports: &[(Path, Ident)],
+    errors: &mut Vec<syn::Error>,
+) -> bool {
+    let name = Ident::from(ident);
+    if types.contains_key(&name)
+        || traits.contains_key(&name)
+        || consts.contains_key(&name)
+        || imports.iter().any(|(_, imported)| *imported == name)
+    {
+        errors.push(duplicate_name_error(ident, &name));
+        false
+    } else {
+        true
+    }
+}
+
+fn duplicate_name_error(tokens: impl ToTokens, name: &Ident) -> syn::Error {
+    syn::Error::new_spanned(
+        tokens,
+        format!("`{name}` is declared more than once in this module"),
+    )
+}
+
 fn extract_imports(base_path: &Path, use_tree: &UseTree, out: &mut Vec<(Path, Ident)>) {
     match use_tree {
         UseTree::Name(name) => out.push((
@@ -251,8 +414,51 @@ pub struct File {
 }
 
 impl File {
+    /// Get all custom types across all modules defined in a given file, leaving
+    /// out the items whose `#[cfg]`s don't hold for `cfg`.
+    ///
+    /// Errors in `#[cfg]`s are recorded on the module they are in. A top-level module
+    /// whose own `#[cfg]` is malformed is kept, with the error recorded on it.
+    pub fn from_syn_with_cfg(file: &syn::File, cfg: &CfgSet) -> File {
+        let mut out = BTreeMap::new();
+        file.items.iter().for_each(|i| {
+            if let Item::Mod(item_mod) = i {
+                let enabled = cfg.is_enabled(item_attrs(i));
+                if let Ok(false) = enabled {
+                    return;
+                }
+                let mut module = Module::from_syn_with_cfg(item_mod, false, cfg);
+                module.errors.extend(enabled.err());
+                out.insert(item_mod.ident.to_string(), module);
+            }
+        });
+
+        File { modules: out }
+    }
+
+    /// All errors found while building the AST for this file. Items with errors
+    /// are left out, so the rest of the file can still be used.
+    pub fn all_errors(&self) -> Vec<&syn::Error> {
+        self.modules
+            .values()
+            .flat_map(|m| m.all_errors().into_iter())
+            .collect()
+    }
+
     /// Fuses all declared types into a single environment `HashMap`.
     pub fn all_types(&self) -> Env {
+        self.all_types_with_crates(None)
+    }
+
+    /// Like [`Self::all_types`], with the types of the bridges of other crates, keyed by
+    /// crate name, so that `use other_crate::ffi::Foo` resolves to them.
+    ///
+    /// The modules of the other crates are put under the name of the crate, and
+    /// [`Env::crate_of`] tells them apart from the modules of this one.
+    pub fn all_types_with_crates<'a>(
+        &self,
+        crates: impl IntoIterator<Item = (&'a str, &'a File)>,
+    ) -> Env {
         let mut out = Env::default();
         let mut top_symbols = ModuleEnv::new(Default::default());
 
@@ -261,6 +467,18 @@ impl File {
             top_symbols.insert(m.name.clone(), ModSymbol::SubModule(m.name.clone()));
         });
 
+        for (name, file) in crates {
+            let name = Ident::from(name.to_string());
+            let crate_path = Path::empty().sub_path(name.clone());
+            let mut crate_symbols = ModuleEnv::new(Default::default());
+            file.modules.values().for_each(|m| {
+                m.insert_all_types(crate_path.clone(), &mut out);
+                crate_symbols.insert(m.name.clone(), ModSymbol::SubModule(m.name.clone()));
+            });
+            out.insert(crate_path, crate_symbols);
+            out.crates.insert(name);
+        }
+
         out.insert(Path::empty(), top_symbols);
 
         out
@@ -313,7 +531,7 @@ mod tests {
 
     use syn;
 
-    use crate::ast::{File, Module};
+    use crate::ast::{CfgSet, CustomType, File, Module};
 
     #[test]
     fn simple_mod() {
@@ -393,6 +611,62 @@ mod tests {
         });
     }
 
+    #[test]
+    fn mod_with_trait() {
+        let mut settings = Settings::new();
+        settings.set_sort_maps(true);
+
+        settings.bind(|| {
+            insta::assert_yaml_snapshot!(Module::from_syn(
+                &syn::parse_quote! {
+                    #[diplomat::bridge]
+                    mod ffi {
+                        trait Logger {
+                            fn log(&self, msg: &DiplomatStr);
+                        }
+
+                        #[diplomat::opaque]
+                        struct Worker;
+
+                        impl Worker {
+                            pub fn run(&self, logger: Box<dyn Logger>) {
+                                unimplemented!()
+                            }
+                        }
+                    }
+                },
+                true
+            ));
+        });
+    }
+
+    #[test]
+    fn mod_with_consts() {
+        let mut settings = Settings::new();
+        settings.set_sort_maps(true);
+
+        settings.bind(|| {
+            insta::assert_yaml_snapshot!(Module::from_syn(
+                &syn::parse_quote! {
+                    #[diplomat::bridge]
+                    mod ffi {
+                        pub const VERSION: &str = "1.0";
+                        const PRIVATE: u32 = 1;
+
+                        #[diplomat::opaque]
+                        struct Foo;
+
+                        impl Foo {
+                            pub const MAX_LEN: usize = 64;
+                            const HIDDEN: bool = true;
+                        }
+                    }
+                },
+                true
+            ));
+        });
+    }
+
     #[test]
     fn import_in_non_diplomat_not_analyzed() {
         let mut settings = Settings::new();
@@ -411,4 +685,185 @@ mod tests {
             }));
         });
     }
+
+    #[test]
+    fn mod_with_errors() {
+        let module = Module::from_syn(
+            &syn::parse_quote! {
+                #[diplomat::bridge]
+                mod ffi {
+                    struct Valid {
+                        a: u8,
+                    }
+
+                    struct Tuple(u8);
+
+                    impl Tuple {
+                        pub fn new() -> Tuple {}
+                    }
+
+                    enum Generic<T> {
+                        A = some_fn(),
+                    }
+
+                    #[diplomat::opaque]
+                    #[diplomat::out]
+                    struct Conflicting;
+
+                    struct Valid {
+                        b: u8,
+                    }
+
+                    pub const NOT_LITERAL: u8 = 1 + 1;
+
+                    impl Missing {
+                        pub fn f() {}
+                    }
+                }
+            },
+            true,
+        );
+
+        let names = module.declared_types.keys().collect::<Vec<_>>();
+        let errors = module
+            .all_errors()
+            .into_iter()
+            .flat_map(|e| e.into_iter())
+            .map(|e| e.to_string())
+            .collect::<Vec<_>>();
+        insta::assert_yaml_snapshot!((names, errors));
+    }
+
+    #[test]
+    fn mod_with_duplicate_names() {
+        let module = Module::from_syn(
+            &syn::parse_quote! {
+                #[diplomat::bridge]
+                mod ffi {
+                    use super::other::Imported;
+
+                    struct Logger;
+
+                    trait Logger {
+                        fn log(&self);
+                    }
+
+                    trait Imported {
+                        fn log(&self);
+                    }
+
+                    pub const Logger: u8 = 1;
+
+                    use super::other::MAX_LEN;
+
+                    pub const MAX_LEN: u32 = 16;
+                }
+            },
+            true,
+        );
+
+        let traits = module.declared_traits.keys().collect::<Vec<_>>();
+        let consts = module.declared_consts.keys().collect::<Vec<_>>();
+        let errors = module
+            .all_errors()
+            .into_iter()
+            .map(|e| e.to_string())
+            .collect::<Vec<_>>();
+        insta::assert_yaml_snapshot!((traits, consts, errors));
+    }
+
+    #[test]
+    fn file_with_cfg() {
+        let mut cfg = CfgSet::new();
+        cfg.insert_feature("buffers");
+        cfg.insert_str("unix").unwrap();
+
+        let file = File::from_syn_with_cfg(
+            &syn::parse_quote! {
+                #[diplomat::bridge]
+                mod ffi {
+                    #[diplomat::opaque]
+                    struct Always;
+
+                    #[cfg(feature = "buffers")]
+                    #[diplomat::opaque]
+                    struct Buffer;
+
+                    #[cfg(feature = "serde")]
+                    #[diplomat::opaque]
+                    struct Serde;
+
+                    struct Point {
+                        x: u8,
+                        #[cfg(not(unix))]
+                        handle: u32,
+                    }
+
+                    enum Kind {
+                        A,
+                        #[cfg(any(windows, feature = "buffers"))]
+                        B,
+                        #[cfg(windows)]
+                        C,
+                    }
+
+                    impl Always {
+                        pub fn always() {}
+                        #[cfg(all(unix, feature = "buffers"))]
+                        pub fn unix_buffers() {}
+                        #[cfg(windows)]
+                        pub fn windows() {}
+                        #[cfg(feature = 1)]
+                        pub fn malformed() {}
+                    }
+
+                    #[cfg(feature = "serde")]
+                    impl Serde {
+                        pub fn new() {}
+                    }
+                }
+
+                #[cfg(windows)]
+                #[diplomat::bridge]
+                mod windows_ffi {
+                    #[diplomat::opaque]
+                    struct Handle;
+                }
+            },
+            &cfg,
+        );
+
+        let mut items = Vec::new();
+        for module in file.modules.values() {
+            for (name, custom_type) in &module.declared_types {
+                items.push(format!("{}::{}", module.name, name));
+                let members: Vec<String> = match custom_type {
+                    CustomType::Struct(strct) => strct
+                        .fields
+                        .iter()
+                        .map(|(name, ..)| name.to_string())
+                        .collect(),
+                    CustomType::Enum(enm) => enm
+                        .variants
+                        .iter()
+                        .map(|variant| variant.name.to_string())
+                        .collect(),
+                    CustomType::Opaque(_) => Vec::new(),
+                };
+                items.extend(members.into_iter().map(|m| format!("  .{m}")));
+                items.extend(
+                    custom_type
+                        .methods()
+                        .iter()
+                        .map(|m| format!("  {}()", m.name)),
+                );
+            }
+        }
+        let errors = file
+            .all_errors()
+            .into_iter()
+            .map(|e| e.to_string())
+            .collect::<Vec<_>>();
+        insta::assert_yaml_snapshot!((items, errors));
+    }
 }
@@ -0,0 +1,42 @@
+/// The location of an item in the source it was parsed from, for pointing
+/// diagnostics at the offending code.
+///
+/// Locations are only known when parsing outside of a proc macro (as
+/// `diplomat-tool` does), since `proc_macro` spans don't expose line
+/// information on stable Rust.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub struct SourceSpan {
+    /// The 1-indexed line the span starts on.
+    pub start_line: usize,
+    /// The 0-indexed column, in chars, the span starts at.
+    pub start_column: usize,
+    /// The 1-indexed line the span ends on.
+    pub end_line: usize,
+    /// The 0-indexed column, in chars, just past the end of the span.
+    pub end_column: usize,
+    /// The byte offset of the start of the span within its file.
+    pub start_byte: usize,
+    /// The byte offset just past the end of the span within its file.
+    pub end_byte: usize,
+}
+
+impl SourceSpan {
+    /// Get the location of a `proc_macro2` span, if it is known.
+    pub fn from_span(span: proc_macro2::Span) -> Option<Self> {
+        let bytes = span.byte_range();
+        let (start, end) = (span.start(), span.end());
+        // Call-site spans (from `quote!`) and compiler spans are empty
+        if bytes.is_empty() || start.line == 0 {
+            return None;
+        }
+        Some(SourceSpan {
+            start_line: start.line,
+            start_column: start.column,
+            end_line: end.line,
+            end_column: end.column,
+            start_byte: bytes.start,
+            end_byte: bytes.end,
+        })
+    }
+}
@@ -1,7 +1,7 @@
 use serde::Serialize;
 
 use super::docs::Docs;
-use super::{Attrs, Ident, LifetimeEnv, Method, Mutability, PathType, TypeName};
+use super::{Attrs, Const, Ident, LifetimeEnv, Method, Mutability, PathType, SourceSpan, TypeName};
 
 /// A struct declaration in an FFI module that is not opaque.
 #[derive(Clone, PartialEq, Eq, Hash, Serialize, Debug)]
@@ -12,24 +12,35 @@ pub struct Struct {
     pub lifetimes: LifetimeEnv,
     pub fields: Vec<(Ident, TypeName, Docs)>,
     pub methods: Vec<Method>,
+    pub consts: Vec<Const>,
     pub output_only: bool,
     pub attrs: Attrs,
+    /// Where the type was declared, if known.
+    #[serde(skip)]
+    pub span: Option<SourceSpan>,
 }
 
 impl Struct {
     /// Extract a [`Struct`] metadata value from an AST node.
-    pub fn new(strct: &syn::ItemStruct, output_only: bool, parent_attrs: &Attrs) -> Self {
+    pub fn new(
+        strct: &syn::ItemStruct,
+        output_only: bool,
+        parent_attrs: &Attrs,
+    ) -> syn::Result<Self> {
+        // Non-opaque tuple structs will never be allowed
+        if let syn::Fields::Unnamed(ref fields) = strct.fields {
+            return Err(syn::Error::new_spanned(
+                fields,
+                "non-opaque tuples structs are disallowed",
+            ));
+        }
+
         let self_path_type = PathType::extract_self_type(strct);
         let fields: Vec<_> = strct
             .fields
             .iter()
             .map(|field| {
-                // Non-opaque tuple structs will never be allowed
-                let name = field
-                    .ident
-                    .as_ref()
-                    .map(Into::into)
-                    .expect("non-opaque tuples structs are disallowed");
+                let name = field.ident.as_ref().unwrap().into();
                 let type_name = TypeName::from_syn(&field.ty, Some(self_path_type.clone()));
                 let docs = Docs::from_attrs(&field.attrs);
 
@@ -40,15 +51,17 @@ impl Struct {
         let lifetimes = LifetimeEnv::from_struct_item(strct, &fields[..]);
         let mut attrs = parent_attrs.clone();
         attrs.add_attrs(&strct.attrs);
-        Struct {
+        Ok(Struct {
             name: (&strct.ident).into(),
             docs: Docs::from_attrs(&strct.attrs),
             lifetimes,
             fields,
             methods: vec![],
+            consts: vec![],
             output_only,
             attrs,
-        }
+            span: SourceSpan::from_span(strct.ident.span()),
+        })
     }
 }
 
@@ -62,8 +75,12 @@ pub struct OpaqueStruct {
     pub docs: Docs,
     pub lifetimes: LifetimeEnv,
     pub methods: Vec<Method>,
+    pub consts: Vec<Const>,
     pub mutability: Mutability,
     pub attrs: Attrs,
+    /// Where the type was declared, if known.
+    #[serde(skip)]
+    pub span: Option<SourceSpan>,
 }
 
 impl OpaqueStruct {
@@ -76,8 +93,10 @@ impl OpaqueStruct {
             docs: Docs::from_attrs(&strct.attrs),
             lifetimes: LifetimeEnv::from_struct_item(strct, &[]),
             methods: vec![],
+            consts: vec![],
             mutability,
             attrs,
+            span: SourceSpan::from_span(strct.ident.span()),
         }
     }
 }
@@ -107,7 +126,8 @@ mod tests {
                 },
                 true,
                 &Default::default()
-            ));
+            )
+            .unwrap());
         });
     }
 }
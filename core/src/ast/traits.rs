@@ -0,0 +1,178 @@
+use serde::Serialize;
+use syn::spanned::Spanned;
+
+use super::docs::Docs;
+use super::{
+    AttrInheritContext, Attrs, Ident, LifetimeEnv, Param, Path, PathType, SelfParam, SourceSpan,
+    TypeName,
+};
+
+/// A trait declaration in an FFI module.
+///
+/// Traits are implemented by foreign code and handed to Rust as `Box<dyn Trait>`
+/// or `&dyn Trait`, crossing the FFI boundary as a `#[repr(C)]` vtable.
+#[derive(Clone, Serialize, Debug, Hash, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Trait {
+    pub name: Ident,
+    pub docs: Docs,
+    pub methods: Vec<TraitMethod>,
+    pub attrs: Attrs,
+    /// Where the trait was declared, if known.
+    #[serde(skip)]
+    pub span: Option<SourceSpan>,
+}
+
+/// A method declared in a [`Trait`], which becomes a function pointer in the vtable.
+#[derive(Clone, Serialize, Debug, Hash, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct TraitMethod {
+    /// The name of the method as initially declared.
+    pub name: Ident,
+
+    /// Lines of documentation for the method.
+    pub docs: Docs,
+
+    /// The `self` param of the method, if any.
+    pub self_param: Option<SelfParam>,
+
+    /// All non-`self` params taken by the method.
+    pub params: Vec<Param>,
+
+    /// The return type of the method, if any.
+    pub return_type: Option<TypeName>,
+
+    /// The lifetimes introduced in this method.
+    pub lifetime_env: LifetimeEnv,
+
+    pub attrs: Attrs,
+
+    /// Where the signature of the method was declared, if known.
+    #[serde(skip)]
+    pub span: Option<SourceSpan>,
+}
+
+impl Trait {
+    /// Extract a [`Trait`] metadata value from an AST node.
+    pub fn new(trt: &syn::ItemTrait, parent_attrs: &Attrs) -> syn::Result<Trait> {
+        if !trt.generics.params.is_empty() {
+            return Err(syn::Error::new_spanned(
+                &trt.generics,
+                "Traits cannot have generic parameters",
+            ));
+        }
+
+        let mut attrs = parent_attrs.clone();
+        attrs.add_attrs(&trt.attrs);
+        let method_parent_attrs = attrs.attrs_for_inheritance(AttrInheritContext::MethodFromImpl);
+
+        let self_path_type = PathType::new(Path {
+            elements: vec![(&trt.ident).into()],
+        });
+
+        let methods = trt
+            .items
+            .iter()
+            .filter_map(|i| match i {
+                syn::TraitItem::Fn(m) => Some(TraitMethod::from_syn(
+                    m,
+                    self_path_type.clone(),
+                    &method_parent_attrs,
+                )),
+                _ => None,
+            })
+            .collect();
+
+        Ok(Trait {
+            name: (&trt.ident).into(),
+            docs: Docs::from_attrs(&trt.attrs),
+            methods,
+            attrs,
+            span: SourceSpan::from_span(trt.ident.span()),
+        })
+    }
+}
+
+impl TraitMethod {
+    /// Extracts a [`TraitMethod`] from an AST node inside a `trait`.
+    pub fn from_syn(
+        m: &syn::TraitItemFn,
+        self_path_type: PathType,
+        trait_attrs: &Attrs,
+    ) -> TraitMethod {
+        let mut attrs = trait_attrs.clone();
+        attrs.add_attrs(&m.attrs);
+
+        let params = m
+            .sig
+            .inputs
+            .iter()
+            .filter_map(|a| match a {
+                syn::FnArg::Receiver(_) => None,
+                syn::FnArg::Typed(ref t) => Some(Param::from_syn(t, self_path_type.clone())),
+            })
+            .collect::<Vec<_>>();
+
+        let self_param = m
+            .sig
+            .receiver()
+            .map(|rec| SelfParam::from_syn(rec, self_path_type.clone()));
+
+        let return_type = match &m.sig.output {
+            syn::ReturnType::Type(_, return_typ) => Some(TypeName::from_syn(
+                return_typ.as_ref(),
+                Some(self_path_type),
+            )),
+            syn::ReturnType::Default => None,
+        };
+
+        let lifetime_env = LifetimeEnv::from_method_sig(
+            &m.sig,
+            None,
+            self_param.as_ref(),
+            &params[..],
+            return_type.as_ref(),
+        );
+
+        TraitMethod {
+            name: (&m.sig.ident).into(),
+            docs: Docs::from_attrs(&m.attrs),
+            self_param,
+            params,
+            return_type,
+            lifetime_env,
+            attrs,
+            span: SourceSpan::from_span(m.sig.span()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use insta::{self, Settings};
+
+    use syn;
+
+    use super::Trait;
+
+    #[test]
+    fn simple_trait() {
+        let mut settings = Settings::new();
+        settings.set_sort_maps(true);
+
+        settings.bind(|| {
+            insta::assert_yaml_snapshot!(Trait::new(
+                &syn::parse_quote! {
+                    /// Receives log messages.
+                    trait Logger {
+                        /// Log a message.
+                        fn log(&self, level: u8, msg: &DiplomatStr);
+                        fn enabled(&self) -> bool;
+                    }
+                },
+                &Default::default()
+            )
+            .unwrap());
+        });
+    }
+}
@@ -1,15 +1,16 @@
 use proc_macro2::Span;
 use quote::ToTokens;
 use serde::{Deserialize, Serialize};
-use syn::{punctuated::Punctuated, *};
+use syn::{punctuated::Punctuated, spanned::Spanned, *};
 
 use lazy_static::lazy_static;
 use std::collections::HashMap;
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::ops::ControlFlow;
 
 use super::{
-    Attrs, Docs, Enum, Ident, Lifetime, LifetimeEnv, LifetimeTransitivity, Method, NamedLifetime, OpaqueStruct, Path, RustLink, Struct
+    Attrs, Const, Docs, Enum, Ident, Lifetime, LifetimeEnv, LifetimeTransitivity, Method, NamedLifetime, OpaqueStruct, Path, RustLink, SourceSpan, Struct, Trait
 };
 use crate::Env;
 
@@ -44,6 +45,15 @@ impl CustomType {
         }
     }
 
+    /// Get the associated consts declared in impls of the custom type.
+    pub fn consts(&self) -> &Vec<Const> {
+        match self {
+            CustomType::Struct(strct) => &strct.consts,
+            CustomType::Opaque(strct) => &strct.consts,
+            CustomType::Enum(enm) => &enm.consts,
+        }
+    }
+
     pub fn attrs(&self) -> &Attrs {
         match self {
             CustomType::Struct(strct) => &strct.attrs,
@@ -100,14 +110,37 @@ pub enum ModSymbol {
     SubModule(Ident),
     /// A symbol that is a custom type.
     CustomType(CustomType),
+    /// A symbol that is a trait.
+    Trait(Trait),
+    /// A symbol that is a module-level constant.
+    Const(Const),
 }
 
 /// A named type that is just a path, e.g. `std::borrow::Cow<'a, T>`.
-#[derive(Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Debug)]
+#[derive(Clone, Serialize, Deserialize, Debug)]
 #[non_exhaustive]
 pub struct PathType {
     pub path: Path,
     pub lifetimes: Vec<Lifetime>,
+    /// Where the path was written, for errors about types it doesn't resolve to.
+    /// Not part of the type's identity.
+    #[serde(skip)]
+    pub span: Option<SourceSpan>,
+}
+
+impl PartialEq for PathType {
+    fn eq(&self, other: &Self) -> bool {
+        self.path == other.path && self.lifetimes == other.lifetimes
+    }
+}
+
+impl Eq for PathType {}
+
+impl Hash for PathType {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.path.hash(state);
+        self.lifetimes.hash(state);
+    }
 }
 
 impl PathType {
@@ -129,6 +162,7 @@ impl PathType {
         Self {
             path,
             lifetimes: vec![],
+            span: None,
         }
     }
 
@@ -161,6 +195,7 @@ impl PathType {
                 .lifetimes()
                 .map(|lt_def| (&lt_def.lifetime).into())
                 .collect(),
+            span: None,
         }
     }
 
@@ -169,17 +204,71 @@ impl PathType {
     ///
     /// Also returns the path the CustomType is in (useful for resolving fields)
     pub fn resolve_with_path<'a>(&self, in_path: &Path, env: &'a Env) -> (Path, &'a CustomType) {
+        self.try_resolve_with_path(in_path, env)
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Like [`Self::resolve_with_path()`], but returns why the path doesn't point to a
+    /// custom type instead of panicking.
+    pub fn try_resolve_with_path<'a>(
+        &self,
+        in_path: &Path,
+        env: &'a Env,
+    ) -> std::result::Result<(Path, &'a CustomType), String> {
+        match self.resolve_symbol_with_path(in_path, env)? {
+            (path, ModSymbol::CustomType(t)) => Ok((path, t)),
+            _ => Err(format!(
+                "Path {} does not point to a custom type",
+                self.path.elements.join("::")
+            )),
+        }
+    }
+
+    /// If this is the path of a [`TypeName::DynTrait`], grab the [`Trait`] it points to
+    /// from the `env`.
+    pub fn resolve_trait<'a>(&self, in_path: &Path, env: &'a Env) -> &'a Trait {
+        self.try_resolve_trait(in_path, env)
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Like [`Self::resolve_trait()`], but returns why the path doesn't point to a trait
+    /// instead of panicking.
+    pub fn try_resolve_trait<'a>(
+        &self,
+        in_path: &Path,
+        env: &'a Env,
+    ) -> std::result::Result<&'a Trait, String> {
+        match self.resolve_symbol_with_path(in_path, env)? {
+            (_, ModSymbol::Trait(t)) => Ok(t),
+            _ => Err(format!(
+                "Path {} does not point to a trait",
+                self.path.elements.join("::")
+            )),
+        }
+    }
+
+    /// Grab the custom type or trait this path points to, along with the path it is in.
+    fn resolve_symbol_with_path<'a>(
+        &self,
+        in_path: &Path,
+        env: &'a Env,
+    ) -> std::result::Result<(Path, &'a ModSymbol), String> {
         let local_path = &self.path;
         let mut cur_path = in_path.clone();
         for (i, elem) in local_path.elements.iter().enumerate() {
             match elem.as_str() {
-                "crate" => {
-                    // TODO(#34): get the name of enclosing crate from env when we support multiple crates
-                    cur_path = Path::empty()
-                }
+                "crate" => cur_path = env.crate_root(&cur_path),
 
                 "super" => cur_path = cur_path.get_super(),
 
+                // Like in Rust 2018, other crates can be named from any module
+                o if i == 0
+                    && env.get(&cur_path, o).is_none()
+                    && env.crates.contains(o) =>
+                {
+                    cur_path = Path::empty().sub_path(o.to_string().into());
+                }
+
                 o => match env.get(&cur_path, o) {
                     Some(ModSymbol::Alias(p)) => {
                         let mut remaining_elements: Vec<Ident> =
@@ -187,35 +276,44 @@ impl PathType {
                         let mut new_path = p.elements.clone();
                         new_path.append(&mut remaining_elements);
                         return PathType::new(Path { elements: new_path })
-                            .resolve_with_path(&cur_path.clone(), env);
+                            .resolve_symbol_with_path(&cur_path.clone(), env);
                     }
                     Some(ModSymbol::SubModule(name)) => {
                         cur_path.elements.push(name.clone());
                     }
-                    Some(ModSymbol::CustomType(t)) => {
+                    Some(symbol @ (ModSymbol::CustomType(_) | ModSymbol::Trait(_))) => {
                         if i == local_path.elements.len() - 1 {
-                            return (cur_path, t);
+                            return Ok((cur_path, symbol));
                         } else {
-                            panic!(
+                            return Err(format!(
                                 "Unexpected custom type when resolving symbol {} in {}",
                                 o,
                                 cur_path.elements.join("::")
-                            )
+                            ));
                         }
                     }
-                    None => panic!(
-                        "Could not resolve symbol {} in {}",
-                        o,
-                        cur_path.elements.join("::")
-                    ),
+                    Some(ModSymbol::Const(_)) => {
+                        return Err(format!(
+                            "Symbol {} in {} is a const, not a type",
+                            o,
+                            cur_path.elements.join("::")
+                        ))
+                    }
+                    None => {
+                        return Err(format!(
+                            "Could not resolve symbol {} in {}",
+                            o,
+                            cur_path.elements.join("::")
+                        ))
+                    }
                 },
             }
         }
 
-        panic!(
-            "Path {} does not point to a custom type",
+        Err(format!(
+            "Path {} does not point to a custom type or trait",
             in_path.elements.join("::")
-        )
+        ))
     }
 
     /// If this is a [`TypeName::Named`], grab the [`CustomType`] it points to from
@@ -255,6 +353,7 @@ impl From<&syn::TypePath> for PathType {
         Self {
             path: Path::from_syn(&other.path),
             lifetimes,
+            span: SourceSpan::from_span(other.span()),
         }
     }
 }
@@ -363,6 +462,8 @@ pub enum TypeName {
     Ordering,
     /// A function type,
     Function(Vec<(TypeName, Option<Ident>)>, Box<TypeName>),
+    /// A `dyn Trait` type, which can only appear behind a `Box` or a reference.
+    DynTrait(PathType),
 }
 
 #[derive(Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Debug, Copy)]
@@ -535,6 +636,12 @@ impl TypeName {
                 }).collect::<Vec<_>>().join(", ");
                 syn::parse_str(&format!("extern \"C\" fn({param_decls})")).unwrap()
             }
+            TypeName::DynTrait(path) => {
+                let path = path.to_syn();
+                syn::parse_quote! {
+                    dyn #path
+                }
+            }
         }
     }
 
@@ -718,6 +825,22 @@ impl TypeName {
                 };
                 TypeName::Function(inputs, Box::new(output))
             },
+            syn::Type::TraitObject(TypeTraitObject { bounds, .. }) => {
+                let mut traits = bounds.iter().filter_map(|b| match b {
+                    TypeParamBound::Trait(bound) => Some(bound),
+                    _ => None,
+                });
+                match (traits.next(), traits.next()) {
+                    (Some(bound), None) => TypeName::DynTrait(PathType::from(&TypePath {
+                        qself: None,
+                        path: bound.path.clone(),
+                    })),
+                    _ => panic!(
+                        "Trait objects must name exactly one trait: {}",
+                        ty.to_token_stream()
+                    ),
+                }
+            }
             other => panic!("Unsupported type: {}", other.to_token_stream()),
         }
     }
@@ -911,9 +1034,23 @@ impl fmt::Display for TypeName {
             TypeName::PrimitiveSlice(None, typ) => write!(f, "Box<[{typ}]>"),
             TypeName::Unit => "()".fmt(f),
             TypeName::Function(params, return_type) => {
-                //write!(f, "extern \"C\" fn(/*2*/)")
-                unimplemented!()
-            },
+                "extern \"C\" fn(".fmt(f)?;
+                for (i, (ty, name)) in params.iter().enumerate() {
+                    if i > 0 {
+                        ", ".fmt(f)?;
+                    }
+                    match name {
+                        Some(name) => write!(f, "{name}: {ty}")?,
+                        None => ty.fmt(f)?,
+                    }
+                }
+                ")".fmt(f)?;
+                match **return_type {
+                    TypeName::Unit => Ok(()),
+                    ref ty => write!(f, " -> {ty}"),
+                }
+            }
+            TypeName::DynTrait(p) => write!(f, "dyn {p}"),
         }
     }
 }
@@ -1115,6 +1252,23 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn typename_dyn_trait() {
+        insta::assert_yaml_snapshot!(TypeName::from_syn(
+            &syn::parse_quote! {
+                Box<dyn MyTrait>
+            },
+            None
+        ));
+
+        insta::assert_yaml_snapshot!(TypeName::from_syn(
+            &syn::parse_quote! {
+                &dyn MyTrait
+            },
+            None
+        ));
+    }
+
     #[test]
     fn typename_option() {
         insta::assert_yaml_snapshot!(TypeName::from_syn(
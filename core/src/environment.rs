@@ -1,5 +1,5 @@
 use crate::ast::*;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::ops::Index;
 
 /// The type resolution environment
@@ -8,6 +8,9 @@ use std::ops::Index;
 #[derive(Default, Clone)]
 pub struct Env {
     pub(crate) env: BTreeMap<Path, ModuleEnv>,
+    /// The other crates whose bridges the bridge crate uses types from. Their modules
+    /// are under a path starting with the name of the crate.
+    pub(crate) crates: BTreeSet<Ident>,
 }
 
 /// The type resolution environment within a specific module
@@ -28,6 +31,20 @@ impl Env {
         self.env.get(path).and_then(|m| m.module.get(name))
     }
 
+    /// The crate the module at `path` is in, if it is another crate whose bridge the
+    /// bridge crate uses types from rather than the bridge crate itself
+    pub fn crate_of(&self, path: &Path) -> Option<&Ident> {
+        path.elements.first().and_then(|first| self.crates.get(first))
+    }
+
+    /// The path `crate` refers to in the module at `path`
+    pub(crate) fn crate_root(&self, path: &Path) -> Path {
+        match self.crate_of(path) {
+            Some(krate) => Path::empty().sub_path(krate.clone()),
+            None => Path::empty(),
+        }
+    }
+
     /// Iterate over all items in the environment
     ///
     /// This will occur in a stable lexically sorted order by path and then name
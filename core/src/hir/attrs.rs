@@ -4,8 +4,8 @@ use crate::ast;
 use crate::ast::attrs::{AttrInheritContext, DiplomatBackendAttrCfg, StandardAttribute};
 use crate::hir::lowering::ErrorStore;
 use crate::hir::{
-    EnumVariant, LoweringError, Method, Mutability, OpaqueId, ReturnType, SelfType, SuccessType,
-    Type, TypeDef, TypeId,
+    ConstDef, EnumVariant, LoweringError, LoweringErrorKind, Method, Mutability, OpaqueId,
+    ReturnType, SelfType, SuccessType, Type, TypeDef, TypeId,
 };
 use syn::Meta;
 
@@ -35,6 +35,11 @@ pub struct Attrs {
     ///
     /// This attribute is inherited except through variants
     pub abi_rename: RenameAttr,
+    /// Panics in this method are caught in its C ABI function. Backends should surface them,
+    /// see `diplomat_runtime::panic` for how they are reported.
+    ///
+    /// This attribute is inherited except through variants
+    pub catch_panics: bool,
     /// This method is "special": it should generate something other than a regular method on the other side.
     /// This can be something like a constructor, an accessor, a stringifier etc.
     ///
@@ -101,6 +106,7 @@ pub enum AttributeContext<'a, 'b> {
     Type(TypeDef<'a>),
     EnumVariant(&'a EnumVariant),
     Method(&'a Method, TypeId, &'b mut SpecialMethodPresence),
+    Const(&'a ConstDef),
     Module,
 }
 
@@ -115,6 +121,7 @@ impl Attrs {
         // Backends must support this since it applies to the macro/C code.
         // No special inheritance, was already appropriately inherited in AST
         this.abi_rename = ast.abi_rename.clone();
+        this.catch_panics = ast.catch_panics;
 
         let support = validator.attrs_supported();
         let backend = validator.primary_name();
@@ -132,19 +139,22 @@ impl Attrs {
                     if path == "disable" {
                         if let Meta::Path(_) = attr.meta {
                             if this.disable {
-                                errors.push(LoweringError::Other(
-                                    "Duplicate `disable` attribute".into(),
+                                errors.push(LoweringError::new(
+                                    LoweringErrorKind::InvalidAttribute,
+                                    "Duplicate `disable` attribute",
                                 ));
                             } else if !support.disabling {
-                                errors.push(LoweringError::Other(format!(
-                                    "`disable` not supported in backend {backend}"
-                                )))
+                                errors.push(LoweringError::new(
+                                    LoweringErrorKind::UnsupportedByBackend,
+                                    format!("`disable` not supported in backend {backend}"),
+                                ))
                             } else {
                                 this.disable = true;
                             }
                         } else {
-                            errors.push(LoweringError::Other(
-                                "`disable` must be a simple path".into(),
+                            errors.push(LoweringError::new(
+                                LoweringErrorKind::InvalidAttribute,
+                                "`disable` must be a simple path",
                             ))
                         }
                     } else if path == "rename" {
@@ -155,15 +165,17 @@ impl Attrs {
                                 // to the end; so a later attribute in the list is more pertinent.
                                 this.rename.extend(&rename);
                             }
-                            Err(e) => errors.push(LoweringError::Other(format!(
-                                "`rename` attr failed to parse: {e:?}"
-                            ))),
+                            Err(e) => errors.push(LoweringError::new(
+                                LoweringErrorKind::InvalidAttribute,
+                                format!("`rename` attr failed to parse: {e:?}"),
+                            )),
                         }
                     } else if path == "namespace" {
                         if !support.namespacing {
-                            errors.push(LoweringError::Other(format!(
-                                "`namespace` not supported in backend {backend}"
-                            )));
+                            errors.push(LoweringError::new(
+                                LoweringErrorKind::UnsupportedByBackend,
+                                format!("`namespace` not supported in backend {backend}"),
+                            ));
                             continue;
                         }
                         match StandardAttribute::from_meta(&attr.meta) {
@@ -172,8 +184,9 @@ impl Attrs {
                             }
                             Ok(StandardAttribute::String(s)) => this.namespace = Some(s),
                             Ok(_) | Err(_) => {
-                                errors.push(LoweringError::Other(
-                                    "`namespace` must have a single string parameter".to_string(),
+                                errors.push(LoweringError::new(
+                                    LoweringErrorKind::InvalidAttribute,
+                                    "`namespace` must have a single string parameter",
                                 ));
                                 continue;
                             }
@@ -186,51 +199,59 @@ impl Attrs {
                         || path == "indexer"
                     {
                         if let Some(ref existing) = this.special_method {
-                            errors.push(LoweringError::Other(format!(
+                            errors.push(LoweringError::new(LoweringErrorKind::InvalidAttribute, format!(
                             "Multiple special method markers found on the same method, found {path} and {existing:?}"
                         )));
                             continue;
                         }
                         let kind = if path == "constructor" {
                             if !support.constructors {
-                                errors.push(LoweringError::Other(format!(
-                                    "constructor not supported in backend {backend}"
-                                )))
+                                errors.push(LoweringError::new(
+                                    LoweringErrorKind::UnsupportedByBackend,
+                                    format!("constructor not supported in backend {backend}"),
+                                ))
                             }
                             SpecialMethod::Constructor
                         } else if path == "stringifier" {
                             if !support.stringifiers {
-                                errors.push(LoweringError::Other(format!(
-                                    "stringifier not supported in backend {backend}"
-                                )))
+                                errors.push(LoweringError::new(
+                                    LoweringErrorKind::UnsupportedByBackend,
+                                    format!("stringifier not supported in backend {backend}"),
+                                ))
                             }
                             SpecialMethod::Stringifier
                         } else if path == "iterable" {
                             if !support.iterables {
-                                errors.push(LoweringError::Other(format!(
-                                    "iterable not supported in backend {backend}"
-                                )))
+                                errors.push(LoweringError::new(
+                                    LoweringErrorKind::UnsupportedByBackend,
+                                    format!("iterable not supported in backend {backend}"),
+                                ))
                             }
                             SpecialMethod::Iterable
                         } else if path == "iterator" {
                             if !support.iterators {
-                                errors.push(LoweringError::Other(format!(
-                                    "iterator not supported in backend {backend}"
-                                )))
+                                errors.push(LoweringError::new(
+                                    LoweringErrorKind::UnsupportedByBackend,
+                                    format!("iterator not supported in backend {backend}"),
+                                ))
                             }
                             SpecialMethod::Iterator
                         } else if path == "indexer" {
                             if !support.indexing {
-                                errors.push(LoweringError::Other(format!(
-                                    "indexing not supported in backend {backend}"
-                                )))
+                                errors.push(LoweringError::new(
+                                    LoweringErrorKind::UnsupportedByBackend,
+                                    format!("indexing not supported in backend {backend}"),
+                                ))
                             }
                             SpecialMethod::Indexer
                         } else {
                             if !support.comparators {
-                                errors.push(LoweringError::Other(format!(
-                                    "comparison overload not supported in backend {backend}"
-                                )))
+                                errors.push(LoweringError::new(
+                                    LoweringErrorKind::UnsupportedByBackend,
+                                    format!(
+                                        "comparison overload not supported in backend {backend}"
+                                    ),
+                                ))
                             }
                             SpecialMethod::Comparison
                         };
@@ -238,30 +259,35 @@ impl Attrs {
                         this.special_method = Some(kind);
                     } else if path == "named_constructor" || path == "getter" || path == "setter" {
                         if let Some(ref existing) = this.special_method {
-                            errors.push(LoweringError::Other(format!(
+                            errors.push(LoweringError::new(LoweringErrorKind::InvalidAttribute, format!(
                             "Multiple special method markers found on the same method, found {path} and {existing:?}"
                         )));
                             continue;
                         }
                         let kind = if path == "named_constructor" {
                             if !support.named_constructors {
-                                errors.push(LoweringError::Other(format!(
-                                    "named constructors not supported in backend {backend}"
-                                )))
+                                errors.push(LoweringError::new(
+                                    LoweringErrorKind::UnsupportedByBackend,
+                                    format!(
+                                        "named constructors not supported in backend {backend}"
+                                    ),
+                                ))
                             }
                             SpecialMethod::NamedConstructor
                         } else if path == "getter" {
                             if !support.accessors {
-                                errors.push(LoweringError::Other(format!(
-                                    "accessors not supported in backend {backend}"
-                                )))
+                                errors.push(LoweringError::new(
+                                    LoweringErrorKind::UnsupportedByBackend,
+                                    format!("accessors not supported in backend {backend}"),
+                                ))
                             }
                             SpecialMethod::Getter
                         } else {
                             if !support.accessors {
-                                errors.push(LoweringError::Other(format!(
-                                    "accessors not supported in backend {backend}"
-                                )))
+                                errors.push(LoweringError::new(
+                                    LoweringErrorKind::UnsupportedByBackend,
+                                    format!("accessors not supported in backend {backend}"),
+                                ))
                             }
                             SpecialMethod::Setter
                         };
@@ -271,19 +297,22 @@ impl Attrs {
                             }
                             Ok(StandardAttribute::Empty) => this.special_method = Some(kind(None)),
                             Ok(_) | Err(_) => {
-                                errors.push(LoweringError::Other(format!(
+                                errors.push(LoweringError::new(
+                                    LoweringErrorKind::InvalidAttribute,
+                                    format!(
                                     "`{path}` must have a single string parameter or no parameter",
-                                )));
+                                ),
+                                ));
                                 continue;
                             }
                         }
                     } else {
-                        errors.push(LoweringError::Other(format!(
+                        errors.push(LoweringError::new(LoweringErrorKind::InvalidAttribute, format!(
                         "Unknown diplomat attribute {path}: expected one of: `disable, rename, namespace, constructor, stringifier, comparison, named_constructor, getter, setter, indexer`"
                     )));
                     }
                 } else {
-                    errors.push(LoweringError::Other(format!(
+                    errors.push(LoweringError::new(LoweringErrorKind::InvalidAttribute, format!(
                         "Unknown diplomat attribute {path:?}: expected one of: `disable, rename, namespace, constructor, stringifier, comparison, named_constructor, getter, setter, indexer`"
                     )));
                 }
@@ -306,12 +335,14 @@ impl Attrs {
             namespace,
             rename: _,
             abi_rename: _,
+            catch_panics: _,
             special_method,
         } = &self;
 
         if *disable && matches!(context, AttributeContext::EnumVariant(..)) {
-            errors.push(LoweringError::Other(
-                "`disable` cannot be used on enum variants".into(),
+            errors.push(LoweringError::new(
+                LoweringErrorKind::InvalidAttribute,
+                "`disable` cannot be used on enum variants",
             ))
         }
 
@@ -322,8 +353,9 @@ impl Attrs {
                 match special {
                     SpecialMethod::Constructor | SpecialMethod::NamedConstructor(..) => {
                         if method.param_self.is_some() {
-                            errors.push(LoweringError::Other(
-                                "Constructors must not accept a self parameter".to_string(),
+                            errors.push(LoweringError::new(
+                                LoweringErrorKind::InvalidSpecialMethod,
+                                "Constructors must not accept a self parameter",
                             ))
                         }
                         let output = method.output.success_type();
@@ -331,33 +363,37 @@ impl Attrs {
                             ReturnType::Infallible(_) => (),
                             ReturnType::Fallible(..) => {
                                 if !validator.attrs_supported().fallible_constructors {
-                                    errors.push(LoweringError::Other(
-                                        "This backend doesn't support fallible constructors"
-                                            .to_string(),
+                                    errors.push(LoweringError::new(
+                                        LoweringErrorKind::UnsupportedByBackend,
+                                        "This backend doesn't support fallible constructors",
                                     ))
                                 }
                             }
                             ReturnType::Nullable(..) => {
-                                errors.push(LoweringError::Other("Diplomat doesn't support turning nullable methods into constructors".to_string()));
+                                errors.push(LoweringError::new(LoweringErrorKind::InvalidSpecialMethod, "Diplomat doesn't support turning nullable methods into constructors"));
                             }
                         }
 
                         if let SuccessType::OutType(t) = &output {
                             if t.id() != Some(self_id) {
-                                errors.push(LoweringError::Other(
-                                    "Constructors must return Self!".to_string(),
+                                errors.push(LoweringError::new(
+                                    LoweringErrorKind::InvalidSpecialMethod,
+                                    "Constructors must return Self!",
                                 ));
                             }
                         } else {
-                            errors.push(LoweringError::Other(
-                                "Constructors must return Self!".to_string(),
+                            errors.push(LoweringError::new(
+                                LoweringErrorKind::InvalidSpecialMethod,
+                                "Constructors must return Self!",
                             ));
                         }
                     }
                     SpecialMethod::Getter(_) => {
                         if !method.params.is_empty() {
-                            errors
-                                .push(LoweringError::Other("Getter cannot have parameters".into()));
+                            errors.push(LoweringError::new(
+                                LoweringErrorKind::InvalidSpecialMethod,
+                                "Getter cannot have parameters",
+                            ));
                         }
 
                         // Currently does not forbid nullable getters, could if desired
@@ -365,11 +401,15 @@ impl Attrs {
 
                     SpecialMethod::Setter(_) => {
                         if !matches!(method.output.success_type(), SuccessType::Unit) {
-                            errors.push(LoweringError::Other("Setters must return unit".into()));
+                            errors.push(LoweringError::new(
+                                LoweringErrorKind::InvalidSpecialMethod,
+                                "Setters must return unit",
+                            ));
                         }
                         if method.params.len() != 1 {
-                            errors.push(LoweringError::Other(
-                                "Setter must have exactly one parameter".into(),
+                            errors.push(LoweringError::new(
+                                LoweringErrorKind::InvalidSpecialMethod,
+                                "Setter must have exactly one parameter",
                             ))
                         }
 
@@ -377,24 +417,29 @@ impl Attrs {
                     }
                     SpecialMethod::Stringifier => {
                         if !method.params.is_empty() {
-                            errors
-                                .push(LoweringError::Other("Getter cannot have parameters".into()));
+                            errors.push(LoweringError::new(
+                                LoweringErrorKind::InvalidSpecialMethod,
+                                "Getter cannot have parameters",
+                            ));
                         }
                         if !matches!(method.output.success_type(), SuccessType::Writeable) {
-                            errors.push(LoweringError::Other(
-                                "Stringifier must return Writeable".into(),
+                            errors.push(LoweringError::new(
+                                LoweringErrorKind::InvalidSpecialMethod,
+                                "Stringifier must return Writeable",
                             ));
                         }
                     }
                     SpecialMethod::Comparison => {
                         if method.params.len() != 1 {
-                            errors.push(LoweringError::Other(
-                                "Comparator must have single parameter".into(),
+                            errors.push(LoweringError::new(
+                                LoweringErrorKind::InvalidSpecialMethod,
+                                "Comparator must have single parameter",
                             ));
                         }
                         if special_method_presence.comparator {
-                            errors.push(LoweringError::Other(
-                                "Cannot define two comparators on the same type".into(),
+                            errors.push(LoweringError::new(
+                                LoweringErrorKind::InvalidSpecialMethod,
+                                "Cannot define two comparators on the same type",
                             ));
                         }
                         special_method_presence.comparator = true;
@@ -406,60 +451,70 @@ impl Attrs {
                                 match (&selfty.ty, &param.ty) {
                                     (SelfType::Opaque(p), Type::Opaque(p2)) => {
                                         if p.tcx_id != p2.tcx_id {
-                                            errors.push(LoweringError::Other(
-                                                COMPARATOR_ERROR.into(),
+                                            errors.push(LoweringError::new(
+                                                LoweringErrorKind::InvalidSpecialMethod,
+                                                COMPARATOR_ERROR,
                                             ));
                                         }
 
                                         if p.owner.mutability != Mutability::Immutable
                                             || p2.owner.mutability != Mutability::Immutable
                                         {
-                                            errors.push(LoweringError::Other(
-                                                "comparators must accept immutable parameters"
-                                                    .into(),
+                                            errors.push(LoweringError::new(
+                                                LoweringErrorKind::InvalidSpecialMethod,
+                                                "comparators must accept immutable parameters",
                                             ));
                                         }
 
                                         if p2.optional.0 {
-                                            errors.push(LoweringError::Other(
-                                                "comparators must accept non-optional parameters"
-                                                    .into(),
+                                            errors.push(LoweringError::new(
+                                                LoweringErrorKind::InvalidSpecialMethod,
+                                                "comparators must accept non-optional parameters",
                                             ));
                                         }
                                     }
                                     (SelfType::Struct(p), Type::Struct(p2)) => {
                                         if p.tcx_id != p2.tcx_id {
-                                            errors.push(LoweringError::Other(
-                                                COMPARATOR_ERROR.into(),
+                                            errors.push(LoweringError::new(
+                                                LoweringErrorKind::InvalidSpecialMethod,
+                                                COMPARATOR_ERROR,
                                             ));
                                         }
                                     }
                                     (SelfType::Enum(p), Type::Enum(p2)) => {
                                         if p.tcx_id != p2.tcx_id {
-                                            errors.push(LoweringError::Other(
-                                                COMPARATOR_ERROR.into(),
+                                            errors.push(LoweringError::new(
+                                                LoweringErrorKind::InvalidSpecialMethod,
+                                                COMPARATOR_ERROR,
                                             ));
                                         }
                                     }
                                     _ => {
-                                        errors.push(LoweringError::Other(COMPARATOR_ERROR.into()));
+                                        errors.push(LoweringError::new(
+                                            LoweringErrorKind::InvalidSpecialMethod,
+                                            COMPARATOR_ERROR,
+                                        ));
                                     }
                                 }
                             }
                         } else {
-                            errors
-                                .push(LoweringError::Other("Comparator must be non-static".into()));
+                            errors.push(LoweringError::new(
+                                LoweringErrorKind::InvalidSpecialMethod,
+                                "Comparator must be non-static",
+                            ));
                         }
                     }
                     SpecialMethod::Iterator => {
                         if special_method_presence.iterator.is_some() {
-                            errors.push(LoweringError::Other(
-                                "Cannot mark type as iterator twice".into(),
+                            errors.push(LoweringError::new(
+                                LoweringErrorKind::InvalidSpecialMethod,
+                                "Cannot mark type as iterator twice",
                             ));
                         }
                         if !method.params.is_empty() {
-                            errors.push(LoweringError::Other(
-                                "Iterators cannot take parameters".into(),
+                            errors.push(LoweringError::new(
+                                LoweringErrorKind::InvalidSpecialMethod,
+                                "Iterators cannot take parameters",
                             ))
                         }
                         // In theory we could support struct and enum iterators. The benefit is slight:
@@ -472,18 +527,23 @@ impl Attrs {
                         // which may not be possible for enums, and would still be an odd-one-out field for structs.g s
                         if let Some(this) = &method.param_self {
                             if !matches!(this.ty, SelfType::Opaque(..)) {
-                                errors.push(LoweringError::Other(
-                                    "Iterators only allowed on opaques".into(),
+                                errors.push(LoweringError::new(
+                                    LoweringErrorKind::InvalidSpecialMethod,
+                                    "Iterators only allowed on opaques",
                                 ))
                             }
                         } else {
-                            errors.push(LoweringError::Other("Iterators must take self".into()))
+                            errors.push(LoweringError::new(
+                                LoweringErrorKind::InvalidSpecialMethod,
+                                "Iterators must take self",
+                            ))
                         }
 
                         if let ReturnType::Nullable(ref o) = method.output {
                             if let SuccessType::Unit = o {
-                                errors.push(LoweringError::Other(
-                                    "Iterator method must return something".into(),
+                                errors.push(LoweringError::new(
+                                    LoweringErrorKind::InvalidSpecialMethod,
+                                    "Iterator method must return something",
                                 ));
                             }
                             special_method_presence.iterator = Some(o.clone());
@@ -502,24 +562,30 @@ impl Attrs {
                             special_method_presence.iterator =
                                 Some(SuccessType::OutType(crate::hir::OutType::Opaque(o)));
                         } else {
-                            errors.push(LoweringError::Other(
-                                "Iterator method must return nullable value".into(),
+                            errors.push(LoweringError::new(
+                                LoweringErrorKind::InvalidSpecialMethod,
+                                "Iterator method must return nullable value",
                             ));
                         }
                     }
                     SpecialMethod::Iterable => {
                         if special_method_presence.iterable.is_some() {
-                            errors.push(LoweringError::Other(
-                                "Cannot mark type as iterable twice".into(),
+                            errors.push(LoweringError::new(
+                                LoweringErrorKind::InvalidSpecialMethod,
+                                "Cannot mark type as iterable twice",
                             ));
                         }
                         if !method.params.is_empty() {
-                            errors.push(LoweringError::Other(
-                                "Iterables cannot take parameters".into(),
+                            errors.push(LoweringError::new(
+                                LoweringErrorKind::InvalidSpecialMethod,
+                                "Iterables cannot take parameters",
                             ))
                         }
                         if method.param_self.is_none() {
-                            errors.push(LoweringError::Other("Iterables must take self".into()))
+                            errors.push(LoweringError::new(
+                                LoweringErrorKind::InvalidSpecialMethod,
+                                "Iterables must take self",
+                            ))
                         }
 
                         match method.output.success_type() {
@@ -527,30 +593,36 @@ impl Attrs {
                                 if let Some(TypeId::Opaque(id)) = ty.id() {
                                     special_method_presence.iterable = Some(id);
                                 } else {
-                                    errors.push(LoweringError::Other(
-                                        "Iterables must return a custom opaque type".into(),
+                                    errors.push(LoweringError::new(
+                                        LoweringErrorKind::InvalidSpecialMethod,
+                                        "Iterables must return a custom opaque type",
                                     ))
                                 }
                             }
-                            _ => errors.push(LoweringError::Other(
-                                "Iterables must return a custom type".into(),
+                            _ => errors.push(LoweringError::new(
+                                LoweringErrorKind::InvalidSpecialMethod,
+                                "Iterables must return a custom type",
                             )),
                         }
                     }
                     SpecialMethod::Indexer => {
                         if method.params.len() != 1 {
-                            errors.push(LoweringError::Other(
-                                "Indexer must have exactly one parameter".into(),
+                            errors.push(LoweringError::new(
+                                LoweringErrorKind::InvalidSpecialMethod,
+                                "Indexer must have exactly one parameter",
                             ));
                         }
 
                         if method.output.success_type().is_unit() {
-                            errors.push(LoweringError::Other("Indexer must return a value".into()));
+                            errors.push(LoweringError::new(
+                                LoweringErrorKind::InvalidSpecialMethod,
+                                "Indexer must return a value",
+                            ));
                         }
                     }
                 }
             } else {
-                errors.push(LoweringError::Other(format!("Special method (type {special:?}) not allowed on non-method context {context:?}")))
+                errors.push(LoweringError::new(LoweringErrorKind::InvalidSpecialMethod, format!("Special method (type {special:?}) not allowed on non-method context {context:?}")))
             }
         }
 
@@ -560,8 +632,9 @@ impl Attrs {
                 AttributeContext::Method(..) | AttributeContext::EnumVariant(..)
             )
         {
-            errors.push(LoweringError::Other(
-                "`namespace` can only be used on types".to_string(),
+            errors.push(LoweringError::new(
+                LoweringErrorKind::InvalidAttribute,
+                "`namespace` can only be used on types",
             ));
         }
     }
@@ -590,6 +663,7 @@ impl Attrs {
             namespace,
             // Was already inherited on the AST side
             abi_rename: Default::default(),
+            catch_panics: false,
             // Never inherited
             special_method: None,
         }
@@ -756,9 +830,10 @@ impl AttributeValidator for BasicAttributeValidator {
                 "iterables" => iterables,
                 "indexing" => indexing,
                 _ => {
-                    return Err(LoweringError::Other(format!(
-                        "Unknown supports = value found: {value}"
-                    )))
+                    return Err(LoweringError::new(
+                        LoweringErrorKind::InvalidAttribute,
+                        format!("Unknown supports = value found: {value}"),
+                    ))
                 }
             }
         } else if let Some(ref nv) = self.is_name_value {
@@ -922,4 +997,20 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_disabled_trait() {
+        uitest_lowering_attr! {
+            #[diplomat::bridge]
+            mod ffi {
+                #[diplomat::attr(tests, disable)]
+                trait Logger {
+                    fn log(&self, level: u8);
+                    #[diplomat::attr(tests, rename = "is_enabled")]
+                    fn enabled(&self) -> bool;
+                    fn mutates(&mut self);
+                }
+            }
+        }
+    }
 }
@@ -1,10 +1,11 @@
-//! Type definitions for structs, output structs, opaque structs, and enums.
+//! Type definitions for structs, output structs, opaque structs, enums, and consts.
 
 use super::lifetimes::LifetimeEnv;
 use super::{
-    Attrs, Everywhere, IdentBuf, Method, OutputOnly, SpecialMethodPresence, TyPosition, Type,
+    Attrs, Everywhere, IdentBuf, Method, OutputOnly, PrimitiveType, SpecialMethodPresence,
+    TraitMethod, TyPosition, Type,
 };
-use crate::ast::Docs;
+use crate::ast::{ConstValue, Docs};
 
 #[non_exhaustive]
 pub enum ReturnableStructDef<'tcx> {
@@ -32,6 +33,7 @@ pub struct StructDef<P: TyPosition = Everywhere> {
     pub name: IdentBuf,
     pub fields: Vec<StructField<P>>,
     pub methods: Vec<Method>,
+    pub consts: Vec<ConstDef>,
     pub attrs: Attrs,
     pub lifetimes: LifetimeEnv,
     pub special_method_presence: SpecialMethodPresence,
@@ -51,12 +53,16 @@ pub struct OpaqueDef {
     pub docs: Docs,
     pub name: IdentBuf,
     pub methods: Vec<Method>,
+    pub consts: Vec<ConstDef>,
     pub attrs: Attrs,
     pub lifetimes: LifetimeEnv,
     pub special_method_presence: SpecialMethodPresence,
 }
 
 /// The enum type.
+///
+/// Enums whose variants carry fields are passed by value as a `#[repr(C)]`
+/// tagged union, see [`EnumDef::is_data_carrying()`].
 #[derive(Debug)]
 #[non_exhaustive]
 pub struct EnumDef {
@@ -64,10 +70,50 @@ pub struct EnumDef {
     pub name: IdentBuf,
     pub variants: Vec<EnumVariant>,
     pub methods: Vec<Method>,
+    pub consts: Vec<ConstDef>,
     pub attrs: Attrs,
     pub special_method_presence: SpecialMethodPresence,
 }
 
+/// A trait declared in an FFI module, which foreign code implements and passes
+/// to Rust through a vtable of function pointers.
+///
+/// Traits are not types in their own right: they only show up as
+/// [`Type::Trait`] in method parameters.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct TraitDef {
+    pub docs: Docs,
+    pub name: IdentBuf,
+    pub methods: Vec<TraitMethod>,
+    pub attrs: Attrs,
+}
+
+/// A `pub const` exposed from an FFI module, either at the top level of the
+/// module (see [`TypeContext::consts()`]) or associated with a type.
+///
+/// [`TypeContext::consts()`]: super::TypeContext::consts
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct ConstDef {
+    pub docs: Docs,
+    pub name: IdentBuf,
+    pub ty: ConstType,
+    /// The literal value, already checked to fit in [`Self::ty`].
+    pub value: ConstValue,
+    pub attrs: Attrs,
+}
+
+/// The types a [`ConstDef`] may have.
+#[derive(Copy, Clone, Debug)]
+#[non_exhaustive]
+pub enum ConstType {
+    /// Any primitive except 128-bit integers.
+    Primitive(PrimitiveType),
+    /// A `&'static str`.
+    Str,
+}
+
 /// A field on a [`OutStruct`]s.
 pub type OutStructField = StructField<OutputOnly>;
 
@@ -88,14 +134,18 @@ pub struct EnumVariant {
     pub name: IdentBuf,
     pub discriminant: isize,
     pub attrs: Attrs,
+    /// The payload carried by this variant, empty for fieldless variants.
+    pub fields: Vec<StructField>,
 }
 
 impl<P: TyPosition> StructDef<P> {
+    #[allow(clippy::too_many_arguments)]
     pub(super) fn new(
         docs: Docs,
         name: IdentBuf,
         fields: Vec<StructField<P>>,
         methods: Vec<Method>,
+        consts: Vec<ConstDef>,
         attrs: Attrs,
         lifetimes: LifetimeEnv,
         special_method_presence: SpecialMethodPresence,
@@ -105,6 +155,7 @@ impl<P: TyPosition> StructDef<P> {
             name,
             fields,
             methods,
+            consts,
             attrs,
             lifetimes,
             special_method_presence,
@@ -117,6 +168,7 @@ impl OpaqueDef {
         docs: Docs,
         name: IdentBuf,
         methods: Vec<Method>,
+        consts: Vec<ConstDef>,
         attrs: Attrs,
         lifetimes: LifetimeEnv,
         special_method_presence: SpecialMethodPresence,
@@ -125,6 +177,7 @@ impl OpaqueDef {
             docs,
             name,
             methods,
+            consts,
             attrs,
             lifetimes,
             special_method_presence,
@@ -138,6 +191,7 @@ impl EnumDef {
         name: IdentBuf,
         variants: Vec<EnumVariant>,
         methods: Vec<Method>,
+        consts: Vec<ConstDef>,
         attrs: Attrs,
         special_method_presence: SpecialMethodPresence,
     ) -> Self {
@@ -146,10 +200,27 @@ impl EnumDef {
             name,
             variants,
             methods,
+            consts,
             attrs,
             special_method_presence,
         }
     }
+
+    /// Whether any variant of this enum carries fields.
+    pub fn is_data_carrying(&self) -> bool {
+        self.variants.iter().any(|v| !v.fields.is_empty())
+    }
+}
+
+impl TraitDef {
+    pub(super) fn new(docs: Docs, name: IdentBuf, methods: Vec<TraitMethod>, attrs: Attrs) -> Self {
+        Self {
+            docs,
+            name,
+            methods,
+            attrs,
+        }
+    }
 }
 
 impl<'a> From<&'a StructDef> for TypeDef<'a> {
@@ -203,6 +274,15 @@ impl<'tcx> TypeDef<'tcx> {
         }
     }
 
+    pub fn consts(&self) -> &'tcx [ConstDef] {
+        match *self {
+            Self::Struct(ty) => &ty.consts,
+            Self::OutStruct(ty) => &ty.consts,
+            Self::Opaque(ty) => &ty.consts,
+            Self::Enum(ty) => &ty.consts,
+        }
+    }
+
     pub fn attrs(&self) -> &'tcx Attrs {
         match *self {
             Self::Struct(ty) => &ty.attrs,
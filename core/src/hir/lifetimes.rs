@@ -14,7 +14,7 @@ use std::borrow::{Borrow, Cow};
 pub(crate) const INLINE_NUM_LIFETIMES: usize = 4;
 
 /// The lifetimes and bounds found on a method or type definition
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct LifetimeEnv {
     /// List of named lifetimes in scope of the method, and their bounds
     nodes: SmallVec<[BoundedLifetime; INLINE_NUM_LIFETIMES]>,
@@ -1,7 +1,8 @@
 use super::{
-    AttributeContext, AttributeValidator, Attrs, Borrow, BoundedLifetime, EnumDef, EnumPath, EnumVariant, FunctionType, IdentBuf, IntType, Lifetime, LifetimeEnv, LifetimeLowerer, LookupId, MaybeOwn, Method, NonOptional, OpaqueDef, OpaquePath, Optional, OutStructDef, OutStructField, OutStructPath, OutType, Param, ParamLifetimeLowerer, ParamSelf, PrimitiveType, ReturnLifetimeLowerer, ReturnType, ReturnableStructPath, SelfParamLifetimeLowerer, SelfType, Slice, SpecialMethod, SpecialMethodPresence, StructDef, StructField, StructPath, SuccessType, Type, TypeDef, TypeId
+    AttributeContext, AttributeValidator, Attrs, Borrow, BoundedLifetime, ConstDef, ConstType, EnumDef, EnumPath, EnumVariant, FunctionType, IdentBuf, IntSizeType, IntType, Lifetime, LifetimeEnv, LifetimeLowerer, LookupId, MaybeOwn, Method, NonOptional, OpaqueDef, OpaquePath, Optional, OutStructDef, OutStructField, OutStructPath, OutType, Param, ParamLifetimeLowerer, ParamSelf, PrimitiveType, ReturnLifetimeLowerer, ReturnType, ReturnableStructPath, SelfParamLifetimeLowerer, SelfType, Slice, SpecialMethod, SpecialMethodPresence, StructDef, StructField, StructPath, SuccessType, TraitDef, TraitMethod, TraitPath, Type, TypeDef, TypeId
 };
 use crate::ast::attrs::AttrInheritContext;
+use crate::ast::SourceSpan;
 use crate::{ast, Env};
 use core::fmt;
 use strck_ident::IntoCk;
@@ -9,30 +10,125 @@ use strck_ident::IntoCk;
 /// An error from lowering the AST to the HIR.
 #[derive(Debug)]
 #[non_exhaustive]
-pub enum LoweringError {
-    /// The purpose of having this is that translating to the HIR has enormous
-    /// potential for really detailed error handling and giving suggestions.
-    ///
-    /// Unfortunately, working out what the error enum should look like to enable
-    /// this is really hard. The plan is that once the lowering code is completely
-    /// written, we ctrl+F for `"LoweringError::Other"` in the lowering code, and turn every
-    /// instance into an specialized enum variant, generalizing where possible
-    /// without losing any information.
-    Other(String),
+pub struct LoweringError {
+    /// What went wrong, for tools that want to handle errors programmatically.
+    pub kind: LoweringErrorKind,
+    /// A human readable description of the error.
+    pub message: String,
+}
+
+impl LoweringError {
+    pub fn new(kind: LoweringErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+        }
+    }
+
+    /// The stable error code for this error, like `D0005`.
+    pub fn code(&self) -> &'static str {
+        self.kind.code()
+    }
 }
 
 impl fmt::Display for LoweringError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {
-            Self::Other(ref s) => s.fmt(f),
+        self.message.fmt(f)
+    }
+}
+
+/// The kind of a [`LoweringError`].
+///
+/// Each kind has a stable error code, so that codes can be documented and
+/// matched on by tooling. New kinds may be added, but existing codes will not
+/// change meaning.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LoweringErrorKind {
+    /// An identifier is not a valid Rust identifier.
+    InvalidIdent,
+    /// A `#[diplomat::attr]` is malformed, unknown, or used somewhere it can't be.
+    InvalidAttribute,
+    /// An attribute requests a feature that the current backend doesn't support.
+    UnsupportedByBackend,
+    /// A constructor, accessor, iterator, or other special method has the wrong signature.
+    InvalidSpecialMethod,
+    /// A type appears somewhere it isn't allowed, such as an opaque passed by value.
+    InvalidType,
+    /// A method's `self` parameter can't be passed across the FFI boundary.
+    InvalidSelfParam,
+    /// A borrow is missing a lifetime or a lifetime bound.
+    Lifetime,
+    /// A trait or trait method can't be implemented across the FFI boundary.
+    InvalidTrait,
+    /// A const has an unsupported type, or a value that doesn't fit its type.
+    InvalidConst,
+    /// A path doesn't name a type or trait of the bridge crate or its dependencies.
+    UnresolvedType,
+}
+
+impl LoweringErrorKind {
+    /// The stable error code for this kind of error.
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::InvalidIdent => "D0001",
+            Self::InvalidAttribute => "D0002",
+            Self::UnsupportedByBackend => "D0003",
+            Self::InvalidSpecialMethod => "D0004",
+            Self::InvalidType => "D0005",
+            Self::InvalidSelfParam => "D0006",
+            Self::Lifetime => "D0007",
+            Self::InvalidTrait => "D0008",
+            Self::InvalidConst => "D0009",
+            Self::UnresolvedType => "D0010",
+        }
+    }
+
+    /// A stable `snake_case` name for this kind of error, for machine-readable output.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::InvalidIdent => "invalid_ident",
+            Self::InvalidAttribute => "invalid_attribute",
+            Self::UnsupportedByBackend => "unsupported_by_backend",
+            Self::InvalidSpecialMethod => "invalid_special_method",
+            Self::InvalidType => "invalid_type",
+            Self::InvalidSelfParam => "invalid_self_param",
+            Self::Lifetime => "lifetime",
+            Self::InvalidTrait => "invalid_trait",
+            Self::InvalidConst => "invalid_const",
+            Self::UnresolvedType => "unresolved_type",
         }
     }
 }
 
+/// Where a [`LoweringError`] occurred: the item and sub-item being lowered,
+/// and where in the source it was declared.
 #[derive(Default, Clone)]
 pub struct ErrorContext {
     item: String,
     subitem: Option<String>,
+    span: Option<SourceSpan>,
+}
+
+impl ErrorContext {
+    /// The type, trait, or module the error occurred in.
+    pub fn item(&self) -> &str {
+        &self.item
+    }
+
+    /// The method, variant, or const within the item the error occurred in, if any.
+    pub fn subitem(&self) -> Option<&str> {
+        self.subitem.as_deref()
+    }
+
+    /// The location of the sub-item, or of the item if there is no sub-item. Errors
+    /// about a type name point at the name instead.
+    ///
+    /// This is `None` when the AST was parsed without location information,
+    /// or for errors from validation passes that don't correspond to a single item.
+    pub fn span(&self) -> Option<SourceSpan> {
+        self.span
+    }
 }
 
 impl fmt::Display for ErrorContext {
@@ -60,8 +156,12 @@ pub struct ErrorStore<'tree> {
     errors: Vec<ErrorAndContext>,
     /// The current context (types, modules)
     item: &'tree str,
+    /// Where the current item was declared
+    item_span: Option<SourceSpan>,
     /// The current sub-item context (methods, etc)
     subitem: Option<&'tree str>,
+    /// Where the current sub-item was declared
+    subitem_span: Option<SourceSpan>,
 }
 
 pub type ErrorAndContext = (ErrorContext, LoweringError);
@@ -72,10 +172,26 @@ impl<'tree> ErrorStore<'tree> {
         let context = ErrorContext {
             item: self.item.into(),
             subitem: self.subitem.map(|s| s.into()),
+            span: if self.subitem.is_some() {
+                self.subitem_span.or(self.item_span)
+            } else {
+                self.item_span
+            },
         };
         self.errors.push((context, error));
     }
 
+    /// Push an error about something at `span` within the current context, such as
+    /// a type name, falling back to the span of the context if it isn't known.
+    pub(super) fn push_at(&mut self, error: LoweringError, span: Option<SourceSpan>) {
+        self.push(error);
+        if let Some(span) = span {
+            if let Some((context, _)) = self.errors.last_mut() {
+                context.span = Some(span);
+            }
+        }
+    }
+
     pub(super) fn take_errors(&mut self) -> Vec<ErrorAndContext> {
         core::mem::take(&mut self.errors)
     }
@@ -84,12 +200,109 @@ impl<'tree> ErrorStore<'tree> {
         self.errors.is_empty()
     }
 
-    pub(super) fn set_item(&mut self, item: &'tree str) {
+    pub(super) fn set_item(&mut self, item: &'tree str, span: Option<SourceSpan>) {
         self.item = item;
+        self.item_span = span;
         self.subitem = None;
+        self.subitem_span = None;
     }
-    pub(super) fn set_subitem(&mut self, subitem: &'tree str) {
+    pub(super) fn set_subitem(&mut self, subitem: &'tree str, span: Option<SourceSpan>) {
         self.subitem = Some(subitem);
+        self.subitem_span = span;
+    }
+}
+
+/// Check that every type and trait named in the bridge crate resolves, so that
+/// lowering can assume it does.
+pub(super) fn check_paths<'ast>(env: &'ast Env, errors: &mut ErrorStore<'ast>) {
+    for (in_path, mod_env) in env.iter_modules() {
+        for sym in mod_env.items() {
+            match sym {
+                ast::ModSymbol::CustomType(custom_type) => {
+                    let span = match custom_type {
+                        ast::CustomType::Struct(strct) => strct.span,
+                        ast::CustomType::Opaque(opaque) => opaque.span,
+                        ast::CustomType::Enum(enm) => enm.span,
+                    };
+                    errors.set_item(custom_type.name().as_str(), span);
+                    match custom_type {
+                        ast::CustomType::Struct(strct) => {
+                            for (_, ty, _) in &strct.fields {
+                                check_type_paths(ty, in_path, env, errors);
+                            }
+                        }
+                        ast::CustomType::Enum(enm) => {
+                            for variant in &enm.variants {
+                                for (_, ty, _) in &variant.fields {
+                                    check_type_paths(ty, in_path, env, errors);
+                                }
+                            }
+                        }
+                        ast::CustomType::Opaque(_) => {}
+                    }
+                    for method in custom_type.methods() {
+                        errors.set_subitem(method.name.as_str(), method.span);
+                        for param in &method.params {
+                            check_type_paths(&param.ty, in_path, env, errors);
+                        }
+                        if let Some(ref return_type) = method.return_type {
+                            check_type_paths(return_type, in_path, env, errors);
+                        }
+                    }
+                }
+                ast::ModSymbol::Trait(trt) => {
+                    errors.set_item(trt.name.as_str(), trt.span);
+                    for method in &trt.methods {
+                        errors.set_subitem(method.name.as_str(), method.span);
+                        for param in &method.params {
+                            check_type_paths(&param.ty, in_path, env, errors);
+                        }
+                        if let Some(ref return_type) = method.return_type {
+                            check_type_paths(return_type, in_path, env, errors);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+fn check_type_paths(ty: &ast::TypeName, in_path: &ast::Path, env: &Env, errors: &mut ErrorStore) {
+    let resolved = match ty {
+        ast::TypeName::Named(path) | ast::TypeName::SelfType(path) => path
+            .try_resolve_with_path(in_path, env)
+            .map(|_| ())
+            .map_err(|e| (e, path.span)),
+        ast::TypeName::DynTrait(path) => path
+            .try_resolve_trait(in_path, env)
+            .map(|_| ())
+            .map_err(|e| (e, path.span)),
+        ast::TypeName::Reference(_, _, ty)
+        | ast::TypeName::Box(ty)
+        | ast::TypeName::Option(ty) => {
+            check_type_paths(ty, in_path, env, errors);
+            Ok(())
+        }
+        ast::TypeName::Result(ok, err, _) => {
+            check_type_paths(ok, in_path, env, errors);
+            check_type_paths(err, in_path, env, errors);
+            Ok(())
+        }
+        ast::TypeName::Function(params, output) => {
+            for (param, _) in params {
+                check_type_paths(param, in_path, env, errors);
+            }
+            check_type_paths(output, in_path, env, errors);
+            Ok(())
+        }
+        _ => Ok(()),
+    };
+    if let Err((message, span)) = resolved {
+        errors.push_at(
+            LoweringError::new(LoweringErrorKind::UnresolvedType, message),
+            span,
+        );
     }
 }
 
@@ -124,9 +337,12 @@ impl<'ast> LoweringContext<'ast> {
         match ident.as_str().ck() {
             Ok(name) => Ok(name.to_owned()),
             Err(e) => {
-                self.errors.push(LoweringError::Other(format!(
-                    "Ident `{ident}` from {context} could not be turned into a Rust ident: {e}"
-                )));
+                self.errors.push(LoweringError::new(
+                    LoweringErrorKind::InvalidIdent,
+                    format!(
+                        "Ident `{ident}` from {context} could not be turned into a Rust ident: {e}"
+                    ),
+                ));
                 Err(())
             }
         }
@@ -177,9 +393,28 @@ impl<'ast> LoweringContext<'ast> {
         self.lower_all(ast_defs, Self::lower_opaque)
     }
 
+    pub(super) fn lower_all_traits(
+        &mut self,
+        ast_defs: impl ExactSizeIterator<Item = (&'ast ast::Trait, &'ast ast::Path, Attrs, Attrs)>,
+    ) -> Result<Vec<TraitDef>, ()> {
+        let mut hir_traits = Ok(Vec::with_capacity(ast_defs.len()));
+
+        for (ast_trait, in_path, ty_parent_attrs, method_parent_attrs) in ast_defs {
+            let hir_trait =
+                self.lower_trait(ast_trait, in_path, &ty_parent_attrs, &method_parent_attrs);
+
+            match (hir_trait, &mut hir_traits) {
+                (Ok(hir_trait), Ok(hir_traits)) => hir_traits.push(hir_trait),
+                _ => hir_traits = Err(()),
+            }
+        }
+
+        hir_traits
+    }
+
     fn lower_enum(&mut self, item: ItemAndInfo<'ast, ast::Enum>) -> Result<EnumDef, ()> {
         let ast_enum = item.item;
-        self.errors.set_item(ast_enum.name.as_str());
+        self.errors.set_item(ast_enum.name.as_str(), ast_enum.span);
         let name = self.lower_ident(&ast_enum.name, "enum name");
         let attrs = self.attr_validator.attr_from_ast(
             &ast_enum.attrs,
@@ -189,18 +424,22 @@ impl<'ast> LoweringContext<'ast> {
 
         let mut variants = Ok(Vec::with_capacity(ast_enum.variants.len()));
         let variant_parent_attrs = attrs.for_inheritance(AttrInheritContext::Variant);
-        for (ident, discriminant, docs, attrs) in ast_enum.variants.iter() {
-            let name = self.lower_ident(ident, "enum variant");
-            let attrs =
-                self.attr_validator
-                    .attr_from_ast(attrs, &variant_parent_attrs, &mut self.errors);
-            match (name, &mut variants) {
-                (Ok(name), Ok(variants)) => {
+        for ast_variant in ast_enum.variants.iter() {
+            let name = self.lower_ident(&ast_variant.name, "enum variant");
+            let attrs = self.attr_validator.attr_from_ast(
+                &ast_variant.attrs,
+                &variant_parent_attrs,
+                &mut self.errors,
+            );
+            let fields = self.lower_enum_variant_fields(&ast_variant.fields, item.in_path);
+            match (name, fields, &mut variants) {
+                (Ok(name), Ok(fields), Ok(variants)) => {
                     let variant = EnumVariant {
-                        docs: docs.clone(),
+                        docs: ast_variant.docs.clone(),
                         name,
-                        discriminant: *discriminant,
+                        discriminant: ast_variant.discriminant,
                         attrs,
+                        fields,
                     };
                     self.attr_validator.validate(
                         &variant.attrs,
@@ -225,12 +464,21 @@ impl<'ast> LoweringContext<'ast> {
                 &mut special_method_presence,
             )?
         };
+        let consts = if attrs.disable {
+            Vec::new()
+        } else {
+            self.lower_all_consts(
+                ast_enum.consts.iter().map(|c| (c, item.method_parent_attrs.clone())),
+                true,
+            )?
+        };
 
         let def = EnumDef::new(
             ast_enum.docs.clone(),
             name?,
             variants?,
             methods,
+            consts,
             attrs,
             special_method_presence,
         );
@@ -244,12 +492,157 @@ impl<'ast> LoweringContext<'ast> {
         Ok(def)
     }
 
+    /// Lowers the payload of a data-carrying enum variant.
+    ///
+    /// Enums don't have lifetime parameters, so payloads may only borrow `'static` data.
+    fn lower_enum_variant_fields(
+        &mut self,
+        ast_fields: &'ast [(ast::Ident, ast::TypeName, ast::Docs)],
+        in_path: &ast::Path,
+    ) -> Result<Vec<StructField>, ()> {
+        let mut fields = Ok(Vec::with_capacity(ast_fields.len()));
+        let no_lifetimes = ast::LifetimeEnv::new();
+
+        for (name, ty, docs) in ast_fields.iter() {
+            let name = self.lower_ident(name, "enum variant field name");
+            let ty = if ty.any_lifetime(|lt, _| *lt != ast::Lifetime::Static) {
+                self.errors.push(LoweringError::new(LoweringErrorKind::Lifetime, format!(
+                    "enum variant field has type {ty}, but enum payloads can only borrow 'static data"
+                )));
+                Err(())
+            } else {
+                self.lower_type(ty, &mut &no_lifetimes, in_path)
+            };
+
+            match (name, ty, &mut fields) {
+                (Ok(name), Ok(ty), Ok(fields)) => fields.push(StructField {
+                    docs: docs.clone(),
+                    name,
+                    ty,
+                }),
+                _ => fields = Err(()),
+            }
+        }
+
+        fields
+    }
+
+    fn lower_trait(
+        &mut self,
+        ast_trait: &'ast ast::Trait,
+        in_path: &ast::Path,
+        ty_parent_attrs: &Attrs,
+        method_parent_attrs: &Attrs,
+    ) -> Result<TraitDef, ()> {
+        self.errors.set_item(ast_trait.name.as_str(), ast_trait.span);
+        let name = self.lower_ident(&ast_trait.name, "trait name");
+        let attrs =
+            self.attr_validator
+                .attr_from_ast(&ast_trait.attrs, ty_parent_attrs, &mut self.errors);
+
+        // The methods already inherited the attributes of the trait on the AST side, like
+        // those of an impl block
+        let mut methods = Ok(Vec::with_capacity(ast_trait.methods.len()));
+        if !attrs.disable {
+            for method in ast_trait.methods.iter() {
+                let method = self.lower_trait_method(method, in_path, method_parent_attrs);
+                match (method, &mut methods) {
+                    (Ok(method), Ok(methods)) => methods.push(method),
+                    _ => methods = Err(()),
+                }
+            }
+        }
+
+        Ok(TraitDef::new(
+            ast_trait.docs.clone(),
+            name?,
+            methods?,
+            attrs,
+        ))
+    }
+
+    /// Lowers a method declared in a trait.
+    ///
+    /// The receiver is the context pointer of the vtable, so it has to be `&self`. The
+    /// return value is handed back to Rust, and so is lowered like a parameter.
+    fn lower_trait_method(
+        &mut self,
+        method: &'ast ast::TraitMethod,
+        in_path: &ast::Path,
+        method_parent_attrs: &Attrs,
+    ) -> Result<TraitMethod, ()> {
+        self.errors.set_subitem(method.name.as_str(), method.span);
+        let name = self.lower_ident(&method.name, "trait method name");
+
+        if !matches!(
+            method.self_param,
+            Some(ast::SelfParam {
+                reference: Some((_, ast::Mutability::Immutable)),
+                ..
+            })
+        ) {
+            self.errors.push(LoweringError::new(
+                LoweringErrorKind::InvalidTrait,
+                format!("trait method `{}` must take `&self`", method.name),
+            ));
+            return Err(());
+        }
+
+        let self_param_ltl = SelfParamLifetimeLowerer::new(&method.lifetime_env, self)?;
+        let param_ltl = SelfParamLifetimeLowerer::no_self_ref(self_param_ltl);
+        let (params, mut return_ltl) =
+            self.lower_many_params(&method.params, param_ltl, in_path)?;
+
+        // The foreign implementation receives the parameters, so they must be
+        // convertible in the opposite direction from method parameters.
+        if params
+            .iter()
+            .any(|p| matches!(p.ty, Type::Trait(_) | Type::Func(_)))
+        {
+            self.errors.push(LoweringError::new(
+                LoweringErrorKind::InvalidTrait,
+                format!(
+                    "trait method `{}` cannot take traits or functions as parameters",
+                    method.name
+                ),
+            ));
+            return Err(());
+        }
+
+        let output = match method.return_type.as_ref() {
+            None | Some(ast::TypeName::Unit) => Ok(None),
+            Some(ty) if ty.any_lifetime(|_, _| true) => {
+                self.errors.push(LoweringError::new(
+                    LoweringErrorKind::InvalidTrait,
+                    format!(
+                        "trait method returns {ty}, but trait methods cannot return borrowed data"
+                    ),
+                ));
+                Err(())
+            }
+            Some(ty) => self.lower_type(ty, &mut return_ltl, in_path).map(Some),
+        };
+
+        let attrs =
+            self.attr_validator
+                .attr_from_ast(&method.attrs, method_parent_attrs, &mut self.errors);
+
+        Ok(TraitMethod {
+            docs: method.docs.clone(),
+            name: name?,
+            lifetime_env: return_ltl.finish(),
+            params,
+            output: output?,
+            attrs,
+        })
+    }
+
     fn lower_opaque(
         &mut self,
         item: ItemAndInfo<'ast, ast::OpaqueStruct>,
     ) -> Result<OpaqueDef, ()> {
         let ast_opaque = item.item;
-        self.errors.set_item(ast_opaque.name.as_str());
+        self.errors.set_item(ast_opaque.name.as_str(), ast_opaque.span);
         let name = self.lower_ident(&ast_opaque.name, "opaque name");
 
         let attrs = self.attr_validator.attr_from_ast(
@@ -269,12 +662,21 @@ impl<'ast> LoweringContext<'ast> {
                 &mut special_method_presence,
             )?
         };
+        let consts = if attrs.disable {
+            Vec::new()
+        } else {
+            self.lower_all_consts(
+                ast_opaque.consts.iter().map(|c| (c, item.method_parent_attrs.clone())),
+                true,
+            )?
+        };
         let lifetimes = self.lower_type_lifetime_env(&ast_opaque.lifetimes);
 
         let def = OpaqueDef::new(
             ast_opaque.docs.clone(),
             name?,
             methods,
+            consts,
             attrs,
             lifetimes?,
             special_method_presence,
@@ -289,14 +691,17 @@ impl<'ast> LoweringContext<'ast> {
 
     fn lower_struct(&mut self, item: ItemAndInfo<'ast, ast::Struct>) -> Result<StructDef, ()> {
         let ast_struct = item.item;
-        self.errors.set_item(ast_struct.name.as_str());
+        self.errors.set_item(ast_struct.name.as_str(), ast_struct.span);
         let name = self.lower_ident(&ast_struct.name, "struct name");
 
         let fields = if ast_struct.fields.is_empty() {
-            self.errors.push(LoweringError::Other(format!(
-                "struct `{}` is a ZST because it has no fields",
-                ast_struct.name
-            )));
+            self.errors.push(LoweringError::new(
+                LoweringErrorKind::InvalidType,
+                format!(
+                    "struct `{}` is a ZST because it has no fields",
+                    ast_struct.name
+                ),
+            ));
             Err(())
         } else {
             let mut fields = Ok(Vec::with_capacity(ast_struct.fields.len()));
@@ -336,11 +741,20 @@ impl<'ast> LoweringContext<'ast> {
                 &mut special_method_presence,
             )?
         };
+        let consts = if attrs.disable {
+            Vec::new()
+        } else {
+            self.lower_all_consts(
+                ast_struct.consts.iter().map(|c| (c, item.method_parent_attrs.clone())),
+                true,
+            )?
+        };
         let def = StructDef::new(
             ast_struct.docs.clone(),
             name?,
             fields?,
             methods,
+            consts,
             attrs,
             lifetimes?,
             special_method_presence,
@@ -359,14 +773,17 @@ impl<'ast> LoweringContext<'ast> {
         item: ItemAndInfo<'ast, ast::Struct>,
     ) -> Result<OutStructDef, ()> {
         let ast_out_struct = item.item;
-        self.errors.set_item(ast_out_struct.name.as_str());
+        self.errors.set_item(ast_out_struct.name.as_str(), ast_out_struct.span);
         let name = self.lower_ident(&ast_out_struct.name, "out-struct name");
 
         let fields = if ast_out_struct.fields.is_empty() {
-            self.errors.push(LoweringError::Other(format!(
-                "struct `{}` is a ZST because it has no fields",
-                ast_out_struct.name
-            )));
+            self.errors.push(LoweringError::new(
+                LoweringErrorKind::InvalidType,
+                format!(
+                    "struct `{}` is a ZST because it has no fields",
+                    ast_out_struct.name
+                ),
+            ));
             Err(())
         } else {
             let mut fields = Ok(Vec::with_capacity(ast_out_struct.fields.len()));
@@ -405,6 +822,14 @@ impl<'ast> LoweringContext<'ast> {
                 &mut special_method_presence,
             )?
         };
+        let consts = if attrs.disable {
+            Vec::new()
+        } else {
+            self.lower_all_consts(
+                ast_out_struct.consts.iter().map(|c| (c, item.method_parent_attrs.clone())),
+                true,
+            )?
+        };
 
         let lifetimes = self.lower_type_lifetime_env(&ast_out_struct.lifetimes);
         let def = OutStructDef::new(
@@ -412,6 +837,7 @@ impl<'ast> LoweringContext<'ast> {
             name?,
             fields?,
             methods,
+            consts,
             attrs,
             lifetimes?,
             special_method_presence,
@@ -436,7 +862,7 @@ impl<'ast> LoweringContext<'ast> {
         self_id: TypeId,
         special_method_presence: &mut SpecialMethodPresence,
     ) -> Result<Method, ()> {
-        self.errors.set_subitem(method.name.as_str());
+        self.errors.set_subitem(method.name.as_str(), method.span);
         let name = self.lower_ident(&method.name, "method name");
 
         let (ast_params, takes_writeable) = match method.params.split_last() {
@@ -475,6 +901,7 @@ impl<'ast> LoweringContext<'ast> {
             params,
             output,
             attrs,
+            span: method.span,
         };
 
         self.attr_validator.validate(
@@ -489,12 +916,13 @@ impl<'ast> LoweringContext<'ast> {
         );
         if let Some(ast::TypeName::Ordering) = method.return_type {
             if !is_comparison {
-                self.errors.push(LoweringError::Other("Found comparison return type in method not marked as #[diplomat::attr(.., comparison)]".into()));
+                self.errors.push(LoweringError::new(LoweringErrorKind::InvalidSpecialMethod, "Found comparison return type in method not marked as #[diplomat::attr(.., comparison)]"));
                 return Err(());
             }
         } else if is_comparison {
-            self.errors.push(LoweringError::Other(
-                "Found comparison method that does not return cmp::Ordering".into(),
+            self.errors.push(LoweringError::new(
+                LoweringErrorKind::InvalidSpecialMethod,
+                "Found comparison method that does not return cmp::Ordering",
             ));
             return Err(());
         }
@@ -534,6 +962,95 @@ impl<'ast> LoweringContext<'ast> {
         methods
     }
 
+    /// Lowers many [`ast::Const`]s, either the consts of a module or the
+    /// associated consts of a type.
+    ///
+    /// If there are any errors, they're pushed to `errors` and `Err` is returned.
+    pub(super) fn lower_all_consts(
+        &mut self,
+        ast_consts: impl IntoIterator<Item = (&'ast ast::Const, Attrs)>,
+        associated: bool,
+    ) -> Result<Vec<ConstDef>, ()> {
+        let mut consts = Ok(Vec::new());
+
+        for (ast_const, parent_attrs) in ast_consts {
+            if associated {
+                self.errors.set_subitem(ast_const.name.as_str(), ast_const.span);
+            } else {
+                self.errors.set_item(ast_const.name.as_str(), ast_const.span);
+            }
+            let attrs =
+                self.attr_validator
+                    .attr_from_ast(&ast_const.attrs, &parent_attrs, &mut self.errors);
+            if attrs.disable {
+                continue;
+            }
+            let cnst = self.lower_const(ast_const, attrs);
+            match (cnst, &mut consts) {
+                (Ok(cnst), Ok(consts)) => {
+                    self.attr_validator.validate(
+                        &cnst.attrs,
+                        AttributeContext::Const(&cnst),
+                        &mut self.errors,
+                    );
+                    consts.push(cnst);
+                }
+                _ => consts = Err(()),
+            }
+        }
+
+        consts
+    }
+
+    /// Lowers an [`ast::Const`], checking that its literal fits in its type.
+    fn lower_const(&mut self, ast_const: &'ast ast::Const, attrs: Attrs) -> Result<ConstDef, ()> {
+        let name = self.lower_ident(&ast_const.name, "const name");
+
+        let ty = match ast_const.ty {
+            ast::TypeName::Primitive(ast::PrimitiveType::i128 | ast::PrimitiveType::u128) => {
+                self.errors.push(LoweringError::new(
+                    LoweringErrorKind::InvalidConst,
+                    format!(
+                        "const `{}` has a 128-bit integer type, which is not supported for consts",
+                        ast_const.name
+                    ),
+                ));
+                return Err(());
+            }
+            ast::TypeName::Primitive(prim) => ConstType::Primitive(PrimitiveType::from_ast(prim)),
+            ast::TypeName::StrReference(_, ast::StringEncoding::Utf8) => ConstType::Str,
+            ref ty => {
+                self.errors.push(LoweringError::new(
+                    LoweringErrorKind::InvalidConst,
+                    format!(
+                        "const `{}` has type {ty}, but consts can only be primitives or `&str`",
+                        ast_const.name
+                    ),
+                ));
+                return Err(());
+            }
+        };
+
+        if !const_value_fits(ty, &ast_const.value) {
+            self.errors.push(LoweringError::new(
+                LoweringErrorKind::InvalidConst,
+                format!(
+                    "const `{}` has a value that is not a valid {}",
+                    ast_const.name, ast_const.ty
+                ),
+            ));
+            return Err(());
+        }
+
+        Ok(ConstDef {
+            docs: ast_const.docs.clone(),
+            name: name?,
+            ty,
+            value: ast_const.value.clone(),
+            attrs,
+        })
+    }
+
     /// Lowers an [`ast::TypeName`]s into a [`hir::Type`].
     ///
     /// If there are any errors, they're pushed to `errors` and `None` is returned.
@@ -546,7 +1063,7 @@ impl<'ast> LoweringContext<'ast> {
         match ty {
             ast::TypeName::Primitive(prim) => Ok(Type::Primitive(PrimitiveType::from_ast(*prim))),
             ast::TypeName::Ordering => {
-                self.errors.push(LoweringError::Other("Found cmp::Ordering in parameter or struct field, it is only allowed in return types".to_string()));
+                self.errors.push(LoweringError::new(LoweringErrorKind::InvalidType, "Found cmp::Ordering in parameter or struct field, it is only allowed in return types"));
                 Err(())
             }
             ast::TypeName::Named(path) | ast::TypeName::SelfType(path) => {
@@ -561,16 +1078,17 @@ impl<'ast> LoweringContext<'ast> {
 
                             Ok(Type::Struct(StructPath::new(lifetimes, tcx_id)))
                         } else if self.lookup_id.resolve_out_struct(strct).is_some() {
-                            self.errors.push(LoweringError::Other(format!("found struct in input that is marked with #[diplomat::out]: {ty} in {path}")));
+                            self.errors.push(LoweringError::new(LoweringErrorKind::InvalidType, format!("found struct in input that is marked with #[diplomat::out]: {ty} in {path}")));
                             Err(())
                         } else {
                             unreachable!("struct `{}` wasn't found in the set of structs or out-structs, this is a bug.", strct.name);
                         }
                     }
                     ast::CustomType::Opaque(_) => {
-                        self.errors.push(LoweringError::Other(format!(
-                            "Opaque passed by value: {path}"
-                        )));
+                        self.errors.push(LoweringError::new(
+                            LoweringErrorKind::InvalidType,
+                            format!("Opaque passed by value: {path}"),
+                        ));
                         Err(())
                     }
                     ast::CustomType::Enum(enm) => {
@@ -582,7 +1100,18 @@ impl<'ast> LoweringContext<'ast> {
                     }
                 }
             }
+            ast::TypeName::Reference(_, ast::Mutability::Immutable, ref_ty)
+                if matches!(ref_ty.as_ref(), ast::TypeName::DynTrait(_)) =>
+            {
+                self.lower_trait_path(ref_ty, false, in_path)
+            }
             ast::TypeName::Reference(lifetime, mutability, ref_ty) => match ref_ty.as_ref() {
+                ast::TypeName::DynTrait(path) => {
+                    self.errors.push(LoweringError::new(LoweringErrorKind::InvalidType, format!(
+                        "found &mut dyn {path} in input, but traits can only be passed as Box<dyn Trait> or &dyn Trait"
+                    )));
+                    Err(())
+                }
                 ast::TypeName::Named(path) | ast::TypeName::SelfType(path) => {
                     match path.resolve(in_path, self.env) {
                         ast::CustomType::Opaque(opaque) => {
@@ -604,25 +1133,28 @@ impl<'ast> LoweringContext<'ast> {
                             )))
                         }
                         _ => {
-                            self.errors.push(LoweringError::Other(format!("found &T in input where T is a custom type, but not opaque. T = {ref_ty}")));
+                            self.errors.push(LoweringError::new(LoweringErrorKind::InvalidType, format!("found &T in input where T is a custom type, but not opaque. T = {ref_ty}")));
                             Err(())
                         }
                     }
                 }
                 _ => {
-                    self.errors.push(LoweringError::Other(format!("found &T in input where T isn't a custom type and therefore not opaque. T = {ref_ty}")));
+                    self.errors.push(LoweringError::new(LoweringErrorKind::InvalidType, format!("found &T in input where T isn't a custom type and therefore not opaque. T = {ref_ty}")));
                     Err(())
                 }
             },
+            ast::TypeName::Box(box_ty) if matches!(box_ty.as_ref(), ast::TypeName::DynTrait(_)) => {
+                self.lower_trait_path(box_ty, true, in_path)
+            }
             ast::TypeName::Box(box_ty) => {
                 self.errors.push(match box_ty.as_ref() {
                 ast::TypeName::Named(path) | ast::TypeName::SelfType(path) => {
                     match path.resolve(in_path, self.env) {
-                        ast::CustomType::Opaque(_) => LoweringError::Other(format!("found Box<T> in input where T is an opaque, but owned opaques aren't allowed in inputs. try &T instead? T = {path}")),
-                        _ => LoweringError::Other(format!("found Box<T> in input where T is a custom type but not opaque. non-opaques can't be behind pointers, and opaques in inputs can't be owned. T = {path}")),
+                        ast::CustomType::Opaque(_) => LoweringError::new(LoweringErrorKind::InvalidType, format!("found Box<T> in input where T is an opaque, but owned opaques aren't allowed in inputs. try &T instead? T = {path}")),
+                        _ => LoweringError::new(LoweringErrorKind::InvalidType, format!("found Box<T> in input where T is a custom type but not opaque. non-opaques can't be behind pointers, and opaques in inputs can't be owned. T = {path}")),
                     }
                 }
-                _ => LoweringError::Other(format!("found Box<T> in input where T isn't a custom type. T = {box_ty}")),
+                _ => LoweringError::new(LoweringErrorKind::InvalidType, format!("found Box<T> in input where T isn't a custom type. T = {box_ty}")),
             });
                 Err(())
             }
@@ -652,35 +1184,37 @@ impl<'ast> LoweringContext<'ast> {
                                 )))
                             }
                             _ => {
-                                self.errors.push(LoweringError::Other(format!("found Option<&T> in input where T is a custom type, but it's not opaque. T = {ref_ty}")));
+                                self.errors.push(LoweringError::new(LoweringErrorKind::InvalidType, format!("found Option<&T> in input where T is a custom type, but it's not opaque. T = {ref_ty}")));
                                 Err(())
                             }
                         },
                         _ => {
-                            self.errors.push(LoweringError::Other(format!("found Option<&T> in input, but T isn't a custom type and therefore not opaque. T = {ref_ty}")));
+                            self.errors.push(LoweringError::new(LoweringErrorKind::InvalidType, format!("found Option<&T> in input, but T isn't a custom type and therefore not opaque. T = {ref_ty}")));
                             Err(())
                         }
                     },
                     ast::TypeName::Box(box_ty) => {
                         // we could see whats in the box here too
-                        self.errors.push(LoweringError::Other(format!("found Option<Box<T>> in input, but box isn't allowed in inputs. T = {box_ty}")));
+                        self.errors.push(LoweringError::new(LoweringErrorKind::InvalidType, format!("found Option<Box<T>> in input, but box isn't allowed in inputs. T = {box_ty}")));
                         Err(())
                     }
                     _ => {
-                        self.errors.push(LoweringError::Other(format!("found Option<T> in input, where T isn't a reference but Option<T> in inputs requires that T is a reference to an opaque. T = {opt_ty}")));
+                        self.errors.push(LoweringError::new(LoweringErrorKind::InvalidType, format!("found Option<T> in input, where T isn't a reference but Option<T> in inputs requires that T is a reference to an opaque. T = {opt_ty}")));
                         Err(())
                     }
                 }
             }
             ast::TypeName::Result(_, _, _) => {
-                self.errors.push(LoweringError::Other(
-                    "Results can only appear as the top-level return type of methods".into(),
+                self.errors.push(LoweringError::new(
+                    LoweringErrorKind::InvalidType,
+                    "Results can only appear as the top-level return type of methods",
                 ));
                 Err(())
             }
             ast::TypeName::Writeable => {
-                self.errors.push(LoweringError::Other(
-                    "Writeables can only appear as the last parameter of a method".into(),
+                self.errors.push(LoweringError::new(
+                    LoweringErrorKind::InvalidType,
+                    "Writeables can only appear as the last parameter of a method",
                 ));
                 Err(())
             }
@@ -695,7 +1229,7 @@ impl<'ast> LoweringContext<'ast> {
                 PrimitiveType::from_ast(*prim),
             ))),
             ast::TypeName::Unit => {
-                self.errors.push(LoweringError::Other("[2] Unit types can only appear as the return value of a method, or as the Ok/Err variants of a returned result".into()));
+                self.errors.push(LoweringError::new(LoweringErrorKind::InvalidType, "[2] Unit types can only appear as the return value of a method, or as the Ok/Err variants of a returned result"));
                 Err(())
             },
             ast::TypeName::Function(inputs, output) => {
@@ -708,9 +1242,34 @@ impl<'ast> LoweringContext<'ast> {
                 };
                 Ok(Type::Func(Box::new(FunctionType { inputs, output })))
             }
+            ast::TypeName::DynTrait(path) => {
+                self.errors.push(LoweringError::new(LoweringErrorKind::InvalidType, format!(
+                    "found dyn {path} in input, but trait objects must be behind a Box or a reference"
+                )));
+                Err(())
+            }
         }
     }
 
+    /// Lowers the `dyn Trait` in a `Box<dyn Trait>` or `&dyn Trait` input.
+    fn lower_trait_path(
+        &mut self,
+        dyn_ty: &ast::TypeName,
+        is_owned: bool,
+        in_path: &ast::Path,
+    ) -> Result<Type, ()> {
+        let ast::TypeName::DynTrait(path) = dyn_ty else {
+            unreachable!("lower_trait_path called on {dyn_ty}, which isn't a trait object")
+        };
+        let trt = path.resolve_trait(in_path, self.env);
+        let tcx_id = self
+            .lookup_id
+            .resolve_trait(trt)
+            .expect("can't find trait in lookup map, which contains all traits from env");
+
+        Ok(Type::Trait(TraitPath::new(tcx_id, is_owned)))
+    }
+
     /// Lowers an [`ast::TypeName`]s into an [`hir::OutType`].
     ///
     /// If there are any errors, they're pushed to `errors` and `None` is returned.
@@ -727,9 +1286,9 @@ impl<'ast> LoweringContext<'ast> {
             }
             ast::TypeName::Ordering => {
                 if in_struct {
-                    self.errors.push(LoweringError::Other(
-                        "Found cmp::Ordering in struct field, it is only allowed in return types"
-                            .to_string(),
+                    self.errors.push(LoweringError::new(
+                        LoweringErrorKind::InvalidType,
+                        "Found cmp::Ordering in struct field, it is only allowed in return types",
                     ));
                     Err(())
                 } else {
@@ -755,9 +1314,10 @@ impl<'ast> LoweringContext<'ast> {
                         }
                     }
                     ast::CustomType::Opaque(_) => {
-                        self.errors.push(LoweringError::Other(format!(
-                            "Opaque passed by value in input: {path}"
-                        )));
+                        self.errors.push(LoweringError::new(
+                            LoweringErrorKind::InvalidType,
+                            format!("Opaque passed by value in input: {path}"),
+                        ));
                         Err(())
                     }
                     ast::CustomType::Enum(enm) => {
@@ -791,13 +1351,13 @@ impl<'ast> LoweringContext<'ast> {
                             )))
                         }
                         _ => {
-                            self.errors.push(LoweringError::Other(format!("found &T in output where T is a custom type, but not opaque. T = {ref_ty}")));
+                            self.errors.push(LoweringError::new(LoweringErrorKind::InvalidType, format!("found &T in output where T is a custom type, but not opaque. T = {ref_ty}")));
                             Err(())
                         }
                     }
                 }
                 _ => {
-                    self.errors.push(LoweringError::Other(format!("found &T in output where T isn't a custom type and therefore not opaque. T = {ref_ty}")));
+                    self.errors.push(LoweringError::new(LoweringErrorKind::InvalidType, format!("found &T in output where T isn't a custom type and therefore not opaque. T = {ref_ty}")));
                     Err(())
                 }
             },
@@ -822,15 +1382,16 @@ impl<'ast> LoweringContext<'ast> {
                             )))
                         }
                         _ => {
-                            self.errors.push(LoweringError::Other(format!("found Box<T> in output where T is a custom type but not opaque. non-opaques can't be behind pointers. T = {path}")));
+                            self.errors.push(LoweringError::new(LoweringErrorKind::InvalidType, format!("found Box<T> in output where T is a custom type but not opaque. non-opaques can't be behind pointers. T = {path}")));
                             Err(())
                         }
                     }
                 }
                 _ => {
-                    self.errors.push(LoweringError::Other(format!(
-                        "found Box<T> in output where T isn't a custom type. T = {box_ty}"
-                    )));
+                    self.errors.push(LoweringError::new(
+                        LoweringErrorKind::InvalidType,
+                        format!("found Box<T> in output where T isn't a custom type. T = {box_ty}"),
+                    ));
                     Err(())
                 }
             },
@@ -857,13 +1418,13 @@ impl<'ast> LoweringContext<'ast> {
                                 )))
                             }
                             _ => {
-                                self.errors.push(LoweringError::Other(format!("found Option<&T> where T is a custom type, but it's not opaque. T = {ref_ty}")));
+                                self.errors.push(LoweringError::new(LoweringErrorKind::InvalidType, format!("found Option<&T> where T is a custom type, but it's not opaque. T = {ref_ty}")));
                                 Err(())
                             }
                         }
                     }
                     _ => {
-                        self.errors.push(LoweringError::Other(format!("found Option<&T>, but T isn't a custom type and therefore not opaque. T = {ref_ty}")));
+                        self.errors.push(LoweringError::new(LoweringErrorKind::InvalidType, format!("found Option<&T>, but T isn't a custom type and therefore not opaque. T = {ref_ty}")));
                         Err(())
                     }
                 },
@@ -888,30 +1449,32 @@ impl<'ast> LoweringContext<'ast> {
                                 )))
                             }
                             _ => {
-                                self.errors.push(LoweringError::Other(format!("found Option<Box<T>> where T is a custom type, but it's not opaque. T = {box_ty}")));
+                                self.errors.push(LoweringError::new(LoweringErrorKind::InvalidType, format!("found Option<Box<T>> where T is a custom type, but it's not opaque. T = {box_ty}")));
                                 Err(())
                             }
                         }
                     }
                     _ => {
-                        self.errors.push(LoweringError::Other(format!("found Option<Box<T>>, but T isn't a custom type and therefore not opaque. T = {box_ty}")));
+                        self.errors.push(LoweringError::new(LoweringErrorKind::InvalidType, format!("found Option<Box<T>>, but T isn't a custom type and therefore not opaque. T = {box_ty}")));
                         Err(())
                     }
                 },
                 _ => {
-                    self.errors.push(LoweringError::Other(format!("found Option<T>, where T isn't a reference but Option<T> requires that T is a reference to an opaque. T = {opt_ty}")));
+                    self.errors.push(LoweringError::new(LoweringErrorKind::InvalidType, format!("found Option<T>, where T isn't a reference but Option<T> requires that T is a reference to an opaque. T = {opt_ty}")));
                     Err(())
                 }
             },
             ast::TypeName::Result(_, _, _) => {
-                self.errors.push(LoweringError::Other(
-                    "Results can only appear as the top-level return type of methods".into(),
+                self.errors.push(LoweringError::new(
+                    LoweringErrorKind::InvalidType,
+                    "Results can only appear as the top-level return type of methods",
                 ));
                 Err(())
             }
             ast::TypeName::Writeable => {
-                self.errors.push(LoweringError::Other(
-                    "Writeables can only appear as the last parameter of a method".into(),
+                self.errors.push(LoweringError::new(
+                    LoweringErrorKind::InvalidType,
+                    "Writeables can only appear as the last parameter of a method",
                 ));
                 Err(())
             }
@@ -920,8 +1483,9 @@ impl<'ast> LoweringContext<'ast> {
                 *encoding,
             ))),
             ast::TypeName::StrSlice(..) => {
-                self.errors.push(LoweringError::Other(
-                    "String slices can only be an input type".into(),
+                self.errors.push(LoweringError::new(
+                    LoweringErrorKind::InvalidType,
+                    "String slices can only be an input type",
                 ));
                 Err(())
             }
@@ -931,11 +1495,21 @@ impl<'ast> LoweringContext<'ast> {
                 PrimitiveType::from_ast(*prim),
             ))),
             ast::TypeName::Unit => {
-                self.errors.push(LoweringError::Other("Unit types can only appear as the return value of a method, or as the Ok/Err variants of a returned result".into()));
+                self.errors.push(LoweringError::new(LoweringErrorKind::InvalidType, "Unit types can only appear as the return value of a method, or as the Ok/Err variants of a returned result"));
                 Err(())
             },
             ast::TypeName::Function(..) => {
-                self.errors.push(LoweringError::Other("Function types can only appear as arguments to a method".into()));
+                self.errors.push(LoweringError::new(
+                    LoweringErrorKind::InvalidType,
+                    "Function types can only appear as arguments to a method",
+                ));
+                Err(())
+            }
+            ast::TypeName::DynTrait(..) => {
+                self.errors.push(LoweringError::new(
+                    LoweringErrorKind::InvalidType,
+                    "Traits can only appear as arguments to a method",
+                ));
                 Err(())
             }
         }
@@ -955,7 +1529,7 @@ impl<'ast> LoweringContext<'ast> {
             ast::CustomType::Struct(strct) => {
                 if let Some(tcx_id) = self.lookup_id.resolve_struct(strct) {
                     if self_param.reference.is_some() {
-                        self.errors.push(LoweringError::Other(format!("Method `{method_full_path}` takes a reference to a struct as a self parameter, which isn't allowed")));
+                        self.errors.push(LoweringError::new(LoweringErrorKind::InvalidSelfParam, format!("Method `{method_full_path}` takes a reference to a struct as a self parameter, which isn't allowed")));
                         Err(())
                     } else {
                         let mut param_ltl = self_param_ltl.no_self_ref();
@@ -979,10 +1553,10 @@ impl<'ast> LoweringContext<'ast> {
                     }
                 } else if self.lookup_id.resolve_out_struct(strct).is_some() {
                     if let Some((lifetime, _)) = &self_param.reference {
-                        self.errors.push(LoweringError::Other(format!("Method `{method_full_path}` takes an out-struct as the self parameter, which isn't allowed. Also, it's behind a reference, `{lifetime}`, but only opaques can be behind references")));
+                        self.errors.push(LoweringError::new(LoweringErrorKind::InvalidSelfParam, format!("Method `{method_full_path}` takes an out-struct as the self parameter, which isn't allowed. Also, it's behind a reference, `{lifetime}`, but only opaques can be behind references")));
                         Err(())
                     } else {
-                        self.errors.push(LoweringError::Other(format!("Method `{method_full_path}` takes an out-struct as the self parameter, which isn't allowed")));
+                        self.errors.push(LoweringError::new(LoweringErrorKind::InvalidSelfParam, format!("Method `{method_full_path}` takes an out-struct as the self parameter, which isn't allowed")));
                         Err(())
                     }
                 } else {
@@ -1017,7 +1591,7 @@ impl<'ast> LoweringContext<'ast> {
                         param_ltl,
                     ))
                 } else {
-                    self.errors.push(LoweringError::Other(format!("Method `{method_full_path}` takes an opaque by value as the self parameter, but opaques as inputs must be behind refs")));
+                    self.errors.push(LoweringError::new(LoweringErrorKind::InvalidSelfParam, format!("Method `{method_full_path}` takes an opaque by value as the self parameter, but opaques as inputs must be behind refs")));
                     Err(())
                 }
             }
@@ -1162,3 +1736,37 @@ impl<'ast> LoweringContext<'ast> {
         Ok(LifetimeEnv::new(nodes, ast.nodes.len()))
     }
 }
+
+/// Whether a const literal can be written out as a value of the given type.
+fn const_value_fits(ty: ConstType, value: &ast::ConstValue) -> bool {
+    let int_range = |int: IntType| -> (i128, i128) {
+        match int {
+            IntType::I8 => (i8::MIN.into(), i8::MAX.into()),
+            IntType::I16 => (i16::MIN.into(), i16::MAX.into()),
+            IntType::I32 => (i32::MIN.into(), i32::MAX.into()),
+            IntType::I64 => (i64::MIN.into(), i64::MAX.into()),
+            IntType::U8 => (0, u8::MAX.into()),
+            IntType::U16 => (0, u16::MAX.into()),
+            IntType::U32 => (0, u32::MAX.into()),
+            IntType::U64 => (0, u64::MAX.into()),
+        }
+    };
+    match (ty, value) {
+        (ConstType::Str, ast::ConstValue::Str(_)) => true,
+        (ConstType::Primitive(PrimitiveType::Bool), ast::ConstValue::Bool(_)) => true,
+        (ConstType::Primitive(PrimitiveType::Float(_)), ast::ConstValue::Float(_)) => true,
+        (ConstType::Primitive(prim), ast::ConstValue::Int(i)) => {
+            let (min, max) = match prim {
+                PrimitiveType::Int(int) => int_range(int),
+                PrimitiveType::Byte => int_range(IntType::U8),
+                PrimitiveType::Char => (0, u32::from(char::MAX).into()),
+                // Sizes are only checked against 64 bits, the widest platform we support
+                PrimitiveType::IntSize(IntSizeType::Isize) => int_range(IntType::I64),
+                PrimitiveType::IntSize(IntSizeType::Usize) => int_range(IntType::U64),
+                _ => return false,
+            };
+            matches!(i.parse::<i128>(), Ok(i) if min <= i && i <= max)
+        }
+        _ => false,
+    }
+}
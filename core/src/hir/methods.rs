@@ -3,7 +3,7 @@
 use std::collections::BTreeSet;
 use std::ops::Deref;
 
-use super::{Attrs, Docs, Ident, IdentBuf, OutType, SelfType, Type, TypeContext};
+use super::{Attrs, Docs, Ident, IdentBuf, OutType, SelfType, SourceSpan, Type, TypeContext};
 
 use super::lifetimes::{Lifetime, LifetimeEnv, Lifetimes, MaybeStatic};
 
@@ -25,6 +25,24 @@ pub struct Method {
     pub params: Vec<Param>,
     pub output: ReturnType,
     pub attrs: Attrs,
+    /// Where the method's signature was declared, if known.
+    pub span: Option<SourceSpan>,
+}
+
+/// A method declared in a trait, which foreign code implements.
+///
+/// Trait methods always take `&self`, which is the context pointer of the vtable,
+/// so it isn't stored here.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct TraitMethod {
+    pub docs: Docs,
+    pub name: IdentBuf,
+    pub lifetime_env: LifetimeEnv,
+    pub params: Vec<Param>,
+    /// The value returned by the foreign implementation, which is an input to Rust.
+    pub output: Option<Type>,
+    pub attrs: Attrs,
 }
 
 /// Type that the method returns.
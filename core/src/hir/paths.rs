@@ -1,7 +1,7 @@
 use super::lifetimes::{Lifetimes, LinkedLifetimes};
 use super::{
     Borrow, EnumDef, EnumId, Everywhere, OpaqueDef, OpaqueId, OpaqueOwner, OutStructDef,
-    OutputOnly, ReturnableStructDef, StructDef, TyPosition, TypeContext,
+    OutputOnly, ReturnableStructDef, StructDef, TraitDef, TraitId, TyPosition, TypeContext,
 };
 
 /// Path to a struct that may appear as an output.
@@ -89,6 +89,16 @@ pub struct EnumPath {
     pub tcx_id: EnumId,
 }
 
+/// Path to a trait object, passed across the FFI boundary as a vtable.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct TraitPath {
+    pub tcx_id: TraitId,
+    /// Whether Rust takes ownership of the trait object (`Box<dyn Trait>`),
+    /// or only borrows it for the duration of the call (`&dyn Trait`).
+    pub is_owned: bool,
+}
+
 /// Determine whether a pointer to an opaque type is owned or borrowed.
 ///
 /// Since owned opaques cannot be used as inputs, this only appears in output types.
@@ -198,3 +208,15 @@ impl EnumPath {
         tcx.resolve_enum(self.tcx_id)
     }
 }
+
+impl TraitPath {
+    /// Returns a new [`TraitPath`].
+    pub(super) fn new(tcx_id: TraitId, is_owned: bool) -> Self {
+        Self { tcx_id, is_owned }
+    }
+
+    /// Returns the [`TraitDef`] that this path references.
+    pub fn resolve<'tcx>(&self, tcx: &'tcx TypeContext) -> &'tcx TraitDef {
+        tcx.resolve_trait(self.tcx_id)
+    }
+}
@@ -1,10 +1,10 @@
 //! Store all the types contained in the HIR.
 
-use super::lowering::{ErrorAndContext, ErrorStore, ItemAndInfo};
+use super::lowering::{self, ErrorAndContext, ErrorStore, ItemAndInfo};
 use super::ty_position::StructPathLike;
 use super::{
-    AttributeValidator, Attrs, EnumDef, LoweringContext, LoweringError, MaybeStatic, OpaqueDef,
-    OutStructDef, StructDef, TypeDef,
+    AttributeValidator, Attrs, ConstDef, EnumDef, LoweringContext, LoweringError,
+    LoweringErrorKind, MaybeStatic, OpaqueDef, OutStructDef, StructDef, TraitDef, TypeDef,
 };
 use crate::ast::attrs::AttrInheritContext;
 #[allow(unused_imports)] // use in docs links
@@ -12,7 +12,7 @@ use crate::hir;
 use crate::{ast, Env};
 use core::fmt::{self, Display};
 use smallvec::SmallVec;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::ops::Index;
 
 /// A context type owning all types exposed to Diplomat.
@@ -22,6 +22,11 @@ pub struct TypeContext {
     structs: Vec<StructDef>,
     opaques: Vec<OpaqueDef>,
     enums: Vec<EnumDef>,
+    traits: Vec<TraitDef>,
+    consts: Vec<ConstDef>,
+    /// The crates of the types and traits that come from the bridges of other crates
+    external_types: BTreeMap<TypeId, String>,
+    external_traits: BTreeMap<TraitId, String>,
 }
 
 /// Key used to index into a [`TypeContext`] representing a struct.
@@ -40,6 +45,10 @@ pub struct OpaqueId(usize);
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct EnumId(usize);
 
+/// Key used to index into a [`TypeContext`] representing a trait.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TraitId(usize);
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[non_exhaustive]
 pub enum TypeId {
@@ -106,6 +115,50 @@ impl TypeContext {
         &self.enums
     }
 
+    pub fn traits(&self) -> &[TraitDef] {
+        &self.traits
+    }
+
+    /// Traits aren't types, so they aren't part of [`Self::all_types()`].
+    pub fn all_traits(&self) -> impl Iterator<Item = (TraitId, &TraitDef)> {
+        self.traits
+            .iter()
+            .enumerate()
+            .map(|(i, trt)| (TraitId(i), trt))
+    }
+
+    /// The crate `id` is defined in, if it comes from the bridge of another crate rather
+    /// than the bridge crate itself.
+    ///
+    /// Those types are lowered so that methods can use them, but their bindings are
+    /// generated with that crate, so backends should import them instead of generating them.
+    pub fn external_crate(&self, id: TypeId) -> Option<&str> {
+        self.external_types.get(&id).map(String::as_str)
+    }
+
+    /// Like [`Self::external_crate()`], for traits
+    pub fn external_trait_crate(&self, id: TraitId) -> Option<&str> {
+        self.external_traits.get(&id).map(String::as_str)
+    }
+
+    /// Whether any type or trait comes from the bridge of another crate
+    pub fn has_external_items(&self) -> bool {
+        !self.external_types.is_empty() || !self.external_traits.is_empty()
+    }
+
+    /// Whether any method of the bridge crate catches panics, in which case the library
+    /// exports the functions of `diplomat_runtime::panic` that report them.
+    pub fn catches_panics(&self) -> bool {
+        self.all_types()
+            .filter(|(id, _)| self.external_crate(*id).is_none())
+            .any(|(_, ty)| ty.methods().iter().any(|m| m.attrs.catch_panics))
+    }
+
+    /// Module-level consts. Associated consts live on their type, see [`TypeDef::consts()`].
+    pub fn consts(&self) -> &[ConstDef] {
+        &self.consts
+    }
+
     pub fn resolve_type<'tcx>(&'tcx self, id: TypeId) -> TypeDef<'tcx> {
         match id {
             TypeId::Struct(i) => TypeDef::Struct(self.resolve_struct(i)),
@@ -143,13 +196,17 @@ impl TypeContext {
         self.enums.index(id.0)
     }
 
+    pub fn resolve_trait(&self, id: TraitId) -> &TraitDef {
+        self.traits.index(id.0)
+    }
+
     /// Lower the AST to the HIR while simultaneously performing validation.
     pub fn from_ast<'ast>(
         env: &'ast Env,
         attr_validator: impl AttributeValidator + 'static,
     ) -> Result<Self, Vec<ErrorAndContext>> {
         let (mut ctx, hir) = Self::from_ast_without_validation(env, attr_validator)?;
-        ctx.errors.set_item("(validation)");
+        ctx.errors.set_item("(validation)", None);
         hir.validate(&mut ctx.errors);
         if !ctx.errors.is_empty() {
             return Err(ctx.errors.take_errors());
@@ -157,6 +214,18 @@ impl TypeContext {
         Ok(hir)
     }
 
+    /// Check that every type and trait named in the bridge crate exists, without lowering it.
+    ///
+    /// [`Self::from_ast()`] does this first, so this is for tools that only read the AST.
+    pub fn check_paths(env: &Env) -> Result<(), Vec<ErrorAndContext>> {
+        let mut errors = ErrorStore::default();
+        lowering::check_paths(env, &mut errors);
+        if !errors.is_empty() {
+            return Err(errors.take_errors());
+        }
+        Ok(())
+    }
+
     /// Lower the AST to the HIR, without validation. For testing
     pub(super) fn from_ast_without_validation<'ast>(
         env: &'ast Env,
@@ -166,15 +235,25 @@ impl TypeContext {
         let mut ast_structs = SmallVec::<[_; 16]>::new();
         let mut ast_opaques = SmallVec::<[_; 16]>::new();
         let mut ast_enums = SmallVec::<[_; 16]>::new();
+        let mut ast_traits = SmallVec::<[_; 16]>::new();
+        let mut ast_consts = SmallVec::<[_; 16]>::new();
+        let mut external_types = BTreeMap::new();
+        let mut external_traits = BTreeMap::new();
 
         let mut errors = ErrorStore::default();
 
+        lowering::check_paths(env, &mut errors);
+        if !errors.is_empty() {
+            return Err(errors.take_errors());
+        }
+
         for (path, mod_env) in env.iter_modules() {
             errors.set_item(
                 path.elements
                     .last()
                     .map(|m| m.as_str())
                     .unwrap_or("root module"),
+                None,
             );
             let mod_attrs = Attrs::from_ast(
                 &mod_env.attrs,
@@ -186,7 +265,26 @@ impl TypeContext {
             let method_attrs =
                 mod_attrs.for_inheritance(AttrInheritContext::MethodOrImplFromModule);
 
+            let krate = env.crate_of(path);
+            let mut mark_external = |id| {
+                if let Some(krate) = krate {
+                    external_types.insert(id, krate.to_string());
+                }
+            };
+
             for sym in mod_env.items() {
+                if let ast::ModSymbol::Trait(trt) = sym {
+                    if let Some(krate) = krate {
+                        external_traits.insert(TraitId(ast_traits.len()), krate.to_string());
+                    }
+                    ast_traits.push((trt, path, ty_attrs.clone(), method_attrs.clone()));
+                }
+                // Module-level consts aren't used by anything, so those of other crates are left out
+                if let ast::ModSymbol::Const(cnst) = sym {
+                    if krate.is_none() {
+                        ast_consts.push((cnst, ty_attrs.clone()));
+                    }
+                }
                 if let ast::ModSymbol::CustomType(custom_type) = sym {
                     match custom_type {
                         ast::CustomType::Struct(strct) => {
@@ -202,6 +300,7 @@ impl TypeContext {
                                 method_parent_attrs: method_attrs.clone(),
                                 id,
                             };
+                            mark_external(item.id);
                             if strct.output_only {
                                 ast_out_structs.push(item);
                             } else {
@@ -216,6 +315,7 @@ impl TypeContext {
                                 method_parent_attrs: method_attrs.clone(),
                                 id: TypeId::Opaque(OpaqueId(ast_opaques.len())),
                             };
+                            mark_external(item.id);
                             ast_opaques.push(item)
                         }
                         ast::CustomType::Enum(enm) => {
@@ -226,6 +326,7 @@ impl TypeContext {
                                 method_parent_attrs: method_attrs.clone(),
                                 id: TypeId::Enum(EnumId(ast_enums.len())),
                             };
+                            mark_external(item.id);
                             ast_enums.push(item)
                         }
                     }
@@ -238,6 +339,7 @@ impl TypeContext {
             &ast_structs[..],
             &ast_opaques[..],
             &ast_enums[..],
+            &ast_traits[..],
         );
         let attr_validator = Box::new(attr_validator);
 
@@ -252,14 +354,20 @@ impl TypeContext {
         let structs = ctx.lower_all_structs(ast_structs.into_iter());
         let opaques = ctx.lower_all_opaques(ast_opaques.into_iter());
         let enums = ctx.lower_all_enums(ast_enums.into_iter());
+        let traits = ctx.lower_all_traits(ast_traits.into_iter());
+        let consts = ctx.lower_all_consts(ast_consts, false);
 
-        match (out_structs, structs, opaques, enums) {
-            (Ok(out_structs), Ok(structs), Ok(opaques), Ok(enums)) => {
+        match (out_structs, structs, opaques, enums, traits, consts) {
+            (Ok(out_structs), Ok(structs), Ok(opaques), Ok(enums), Ok(traits), Ok(consts)) => {
                 let res = Self {
                     out_structs,
                     structs,
                     opaques,
                     enums,
+                    traits,
+                    consts,
+                    external_types,
+                    external_traits,
                 };
 
                 if !ctx.errors.is_empty() {
@@ -284,9 +392,9 @@ impl TypeContext {
     fn validate<'hir>(&'hir self, errors: &mut ErrorStore<'hir>) {
         // Lifetime validity check
         for (_id, ty) in self.all_types() {
-            errors.set_item(ty.name().as_str());
+            errors.set_item(ty.name().as_str(), None);
             for method in ty.methods() {
-                errors.set_subitem(method.name.as_str());
+                errors.set_subitem(method.name.as_str(), method.span);
 
                 // This check must occur before validate_ty_in_method is called
                 // since validate_ty_in_method calls link_lifetimes which does not
@@ -296,8 +404,8 @@ impl TypeContext {
                     for lt in out_ty.lifetimes() {
                         if let MaybeStatic::NonStatic(lt) = lt {
                             if method.lifetime_env.get_bounds(lt).is_none() {
-                                errors.push(LoweringError::Other(
-                                    "Found elided lifetime in return type, please explicitly specify".into(),
+                                errors.push(LoweringError::new(LoweringErrorKind::Lifetime,
+                                    "Found elided lifetime in return type, please explicitly specify",
                                 ));
 
                                 failed = true;
@@ -385,7 +493,7 @@ impl TypeContext {
                         // This case is technically already handled in the lifetime lowerer, we're being careful
                         "comes from &-ref's lifetime in parameter".into()
                     };
-                    errors.push(LoweringError::Other(format!("Method should explicitly include this \
+                    errors.push(LoweringError::new(LoweringErrorKind::Lifetime, format!("Method should explicitly include this \
                                         lifetime bound from {param}: '{use_longer_name}: '{use_name} ({def_cause})")))
                 }
             }
@@ -404,6 +512,7 @@ pub(super) struct LookupId<'ast> {
     struct_map: HashMap<&'ast ast::Struct, StructId>,
     opaque_map: HashMap<&'ast ast::OpaqueStruct, OpaqueId>,
     enum_map: HashMap<&'ast ast::Enum, EnumId>,
+    trait_map: HashMap<&'ast ast::Trait, TraitId>,
 }
 
 impl<'ast> LookupId<'ast> {
@@ -413,6 +522,7 @@ impl<'ast> LookupId<'ast> {
         structs: &[ItemAndInfo<'ast, ast::Struct>],
         opaques: &[ItemAndInfo<'ast, ast::OpaqueStruct>],
         enums: &[ItemAndInfo<'ast, ast::Enum>],
+        traits: &[(&'ast ast::Trait, &'ast ast::Path, Attrs, Attrs)],
     ) -> Self {
         Self {
             out_struct_map: out_structs
@@ -435,6 +545,11 @@ impl<'ast> LookupId<'ast> {
                 .enumerate()
                 .map(|(index, item)| (item.item, EnumId(index)))
                 .collect(),
+            trait_map: traits
+                .iter()
+                .enumerate()
+                .map(|(index, (trt, ..))| (*trt, TraitId(index)))
+                .collect(),
         }
     }
 
@@ -453,6 +568,10 @@ impl<'ast> LookupId<'ast> {
     pub(super) fn resolve_enum(&self, enm: &ast::Enum) -> Option<EnumId> {
         self.enum_map.get(enm).copied()
     }
+
+    pub(super) fn resolve_trait(&self, trt: &ast::Trait) -> Option<TraitId> {
+        self.trait_map.get(trt).copied()
+    }
 }
 
 impl From<StructId> for TypeId {
@@ -535,6 +654,57 @@ mod tests {
     }
 
     /// This is a buch of tests put together
+    #[test]
+    fn test_types_from_other_crates() {
+        let core_crate = crate::ast::File::from(&syn::parse_quote! {
+            #[diplomat::bridge]
+            mod ffi {
+                #[diplomat::opaque]
+                struct Foo;
+
+                struct Point {
+                    x: u8,
+                }
+
+                pub const VERSION: u8 = 1;
+
+                impl Foo {
+                    pub fn at(&self, point: crate::ffi::Point) {}
+                }
+            }
+        });
+        let feature_crate = crate::ast::File::from(&syn::parse_quote! {
+            #[diplomat::bridge]
+            mod ffi {
+                use core_crate::ffi::Foo;
+
+                #[diplomat::opaque]
+                struct Bar;
+
+                impl Bar {
+                    pub fn foo(&self, point: core_crate::ffi::Point) -> Box<Foo> {}
+                }
+            }
+        });
+        let env = feature_crate.all_types_with_crates([("core_crate", &core_crate)]);
+        let tcx =
+            hir::TypeContext::from_ast(&env, hir::BasicAttributeValidator::new("tests")).unwrap();
+
+        let types = tcx
+            .all_types()
+            .map(|(id, ty)| (ty.name().as_str(), tcx.external_crate(id)))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            types,
+            [
+                ("Point", Some("core_crate")),
+                ("Foo", Some("core_crate")),
+                ("Bar", None)
+            ]
+        );
+        assert!(tcx.consts().is_empty());
+    }
+
     #[test]
     fn test_basic_lowering() {
         uitest_lowering! {
@@ -764,4 +934,121 @@ mod tests {
             }
         };
     }
+
+    #[test]
+    fn test_enum_payloads() {
+        uitest_lowering! {
+            #[diplomat::bridge]
+            mod ffi {
+                #[diplomat::opaque]
+                struct Opaque;
+
+                struct Point {
+                    x: f64,
+                    y: f64,
+                }
+
+                enum Shape {
+                    Circle { center: Point, r: f64 },
+                    Label { text: &'static str },
+                    Borrowing { text: &'a str },
+                    Boxed { o: Box<Opaque> },
+                    Empty,
+                }
+            }
+        };
+    }
+
+    #[test]
+    fn test_traits() {
+        uitest_lowering! {
+            #[diplomat::bridge]
+            mod ffi {
+                #[diplomat::opaque]
+                struct Opaque;
+
+                trait Logger {
+                    fn log(&self, level: u8, msg: &DiplomatStr);
+                    fn enabled(&self) -> bool;
+                }
+
+                trait Broken {
+                    fn mutates(&mut self);
+                    fn borrows(&self) -> &'static str;
+                    fn nested(&self, logger: Box<dyn Logger>);
+                }
+
+                impl Opaque {
+                    pub fn set_logger(&self, logger: Box<dyn Logger>) {}
+                    pub fn log_with(&self, logger: &dyn Logger) {}
+                    pub fn log_with_mut(&self, logger: &mut dyn Logger) {}
+                    pub fn get_logger(&self) -> Box<dyn Logger> {}
+                }
+            }
+        };
+    }
+
+    #[test]
+    fn test_unresolved_types() {
+        uitest_lowering! {
+            #[diplomat::bridge]
+            mod ffi {
+                use crate::other::Imported;
+
+                #[diplomat::opaque]
+                struct Opaque;
+
+                struct Point {
+                    x: f64,
+                    missing: Missing,
+                }
+
+                enum Shape {
+                    Point { p: Point },
+                    Gone { g: Option<Box<Gone>> },
+                }
+
+                trait Logger {
+                    fn log(&self, from: &Unknown);
+                }
+
+                impl Opaque {
+                    pub fn imported(&self, i: &Imported) {}
+                    pub fn takes(&self, x: &crate::ffi::Nope) {}
+                    pub fn returns() -> Result<Box<Opaque>, Box<Nothing>> {}
+                    pub fn not_a_trait(&self, p: &dyn Point) {}
+                }
+            }
+
+            #[diplomat::bridge]
+            mod other {
+                #[diplomat::opaque]
+                struct Imported;
+            }
+        };
+    }
+
+    #[test]
+    fn test_consts() {
+        uitest_lowering! {
+            #[diplomat::bridge]
+            mod ffi {
+                pub const VERSION: &str = "1.0";
+                pub const MAX: u64 = 18446744073709551615;
+                pub const TOO_BIG: u8 = 256;
+                pub const NEGATIVE: u32 = -1;
+                pub const WIDE: i128 = 0;
+                pub const WRONG_KIND: f64 = true;
+                pub const OPT: Option<u8> = 1;
+
+                #[diplomat::opaque]
+                struct Opaque;
+
+                impl Opaque {
+                    pub const SCALE: f32 = 0.5;
+                    pub const OVERFLOW: i8 = -129;
+                }
+            }
+        };
+    }
 }
@@ -3,7 +3,7 @@
 use super::lifetimes::{Lifetime, MaybeStatic};
 use super::{
     EnumPath, Everywhere, NonOptional, OpaqueOwner, OpaquePath, Optional, OutputOnly,
-    PrimitiveType, StructPath, StructPathLike, TyPosition, TypeContext, TypeId,
+    PrimitiveType, StructPath, StructPathLike, TraitPath, TyPosition, TypeContext, TypeId,
 };
 use crate::ast;
 pub use ast::Mutability;
@@ -23,6 +23,8 @@ pub enum Type<P: TyPosition = Everywhere> {
     Enum(EnumPath),
     Slice(Slice),
     Func(Box<FunctionType>),
+    /// A `Box<dyn Trait>` or `&dyn Trait` implemented by foreign code.
+    Trait(TraitPath),
 }
 
 /// Type that can appear in the `self` position.
@@ -89,7 +91,7 @@ impl Type {
                 (acc.0 + inner.0, acc.1 + inner.1)
             }),
             Type::Opaque(_) | Type::Slice(_) => (1, 1),
-            Type::Primitive(_) | Type::Enum(_) | Type::Func(_) => (0, 0),
+            Type::Primitive(_) | Type::Enum(_) | Type::Func(_) | Type::Trait(_) => (0, 0),
         }
     }
 }
@@ -6,15 +6,18 @@
 #include <stdbool.h>
 #include "diplomat_runtime.h"
 
+/* [ WAKKA 3 ] */
 #ifdef __cplusplus
 namespace capi {
 #endif
 
 typedef struct ICU4XDataProvider ICU4XDataProvider;
+/* [ WAKKA 2 ] */
 #ifdef __cplusplus
 } // namespace capi
 #endif
 #include "diplomat_result_void_void.h"
+/* [ WAKKA 1 ] */
 #ifdef __cplusplus
 namespace capi {
 extern "C" {
@@ -25,6 +28,7 @@ ICU4XDataProvider* ICU4XDataProvider_new_static();
 diplomat_result_void_void ICU4XDataProvider_returns_result();
 void ICU4XDataProvider_destroy(ICU4XDataProvider* self);
 
+/* [ WAKKA 4 ] */
 #ifdef __cplusplus
 } // extern "C"
 } // namespace capi
@@ -6,15 +6,18 @@
 #include <stdbool.h>
 #include "diplomat_runtime.h"
 
+/* [ WAKKA 3 ] */
 #ifdef __cplusplus
 namespace capi {
 #endif
 
 typedef struct ICU4XFixedDecimal ICU4XFixedDecimal;
+/* [ WAKKA 2 ] */
 #ifdef __cplusplus
 } // namespace capi
 #endif
 #include "diplomat_result_void_void.h"
+/* [ WAKKA 1 ] */
 #ifdef __cplusplus
 namespace capi {
 extern "C" {
@@ -27,6 +30,7 @@ void ICU4XFixedDecimal_multiply_pow10(ICU4XFixedDecimal* self, int16_t power);
 diplomat_result_void_void ICU4XFixedDecimal_to_string(const ICU4XFixedDecimal* self, DiplomatWriteable* to);
 void ICU4XFixedDecimal_destroy(ICU4XFixedDecimal* self);
 
+/* [ WAKKA 4 ] */
 #ifdef __cplusplus
 } // extern "C"
 } // namespace capi
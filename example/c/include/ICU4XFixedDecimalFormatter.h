@@ -6,11 +6,13 @@
 #include <stdbool.h>
 #include "diplomat_runtime.h"
 
+/* [ WAKKA 3 ] */
 #ifdef __cplusplus
 namespace capi {
 #endif
 
 typedef struct ICU4XFixedDecimalFormatter ICU4XFixedDecimalFormatter;
+/* [ WAKKA 2 ] */
 #ifdef __cplusplus
 } // namespace capi
 #endif
@@ -19,6 +21,7 @@ typedef struct ICU4XFixedDecimalFormatter ICU4XFixedDecimalFormatter;
 #include "ICU4XFixedDecimalFormatterOptions.h"
 #include "diplomat_result_box_ICU4XFixedDecimalFormatter_void.h"
 #include "ICU4XFixedDecimal.h"
+/* [ WAKKA 1 ] */
 #ifdef __cplusplus
 namespace capi {
 extern "C" {
@@ -29,6 +32,7 @@ diplomat_result_box_ICU4XFixedDecimalFormatter_void ICU4XFixedDecimalFormatter_t
 void ICU4XFixedDecimalFormatter_format_write(const ICU4XFixedDecimalFormatter* self, const ICU4XFixedDecimal* value, DiplomatWriteable* write);
 void ICU4XFixedDecimalFormatter_destroy(ICU4XFixedDecimalFormatter* self);
 
+/* [ WAKKA 4 ] */
 #ifdef __cplusplus
 } // extern "C"
 } // namespace capi
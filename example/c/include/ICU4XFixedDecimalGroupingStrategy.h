@@ -6,6 +6,7 @@
 #include <stdbool.h>
 #include "diplomat_runtime.h"
 
+/* [ WAKKA 3 ] */
 #ifdef __cplusplus
 namespace capi {
 #endif
@@ -16,9 +17,11 @@ typedef enum ICU4XFixedDecimalGroupingStrategy {
   ICU4XFixedDecimalGroupingStrategy_Always = 2,
   ICU4XFixedDecimalGroupingStrategy_Min2 = 3,
 } ICU4XFixedDecimalGroupingStrategy;
+/* [ WAKKA 2 ] */
 #ifdef __cplusplus
 } // namespace capi
 #endif
+/* [ WAKKA 1 ] */
 #ifdef __cplusplus
 namespace capi {
 extern "C" {
@@ -26,6 +29,7 @@ extern "C" {
 
 void ICU4XFixedDecimalGroupingStrategy_destroy(ICU4XFixedDecimalGroupingStrategy* self);
 
+/* [ WAKKA 4 ] */
 #ifdef __cplusplus
 } // extern "C"
 } // namespace capi
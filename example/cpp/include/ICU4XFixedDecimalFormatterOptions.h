@@ -7,6 +7,7 @@
 #include "diplomat_runtime.h"
 
 #include "ICU4XFixedDecimalGroupingStrategy.h"
+/* [ WAKKA 3 ] */
 #ifdef __cplusplus
 namespace capi {
 #endif
@@ -15,10 +16,12 @@ typedef struct ICU4XFixedDecimalFormatterOptions {
     ICU4XFixedDecimalGroupingStrategy grouping_strategy;
     bool some_other_config;
 } ICU4XFixedDecimalFormatterOptions;
+/* [ WAKKA 2 ] */
 #ifdef __cplusplus
 } // namespace capi
 #endif
 #include "ICU4XFixedDecimalGroupingStrategy.h"
+/* [ WAKKA 1 ] */
 #ifdef __cplusplus
 namespace capi {
 extern "C" {
@@ -27,6 +30,7 @@ extern "C" {
 ICU4XFixedDecimalFormatterOptions ICU4XFixedDecimalFormatterOptions_default();
 void ICU4XFixedDecimalFormatterOptions_destroy(ICU4XFixedDecimalFormatterOptions* self);
 
+/* [ WAKKA 4 ] */
 #ifdef __cplusplus
 } // extern "C"
 } // namespace capi
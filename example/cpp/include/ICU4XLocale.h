@@ -6,14 +6,17 @@
 #include <stdbool.h>
 #include "diplomat_runtime.h"
 
+/* [ WAKKA 3 ] */
 #ifdef __cplusplus
 namespace capi {
 #endif
 
 typedef struct ICU4XLocale ICU4XLocale;
+/* [ WAKKA 2 ] */
 #ifdef __cplusplus
 } // namespace capi
 #endif
+/* [ WAKKA 1 ] */
 #ifdef __cplusplus
 namespace capi {
 extern "C" {
@@ -22,6 +25,7 @@ extern "C" {
 ICU4XLocale* ICU4XLocale_new(const char* name_data, size_t name_len);
 void ICU4XLocale_destroy(ICU4XLocale* self);
 
+/* [ WAKKA 4 ] */
 #ifdef __cplusplus
 } // extern "C"
 } // namespace capi
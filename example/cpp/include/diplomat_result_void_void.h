@@ -6,6 +6,7 @@
 #include <stdbool.h>
 #include "diplomat_runtime.h"
 
+/* [ WAKKA 5 ] */
 #ifdef __cplusplus
 namespace capi {
 extern "C" {
@@ -13,6 +14,7 @@ extern "C" {
 typedef struct diplomat_result_void_void {
     bool is_ok;
 } diplomat_result_void_void;
+/* [ WAKKA 6 ] */
 #ifdef __cplusplus
 } // extern "C"
 } // namespace capi
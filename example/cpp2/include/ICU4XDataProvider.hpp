@@ -9,20 +9,20 @@
 #include <stdbool.h>
 #include <memory>
 #include <optional>
+#include <variant>
 #include "diplomat_runtime.hpp"
 #include "ICU4XDataProvider.h"
 
 
+
 inline std::unique_ptr<ICU4XDataProvider> ICU4XDataProvider::new_static() {
   auto result = capi::ICU4XDataProvider_new_static();
   return std::unique_ptr<ICU4XDataProvider>(ICU4XDataProvider::FromFFI(result));
 }
-
 inline diplomat::result<std::monostate, std::monostate> ICU4XDataProvider::returns_result() {
   auto result = capi::ICU4XDataProvider_returns_result();
   return result.is_ok ? diplomat::result<std::monostate, std::monostate>(diplomat::Ok<std::monostate>()) : diplomat::result<std::monostate, std::monostate>(diplomat::Err<std::monostate>());
 }
-
 inline const capi::ICU4XDataProvider* ICU4XDataProvider::AsFFI() const {
   return reinterpret_cast<const capi::ICU4XDataProvider*>(this);
 }
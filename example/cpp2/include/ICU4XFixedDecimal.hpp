@@ -9,20 +9,20 @@
 #include <stdbool.h>
 #include <memory>
 #include <optional>
+#include <variant>
 #include "diplomat_runtime.hpp"
 #include "ICU4XFixedDecimal.h"
 
 
+
 inline std::unique_ptr<ICU4XFixedDecimal> ICU4XFixedDecimal::new_(int32_t v) {
   auto result = capi::ICU4XFixedDecimal_new(v);
   return std::unique_ptr<ICU4XFixedDecimal>(ICU4XFixedDecimal::FromFFI(result));
 }
-
 inline void ICU4XFixedDecimal::multiply_pow10(int16_t power) {
   capi::ICU4XFixedDecimal_multiply_pow10(this->AsFFI(),
     power);
 }
-
 inline diplomat::result<std::string, std::monostate> ICU4XFixedDecimal::to_string() const {
   std::string output;
   capi::DiplomatWriteable writeable = diplomat::WriteableFromString(output);
@@ -30,7 +30,6 @@ inline diplomat::result<std::string, std::monostate> ICU4XFixedDecimal::to_strin
     &writeable);
   return result.is_ok ? diplomat::result<std::string, std::monostate>(diplomat::Ok<std::string>(std::move(output))) : diplomat::result<std::string, std::monostate>(diplomat::Err<std::monostate>());
 }
-
 inline const capi::ICU4XFixedDecimal* ICU4XFixedDecimal::AsFFI() const {
   return reinterpret_cast<const capi::ICU4XFixedDecimal*>(this);
 }
@@ -9,6 +9,7 @@
 #include <stdbool.h>
 #include <memory>
 #include <optional>
+#include <variant>
 #include "diplomat_runtime.hpp"
 #include "ICU4XDataProvider.hpp"
 #include "ICU4XFixedDecimal.hpp"
@@ -17,13 +18,13 @@
 #include "ICU4XLocale.hpp"
 
 
+
 inline diplomat::result<std::unique_ptr<ICU4XFixedDecimalFormatter>, std::monostate> ICU4XFixedDecimalFormatter::try_new(const ICU4XLocale& locale, const ICU4XDataProvider& provider, ICU4XFixedDecimalFormatterOptions options) {
   auto result = capi::ICU4XFixedDecimalFormatter_try_new(locale.AsFFI(),
     provider.AsFFI(),
     options.AsFFI());
   return result.is_ok ? diplomat::result<std::unique_ptr<ICU4XFixedDecimalFormatter>, std::monostate>(diplomat::Ok<std::unique_ptr<ICU4XFixedDecimalFormatter>>(std::unique_ptr<ICU4XFixedDecimalFormatter>(ICU4XFixedDecimalFormatter::FromFFI(result.ok)))) : diplomat::result<std::unique_ptr<ICU4XFixedDecimalFormatter>, std::monostate>(diplomat::Err<std::monostate>());
 }
-
 inline std::string ICU4XFixedDecimalFormatter::format_write(const ICU4XFixedDecimal& value) const {
   std::string output;
   capi::DiplomatWriteable writeable = diplomat::WriteableFromString(output);
@@ -32,7 +33,6 @@ inline std::string ICU4XFixedDecimalFormatter::format_write(const ICU4XFixedDeci
     &writeable);
   return output;
 }
-
 inline const capi::ICU4XFixedDecimalFormatter* ICU4XFixedDecimalFormatter::AsFFI() const {
   return reinterpret_cast<const capi::ICU4XFixedDecimalFormatter*>(this);
 }
@@ -7,6 +7,7 @@
 #include <stdbool.h>
 #include <memory>
 #include <optional>
+#include <variant>
 #include "diplomat_runtime.hpp"
 #include "ICU4XFixedDecimalFormatterOptions.d.h"
 #include "ICU4XFixedDecimalGroupingStrategy.d.hpp"
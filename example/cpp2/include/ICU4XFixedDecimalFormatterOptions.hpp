@@ -9,6 +9,7 @@
 #include <stdbool.h>
 #include <memory>
 #include <optional>
+#include <variant>
 #include "diplomat_runtime.hpp"
 #include "ICU4XFixedDecimalFormatterOptions.h"
 #include "ICU4XFixedDecimalGroupingStrategy.hpp"
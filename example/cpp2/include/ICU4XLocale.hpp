@@ -9,16 +9,17 @@
 #include <stdbool.h>
 #include <memory>
 #include <optional>
+#include <variant>
 #include "diplomat_runtime.hpp"
 #include "ICU4XLocale.h"
 
 
+
 inline std::unique_ptr<ICU4XLocale> ICU4XLocale::new_(std::string_view name) {
   auto result = capi::ICU4XLocale_new(name.data(),
     name.size());
   return std::unique_ptr<ICU4XLocale>(ICU4XLocale::FromFFI(result));
 }
-
 inline const capi::ICU4XLocale* ICU4XLocale::AsFFI() const {
   return reinterpret_cast<const capi::ICU4XLocale*>(this);
 }
@@ -0,0 +1,65 @@
+// generated by diplomat-tool
+
+package example
+
+// #include "diplomat_go_runtime.h"
+// #include "ICU4XDataProvider.h"
+import "C"
+
+import (
+	"runtime"
+)
+
+// An ICU4X data provider, capable of loading ICU4X data keys from some source.
+//
+// See the [Rust documentation for `icu_provider`](https://docs.rs/icu_provider/latest/icu_provider/index.html) for more information.
+type ICU4XDataProvider struct {
+	handle *C.ICU4XDataProvider
+	owned  bool
+	// Objects this may borrow from, kept alive for as long as this is
+	edges []any
+}
+
+// newICU4XDataProvider wraps a handle, which is destroyed by Close or the garbage collector if it is owned
+func newICU4XDataProvider(handle *C.ICU4XDataProvider, owned bool, edges []any) *ICU4XDataProvider {
+	if handle == nil {
+		return nil
+	}
+	self := &ICU4XDataProvider{handle, owned, edges}
+	if owned {
+		runtime.SetFinalizer(self, (*ICU4XDataProvider).Close)
+	}
+	return self
+}
+
+// Close releases the Rust object if this owns it. It must not be used afterwards.
+func (self *ICU4XDataProvider) Close() {
+	if self.owned {
+		self.owned = false
+		runtime.SetFinalizer(self, nil)
+		C.ICU4XDataProvider_destroy(self.handle)
+	}
+}
+
+// toNative returns the handle of an object that may be nil
+func (self *ICU4XDataProvider) toNative() *C.ICU4XDataProvider {
+	if self == nil {
+		return nil
+	}
+	return self.handle
+}
+
+// See the [Rust documentation for `get_static_provider`](https://docs.rs/icu_testdata/latest/icu_testdata/fn.get_static_provider.html) for more information.
+func ICU4XDataProviderStatic() *ICU4XDataProvider {
+	result := C.ICU4XDataProvider_new_static()
+	return newICU4XDataProvider(result, true, nil)
+}
+
+// This exists as a regression test for https://github.com/rust-diplomat/diplomat/issues/155
+func ICU4XDataProviderReturnsResult() error {
+	result := C.ICU4XDataProvider_returns_result()
+	if !result.is_ok {
+		return &DiplomatError{}
+	}
+	return nil
+}
@@ -0,0 +1,76 @@
+// generated by diplomat-tool
+
+package example
+
+// #include "diplomat_go_runtime.h"
+// #include "ICU4XFixedDecimal.h"
+import "C"
+
+import (
+	"runtime"
+)
+
+// See the [Rust documentation for `FixedDecimal`](https://docs.rs/fixed_decimal/latest/fixed_decimal/struct.FixedDecimal.html) for more information.
+type ICU4XFixedDecimal struct {
+	handle *C.ICU4XFixedDecimal
+	owned  bool
+	// Objects this may borrow from, kept alive for as long as this is
+	edges []any
+}
+
+// newICU4XFixedDecimal wraps a handle, which is destroyed by Close or the garbage collector if it is owned
+func newICU4XFixedDecimal(handle *C.ICU4XFixedDecimal, owned bool, edges []any) *ICU4XFixedDecimal {
+	if handle == nil {
+		return nil
+	}
+	self := &ICU4XFixedDecimal{handle, owned, edges}
+	if owned {
+		runtime.SetFinalizer(self, (*ICU4XFixedDecimal).Close)
+	}
+	return self
+}
+
+// Close releases the Rust object if this owns it. It must not be used afterwards.
+func (self *ICU4XFixedDecimal) Close() {
+	if self.owned {
+		self.owned = false
+		runtime.SetFinalizer(self, nil)
+		C.ICU4XFixedDecimal_destroy(self.handle)
+	}
+}
+
+// toNative returns the handle of an object that may be nil
+func (self *ICU4XFixedDecimal) toNative() *C.ICU4XFixedDecimal {
+	if self == nil {
+		return nil
+	}
+	return self.handle
+}
+
+// Construct an [`ICU4XFixedDecimal`] from an integer.
+func NewICU4XFixedDecimal(v int32) *ICU4XFixedDecimal {
+	result := C.ICU4XFixedDecimal_new(C.int32_t(v))
+	return newICU4XFixedDecimal(result, true, nil)
+}
+
+// Multiply the [`ICU4XFixedDecimal`] by a given power of ten.
+//
+// See the [Rust documentation for `multiply_pow10`](https://docs.rs/fixed_decimal/latest/fixed_decimal/struct.FixedDecimal.html#method.multiply_pow10) for more information.
+func (self *ICU4XFixedDecimal) MultiplyPow10(power int16) {
+	C.ICU4XFixedDecimal_multiply_pow10(self.handle, C.int16_t(power))
+	runtime.KeepAlive(self)
+}
+
+// Format the [`ICU4XFixedDecimal`] as a string.
+//
+// See the [Rust documentation for `write_to`](https://docs.rs/fixed_decimal/latest/fixed_decimal/struct.FixedDecimal.html#method.write_to) for more information.
+func (self *ICU4XFixedDecimal) ToString() (string, error) {
+	writeable := newDiplomatWriteable()
+	defer writeable.free()
+	result := C.ICU4XFixedDecimal_to_string(self.handle, writeable.handle)
+	runtime.KeepAlive(self)
+	if !result.is_ok {
+		return "", &DiplomatError{}
+	}
+	return writeable.String(), nil
+}
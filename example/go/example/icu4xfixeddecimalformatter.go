@@ -0,0 +1,78 @@
+// generated by diplomat-tool
+
+package example
+
+// #include "diplomat_go_runtime.h"
+// #include "ICU4XFixedDecimalFormatter.h"
+import "C"
+
+import (
+	"runtime"
+)
+
+// An ICU4X Fixed Decimal Format object, capable of formatting a [`ICU4XFixedDecimal`] as a string.
+//
+// See the [Rust documentation for `FixedDecimalFormatter`](https://docs.rs/icu/latest/icu/decimal/struct.FixedDecimalFormatter.html) for more information.
+type ICU4XFixedDecimalFormatter struct {
+	handle *C.ICU4XFixedDecimalFormatter
+	owned  bool
+	// Objects this may borrow from, kept alive for as long as this is
+	edges []any
+}
+
+// newICU4XFixedDecimalFormatter wraps a handle, which is destroyed by Close or the garbage collector if it is owned
+func newICU4XFixedDecimalFormatter(handle *C.ICU4XFixedDecimalFormatter, owned bool, edges []any) *ICU4XFixedDecimalFormatter {
+	if handle == nil {
+		return nil
+	}
+	self := &ICU4XFixedDecimalFormatter{handle, owned, edges}
+	if owned {
+		runtime.SetFinalizer(self, (*ICU4XFixedDecimalFormatter).Close)
+	}
+	return self
+}
+
+// Close releases the Rust object if this owns it. It must not be used afterwards.
+func (self *ICU4XFixedDecimalFormatter) Close() {
+	if self.owned {
+		self.owned = false
+		runtime.SetFinalizer(self, nil)
+		C.ICU4XFixedDecimalFormatter_destroy(self.handle)
+	}
+}
+
+// toNative returns the handle of an object that may be nil
+func (self *ICU4XFixedDecimalFormatter) toNative() *C.ICU4XFixedDecimalFormatter {
+	if self == nil {
+		return nil
+	}
+	return self.handle
+}
+
+// Creates a new [`ICU4XFixedDecimalFormatter`] from locale data.
+//
+// See the [Rust documentation for `try_new`](https://docs.rs/icu/latest/icu/decimal/struct.FixedDecimalFormatter.html#method.try_new) for more information.
+func ICU4XFixedDecimalFormatterTryNew(locale *ICU4XLocale, provider *ICU4XDataProvider, options ICU4XFixedDecimalFormatterOptions) (*ICU4XFixedDecimalFormatter, error) {
+	keep := &diplomatKeep{}
+	defer keep.free()
+	result := C.ICU4XFixedDecimalFormatter_try_new(locale.handle, provider.handle, options.toNative(keep))
+	runtime.KeepAlive(locale)
+	runtime.KeepAlive(provider)
+	if !result.is_ok {
+		return nil, &DiplomatError{}
+	}
+	ok := diplomatResultValue[*C.ICU4XFixedDecimalFormatter](&result)
+	return newICU4XFixedDecimalFormatter(ok, true, nil), nil
+}
+
+// Formats a [`ICU4XFixedDecimal`] to a string.
+//
+// See the [Rust documentation for `format`](https://docs.rs/icu/latest/icu/decimal/struct.FixedDecimalFormatter.html#method.format) for more information.
+func (self *ICU4XFixedDecimalFormatter) FormatWrite(value *ICU4XFixedDecimal) string {
+	writeable := newDiplomatWriteable()
+	defer writeable.free()
+	C.ICU4XFixedDecimalFormatter_format_write(self.handle, value.handle, writeable.handle)
+	runtime.KeepAlive(self)
+	runtime.KeepAlive(value)
+	return writeable.String()
+}
@@ -0,0 +1,31 @@
+// generated by diplomat-tool
+
+package example
+
+// #include "diplomat_go_runtime.h"
+// #include "ICU4XFixedDecimalFormatterOptions.h"
+import "C"
+
+type ICU4XFixedDecimalFormatterOptions struct {
+	GroupingStrategy ICU4XFixedDecimalGroupingStrategy
+	SomeOtherConfig  bool
+}
+
+func newICU4XFixedDecimalFormatterOptions(native C.ICU4XFixedDecimalFormatterOptions, edges []any) ICU4XFixedDecimalFormatterOptions {
+	return ICU4XFixedDecimalFormatterOptions{
+		GroupingStrategy: ICU4XFixedDecimalGroupingStrategy(native.grouping_strategy),
+		SomeOtherConfig:  bool(native.some_other_config),
+	}
+}
+
+func (self ICU4XFixedDecimalFormatterOptions) toNative(keep *diplomatKeep) C.ICU4XFixedDecimalFormatterOptions {
+	var native C.ICU4XFixedDecimalFormatterOptions
+	native.grouping_strategy = C.ICU4XFixedDecimalGroupingStrategy(self.GroupingStrategy)
+	native.some_other_config = C.bool(self.SomeOtherConfig)
+	return native
+}
+
+func NewICU4XFixedDecimalFormatterOptions() ICU4XFixedDecimalFormatterOptions {
+	result := C.ICU4XFixedDecimalFormatterOptions_default()
+	return newICU4XFixedDecimalFormatterOptions(result, nil)
+}
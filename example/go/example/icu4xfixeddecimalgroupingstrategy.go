@@ -0,0 +1,20 @@
+// generated by diplomat-tool
+
+package example
+
+// #include "diplomat_go_runtime.h"
+// #include "ICU4XFixedDecimalGroupingStrategy.h"
+import "C"
+
+type ICU4XFixedDecimalGroupingStrategy int32
+
+const (
+	// Auto grouping
+	ICU4XFixedDecimalGroupingStrategyAuto ICU4XFixedDecimalGroupingStrategy = 0
+	// No grouping
+	ICU4XFixedDecimalGroupingStrategyNever ICU4XFixedDecimalGroupingStrategy = 1
+	// Always group
+	ICU4XFixedDecimalGroupingStrategyAlways ICU4XFixedDecimalGroupingStrategy = 2
+	// At least 2 groups
+	ICU4XFixedDecimalGroupingStrategyMin2 ICU4XFixedDecimalGroupingStrategy = 3
+)
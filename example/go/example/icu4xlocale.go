@@ -0,0 +1,57 @@
+// generated by diplomat-tool
+
+package example
+
+// #include "diplomat_go_runtime.h"
+// #include "ICU4XLocale.h"
+import "C"
+
+import (
+	"runtime"
+)
+
+// An ICU4X Locale, capable of representing strings like `"en-US"`.
+//
+// See the [Rust documentation for `Locale`](https://docs.rs/icu/latest/icu/locid/struct.Locale.html) for more information.
+type ICU4XLocale struct {
+	handle *C.ICU4XLocale
+	owned  bool
+	// Objects this may borrow from, kept alive for as long as this is
+	edges []any
+}
+
+// newICU4XLocale wraps a handle, which is destroyed by Close or the garbage collector if it is owned
+func newICU4XLocale(handle *C.ICU4XLocale, owned bool, edges []any) *ICU4XLocale {
+	if handle == nil {
+		return nil
+	}
+	self := &ICU4XLocale{handle, owned, edges}
+	if owned {
+		runtime.SetFinalizer(self, (*ICU4XLocale).Close)
+	}
+	return self
+}
+
+// Close releases the Rust object if this owns it. It must not be used afterwards.
+func (self *ICU4XLocale) Close() {
+	if self.owned {
+		self.owned = false
+		runtime.SetFinalizer(self, nil)
+		C.ICU4XLocale_destroy(self.handle)
+	}
+}
+
+// toNative returns the handle of an object that may be nil
+func (self *ICU4XLocale) toNative() *C.ICU4XLocale {
+	if self == nil {
+		return nil
+	}
+	return self.handle
+}
+
+// Construct an [`ICU4XLocale`] from a locale identifier represented as a string.
+func NewICU4XLocale(name string) *ICU4XLocale {
+	nameData, nameLen := diplomatStrRef(name)
+	result := C.ICU4XLocale_new(nameData, nameLen)
+	return newICU4XLocale(result, true, nil)
+}
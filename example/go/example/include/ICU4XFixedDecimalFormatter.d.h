@@ -0,0 +1,24 @@
+#ifndef ICU4XFixedDecimalFormatter_D_H
+#define ICU4XFixedDecimalFormatter_D_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+#ifdef __cplusplus
+namespace capi {
+extern "C" {
+#endif // __cplusplus
+
+
+typedef struct ICU4XFixedDecimalFormatter ICU4XFixedDecimalFormatter;
+
+
+#ifdef __cplusplus
+} // extern "C"
+} // namespace capi
+#endif // __cplusplus
+
+#endif // ICU4XFixedDecimalFormatter_D_H
@@ -0,0 +1,24 @@
+#ifndef ICU4XLocale_D_H
+#define ICU4XLocale_D_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+#ifdef __cplusplus
+namespace capi {
+extern "C" {
+#endif // __cplusplus
+
+
+typedef struct ICU4XLocale ICU4XLocale;
+
+
+#ifdef __cplusplus
+} // extern "C"
+} // namespace capi
+#endif // __cplusplus
+
+#endif // ICU4XLocale_D_H
@@ -0,0 +1,193 @@
+// generated by diplomat-tool
+
+package example
+
+// #cgo CFLAGS: -I${SRCDIR}/include
+// #cgo LDFLAGS: -ldiplomat_example
+// #include <stdlib.h>
+// #include "diplomat_go_runtime.h"
+//
+// // Rust expects a dangling, aligned pointer for empty slices
+// static const uint64_t diplomat_empty_slice = 0;
+import "C"
+
+import (
+	"fmt"
+	"runtime"
+	"unicode/utf16"
+	"unsafe"
+)
+
+// DiplomatError is returned when a Rust method fails. Value holds the error
+// Rust returned, if it returned one.
+type DiplomatError struct {
+	Value any
+}
+
+func (e *DiplomatError) Error() string {
+	if e.Value == nil {
+		return "diplomat: Rust method failed"
+	}
+	return fmt.Sprintf("diplomat: Rust method failed: %v", e.Value)
+}
+
+// diplomatKeep owns memory Rust borrows
+type diplomatKeep struct {
+	allocations []unsafe.Pointer
+}
+
+// newDiplomatKeep creates a keep for memory that returned values borrow,
+// which is freed once it is garbage collected
+func newDiplomatKeep() *diplomatKeep {
+	keep := &diplomatKeep{}
+	runtime.SetFinalizer(keep, (*diplomatKeep).free)
+	return keep
+}
+
+func (keep *diplomatKeep) free() {
+	for _, allocation := range keep.allocations {
+		C.free(allocation)
+	}
+	keep.allocations = nil
+}
+
+// diplomatAlloc allocates memory in keep. If keep is nil, the memory is owned
+// by Rust, so it is allocated with Rust's allocator.
+func diplomatAlloc(size, align uintptr, keep *diplomatKeep) unsafe.Pointer {
+	if keep == nil {
+		return unsafe.Pointer(C.diplomat_alloc(C.size_t(size), C.size_t(align)))
+	}
+	allocation := C.malloc(C.size_t(size))
+	keep.allocations = append(keep.allocations, allocation)
+	return allocation
+}
+
+func diplomatEmpty() unsafe.Pointer {
+	return unsafe.Pointer(&C.diplomat_empty_slice)
+}
+
+// diplomatSlice copies values into memory Rust can read as a slice of U,
+// which must have the same layout as T
+func diplomatSlice[U, T any](values []T, keep *diplomatKeep) (*U, C.size_t) {
+	if len(values) == 0 {
+		return (*U)(diplomatEmpty()), 0
+	}
+	data := diplomatAlloc(unsafe.Sizeof(values[0])*uintptr(len(values)), unsafe.Alignof(values[0]), keep)
+	copy(unsafe.Slice((*T)(data), len(values)), values)
+	return (*U)(data), C.size_t(len(values))
+}
+
+// diplomatSliceRef lets Rust use values as a slice of U for the duration of a
+// call, without copying them
+func diplomatSliceRef[U, T any](values []T) (*U, C.size_t) {
+	if len(values) == 0 {
+		return (*U)(diplomatEmpty()), 0
+	}
+	return (*U)(unsafe.Pointer(unsafe.SliceData(values))), C.size_t(len(values))
+}
+
+func diplomatStr(value string, keep *diplomatKeep) (*C.char, C.size_t) {
+	if len(value) == 0 {
+		return (*C.char)(diplomatEmpty()), 0
+	}
+	data := diplomatAlloc(uintptr(len(value)), 1, keep)
+	copy(unsafe.Slice((*byte)(data), len(value)), value)
+	return (*C.char)(data), C.size_t(len(value))
+}
+
+// diplomatStrRef lets Rust read a string for the duration of a call, without
+// copying it
+func diplomatStrRef(value string) (*C.char, C.size_t) {
+	if len(value) == 0 {
+		return (*C.char)(diplomatEmpty()), 0
+	}
+	return (*C.char)(unsafe.Pointer(unsafe.StringData(value))), C.size_t(len(value))
+}
+
+func diplomatStr16(value string, keep *diplomatKeep) (*C.char16_t, C.size_t) {
+	return diplomatSlice[C.char16_t](utf16.Encode([]rune(value)), keep)
+}
+
+func diplomatStr16Ref(value string) (*C.char16_t, C.size_t) {
+	return diplomatSliceRef[C.char16_t](utf16.Encode([]rune(value)))
+}
+
+// diplomatStrs copies a list of strings, which always lives in C memory
+// since it holds pointers
+func diplomatStrs(values []string, keep *diplomatKeep) (*C.DiplomatStrs8View, C.size_t) {
+	views := make([]C.DiplomatStrs8View, len(values))
+	for i, value := range values {
+		views[i].data, views[i].len = diplomatStr(value, keep)
+	}
+	return diplomatSlice[C.DiplomatStrs8View](views, keep)
+}
+
+func diplomatStrs16(values []string, keep *diplomatKeep) (*C.DiplomatStrs16View, C.size_t) {
+	views := make([]C.DiplomatStrs16View, len(values))
+	for i, value := range values {
+		views[i].data, views[i].len = diplomatStr16(value, keep)
+	}
+	return diplomatSlice[C.DiplomatStrs16View](views, keep)
+}
+
+// diplomatRead copies a slice returned by Rust into values of type T, freeing
+// it if it is owned
+func diplomatRead[T, U any](data *U, n C.size_t, owned bool) []T {
+	if data == nil || n == 0 {
+		return []T{}
+	}
+	values := make([]T, n)
+	copy(values, unsafe.Slice((*T)(unsafe.Pointer(data)), n))
+	if owned {
+		diplomatFree(unsafe.Pointer(data), uintptr(n)*unsafe.Sizeof(*data), unsafe.Alignof(*data))
+	}
+	return values
+}
+
+func diplomatReadStr(data *C.char, n C.size_t, owned bool) string {
+	if data == nil || n == 0 {
+		return ""
+	}
+	value := C.GoStringN(data, C.int(n))
+	if owned {
+		diplomatFree(unsafe.Pointer(data), uintptr(n), 1)
+	}
+	return value
+}
+
+func diplomatReadStr16(data *C.char16_t, n C.size_t, owned bool) string {
+	return string(utf16.Decode(diplomatRead[uint16](data, n, owned)))
+}
+
+func diplomatFree(data unsafe.Pointer, size, align uintptr) {
+	C.diplomat_free((*C.uint8_t)(data), C.size_t(size), C.size_t(align))
+}
+
+// diplomatResultValue reads the value of a result, which is a union at its start
+func diplomatResultValue[T, R any](result *R) T {
+	return *(*T)(unsafe.Pointer(result))
+}
+
+// diplomatWriteable is a buffer in Rust's memory that methods returning
+// strings write into
+type diplomatWriteable struct {
+	handle *C.DiplomatWriteable
+}
+
+func newDiplomatWriteable() diplomatWriteable {
+	return diplomatWriteable{C.diplomat_buffer_writeable_create(0)}
+}
+
+func (writeable diplomatWriteable) free() {
+	C.diplomat_buffer_writeable_destroy(writeable.handle)
+}
+
+// String decodes what was written to the buffer
+func (writeable diplomatWriteable) String() string {
+	n := C.diplomat_buffer_writeable_len(writeable.handle)
+	if n == 0 {
+		return ""
+	}
+	bytes := C.diplomat_buffer_writeable_get_bytes(writeable.handle)
+	return C.GoStringN((*C.char)(unsafe.Pointer(bytes)), C.int(n))
+}
@@ -0,0 +1,52 @@
+// generated by diplomat-tool
+@file:Suppress("unused", "UNUSED_PARAMETER", "UNUSED_VARIABLE", "FunctionName", "ClassName")
+
+package dev.diplomat.example
+
+import com.sun.jna.Pointer
+import com.sun.jna.Structure
+import com.sun.jna.Union
+import java.lang.ref.Reference
+
+/**
+ * An ICU4X data provider, capable of loading ICU4X data keys from some source.
+ *
+ * See the [Rust documentation for `icu_provider`](https://docs.rs/icu_provider/latest/icu_provider/index.html) for more information.
+ */
+class ICU4XDataProvider internal constructor(
+    internal val handle: Pointer,
+    owned: Boolean,
+    /** Objects this may borrow from, kept alive for as long as this is */
+    internal val edges: List<Any>,
+) : AutoCloseable {
+    private val cleanable = if (owned) DiplomatRuntime.CLEANER.register(this, Destroy(handle)) else null
+
+    private class Destroy(val handle: Pointer) : Runnable {
+        override fun run() = lib.ICU4XDataProvider_destroy(handle)
+    }
+
+    /** Releases the Rust object if this owns it. It must not be used afterwards. */
+    override fun close() {
+        cleanable?.clean()
+    }
+
+    companion object {
+        /** See the [Rust documentation for `get_static_provider`](https://docs.rs/icu_testdata/latest/icu_testdata/fn.get_static_provider.html) for more information. */
+        fun static(): ICU4XDataProvider {
+            val result = lib.ICU4XDataProvider_new_static()
+            return ICU4XDataProvider(result!!, true, listOf())
+        }
+
+        /**
+         * This exists as a regression test for https://github.com/rust-diplomat/diplomat/issues/155
+         *
+         * @throws DiplomatException on failure
+         */
+        fun returnsResult() {
+            val result = lib.ICU4XDataProvider_returns_result()
+            if (result.isOk == 0.toByte()) {
+                throw DiplomatException(null)
+            }
+        }
+    }
+}
@@ -0,0 +1,63 @@
+// generated by diplomat-tool
+@file:Suppress("unused", "UNUSED_PARAMETER", "UNUSED_VARIABLE", "FunctionName", "ClassName")
+
+package dev.diplomat.example
+
+import com.sun.jna.Pointer
+import com.sun.jna.Structure
+import com.sun.jna.Union
+import java.lang.ref.Reference
+
+/** See the [Rust documentation for `FixedDecimal`](https://docs.rs/fixed_decimal/latest/fixed_decimal/struct.FixedDecimal.html) for more information. */
+class ICU4XFixedDecimal internal constructor(
+    internal val handle: Pointer,
+    owned: Boolean,
+    /** Objects this may borrow from, kept alive for as long as this is */
+    internal val edges: List<Any>,
+) : AutoCloseable {
+    private val cleanable = if (owned) DiplomatRuntime.CLEANER.register(this, Destroy(handle)) else null
+
+    private class Destroy(val handle: Pointer) : Runnable {
+        override fun run() = lib.ICU4XFixedDecimal_destroy(handle)
+    }
+
+    /** Releases the Rust object if this owns it. It must not be used afterwards. */
+    override fun close() {
+        cleanable?.clean()
+    }
+
+    /**
+     * Multiply the [`ICU4XFixedDecimal`] by a given power of ten.
+     *
+     * See the [Rust documentation for `multiply_pow10`](https://docs.rs/fixed_decimal/latest/fixed_decimal/struct.FixedDecimal.html#method.multiply_pow10) for more information.
+     */
+    fun multiplyPow10(power: Short) {
+        lib.ICU4XFixedDecimal_multiply_pow10(handle, power)
+        Reference.reachabilityFence(this)
+    }
+
+    /**
+     * Format the [`ICU4XFixedDecimal`] as a string.
+     *
+     * See the [Rust documentation for `write_to`](https://docs.rs/fixed_decimal/latest/fixed_decimal/struct.FixedDecimal.html#method.write_to) for more information.
+     *
+     * @throws DiplomatException on failure
+     */
+    fun toString(): String {
+        val writeable = DiplomatWriteable()
+        val result = lib.ICU4XFixedDecimal_to_string(handle, writeable.handle)
+        Reference.reachabilityFence(this)
+        if (result.isOk == 0.toByte()) {
+            throw DiplomatException(null)
+        }
+        return writeable.finalizeString()
+    }
+
+    companion object {
+        /** Construct an [`ICU4XFixedDecimal`] from an integer. */
+        operator fun invoke(v: Int): ICU4XFixedDecimal {
+            val result = lib.ICU4XFixedDecimal_new(v)
+            return ICU4XFixedDecimal(result!!, true, listOf())
+        }
+    }
+}
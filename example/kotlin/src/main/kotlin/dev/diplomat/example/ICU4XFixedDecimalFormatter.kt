@@ -0,0 +1,66 @@
+// generated by diplomat-tool
+@file:Suppress("unused", "UNUSED_PARAMETER", "UNUSED_VARIABLE", "FunctionName", "ClassName")
+
+package dev.diplomat.example
+
+import com.sun.jna.Pointer
+import com.sun.jna.Structure
+import com.sun.jna.Union
+import java.lang.ref.Reference
+
+/**
+ * An ICU4X Fixed Decimal Format object, capable of formatting a [`ICU4XFixedDecimal`] as a string.
+ *
+ * See the [Rust documentation for `FixedDecimalFormatter`](https://docs.rs/icu/latest/icu/decimal/struct.FixedDecimalFormatter.html) for more information.
+ */
+class ICU4XFixedDecimalFormatter internal constructor(
+    internal val handle: Pointer,
+    owned: Boolean,
+    /** Objects this may borrow from, kept alive for as long as this is */
+    internal val edges: List<Any>,
+) : AutoCloseable {
+    private val cleanable = if (owned) DiplomatRuntime.CLEANER.register(this, Destroy(handle)) else null
+
+    private class Destroy(val handle: Pointer) : Runnable {
+        override fun run() = lib.ICU4XFixedDecimalFormatter_destroy(handle)
+    }
+
+    /** Releases the Rust object if this owns it. It must not be used afterwards. */
+    override fun close() {
+        cleanable?.clean()
+    }
+
+    /**
+     * Formats a [`ICU4XFixedDecimal`] to a string.
+     *
+     * See the [Rust documentation for `format`](https://docs.rs/icu/latest/icu/decimal/struct.FixedDecimalFormatter.html#method.format) for more information.
+     */
+    fun formatWrite(value: ICU4XFixedDecimal): String {
+        val writeable = DiplomatWriteable()
+        lib.ICU4XFixedDecimalFormatter_format_write(handle, value.handle, writeable.handle)
+        Reference.reachabilityFence(this)
+        Reference.reachabilityFence(value)
+        return writeable.finalizeString()
+    }
+
+    companion object {
+        /**
+         * Creates a new [`ICU4XFixedDecimalFormatter`] from locale data.
+         *
+         * See the [Rust documentation for `try_new`](https://docs.rs/icu/latest/icu/decimal/struct.FixedDecimalFormatter.html#method.try_new) for more information.
+         *
+         * @throws DiplomatException on failure
+         */
+        fun tryNew(locale: ICU4XLocale, provider: ICU4XDataProvider, options: ICU4XFixedDecimalFormatterOptions): ICU4XFixedDecimalFormatter {
+            val keep = mutableListOf<Any>()
+            val result = lib.ICU4XFixedDecimalFormatter_try_new(locale.handle, provider.handle, options.toNative(keep))
+            Reference.reachabilityFence(locale)
+            Reference.reachabilityFence(provider)
+            Reference.reachabilityFence(keep)
+            if (result.isOk == 0.toByte()) {
+                throw DiplomatException(null)
+            }
+            return ICU4XFixedDecimalFormatter((result.union.readField("ok") as Pointer?)!!, true, listOf())
+        }
+    }
+}
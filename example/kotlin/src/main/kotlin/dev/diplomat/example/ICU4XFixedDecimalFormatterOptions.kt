@@ -0,0 +1,39 @@
+// generated by diplomat-tool
+@file:Suppress("unused", "UNUSED_PARAMETER", "UNUSED_VARIABLE", "FunctionName", "ClassName")
+
+package dev.diplomat.example
+
+import com.sun.jna.Pointer
+import com.sun.jna.Structure
+import com.sun.jna.Union
+import java.lang.ref.Reference
+
+data class ICU4XFixedDecimalFormatterOptions(
+    var groupingStrategy: ICU4XFixedDecimalGroupingStrategy,
+    var someOtherConfig: Boolean,
+) {
+    internal fun toNative(keep: MutableList<Any>): ICU4XFixedDecimalFormatterOptionsNative.ByValue {
+        val native = ICU4XFixedDecimalFormatterOptionsNative.ByValue()
+        native.groupingStrategy = this.groupingStrategy.value
+        native.someOtherConfig = (if (this.someOtherConfig) 1 else 0).toByte()
+        return native
+    }
+
+    companion object {
+        internal fun fromNative(native: ICU4XFixedDecimalFormatterOptionsNative, edges: List<Any>): ICU4XFixedDecimalFormatterOptions = ICU4XFixedDecimalFormatterOptions(ICU4XFixedDecimalGroupingStrategy.fromNative(native.groupingStrategy), (native.someOtherConfig != 0.toByte()))
+
+        operator fun invoke(): ICU4XFixedDecimalFormatterOptions {
+            val result = lib.ICU4XFixedDecimalFormatterOptions_default()
+            return ICU4XFixedDecimalFormatterOptions.fromNative(result, listOf())
+        }
+    }
+}
+
+internal open class ICU4XFixedDecimalFormatterOptionsNative : Structure() {
+    @JvmField var groupingStrategy: Int = 0
+    @JvmField var someOtherConfig: Byte = 0
+
+    override fun getFieldOrder() = listOf("groupingStrategy", "someOtherConfig")
+
+    class ByValue : ICU4XFixedDecimalFormatterOptionsNative(), Structure.ByValue
+}
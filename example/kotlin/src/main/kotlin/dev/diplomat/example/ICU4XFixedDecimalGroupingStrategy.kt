@@ -0,0 +1,24 @@
+// generated by diplomat-tool
+@file:Suppress("unused", "UNUSED_PARAMETER", "UNUSED_VARIABLE", "FunctionName", "ClassName")
+
+package dev.diplomat.example
+
+import com.sun.jna.Pointer
+import com.sun.jna.Structure
+import com.sun.jna.Union
+import java.lang.ref.Reference
+
+enum class ICU4XFixedDecimalGroupingStrategy(internal val value: Int) {
+    /** Auto grouping */
+    AUTO(0),
+    /** No grouping */
+    NEVER(1),
+    /** Always group */
+    ALWAYS(2),
+    /** At least 2 groups */
+    MIN2(3);
+
+    companion object {
+        internal fun fromNative(native: Int): ICU4XFixedDecimalGroupingStrategy = values().first { it.value == native }
+    }
+}
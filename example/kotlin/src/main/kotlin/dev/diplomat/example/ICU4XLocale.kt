@@ -0,0 +1,43 @@
+// generated by diplomat-tool
+@file:Suppress("unused", "UNUSED_PARAMETER", "UNUSED_VARIABLE", "FunctionName", "ClassName")
+
+package dev.diplomat.example
+
+import com.sun.jna.Pointer
+import com.sun.jna.Structure
+import com.sun.jna.Union
+import java.lang.ref.Reference
+
+/**
+ * An ICU4X Locale, capable of representing strings like `"en-US"`.
+ *
+ * See the [Rust documentation for `Locale`](https://docs.rs/icu/latest/icu/locid/struct.Locale.html) for more information.
+ */
+class ICU4XLocale internal constructor(
+    internal val handle: Pointer,
+    owned: Boolean,
+    /** Objects this may borrow from, kept alive for as long as this is */
+    internal val edges: List<Any>,
+) : AutoCloseable {
+    private val cleanable = if (owned) DiplomatRuntime.CLEANER.register(this, Destroy(handle)) else null
+
+    private class Destroy(val handle: Pointer) : Runnable {
+        override fun run() = lib.ICU4XLocale_destroy(handle)
+    }
+
+    /** Releases the Rust object if this owns it. It must not be used afterwards. */
+    override fun close() {
+        cleanable?.clean()
+    }
+
+    companion object {
+        /** Construct an [`ICU4XLocale`] from a locale identifier represented as a string. */
+        operator fun invoke(name: String): ICU4XLocale {
+            val keep = mutableListOf<Any>()
+            val nameSlice = DiplomatRuntime.str8(name, keep)
+            val result = lib.ICU4XLocale_new(nameSlice.data, nameSlice.len)
+            Reference.reachabilityFence(keep)
+            return ICU4XLocale(result!!, true, listOf())
+        }
+    }
+}
@@ -0,0 +1,69 @@
+// generated by diplomat-tool
+@file:Suppress("unused", "UNUSED_PARAMETER", "UNUSED_VARIABLE", "FunctionName", "ClassName")
+
+package dev.diplomat.example
+
+import com.sun.jna.Library
+import com.sun.jna.Native
+import com.sun.jna.Pointer
+import com.sun.jna.Structure
+import com.sun.jna.Union
+import java.lang.ref.Reference
+
+/** The functions exported by the native library */
+internal interface DiplomatLib : Library {
+    fun diplomat_alloc(size: SizeT, align: SizeT): Pointer?
+    fun diplomat_free(ptr: Pointer?, size: SizeT, align: SizeT)
+    fun diplomat_buffer_writeable_create(cap: SizeT): Pointer?
+    fun diplomat_buffer_writeable_get_bytes(writeable: Pointer?): Pointer?
+    fun diplomat_buffer_writeable_len(writeable: Pointer?): SizeT
+    fun diplomat_buffer_writeable_destroy(writeable: Pointer?)
+    // Only looked up if called, which needs the `catch_panics` feature of the runtime
+    fun diplomat_panicked(): Byte
+    fun diplomat_take_panic(writeable: Pointer?)
+    fun ICU4XFixedDecimalFormatterOptions_default(): ICU4XFixedDecimalFormatterOptionsNative.ByValue
+    fun ICU4XDataProvider_destroy(self: Pointer?)
+    fun ICU4XDataProvider_new_static(): Pointer?
+    fun ICU4XDataProvider_returns_result(): ResultVoidVoidNative.ByValue
+    fun ICU4XFixedDecimalFormatter_destroy(self: Pointer?)
+    fun ICU4XFixedDecimalFormatter_try_new(locale: Pointer?, provider: Pointer?, options: ICU4XFixedDecimalFormatterOptionsNative.ByValue): ResultBoxIcu4xFixedDecimalFormatterVoidNative.ByValue
+    fun ICU4XFixedDecimalFormatter_format_write(self: Pointer?, value: Pointer?, writeable: Pointer?)
+    fun ICU4XFixedDecimal_destroy(self: Pointer?)
+    fun ICU4XFixedDecimal_new(v: Int): Pointer?
+    fun ICU4XFixedDecimal_multiply_pow10(self: Pointer?, power: Short)
+    fun ICU4XFixedDecimal_to_string(self: Pointer?, writeable: Pointer?): ResultVoidVoidNative.ByValue
+    fun ICU4XLocale_destroy(self: Pointer?)
+    fun ICU4XLocale_new(nameData: Pointer?, nameLen: SizeT): Pointer?
+}
+
+/**
+ * The native library, loaded on first use.
+ *
+ * The library name can be overridden with the `diplomat.library` system property.
+ */
+internal val lib: DiplomatLib by lazy {
+    Native.load(System.getProperty("diplomat.library", "diplomat_example"), DiplomatLib::class.java)
+}
+
+internal open class ResultBoxIcu4xFixedDecimalFormatterVoidNative : Structure() {
+    @JvmField var union: ResultBoxIcu4xFixedDecimalFormatterVoidNativeUnion = ResultBoxIcu4xFixedDecimalFormatterVoidNativeUnion()
+    @JvmField var isOk: Byte = 0
+
+    override fun getFieldOrder() = listOf("union", "isOk")
+
+    class ByValue : ResultBoxIcu4xFixedDecimalFormatterVoidNative(), Structure.ByValue
+}
+
+internal class ResultBoxIcu4xFixedDecimalFormatterVoidNativeUnion : Union() {
+    @JvmField var ok: Pointer? = null
+
+    override fun getFieldOrder() = listOf("ok")
+}
+
+internal open class ResultVoidVoidNative : Structure() {
+    @JvmField var isOk: Byte = 0
+
+    override fun getFieldOrder() = listOf("isOk")
+
+    class ByValue : ResultVoidVoidNative(), Structure.ByValue
+}
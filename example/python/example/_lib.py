@@ -0,0 +1,273 @@
+# generated by diplomat-tool
+
+from __future__ import annotations
+
+import ctypes
+import enum
+import typing
+
+from . import _runtime
+
+__all__ = [
+    "ICU4XFixedDecimalFormatterOptions",
+    "ICU4XDataProvider",
+    "ICU4XFixedDecimalFormatter",
+    "ICU4XFixedDecimal",
+    "ICU4XLocale",
+    "ICU4XFixedDecimalGroupingStrategy",
+]
+
+
+class _ICU4XFixedDecimalFormatterOptionsFfi(ctypes.Structure):
+    pass
+
+
+class _diplomat_result_box_ICU4XFixedDecimalFormatter_void(ctypes.Structure):
+    pass
+
+
+class _diplomat_result_void_void(ctypes.Structure):
+    pass
+
+# By-value fields need complete types, so fields are assigned in dependency order
+_ICU4XFixedDecimalFormatterOptionsFfi._fields_ = [
+    ("grouping_strategy", ctypes.c_int32),
+    ("some_other_config", ctypes.c_bool),
+]
+_diplomat_result_box_ICU4XFixedDecimalFormatter_voidUnion = _runtime.union_type([ctypes.c_void_p])
+_diplomat_result_box_ICU4XFixedDecimalFormatter_void._fields_ = [
+    ("union", _diplomat_result_box_ICU4XFixedDecimalFormatter_voidUnion),
+    ("is_ok", ctypes.c_bool),
+]
+_diplomat_result_void_void._fields_ = [
+    ("is_ok", ctypes.c_bool),
+]
+
+
+class ICU4XFixedDecimalFormatterOptions:
+
+    @classmethod
+    def _from_ffi(cls, ffi, edges):
+        self = object.__new__(cls)
+        self.grouping_strategy = ICU4XFixedDecimalGroupingStrategy(ffi.grouping_strategy)
+        self.some_other_config = ffi.some_other_config
+        return self
+
+    def _to_ffi(self, keep):
+        return _ICU4XFixedDecimalFormatterOptionsFfi(
+            int(self.grouping_strategy),
+            self.some_other_config,
+        )
+
+    def __eq__(self, other):
+        if not isinstance(other, ICU4XFixedDecimalFormatterOptions):
+            return NotImplemented
+        return self.grouping_strategy == other.grouping_strategy and self.some_other_config == other.some_other_config
+
+    def __repr__(self):
+        return f"ICU4XFixedDecimalFormatterOptions(grouping_strategy={self.grouping_strategy!r}, some_other_config={self.some_other_config!r})"
+
+    def __new__(cls, grouping_strategy: ICU4XFixedDecimalGroupingStrategy = None, some_other_config: bool = None) -> ICU4XFixedDecimalFormatterOptions:
+        result = _runtime.lib().ICU4XFixedDecimalFormatterOptions_default()
+        self = ICU4XFixedDecimalFormatterOptions._from_ffi(result, [])
+        if grouping_strategy is not None:
+            self.grouping_strategy = grouping_strategy
+        if some_other_config is not None:
+            self.some_other_config = some_other_config
+        return self
+
+
+class ICU4XDataProvider:
+    """An ICU4X data provider, capable of loading ICU4X data keys from some source.
+
+    See the [Rust documentation for `icu_provider`](https://docs.rs/icu_provider/latest/icu_provider/index.html) for more information.
+    """
+
+    def __init__(self, *args, **kwargs):
+        raise TypeError("ICU4XDataProvider cannot be constructed directly")
+
+    @classmethod
+    def _from_ffi(cls, ffi, owned, edges):
+        self = object.__new__(cls)
+        self._ffi = ffi
+        self._owned = owned
+        # Objects this may borrow from, kept alive for as long as this is
+        self._edges = edges
+        return self
+
+    def __del__(self):
+        if getattr(self, "_owned", False):
+            _runtime.lib().ICU4XDataProvider_destroy(self._ffi)
+
+    @staticmethod
+    def static() -> ICU4XDataProvider:
+        """See the [Rust documentation for `get_static_provider`](https://docs.rs/icu_testdata/latest/icu_testdata/fn.get_static_provider.html) for more information."""
+        result = _runtime.lib().ICU4XDataProvider_new_static()
+        return ICU4XDataProvider._from_ffi(result, True, [])
+
+    @staticmethod
+    def returns_result() -> None:
+        """This exists as a regression test for https://github.com/rust-diplomat/diplomat/issues/155
+
+        Raises `DiplomatError` on failure.
+        """
+        result = _runtime.lib().ICU4XDataProvider_returns_result()
+        if not result.is_ok:
+            raise _runtime.DiplomatError()
+
+
+class ICU4XFixedDecimalFormatter:
+    """An ICU4X Fixed Decimal Format object, capable of formatting a [`ICU4XFixedDecimal`] as a string.
+
+    See the [Rust documentation for `FixedDecimalFormatter`](https://docs.rs/icu/latest/icu/decimal/struct.FixedDecimalFormatter.html) for more information.
+    """
+
+    def __init__(self, *args, **kwargs):
+        raise TypeError("ICU4XFixedDecimalFormatter cannot be constructed directly")
+
+    @classmethod
+    def _from_ffi(cls, ffi, owned, edges):
+        self = object.__new__(cls)
+        self._ffi = ffi
+        self._owned = owned
+        # Objects this may borrow from, kept alive for as long as this is
+        self._edges = edges
+        return self
+
+    def __del__(self):
+        if getattr(self, "_owned", False):
+            _runtime.lib().ICU4XFixedDecimalFormatter_destroy(self._ffi)
+
+    @staticmethod
+    def try_new(locale: ICU4XLocale, provider: ICU4XDataProvider, options: ICU4XFixedDecimalFormatterOptions) -> ICU4XFixedDecimalFormatter:
+        """Creates a new [`ICU4XFixedDecimalFormatter`] from locale data.
+
+        See the [Rust documentation for `try_new`](https://docs.rs/icu/latest/icu/decimal/struct.FixedDecimalFormatter.html#method.try_new) for more information.
+
+        Raises `DiplomatError` on failure.
+        """
+        keep = []
+        result = _runtime.lib().ICU4XFixedDecimalFormatter_try_new(locale._ffi, provider._ffi, options._to_ffi(keep))
+        if not result.is_ok:
+            raise _runtime.DiplomatError()
+        return ICU4XFixedDecimalFormatter._from_ffi(_runtime.read_union(result.union, ctypes.c_void_p), True, [])
+
+    def format_write(self, value: ICU4XFixedDecimal) -> str:
+        """Formats a [`ICU4XFixedDecimal`] to a string.
+
+        See the [Rust documentation for `format`](https://docs.rs/icu/latest/icu/decimal/struct.FixedDecimalFormatter.html#method.format) for more information.
+        """
+        writeable = _runtime.Writeable()
+        _runtime.lib().ICU4XFixedDecimalFormatter_format_write(self._ffi, value._ffi, writeable.ffi)
+        return writeable.finalize()
+
+
+class ICU4XFixedDecimal:
+    """See the [Rust documentation for `FixedDecimal`](https://docs.rs/fixed_decimal/latest/fixed_decimal/struct.FixedDecimal.html) for more information."""
+
+    @classmethod
+    def _from_ffi(cls, ffi, owned, edges):
+        self = object.__new__(cls)
+        self._ffi = ffi
+        self._owned = owned
+        # Objects this may borrow from, kept alive for as long as this is
+        self._edges = edges
+        return self
+
+    def __del__(self):
+        if getattr(self, "_owned", False):
+            _runtime.lib().ICU4XFixedDecimal_destroy(self._ffi)
+
+    def __new__(cls, v: int) -> ICU4XFixedDecimal:
+        """Construct an [`ICU4XFixedDecimal`] from an integer."""
+        result = _runtime.lib().ICU4XFixedDecimal_new(v)
+        return ICU4XFixedDecimal._from_ffi(result, True, [])
+
+    def multiply_pow10(self, power: int) -> None:
+        """Multiply the [`ICU4XFixedDecimal`] by a given power of ten.
+
+        See the [Rust documentation for `multiply_pow10`](https://docs.rs/fixed_decimal/latest/fixed_decimal/struct.FixedDecimal.html#method.multiply_pow10) for more information.
+        """
+        _runtime.lib().ICU4XFixedDecimal_multiply_pow10(self._ffi, power)
+
+    def to_string(self) -> str:
+        """Format the [`ICU4XFixedDecimal`] as a string.
+
+        See the [Rust documentation for `write_to`](https://docs.rs/fixed_decimal/latest/fixed_decimal/struct.FixedDecimal.html#method.write_to) for more information.
+
+        Raises `DiplomatError` on failure.
+        """
+        writeable = _runtime.Writeable()
+        result = _runtime.lib().ICU4XFixedDecimal_to_string(self._ffi, writeable.ffi)
+        if not result.is_ok:
+            raise _runtime.DiplomatError()
+        return writeable.finalize()
+
+
+class ICU4XLocale:
+    """An ICU4X Locale, capable of representing strings like `"en-US"`.
+
+    See the [Rust documentation for `Locale`](https://docs.rs/icu/latest/icu/locid/struct.Locale.html) for more information.
+    """
+
+    @classmethod
+    def _from_ffi(cls, ffi, owned, edges):
+        self = object.__new__(cls)
+        self._ffi = ffi
+        self._owned = owned
+        # Objects this may borrow from, kept alive for as long as this is
+        self._edges = edges
+        return self
+
+    def __del__(self):
+        if getattr(self, "_owned", False):
+            _runtime.lib().ICU4XLocale_destroy(self._ffi)
+
+    def __new__(cls, name: str) -> ICU4XLocale:
+        """Construct an [`ICU4XLocale`] from a locale identifier represented as a string."""
+        keep = []
+        result = _runtime.lib().ICU4XLocale_new(*_runtime.str8(name, keep))
+        return ICU4XLocale._from_ffi(result, True, [])
+
+
+class ICU4XFixedDecimalGroupingStrategy(enum.IntEnum):
+    AUTO = 0
+    """Auto grouping"""
+    NEVER = 1
+    """No grouping"""
+    ALWAYS = 2
+    """Always group"""
+    MIN2 = 3
+    """At least 2 groups"""
+
+
+def _declare(lib):
+    lib.ICU4XFixedDecimalFormatterOptions_default.argtypes = []
+    lib.ICU4XFixedDecimalFormatterOptions_default.restype = _ICU4XFixedDecimalFormatterOptionsFfi
+    lib.ICU4XDataProvider_new_static.argtypes = []
+    lib.ICU4XDataProvider_new_static.restype = ctypes.c_void_p
+    lib.ICU4XDataProvider_returns_result.argtypes = []
+    lib.ICU4XDataProvider_returns_result.restype = _diplomat_result_void_void
+    lib.ICU4XDataProvider_destroy.argtypes = [ctypes.c_void_p]
+    lib.ICU4XDataProvider_destroy.restype = None
+    lib.ICU4XFixedDecimalFormatter_try_new.argtypes = [ctypes.c_void_p, ctypes.c_void_p, _ICU4XFixedDecimalFormatterOptionsFfi]
+    lib.ICU4XFixedDecimalFormatter_try_new.restype = _diplomat_result_box_ICU4XFixedDecimalFormatter_void
+    lib.ICU4XFixedDecimalFormatter_format_write.argtypes = [ctypes.c_void_p, ctypes.c_void_p, ctypes.c_void_p]
+    lib.ICU4XFixedDecimalFormatter_format_write.restype = None
+    lib.ICU4XFixedDecimalFormatter_destroy.argtypes = [ctypes.c_void_p]
+    lib.ICU4XFixedDecimalFormatter_destroy.restype = None
+    lib.ICU4XFixedDecimal_new.argtypes = [ctypes.c_int32]
+    lib.ICU4XFixedDecimal_new.restype = ctypes.c_void_p
+    lib.ICU4XFixedDecimal_multiply_pow10.argtypes = [ctypes.c_void_p, ctypes.c_int16]
+    lib.ICU4XFixedDecimal_multiply_pow10.restype = None
+    lib.ICU4XFixedDecimal_to_string.argtypes = [ctypes.c_void_p, ctypes.c_void_p]
+    lib.ICU4XFixedDecimal_to_string.restype = _diplomat_result_void_void
+    lib.ICU4XFixedDecimal_destroy.argtypes = [ctypes.c_void_p]
+    lib.ICU4XFixedDecimal_destroy.restype = None
+    lib.ICU4XLocale_new.argtypes = [ctypes.POINTER(ctypes.c_uint8), ctypes.c_size_t]
+    lib.ICU4XLocale_new.restype = ctypes.c_void_p
+    lib.ICU4XLocale_destroy.argtypes = [ctypes.c_void_p]
+    lib.ICU4XLocale_destroy.restype = None
+
+
+_runtime.register_prototypes(_declare)
\ No newline at end of file
@@ -0,0 +1,60 @@
+// generated by diplomat-tool
+
+// The bindings follow the API of the library rather than Rust's conventions
+#![allow(
+    clippy::inherent_to_string,
+    clippy::needless_lifetimes,
+    clippy::new_without_default,
+    clippy::result_unit_err,
+    clippy::should_implement_trait
+)]
+
+#[allow(unused_imports)]
+use super::diplomat_runtime::*;
+#[allow(unused_imports)]
+use super::*;
+
+/// An ICU4X data provider, capable of loading ICU4X data keys from some source.
+///
+/// See the [Rust documentation for `icu_provider`](https://docs.rs/icu_provider/latest/icu_provider/index.html) for more information.
+#[repr(C)]
+pub struct ICU4XDataProvider {
+    _data: [u8; 0],
+    _marker: std::marker::PhantomData<(*mut u8, std::marker::PhantomPinned)>,
+}
+
+impl std::fmt::Debug for ICU4XDataProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("ICU4XDataProvider")
+    }
+}
+
+unsafe impl DiplomatOpaque for ICU4XDataProvider {
+    unsafe fn destroy(this: *mut Self) {
+        ICU4XDataProvider_destroy(this)
+    }
+}
+
+impl ICU4XDataProvider {
+    /// See the [Rust documentation for `get_static_provider`](https://docs.rs/icu_testdata/latest/icu_testdata/fn.get_static_provider.html) for more information.
+    pub fn new_static() -> DiplomatBox<ICU4XDataProvider> {
+        unsafe {
+            ICU4XDataProvider_new_static()
+        }
+    }
+
+    /// This exists as a regression test for https://github.com/rust-diplomat/diplomat/issues/155
+    pub fn returns_result() -> Result<(), ()> {
+        unsafe {
+            ICU4XDataProvider_returns_result().into_result()
+        }
+    }
+}
+
+#[link(name = "diplomat_example")]
+#[allow(improper_ctypes, non_snake_case)]
+extern "C" {
+    fn ICU4XDataProvider_destroy(this: *mut ICU4XDataProvider);
+    fn ICU4XDataProvider_new_static() -> DiplomatBox<ICU4XDataProvider>;
+    fn ICU4XDataProvider_returns_result() -> DiplomatResult<(), ()>;
+}
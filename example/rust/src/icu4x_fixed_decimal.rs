@@ -0,0 +1,71 @@
+// generated by diplomat-tool
+
+// The bindings follow the API of the library rather than Rust's conventions
+#![allow(
+    clippy::inherent_to_string,
+    clippy::needless_lifetimes,
+    clippy::new_without_default,
+    clippy::result_unit_err,
+    clippy::should_implement_trait
+)]
+
+#[allow(unused_imports)]
+use super::diplomat_runtime::*;
+#[allow(unused_imports)]
+use super::*;
+
+/// See the [Rust documentation for `FixedDecimal`](https://docs.rs/fixed_decimal/latest/fixed_decimal/struct.FixedDecimal.html) for more information.
+#[repr(C)]
+pub struct ICU4XFixedDecimal {
+    _data: [u8; 0],
+    _marker: std::marker::PhantomData<(*mut u8, std::marker::PhantomPinned)>,
+}
+
+impl std::fmt::Debug for ICU4XFixedDecimal {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("ICU4XFixedDecimal")
+    }
+}
+
+unsafe impl DiplomatOpaque for ICU4XFixedDecimal {
+    unsafe fn destroy(this: *mut Self) {
+        ICU4XFixedDecimal_destroy(this)
+    }
+}
+
+impl ICU4XFixedDecimal {
+    /// Construct an [`ICU4XFixedDecimal`] from an integer.
+    pub fn new(v: i32) -> DiplomatBox<ICU4XFixedDecimal> {
+        unsafe {
+            ICU4XFixedDecimal_new(v)
+        }
+    }
+
+    /// Multiply the [`ICU4XFixedDecimal`] by a given power of ten.
+    ///
+    /// See the [Rust documentation for `multiply_pow10`](https://docs.rs/fixed_decimal/latest/fixed_decimal/struct.FixedDecimal.html#method.multiply_pow10) for more information.
+    pub fn multiply_pow10<'anon_0>(&'anon_0 mut self, power: i16) {
+        unsafe {
+            ICU4XFixedDecimal_multiply_pow10(self, power)
+        }
+    }
+
+    /// Format the [`ICU4XFixedDecimal`] as a string.
+    ///
+    /// See the [Rust documentation for `write_to`](https://docs.rs/fixed_decimal/latest/fixed_decimal/struct.FixedDecimal.html#method.write_to) for more information.
+    pub fn to_string<'anon_0>(&'anon_0 self) -> Result<String, ()> {
+        unsafe {
+            let mut diplomat_write = DiplomatWriteable::new();
+            ICU4XFixedDecimal_to_string(self, &mut diplomat_write).into_result().map(|()| diplomat_write.into_string())
+        }
+    }
+}
+
+#[link(name = "diplomat_example")]
+#[allow(improper_ctypes, non_snake_case)]
+extern "C" {
+    fn ICU4XFixedDecimal_destroy(this: *mut ICU4XFixedDecimal);
+    fn ICU4XFixedDecimal_new(v: i32) -> DiplomatBox<ICU4XFixedDecimal>;
+    fn ICU4XFixedDecimal_multiply_pow10<'anon_0>(this: &'anon_0 mut ICU4XFixedDecimal, power: i16);
+    fn ICU4XFixedDecimal_to_string<'anon_0>(this: &'anon_0 ICU4XFixedDecimal, write: &mut DiplomatWriteable) -> DiplomatResult<(), ()>;
+}
@@ -0,0 +1,66 @@
+// generated by diplomat-tool
+
+// The bindings follow the API of the library rather than Rust's conventions
+#![allow(
+    clippy::inherent_to_string,
+    clippy::needless_lifetimes,
+    clippy::new_without_default,
+    clippy::result_unit_err,
+    clippy::should_implement_trait
+)]
+
+#[allow(unused_imports)]
+use super::diplomat_runtime::*;
+#[allow(unused_imports)]
+use super::*;
+
+/// An ICU4X Fixed Decimal Format object, capable of formatting a [`ICU4XFixedDecimal`] as a string.
+///
+/// See the [Rust documentation for `FixedDecimalFormatter`](https://docs.rs/icu/latest/icu/decimal/struct.FixedDecimalFormatter.html) for more information.
+#[repr(C)]
+pub struct ICU4XFixedDecimalFormatter {
+    _data: [u8; 0],
+    _marker: std::marker::PhantomData<(*mut u8, std::marker::PhantomPinned)>,
+}
+
+impl std::fmt::Debug for ICU4XFixedDecimalFormatter {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("ICU4XFixedDecimalFormatter")
+    }
+}
+
+unsafe impl DiplomatOpaque for ICU4XFixedDecimalFormatter {
+    unsafe fn destroy(this: *mut Self) {
+        ICU4XFixedDecimalFormatter_destroy(this)
+    }
+}
+
+impl ICU4XFixedDecimalFormatter {
+    /// Creates a new [`ICU4XFixedDecimalFormatter`] from locale data.
+    ///
+    /// See the [Rust documentation for `try_new`](https://docs.rs/icu/latest/icu/decimal/struct.FixedDecimalFormatter.html#method.try_new) for more information.
+    pub fn try_new<'anon_0, 'anon_1>(locale: &'anon_0 ICU4XLocale, provider: &'anon_1 ICU4XDataProvider, options: ICU4XFixedDecimalFormatterOptions) -> Result<DiplomatBox<ICU4XFixedDecimalFormatter>, ()> {
+        unsafe {
+            ICU4XFixedDecimalFormatter_try_new(locale, provider, options).into_result()
+        }
+    }
+
+    /// Formats a [`ICU4XFixedDecimal`] to a string.
+    ///
+    /// See the [Rust documentation for `format`](https://docs.rs/icu/latest/icu/decimal/struct.FixedDecimalFormatter.html#method.format) for more information.
+    pub fn format_write<'anon_0, 'anon_1>(&'anon_0 self, value: &'anon_1 ICU4XFixedDecimal) -> String {
+        unsafe {
+            let mut diplomat_write = DiplomatWriteable::new();
+            ICU4XFixedDecimalFormatter_format_write(self, value, &mut diplomat_write);
+            diplomat_write.into_string()
+        }
+    }
+}
+
+#[link(name = "diplomat_example")]
+#[allow(improper_ctypes, non_snake_case)]
+extern "C" {
+    fn ICU4XFixedDecimalFormatter_destroy(this: *mut ICU4XFixedDecimalFormatter);
+    fn ICU4XFixedDecimalFormatter_try_new<'anon_0, 'anon_1>(locale: &'anon_0 ICU4XLocale, provider: &'anon_1 ICU4XDataProvider, options: ICU4XFixedDecimalFormatterOptions) -> DiplomatResult<DiplomatBox<ICU4XFixedDecimalFormatter>, ()>;
+    fn ICU4XFixedDecimalFormatter_format_write<'anon_0, 'anon_1>(this: &'anon_0 ICU4XFixedDecimalFormatter, value: &'anon_1 ICU4XFixedDecimal, write: &mut DiplomatWriteable);
+}
@@ -0,0 +1,36 @@
+// generated by diplomat-tool
+
+// The bindings follow the API of the library rather than Rust's conventions
+#![allow(
+    clippy::inherent_to_string,
+    clippy::needless_lifetimes,
+    clippy::new_without_default,
+    clippy::result_unit_err,
+    clippy::should_implement_trait
+)]
+
+#[allow(unused_imports)]
+use super::diplomat_runtime::*;
+#[allow(unused_imports)]
+use super::*;
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ICU4XFixedDecimalFormatterOptions {
+    pub grouping_strategy: ICU4XFixedDecimalGroupingStrategy,
+    pub some_other_config: bool,
+}
+
+impl ICU4XFixedDecimalFormatterOptions {
+    pub fn default() -> ICU4XFixedDecimalFormatterOptions {
+        unsafe {
+            ICU4XFixedDecimalFormatterOptions_default()
+        }
+    }
+}
+
+#[link(name = "diplomat_example")]
+#[allow(improper_ctypes, non_snake_case)]
+extern "C" {
+    fn ICU4XFixedDecimalFormatterOptions_default() -> ICU4XFixedDecimalFormatterOptions;
+}
@@ -0,0 +1,28 @@
+// generated by diplomat-tool
+
+// The bindings follow the API of the library rather than Rust's conventions
+#![allow(
+    clippy::inherent_to_string,
+    clippy::needless_lifetimes,
+    clippy::new_without_default,
+    clippy::result_unit_err,
+    clippy::should_implement_trait
+)]
+
+#[allow(unused_imports)]
+use super::diplomat_runtime::*;
+#[allow(unused_imports)]
+use super::*;
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ICU4XFixedDecimalGroupingStrategy {
+    /// Auto grouping
+    Auto = 0,
+    /// No grouping
+    Never = 1,
+    /// Always group
+    Always = 2,
+    /// At least 2 groups
+    Min2 = 3,
+}
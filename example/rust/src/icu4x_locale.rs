@@ -0,0 +1,52 @@
+// generated by diplomat-tool
+
+// The bindings follow the API of the library rather than Rust's conventions
+#![allow(
+    clippy::inherent_to_string,
+    clippy::needless_lifetimes,
+    clippy::new_without_default,
+    clippy::result_unit_err,
+    clippy::should_implement_trait
+)]
+
+#[allow(unused_imports)]
+use super::diplomat_runtime::*;
+#[allow(unused_imports)]
+use super::*;
+
+/// An ICU4X Locale, capable of representing strings like `"en-US"`.
+///
+/// See the [Rust documentation for `Locale`](https://docs.rs/icu/latest/icu/locid/struct.Locale.html) for more information.
+#[repr(C)]
+pub struct ICU4XLocale {
+    _data: [u8; 0],
+    _marker: std::marker::PhantomData<(*mut u8, std::marker::PhantomPinned)>,
+}
+
+impl std::fmt::Debug for ICU4XLocale {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("ICU4XLocale")
+    }
+}
+
+unsafe impl DiplomatOpaque for ICU4XLocale {
+    unsafe fn destroy(this: *mut Self) {
+        ICU4XLocale_destroy(this)
+    }
+}
+
+impl ICU4XLocale {
+    /// Construct an [`ICU4XLocale`] from a locale identifier represented as a string.
+    pub fn new<'anon_0>(name: &'anon_0 [u8]) -> DiplomatBox<ICU4XLocale> {
+        unsafe {
+            ICU4XLocale_new(name.as_ptr(), name.len())
+        }
+    }
+}
+
+#[link(name = "diplomat_example")]
+#[allow(improper_ctypes, non_snake_case)]
+extern "C" {
+    fn ICU4XLocale_destroy(this: *mut ICU4XLocale);
+    fn ICU4XLocale_new<'anon_0>(name_diplomat_data: *const u8, name_diplomat_len: usize) -> DiplomatBox<ICU4XLocale>;
+}
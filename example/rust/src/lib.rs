@@ -0,0 +1,17 @@
+// generated by diplomat-tool
+
+mod diplomat_runtime;
+mod icu4x_data_provider;
+mod icu4x_fixed_decimal;
+mod icu4x_fixed_decimal_formatter;
+mod icu4x_fixed_decimal_formatter_options;
+mod icu4x_fixed_decimal_grouping_strategy;
+mod icu4x_locale;
+
+pub use diplomat_runtime::{DiplomatBox, DiplomatChar, DiplomatOpaque};
+pub use icu4x_data_provider::ICU4XDataProvider;
+pub use icu4x_fixed_decimal::ICU4XFixedDecimal;
+pub use icu4x_fixed_decimal_formatter::ICU4XFixedDecimalFormatter;
+pub use icu4x_fixed_decimal_formatter_options::ICU4XFixedDecimalFormatterOptions;
+pub use icu4x_fixed_decimal_grouping_strategy::ICU4XFixedDecimalGroupingStrategy;
+pub use icu4x_locale::ICU4XLocale;
@@ -0,0 +1,135 @@
+// generated by diplomat-tool
+
+import DiplomatExampleFFI
+
+/// Thrown when a Rust method returns an error, which is available as `error`.
+public struct DiplomatError: Error {
+    public let error: Any?
+}
+
+/// Memory Rust borrows for the duration of a call.
+///
+/// Values passed to Rust are copied into memory owned by this, which is freed
+/// once it is deinitialized.
+final class DiplomatKeep {
+    private var allocations: [UnsafeMutableRawPointer] = []
+
+    deinit {
+        for allocation in allocations {
+            allocation.deallocate()
+        }
+    }
+
+    fileprivate func alloc<T>(_ count: Int, as type: T.Type) -> UnsafeMutablePointer<T> {
+        let memory = UnsafeMutableRawPointer.allocate(
+            byteCount: MemoryLayout<T>.stride * count,
+            alignment: MemoryLayout<T>.alignment
+        )
+        allocations.append(memory)
+        return memory.bindMemory(to: T.self, capacity: count)
+    }
+}
+
+/// A buffer in Rust's memory that methods returning strings write into
+final class DiplomatWriteableBuffer {
+    let handle: UnsafeMutablePointer<DiplomatWriteable>
+
+    init() {
+        handle = diplomat_buffer_writeable_create(0)
+    }
+
+    deinit {
+        diplomat_buffer_writeable_destroy(handle)
+    }
+
+    /// Decodes what was written to the buffer
+    func finalizeString() -> String {
+        let len = diplomat_buffer_writeable_len(handle)
+        guard len > 0, let bytes = diplomat_buffer_writeable_get_bytes(handle) else {
+            return ""
+        }
+        return String(decoding: UnsafeBufferPointer(start: bytes, count: len), as: UTF8.self)
+    }
+}
+
+enum DiplomatRuntime {
+    /// Copies `values` into memory Rust can read as a slice of `U`, which
+    /// must have the same layout as `T`.
+    ///
+    /// Borrowed slices live in `keep`. If `keep` is nil, the slice is owned by
+    /// Rust, so it is allocated with Rust's allocator.
+    static func slice<T, U>(_ values: [T], as type: U.Type, _ keep: DiplomatKeep?) -> (data: UnsafeMutablePointer<U>, len: Int) {
+        if values.isEmpty {
+            // Rust expects a dangling, aligned pointer for empty slices
+            return (UnsafeMutablePointer(bitPattern: MemoryLayout<U>.alignment)!, 0)
+        }
+        let data: UnsafeMutablePointer<U>
+        if let keep = keep {
+            data = keep.alloc(values.count, as: U.self)
+        } else {
+            data = UnsafeMutableRawPointer(diplomat_alloc(MemoryLayout<U>.stride * values.count, MemoryLayout<U>.alignment)!)
+                .bindMemory(to: U.self, capacity: values.count)
+        }
+        values.withUnsafeBytes { bytes in
+            UnsafeMutableRawPointer(data).copyMemory(from: bytes.baseAddress!, byteCount: bytes.count)
+        }
+        return (data, values.count)
+    }
+
+    static func str8(_ value: String, _ keep: DiplomatKeep?) -> (data: UnsafeMutablePointer<CChar>, len: Int) {
+        return slice(Array(value.utf8), as: CChar.self, keep)
+    }
+
+    static func str16(_ value: String, _ keep: DiplomatKeep?) -> (data: UnsafeMutablePointer<UInt16>, len: Int) {
+        return slice(Array(value.utf16), as: UInt16.self, keep)
+    }
+
+    static func strs8(_ values: [String], _ keep: DiplomatKeep) -> (data: UnsafeMutablePointer<DiplomatStrs8View>, len: Int) {
+        let views = values.map { value -> DiplomatStrs8View in
+            let str = str8(value, keep)
+            return DiplomatStrs8View(data: str.data, len: str.len)
+        }
+        return slice(views, as: DiplomatStrs8View.self, keep)
+    }
+
+    static func strs16(_ values: [String], _ keep: DiplomatKeep) -> (data: UnsafeMutablePointer<DiplomatStrs16View>, len: Int) {
+        let views = values.map { value -> DiplomatStrs16View in
+            let str = str16(value, keep)
+            return DiplomatStrs16View(data: str.data, len: str.len)
+        }
+        return slice(views, as: DiplomatStrs16View.self, keep)
+    }
+
+    /// Copies what Rust wrote into a mutable slice back into the array it was created from
+    static func copyBack<T, U>(_ slice: (data: UnsafeMutablePointer<U>, len: Int), _ values: inout [T]) {
+        values.withUnsafeMutableBytes { bytes in
+            if let base = bytes.baseAddress {
+                base.copyMemory(from: slice.data, byteCount: bytes.count)
+            }
+        }
+    }
+
+    /// Reads a slice returned by Rust as values of type `T`, freeing it if it is owned
+    static func read<T, U>(_ data: UnsafePointer<U>?, _ len: Int, _ owned: Bool, as type: T.Type) -> [T] {
+        guard let data = data, len > 0 else {
+            return []
+        }
+        let values = Array(UnsafeBufferPointer(start: UnsafeRawPointer(data).assumingMemoryBound(to: T.self), count: len))
+        if owned {
+            diplomat_free(
+                UnsafeMutableRawPointer(mutating: data).assumingMemoryBound(to: UInt8.self),
+                MemoryLayout<U>.stride * len,
+                MemoryLayout<U>.alignment
+            )
+        }
+        return values
+    }
+
+    static func readStr8(_ data: UnsafePointer<CChar>?, _ len: Int, _ owned: Bool) -> String {
+        return String(decoding: read(data, len, owned, as: UInt8.self), as: UTF8.self)
+    }
+
+    static func readStr16(_ data: UnsafePointer<UInt16>?, _ len: Int, _ owned: Bool) -> String {
+        return String(decoding: read(data, len, owned, as: UInt16.self), as: UTF16.self)
+    }
+}
@@ -0,0 +1,41 @@
+// generated by diplomat-tool
+
+import DiplomatExampleFFI
+
+/// An ICU4X data provider, capable of loading ICU4X data keys from some source.
+///
+/// See the [Rust documentation for `icu_provider`](https://docs.rs/icu_provider/latest/icu_provider/index.html) for more information.
+public final class ICU4XDataProvider {
+    let handle: OpaquePointer
+    private let owned: Bool
+    /// Objects this may borrow from, kept alive for as long as this is
+    let edges: [Any]
+
+    init(handle: OpaquePointer, owned: Bool, edges: [Any]) {
+        self.handle = handle
+        self.owned = owned
+        self.edges = edges
+    }
+
+    deinit {
+        if owned {
+            ICU4XDataProvider_destroy(handle)
+        }
+    }
+
+    /// See the [Rust documentation for `get_static_provider`](https://docs.rs/icu_testdata/latest/icu_testdata/fn.get_static_provider.html) for more information.
+    public static func `static`() -> ICU4XDataProvider {
+        let result = ICU4XDataProvider_new_static()
+        return ICU4XDataProvider(handle: result, owned: true, edges: [])
+    }
+
+    /// This exists as a regression test for https://github.com/rust-diplomat/diplomat/issues/155
+    ///
+    /// - Throws: `DiplomatError` on failure
+    public static func returnsResult() throws {
+        let result = ICU4XDataProvider_returns_result()
+        if !result.is_ok {
+            throw DiplomatError(error: nil)
+        }
+    }
+}
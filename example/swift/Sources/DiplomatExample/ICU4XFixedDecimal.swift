@@ -0,0 +1,50 @@
+// generated by diplomat-tool
+
+import DiplomatExampleFFI
+
+/// See the [Rust documentation for `FixedDecimal`](https://docs.rs/fixed_decimal/latest/fixed_decimal/struct.FixedDecimal.html) for more information.
+public final class ICU4XFixedDecimal {
+    let handle: OpaquePointer
+    private let owned: Bool
+    /// Objects this may borrow from, kept alive for as long as this is
+    let edges: [Any]
+
+    init(handle: OpaquePointer, owned: Bool, edges: [Any]) {
+        self.handle = handle
+        self.owned = owned
+        self.edges = edges
+    }
+
+    deinit {
+        if owned {
+            ICU4XFixedDecimal_destroy(handle)
+        }
+    }
+
+    /// Construct an [`ICU4XFixedDecimal`] from an integer.
+    public convenience init(v: Int32) {
+        let result = ICU4XFixedDecimal_new(v)
+        self.init(handle: result, owned: true, edges: [])
+    }
+
+    /// Multiply the [`ICU4XFixedDecimal`] by a given power of ten.
+    ///
+    /// See the [Rust documentation for `multiply_pow10`](https://docs.rs/fixed_decimal/latest/fixed_decimal/struct.FixedDecimal.html#method.multiply_pow10) for more information.
+    public func multiplyPow10(power: Int16) {
+        ICU4XFixedDecimal_multiply_pow10(handle, power)
+    }
+
+    /// Format the [`ICU4XFixedDecimal`] as a string.
+    ///
+    /// See the [Rust documentation for `write_to`](https://docs.rs/fixed_decimal/latest/fixed_decimal/struct.FixedDecimal.html#method.write_to) for more information.
+    ///
+    /// - Throws: `DiplomatError` on failure
+    public func toString() throws -> String {
+        let writeable = DiplomatWriteableBuffer()
+        let result = ICU4XFixedDecimal_to_string(handle, writeable.handle)
+        if !result.is_ok {
+            throw DiplomatError(error: nil)
+        }
+        return writeable.finalizeString()
+    }
+}
@@ -0,0 +1,48 @@
+// generated by diplomat-tool
+
+import DiplomatExampleFFI
+
+/// An ICU4X Fixed Decimal Format object, capable of formatting a [`ICU4XFixedDecimal`] as a string.
+///
+/// See the [Rust documentation for `FixedDecimalFormatter`](https://docs.rs/icu/latest/icu/decimal/struct.FixedDecimalFormatter.html) for more information.
+public final class ICU4XFixedDecimalFormatter {
+    let handle: OpaquePointer
+    private let owned: Bool
+    /// Objects this may borrow from, kept alive for as long as this is
+    let edges: [Any]
+
+    init(handle: OpaquePointer, owned: Bool, edges: [Any]) {
+        self.handle = handle
+        self.owned = owned
+        self.edges = edges
+    }
+
+    deinit {
+        if owned {
+            ICU4XFixedDecimalFormatter_destroy(handle)
+        }
+    }
+
+    /// Creates a new [`ICU4XFixedDecimalFormatter`] from locale data.
+    ///
+    /// See the [Rust documentation for `try_new`](https://docs.rs/icu/latest/icu/decimal/struct.FixedDecimalFormatter.html#method.try_new) for more information.
+    ///
+    /// - Throws: `DiplomatError` on failure
+    public static func tryNew(locale: ICU4XLocale, provider: ICU4XDataProvider, options: ICU4XFixedDecimalFormatterOptions) throws -> ICU4XFixedDecimalFormatter {
+        let keep = DiplomatKeep()
+        let result = withExtendedLifetime(keep) { ICU4XFixedDecimalFormatter_try_new(locale.handle, provider.handle, options.toNative(keep)) }
+        if !result.is_ok {
+            throw DiplomatError(error: nil)
+        }
+        return ICU4XFixedDecimalFormatter(handle: result.ok, owned: true, edges: [])
+    }
+
+    /// Formats a [`ICU4XFixedDecimal`] to a string.
+    ///
+    /// See the [Rust documentation for `format`](https://docs.rs/icu/latest/icu/decimal/struct.FixedDecimalFormatter.html#method.format) for more information.
+    public func formatWrite(value: ICU4XFixedDecimal) -> String {
+        let writeable = DiplomatWriteableBuffer()
+        ICU4XFixedDecimalFormatter_format_write(handle, value.handle, writeable.handle)
+        return writeable.finalizeString()
+    }
+}
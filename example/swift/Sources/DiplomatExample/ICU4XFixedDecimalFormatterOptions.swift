@@ -0,0 +1,29 @@
+// generated by diplomat-tool
+
+import DiplomatExampleFFI
+
+public struct ICU4XFixedDecimalFormatterOptions {
+    public var groupingStrategy: ICU4XFixedDecimalGroupingStrategy
+    public var someOtherConfig: Bool
+
+    public init(groupingStrategy: ICU4XFixedDecimalGroupingStrategy, someOtherConfig: Bool) {
+        self.groupingStrategy = groupingStrategy
+        self.someOtherConfig = someOtherConfig
+    }
+
+    init(native: DiplomatExampleFFI.ICU4XFixedDecimalFormatterOptions, edges: [Any]) {
+        self.init(groupingStrategy: ICU4XFixedDecimalGroupingStrategy(native: native.grouping_strategy), someOtherConfig: native.some_other_config)
+    }
+
+    func toNative(_ keep: DiplomatKeep) -> DiplomatExampleFFI.ICU4XFixedDecimalFormatterOptions {
+        var native = DiplomatExampleFFI.ICU4XFixedDecimalFormatterOptions()
+        native.grouping_strategy = self.groupingStrategy.toNative()
+        native.some_other_config = self.someOtherConfig
+        return native
+    }
+
+    public init() {
+        let result = ICU4XFixedDecimalFormatterOptions_default()
+        self = ICU4XFixedDecimalFormatterOptions(native: result, edges: [])
+    }
+}
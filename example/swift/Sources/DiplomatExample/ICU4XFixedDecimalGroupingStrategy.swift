@@ -0,0 +1,22 @@
+// generated by diplomat-tool
+
+import DiplomatExampleFFI
+
+public enum ICU4XFixedDecimalGroupingStrategy: Int32 {
+    /// Auto grouping
+    case auto = 0
+    /// No grouping
+    case never = 1
+    /// Always group
+    case always = 2
+    /// At least 2 groups
+    case min2 = 3
+
+    init(native: DiplomatExampleFFI.ICU4XFixedDecimalGroupingStrategy) {
+        self.init(rawValue: numericCast(native.rawValue))!
+    }
+
+    func toNative() -> DiplomatExampleFFI.ICU4XFixedDecimalGroupingStrategy {
+        return DiplomatExampleFFI.ICU4XFixedDecimalGroupingStrategy(rawValue: numericCast(rawValue))
+    }
+}
@@ -0,0 +1,33 @@
+// generated by diplomat-tool
+
+import DiplomatExampleFFI
+
+/// An ICU4X Locale, capable of representing strings like `"en-US"`.
+///
+/// See the [Rust documentation for `Locale`](https://docs.rs/icu/latest/icu/locid/struct.Locale.html) for more information.
+public final class ICU4XLocale {
+    let handle: OpaquePointer
+    private let owned: Bool
+    /// Objects this may borrow from, kept alive for as long as this is
+    let edges: [Any]
+
+    init(handle: OpaquePointer, owned: Bool, edges: [Any]) {
+        self.handle = handle
+        self.owned = owned
+        self.edges = edges
+    }
+
+    deinit {
+        if owned {
+            ICU4XLocale_destroy(handle)
+        }
+    }
+
+    /// Construct an [`ICU4XLocale`] from a locale identifier represented as a string.
+    public convenience init(name: String) {
+        let keep = DiplomatKeep()
+        let nameSlice = DiplomatRuntime.str8(name, keep)
+        let result = withExtendedLifetime(keep) { ICU4XLocale_new(nameSlice.data, nameSlice.len) }
+        self.init(handle: result, owned: true, edges: [])
+    }
+}
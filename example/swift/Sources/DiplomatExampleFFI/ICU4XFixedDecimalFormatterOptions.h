@@ -0,0 +1,26 @@
+#ifndef ICU4XFixedDecimalFormatterOptions_H
+#define ICU4XFixedDecimalFormatterOptions_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+#include "ICU4XFixedDecimalFormatterOptions.d.h"
+
+#ifdef __cplusplus
+namespace capi {
+extern "C" {
+#endif // __cplusplus
+
+
+ICU4XFixedDecimalFormatterOptions ICU4XFixedDecimalFormatterOptions_default();
+
+
+#ifdef __cplusplus
+} // extern "C"
+} // namespace capi
+#endif // __cplusplus
+
+#endif // ICU4XFixedDecimalFormatterOptions_H
@@ -0,0 +1,28 @@
+#ifndef DIPLOMAT_SWIFT_RUNTIME_H
+#define DIPLOMAT_SWIFT_RUNTIME_H
+
+#include "diplomat_runtime.h"
+
+// The functions of the Rust runtime the Swift wrappers call directly
+
+uint8_t* diplomat_alloc(size_t size, size_t align);
+void diplomat_free(uint8_t* ptr, size_t size, size_t align);
+
+DiplomatWriteable* diplomat_buffer_writeable_create(size_t cap);
+uint8_t* diplomat_buffer_writeable_get_bytes(DiplomatWriteable* self);
+size_t diplomat_buffer_writeable_len(DiplomatWriteable* self);
+void diplomat_buffer_writeable_destroy(DiplomatWriteable* self);
+
+// Lists of strings are passed as arrays of these
+
+typedef struct DiplomatStrs8View {
+    const char* data;
+    size_t len;
+} DiplomatStrs8View;
+
+typedef struct DiplomatStrs16View {
+    const char16_t* data;
+    size_t len;
+} DiplomatStrs16View;
+
+#endif
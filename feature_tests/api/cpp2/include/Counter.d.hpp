@@ -0,0 +1,41 @@
+#ifndef Counter_D_HPP
+#define Counter_D_HPP
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <optional>
+#include <variant>
+#include "diplomat_runtime.hpp"
+#include "Counter.d.h"
+
+
+class Counter {
+public:
+
+  inline static std::unique_ptr<Counter> new_(uint32_t start);
+
+  inline uint32_t increment();
+
+  inline static uint32_t created();
+
+  inline std::string describe() const;
+
+  inline const capi::Counter* AsFFI() const;
+  inline capi::Counter* AsFFI();
+  inline static const Counter* FromFFI(const capi::Counter* ptr);
+  inline static Counter* FromFFI(capi::Counter* ptr);
+  inline static void operator delete(void* ptr);
+private:
+  Counter() = delete;
+  Counter(const Counter&) = delete;
+  Counter(Counter&&) noexcept = delete;
+  Counter operator=(const Counter&) = delete;
+  Counter operator=(Counter&&) noexcept = delete;
+  static void operator delete[](void*, size_t) = delete;
+};
+
+
+#endif // Counter_D_HPP
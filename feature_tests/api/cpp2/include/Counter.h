@@ -0,0 +1,45 @@
+#ifndef Counter_H
+#define Counter_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+#include "Counter.d.h"
+
+#ifdef __cplusplus
+namespace capi {
+extern "C" {
+#endif // __cplusplus
+
+
+Counter* Counter_new(uint32_t start);
+
+uint32_t Counter_increment(Counter* self);
+
+uint32_t Counter_created();
+
+void Counter_describe(const Counter* self, DiplomatWriteable* writeable);
+
+void Counter_destroy(Counter* self);
+
+
+typedef struct __Counter_API__
+{
+  size_t size;
+  Counter* (*new_)(uint32_t start);
+  uint32_t (*increment)(Counter* self);
+  uint32_t (*created)();
+  void (*describe)(const Counter* self, DiplomatWriteable* writeable);
+  void (*Counter_destroy)(Counter* self);
+} __Counter_API__;
+
+
+#ifdef __cplusplus
+} // extern "C"
+} // namespace capi
+#endif // __cplusplus
+
+#endif // Counter_H
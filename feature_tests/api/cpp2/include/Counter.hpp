@@ -0,0 +1,59 @@
+#ifndef Counter_HPP
+#define Counter_HPP
+
+#include "Counter.d.hpp"
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <optional>
+#include <variant>
+#include "diplomat_runtime.hpp"
+#include "Counter.h"
+#include "FeatureApiLoader.hpp"
+
+
+
+inline std::unique_ptr<Counter> Counter::new_(uint32_t start) {
+  auto result = get_feature_api().Counter->new_(start);
+  return std::unique_ptr<Counter>(Counter::FromFFI(result));
+}
+inline uint32_t Counter::increment() {
+  auto result = get_feature_api().Counter->increment(this->AsFFI());
+  return result;
+}
+inline uint32_t Counter::created() {
+  auto result = get_feature_api().Counter->created();
+  return result;
+}
+inline std::string Counter::describe() const {
+  std::string output;
+  capi::DiplomatWriteable writeable = diplomat::WriteableFromString(output);
+  get_feature_api().Counter->describe(this->AsFFI(),
+    &writeable);
+  return output;
+}
+inline const capi::Counter* Counter::AsFFI() const {
+  return reinterpret_cast<const capi::Counter*>(this);
+}
+
+inline capi::Counter* Counter::AsFFI() {
+  return reinterpret_cast<capi::Counter*>(this);
+}
+
+inline const Counter* Counter::FromFFI(const capi::Counter* ptr) {
+  return reinterpret_cast<const Counter*>(ptr);
+}
+
+inline Counter* Counter::FromFFI(capi::Counter* ptr) {
+  return reinterpret_cast<Counter*>(ptr);
+}
+
+inline void Counter::operator delete(void* ptr) {
+  get_feature_api().Counter->Counter_destroy(reinterpret_cast<capi::Counter*>(ptr));
+}
+
+
+#endif // Counter_HPP
@@ -0,0 +1,150 @@
+#ifndef FeatureApiLoader_HPP
+#define FeatureApiLoader_HPP
+
+#include <atomic>
+#include <cstddef>
+#include <filesystem>
+#include <stdexcept>
+#include <string>
+#include <vector>
+#ifdef _WIN32
+#include <windows.h>
+#else
+#include <dlfcn.h>
+#include <unistd.h>
+#endif
+#include "api.h"
+
+/// Loads the library that implements FeatureApi at runtime, and loads it again
+/// when it is rebuilt.
+///
+/// The library is copied to a temporary file before it is opened, so that the build
+/// can replace it while it is loaded. Every generated wrapper calls through the table
+/// of the current loader, so after `reload()` every call goes to the new library.
+/// Libraries that were replaced stay open until the loader is destroyed, since objects
+/// they created may still be alive. Those are destroyed through the new library, so
+/// reloading only works as long as the types keep their layout.
+class FeatureApiLoader {
+public:
+	/// Open the library at `path`, and make this the current loader if there is none yet.
+	/// Throws `std::runtime_error` if it cannot be opened or its table doesn't match api.h.
+	explicit FeatureApiLoader(std::filesystem::path path) : path_(std::move(path)) {
+		reload();
+		FeatureApiLoader* none = nullptr;
+		current_.compare_exchange_strong(none, this);
+	}
+
+	~FeatureApiLoader() {
+		FeatureApiLoader* self = this;
+		current_.compare_exchange_strong(self, nullptr);
+		for (const Library& library : libraries_) {
+#ifdef _WIN32
+			FreeLibrary(static_cast<HMODULE>(library.handle));
+#else
+			dlclose(library.handle);
+#endif
+			std::error_code ignored;
+			std::filesystem::remove(library.copy, ignored);
+		}
+	}
+
+	FeatureApiLoader(const FeatureApiLoader&) = delete;
+	FeatureApiLoader& operator=(const FeatureApiLoader&) = delete;
+
+	/// Open the library again and switch to its table. If it cannot be opened or its table
+	/// doesn't match api.h, this throws `std::runtime_error` and keeps the current table.
+	void reload() {
+#ifdef _WIN32
+		unsigned long pid = GetCurrentProcessId();
+#else
+		long pid = getpid();
+#endif
+		std::filesystem::path copy = std::filesystem::temp_directory_path()
+			/ (path_.stem().string() + "." + std::to_string(pid) + "." + std::to_string(copies_++)
+				+ path_.extension().string());
+		std::filesystem::copy_file(path_, copy, std::filesystem::copy_options::overwrite_existing);
+
+#ifdef _WIN32
+		void* handle = LoadLibraryW(copy.c_str());
+		if (!handle) {
+			std::filesystem::remove(copy);
+			throw std::runtime_error("cannot load " + path_.string() + ": error " + std::to_string(GetLastError()));
+		}
+		auto refresh = reinterpret_cast<const capi::FeatureApi* (*)()>(
+			GetProcAddress(static_cast<HMODULE>(handle), "diplomat_feature_api"));
+#else
+		void* handle = dlopen(copy.c_str(), RTLD_NOW | RTLD_LOCAL);
+		if (!handle) {
+			std::filesystem::remove(copy);
+			throw std::runtime_error("cannot load " + path_.string() + ": " + dlerror());
+		}
+		auto refresh = reinterpret_cast<const capi::FeatureApi* (*)()>(dlsym(handle, "diplomat_feature_api"));
+#endif
+
+		const capi::FeatureApi* table = refresh ? refresh() : nullptr;
+		if (!table || !capi::FeatureApi_is_compatible(table)) {
+#ifdef _WIN32
+			FreeLibrary(static_cast<HMODULE>(handle));
+#else
+			dlclose(handle);
+#endif
+			std::filesystem::remove(copy);
+			throw std::runtime_error(path_.string() + (refresh
+				? " has a FeatureApi table these headers are not compatible with"
+				: " does not export diplomat_feature_api"));
+		}
+
+		libraries_.push_back(Library { handle, copy });
+		table_.store(table);
+	}
+
+	/// The table calls currently go through
+	const capi::FeatureApi& api() const {
+		return *table_.load();
+	}
+
+	/// The path the library is loaded from
+	const std::filesystem::path& path() const {
+		return path_;
+	}
+
+	/// How many times the library was loaded, starting at 1
+	size_t generation() const {
+		return libraries_.size();
+	}
+
+	/// Make the generated wrappers call through the table of this loader
+	void make_current() {
+		current_.store(this);
+	}
+
+	/// The loader the generated wrappers call through.
+	/// Throws `std::logic_error` if no loader was created.
+	static FeatureApiLoader& current() {
+		FeatureApiLoader* loader = current_.load();
+		if (!loader) {
+			throw std::logic_error("FeatureApi is used before a FeatureApiLoader was created");
+		}
+		return *loader;
+	}
+
+private:
+	struct Library {
+		void* handle;
+		std::filesystem::path copy;
+	};
+
+	std::filesystem::path path_;
+	std::vector<Library> libraries_;
+	std::atomic<const capi::FeatureApi*> table_ { nullptr };
+	static inline std::atomic<FeatureApiLoader*> current_ { nullptr };
+	/// Numbers the copies of the library, so that every loader opens its own
+	static inline std::atomic<size_t> copies_ { 0 };
+};
+
+/// The table the generated wrappers call through
+inline const capi::FeatureApi& get_feature_api() {
+	return FeatureApiLoader::current().api();
+}
+
+#endif // FeatureApiLoader_HPP
@@ -0,0 +1,59 @@
+#ifndef API_FeatureApi_H
+#define API_FeatureApi_H
+
+#include "diplomat_runtime.h"
+#include "Counter.h"
+
+#ifdef __cplusplus
+namespace capi {
+extern "C" {
+#endif // __cplusplus
+
+// The version of the table header and the hash of the table layout these headers
+// were generated for
+#define FEATUREAPI_API_VERSION 1
+#define FEATUREAPI_API_HASH 0x40a41214beb90817ULL
+
+typedef struct __API_Header__ {
+  size_t size;
+  uint32_t version;
+  uint32_t append_only;
+  uint64_t hash;
+} __API_Header__;
+
+// The functions of diplomat-runtime, which hosts call through the table instead of
+// linking the library
+typedef struct __Core_API__ {
+  size_t size;
+  void(*free)(void* ptr);
+  uint8_t* (*diplomat_alloc)(size_t size, size_t align);
+  void (*diplomat_free)(uint8_t* ptr, size_t size, size_t align);
+  DiplomatWriteable* (*diplomat_buffer_writeable_create)(size_t cap);
+  uint8_t* (*diplomat_buffer_writeable_get_bytes)(const DiplomatWriteable* self);
+  size_t (*diplomat_buffer_writeable_len)(const DiplomatWriteable* self);
+  void (*diplomat_buffer_writeable_destroy)(DiplomatWriteable* self);
+  DiplomatWriteable (*diplomat_simple_writeable)(char* buf, size_t buf_size);
+} __Core_API__;
+
+typedef struct FeatureApi
+{
+  __API_Header__ header;
+  const __Core_API__* core;
+  const __Counter_API__* Counter;
+} FeatureApi;
+
+const FeatureApi* diplomat_feature_api();
+
+// Whether the table a library returned can be used through these headers
+static inline bool FeatureApi_is_compatible(const FeatureApi* api) {
+  return api->header.version == FEATUREAPI_API_VERSION
+    && api->header.hash == FEATUREAPI_API_HASH
+    && api->core->size >= sizeof(__Core_API__);
+}
+
+#ifdef __cplusplus
+} // extern "C"
+} // namespace capi
+#endif // __cplusplus
+
+#endif // API_FeatureApi_H
@@ -0,0 +1,45 @@
+//! A bridge crate that hosts load at runtime and call through its API table, whose
+//! cpp2 bindings are generated with a loader
+
+// The `free` of the core table frees any pointer the library handed out
+#![allow(clippy::from_raw_with_void_ptr)]
+
+use std::sync::atomic::AtomicU32;
+
+/// The counters this copy of the library created, which starts over when it is loaded again
+static CREATED: AtomicU32 = AtomicU32::new(0);
+
+#[diplomat::bridge(
+    apiname = FeatureApi,
+    refresh_api_fn = diplomat_feature_api,
+    get_api_fn = get_feature_api
+)]
+pub mod ffi {
+    use diplomat_runtime::DiplomatWriteable;
+    use std::fmt::Write;
+    use std::sync::atomic::Ordering;
+
+    #[diplomat::opaque]
+    pub struct Counter(u32);
+
+    impl Counter {
+        pub fn new(start: u32) -> Box<Counter> {
+            super::CREATED.fetch_add(1, Ordering::Relaxed);
+            Box::new(Counter(start))
+        }
+
+        pub fn increment(&mut self) -> u32 {
+            self.0 += 1;
+            self.0
+        }
+
+        pub fn created() -> u32 {
+            super::CREATED.load(Ordering::Relaxed)
+        }
+
+        pub fn describe(&self, write: &mut DiplomatWriteable) {
+            write!(write, "Counter at {}", self.0).unwrap();
+            write.flush();
+        }
+    }
+}
@@ -6,6 +6,7 @@
 #include <stdbool.h>
 #include "diplomat_runtime.h"
 
+/* [ WAKKA 3 ] */
 #ifdef __cplusplus
 namespace capi {
 #endif
@@ -13,9 +14,11 @@ namespace capi {
 typedef struct BorrowedFieldsReturning {
     DiplomatStringView bytes;
 } BorrowedFieldsReturning;
+/* [ WAKKA 2 ] */
 #ifdef __cplusplus
 } // namespace capi
 #endif
+/* [ WAKKA 1 ] */
 #ifdef __cplusplus
 namespace capi {
 extern "C" {
@@ -23,6 +26,7 @@ extern "C" {
 
 void BorrowedFieldsReturning_destroy(BorrowedFieldsReturning* self);
 
+/* [ WAKKA 4 ] */
 #ifdef __cplusplus
 } // extern "C"
 } // namespace capi
@@ -6,14 +6,17 @@
 #include <stdbool.h>
 #include "diplomat_runtime.h"
 
+/* [ WAKKA 3 ] */
 #ifdef __cplusplus
 namespace capi {
 #endif
 
 typedef struct Comparable Comparable;
+/* [ WAKKA 2 ] */
 #ifdef __cplusplus
 } // namespace capi
 #endif
+/* [ WAKKA 1 ] */
 #ifdef __cplusplus
 namespace capi {
 extern "C" {
@@ -24,6 +27,7 @@ Comparable* namespace_Comparable_new(uint8_t int);
 int8_t namespace_Comparable_cmp(const Comparable* self, const Comparable* other);
 void namespace_Comparable_destroy(Comparable* self);
 
+/* [ WAKKA 4 ] */
 #ifdef __cplusplus
 } // extern "C"
 } // namespace capi
@@ -0,0 +1,33 @@
+#ifndef ConstHolder_H
+#define ConstHolder_H
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+/* [ WAKKA 3 ] */
+#ifdef __cplusplus
+namespace capi {
+#endif
+
+typedef struct ConstHolder ConstHolder;
+/* [ WAKKA 2 ] */
+#ifdef __cplusplus
+} // namespace capi
+#endif
+/* [ WAKKA 1 ] */
+#ifdef __cplusplus
+namespace capi {
+extern "C" {
+#endif
+
+uint32_t ConstHolder_max_len();
+void ConstHolder_destroy(ConstHolder* self);
+
+/* [ WAKKA 4 ] */
+#ifdef __cplusplus
+} // extern "C"
+} // namespace capi
+#endif
+#endif
@@ -6,6 +6,7 @@
 #include <stdbool.h>
 #include "diplomat_runtime.h"
 
+/* [ WAKKA 3 ] */
 #ifdef __cplusplus
 namespace capi {
 #endif
@@ -16,9 +17,11 @@ typedef enum ContiguousEnum {
   ContiguousEnum_E = 2,
   ContiguousEnum_F = 3,
 } ContiguousEnum;
+/* [ WAKKA 2 ] */
 #ifdef __cplusplus
 } // namespace capi
 #endif
+/* [ WAKKA 1 ] */
 #ifdef __cplusplus
 namespace capi {
 extern "C" {
@@ -26,6 +29,7 @@ extern "C" {
 
 void ContiguousEnum_destroy(ContiguousEnum* self);
 
+/* [ WAKKA 4 ] */
 #ifdef __cplusplus
 } // extern "C"
 } // namespace capi
@@ -6,15 +6,18 @@
 #include <stdbool.h>
 #include "diplomat_runtime.h"
 
+/* [ WAKKA 3 ] */
 #ifdef __cplusplus
 namespace capi {
 #endif
 
 typedef struct MyIterator MyIterator;
+/* [ WAKKA 2 ] */
 #ifdef __cplusplus
 } // namespace capi
 #endif
 #include "diplomat_result_uint8_t_void.h"
+/* [ WAKKA 1 ] */
 #ifdef __cplusplus
 namespace capi {
 extern "C" {
@@ -23,6 +26,7 @@ extern "C" {
 diplomat_result_uint8_t_void namespace_MyIterator_next(MyIterator* self);
 void namespace_MyIterator_destroy(MyIterator* self);
 
+/* [ WAKKA 4 ] */
 #ifdef __cplusplus
 } // extern "C"
 } // namespace capi
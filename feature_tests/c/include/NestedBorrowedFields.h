@@ -8,6 +8,7 @@
 
 #include "BorrowedFields.h"
 #include "BorrowedFieldsWithBounds.h"
+/* [ WAKKA 3 ] */
 #ifdef __cplusplus
 namespace capi {
 #endif
@@ -17,11 +18,13 @@ typedef struct NestedBorrowedFields {
     BorrowedFieldsWithBounds bounds;
     BorrowedFieldsWithBounds bounds2;
 } NestedBorrowedFields;
+/* [ WAKKA 2 ] */
 #ifdef __cplusplus
 } // namespace capi
 #endif
 #include "BorrowedFields.h"
 #include "BorrowedFieldsWithBounds.h"
+/* [ WAKKA 1 ] */
 #ifdef __cplusplus
 namespace capi {
 extern "C" {
@@ -29,6 +32,7 @@ extern "C" {
 
 void NestedBorrowedFields_destroy(NestedBorrowedFields* self);
 
+/* [ WAKKA 4 ] */
 #ifdef __cplusplus
 } // extern "C"
 } // namespace capi
@@ -6,6 +6,7 @@
 #include <stdbool.h>
 #include "diplomat_runtime.h"
 
+/* [ WAKKA 3 ] */
 #ifdef __cplusplus
 namespace capi {
 #endif
@@ -15,9 +16,11 @@ typedef enum UnimportedEnum {
   UnimportedEnum_B = 1,
   UnimportedEnum_C = 2,
 } UnimportedEnum;
+/* [ WAKKA 2 ] */
 #ifdef __cplusplus
 } // namespace capi
 #endif
+/* [ WAKKA 1 ] */
 #ifdef __cplusplus
 namespace capi {
 extern "C" {
@@ -25,6 +28,7 @@ extern "C" {
 
 void UnimportedEnum_destroy(UnimportedEnum* self);
 
+/* [ WAKKA 4 ] */
 #ifdef __cplusplus
 } // extern "C"
 } // namespace capi
@@ -6,6 +6,7 @@
 #include <stdbool.h>
 #include "diplomat_runtime.h"
 
+/* [ WAKKA 5 ] */
 #ifdef __cplusplus
 namespace capi {
 extern "C" {
@@ -16,6 +17,7 @@ typedef struct diplomat_result_uint8_t_void {
     };
     bool is_ok;
 } diplomat_result_uint8_t_void;
+/* [ WAKKA 6 ] */
 #ifdef __cplusplus
 } // extern "C"
 } // namespace capi
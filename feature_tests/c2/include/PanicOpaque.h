@@ -0,0 +1,33 @@
+#ifndef PanicOpaque_H
+#define PanicOpaque_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+#include "diplomat_result_uint32_t_PanicError.d.h"
+
+#include "PanicOpaque.d.h"
+
+#ifdef __cplusplus
+namespace capi {
+extern "C" {
+#endif // __cplusplus
+
+
+void PanicOpaque_panics(const char* message_data, size_t message_len);
+
+diplomat_result_uint32_t_PanicError PanicOpaque_checked_divide(uint32_t x);
+
+uint32_t PanicOpaque_not_panicking(uint32_t x);
+
+void PanicOpaque_destroy(PanicOpaque* self);
+
+
+#ifdef __cplusplus
+} // extern "C"
+} // namespace capi
+#endif // __cplusplus
+
+#endif // PanicOpaque_H
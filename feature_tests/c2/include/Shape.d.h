@@ -0,0 +1,41 @@
+#ifndef Shape_D_H
+#define Shape_D_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+#ifdef __cplusplus
+namespace capi {
+extern "C" {
+#endif // __cplusplus
+
+
+typedef enum Shape_Tag {
+  Shape_Circle = 0,
+  Shape_Rect = 1,
+  Shape_Empty = 2,
+} Shape_Tag;
+
+typedef struct Shape {
+  Shape_Tag tag;
+  union {
+    struct {
+      double radius;
+    } Circle;
+    struct {
+      double width;
+      double height;
+    } Rect;
+  };
+} Shape;
+
+
+#ifdef __cplusplus
+} // extern "C"
+} // namespace capi
+#endif // __cplusplus
+
+#endif // Shape_D_H
@@ -0,0 +1,36 @@
+#ifndef ShapeList_H
+#define ShapeList_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+#include "Shape.d.h"
+#include "Shape.h"
+
+#include "ShapeList.d.h"
+
+#ifdef __cplusplus
+namespace capi {
+extern "C" {
+#endif // __cplusplus
+
+
+ShapeList* ShapeList_new();
+
+void ShapeList_push(ShapeList* self, Shape shape);
+
+Shape ShapeList_last(const ShapeList* self);
+
+double ShapeList_total_area(const ShapeList* self);
+
+void ShapeList_destroy(ShapeList* self);
+
+
+#ifdef __cplusplus
+} // extern "C"
+} // namespace capi
+#endif // __cplusplus
+
+#endif // ShapeList_H
@@ -0,0 +1,29 @@
+#ifndef TesterTrait_D_H
+#define TesterTrait_D_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+#ifdef __cplusplus
+namespace capi {
+extern "C" {
+#endif // __cplusplus
+
+
+typedef struct TesterTrait_VTable {
+  void* ctx;
+  uint32_t (*test_trait_fn)(void* ctx, uint32_t x);
+  void (*test_void_trait_fn)(void* ctx);
+  void (*destroy)(void* ctx);
+} TesterTrait_VTable;
+
+
+#ifdef __cplusplus
+} // extern "C"
+} // namespace capi
+#endif // __cplusplus
+
+#endif // TesterTrait_D_H
@@ -0,0 +1,33 @@
+#ifndef TraitWrapper_H
+#define TraitWrapper_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+#include "TesterTrait.d.h"
+
+#include "TraitWrapper.d.h"
+
+#ifdef __cplusplus
+namespace capi {
+extern "C" {
+#endif // __cplusplus
+
+
+uint32_t TraitWrapper_test_with_trait(TesterTrait_VTable t, uint32_t x);
+
+TraitWrapper* TraitWrapper_from_trait(TesterTrait_VTable t);
+
+uint32_t TraitWrapper_call(const TraitWrapper* self, uint32_t x);
+
+void TraitWrapper_destroy(TraitWrapper* self);
+
+
+#ifdef __cplusplus
+} // extern "C"
+} // namespace capi
+#endif // __cplusplus
+
+#endif // TraitWrapper_H
@@ -0,0 +1,27 @@
+#ifndef diplomat_consts_H
+#define diplomat_consts_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+#ifdef __cplusplus
+namespace capi {
+extern "C" {
+#endif // __cplusplus
+
+
+#define diplomat_feature_tests_ENABLED true
+static const char diplomat_feature_tests_GREETING[] = "hello";
+#define diplomat_feature_tests_MAX_LEN ((uint32_t)16)
+#define diplomat_feature_tests_RATIO 0.5
+
+
+#ifdef __cplusplus
+} // extern "C"
+} // namespace capi
+#endif // __cplusplus
+
+#endif // diplomat_consts_H
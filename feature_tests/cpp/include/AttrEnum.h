@@ -6,6 +6,7 @@
 #include <stdbool.h>
 #include "diplomat_runtime.h"
 
+/* [ WAKKA 3 ] */
 #ifdef __cplusplus
 namespace capi {
 #endif
@@ -15,9 +16,11 @@ typedef enum AttrEnum {
   AttrEnum_B = 1,
   AttrEnum_C = 2,
 } AttrEnum;
+/* [ WAKKA 2 ] */
 #ifdef __cplusplus
 } // namespace capi
 #endif
+/* [ WAKKA 1 ] */
 #ifdef __cplusplus
 namespace capi {
 extern "C" {
@@ -25,6 +28,7 @@ extern "C" {
 
 void namespace_AttrEnum_destroy(AttrEnum* self);
 
+/* [ WAKKA 4 ] */
 #ifdef __cplusplus
 } // extern "C"
 } // namespace capi
@@ -6,16 +6,19 @@
 #include <stdbool.h>
 #include "diplomat_runtime.h"
 
+/* [ WAKKA 3 ] */
 #ifdef __cplusplus
 namespace capi {
 #endif
 
 typedef struct AttrOpaque1 AttrOpaque1;
+/* [ WAKKA 2 ] */
 #ifdef __cplusplus
 } // namespace capi
 #endif
 #include "Unnamespaced.h"
 #include "AttrEnum.h"
+/* [ WAKKA 1 ] */
 #ifdef __cplusplus
 namespace capi {
 extern "C" {
@@ -34,6 +37,7 @@ void namespace_AttrOpaque1_use_unnamespaced(const AttrOpaque1* self, const Unnam
 void namespace_AttrOpaque1_use_namespaced(const AttrOpaque1* self, AttrEnum _n);
 void namespace_AttrOpaque1_destroy(AttrOpaque1* self);
 
+/* [ WAKKA 4 ] */
 #ifdef __cplusplus
 } // extern "C"
 } // namespace capi
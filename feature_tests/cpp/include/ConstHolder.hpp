@@ -0,0 +1,40 @@
+#ifndef ConstHolder_HPP
+#define ConstHolder_HPP
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <algorithm>
+#include <memory>
+#include <variant>
+#include <optional>
+#include "diplomat_runtime.hpp"
+
+#include "ConstHolder.h"
+
+
+/**
+ * A destruction policy for using ConstHolder with std::unique_ptr.
+ */
+struct ConstHolderDeleter {
+  void operator()(capi::ConstHolder* l) const noexcept {
+    capi::ConstHolder_destroy(l);
+  }
+};
+class ConstHolder {
+ public:
+  static uint32_t max_len();
+  inline const capi::ConstHolder* AsFFI() const { return this->inner.get(); }
+  inline capi::ConstHolder* AsFFIMut() { return this->inner.get(); }
+  inline explicit ConstHolder(capi::ConstHolder* i) : inner(i) {}
+  ConstHolder() = default;
+  ConstHolder(ConstHolder&&) noexcept = default;
+  ConstHolder& operator=(ConstHolder&& other) noexcept = default;
+ private:
+  std::unique_ptr<capi::ConstHolder, ConstHolderDeleter> inner;
+};
+
+
+inline uint32_t ConstHolder::max_len() {
+  return capi::ConstHolder_max_len();
+}
+#endif
@@ -6,6 +6,7 @@
 #include <stdbool.h>
 #include "diplomat_runtime.h"
 
+/* [ WAKKA 3 ] */
 #ifdef __cplusplus
 namespace capi {
 #endif
@@ -14,9 +15,11 @@ typedef enum ErrorEnum {
   ErrorEnum_Foo = 0,
   ErrorEnum_Bar = 1,
 } ErrorEnum;
+/* [ WAKKA 2 ] */
 #ifdef __cplusplus
 } // namespace capi
 #endif
+/* [ WAKKA 1 ] */
 #ifdef __cplusplus
 namespace capi {
 extern "C" {
@@ -24,6 +27,7 @@ extern "C" {
 
 void ErrorEnum_destroy(ErrorEnum* self);
 
+/* [ WAKKA 4 ] */
 #ifdef __cplusplus
 } // extern "C"
 } // namespace capi
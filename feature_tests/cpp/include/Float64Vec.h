@@ -6,15 +6,18 @@
 #include <stdbool.h>
 #include "diplomat_runtime.h"
 
+/* [ WAKKA 3 ] */
 #ifdef __cplusplus
 namespace capi {
 #endif
 
 typedef struct Float64Vec Float64Vec;
+/* [ WAKKA 2 ] */
 #ifdef __cplusplus
 } // namespace capi
 #endif
 #include "diplomat_result_double_void.h"
+/* [ WAKKA 1 ] */
 #ifdef __cplusplus
 namespace capi {
 extern "C" {
@@ -51,6 +54,7 @@ DiplomatF64View Float64Vec_borrow(const Float64Vec* self);
 diplomat_result_double_void Float64Vec_get(const Float64Vec* self, size_t i);
 void Float64Vec_destroy(Float64Vec* self);
 
+/* [ WAKKA 4 ] */
 #ifdef __cplusplus
 } // extern "C"
 } // namespace capi
@@ -6,11 +6,13 @@
 #include <stdbool.h>
 #include "diplomat_runtime.h"
 
+/* [ WAKKA 3 ] */
 #ifdef __cplusplus
 namespace capi {
 #endif
 
 typedef struct Foo Foo;
+/* [ WAKKA 2 ] */
 #ifdef __cplusplus
 } // namespace capi
 #endif
@@ -18,6 +20,7 @@ typedef struct Foo Foo;
 #include "BorrowedFieldsReturning.h"
 #include "BorrowedFields.h"
 #include "BorrowedFieldsWithBounds.h"
+/* [ WAKKA 1 ] */
 #ifdef __cplusplus
 namespace capi {
 extern "C" {
@@ -36,6 +39,7 @@ Foo* Foo_extract_from_fields(BorrowedFields fields);
 Foo* Foo_extract_from_bounds(BorrowedFieldsWithBounds bounds, const char* another_string_data, size_t another_string_len);
 void Foo_destroy(Foo* self);
 
+/* [ WAKKA 4 ] */
 #ifdef __cplusplus
 } // extern "C"
 } // namespace capi
@@ -7,6 +7,7 @@
 #include "diplomat_runtime.h"
 
 #include "UnimportedEnum.h"
+/* [ WAKKA 3 ] */
 #ifdef __cplusplus
 namespace capi {
 #endif
@@ -15,10 +16,12 @@ typedef struct ImportedStruct {
     UnimportedEnum foo;
     uint8_t count;
 } ImportedStruct;
+/* [ WAKKA 2 ] */
 #ifdef __cplusplus
 } // namespace capi
 #endif
 #include "UnimportedEnum.h"
+/* [ WAKKA 1 ] */
 #ifdef __cplusplus
 namespace capi {
 extern "C" {
@@ -26,6 +29,7 @@ extern "C" {
 
 void ImportedStruct_destroy(ImportedStruct* self);
 
+/* [ WAKKA 4 ] */
 #ifdef __cplusplus
 } // extern "C"
 } // namespace capi
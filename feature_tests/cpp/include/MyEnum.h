@@ -6,6 +6,7 @@
 #include <stdbool.h>
 #include "diplomat_runtime.h"
 
+/* [ WAKKA 3 ] */
 #ifdef __cplusplus
 namespace capi {
 #endif
@@ -18,9 +19,11 @@ typedef enum MyEnum {
   MyEnum_E = 2,
   MyEnum_F = 3,
 } MyEnum;
+/* [ WAKKA 2 ] */
 #ifdef __cplusplus
 } // namespace capi
 #endif
+/* [ WAKKA 1 ] */
 #ifdef __cplusplus
 namespace capi {
 extern "C" {
@@ -29,6 +32,7 @@ extern "C" {
 int8_t MyEnum_into_value(MyEnum self);
 void MyEnum_destroy(MyEnum* self);
 
+/* [ WAKKA 4 ] */
 #ifdef __cplusplus
 } // extern "C"
 } // namespace capi
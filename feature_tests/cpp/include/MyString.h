@@ -6,14 +6,17 @@
 #include <stdbool.h>
 #include "diplomat_runtime.h"
 
+/* [ WAKKA 3 ] */
 #ifdef __cplusplus
 namespace capi {
 #endif
 
 typedef struct MyString MyString;
+/* [ WAKKA 2 ] */
 #ifdef __cplusplus
 } // namespace capi
 #endif
+/* [ WAKKA 1 ] */
 #ifdef __cplusplus
 namespace capi {
 extern "C" {
@@ -30,6 +33,7 @@ void MyString_set_str(MyString* self, const char* new_str_data, size_t new_str_l
 void MyString_get_str(const MyString* self, DiplomatWriteable* writeable);
 void MyString_destroy(MyString* self);
 
+/* [ WAKKA 4 ] */
 #ifdef __cplusplus
 } // extern "C"
 } // namespace capi
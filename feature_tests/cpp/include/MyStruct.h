@@ -7,6 +7,7 @@
 #include "diplomat_runtime.h"
 
 #include "MyEnum.h"
+/* [ WAKKA 3 ] */
 #ifdef __cplusplus
 namespace capi {
 #endif
@@ -20,10 +21,12 @@ typedef struct MyStruct {
     char32_t f;
     MyEnum g;
 } MyStruct;
+/* [ WAKKA 2 ] */
 #ifdef __cplusplus
 } // namespace capi
 #endif
 #include "MyEnum.h"
+/* [ WAKKA 1 ] */
 #ifdef __cplusplus
 namespace capi {
 extern "C" {
@@ -34,6 +37,7 @@ MyStruct MyStruct_new();
 uint8_t MyStruct_into_a(MyStruct self);
 void MyStruct_destroy(MyStruct* self);
 
+/* [ WAKKA 4 ] */
 #ifdef __cplusplus
 } // extern "C"
 } // namespace capi
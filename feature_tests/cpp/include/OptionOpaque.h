@@ -6,16 +6,19 @@
 #include <stdbool.h>
 #include "diplomat_runtime.h"
 
+/* [ WAKKA 3 ] */
 #ifdef __cplusplus
 namespace capi {
 #endif
 
 typedef struct OptionOpaque OptionOpaque;
+/* [ WAKKA 2 ] */
 #ifdef __cplusplus
 } // namespace capi
 #endif
 #include "diplomat_result_OptionStruct_void.h"
 #include "OptionStruct.h"
+/* [ WAKKA 1 ] */
 #ifdef __cplusplus
 namespace capi {
 extern "C" {
@@ -36,6 +39,7 @@ void OptionOpaque_assert_integer(const OptionOpaque* self, int32_t i);
 bool OptionOpaque_option_opaque_argument(const OptionOpaque* arg);
 void OptionOpaque_destroy(OptionOpaque* self);
 
+/* [ WAKKA 4 ] */
 #ifdef __cplusplus
 } // extern "C"
 } // namespace capi
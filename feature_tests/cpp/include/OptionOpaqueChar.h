@@ -6,14 +6,17 @@
 #include <stdbool.h>
 #include "diplomat_runtime.h"
 
+/* [ WAKKA 3 ] */
 #ifdef __cplusplus
 namespace capi {
 #endif
 
 typedef struct OptionOpaqueChar OptionOpaqueChar;
+/* [ WAKKA 2 ] */
 #ifdef __cplusplus
 } // namespace capi
 #endif
+/* [ WAKKA 1 ] */
 #ifdef __cplusplus
 namespace capi {
 extern "C" {
@@ -22,6 +25,7 @@ extern "C" {
 void OptionOpaqueChar_assert_char(const OptionOpaqueChar* self, char32_t ch);
 void OptionOpaqueChar_destroy(OptionOpaqueChar* self);
 
+/* [ WAKKA 4 ] */
 #ifdef __cplusplus
 } // extern "C"
 } // namespace capi
@@ -8,6 +8,7 @@
 
 #include "OptionOpaque.h"
 #include "OptionOpaqueChar.h"
+/* [ WAKKA 3 ] */
 #ifdef __cplusplus
 namespace capi {
 #endif
@@ -18,11 +19,13 @@ typedef struct OptionStruct {
     uint32_t c;
     OptionOpaque* d;
 } OptionStruct;
+/* [ WAKKA 2 ] */
 #ifdef __cplusplus
 } // namespace capi
 #endif
 #include "OptionOpaque.h"
 #include "OptionOpaqueChar.h"
+/* [ WAKKA 1 ] */
 #ifdef __cplusplus
 namespace capi {
 extern "C" {
@@ -30,6 +33,7 @@ extern "C" {
 
 void OptionStruct_destroy(OptionStruct* self);
 
+/* [ WAKKA 4 ] */
 #ifdef __cplusplus
 } // extern "C"
 } // namespace capi
@@ -0,0 +1,35 @@
+#ifndef PanicError_H
+#define PanicError_H
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+/* [ WAKKA 3 ] */
+#ifdef __cplusplus
+namespace capi {
+#endif
+
+typedef enum PanicError {
+  PanicError_Panicked = 0,
+  PanicError_TooLarge = 1,
+} PanicError;
+/* [ WAKKA 2 ] */
+#ifdef __cplusplus
+} // namespace capi
+#endif
+/* [ WAKKA 1 ] */
+#ifdef __cplusplus
+namespace capi {
+extern "C" {
+#endif
+
+void PanicError_destroy(PanicError* self);
+
+/* [ WAKKA 4 ] */
+#ifdef __cplusplus
+} // extern "C"
+} // namespace capi
+#endif
+#endif
@@ -0,0 +1,20 @@
+#ifndef PanicError_HPP
+#define PanicError_HPP
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <algorithm>
+#include <memory>
+#include <variant>
+#include <optional>
+#include "diplomat_runtime.hpp"
+
+#include "PanicError.h"
+
+
+enum struct PanicError {
+  Panicked = 0,
+  TooLarge = 1,
+};
+
+#endif
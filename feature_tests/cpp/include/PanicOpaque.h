@@ -0,0 +1,38 @@
+#ifndef PanicOpaque_H
+#define PanicOpaque_H
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+/* [ WAKKA 3 ] */
+#ifdef __cplusplus
+namespace capi {
+#endif
+
+typedef struct PanicOpaque PanicOpaque;
+/* [ WAKKA 2 ] */
+#ifdef __cplusplus
+} // namespace capi
+#endif
+#include "diplomat_result_uint32_t_PanicError.h"
+/* [ WAKKA 1 ] */
+#ifdef __cplusplus
+namespace capi {
+extern "C" {
+#endif
+
+void PanicOpaque_panics(const char* message_data, size_t message_len);
+
+diplomat_result_uint32_t_PanicError PanicOpaque_checked_divide(uint32_t x);
+
+uint32_t PanicOpaque_not_panicking(uint32_t x);
+void PanicOpaque_destroy(PanicOpaque* self);
+
+/* [ WAKKA 4 ] */
+#ifdef __cplusplus
+} // extern "C"
+} // namespace capi
+#endif
+#endif
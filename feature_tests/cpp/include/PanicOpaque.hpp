@@ -0,0 +1,64 @@
+#ifndef PanicOpaque_HPP
+#define PanicOpaque_HPP
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <algorithm>
+#include <memory>
+#include <variant>
+#include <optional>
+#include "diplomat_runtime.hpp"
+
+#include "PanicOpaque.h"
+
+#include "PanicError.hpp"
+
+/**
+ * A destruction policy for using PanicOpaque with std::unique_ptr.
+ */
+struct PanicOpaqueDeleter {
+  void operator()(capi::PanicOpaque* l) const noexcept {
+    capi::PanicOpaque_destroy(l);
+  }
+};
+class PanicOpaque {
+ public:
+
+  /**
+   * Panics, which foreign code is told about after the call
+   */
+  static void panics(const std::string_view message);
+
+  /**
+   * Panics when `x` is zero, which is reported as `PanicError::Panicked`
+   */
+  static diplomat::result<uint32_t, PanicError> checked_divide(uint32_t x);
+  static uint32_t not_panicking(uint32_t x);
+  inline const capi::PanicOpaque* AsFFI() const { return this->inner.get(); }
+  inline capi::PanicOpaque* AsFFIMut() { return this->inner.get(); }
+  inline explicit PanicOpaque(capi::PanicOpaque* i) : inner(i) {}
+  PanicOpaque() = default;
+  PanicOpaque(PanicOpaque&&) noexcept = default;
+  PanicOpaque& operator=(PanicOpaque&& other) noexcept = default;
+ private:
+  std::unique_ptr<capi::PanicOpaque, PanicOpaqueDeleter> inner;
+};
+
+
+inline void PanicOpaque::panics(const std::string_view message) {
+  capi::PanicOpaque_panics(message.data(), message.size());
+}
+inline diplomat::result<uint32_t, PanicError> PanicOpaque::checked_divide(uint32_t x) {
+  auto diplomat_result_raw_out_value = capi::PanicOpaque_checked_divide(x);
+  diplomat::result<uint32_t, PanicError> diplomat_result_out_value;
+  if (diplomat_result_raw_out_value.is_ok) {
+    diplomat_result_out_value = diplomat::Ok<uint32_t>(diplomat_result_raw_out_value.ok);
+  } else {
+    diplomat_result_out_value = diplomat::Err<PanicError>(static_cast<PanicError>(diplomat_result_raw_out_value.err));
+  }
+  return diplomat_result_out_value;
+}
+inline uint32_t PanicOpaque::not_panicking(uint32_t x) {
+  return capi::PanicOpaque_not_panicking(x);
+}
+#endif
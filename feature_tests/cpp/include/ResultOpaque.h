@@ -6,11 +6,13 @@
 #include <stdbool.h>
 #include "diplomat_runtime.h"
 
+/* [ WAKKA 3 ] */
 #ifdef __cplusplus
 namespace capi {
 #endif
 
 typedef struct ResultOpaque ResultOpaque;
+/* [ WAKKA 2 ] */
 #ifdef __cplusplus
 } // namespace capi
 #endif
@@ -20,6 +22,7 @@ typedef struct ResultOpaque ResultOpaque;
 #include "diplomat_result_void_box_ResultOpaque.h"
 #include "diplomat_result_int32_t_void.h"
 #include "diplomat_result_ErrorEnum_box_ResultOpaque.h"
+/* [ WAKKA 1 ] */
 #ifdef __cplusplus
 namespace capi {
 extern "C" {
@@ -44,6 +47,7 @@ diplomat_result_ErrorEnum_box_ResultOpaque ResultOpaque_new_in_enum_err(int32_t
 void ResultOpaque_assert_integer(const ResultOpaque* self, int32_t i);
 void ResultOpaque_destroy(ResultOpaque* self);
 
+/* [ WAKKA 4 ] */
 #ifdef __cplusplus
 } // extern "C"
 } // namespace capi
@@ -8,6 +8,7 @@
 
 #include "ErrorEnum.h"
 #include "ResultOpaque.h"
+/* [ WAKKA 5 ] */
 #ifdef __cplusplus
 namespace capi {
 extern "C" {
@@ -19,6 +20,7 @@ typedef struct diplomat_result_ErrorEnum_box_ResultOpaque {
     };
     bool is_ok;
 } diplomat_result_ErrorEnum_box_ResultOpaque;
+/* [ WAKKA 6 ] */
 #ifdef __cplusplus
 } // extern "C"
 } // namespace capi
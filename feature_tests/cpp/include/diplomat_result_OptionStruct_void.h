@@ -7,6 +7,7 @@
 #include "diplomat_runtime.h"
 
 #include "OptionStruct.h"
+/* [ WAKKA 5 ] */
 #ifdef __cplusplus
 namespace capi {
 extern "C" {
@@ -17,6 +18,7 @@ typedef struct diplomat_result_OptionStruct_void {
     };
     bool is_ok;
 } diplomat_result_OptionStruct_void;
+/* [ WAKKA 6 ] */
 #ifdef __cplusplus
 } // extern "C"
 } // namespace capi
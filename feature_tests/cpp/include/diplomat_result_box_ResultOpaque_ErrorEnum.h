@@ -8,6 +8,7 @@
 
 #include "ResultOpaque.h"
 #include "ErrorEnum.h"
+/* [ WAKKA 5 ] */
 #ifdef __cplusplus
 namespace capi {
 extern "C" {
@@ -19,6 +20,7 @@ typedef struct diplomat_result_box_ResultOpaque_ErrorEnum {
     };
     bool is_ok;
 } diplomat_result_box_ResultOpaque_ErrorEnum;
+/* [ WAKKA 6 ] */
 #ifdef __cplusplus
 } // extern "C"
 } // namespace capi
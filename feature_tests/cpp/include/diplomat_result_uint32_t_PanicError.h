@@ -0,0 +1,27 @@
+#ifndef diplomat_result_uint32_t_PanicError_H
+#define diplomat_result_uint32_t_PanicError_H
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+#include "PanicError.h"
+/* [ WAKKA 5 ] */
+#ifdef __cplusplus
+namespace capi {
+extern "C" {
+#endif
+typedef struct diplomat_result_uint32_t_PanicError {
+    union {
+        uint32_t ok;
+        PanicError err;
+    };
+    bool is_ok;
+} diplomat_result_uint32_t_PanicError;
+/* [ WAKKA 6 ] */
+#ifdef __cplusplus
+} // extern "C"
+} // namespace capi
+#endif
+#endif
@@ -7,6 +7,7 @@
 #include <stdbool.h>
 #include <memory>
 #include <optional>
+#include <variant>
 #include "diplomat_runtime.hpp"
 #include "AttrOpaque1.d.h"
 #include "CPPRenamedAttrEnum.d.hpp"
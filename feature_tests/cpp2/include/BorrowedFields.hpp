@@ -9,6 +9,7 @@
 #include <stdbool.h>
 #include <memory>
 #include <optional>
+#include <variant>
 #include "diplomat_runtime.hpp"
 #include "BorrowedFields.h"
 
@@ -27,9 +28,9 @@ inline capi::BorrowedFields BorrowedFields::AsFFI() const {
 
 inline BorrowedFields BorrowedFields::FromFFI(capi::BorrowedFields c_struct) {
   return BorrowedFields {
-    .a = std::u16string_view(c_struct.a_data, c_struct.a_size),
-    .b = std::string_view(c_struct.b_data, c_struct.b_size),
-    .c = std::string_view(c_struct.c_data, c_struct.c_size),
+    .a = std::u16string_view(c_struct.a_data, c_struct.a_len),
+    .b = std::string_view(c_struct.b_data, c_struct.b_len),
+    .c = std::string_view(c_struct.c_data, c_struct.c_len),
   };
 }
 
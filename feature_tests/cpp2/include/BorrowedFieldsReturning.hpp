@@ -9,6 +9,7 @@
 #include <stdbool.h>
 #include <memory>
 #include <optional>
+#include <variant>
 #include "diplomat_runtime.hpp"
 #include "BorrowedFieldsReturning.h"
 
@@ -23,7 +24,7 @@ inline capi::BorrowedFieldsReturning BorrowedFieldsReturning::AsFFI() const {
 
 inline BorrowedFieldsReturning BorrowedFieldsReturning::FromFFI(capi::BorrowedFieldsReturning c_struct) {
   return BorrowedFieldsReturning {
-    .bytes = std::string_view(c_struct.bytes_data, c_struct.bytes_size),
+    .bytes = std::string_view(c_struct.bytes_data, c_struct.bytes_len),
   };
 }
 
@@ -9,6 +9,7 @@
 #include <stdbool.h>
 #include <memory>
 #include <optional>
+#include <variant>
 #include "diplomat_runtime.hpp"
 #include "BorrowedFieldsWithBounds.h"
 
@@ -27,9 +28,9 @@ inline capi::BorrowedFieldsWithBounds BorrowedFieldsWithBounds::AsFFI() const {
 
 inline BorrowedFieldsWithBounds BorrowedFieldsWithBounds::FromFFI(capi::BorrowedFieldsWithBounds c_struct) {
   return BorrowedFieldsWithBounds {
-    .field_a = std::u16string_view(c_struct.field_a_data, c_struct.field_a_size),
-    .field_b = std::string_view(c_struct.field_b_data, c_struct.field_b_size),
-    .field_c = std::string_view(c_struct.field_c_data, c_struct.field_c_size),
+    .field_a = std::u16string_view(c_struct.field_a_data, c_struct.field_a_len),
+    .field_b = std::string_view(c_struct.field_b_data, c_struct.field_b_len),
+    .field_c = std::string_view(c_struct.field_c_data, c_struct.field_c_len),
   };
 }
 
@@ -0,0 +1,37 @@
+#ifndef ConstHolder_D_HPP
+#define ConstHolder_D_HPP
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <optional>
+#include <variant>
+#include "diplomat_runtime.hpp"
+#include "ConstHolder.d.h"
+
+
+class ConstHolder {
+public:
+  static constexpr int8_t MIN_VALUE = ((int8_t)-128);
+  static constexpr char32_t SEPARATOR = ((char32_t)124);
+
+  inline static uint32_t max_len();
+
+  inline const capi::ConstHolder* AsFFI() const;
+  inline capi::ConstHolder* AsFFI();
+  inline static const ConstHolder* FromFFI(const capi::ConstHolder* ptr);
+  inline static ConstHolder* FromFFI(capi::ConstHolder* ptr);
+  inline static void operator delete(void* ptr);
+private:
+  ConstHolder() = delete;
+  ConstHolder(const ConstHolder&) = delete;
+  ConstHolder(ConstHolder&&) noexcept = delete;
+  ConstHolder operator=(const ConstHolder&) = delete;
+  ConstHolder operator=(ConstHolder&&) noexcept = delete;
+  static void operator delete[](void*, size_t) = delete;
+};
+
+
+#endif // ConstHolder_D_HPP
@@ -0,0 +1,28 @@
+#ifndef ConstHolder_H
+#define ConstHolder_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+#include "ConstHolder.d.h"
+
+#ifdef __cplusplus
+namespace capi {
+extern "C" {
+#endif // __cplusplus
+
+
+uint32_t ConstHolder_max_len();
+
+void ConstHolder_destroy(ConstHolder* self);
+
+
+#ifdef __cplusplus
+} // extern "C"
+} // namespace capi
+#endif // __cplusplus
+
+#endif // ConstHolder_H
@@ -0,0 +1,43 @@
+#ifndef ConstHolder_HPP
+#define ConstHolder_HPP
+
+#include "ConstHolder.d.hpp"
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <optional>
+#include <variant>
+#include "diplomat_runtime.hpp"
+#include "ConstHolder.h"
+
+
+
+inline uint32_t ConstHolder::max_len() {
+  auto result = capi::ConstHolder_max_len();
+  return result;
+}
+inline const capi::ConstHolder* ConstHolder::AsFFI() const {
+  return reinterpret_cast<const capi::ConstHolder*>(this);
+}
+
+inline capi::ConstHolder* ConstHolder::AsFFI() {
+  return reinterpret_cast<capi::ConstHolder*>(this);
+}
+
+inline const ConstHolder* ConstHolder::FromFFI(const capi::ConstHolder* ptr) {
+  return reinterpret_cast<const ConstHolder*>(ptr);
+}
+
+inline ConstHolder* ConstHolder::FromFFI(capi::ConstHolder* ptr) {
+  return reinterpret_cast<ConstHolder*>(ptr);
+}
+
+inline void ConstHolder::operator delete(void* ptr) {
+  capi::ConstHolder_destroy(reinterpret_cast<capi::ConstHolder*>(ptr));
+}
+
+
+#endif // ConstHolder_HPP
@@ -9,74 +9,65 @@
 #include <stdbool.h>
 #include <memory>
 #include <optional>
+#include <variant>
 #include "diplomat_runtime.hpp"
 #include "Float64Vec.h"
 
 
+
 inline std::unique_ptr<Float64Vec> Float64Vec::new_(diplomat::span<const double> v) {
   auto result = capi::Float64Vec_new(v.data(),
     v.size());
   return std::unique_ptr<Float64Vec>(Float64Vec::FromFFI(result));
 }
-
 inline std::unique_ptr<Float64Vec> Float64Vec::new_bool(diplomat::span<const bool> v) {
   auto result = capi::Float64Vec_new_bool(v.data(),
     v.size());
   return std::unique_ptr<Float64Vec>(Float64Vec::FromFFI(result));
 }
-
 inline std::unique_ptr<Float64Vec> Float64Vec::new_i16(diplomat::span<const int16_t> v) {
   auto result = capi::Float64Vec_new_i16(v.data(),
     v.size());
   return std::unique_ptr<Float64Vec>(Float64Vec::FromFFI(result));
 }
-
 inline std::unique_ptr<Float64Vec> Float64Vec::new_u16(diplomat::span<const uint16_t> v) {
   auto result = capi::Float64Vec_new_u16(v.data(),
     v.size());
   return std::unique_ptr<Float64Vec>(Float64Vec::FromFFI(result));
 }
-
 inline std::unique_ptr<Float64Vec> Float64Vec::new_isize(diplomat::span<const intptr_t> v) {
   auto result = capi::Float64Vec_new_isize(v.data(),
     v.size());
   return std::unique_ptr<Float64Vec>(Float64Vec::FromFFI(result));
 }
-
 inline std::unique_ptr<Float64Vec> Float64Vec::new_usize(diplomat::span<const size_t> v) {
   auto result = capi::Float64Vec_new_usize(v.data(),
     v.size());
   return std::unique_ptr<Float64Vec>(Float64Vec::FromFFI(result));
 }
-
 inline std::unique_ptr<Float64Vec> Float64Vec::new_f64_be_bytes(diplomat::span<const uint8_t> v) {
   auto result = capi::Float64Vec_new_f64_be_bytes(v.data(),
     v.size());
   return std::unique_ptr<Float64Vec>(Float64Vec::FromFFI(result));
 }
-
 inline diplomat::span<double> Float64Vec::as_boxed_slice() const {
   auto result = capi::Float64Vec_as_boxed_slice(this->AsFFI());
-  return diplomat::span<double>(result_data, result_size);
+  return diplomat::span<double>(result_data, result_len);
 }
-
 inline diplomat::span<const double> Float64Vec::as_slice() const {
   auto result = capi::Float64Vec_as_slice(this->AsFFI());
-  return diplomat::span<const double>(result_data, result_size);
+  return diplomat::span<const double>(result_data, result_len);
 }
-
 inline void Float64Vec::fill_slice(diplomat::span<double> v) const {
   capi::Float64Vec_fill_slice(this->AsFFI(),
     v.data(),
     v.size());
 }
-
 inline void Float64Vec::set_value(diplomat::span<const double> new_slice) {
   capi::Float64Vec_set_value(this->AsFFI(),
     new_slice.data(),
     new_slice.size());
 }
-
 inline std::string Float64Vec::to_string() const {
   std::string output;
   capi::DiplomatWriteable writeable = diplomat::WriteableFromString(output);
@@ -84,18 +75,15 @@ inline std::string Float64Vec::to_string() const {
     &writeable);
   return output;
 }
-
 inline diplomat::span<const double> Float64Vec::borrow() const {
   auto result = capi::Float64Vec_borrow(this->AsFFI());
-  return diplomat::span<const double>(result_data, result_size);
+  return diplomat::span<const double>(result_data, result_len);
 }
-
 inline std::optional<double> Float64Vec::get(size_t i) const {
   auto result = capi::Float64Vec_get(this->AsFFI(),
     i);
   return result.is_ok ? std::optional<double>(result.ok) : std::nullopt;
 }
-
 inline const capi::Float64Vec* Float64Vec::AsFFI() const {
   return reinterpret_cast<const capi::Float64Vec*>(this);
 }
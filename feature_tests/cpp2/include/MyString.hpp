@@ -9,40 +9,37 @@
 #include <stdbool.h>
 #include <memory>
 #include <optional>
+#include <variant>
 #include "diplomat_runtime.hpp"
 #include "MyString.h"
 
 
+
 inline std::unique_ptr<MyString> MyString::new_(std::string_view v) {
   auto result = capi::MyString_new(v.data(),
     v.size());
   return std::unique_ptr<MyString>(MyString::FromFFI(result));
 }
-
 inline std::unique_ptr<MyString> MyString::new_unsafe(std::string_view v) {
   auto result = capi::MyString_new_unsafe(v.data(),
     v.size());
   return std::unique_ptr<MyString>(MyString::FromFFI(result));
 }
-
 inline std::unique_ptr<MyString> MyString::new_owned(std::string_view v) {
   auto result = capi::MyString_new_owned(v.data(),
     v.size());
   return std::unique_ptr<MyString>(MyString::FromFFI(result));
 }
-
 inline std::unique_ptr<MyString> MyString::new_from_first(diplomat::span<const std::string_view> v) {
   auto result = capi::MyString_new_from_first(v.data(),
     v.size());
   return std::unique_ptr<MyString>(MyString::FromFFI(result));
 }
-
 inline void MyString::set_str(std::string_view new_str) {
   capi::MyString_set_str(this->AsFFI(),
     new_str.data(),
     new_str.size());
 }
-
 inline std::string MyString::get_str() const {
   std::string output;
   capi::DiplomatWriteable writeable = diplomat::WriteableFromString(output);
@@ -50,7 +47,6 @@ inline std::string MyString::get_str() const {
     &writeable);
   return output;
 }
-
 inline const capi::MyString* MyString::AsFFI() const {
   return reinterpret_cast<const capi::MyString*>(this);
 }
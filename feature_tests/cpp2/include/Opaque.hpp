@@ -9,32 +9,30 @@
 #include <stdbool.h>
 #include <memory>
 #include <optional>
+#include <variant>
 #include "diplomat_runtime.hpp"
 #include "ImportedStruct.hpp"
 #include "MyStruct.hpp"
 #include "Opaque.h"
 
 
+
 inline std::unique_ptr<Opaque> Opaque::new_() {
   auto result = capi::Opaque_new();
   return std::unique_ptr<Opaque>(Opaque::FromFFI(result));
 }
-
 inline void Opaque::assert_struct(MyStruct s) const {
   capi::Opaque_assert_struct(this->AsFFI(),
     s.AsFFI());
 }
-
 inline size_t Opaque::returns_usize() {
   auto result = capi::Opaque_returns_usize();
   return result;
 }
-
 inline ImportedStruct Opaque::returns_imported() {
   auto result = capi::Opaque_returns_imported();
   return ImportedStruct::FromFFI(result);
 }
-
 inline const capi::Opaque* Opaque::AsFFI() const {
   return reinterpret_cast<const capi::Opaque*>(this);
 }
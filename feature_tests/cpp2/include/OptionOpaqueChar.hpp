@@ -9,15 +9,16 @@
 #include <stdbool.h>
 #include <memory>
 #include <optional>
+#include <variant>
 #include "diplomat_runtime.hpp"
 #include "OptionOpaqueChar.h"
 
 
+
 inline void OptionOpaqueChar::assert_char(char32_t ch) const {
   capi::OptionOpaqueChar_assert_char(this->AsFFI(),
     ch);
 }
-
 inline const capi::OptionOpaqueChar* OptionOpaqueChar::AsFFI() const {
   return reinterpret_cast<const capi::OptionOpaqueChar*>(this);
 }
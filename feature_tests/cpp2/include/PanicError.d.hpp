@@ -0,0 +1,32 @@
+#ifndef PanicError_D_HPP
+#define PanicError_D_HPP
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <optional>
+#include <variant>
+#include "diplomat_runtime.hpp"
+#include "PanicError.d.h"
+
+
+class PanicError {
+  capi::PanicError value;
+
+public:
+  enum Value {
+    Panicked,
+    TooLarge,
+  };
+
+  inline PanicError(PanicError::Value cpp_value);
+  inline PanicError(capi::PanicError c_enum) : value(c_enum) {};
+
+  inline capi::PanicError AsFFI() const;
+  inline static PanicError FromFFI(capi::PanicError c_enum);
+};
+
+
+#endif // PanicError_D_HPP
@@ -0,0 +1,38 @@
+#ifndef PanicError_HPP
+#define PanicError_HPP
+
+#include "PanicError.d.hpp"
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <optional>
+#include <variant>
+#include "diplomat_runtime.hpp"
+#include "PanicError.h"
+
+
+inline PanicError::PanicError(PanicError::Value cpp_value) {
+  switch (cpp_value) {
+    case Panicked:
+      value = capi::PanicError_Panicked;
+      break;
+    case TooLarge:
+      value = capi::PanicError_TooLarge;
+      break;
+    default:
+      abort();
+  }
+}
+
+inline capi::PanicError PanicError::AsFFI() const {
+  return value;
+}
+
+inline PanicError PanicError::FromFFI(capi::PanicError c_enum) {
+  return PanicError(c_enum);
+}
+
+#endif // PanicError_HPP
@@ -0,0 +1,42 @@
+#ifndef PanicOpaque_D_HPP
+#define PanicOpaque_D_HPP
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <optional>
+#include <variant>
+#include "diplomat_runtime.hpp"
+#include "PanicError.d.hpp"
+#include "PanicOpaque.d.h"
+
+class PanicError;
+
+
+class PanicOpaque {
+public:
+
+  inline static void panics(std::string_view message);
+
+  inline static diplomat::result<uint32_t, PanicError> checked_divide(uint32_t x);
+
+  inline static uint32_t not_panicking(uint32_t x);
+
+  inline const capi::PanicOpaque* AsFFI() const;
+  inline capi::PanicOpaque* AsFFI();
+  inline static const PanicOpaque* FromFFI(const capi::PanicOpaque* ptr);
+  inline static PanicOpaque* FromFFI(capi::PanicOpaque* ptr);
+  inline static void operator delete(void* ptr);
+private:
+  PanicOpaque() = delete;
+  PanicOpaque(const PanicOpaque&) = delete;
+  PanicOpaque(PanicOpaque&&) noexcept = delete;
+  PanicOpaque operator=(const PanicOpaque&) = delete;
+  PanicOpaque operator=(PanicOpaque&&) noexcept = delete;
+  static void operator delete[](void*, size_t) = delete;
+};
+
+
+#endif // PanicOpaque_D_HPP
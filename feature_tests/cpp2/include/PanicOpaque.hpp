@@ -0,0 +1,64 @@
+#ifndef PanicOpaque_HPP
+#define PanicOpaque_HPP
+
+#include "PanicOpaque.d.hpp"
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <optional>
+#include <variant>
+#include "diplomat_runtime.hpp"
+#include "PanicError.hpp"
+#include "PanicOpaque.h"
+
+
+
+inline void PanicOpaque::panics(std::string_view message) {
+  capi::PanicOpaque_panics(message.data(),
+    message.size());
+  if (capi::diplomat_panicked()) {
+    std::string panic_message;
+    capi::DiplomatWriteable panic_writeable = diplomat::WriteableFromString(panic_message);
+    capi::diplomat_take_panic(&panic_writeable);
+    throw diplomat::panic(panic_message);
+  }
+}
+inline diplomat::result<uint32_t, PanicError> PanicOpaque::checked_divide(uint32_t x) {
+  auto result = capi::PanicOpaque_checked_divide(x);
+  return result.is_ok ? diplomat::result<uint32_t, PanicError>(diplomat::Ok<uint32_t>(result.ok)) : diplomat::result<uint32_t, PanicError>(diplomat::Err<PanicError>(PanicError::FromFFI(result.err)));
+}
+inline uint32_t PanicOpaque::not_panicking(uint32_t x) {
+  auto result = capi::PanicOpaque_not_panicking(x);
+  if (capi::diplomat_panicked()) {
+    std::string panic_message;
+    capi::DiplomatWriteable panic_writeable = diplomat::WriteableFromString(panic_message);
+    capi::diplomat_take_panic(&panic_writeable);
+    throw diplomat::panic(panic_message);
+  }
+  return result;
+}
+inline const capi::PanicOpaque* PanicOpaque::AsFFI() const {
+  return reinterpret_cast<const capi::PanicOpaque*>(this);
+}
+
+inline capi::PanicOpaque* PanicOpaque::AsFFI() {
+  return reinterpret_cast<capi::PanicOpaque*>(this);
+}
+
+inline const PanicOpaque* PanicOpaque::FromFFI(const capi::PanicOpaque* ptr) {
+  return reinterpret_cast<const PanicOpaque*>(ptr);
+}
+
+inline PanicOpaque* PanicOpaque::FromFFI(capi::PanicOpaque* ptr) {
+  return reinterpret_cast<PanicOpaque*>(ptr);
+}
+
+inline void PanicOpaque::operator delete(void* ptr) {
+  capi::PanicOpaque_destroy(reinterpret_cast<capi::PanicOpaque*>(ptr));
+}
+
+
+#endif // PanicOpaque_HPP
@@ -9,16 +9,17 @@
 #include <stdbool.h>
 #include <memory>
 #include <optional>
+#include <variant>
 #include "diplomat_runtime.hpp"
 #include "RefList.h"
 #include "RefListParameter.hpp"
 
 
+
 inline std::unique_ptr<RefList> RefList::node(const RefListParameter& data) {
   auto result = capi::RefList_node(data.AsFFI());
   return std::unique_ptr<RefList>(RefList::FromFFI(result));
 }
-
 inline const capi::RefList* RefList::AsFFI() const {
   return reinterpret_cast<const capi::RefList*>(this);
 }
@@ -9,10 +9,12 @@
 #include <stdbool.h>
 #include <memory>
 #include <optional>
+#include <variant>
 #include "diplomat_runtime.hpp"
 #include "RefListParameter.h"
 
 
+
 inline const capi::RefListParameter* RefListParameter::AsFFI() const {
   return reinterpret_cast<const capi::RefListParameter*>(this);
 }
@@ -0,0 +1,38 @@
+#ifndef Shape_D_HPP
+#define Shape_D_HPP
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <optional>
+#include <variant>
+#include "diplomat_runtime.hpp"
+#include "Shape.d.h"
+
+
+class Shape {
+public:
+  struct Circle {
+    double radius;
+  };
+  struct Rect {
+    double width;
+    double height;
+  };
+  struct Empty {
+  };
+
+  std::variant<Circle, Rect, Empty> value;
+
+  inline Shape(Circle v) : value(std::move(v)) {};
+  inline Shape(Rect v) : value(std::move(v)) {};
+  inline Shape(Empty v) : value(std::move(v)) {};
+
+  inline capi::Shape AsFFI() const;
+  inline static Shape FromFFI(capi::Shape c_enum);
+};
+
+
+#endif // Shape_D_HPP
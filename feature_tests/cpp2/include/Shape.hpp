@@ -0,0 +1,62 @@
+#ifndef Shape_HPP
+#define Shape_HPP
+
+#include "Shape.d.hpp"
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <optional>
+#include <variant>
+#include "diplomat_runtime.hpp"
+#include "Shape.h"
+
+
+
+inline capi::Shape Shape::AsFFI() const {
+  capi::Shape c_enum {};
+  switch (value.index()) {
+    case 0: {
+      const auto& payload = std::get<0>(value);
+      c_enum.tag = capi::Shape_Circle;
+      c_enum.Circle.radius = payload.radius;
+      break;
+    }
+    case 1: {
+      const auto& payload = std::get<1>(value);
+      c_enum.tag = capi::Shape_Rect;
+      c_enum.Rect.width = payload.width;
+      c_enum.Rect.height = payload.height;
+      break;
+    }
+    case 2: {
+      c_enum.tag = capi::Shape_Empty;
+      break;
+    }
+    default:
+      abort();
+  }
+  return c_enum;
+}
+
+inline Shape Shape::FromFFI(capi::Shape c_enum) {
+  switch (c_enum.tag) {
+    case capi::Shape_Circle:
+      return Shape(Shape::Circle {
+        .radius = c_enum.Circle.radius,
+      });
+    case capi::Shape_Rect:
+      return Shape(Shape::Rect {
+        .width = c_enum.Rect.width,
+        .height = c_enum.Rect.height,
+      });
+    case capi::Shape_Empty:
+      return Shape(Shape::Empty {
+      });
+    default:
+      abort();
+  }
+}
+#endif // Shape_HPP
@@ -0,0 +1,44 @@
+#ifndef ShapeList_D_HPP
+#define ShapeList_D_HPP
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <optional>
+#include <variant>
+#include "diplomat_runtime.hpp"
+#include "Shape.d.hpp"
+#include "ShapeList.d.h"
+
+class Shape;
+
+
+class ShapeList {
+public:
+
+  inline static std::unique_ptr<ShapeList> new_();
+
+  inline void push(Shape shape);
+
+  inline Shape last() const;
+
+  inline double total_area() const;
+
+  inline const capi::ShapeList* AsFFI() const;
+  inline capi::ShapeList* AsFFI();
+  inline static const ShapeList* FromFFI(const capi::ShapeList* ptr);
+  inline static ShapeList* FromFFI(capi::ShapeList* ptr);
+  inline static void operator delete(void* ptr);
+private:
+  ShapeList() = delete;
+  ShapeList(const ShapeList&) = delete;
+  ShapeList(ShapeList&&) noexcept = delete;
+  ShapeList operator=(const ShapeList&) = delete;
+  ShapeList operator=(ShapeList&&) noexcept = delete;
+  static void operator delete[](void*, size_t) = delete;
+};
+
+
+#endif // ShapeList_D_HPP
@@ -0,0 +1,56 @@
+#ifndef ShapeList_HPP
+#define ShapeList_HPP
+
+#include "ShapeList.d.hpp"
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <optional>
+#include <variant>
+#include "diplomat_runtime.hpp"
+#include "Shape.hpp"
+#include "ShapeList.h"
+
+
+
+inline std::unique_ptr<ShapeList> ShapeList::new_() {
+  auto result = capi::ShapeList_new();
+  return std::unique_ptr<ShapeList>(ShapeList::FromFFI(result));
+}
+inline void ShapeList::push(Shape shape) {
+  capi::ShapeList_push(this->AsFFI(),
+    shape.AsFFI());
+}
+inline Shape ShapeList::last() const {
+  auto result = capi::ShapeList_last(this->AsFFI());
+  return Shape::FromFFI(result);
+}
+inline double ShapeList::total_area() const {
+  auto result = capi::ShapeList_total_area(this->AsFFI());
+  return result;
+}
+inline const capi::ShapeList* ShapeList::AsFFI() const {
+  return reinterpret_cast<const capi::ShapeList*>(this);
+}
+
+inline capi::ShapeList* ShapeList::AsFFI() {
+  return reinterpret_cast<capi::ShapeList*>(this);
+}
+
+inline const ShapeList* ShapeList::FromFFI(const capi::ShapeList* ptr) {
+  return reinterpret_cast<const ShapeList*>(ptr);
+}
+
+inline ShapeList* ShapeList::FromFFI(capi::ShapeList* ptr) {
+  return reinterpret_cast<ShapeList*>(ptr);
+}
+
+inline void ShapeList::operator delete(void* ptr) {
+  capi::ShapeList_destroy(reinterpret_cast<capi::ShapeList*>(ptr));
+}
+
+
+#endif // ShapeList_HPP
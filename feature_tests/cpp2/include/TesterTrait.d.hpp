@@ -0,0 +1,35 @@
+#ifndef TesterTrait_D_HPP
+#define TesterTrait_D_HPP
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <optional>
+#include <variant>
+#include "diplomat_runtime.hpp"
+#include "TesterTrait.d.h"
+
+
+class TesterTrait {
+public:
+  virtual ~TesterTrait() = default;
+
+  virtual uint32_t test_trait_fn(uint32_t x) const = 0;
+
+  virtual void test_void_trait_fn() const = 0;
+
+  inline static capi::TesterTrait_VTable AsFFI(std::unique_ptr<TesterTrait> self);
+  inline capi::TesterTrait_VTable AsFFI() const;
+private:
+  // The functions the vtable points to, named after its fields
+  struct thunks {
+    inline static uint32_t test_trait_fn(void* ctx, uint32_t x);
+    inline static void test_void_trait_fn(void* ctx);
+    inline static void destroy(void* ctx);
+  };
+};
+
+
+#endif // TesterTrait_D_HPP
@@ -0,0 +1,43 @@
+#ifndef TesterTrait_HPP
+#define TesterTrait_HPP
+
+#include "TesterTrait.d.hpp"
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <optional>
+#include <variant>
+#include "diplomat_runtime.hpp"
+
+
+inline uint32_t TesterTrait::thunks::test_trait_fn(void* ctx, uint32_t x) {
+  auto result = static_cast<const TesterTrait*>(ctx)->test_trait_fn(x);
+  return result;
+}
+
+inline void TesterTrait::thunks::test_void_trait_fn(void* ctx) {
+  static_cast<const TesterTrait*>(ctx)->test_void_trait_fn();
+}
+
+inline void TesterTrait::thunks::destroy(void* ctx) {
+  delete static_cast<TesterTrait*>(ctx);
+}
+
+inline capi::TesterTrait_VTable TesterTrait::AsFFI() const {
+  return capi::TesterTrait_VTable {
+    const_cast<TesterTrait*>(this),
+    thunks::test_trait_fn,
+    thunks::test_void_trait_fn,
+    nullptr,
+  };
+}
+
+inline capi::TesterTrait_VTable TesterTrait::AsFFI(std::unique_ptr<TesterTrait> self) {
+  capi::TesterTrait_VTable vtable = self.release()->AsFFI();
+  vtable.destroy = thunks::destroy;
+  return vtable;
+}
+#endif // TesterTrait_HPP
@@ -0,0 +1,41 @@
+#ifndef TraitWrapper_D_HPP
+#define TraitWrapper_D_HPP
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <optional>
+#include <variant>
+#include "diplomat_runtime.hpp"
+#include "TraitWrapper.d.h"
+
+class TesterTrait;
+
+
+class TraitWrapper {
+public:
+
+  inline static uint32_t test_with_trait(const TesterTrait& t, uint32_t x);
+
+  inline static std::unique_ptr<TraitWrapper> from_trait(std::unique_ptr<TesterTrait> t);
+
+  inline uint32_t call(uint32_t x) const;
+
+  inline const capi::TraitWrapper* AsFFI() const;
+  inline capi::TraitWrapper* AsFFI();
+  inline static const TraitWrapper* FromFFI(const capi::TraitWrapper* ptr);
+  inline static TraitWrapper* FromFFI(capi::TraitWrapper* ptr);
+  inline static void operator delete(void* ptr);
+private:
+  TraitWrapper() = delete;
+  TraitWrapper(const TraitWrapper&) = delete;
+  TraitWrapper(TraitWrapper&&) noexcept = delete;
+  TraitWrapper operator=(const TraitWrapper&) = delete;
+  TraitWrapper operator=(TraitWrapper&&) noexcept = delete;
+  static void operator delete[](void*, size_t) = delete;
+};
+
+
+#endif // TraitWrapper_D_HPP
@@ -0,0 +1,54 @@
+#ifndef TraitWrapper_HPP
+#define TraitWrapper_HPP
+
+#include "TraitWrapper.d.hpp"
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <optional>
+#include <variant>
+#include "diplomat_runtime.hpp"
+#include "TesterTrait.hpp"
+#include "TraitWrapper.h"
+
+
+
+inline uint32_t TraitWrapper::test_with_trait(const TesterTrait& t, uint32_t x) {
+  auto result = capi::TraitWrapper_test_with_trait(t.AsFFI(),
+    x);
+  return result;
+}
+inline std::unique_ptr<TraitWrapper> TraitWrapper::from_trait(std::unique_ptr<TesterTrait> t) {
+  auto result = capi::TraitWrapper_from_trait(TesterTrait::AsFFI(std::move(t)));
+  return std::unique_ptr<TraitWrapper>(TraitWrapper::FromFFI(result));
+}
+inline uint32_t TraitWrapper::call(uint32_t x) const {
+  auto result = capi::TraitWrapper_call(this->AsFFI(),
+    x);
+  return result;
+}
+inline const capi::TraitWrapper* TraitWrapper::AsFFI() const {
+  return reinterpret_cast<const capi::TraitWrapper*>(this);
+}
+
+inline capi::TraitWrapper* TraitWrapper::AsFFI() {
+  return reinterpret_cast<capi::TraitWrapper*>(this);
+}
+
+inline const TraitWrapper* TraitWrapper::FromFFI(const capi::TraitWrapper* ptr) {
+  return reinterpret_cast<const TraitWrapper*>(ptr);
+}
+
+inline TraitWrapper* TraitWrapper::FromFFI(capi::TraitWrapper* ptr) {
+  return reinterpret_cast<TraitWrapper*>(ptr);
+}
+
+inline void TraitWrapper::operator delete(void* ptr) {
+  capi::TraitWrapper_destroy(reinterpret_cast<capi::TraitWrapper*>(ptr));
+}
+
+
+#endif // TraitWrapper_HPP
@@ -9,10 +9,12 @@
 #include <stdbool.h>
 #include <memory>
 #include <optional>
+#include <variant>
 #include "diplomat_runtime.hpp"
 #include "Two.h"
 
 
+
 inline const capi::Two* Two::AsFFI() const {
   return reinterpret_cast<const capi::Two*>(this);
 }
@@ -9,22 +9,22 @@
 #include <stdbool.h>
 #include <memory>
 #include <optional>
+#include <variant>
 #include "diplomat_runtime.hpp"
 #include "AttrOpaque1Renamed.hpp"
 #include "CPPRenamedAttrEnum.hpp"
 #include "Unnamespaced.h"
 
 
+
 inline std::unique_ptr<Unnamespaced> Unnamespaced::make(ns::CPPRenamedAttrEnum _e) {
   auto result = capi::namespace_Unnamespaced_make(_e.AsFFI());
   return std::unique_ptr<Unnamespaced>(Unnamespaced::FromFFI(result));
 }
-
 inline void Unnamespaced::use_namespaced(const ns::AttrOpaque1Renamed& _n) const {
   capi::namespace_Unnamespaced_use_namespaced(this->AsFFI(),
     _n.AsFFI());
 }
-
 inline const capi::Unnamespaced* Unnamespaced::AsFFI() const {
   return reinterpret_cast<const capi::Unnamespaced*>(this);
 }
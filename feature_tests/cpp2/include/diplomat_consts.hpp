@@ -0,0 +1,20 @@
+#ifndef diplomat_consts_HPP
+#define diplomat_consts_HPP
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <optional>
+#include <variant>
+#include "diplomat_runtime.hpp"
+
+
+inline constexpr bool ENABLED = true;
+inline constexpr std::string_view GREETING = "hello";
+inline constexpr uint32_t MAX_LEN = ((uint32_t)16);
+inline constexpr double RATIO = 0.5;
+
+
+#endif // diplomat_consts_HPP
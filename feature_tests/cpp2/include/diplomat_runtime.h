@@ -34,6 +34,8 @@ typedef struct DiplomatWriteable {
 } DiplomatWriteable;
 
 DiplomatWriteable diplomat_simple_writeable(char* buf, size_t buf_size);
+bool diplomat_panicked();
+void diplomat_take_panic(DiplomatWriteable* out);
 
 #define MAKE_SLICES(name, c_ty) \
     typedef struct Diplomat##name##View { \
@@ -0,0 +1,26 @@
+#include <iostream>
+#include "../../api/cpp2/include/Counter.hpp"
+#include "assert.hpp"
+
+// Takes the path of the library, which the wrappers only call through its API table
+int main(int argc, char *argv[]) {
+    simple_assert("library path given", argc == 2);
+    FeatureApiLoader loader(argv[1]);
+    simple_assert_eq("first load", loader.generation(), 1);
+
+    std::unique_ptr<Counter> counter = Counter::new_(41);
+    simple_assert_eq("method through the table", counter->increment(), 42);
+    simple_assert_eq("writeable through the table", counter->describe(), "Counter at 42");
+    simple_assert_eq("counters of the first library", Counter::created(), 1);
+
+    loader.reload();
+    simple_assert_eq("second load", loader.generation(), 2);
+    // The reloaded library is a new copy, with its own statics
+    simple_assert_eq("counters of the reloaded library", Counter::created(), 0);
+    // Objects of the first library are used through the new one, since the layout is the same
+    simple_assert_eq("object from before the reload", counter->increment(), 43);
+
+    std::unique_ptr<Counter> other = Counter::new_(0);
+    simple_assert_eq("new object after the reload", other->increment(), 1);
+    simple_assert_eq("counters after the reload", Counter::created(), 1);
+}
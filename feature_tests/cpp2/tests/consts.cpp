@@ -0,0 +1,15 @@
+#include <iostream>
+#include "../include/ConstHolder.hpp"
+#include "../include/diplomat_consts.hpp"
+#include "assert.hpp"
+
+int main(int argc, char *argv[]) {
+    simple_assert_eq("module const matches Rust", MAX_LEN, ConstHolder::max_len());
+    simple_assert_eq("string const", GREETING, "hello");
+    simple_assert_eq("float const", RATIO, 0.5);
+    simple_assert("bool const", ENABLED);
+    simple_assert_eq("associated const", ConstHolder::MIN_VALUE, -128);
+    simple_assert_eq("char const", ConstHolder::SEPARATOR, U'|');
+
+    static_assert(MAX_LEN == 16, "consts are usable at compile time");
+}
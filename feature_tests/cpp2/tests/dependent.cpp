@@ -0,0 +1,14 @@
+#include <iostream>
+#include "../../dependent/cpp2/include/DependentOpaque.hpp"
+#include "../include/OptionOpaque.hpp"
+#include "assert.hpp"
+
+int main(int argc, char *argv[]) {
+    std::unique_ptr<DependentOpaque> d = DependentOpaque::new_(1415);
+
+    std::unique_ptr<OptionOpaque> o = d->to_option_opaque();
+    simple_assert("to_option_opaque() returns Some", o);
+    o->assert_integer(1415);
+
+    d->assert_option_opaque(*OptionOpaque::new_(1415));
+}
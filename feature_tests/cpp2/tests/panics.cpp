@@ -0,0 +1,24 @@
+#include <iostream>
+#include "../include/PanicOpaque.hpp"
+#include "../include/PanicError.hpp"
+#include "assert.hpp"
+
+int main(int argc, char *argv[]) {
+    bool thrown = false;
+    try {
+        PanicOpaque::panics("oops");
+    } catch (const diplomat::panic& e) {
+        thrown = true;
+        simple_assert_eq("panic message", std::string(e.what()), "oops");
+    }
+    simple_assert("panic becomes an exception", thrown);
+
+    auto panicked = PanicOpaque::checked_divide(0).err().value();
+    simple_assert_eq("panic becomes an error", (int)panicked.AsFFI(), (int)PanicError(PanicError::Panicked).AsFFI());
+
+    auto too_large = PanicOpaque::checked_divide(200).err().value();
+    simple_assert_eq("regular error", (int)too_large.AsFFI(), (int)PanicError(PanicError::TooLarge).AsFFI());
+
+    simple_assert_eq("ok result", PanicOpaque::checked_divide(5).ok().value(), 20);
+    simple_assert_eq("no panic", PanicOpaque::not_panicking(1), 2);
+}
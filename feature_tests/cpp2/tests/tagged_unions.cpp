@@ -0,0 +1,24 @@
+#include <iostream>
+#include "../include/ShapeList.hpp"
+#include "../include/Shape.hpp"
+#include "assert.hpp"
+
+int main(int argc, char *argv[]) {
+    std::unique_ptr<ShapeList> list = ShapeList::new_();
+    simple_assert("empty list returns Empty", std::holds_alternative<Shape::Empty>(list->last().value));
+
+    list->push(Shape(Shape::Rect { 2.0, 3.0 }));
+    Shape last = list->last();
+    simple_assert("last() returns the pushed Rect", std::holds_alternative<Shape::Rect>(last.value));
+    simple_assert_eq("Rect width round-trips", std::get<Shape::Rect>(last.value).width, 2.0);
+    simple_assert_eq("Rect height round-trips", std::get<Shape::Rect>(last.value).height, 3.0);
+
+    list->push(Shape(Shape::Circle { 1.0 }));
+    last = list->last();
+    simple_assert("last() returns the pushed Circle", std::holds_alternative<Shape::Circle>(last.value));
+    simple_assert_eq("Circle radius round-trips", std::get<Shape::Circle>(last.value).radius, 1.0);
+
+    list->push(Shape(Shape::Empty {}));
+    double area = list->total_area();
+    simple_assert("total_area() adds up the variants", area > 9.14 && area < 9.15);
+}
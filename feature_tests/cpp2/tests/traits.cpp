@@ -0,0 +1,36 @@
+#include <iostream>
+#include "../include/TraitWrapper.hpp"
+#include "../include/TesterTrait.hpp"
+#include "assert.hpp"
+
+struct TestingStruct : TesterTrait {
+    int* destroyed;
+    mutable int void_calls = 0;
+
+    TestingStruct(int* destroyed) : destroyed(destroyed) {}
+    ~TestingStruct() { *destroyed += 1; }
+
+    uint32_t test_trait_fn(uint32_t x) const override {
+        return x + 5;
+    }
+
+    void test_void_trait_fn() const override {
+        void_calls += 1;
+    }
+};
+
+int main(int argc, char *argv[]) {
+    int destroyed = 0;
+
+    TestingStruct borrowed(&destroyed);
+    simple_assert_eq("borrowed trait is called", TraitWrapper::test_with_trait(borrowed, 10), 15);
+    simple_assert_eq("void method is called", borrowed.void_calls, 1);
+    simple_assert_eq("borrowed trait is not destroyed by Rust", destroyed, 0);
+
+    {
+        std::unique_ptr<TraitWrapper> wrapper = TraitWrapper::from_trait(std::make_unique<TestingStruct>(&destroyed));
+        simple_assert_eq("owned trait is called", wrapper->call(1), 6);
+        simple_assert_eq("owned trait is kept alive", destroyed, 0);
+    }
+    simple_assert_eq("owned trait is destroyed with its owner", destroyed, 1);
+}
@@ -0,0 +1,42 @@
+// generated by diplomat-tool
+
+part of 'lib.g.dart';
+
+final class ConstHolder implements ffi.Finalizable {
+  final ffi.Pointer<ffi.Opaque> _ffi;
+
+  // These are "used" in the sense that they keep dependencies alive
+  // ignore: unused_field
+  final core.List<Object> _selfEdge;
+
+  // This takes in a list of lifetime edges (including for &self borrows)
+  // corresponding to data this may borrow from. These should be flat arrays containing
+  // references to objects, and this object will hold on to them to keep them alive and
+  // maintain borrow validity.
+  ConstHolder._fromFfi(this._ffi, this._selfEdge) {
+    if (_selfEdge.isEmpty) {
+      _finalizer.attach(this, _ffi.cast());
+    }
+  }
+
+  static final _finalizer = ffi.NativeFinalizer(ffi.Native.addressOf(_ConstHolder_destroy));
+
+  static const int minValue = -128;
+
+  static const Rune separator = 124;
+
+  static int maxLen() {
+    final result = _ConstHolder_max_len();
+    return result;
+  }
+}
+
+@meta.ResourceIdentifier('ConstHolder_destroy')
+@ffi.Native<ffi.Void Function(ffi.Pointer<ffi.Void>)>(isLeaf: true, symbol: 'ConstHolder_destroy')
+// ignore: non_constant_identifier_names
+external void _ConstHolder_destroy(ffi.Pointer<ffi.Void> self);
+
+@meta.ResourceIdentifier('ConstHolder_max_len')
+@ffi.Native<ffi.Uint32 Function()>(isLeaf: true, symbol: 'ConstHolder_max_len')
+// ignore: non_constant_identifier_names
+external int _ConstHolder_max_len();
@@ -0,0 +1,9 @@
+// generated by diplomat-tool
+
+part of 'lib.g.dart';
+
+enum PanicError {
+  panicked,
+
+  tooLarge;
+}
@@ -0,0 +1,74 @@
+// generated by diplomat-tool
+
+part of 'lib.g.dart';
+
+final class PanicOpaque implements ffi.Finalizable {
+  final ffi.Pointer<ffi.Opaque> _ffi;
+
+  // These are "used" in the sense that they keep dependencies alive
+  // ignore: unused_field
+  final core.List<Object> _selfEdge;
+
+  // This takes in a list of lifetime edges (including for &self borrows)
+  // corresponding to data this may borrow from. These should be flat arrays containing
+  // references to objects, and this object will hold on to them to keep them alive and
+  // maintain borrow validity.
+  PanicOpaque._fromFfi(this._ffi, this._selfEdge) {
+    if (_selfEdge.isEmpty) {
+      _finalizer.attach(this, _ffi.cast());
+    }
+  }
+
+  static final _finalizer = ffi.NativeFinalizer(ffi.Native.addressOf(_PanicOpaque_destroy));
+
+  /// Panics, which foreign code is told about after the call
+  ///
+  /// Throws [StateError] if it panics.
+  static void panics(String message) {
+    final temp = ffi2.Arena();
+    final messageView = message.utf8View;
+    _PanicOpaque_panics(messageView.allocIn(temp), messageView.length);
+    temp.releaseAll();
+    _checkPanic();
+  }
+
+  /// Panics when `x` is zero, which is reported as `PanicError::Panicked`
+  ///
+  /// Throws [PanicError] on failure.
+  static int checkedDivide(int x) {
+    final result = _PanicOpaque_checked_divide(x);
+    if (!result.isOk) {
+      throw PanicError.values[result.union.err];
+    }
+    return result.union.ok;
+  }
+
+  /// 
+  ///
+  /// Throws [StateError] if it panics.
+  static int notPanicking(int x) {
+    final result = _PanicOpaque_not_panicking(x);
+    _checkPanic();
+    return result;
+  }
+}
+
+@meta.ResourceIdentifier('PanicOpaque_destroy')
+@ffi.Native<ffi.Void Function(ffi.Pointer<ffi.Void>)>(isLeaf: true, symbol: 'PanicOpaque_destroy')
+// ignore: non_constant_identifier_names
+external void _PanicOpaque_destroy(ffi.Pointer<ffi.Void> self);
+
+@meta.ResourceIdentifier('PanicOpaque_panics')
+@ffi.Native<ffi.Void Function(ffi.Pointer<ffi.Uint8>, ffi.Size)>(isLeaf: true, symbol: 'PanicOpaque_panics')
+// ignore: non_constant_identifier_names
+external void _PanicOpaque_panics(ffi.Pointer<ffi.Uint8> messageData, int messageLength);
+
+@meta.ResourceIdentifier('PanicOpaque_checked_divide')
+@ffi.Native<_ResultUint32Int32 Function(ffi.Uint32)>(isLeaf: true, symbol: 'PanicOpaque_checked_divide')
+// ignore: non_constant_identifier_names
+external _ResultUint32Int32 _PanicOpaque_checked_divide(int x);
+
+@meta.ResourceIdentifier('PanicOpaque_not_panicking')
+@ffi.Native<ffi.Uint32 Function(ffi.Uint32)>(isLeaf: true, symbol: 'PanicOpaque_not_panicking')
+// ignore: non_constant_identifier_names
+external int _PanicOpaque_not_panicking(int x);
@@ -0,0 +1,125 @@
+// generated by diplomat-tool
+
+part of 'lib.g.dart';
+
+sealed class Shape {
+  const Shape();
+
+  // ignore: unused_element
+  factory Shape._fromFfi(_ShapeFfi ffi) {
+    switch (ffi.tag) {
+      case 0:
+        return ShapeCircle(radius: ffi.payload.circle.radius);
+      case 1:
+        return ShapeRect(width: ffi.payload.rect.width, height: ffi.payload.rect.height);
+      case 2:
+        return ShapeEmpty();
+      default:
+        throw core.StateError('Unknown Shape tag ${ffi.tag}');
+    }
+  }
+
+  // ignore: unused_element
+  _ShapeFfi _toFfi(ffi.Allocator temp);
+}
+
+final class ShapeCircle extends Shape {
+  final double radius;
+
+  const ShapeCircle({required this.radius});
+
+  @override
+  // ignore: unused_element
+  _ShapeFfi _toFfi(ffi.Allocator temp) {
+    final struct = ffi.Struct.create<_ShapeFfi>();
+    struct.tag = 0;
+    struct.payload.circle.radius = radius;
+    return struct;
+  }
+
+  @override
+  bool operator ==(Object other) =>
+      other is ShapeCircle &&
+      other.radius == radius;
+
+  @override
+  int get hashCode => Object.hashAll([
+        0,
+        radius,
+      ]);
+}
+
+final class ShapeRect extends Shape {
+  final double width;
+  final double height;
+
+  const ShapeRect({required this.width, required this.height});
+
+  @override
+  // ignore: unused_element
+  _ShapeFfi _toFfi(ffi.Allocator temp) {
+    final struct = ffi.Struct.create<_ShapeFfi>();
+    struct.tag = 1;
+    struct.payload.rect.width = width;
+    struct.payload.rect.height = height;
+    return struct;
+  }
+
+  @override
+  bool operator ==(Object other) =>
+      other is ShapeRect &&
+      other.width == width &&
+      other.height == height;
+
+  @override
+  int get hashCode => Object.hashAll([
+        1,
+        width,
+        height,
+      ]);
+}
+
+final class ShapeEmpty extends Shape {
+
+  const ShapeEmpty();
+
+  @override
+  // ignore: unused_element
+  _ShapeFfi _toFfi(ffi.Allocator temp) {
+    final struct = ffi.Struct.create<_ShapeFfi>();
+    struct.tag = 2;
+    return struct;
+  }
+
+  @override
+  bool operator ==(Object other) =>
+      other is ShapeEmpty;
+
+  @override
+  int get hashCode => Object.hashAll([
+        2,
+      ]);
+}
+
+final class _ShapeFfi extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+  external _ShapePayloadFfi payload;
+}
+
+final class _ShapePayloadFfi extends ffi.Union {
+  external _ShapeCircleFfi circle;
+  external _ShapeRectFfi rect;
+}
+
+final class _ShapeCircleFfi extends ffi.Struct {
+  @ffi.Double()
+  external double radius;
+}
+
+final class _ShapeRectFfi extends ffi.Struct {
+  @ffi.Double()
+  external double width;
+  @ffi.Double()
+  external double height;
+}
@@ -0,0 +1,69 @@
+// generated by diplomat-tool
+
+part of 'lib.g.dart';
+
+final class ShapeList implements ffi.Finalizable {
+  final ffi.Pointer<ffi.Opaque> _ffi;
+
+  // These are "used" in the sense that they keep dependencies alive
+  // ignore: unused_field
+  final core.List<Object> _selfEdge;
+
+  // This takes in a list of lifetime edges (including for &self borrows)
+  // corresponding to data this may borrow from. These should be flat arrays containing
+  // references to objects, and this object will hold on to them to keep them alive and
+  // maintain borrow validity.
+  ShapeList._fromFfi(this._ffi, this._selfEdge) {
+    if (_selfEdge.isEmpty) {
+      _finalizer.attach(this, _ffi.cast());
+    }
+  }
+
+  static final _finalizer = ffi.NativeFinalizer(ffi.Native.addressOf(_ShapeList_destroy));
+
+  factory ShapeList() {
+    final result = _ShapeList_new();
+    return ShapeList._fromFfi(result, []);
+  }
+
+  void push(Shape shape) {
+    final temp = ffi2.Arena();
+    _ShapeList_push(_ffi, shape._toFfi(temp));
+    temp.releaseAll();
+  }
+
+  Shape last() {
+    final result = _ShapeList_last(_ffi);
+    return Shape._fromFfi(result);
+  }
+
+  double totalArea() {
+    final result = _ShapeList_total_area(_ffi);
+    return result;
+  }
+}
+
+@meta.ResourceIdentifier('ShapeList_destroy')
+@ffi.Native<ffi.Void Function(ffi.Pointer<ffi.Void>)>(isLeaf: true, symbol: 'ShapeList_destroy')
+// ignore: non_constant_identifier_names
+external void _ShapeList_destroy(ffi.Pointer<ffi.Void> self);
+
+@meta.ResourceIdentifier('ShapeList_new')
+@ffi.Native<ffi.Pointer<ffi.Opaque> Function()>(isLeaf: true, symbol: 'ShapeList_new')
+// ignore: non_constant_identifier_names
+external ffi.Pointer<ffi.Opaque> _ShapeList_new();
+
+@meta.ResourceIdentifier('ShapeList_push')
+@ffi.Native<ffi.Void Function(ffi.Pointer<ffi.Opaque>, _ShapeFfi)>(isLeaf: true, symbol: 'ShapeList_push')
+// ignore: non_constant_identifier_names
+external void _ShapeList_push(ffi.Pointer<ffi.Opaque> self, _ShapeFfi shape);
+
+@meta.ResourceIdentifier('ShapeList_last')
+@ffi.Native<_ShapeFfi Function(ffi.Pointer<ffi.Opaque>)>(isLeaf: true, symbol: 'ShapeList_last')
+// ignore: non_constant_identifier_names
+external _ShapeFfi _ShapeList_last(ffi.Pointer<ffi.Opaque> self);
+
+@meta.ResourceIdentifier('ShapeList_total_area')
+@ffi.Native<ffi.Double Function(ffi.Pointer<ffi.Opaque>)>(isLeaf: true, symbol: 'ShapeList_total_area')
+// ignore: non_constant_identifier_names
+external double _ShapeList_total_area(ffi.Pointer<ffi.Opaque> self);
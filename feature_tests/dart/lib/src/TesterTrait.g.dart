@@ -0,0 +1,49 @@
+// generated by diplomat-tool
+
+part of 'lib.g.dart';
+
+final class _TesterTraitVTable extends ffi.Struct {
+  external ffi.Pointer<ffi.Void> ctx;
+  external ffi.Pointer<ffi.NativeFunction<ffi.Uint32 Function(ffi.Pointer<ffi.Void>, ffi.Uint32)>> test_trait_fn;
+  external ffi.Pointer<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>> test_void_trait_fn;
+  external ffi.Pointer<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>> destroy;
+
+  // Implementations handed to Rust, keyed by the address used as their context
+  static final _impls = <int, TesterTrait>{};
+  static int _nextCtx = 1;
+
+  // Borrowed implementations are only registered until `temp` is released
+  static _TesterTraitVTable _fromDart(TesterTrait impl, ffi2.Arena temp, {required bool owned}) {
+    final ctx = _nextCtx++;
+    _impls[ctx] = impl;
+    final vtable = temp<_TesterTraitVTable>().ref;
+    vtable.ctx = ffi.Pointer.fromAddress(ctx);
+    vtable.test_trait_fn = ffi.Pointer.fromFunction<ffi.Uint32 Function(ffi.Pointer<ffi.Void>, ffi.Uint32)>(_test_trait_fn, 0);
+    vtable.test_void_trait_fn = ffi.Pointer.fromFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>(_test_void_trait_fn);
+    if (owned) {
+      vtable.destroy = ffi.Pointer.fromFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>(_destroy);
+    } else {
+      vtable.destroy = ffi.Pointer.fromAddress(0);
+      temp.using(ctx, _impls.remove);
+    }
+    return vtable;
+  }
+
+  static int _test_trait_fn(ffi.Pointer<ffi.Void> ctx, int x) {
+    final result = _impls[ctx.address]!.testTraitFn(x);
+    return result;
+  }
+
+  static void _test_void_trait_fn(ffi.Pointer<ffi.Void> ctx) {
+    _impls[ctx.address]!.testVoidTraitFn();
+  }
+
+  static void _destroy(ffi.Pointer<ffi.Void> ctx) {
+    _impls.remove(ctx.address);
+  }
+}
+
+abstract interface class TesterTrait {
+  int testTraitFn(int x);
+  void testVoidTraitFn();
+}
@@ -0,0 +1,62 @@
+// generated by diplomat-tool
+
+part of 'lib.g.dart';
+
+final class TraitWrapper implements ffi.Finalizable {
+  final ffi.Pointer<ffi.Opaque> _ffi;
+
+  // These are "used" in the sense that they keep dependencies alive
+  // ignore: unused_field
+  final core.List<Object> _selfEdge;
+
+  // This takes in a list of lifetime edges (including for &self borrows)
+  // corresponding to data this may borrow from. These should be flat arrays containing
+  // references to objects, and this object will hold on to them to keep them alive and
+  // maintain borrow validity.
+  TraitWrapper._fromFfi(this._ffi, this._selfEdge) {
+    if (_selfEdge.isEmpty) {
+      _finalizer.attach(this, _ffi.cast());
+    }
+  }
+
+  static final _finalizer = ffi.NativeFinalizer(ffi.Native.addressOf(_TraitWrapper_destroy));
+
+  static int testWithTrait(TesterTrait t, int x) {
+    final temp = ffi2.Arena();
+    final result = _TraitWrapper_test_with_trait(_TesterTraitVTable._fromDart(t, temp, owned: false), x);
+    temp.releaseAll();
+    return result;
+  }
+
+  static TraitWrapper fromTrait(TesterTrait t) {
+    final temp = ffi2.Arena();
+    final result = _TraitWrapper_from_trait(_TesterTraitVTable._fromDart(t, temp, owned: true));
+    temp.releaseAll();
+    return TraitWrapper._fromFfi(result, []);
+  }
+
+  int call(int x) {
+    final result = _TraitWrapper_call(_ffi, x);
+    return result;
+  }
+}
+
+@meta.ResourceIdentifier('TraitWrapper_destroy')
+@ffi.Native<ffi.Void Function(ffi.Pointer<ffi.Void>)>(isLeaf: true, symbol: 'TraitWrapper_destroy')
+// ignore: non_constant_identifier_names
+external void _TraitWrapper_destroy(ffi.Pointer<ffi.Void> self);
+
+@meta.ResourceIdentifier('TraitWrapper_test_with_trait')
+@ffi.Native<ffi.Uint32 Function(_TesterTraitVTable, ffi.Uint32)>(isLeaf: false, symbol: 'TraitWrapper_test_with_trait')
+// ignore: non_constant_identifier_names
+external int _TraitWrapper_test_with_trait(_TesterTraitVTable t, int x);
+
+@meta.ResourceIdentifier('TraitWrapper_from_trait')
+@ffi.Native<ffi.Pointer<ffi.Opaque> Function(_TesterTraitVTable)>(isLeaf: false, symbol: 'TraitWrapper_from_trait')
+// ignore: non_constant_identifier_names
+external ffi.Pointer<ffi.Opaque> _TraitWrapper_from_trait(_TesterTraitVTable t);
+
+@meta.ResourceIdentifier('TraitWrapper_call')
+@ffi.Native<ffi.Uint32 Function(ffi.Pointer<ffi.Opaque>, ffi.Uint32)>(isLeaf: true, symbol: 'TraitWrapper_call')
+// ignore: non_constant_identifier_names
+external int _TraitWrapper_call(ffi.Pointer<ffi.Opaque> self, int x);
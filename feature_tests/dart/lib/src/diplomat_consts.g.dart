@@ -0,0 +1,12 @@
+// generated by diplomat-tool
+
+part of 'lib.g.dart';
+
+const bool enabled = true;
+
+const String greeting = 'hello';
+
+/// The length `ConstHolder::max_len` returns
+const int maxLen = 16;
+
+const double ratio = 0.5;
@@ -16,6 +16,7 @@ part 'BorrowedFields.g.dart';
 part 'BorrowedFieldsReturning.g.dart';
 part 'BorrowedFieldsWithBounds.g.dart';
 part 'Comparable.g.dart';
+part 'ConstHolder.g.dart';
 part 'ContiguousEnum.g.dart';
 part 'ErrorEnum.g.dart';
 part 'ErrorStruct.g.dart';
@@ -33,12 +34,19 @@ part 'Opaque.g.dart';
 part 'OptionOpaque.g.dart';
 part 'OptionOpaqueChar.g.dart';
 part 'OptionStruct.g.dart';
+part 'PanicError.g.dart';
+part 'PanicOpaque.g.dart';
 part 'RefList.g.dart';
 part 'RefListParameter.g.dart';
 part 'ResultOpaque.g.dart';
+part 'Shape.g.dart';
+part 'ShapeList.g.dart';
+part 'TesterTrait.g.dart';
+part 'TraitWrapper.g.dart';
 part 'Two.g.dart';
 part 'UnimportedEnum.g.dart';
 part 'Unnamespaced.g.dart';
+part 'diplomat_consts.g.dart';
 
 /// A [Rune] is a Unicode code point, such as `a`, or `💡`.
 /// 
@@ -520,6 +528,21 @@ final class _ResultOptionStructFfiVoid extends ffi.Struct {
   external bool isOk;
 }
 
+final class _ResultUint32Int32Union extends ffi.Union {
+  @ffi.Uint32()
+  external int ok;
+
+  @ffi.Int32()
+  external int err;
+}
+
+final class _ResultUint32Int32 extends ffi.Struct {
+  external _ResultUint32Int32Union union;
+
+  @ffi.Bool()
+  external bool isOk;
+}
+
 final class _ResultUint8VoidUnion extends ffi.Union {
   @ffi.Uint8()
   external int ok;
@@ -648,6 +671,25 @@ final class _SliceUtf8 extends ffi.Struct {
   }
 }
 
+@meta.ResourceIdentifier('diplomat_panicked')
+@ffi.Native<ffi.Bool Function()>(symbol: 'diplomat_panicked', isLeaf: true)
+// ignore: non_constant_identifier_names
+external bool _diplomat_panicked();
+
+@meta.ResourceIdentifier('diplomat_take_panic')
+@ffi.Native<ffi.Void Function(ffi.Pointer<ffi.Opaque>)>(symbol: 'diplomat_take_panic', isLeaf: true)
+// ignore: non_constant_identifier_names
+external void _diplomat_take_panic(ffi.Pointer<ffi.Opaque> out);
+
+/// Throws a [StateError] with the message of the panic of the last call, if it panicked.
+void _checkPanic() {
+  if (_diplomat_panicked()) {
+    final writeable = _Writeable();
+    _diplomat_take_panic(writeable._ffi);
+    throw StateError(writeable.finalize());
+  }
+}
+
 final class _Writeable {
   final ffi.Pointer<ffi.Opaque> _ffi;
 
@@ -0,0 +1,13 @@
+import 'package:feature_tests/lib.dart';
+import 'package:test/test.dart';
+
+void main() {
+  test("Verify consts", () {
+    expect(maxLen, ConstHolder.maxLen());
+    expect(greeting, 'hello');
+    expect(ratio, 0.5);
+    expect(enabled, true);
+    expect(ConstHolder.minValue, -128);
+    expect(ConstHolder.separator, '|'.runes.first);
+  });
+}
@@ -0,0 +1,12 @@
+import 'package:feature_tests/lib.dart';
+import 'package:test/test.dart';
+
+void main() {
+  test("Verify panics", () {
+    expect(() => PanicOpaque.panics('oops'), throwsA(isA<StateError>().having((e) => e.message, 'message', 'oops')));
+    expect(() => PanicOpaque.checkedDivide(0), throwsA(PanicError.panicked));
+    expect(() => PanicOpaque.checkedDivide(200), throwsA(PanicError.tooLarge));
+    expect(PanicOpaque.checkedDivide(5), 20);
+    expect(PanicOpaque.notPanicking(1), 2);
+  });
+}
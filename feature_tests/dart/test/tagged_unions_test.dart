@@ -0,0 +1,18 @@
+import 'package:feature_tests/lib.dart';
+import 'package:test/test.dart';
+
+void main() {
+  test("Verify tagged unions round-trip", () {
+    final list = ShapeList();
+    expect(list.last(), const ShapeEmpty());
+
+    list.push(const ShapeRect(width: 2, height: 3));
+    expect(list.last(), const ShapeRect(width: 2, height: 3));
+
+    list.push(const ShapeCircle(radius: 1));
+    expect(list.last(), const ShapeCircle(radius: 1));
+
+    list.push(const ShapeEmpty());
+    expect(list.totalArea(), closeTo(9.1416, 0.0001));
+  });
+}
@@ -0,0 +1,25 @@
+import 'package:feature_tests/lib.dart';
+import 'package:test/test.dart';
+
+class TestingTrait implements TesterTrait {
+  int voidCalls = 0;
+
+  @override
+  int testTraitFn(int x) => x + 5;
+
+  @override
+  void testVoidTraitFn() {
+    voidCalls++;
+  }
+}
+
+void main() {
+  test("Verify traits implemented in Dart", () {
+    final borrowed = TestingTrait();
+    expect(TraitWrapper.testWithTrait(borrowed, 10), 15);
+    expect(borrowed.voidCalls, 1);
+
+    final wrapper = TraitWrapper.fromTrait(TestingTrait());
+    expect(wrapper.call(1), 6);
+  });
+}
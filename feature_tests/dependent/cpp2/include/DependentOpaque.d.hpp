@@ -0,0 +1,41 @@
+#ifndef DependentOpaque_D_HPP
+#define DependentOpaque_D_HPP
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <optional>
+#include <variant>
+#include "diplomat_runtime.hpp"
+#include "DependentOpaque.d.h"
+
+class OptionOpaque;
+
+
+class DependentOpaque {
+public:
+
+  inline static std::unique_ptr<DependentOpaque> new_(int32_t i);
+
+  inline std::unique_ptr<OptionOpaque> to_option_opaque() const;
+
+  inline void assert_option_opaque(const OptionOpaque& o) const;
+
+  inline const capi::DependentOpaque* AsFFI() const;
+  inline capi::DependentOpaque* AsFFI();
+  inline static const DependentOpaque* FromFFI(const capi::DependentOpaque* ptr);
+  inline static DependentOpaque* FromFFI(capi::DependentOpaque* ptr);
+  inline static void operator delete(void* ptr);
+private:
+  DependentOpaque() = delete;
+  DependentOpaque(const DependentOpaque&) = delete;
+  DependentOpaque(DependentOpaque&&) noexcept = delete;
+  DependentOpaque operator=(const DependentOpaque&) = delete;
+  DependentOpaque operator=(DependentOpaque&&) noexcept = delete;
+  static void operator delete[](void*, size_t) = delete;
+};
+
+
+#endif // DependentOpaque_D_HPP
@@ -0,0 +1,34 @@
+#ifndef DependentOpaque_H
+#define DependentOpaque_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+#include "OptionOpaque.d.h"
+#include "OptionOpaque.h"
+
+#include "DependentOpaque.d.h"
+
+#ifdef __cplusplus
+namespace capi {
+extern "C" {
+#endif // __cplusplus
+
+
+DependentOpaque* DependentOpaque_new(int32_t i);
+
+OptionOpaque* DependentOpaque_to_option_opaque(const DependentOpaque* self);
+
+void DependentOpaque_assert_option_opaque(const DependentOpaque* self, const OptionOpaque* o);
+
+void DependentOpaque_destroy(DependentOpaque* self);
+
+
+#ifdef __cplusplus
+} // extern "C"
+} // namespace capi
+#endif // __cplusplus
+
+#endif // DependentOpaque_H
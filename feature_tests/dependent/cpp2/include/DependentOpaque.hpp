@@ -0,0 +1,52 @@
+#ifndef DependentOpaque_HPP
+#define DependentOpaque_HPP
+
+#include "DependentOpaque.d.hpp"
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <memory>
+#include <optional>
+#include <variant>
+#include "diplomat_runtime.hpp"
+#include "DependentOpaque.h"
+#include "OptionOpaque.hpp"
+
+
+
+inline std::unique_ptr<DependentOpaque> DependentOpaque::new_(int32_t i) {
+  auto result = capi::DependentOpaque_new(i);
+  return std::unique_ptr<DependentOpaque>(DependentOpaque::FromFFI(result));
+}
+inline std::unique_ptr<OptionOpaque> DependentOpaque::to_option_opaque() const {
+  auto result = capi::DependentOpaque_to_option_opaque(this->AsFFI());
+  return std::unique_ptr<OptionOpaque>(OptionOpaque::FromFFI(result));
+}
+inline void DependentOpaque::assert_option_opaque(const OptionOpaque& o) const {
+  capi::DependentOpaque_assert_option_opaque(this->AsFFI(),
+    o.AsFFI());
+}
+inline const capi::DependentOpaque* DependentOpaque::AsFFI() const {
+  return reinterpret_cast<const capi::DependentOpaque*>(this);
+}
+
+inline capi::DependentOpaque* DependentOpaque::AsFFI() {
+  return reinterpret_cast<capi::DependentOpaque*>(this);
+}
+
+inline const DependentOpaque* DependentOpaque::FromFFI(const capi::DependentOpaque* ptr) {
+  return reinterpret_cast<const DependentOpaque*>(ptr);
+}
+
+inline DependentOpaque* DependentOpaque::FromFFI(capi::DependentOpaque* ptr) {
+  return reinterpret_cast<DependentOpaque*>(ptr);
+}
+
+inline void DependentOpaque::operator delete(void* ptr) {
+  capi::DependentOpaque_destroy(reinterpret_cast<capi::DependentOpaque*>(ptr));
+}
+
+
+#endif // DependentOpaque_HPP
@@ -0,0 +1,25 @@
+//! A bridge crate that uses the types of the bridge of another crate, whose bindings
+//! import those types from the bindings of `diplomat-feature-tests`
+
+#[diplomat::bridge]
+pub mod ffi {
+    use diplomat_feature_tests::option::ffi::OptionOpaque;
+
+    #[diplomat::opaque]
+    pub struct DependentOpaque(i32);
+
+    impl DependentOpaque {
+        #[diplomat::attr(supports = constructors, constructor)]
+        pub fn new(i: i32) -> Box<DependentOpaque> {
+            Box::new(DependentOpaque(i))
+        }
+
+        pub fn to_option_opaque(&self) -> Option<Box<OptionOpaque>> {
+            OptionOpaque::new(self.0)
+        }
+
+        pub fn assert_option_opaque(&self, o: &OptionOpaque) {
+            o.assert_integer(self.0);
+        }
+    }
+}
@@ -0,0 +1,76 @@
+// <auto-generated/> by Diplomat
+
+#pragma warning disable 0105
+using System;
+using System.Runtime.InteropServices;
+
+using DiplomatFeatures.Diplomat;
+#pragma warning restore 0105
+
+namespace DiplomatFeatures;
+
+#nullable enable
+
+public partial class ConstHolder: IDisposable
+{
+    private unsafe Raw.ConstHolder* _inner;
+
+    public const sbyte MinValue = -128;
+
+    public const uint Separator = 124;
+
+    /// <summary>
+    /// Creates a managed <c>ConstHolder</c> from a raw handle.
+    /// </summary>
+    /// <remarks>
+    /// Safety: you should not build two managed objects using the same raw handle (may causes use-after-free and double-free).
+    /// <br/>
+    /// This constructor assumes the raw struct is allocated on Rust side.
+    /// If implemented, the custom Drop implementation on Rust side WILL run on destruction.
+    /// </remarks>
+    public unsafe ConstHolder(Raw.ConstHolder* handle)
+    {
+        _inner = handle;
+    }
+
+    public static uint MaxLen()
+    {
+        unsafe
+        {
+            uint retVal = Raw.ConstHolder.MaxLen();
+            return retVal;
+        }
+    }
+
+    /// <summary>
+    /// Returns the underlying raw handle.
+    /// </summary>
+    public unsafe Raw.ConstHolder* AsFFI()
+    {
+        return _inner;
+    }
+
+    /// <summary>
+    /// Destroys the underlying object immediately.
+    /// </summary>
+    public void Dispose()
+    {
+        unsafe
+        {
+            if (_inner == null)
+            {
+                return;
+            }
+
+            Raw.ConstHolder.Destroy(_inner);
+            _inner = null;
+
+            GC.SuppressFinalize(this);
+        }
+    }
+
+    ~ConstHolder()
+    {
+        Dispose();
+    }
+}
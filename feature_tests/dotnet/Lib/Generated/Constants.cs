@@ -0,0 +1,26 @@
+// <auto-generated/> by Diplomat
+
+#pragma warning disable 0105
+using System;
+using System.Runtime.InteropServices;
+
+using DiplomatFeatures.Diplomat;
+#pragma warning restore 0105
+
+namespace DiplomatFeatures;
+
+#nullable enable
+
+public static class Constants
+{
+    public const bool Enabled = true;
+
+    public const string Greeting = "hello";
+
+    /// <summary>
+    /// The length `ConstHolder::max_len` returns
+    /// </summary>
+    public const uint MaxLen = 16;
+
+    public const double Ratio = 0.5;
+}
@@ -0,0 +1,30 @@
+// <auto-generated/> by Diplomat
+
+#pragma warning disable 0105
+using System;
+using System.Runtime.InteropServices;
+
+using DiplomatFeatures.Diplomat;
+#pragma warning restore 0105
+
+namespace DiplomatFeatures;
+
+#nullable enable
+
+public partial class PanicErrorException : Exception
+{
+    private PanicError _inner;
+
+    public PanicErrorException(PanicError inner) : base("PanicError error occurred")
+    {
+        _inner = inner;
+    }
+
+    public PanicError Inner
+    {
+        get
+        {
+            return _inner;
+        }
+    }
+}
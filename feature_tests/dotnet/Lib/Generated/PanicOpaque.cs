@@ -0,0 +1,106 @@
+// <auto-generated/> by Diplomat
+
+#pragma warning disable 0105
+using System;
+using System.Runtime.InteropServices;
+
+using DiplomatFeatures.Diplomat;
+#pragma warning restore 0105
+
+namespace DiplomatFeatures;
+
+#nullable enable
+
+public partial class PanicOpaque: IDisposable
+{
+    private unsafe Raw.PanicOpaque* _inner;
+
+    /// <summary>
+    /// Creates a managed <c>PanicOpaque</c> from a raw handle.
+    /// </summary>
+    /// <remarks>
+    /// Safety: you should not build two managed objects using the same raw handle (may causes use-after-free and double-free).
+    /// <br/>
+    /// This constructor assumes the raw struct is allocated on Rust side.
+    /// If implemented, the custom Drop implementation on Rust side WILL run on destruction.
+    /// </remarks>
+    public unsafe PanicOpaque(Raw.PanicOpaque* handle)
+    {
+        _inner = handle;
+    }
+
+    /// <summary>
+    /// Panics, which foreign code is told about after the call
+    /// </summary>
+    public static void Panics(string message)
+    {
+        unsafe
+        {
+            byte[] messageBuf = DiplomatUtils.StringToUtf8(message);
+            nuint messageBufLength = (nuint)messageBuf.Length;
+            fixed (byte* messageBufPtr = messageBuf)
+            {
+                Raw.PanicOpaque.Panics(messageBufPtr, messageBufLength);
+            }
+        }
+    }
+
+    /// <summary>
+    /// Panics when `x` is zero, which is reported as `PanicError::Panicked`
+    /// </summary>
+    /// <exception cref="PanicErrorException"></exception>
+    public static uint CheckedDivide(uint x)
+    {
+        unsafe
+        {
+            Raw.PanicsFfiResultU32PanicError result = Raw.PanicOpaque.CheckedDivide(x);
+            if (!result.isOk)
+            {
+                throw new PanicErrorException((PanicError)result.Err);
+            }
+            uint retVal = result.Ok;
+            return retVal;
+        }
+    }
+
+    public static uint NotPanicking(uint x)
+    {
+        unsafe
+        {
+            uint retVal = Raw.PanicOpaque.NotPanicking(x);
+            return retVal;
+        }
+    }
+
+    /// <summary>
+    /// Returns the underlying raw handle.
+    /// </summary>
+    public unsafe Raw.PanicOpaque* AsFFI()
+    {
+        return _inner;
+    }
+
+    /// <summary>
+    /// Destroys the underlying object immediately.
+    /// </summary>
+    public void Dispose()
+    {
+        unsafe
+        {
+            if (_inner == null)
+            {
+                return;
+            }
+
+            Raw.PanicOpaque.Destroy(_inner);
+            _inner = null;
+
+            GC.SuppressFinalize(this);
+        }
+    }
+
+    ~PanicOpaque()
+    {
+        Dispose();
+    }
+}
@@ -0,0 +1,24 @@
+// <auto-generated/> by Diplomat
+
+#pragma warning disable 0105
+using System;
+using System.Runtime.InteropServices;
+
+using DiplomatFeatures.Diplomat;
+#pragma warning restore 0105
+
+namespace DiplomatFeatures.Raw;
+
+#nullable enable
+
+[StructLayout(LayoutKind.Sequential)]
+public partial struct ConstHolder
+{
+    private const string NativeLib = "diplomat_feature_tests";
+
+    [DllImport(NativeLib, CallingConvention = CallingConvention.Cdecl, EntryPoint = "ConstHolder_max_len", ExactSpelling = true)]
+    public static unsafe extern uint MaxLen();
+
+    [DllImport(NativeLib, CallingConvention = CallingConvention.Cdecl, EntryPoint = "ConstHolder_destroy", ExactSpelling = true)]
+    public static unsafe extern void Destroy(ConstHolder* self);
+}
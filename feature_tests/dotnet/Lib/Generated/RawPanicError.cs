@@ -0,0 +1,18 @@
+// <auto-generated/> by Diplomat
+
+#pragma warning disable 0105
+using System;
+using System.Runtime.InteropServices;
+
+using DiplomatFeatures.Diplomat;
+#pragma warning restore 0105
+
+namespace DiplomatFeatures.Raw;
+
+#nullable enable
+
+public enum PanicError
+{
+    Panicked = 0,
+    TooLarge = 1,
+}
@@ -0,0 +1,36 @@
+// <auto-generated/> by Diplomat
+
+#pragma warning disable 0105
+using System;
+using System.Runtime.InteropServices;
+
+using DiplomatFeatures.Diplomat;
+#pragma warning restore 0105
+
+namespace DiplomatFeatures.Raw;
+
+#nullable enable
+
+[StructLayout(LayoutKind.Sequential)]
+public partial struct PanicOpaque
+{
+    private const string NativeLib = "diplomat_feature_tests";
+
+    /// <summary>
+    /// Panics, which foreign code is told about after the call
+    /// </summary>
+    [DllImport(NativeLib, CallingConvention = CallingConvention.Cdecl, EntryPoint = "PanicOpaque_panics", ExactSpelling = true)]
+    public static unsafe extern void Panics(byte* message, nuint messageSz);
+
+    /// <summary>
+    /// Panics when `x` is zero, which is reported as `PanicError::Panicked`
+    /// </summary>
+    [DllImport(NativeLib, CallingConvention = CallingConvention.Cdecl, EntryPoint = "PanicOpaque_checked_divide", ExactSpelling = true)]
+    public static unsafe extern PanicsFfiResultU32PanicError CheckedDivide(uint x);
+
+    [DllImport(NativeLib, CallingConvention = CallingConvention.Cdecl, EntryPoint = "PanicOpaque_not_panicking", ExactSpelling = true)]
+    public static unsafe extern uint NotPanicking(uint x);
+
+    [DllImport(NativeLib, CallingConvention = CallingConvention.Cdecl, EntryPoint = "PanicOpaque_destroy", ExactSpelling = true)]
+    public static unsafe extern void Destroy(PanicOpaque* self);
+}
@@ -0,0 +1,46 @@
+// <auto-generated/> by Diplomat
+
+#pragma warning disable 0105
+using System;
+using System.Runtime.InteropServices;
+
+using DiplomatFeatures.Diplomat;
+#pragma warning restore 0105
+
+namespace DiplomatFeatures.Raw;
+
+#nullable enable
+
+[StructLayout(LayoutKind.Sequential)]
+public partial struct PanicsFfiResultU32PanicError
+{
+    [StructLayout(LayoutKind.Explicit)]
+    private unsafe struct InnerUnion
+    {
+        [FieldOffset(0)]
+        internal uint ok;
+        [FieldOffset(0)]
+        internal PanicError err;
+    }
+
+    private InnerUnion _inner;
+
+    [MarshalAs(UnmanagedType.U1)]
+    public bool isOk;
+
+    public unsafe uint Ok
+    {
+        get
+        {
+            return _inner.ok;
+        }
+    }
+
+    public unsafe PanicError Err
+    {
+        get
+        {
+            return _inner.err;
+        }
+    }
+}
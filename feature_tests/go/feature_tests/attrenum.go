@@ -0,0 +1,15 @@
+// generated by diplomat-tool
+
+package feature_tests
+
+// #include "diplomat_go_runtime.h"
+// #include "AttrEnum.h"
+import "C"
+
+type AttrEnum int32
+
+const (
+	AttrEnumA AttrEnum = 0
+	AttrEnumB AttrEnum = 1
+	AttrEnumC AttrEnum = 2
+)
@@ -0,0 +1,80 @@
+// generated by diplomat-tool
+
+package feature_tests
+
+// #include "diplomat_go_runtime.h"
+// #include "AttrOpaque1.h"
+import "C"
+
+import (
+	"runtime"
+)
+
+type AttrOpaque1 struct {
+	handle *C.AttrOpaque1
+	owned  bool
+	// Objects this may borrow from, kept alive for as long as this is
+	edges []any
+}
+
+// newAttrOpaque1 wraps a handle, which is destroyed by Close or the garbage collector if it is owned
+func newAttrOpaque1(handle *C.AttrOpaque1, owned bool, edges []any) *AttrOpaque1 {
+	if handle == nil {
+		return nil
+	}
+	self := &AttrOpaque1{handle, owned, edges}
+	if owned {
+		runtime.SetFinalizer(self, (*AttrOpaque1).Close)
+	}
+	return self
+}
+
+// Close releases the Rust object if this owns it. It must not be used afterwards.
+func (self *AttrOpaque1) Close() {
+	if self.owned {
+		self.owned = false
+		runtime.SetFinalizer(self, nil)
+		C.namespace_AttrOpaque1_destroy(self.handle)
+	}
+}
+
+// toNative returns the handle of an object that may be nil
+func (self *AttrOpaque1) toNative() *C.AttrOpaque1 {
+	if self == nil {
+		return nil
+	}
+	return self.handle
+}
+
+func NewAttrOpaque1() *AttrOpaque1 {
+	result := C.namespace_AttrOpaque1_new()
+	return newAttrOpaque1(result, true, nil)
+}
+
+func (self *AttrOpaque1) Method() uint8 {
+	result := C.namespace_AttrOpaque1_method(self.handle)
+	runtime.KeepAlive(self)
+	return uint8(result)
+}
+
+func (self *AttrOpaque1) Abirenamed() uint8 {
+	result := C.renamed_on_abi_only(self.handle)
+	runtime.KeepAlive(self)
+	return uint8(result)
+}
+
+func (self *AttrOpaque1) MethodDisabledcpp() {
+	C.namespace_AttrOpaque1_method_disabledcpp(self.handle)
+	runtime.KeepAlive(self)
+}
+
+func (self *AttrOpaque1) UseUnnamespaced(un *Unnamespaced) {
+	C.namespace_AttrOpaque1_use_unnamespaced(self.handle, un.handle)
+	runtime.KeepAlive(self)
+	runtime.KeepAlive(un)
+}
+
+func (self *AttrOpaque1) UseNamespaced(n AttrEnum) {
+	C.namespace_AttrOpaque1_use_namespaced(self.handle, C.AttrEnum(n))
+	runtime.KeepAlive(self)
+}
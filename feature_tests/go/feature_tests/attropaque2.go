@@ -0,0 +1,47 @@
+// generated by diplomat-tool
+
+package feature_tests
+
+// #include "diplomat_go_runtime.h"
+// #include "AttrOpaque2.h"
+import "C"
+
+import (
+	"runtime"
+)
+
+type AttrOpaque2 struct {
+	handle *C.AttrOpaque2
+	owned  bool
+	// Objects this may borrow from, kept alive for as long as this is
+	edges []any
+}
+
+// newAttrOpaque2 wraps a handle, which is destroyed by Close or the garbage collector if it is owned
+func newAttrOpaque2(handle *C.AttrOpaque2, owned bool, edges []any) *AttrOpaque2 {
+	if handle == nil {
+		return nil
+	}
+	self := &AttrOpaque2{handle, owned, edges}
+	if owned {
+		runtime.SetFinalizer(self, (*AttrOpaque2).Close)
+	}
+	return self
+}
+
+// Close releases the Rust object if this owns it. It must not be used afterwards.
+func (self *AttrOpaque2) Close() {
+	if self.owned {
+		self.owned = false
+		runtime.SetFinalizer(self, nil)
+		C.namespace_AttrOpaque2_destroy(self.handle)
+	}
+}
+
+// toNative returns the handle of an object that may be nil
+func (self *AttrOpaque2) toNative() *C.AttrOpaque2 {
+	if self == nil {
+		return nil
+	}
+	return self.handle
+}
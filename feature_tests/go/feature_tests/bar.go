@@ -0,0 +1,53 @@
+// generated by diplomat-tool
+
+package feature_tests
+
+// #include "diplomat_go_runtime.h"
+// #include "Bar.h"
+import "C"
+
+import (
+	"runtime"
+)
+
+type Bar struct {
+	handle *C.Bar
+	owned  bool
+	// Objects this may borrow from, kept alive for as long as this is
+	edges []any
+}
+
+// newBar wraps a handle, which is destroyed by Close or the garbage collector if it is owned
+func newBar(handle *C.Bar, owned bool, edges []any) *Bar {
+	if handle == nil {
+		return nil
+	}
+	self := &Bar{handle, owned, edges}
+	if owned {
+		runtime.SetFinalizer(self, (*Bar).Close)
+	}
+	return self
+}
+
+// Close releases the Rust object if this owns it. It must not be used afterwards.
+func (self *Bar) Close() {
+	if self.owned {
+		self.owned = false
+		runtime.SetFinalizer(self, nil)
+		C.Bar_destroy(self.handle)
+	}
+}
+
+// toNative returns the handle of an object that may be nil
+func (self *Bar) toNative() *C.Bar {
+	if self == nil {
+		return nil
+	}
+	return self.handle
+}
+
+func (self *Bar) Foo() *Foo {
+	result := C.Bar_foo(self.handle)
+	runtime.KeepAlive(self)
+	return newFoo(result, false, []any{self})
+}
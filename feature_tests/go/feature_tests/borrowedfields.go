@@ -0,0 +1,29 @@
+// generated by diplomat-tool
+
+package feature_tests
+
+// #include "diplomat_go_runtime.h"
+// #include "BorrowedFields.h"
+import "C"
+
+type BorrowedFields struct {
+	A string
+	B string
+	C string
+}
+
+func newBorrowedFields(native C.BorrowedFields, edges []any) BorrowedFields {
+	return BorrowedFields{
+		A: diplomatReadStr16(native.a.data, native.a.len, false),
+		B: diplomatReadStr(native.b.data, native.b.len, false),
+		C: diplomatReadStr(native.c.data, native.c.len, false),
+	}
+}
+
+func (self BorrowedFields) toNative(keep *diplomatKeep) C.BorrowedFields {
+	var native C.BorrowedFields
+	native.a.data, native.a.len = diplomatStr16(self.A, keep)
+	native.b.data, native.b.len = diplomatStr(self.B, keep)
+	native.c.data, native.c.len = diplomatStr(self.C, keep)
+	return native
+}
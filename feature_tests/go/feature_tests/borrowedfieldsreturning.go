@@ -0,0 +1,23 @@
+// generated by diplomat-tool
+
+package feature_tests
+
+// #include "diplomat_go_runtime.h"
+// #include "BorrowedFieldsReturning.h"
+import "C"
+
+type BorrowedFieldsReturning struct {
+	Bytes string
+}
+
+func newBorrowedFieldsReturning(native C.BorrowedFieldsReturning, edges []any) BorrowedFieldsReturning {
+	return BorrowedFieldsReturning{
+		Bytes: diplomatReadStr(native.bytes.data, native.bytes.len, false),
+	}
+}
+
+func (self BorrowedFieldsReturning) toNative(keep *diplomatKeep) C.BorrowedFieldsReturning {
+	var native C.BorrowedFieldsReturning
+	native.bytes.data, native.bytes.len = diplomatStr(self.Bytes, keep)
+	return native
+}
@@ -0,0 +1,29 @@
+// generated by diplomat-tool
+
+package feature_tests
+
+// #include "diplomat_go_runtime.h"
+// #include "BorrowedFieldsWithBounds.h"
+import "C"
+
+type BorrowedFieldsWithBounds struct {
+	FieldA string
+	FieldB string
+	FieldC string
+}
+
+func newBorrowedFieldsWithBounds(native C.BorrowedFieldsWithBounds, edges []any) BorrowedFieldsWithBounds {
+	return BorrowedFieldsWithBounds{
+		FieldA: diplomatReadStr16(native.field_a.data, native.field_a.len, false),
+		FieldB: diplomatReadStr(native.field_b.data, native.field_b.len, false),
+		FieldC: diplomatReadStr(native.field_c.data, native.field_c.len, false),
+	}
+}
+
+func (self BorrowedFieldsWithBounds) toNative(keep *diplomatKeep) C.BorrowedFieldsWithBounds {
+	var native C.BorrowedFieldsWithBounds
+	native.field_a.data, native.field_a.len = diplomatStr16(self.FieldA, keep)
+	native.field_b.data, native.field_b.len = diplomatStr(self.FieldB, keep)
+	native.field_c.data, native.field_c.len = diplomatStr(self.FieldC, keep)
+	return native
+}
@@ -0,0 +1,59 @@
+// generated by diplomat-tool
+
+package feature_tests
+
+// #include "diplomat_go_runtime.h"
+// #include "Comparable.h"
+import "C"
+
+import (
+	"runtime"
+)
+
+type Comparable struct {
+	handle *C.Comparable
+	owned  bool
+	// Objects this may borrow from, kept alive for as long as this is
+	edges []any
+}
+
+// newComparable wraps a handle, which is destroyed by Close or the garbage collector if it is owned
+func newComparable(handle *C.Comparable, owned bool, edges []any) *Comparable {
+	if handle == nil {
+		return nil
+	}
+	self := &Comparable{handle, owned, edges}
+	if owned {
+		runtime.SetFinalizer(self, (*Comparable).Close)
+	}
+	return self
+}
+
+// Close releases the Rust object if this owns it. It must not be used afterwards.
+func (self *Comparable) Close() {
+	if self.owned {
+		self.owned = false
+		runtime.SetFinalizer(self, nil)
+		C.namespace_Comparable_destroy(self.handle)
+	}
+}
+
+// toNative returns the handle of an object that may be nil
+func (self *Comparable) toNative() *C.Comparable {
+	if self == nil {
+		return nil
+	}
+	return self.handle
+}
+
+func ComparableNew(int_ uint8) *Comparable {
+	result := C.namespace_Comparable_new(C.uint8_t(int_))
+	return newComparable(result, true, nil)
+}
+
+func (self *Comparable) Cmp(other *Comparable) int8 {
+	result := C.namespace_Comparable_cmp(self.handle, other.handle)
+	runtime.KeepAlive(self)
+	runtime.KeepAlive(other)
+	return int8(result)
+}
@@ -0,0 +1,57 @@
+// generated by diplomat-tool
+
+package feature_tests
+
+// #include "diplomat_go_runtime.h"
+// #include "ConstHolder.h"
+import "C"
+
+import (
+	"runtime"
+)
+
+type ConstHolder struct {
+	handle *C.ConstHolder
+	owned  bool
+	// Objects this may borrow from, kept alive for as long as this is
+	edges []any
+}
+
+// newConstHolder wraps a handle, which is destroyed by Close or the garbage collector if it is owned
+func newConstHolder(handle *C.ConstHolder, owned bool, edges []any) *ConstHolder {
+	if handle == nil {
+		return nil
+	}
+	self := &ConstHolder{handle, owned, edges}
+	if owned {
+		runtime.SetFinalizer(self, (*ConstHolder).Close)
+	}
+	return self
+}
+
+// Close releases the Rust object if this owns it. It must not be used afterwards.
+func (self *ConstHolder) Close() {
+	if self.owned {
+		self.owned = false
+		runtime.SetFinalizer(self, nil)
+		C.ConstHolder_destroy(self.handle)
+	}
+}
+
+// toNative returns the handle of an object that may be nil
+func (self *ConstHolder) toNative() *C.ConstHolder {
+	if self == nil {
+		return nil
+	}
+	return self.handle
+}
+
+const (
+	ConstHolderMinValue  int8 = -128
+	ConstHolderSeparator rune = 124
+)
+
+func ConstHolderMaxLen() uint32 {
+	result := C.ConstHolder_max_len()
+	return uint32(result)
+}
@@ -0,0 +1,11 @@
+// generated by diplomat-tool
+
+package feature_tests
+
+const (
+	Enabled  bool   = true
+	Greeting string = "hello"
+	// The length `ConstHolder::max_len` returns
+	MaxLen uint32  = 16
+	Ratio  float64 = 0.5
+)
@@ -0,0 +1,16 @@
+// generated by diplomat-tool
+
+package feature_tests
+
+// #include "diplomat_go_runtime.h"
+// #include "ContiguousEnum.h"
+import "C"
+
+type ContiguousEnum int32
+
+const (
+	ContiguousEnumC ContiguousEnum = 0
+	ContiguousEnumD ContiguousEnum = 1
+	ContiguousEnumE ContiguousEnum = 2
+	ContiguousEnumF ContiguousEnum = 3
+)
@@ -0,0 +1,14 @@
+// generated by diplomat-tool
+
+package feature_tests
+
+// #include "diplomat_go_runtime.h"
+// #include "ErrorEnum.h"
+import "C"
+
+type ErrorEnum int32
+
+const (
+	ErrorEnumFoo ErrorEnum = 0
+	ErrorEnumBar ErrorEnum = 1
+)
@@ -0,0 +1,26 @@
+// generated by diplomat-tool
+
+package feature_tests
+
+// #include "diplomat_go_runtime.h"
+// #include "ErrorStruct.h"
+import "C"
+
+type ErrorStruct struct {
+	I int32
+	J int32
+}
+
+func newErrorStruct(native C.ErrorStruct, edges []any) ErrorStruct {
+	return ErrorStruct{
+		I: int32(native.i),
+		J: int32(native.j),
+	}
+}
+
+func (self ErrorStruct) toNative(keep *diplomatKeep) C.ErrorStruct {
+	var native C.ErrorStruct
+	native.i = C.int32_t(self.I)
+	native.j = C.int32_t(self.J)
+	return native
+}
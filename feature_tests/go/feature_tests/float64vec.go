@@ -0,0 +1,137 @@
+// generated by diplomat-tool
+
+package feature_tests
+
+// #include "diplomat_go_runtime.h"
+// #include "Float64Vec.h"
+import "C"
+
+import (
+	"runtime"
+)
+
+type Float64Vec struct {
+	handle *C.Float64Vec
+	owned  bool
+	// Objects this may borrow from, kept alive for as long as this is
+	edges []any
+}
+
+// newFloat64Vec wraps a handle, which is destroyed by Close or the garbage collector if it is owned
+func newFloat64Vec(handle *C.Float64Vec, owned bool, edges []any) *Float64Vec {
+	if handle == nil {
+		return nil
+	}
+	self := &Float64Vec{handle, owned, edges}
+	if owned {
+		runtime.SetFinalizer(self, (*Float64Vec).Close)
+	}
+	return self
+}
+
+// Close releases the Rust object if this owns it. It must not be used afterwards.
+func (self *Float64Vec) Close() {
+	if self.owned {
+		self.owned = false
+		runtime.SetFinalizer(self, nil)
+		C.Float64Vec_destroy(self.handle)
+	}
+}
+
+// toNative returns the handle of an object that may be nil
+func (self *Float64Vec) toNative() *C.Float64Vec {
+	if self == nil {
+		return nil
+	}
+	return self.handle
+}
+
+func NewFloat64Vec(v []float64) *Float64Vec {
+	vData, vLen := diplomatSliceRef[C.double](v)
+	result := C.Float64Vec_new(vData, vLen)
+	return newFloat64Vec(result, true, nil)
+}
+
+func Float64VecBool(v []bool) *Float64Vec {
+	vData, vLen := diplomatSliceRef[C.bool](v)
+	result := C.Float64Vec_new_bool(vData, vLen)
+	return newFloat64Vec(result, true, nil)
+}
+
+func Float64VecI16(v []int16) *Float64Vec {
+	vData, vLen := diplomatSliceRef[C.int16_t](v)
+	result := C.Float64Vec_new_i16(vData, vLen)
+	return newFloat64Vec(result, true, nil)
+}
+
+func Float64VecU16(v []uint16) *Float64Vec {
+	vData, vLen := diplomatSliceRef[C.uint16_t](v)
+	result := C.Float64Vec_new_u16(vData, vLen)
+	return newFloat64Vec(result, true, nil)
+}
+
+func Float64VecIsize(v []int) *Float64Vec {
+	vData, vLen := diplomatSliceRef[C.intptr_t](v)
+	result := C.Float64Vec_new_isize(vData, vLen)
+	return newFloat64Vec(result, true, nil)
+}
+
+func Float64VecUsize(v []uint) *Float64Vec {
+	vData, vLen := diplomatSliceRef[C.size_t](v)
+	result := C.Float64Vec_new_usize(vData, vLen)
+	return newFloat64Vec(result, true, nil)
+}
+
+func Float64VecF64BeBytes(v []uint8) *Float64Vec {
+	vData, vLen := diplomatSliceRef[C.uint8_t](v)
+	result := C.Float64Vec_new_f64_be_bytes(vData, vLen)
+	return newFloat64Vec(result, true, nil)
+}
+
+func (self *Float64Vec) AsBoxedSlice() []float64 {
+	result := C.Float64Vec_as_boxed_slice(self.handle)
+	runtime.KeepAlive(self)
+	return diplomatRead[float64](result.data, result.len, true)
+}
+
+func (self *Float64Vec) AsSlice() []float64 {
+	result := C.Float64Vec_as_slice(self.handle)
+	runtime.KeepAlive(self)
+	return diplomatRead[float64](result.data, result.len, false)
+}
+
+func (self *Float64Vec) FillSlice(v []float64) {
+	vData, vLen := diplomatSliceRef[C.double](v)
+	C.Float64Vec_fill_slice(self.handle, vData, vLen)
+	runtime.KeepAlive(self)
+}
+
+func (self *Float64Vec) SetValue(newSlice []float64) {
+	newSliceData, newSliceLen := diplomatSliceRef[C.double](newSlice)
+	C.Float64Vec_set_value(self.handle, newSliceData, newSliceLen)
+	runtime.KeepAlive(self)
+}
+
+func (self *Float64Vec) String() string {
+	writeable := newDiplomatWriteable()
+	defer writeable.free()
+	C.Float64Vec_to_string(self.handle, writeable.handle)
+	runtime.KeepAlive(self)
+	return writeable.String()
+}
+
+func (self *Float64Vec) Borrow() []float64 {
+	result := C.Float64Vec_borrow(self.handle)
+	runtime.KeepAlive(self)
+	return diplomatRead[float64](result.data, result.len, false)
+}
+
+func (self *Float64Vec) Get(i uint) (float64, bool) {
+	result := C.Float64Vec_get(self.handle, C.size_t(i))
+	runtime.KeepAlive(self)
+	if !result.is_ok {
+		return 0, false
+	}
+	ok := diplomatResultValue[C.double](&result)
+	return float64(ok), true
+}
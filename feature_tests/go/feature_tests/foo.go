@@ -0,0 +1,86 @@
+// generated by diplomat-tool
+
+package feature_tests
+
+// #include "diplomat_go_runtime.h"
+// #include "Foo.h"
+import "C"
+
+import (
+	"runtime"
+)
+
+type Foo struct {
+	handle *C.Foo
+	owned  bool
+	// Objects this may borrow from, kept alive for as long as this is
+	edges []any
+}
+
+// newFoo wraps a handle, which is destroyed by Close or the garbage collector if it is owned
+func newFoo(handle *C.Foo, owned bool, edges []any) *Foo {
+	if handle == nil {
+		return nil
+	}
+	self := &Foo{handle, owned, edges}
+	if owned {
+		runtime.SetFinalizer(self, (*Foo).Close)
+	}
+	return self
+}
+
+// Close releases the Rust object if this owns it. It must not be used afterwards.
+func (self *Foo) Close() {
+	if self.owned {
+		self.owned = false
+		runtime.SetFinalizer(self, nil)
+		C.Foo_destroy(self.handle)
+	}
+}
+
+// toNative returns the handle of an object that may be nil
+func (self *Foo) toNative() *C.Foo {
+	if self == nil {
+		return nil
+	}
+	return self.handle
+}
+
+func NewFoo(x string) *Foo {
+	keep := newDiplomatKeep()
+	xData, xLen := diplomatStr(x, keep)
+	result := C.Foo_new(xData, xLen)
+	return newFoo(result, true, []any{keep})
+}
+
+func (self *Foo) Bar() *Bar {
+	result := C.Foo_get_bar(self.handle)
+	runtime.KeepAlive(self)
+	return newBar(result, true, []any{self})
+}
+
+func FooStatic(x string) *Foo {
+	xData, xLen := diplomatStr(x, nil)
+	result := C.Foo_new_static(xData, xLen)
+	return newFoo(result, true, nil)
+}
+
+func (self *Foo) AsReturning() BorrowedFieldsReturning {
+	result := C.Foo_as_returning(self.handle)
+	runtime.KeepAlive(self)
+	return newBorrowedFieldsReturning(result, []any{self})
+}
+
+func FooExtractFromFields(fields BorrowedFields) *Foo {
+	keep := newDiplomatKeep()
+	result := C.Foo_extract_from_fields(fields.toNative(keep))
+	return newFoo(result, true, []any{fields, keep})
+}
+
+// Test that the extraction logic correctly pins the right fields
+func FooExtractFromBounds(bounds BorrowedFieldsWithBounds, anotherString string) *Foo {
+	keep := newDiplomatKeep()
+	anotherStringData, anotherStringLen := diplomatStr(anotherString, keep)
+	result := C.Foo_extract_from_bounds(bounds.toNative(keep), anotherStringData, anotherStringLen)
+	return newFoo(result, true, []any{bounds, keep})
+}
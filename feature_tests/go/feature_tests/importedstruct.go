@@ -0,0 +1,26 @@
+// generated by diplomat-tool
+
+package feature_tests
+
+// #include "diplomat_go_runtime.h"
+// #include "ImportedStruct.h"
+import "C"
+
+type ImportedStruct struct {
+	Foo   UnimportedEnum
+	Count uint8
+}
+
+func newImportedStruct(native C.ImportedStruct, edges []any) ImportedStruct {
+	return ImportedStruct{
+		Foo:   UnimportedEnum(native.foo),
+		Count: uint8(native.count),
+	}
+}
+
+func (self ImportedStruct) toNative(keep *diplomatKeep) C.ImportedStruct {
+	var native C.ImportedStruct
+	native.foo = C.UnimportedEnum(self.Foo)
+	native.count = C.uint8_t(self.Count)
+	return native
+}
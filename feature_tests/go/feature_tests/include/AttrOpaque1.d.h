@@ -0,0 +1,24 @@
+#ifndef AttrOpaque1_D_H
+#define AttrOpaque1_D_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+#ifdef __cplusplus
+namespace capi {
+extern "C" {
+#endif // __cplusplus
+
+
+typedef struct AttrOpaque1 AttrOpaque1;
+
+
+#ifdef __cplusplus
+} // extern "C"
+} // namespace capi
+#endif // __cplusplus
+
+#endif // AttrOpaque1_D_H
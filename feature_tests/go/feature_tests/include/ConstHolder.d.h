@@ -0,0 +1,27 @@
+#ifndef ConstHolder_D_H
+#define ConstHolder_D_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+#ifdef __cplusplus
+namespace capi {
+extern "C" {
+#endif // __cplusplus
+
+
+typedef struct ConstHolder ConstHolder;
+
+#define ConstHolder_MIN_VALUE ((int8_t)-128)
+#define ConstHolder_SEPARATOR ((char32_t)124)
+
+
+#ifdef __cplusplus
+} // extern "C"
+} // namespace capi
+#endif // __cplusplus
+
+#endif // ConstHolder_D_H
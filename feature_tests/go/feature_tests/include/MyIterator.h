@@ -0,0 +1,29 @@
+#ifndef MyIterator_H
+#define MyIterator_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+#include "diplomat_result_uint8_t_void.d.h"
+
+#include "MyIterator.d.h"
+
+#ifdef __cplusplus
+namespace capi {
+extern "C" {
+#endif // __cplusplus
+
+
+diplomat_result_uint8_t_void namespace_MyIterator_next(MyIterator* self);
+
+void namespace_MyIterator_destroy(MyIterator* self);
+
+
+#ifdef __cplusplus
+} // extern "C"
+} // namespace capi
+#endif // __cplusplus
+
+#endif // MyIterator_H
@@ -0,0 +1,27 @@
+#ifndef PanicError_D_H
+#define PanicError_D_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+#ifdef __cplusplus
+namespace capi {
+extern "C" {
+#endif // __cplusplus
+
+
+typedef enum PanicError {
+  PanicError_Panicked = 0,
+  PanicError_TooLarge = 1,
+} PanicError;
+
+
+#ifdef __cplusplus
+} // extern "C"
+} // namespace capi
+#endif // __cplusplus
+
+#endif // PanicError_D_H
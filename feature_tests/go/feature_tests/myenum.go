@@ -0,0 +1,23 @@
+// generated by diplomat-tool
+
+package feature_tests
+
+// #include "diplomat_go_runtime.h"
+// #include "MyEnum.h"
+import "C"
+
+type MyEnum int32
+
+const (
+	MyEnumA MyEnum = -2
+	MyEnumB MyEnum = -1
+	MyEnumC MyEnum = 0
+	MyEnumD MyEnum = 1
+	MyEnumE MyEnum = 2
+	MyEnumF MyEnum = 3
+)
+
+func (self MyEnum) IntoValue() int8 {
+	result := C.MyEnum_into_value(C.MyEnum(self))
+	return int8(result)
+}
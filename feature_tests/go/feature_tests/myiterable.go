@@ -0,0 +1,59 @@
+// generated by diplomat-tool
+
+package feature_tests
+
+// #include "diplomat_go_runtime.h"
+// #include "MyIterable.h"
+import "C"
+
+import (
+	"runtime"
+)
+
+type MyIterable struct {
+	handle *C.MyIterable
+	owned  bool
+	// Objects this may borrow from, kept alive for as long as this is
+	edges []any
+}
+
+// newMyIterable wraps a handle, which is destroyed by Close or the garbage collector if it is owned
+func newMyIterable(handle *C.MyIterable, owned bool, edges []any) *MyIterable {
+	if handle == nil {
+		return nil
+	}
+	self := &MyIterable{handle, owned, edges}
+	if owned {
+		runtime.SetFinalizer(self, (*MyIterable).Close)
+	}
+	return self
+}
+
+// Close releases the Rust object if this owns it. It must not be used afterwards.
+func (self *MyIterable) Close() {
+	if self.owned {
+		self.owned = false
+		runtime.SetFinalizer(self, nil)
+		C.namespace_MyIterable_destroy(self.handle)
+	}
+}
+
+// toNative returns the handle of an object that may be nil
+func (self *MyIterable) toNative() *C.MyIterable {
+	if self == nil {
+		return nil
+	}
+	return self.handle
+}
+
+func NewMyIterable(x []uint8) *MyIterable {
+	xData, xLen := diplomatSliceRef[C.uint8_t](x)
+	result := C.namespace_MyIterable_new(xData, xLen)
+	return newMyIterable(result, true, nil)
+}
+
+func (self *MyIterable) Iter() *MyIterator {
+	result := C.namespace_MyIterable_iter(self.handle)
+	runtime.KeepAlive(self)
+	return newMyIterator(result, true, []any{self})
+}
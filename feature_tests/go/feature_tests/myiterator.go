@@ -0,0 +1,57 @@
+// generated by diplomat-tool
+
+package feature_tests
+
+// #include "diplomat_go_runtime.h"
+// #include "MyIterator.h"
+import "C"
+
+import (
+	"runtime"
+)
+
+type MyIterator struct {
+	handle *C.MyIterator
+	owned  bool
+	// Objects this may borrow from, kept alive for as long as this is
+	edges []any
+}
+
+// newMyIterator wraps a handle, which is destroyed by Close or the garbage collector if it is owned
+func newMyIterator(handle *C.MyIterator, owned bool, edges []any) *MyIterator {
+	if handle == nil {
+		return nil
+	}
+	self := &MyIterator{handle, owned, edges}
+	if owned {
+		runtime.SetFinalizer(self, (*MyIterator).Close)
+	}
+	return self
+}
+
+// Close releases the Rust object if this owns it. It must not be used afterwards.
+func (self *MyIterator) Close() {
+	if self.owned {
+		self.owned = false
+		runtime.SetFinalizer(self, nil)
+		C.namespace_MyIterator_destroy(self.handle)
+	}
+}
+
+// toNative returns the handle of an object that may be nil
+func (self *MyIterator) toNative() *C.MyIterator {
+	if self == nil {
+		return nil
+	}
+	return self.handle
+}
+
+func (self *MyIterator) Next() (uint8, bool) {
+	result := C.namespace_MyIterator_next(self.handle)
+	runtime.KeepAlive(self)
+	if !result.is_ok {
+		return 0, false
+	}
+	ok := diplomatResultValue[C.uint8_t](&result)
+	return uint8(ok), true
+}
@@ -0,0 +1,87 @@
+// generated by diplomat-tool
+
+package feature_tests
+
+// #include "diplomat_go_runtime.h"
+// #include "MyString.h"
+import "C"
+
+import (
+	"runtime"
+)
+
+type MyString struct {
+	handle *C.MyString
+	owned  bool
+	// Objects this may borrow from, kept alive for as long as this is
+	edges []any
+}
+
+// newMyString wraps a handle, which is destroyed by Close or the garbage collector if it is owned
+func newMyString(handle *C.MyString, owned bool, edges []any) *MyString {
+	if handle == nil {
+		return nil
+	}
+	self := &MyString{handle, owned, edges}
+	if owned {
+		runtime.SetFinalizer(self, (*MyString).Close)
+	}
+	return self
+}
+
+// Close releases the Rust object if this owns it. It must not be used afterwards.
+func (self *MyString) Close() {
+	if self.owned {
+		self.owned = false
+		runtime.SetFinalizer(self, nil)
+		C.MyString_destroy(self.handle)
+	}
+}
+
+// toNative returns the handle of an object that may be nil
+func (self *MyString) toNative() *C.MyString {
+	if self == nil {
+		return nil
+	}
+	return self.handle
+}
+
+func NewMyString(v string) *MyString {
+	vData, vLen := diplomatStrRef(v)
+	result := C.MyString_new(vData, vLen)
+	return newMyString(result, true, nil)
+}
+
+func MyStringUnsafe(v string) *MyString {
+	vData, vLen := diplomatStrRef(v)
+	result := C.MyString_new_unsafe(vData, vLen)
+	return newMyString(result, true, nil)
+}
+
+func MyStringNewOwned(v string) *MyString {
+	vData, vLen := diplomatStr(v, nil)
+	result := C.MyString_new_owned(vData, vLen)
+	return newMyString(result, true, nil)
+}
+
+func MyStringNewFromFirst(v []string) *MyString {
+	keep := &diplomatKeep{}
+	defer keep.free()
+	vData, vLen := diplomatStrs(v, keep)
+	result := C.MyString_new_from_first(vData, vLen)
+	return newMyString(result, true, nil)
+}
+
+func (self *MyString) SetStr(newStr string) {
+	newStrData, newStrLen := diplomatStrRef(newStr)
+	C.MyString_set_str(self.handle, newStrData, newStrLen)
+	runtime.KeepAlive(self)
+}
+
+func (self *MyString) Str() string {
+	writeable := newDiplomatWriteable()
+	defer writeable.free()
+	C.MyString_get_str(self.handle, writeable.handle)
+	runtime.KeepAlive(self)
+	return writeable.String()
+}
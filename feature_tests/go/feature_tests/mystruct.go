@@ -0,0 +1,53 @@
+// generated by diplomat-tool
+
+package feature_tests
+
+// #include "diplomat_go_runtime.h"
+// #include "MyStruct.h"
+import "C"
+
+type MyStruct struct {
+	A uint8
+	B bool
+	C uint8
+	D uint64
+	E int32
+	F rune
+	G MyEnum
+}
+
+func newMyStruct(native C.MyStruct, edges []any) MyStruct {
+	return MyStruct{
+		A: uint8(native.a),
+		B: bool(native.b),
+		C: uint8(native.c),
+		D: uint64(native.d),
+		E: int32(native.e),
+		F: rune(native.f),
+		G: MyEnum(native.g),
+	}
+}
+
+func (self MyStruct) toNative(keep *diplomatKeep) C.MyStruct {
+	var native C.MyStruct
+	native.a = C.uint8_t(self.A)
+	native.b = C.bool(self.B)
+	native.c = C.uint8_t(self.C)
+	native.d = C.uint64_t(self.D)
+	native.e = C.int32_t(self.E)
+	native.f = C.char32_t(self.F)
+	native.g = C.MyEnum(self.G)
+	return native
+}
+
+func NewMyStruct() MyStruct {
+	result := C.MyStruct_new()
+	return newMyStruct(result, nil)
+}
+
+func (self MyStruct) IntoA() uint8 {
+	keep := &diplomatKeep{}
+	defer keep.free()
+	result := C.MyStruct_into_a(self.toNative(keep))
+	return uint8(result)
+}
@@ -0,0 +1,29 @@
+// generated by diplomat-tool
+
+package feature_tests
+
+// #include "diplomat_go_runtime.h"
+// #include "NestedBorrowedFields.h"
+import "C"
+
+type NestedBorrowedFields struct {
+	Fields  BorrowedFields
+	Bounds  BorrowedFieldsWithBounds
+	Bounds2 BorrowedFieldsWithBounds
+}
+
+func newNestedBorrowedFields(native C.NestedBorrowedFields, edges []any) NestedBorrowedFields {
+	return NestedBorrowedFields{
+		Fields:  newBorrowedFields(native.fields, edges),
+		Bounds:  newBorrowedFieldsWithBounds(native.bounds, edges),
+		Bounds2: newBorrowedFieldsWithBounds(native.bounds2, edges),
+	}
+}
+
+func (self NestedBorrowedFields) toNative(keep *diplomatKeep) C.NestedBorrowedFields {
+	var native C.NestedBorrowedFields
+	native.fields = self.Fields.toNative(keep)
+	native.bounds = self.Bounds.toNative(keep)
+	native.bounds2 = self.Bounds2.toNative(keep)
+	return native
+}
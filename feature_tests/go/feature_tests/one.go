@@ -0,0 +1,141 @@
+// generated by diplomat-tool
+
+package feature_tests
+
+// #include "diplomat_go_runtime.h"
+// #include "One.h"
+import "C"
+
+import (
+	"runtime"
+)
+
+type One struct {
+	handle *C.One
+	owned  bool
+	// Objects this may borrow from, kept alive for as long as this is
+	edges []any
+}
+
+// newOne wraps a handle, which is destroyed by Close or the garbage collector if it is owned
+func newOne(handle *C.One, owned bool, edges []any) *One {
+	if handle == nil {
+		return nil
+	}
+	self := &One{handle, owned, edges}
+	if owned {
+		runtime.SetFinalizer(self, (*One).Close)
+	}
+	return self
+}
+
+// Close releases the Rust object if this owns it. It must not be used afterwards.
+func (self *One) Close() {
+	if self.owned {
+		self.owned = false
+		runtime.SetFinalizer(self, nil)
+		C.One_destroy(self.handle)
+	}
+}
+
+// toNative returns the handle of an object that may be nil
+func (self *One) toNative() *C.One {
+	if self == nil {
+		return nil
+	}
+	return self.handle
+}
+
+func OneTransitivity(hold *One, nohold *One) *One {
+	result := C.One_transitivity(hold.handle, nohold.handle)
+	runtime.KeepAlive(hold)
+	runtime.KeepAlive(nohold)
+	return newOne(result, true, []any{hold})
+}
+
+func OneCycle(hold *Two, nohold *One) *One {
+	result := C.One_cycle(hold.handle, nohold.handle)
+	runtime.KeepAlive(hold)
+	runtime.KeepAlive(nohold)
+	return newOne(result, true, []any{hold})
+}
+
+func OneManyDependents(a *One, b *One, c *Two, d *Two, nohold *Two) *One {
+	result := C.One_many_dependents(a.handle, b.handle, c.handle, d.handle, nohold.handle)
+	runtime.KeepAlive(a)
+	runtime.KeepAlive(b)
+	runtime.KeepAlive(c)
+	runtime.KeepAlive(d)
+	runtime.KeepAlive(nohold)
+	return newOne(result, true, []any{a, b, c, d})
+}
+
+func OneReturnOutlivesParam(hold *Two, nohold *One) *One {
+	result := C.One_return_outlives_param(hold.handle, nohold.handle)
+	runtime.KeepAlive(hold)
+	runtime.KeepAlive(nohold)
+	return newOne(result, true, []any{hold})
+}
+
+func OneDiamondTop(top *One, left *One, right *One, bottom *One) *One {
+	result := C.One_diamond_top(top.handle, left.handle, right.handle, bottom.handle)
+	runtime.KeepAlive(top)
+	runtime.KeepAlive(left)
+	runtime.KeepAlive(right)
+	runtime.KeepAlive(bottom)
+	return newOne(result, true, []any{top, left, right, bottom})
+}
+
+func OneDiamondLeft(top *One, left *One, right *One, bottom *One) *One {
+	result := C.One_diamond_left(top.handle, left.handle, right.handle, bottom.handle)
+	runtime.KeepAlive(top)
+	runtime.KeepAlive(left)
+	runtime.KeepAlive(right)
+	runtime.KeepAlive(bottom)
+	return newOne(result, true, []any{left, bottom})
+}
+
+func OneDiamondRight(top *One, left *One, right *One, bottom *One) *One {
+	result := C.One_diamond_right(top.handle, left.handle, right.handle, bottom.handle)
+	runtime.KeepAlive(top)
+	runtime.KeepAlive(left)
+	runtime.KeepAlive(right)
+	runtime.KeepAlive(bottom)
+	return newOne(result, true, []any{right, bottom})
+}
+
+func OneDiamondBottom(top *One, left *One, right *One, bottom *One) *One {
+	result := C.One_diamond_bottom(top.handle, left.handle, right.handle, bottom.handle)
+	runtime.KeepAlive(top)
+	runtime.KeepAlive(left)
+	runtime.KeepAlive(right)
+	runtime.KeepAlive(bottom)
+	return newOne(result, true, []any{bottom})
+}
+
+func OneDiamondAndNestedTypes(a *One, b *One, c *One, d *One, nohold *One) *One {
+	result := C.One_diamond_and_nested_types(a.handle, b.handle, c.handle, d.handle, nohold.handle)
+	runtime.KeepAlive(a)
+	runtime.KeepAlive(b)
+	runtime.KeepAlive(c)
+	runtime.KeepAlive(d)
+	runtime.KeepAlive(nohold)
+	return newOne(result, true, []any{a, b, c, d})
+}
+
+func OneImplicitBounds(explicitHold *One, implicitHold *One, nohold *One) *One {
+	result := C.One_implicit_bounds(explicitHold.handle, implicitHold.handle, nohold.handle)
+	runtime.KeepAlive(explicitHold)
+	runtime.KeepAlive(implicitHold)
+	runtime.KeepAlive(nohold)
+	return newOne(result, true, []any{explicitHold, implicitHold})
+}
+
+func OneImplicitBoundsDeep(explicit *One, implicit1 *One, implicit2 *One, nohold *One) *One {
+	result := C.One_implicit_bounds_deep(explicit.handle, implicit1.handle, implicit2.handle, nohold.handle)
+	runtime.KeepAlive(explicit)
+	runtime.KeepAlive(implicit1)
+	runtime.KeepAlive(implicit2)
+	runtime.KeepAlive(nohold)
+	return newOne(result, true, []any{explicit, implicit1, implicit2})
+}
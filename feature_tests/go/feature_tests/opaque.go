@@ -0,0 +1,74 @@
+// generated by diplomat-tool
+
+package feature_tests
+
+// #include "diplomat_go_runtime.h"
+// #include "Opaque.h"
+import "C"
+
+import (
+	"runtime"
+)
+
+type Opaque struct {
+	handle *C.Opaque
+	owned  bool
+	// Objects this may borrow from, kept alive for as long as this is
+	edges []any
+}
+
+// newOpaque wraps a handle, which is destroyed by Close or the garbage collector if it is owned
+func newOpaque(handle *C.Opaque, owned bool, edges []any) *Opaque {
+	if handle == nil {
+		return nil
+	}
+	self := &Opaque{handle, owned, edges}
+	if owned {
+		runtime.SetFinalizer(self, (*Opaque).Close)
+	}
+	return self
+}
+
+// Close releases the Rust object if this owns it. It must not be used afterwards.
+func (self *Opaque) Close() {
+	if self.owned {
+		self.owned = false
+		runtime.SetFinalizer(self, nil)
+		C.Opaque_destroy(self.handle)
+	}
+}
+
+// toNative returns the handle of an object that may be nil
+func (self *Opaque) toNative() *C.Opaque {
+	if self == nil {
+		return nil
+	}
+	return self.handle
+}
+
+func NewOpaque() *Opaque {
+	result := C.Opaque_new()
+	return newOpaque(result, true, nil)
+}
+
+// See the [Rust documentation for `something`](https://docs.rs/Something/latest/struct.Something.html#method.something) for more information.
+//
+// See the [Rust documentation for `something_else`](https://docs.rs/Something/latest/struct.Something.html#method.something_else) for more information.
+//
+// Additional information: [1](https://docs.rs/Something/latest/struct.Something.html#method.something_small), [2](https://docs.rs/SomethingElse/latest/struct.SomethingElse.html#method.something)
+func (self *Opaque) AssertStruct(s MyStruct) {
+	keep := &diplomatKeep{}
+	defer keep.free()
+	C.Opaque_assert_struct(self.handle, s.toNative(keep))
+	runtime.KeepAlive(self)
+}
+
+func OpaqueReturnsUsize() uint {
+	result := C.Opaque_returns_usize()
+	return uint(result)
+}
+
+func OpaqueReturnsImported() ImportedStruct {
+	result := C.Opaque_returns_imported()
+	return newImportedStruct(result, nil)
+}
@@ -0,0 +1,87 @@
+// generated by diplomat-tool
+
+package feature_tests
+
+// #include "diplomat_go_runtime.h"
+// #include "OptionOpaque.h"
+import "C"
+
+import (
+	"runtime"
+)
+
+type OptionOpaque struct {
+	handle *C.OptionOpaque
+	owned  bool
+	// Objects this may borrow from, kept alive for as long as this is
+	edges []any
+}
+
+// newOptionOpaque wraps a handle, which is destroyed by Close or the garbage collector if it is owned
+func newOptionOpaque(handle *C.OptionOpaque, owned bool, edges []any) *OptionOpaque {
+	if handle == nil {
+		return nil
+	}
+	self := &OptionOpaque{handle, owned, edges}
+	if owned {
+		runtime.SetFinalizer(self, (*OptionOpaque).Close)
+	}
+	return self
+}
+
+// Close releases the Rust object if this owns it. It must not be used afterwards.
+func (self *OptionOpaque) Close() {
+	if self.owned {
+		self.owned = false
+		runtime.SetFinalizer(self, nil)
+		C.OptionOpaque_destroy(self.handle)
+	}
+}
+
+// toNative returns the handle of an object that may be nil
+func (self *OptionOpaque) toNative() *C.OptionOpaque {
+	if self == nil {
+		return nil
+	}
+	return self.handle
+}
+
+func OptionOpaqueNew(i int32) *OptionOpaque {
+	result := C.OptionOpaque_new(C.int32_t(i))
+	return newOptionOpaque(result, true, nil)
+}
+
+func OptionOpaqueNewNone() *OptionOpaque {
+	result := C.OptionOpaque_new_none()
+	return newOptionOpaque(result, true, nil)
+}
+
+func OptionOpaqueReturns() (OptionStruct, bool) {
+	result := C.OptionOpaque_returns()
+	if !result.is_ok {
+		return OptionStruct{}, false
+	}
+	ok := diplomatResultValue[C.OptionStruct](&result)
+	return newOptionStruct(ok, nil), true
+}
+
+func OptionOpaqueNewStruct() OptionStruct {
+	result := C.OptionOpaque_new_struct()
+	return newOptionStruct(result, nil)
+}
+
+func OptionOpaqueNewStructNones() OptionStruct {
+	result := C.OptionOpaque_new_struct_nones()
+	return newOptionStruct(result, nil)
+}
+
+func (self *OptionOpaque) AssertInteger(i int32) {
+	C.OptionOpaque_assert_integer(self.handle, C.int32_t(i))
+	runtime.KeepAlive(self)
+}
+
+func OptionOpaqueOptionOpaqueArgument(arg *OptionOpaque) bool {
+	result := C.OptionOpaque_option_opaque_argument(arg.toNative())
+	runtime.KeepAlive(arg)
+	return bool(result)
+}
@@ -0,0 +1,52 @@
+// generated by diplomat-tool
+
+package feature_tests
+
+// #include "diplomat_go_runtime.h"
+// #include "OptionOpaqueChar.h"
+import "C"
+
+import (
+	"runtime"
+)
+
+type OptionOpaqueChar struct {
+	handle *C.OptionOpaqueChar
+	owned  bool
+	// Objects this may borrow from, kept alive for as long as this is
+	edges []any
+}
+
+// newOptionOpaqueChar wraps a handle, which is destroyed by Close or the garbage collector if it is owned
+func newOptionOpaqueChar(handle *C.OptionOpaqueChar, owned bool, edges []any) *OptionOpaqueChar {
+	if handle == nil {
+		return nil
+	}
+	self := &OptionOpaqueChar{handle, owned, edges}
+	if owned {
+		runtime.SetFinalizer(self, (*OptionOpaqueChar).Close)
+	}
+	return self
+}
+
+// Close releases the Rust object if this owns it. It must not be used afterwards.
+func (self *OptionOpaqueChar) Close() {
+	if self.owned {
+		self.owned = false
+		runtime.SetFinalizer(self, nil)
+		C.OptionOpaqueChar_destroy(self.handle)
+	}
+}
+
+// toNative returns the handle of an object that may be nil
+func (self *OptionOpaqueChar) toNative() *C.OptionOpaqueChar {
+	if self == nil {
+		return nil
+	}
+	return self.handle
+}
+
+func (self *OptionOpaqueChar) AssertChar(ch rune) {
+	C.OptionOpaqueChar_assert_char(self.handle, C.char32_t(ch))
+	runtime.KeepAlive(self)
+}
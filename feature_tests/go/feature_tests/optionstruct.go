@@ -0,0 +1,32 @@
+// generated by diplomat-tool
+
+package feature_tests
+
+// #include "diplomat_go_runtime.h"
+// #include "OptionStruct.h"
+import "C"
+
+type OptionStruct struct {
+	A *OptionOpaque
+	B *OptionOpaqueChar
+	C uint32
+	D *OptionOpaque
+}
+
+func newOptionStruct(native C.OptionStruct, edges []any) OptionStruct {
+	return OptionStruct{
+		A: newOptionOpaque(native.a, true, nil),
+		B: newOptionOpaqueChar(native.b, true, nil),
+		C: uint32(native.c),
+		D: newOptionOpaque(native.d, true, nil),
+	}
+}
+
+func (self OptionStruct) toNative(keep *diplomatKeep) C.OptionStruct {
+	var native C.OptionStruct
+	native.a = self.A.toNative()
+	native.b = self.B.toNative()
+	native.c = C.uint32_t(self.C)
+	native.d = self.D.toNative()
+	return native
+}
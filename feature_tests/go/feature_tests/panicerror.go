@@ -0,0 +1,14 @@
+// generated by diplomat-tool
+
+package feature_tests
+
+// #include "diplomat_go_runtime.h"
+// #include "PanicError.h"
+import "C"
+
+type PanicError int32
+
+const (
+	PanicErrorPanicked PanicError = 0
+	PanicErrorTooLarge PanicError = 1
+)
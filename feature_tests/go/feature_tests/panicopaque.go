@@ -0,0 +1,80 @@
+// generated by diplomat-tool
+
+package feature_tests
+
+// #include "diplomat_go_runtime.h"
+// #include "PanicOpaque.h"
+import "C"
+
+import (
+	"runtime"
+)
+
+type PanicOpaque struct {
+	handle *C.PanicOpaque
+	owned  bool
+	// Objects this may borrow from, kept alive for as long as this is
+	edges []any
+}
+
+// newPanicOpaque wraps a handle, which is destroyed by Close or the garbage collector if it is owned
+func newPanicOpaque(handle *C.PanicOpaque, owned bool, edges []any) *PanicOpaque {
+	if handle == nil {
+		return nil
+	}
+	self := &PanicOpaque{handle, owned, edges}
+	if owned {
+		runtime.SetFinalizer(self, (*PanicOpaque).Close)
+	}
+	return self
+}
+
+// Close releases the Rust object if this owns it. It must not be used afterwards.
+func (self *PanicOpaque) Close() {
+	if self.owned {
+		self.owned = false
+		runtime.SetFinalizer(self, nil)
+		C.PanicOpaque_destroy(self.handle)
+	}
+}
+
+// toNative returns the handle of an object that may be nil
+func (self *PanicOpaque) toNative() *C.PanicOpaque {
+	if self == nil {
+		return nil
+	}
+	return self.handle
+}
+
+// Panics, which foreign code is told about after the call
+//
+// Panics with a `*DiplomatPanic` if the Rust method panics.
+func PanicOpaquePanics(message string) {
+	messageData, messageLen := diplomatStrRef(message)
+	runtime.LockOSThread()
+	defer runtime.UnlockOSThread()
+	C.PanicOpaque_panics(messageData, messageLen)
+	diplomatCheckPanic()
+}
+
+// Panics when `x` is zero, which is reported as `PanicError::Panicked`
+//
+// Failures are returned as a `*DiplomatError` holding a `PanicError`.
+func PanicOpaqueCheckedDivide(x uint32) (uint32, error) {
+	result := C.PanicOpaque_checked_divide(C.uint32_t(x))
+	if !result.is_ok {
+		err := diplomatResultValue[C.PanicError](&result)
+		return 0, &DiplomatError{Value: PanicError(err)}
+	}
+	ok := diplomatResultValue[C.uint32_t](&result)
+	return uint32(ok), nil
+}
+
+// Panics with a `*DiplomatPanic` if the Rust method panics.
+func PanicOpaqueNotPanicking(x uint32) uint32 {
+	runtime.LockOSThread()
+	defer runtime.UnlockOSThread()
+	result := C.PanicOpaque_not_panicking(C.uint32_t(x))
+	diplomatCheckPanic()
+	return uint32(result)
+}
@@ -0,0 +1,37 @@
+package feature_tests
+
+import (
+	"errors"
+	"testing"
+)
+
+// recoverPanic calls f, returning what it panicked with
+func recoverPanic(f func()) (recovered any) {
+	defer func() { recovered = recover() }()
+	f()
+	return nil
+}
+
+func TestPanicIsRaised(t *testing.T) {
+	recovered := recoverPanic(func() { PanicOpaquePanics("oops") })
+	if p, ok := recovered.(*DiplomatPanic); !ok || p.Message != "oops" {
+		t.Fatalf("expected a *DiplomatPanic with message oops, got %v", recovered)
+	}
+	// The panic was taken, so the next call doesn't raise it again
+	if got := PanicOpaqueNotPanicking(1); got != 2 {
+		t.Fatalf("expected 2, got %v", got)
+	}
+}
+
+func TestPanicInResultIsItsError(t *testing.T) {
+	var err *DiplomatError
+	if _, e := PanicOpaqueCheckedDivide(0); !errors.As(e, &err) || err.Value != PanicErrorPanicked {
+		t.Fatalf("expected PanicErrorPanicked, got %v", e)
+	}
+	if _, e := PanicOpaqueCheckedDivide(200); !errors.As(e, &err) || err.Value != PanicErrorTooLarge {
+		t.Fatalf("expected PanicErrorTooLarge, got %v", e)
+	}
+	if got, e := PanicOpaqueCheckedDivide(5); e != nil || got != 20 {
+		t.Fatalf("expected 20, got %v, %v", got, e)
+	}
+}
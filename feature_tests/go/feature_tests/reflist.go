@@ -0,0 +1,53 @@
+// generated by diplomat-tool
+
+package feature_tests
+
+// #include "diplomat_go_runtime.h"
+// #include "RefList.h"
+import "C"
+
+import (
+	"runtime"
+)
+
+type RefList struct {
+	handle *C.RefList
+	owned  bool
+	// Objects this may borrow from, kept alive for as long as this is
+	edges []any
+}
+
+// newRefList wraps a handle, which is destroyed by Close or the garbage collector if it is owned
+func newRefList(handle *C.RefList, owned bool, edges []any) *RefList {
+	if handle == nil {
+		return nil
+	}
+	self := &RefList{handle, owned, edges}
+	if owned {
+		runtime.SetFinalizer(self, (*RefList).Close)
+	}
+	return self
+}
+
+// Close releases the Rust object if this owns it. It must not be used afterwards.
+func (self *RefList) Close() {
+	if self.owned {
+		self.owned = false
+		runtime.SetFinalizer(self, nil)
+		C.RefList_destroy(self.handle)
+	}
+}
+
+// toNative returns the handle of an object that may be nil
+func (self *RefList) toNative() *C.RefList {
+	if self == nil {
+		return nil
+	}
+	return self.handle
+}
+
+func RefListNode(data *RefListParameter) *RefList {
+	result := C.RefList_node(data.handle)
+	runtime.KeepAlive(data)
+	return newRefList(result, true, []any{data})
+}
@@ -0,0 +1,47 @@
+// generated by diplomat-tool
+
+package feature_tests
+
+// #include "diplomat_go_runtime.h"
+// #include "RefListParameter.h"
+import "C"
+
+import (
+	"runtime"
+)
+
+type RefListParameter struct {
+	handle *C.RefListParameter
+	owned  bool
+	// Objects this may borrow from, kept alive for as long as this is
+	edges []any
+}
+
+// newRefListParameter wraps a handle, which is destroyed by Close or the garbage collector if it is owned
+func newRefListParameter(handle *C.RefListParameter, owned bool, edges []any) *RefListParameter {
+	if handle == nil {
+		return nil
+	}
+	self := &RefListParameter{handle, owned, edges}
+	if owned {
+		runtime.SetFinalizer(self, (*RefListParameter).Close)
+	}
+	return self
+}
+
+// Close releases the Rust object if this owns it. It must not be used afterwards.
+func (self *RefListParameter) Close() {
+	if self.owned {
+		self.owned = false
+		runtime.SetFinalizer(self, nil)
+		C.RefListParameter_destroy(self.handle)
+	}
+}
+
+// toNative returns the handle of an object that may be nil
+func (self *RefListParameter) toNative() *C.RefListParameter {
+	if self == nil {
+		return nil
+	}
+	return self.handle
+}
@@ -0,0 +1,135 @@
+// generated by diplomat-tool
+
+package feature_tests
+
+// #include "diplomat_go_runtime.h"
+// #include "ResultOpaque.h"
+import "C"
+
+import (
+	"runtime"
+)
+
+type ResultOpaque struct {
+	handle *C.ResultOpaque
+	owned  bool
+	// Objects this may borrow from, kept alive for as long as this is
+	edges []any
+}
+
+// newResultOpaque wraps a handle, which is destroyed by Close or the garbage collector if it is owned
+func newResultOpaque(handle *C.ResultOpaque, owned bool, edges []any) *ResultOpaque {
+	if handle == nil {
+		return nil
+	}
+	self := &ResultOpaque{handle, owned, edges}
+	if owned {
+		runtime.SetFinalizer(self, (*ResultOpaque).Close)
+	}
+	return self
+}
+
+// Close releases the Rust object if this owns it. It must not be used afterwards.
+func (self *ResultOpaque) Close() {
+	if self.owned {
+		self.owned = false
+		runtime.SetFinalizer(self, nil)
+		C.ResultOpaque_destroy(self.handle)
+	}
+}
+
+// toNative returns the handle of an object that may be nil
+func (self *ResultOpaque) toNative() *C.ResultOpaque {
+	if self == nil {
+		return nil
+	}
+	return self.handle
+}
+
+// Failures are returned as a `*DiplomatError` holding a `ErrorEnum`.
+func NewResultOpaque(i int32) (*ResultOpaque, error) {
+	result := C.ResultOpaque_new(C.int32_t(i))
+	if !result.is_ok {
+		err := diplomatResultValue[C.ErrorEnum](&result)
+		return nil, &DiplomatError{Value: ErrorEnum(err)}
+	}
+	ok := diplomatResultValue[*C.ResultOpaque](&result)
+	return newResultOpaque(ok, true, nil), nil
+}
+
+// Failures are returned as a `*DiplomatError` holding a `ErrorEnum`.
+func ResultOpaqueFailingFoo() (*ResultOpaque, error) {
+	result := C.ResultOpaque_new_failing_foo()
+	if !result.is_ok {
+		err := diplomatResultValue[C.ErrorEnum](&result)
+		return nil, &DiplomatError{Value: ErrorEnum(err)}
+	}
+	ok := diplomatResultValue[*C.ResultOpaque](&result)
+	return newResultOpaque(ok, true, nil), nil
+}
+
+// Failures are returned as a `*DiplomatError` holding a `ErrorEnum`.
+func ResultOpaqueFailingBar() (*ResultOpaque, error) {
+	result := C.ResultOpaque_new_failing_bar()
+	if !result.is_ok {
+		err := diplomatResultValue[C.ErrorEnum](&result)
+		return nil, &DiplomatError{Value: ErrorEnum(err)}
+	}
+	ok := diplomatResultValue[*C.ResultOpaque](&result)
+	return newResultOpaque(ok, true, nil), nil
+}
+
+func ResultOpaqueNewFailingUnit() (*ResultOpaque, error) {
+	result := C.ResultOpaque_new_failing_unit()
+	if !result.is_ok {
+		return nil, &DiplomatError{}
+	}
+	ok := diplomatResultValue[*C.ResultOpaque](&result)
+	return newResultOpaque(ok, true, nil), nil
+}
+
+// Failures are returned as a `*DiplomatError` holding a `ErrorStruct`.
+func ResultOpaqueFailingStruct(i int32) (*ResultOpaque, error) {
+	result := C.ResultOpaque_new_failing_struct(C.int32_t(i))
+	if !result.is_ok {
+		err := diplomatResultValue[C.ErrorStruct](&result)
+		return nil, &DiplomatError{Value: newErrorStruct(err, nil)}
+	}
+	ok := diplomatResultValue[*C.ResultOpaque](&result)
+	return newResultOpaque(ok, true, nil), nil
+}
+
+// Failures are returned as a `*DiplomatError` holding a `*ResultOpaque`.
+func ResultOpaqueNewInErr(i int32) error {
+	result := C.ResultOpaque_new_in_err(C.int32_t(i))
+	if !result.is_ok {
+		err := diplomatResultValue[*C.ResultOpaque](&result)
+		return &DiplomatError{Value: newResultOpaque(err, true, nil)}
+	}
+	return nil
+}
+
+func ResultOpaqueNewInt(i int32) (int32, error) {
+	result := C.ResultOpaque_new_int(C.int32_t(i))
+	if !result.is_ok {
+		return 0, &DiplomatError{}
+	}
+	ok := diplomatResultValue[C.int32_t](&result)
+	return int32(ok), nil
+}
+
+// Failures are returned as a `*DiplomatError` holding a `*ResultOpaque`.
+func ResultOpaqueNewInEnumErr(i int32) (ErrorEnum, error) {
+	result := C.ResultOpaque_new_in_enum_err(C.int32_t(i))
+	if !result.is_ok {
+		err := diplomatResultValue[*C.ResultOpaque](&result)
+		return 0, &DiplomatError{Value: newResultOpaque(err, true, nil)}
+	}
+	ok := diplomatResultValue[C.ErrorEnum](&result)
+	return ErrorEnum(ok), nil
+}
+
+func (self *ResultOpaque) AssertInteger(i int32) {
+	C.ResultOpaque_assert_integer(self.handle, C.int32_t(i))
+	runtime.KeepAlive(self)
+}
@@ -0,0 +1,47 @@
+// generated by diplomat-tool
+
+package feature_tests
+
+// #include "diplomat_go_runtime.h"
+// #include "Two.h"
+import "C"
+
+import (
+	"runtime"
+)
+
+type Two struct {
+	handle *C.Two
+	owned  bool
+	// Objects this may borrow from, kept alive for as long as this is
+	edges []any
+}
+
+// newTwo wraps a handle, which is destroyed by Close or the garbage collector if it is owned
+func newTwo(handle *C.Two, owned bool, edges []any) *Two {
+	if handle == nil {
+		return nil
+	}
+	self := &Two{handle, owned, edges}
+	if owned {
+		runtime.SetFinalizer(self, (*Two).Close)
+	}
+	return self
+}
+
+// Close releases the Rust object if this owns it. It must not be used afterwards.
+func (self *Two) Close() {
+	if self.owned {
+		self.owned = false
+		runtime.SetFinalizer(self, nil)
+		C.Two_destroy(self.handle)
+	}
+}
+
+// toNative returns the handle of an object that may be nil
+func (self *Two) toNative() *C.Two {
+	if self == nil {
+		return nil
+	}
+	return self.handle
+}
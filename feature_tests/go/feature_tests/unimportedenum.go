@@ -0,0 +1,15 @@
+// generated by diplomat-tool
+
+package feature_tests
+
+// #include "diplomat_go_runtime.h"
+// #include "UnimportedEnum.h"
+import "C"
+
+type UnimportedEnum int32
+
+const (
+	UnimportedEnumA UnimportedEnum = 0
+	UnimportedEnumB UnimportedEnum = 1
+	UnimportedEnumC UnimportedEnum = 2
+)
@@ -0,0 +1,58 @@
+// generated by diplomat-tool
+
+package feature_tests
+
+// #include "diplomat_go_runtime.h"
+// #include "Unnamespaced.h"
+import "C"
+
+import (
+	"runtime"
+)
+
+type Unnamespaced struct {
+	handle *C.Unnamespaced
+	owned  bool
+	// Objects this may borrow from, kept alive for as long as this is
+	edges []any
+}
+
+// newUnnamespaced wraps a handle, which is destroyed by Close or the garbage collector if it is owned
+func newUnnamespaced(handle *C.Unnamespaced, owned bool, edges []any) *Unnamespaced {
+	if handle == nil {
+		return nil
+	}
+	self := &Unnamespaced{handle, owned, edges}
+	if owned {
+		runtime.SetFinalizer(self, (*Unnamespaced).Close)
+	}
+	return self
+}
+
+// Close releases the Rust object if this owns it. It must not be used afterwards.
+func (self *Unnamespaced) Close() {
+	if self.owned {
+		self.owned = false
+		runtime.SetFinalizer(self, nil)
+		C.namespace_Unnamespaced_destroy(self.handle)
+	}
+}
+
+// toNative returns the handle of an object that may be nil
+func (self *Unnamespaced) toNative() *C.Unnamespaced {
+	if self == nil {
+		return nil
+	}
+	return self.handle
+}
+
+func UnnamespacedMake(e AttrEnum) *Unnamespaced {
+	result := C.namespace_Unnamespaced_make(C.AttrEnum(e))
+	return newUnnamespaced(result, true, nil)
+}
+
+func (self *Unnamespaced) UseNamespaced(n *AttrOpaque1) {
+	C.namespace_Unnamespaced_use_namespaced(self.handle, n.handle)
+	runtime.KeepAlive(self)
+	runtime.KeepAlive(n)
+}
@@ -0,0 +1,3 @@
+module diplomat/feature_tests
+
+go 1.21
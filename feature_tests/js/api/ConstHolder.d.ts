@@ -0,0 +1,18 @@
+import { i8, u32, char } from "./diplomat-runtime"
+
+/**
+ */
+export class ConstHolder {
+
+  /**
+   */
+  static readonly MIN_VALUE: i8;
+
+  /**
+   */
+  static readonly SEPARATOR: char;
+
+  /**
+   */
+  static max_len(): u32;
+}
@@ -0,0 +1,25 @@
+import wasm from "./diplomat-wasm.mjs"
+import * as diplomatRuntime from "./diplomat-runtime.mjs"
+
+const ConstHolder_box_destroy_registry = new FinalizationRegistry(underlying => {
+  wasm.ConstHolder_destroy(underlying);
+});
+
+export class ConstHolder {
+  #lifetimeEdges = [];
+  constructor(underlying, owned, edges) {
+    this.underlying = underlying;
+    this.#lifetimeEdges.push(...edges);
+    if (owned) {
+      ConstHolder_box_destroy_registry.register(this, underlying);
+    }
+  }
+
+  static MIN_VALUE = -128;
+
+  static SEPARATOR = "\u{7c}";
+
+  static max_len() {
+    return wasm.ConstHolder_max_len();
+  }
+}
@@ -0,0 +1,11 @@
+
+/**
+ */
+export enum PanicError {
+  /**
+   */
+  Panicked = 'Panicked',
+  /**
+   */
+  TooLarge = 'TooLarge',
+}
\ No newline at end of file
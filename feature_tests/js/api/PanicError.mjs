@@ -0,0 +1,17 @@
+import wasm from "./diplomat-wasm.mjs"
+import * as diplomatRuntime from "./diplomat-runtime.mjs"
+
+export const PanicError_js_to_rust = {
+  "Panicked": 0,
+  "TooLarge": 1,
+};
+
+export const PanicError_rust_to_js = {
+  [0]: "Panicked",
+  [1]: "TooLarge",
+};
+
+export const PanicError = {
+  "Panicked": "Panicked",
+  "TooLarge": "TooLarge",
+};
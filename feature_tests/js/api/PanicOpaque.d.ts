@@ -0,0 +1,25 @@
+import { u32 } from "./diplomat-runtime"
+import { FFIError } from "./diplomat-runtime"
+import { PanicError } from "./PanicError";
+
+/**
+ */
+export class PanicOpaque {
+
+  /**
+
+   * Panics, which foreign code is told about after the call
+   */
+  static panics(message: string): void;
+
+  /**
+
+   * Panics when `x` is zero, which is reported as `PanicError::Panicked`
+   * @throws {@link FFIError}<{@link PanicError}>
+   */
+  static checked_divide(x: u32): u32 | never;
+
+  /**
+   */
+  static not_panicking(x: u32): u32;
+}
@@ -0,0 +1,45 @@
+import wasm from "./diplomat-wasm.mjs"
+import * as diplomatRuntime from "./diplomat-runtime.mjs"
+import { PanicError_js_to_rust, PanicError_rust_to_js } from "./PanicError.mjs"
+
+const PanicOpaque_box_destroy_registry = new FinalizationRegistry(underlying => {
+  wasm.PanicOpaque_destroy(underlying);
+});
+
+export class PanicOpaque {
+  #lifetimeEdges = [];
+  constructor(underlying, owned, edges) {
+    this.underlying = underlying;
+    this.#lifetimeEdges.push(...edges);
+    if (owned) {
+      PanicOpaque_box_destroy_registry.register(this, underlying);
+    }
+  }
+
+  static panics(arg_message) {
+    const buf_arg_message = diplomatRuntime.DiplomatBuf.str8(wasm, arg_message);
+    wasm.PanicOpaque_panics(buf_arg_message.ptr, buf_arg_message.size);
+    buf_arg_message.free();
+  }
+
+  static checked_divide(arg_x) {
+    return (() => {
+      const diplomat_receive_buffer = wasm.diplomat_alloc(5, 4);
+      wasm.PanicOpaque_checked_divide(diplomat_receive_buffer, arg_x);
+      const is_ok = diplomatRuntime.resultFlag(wasm, diplomat_receive_buffer, 4);
+      if (is_ok) {
+        const ok_value = (new Uint32Array(wasm.memory.buffer, diplomat_receive_buffer, 1))[0];
+        wasm.diplomat_free(diplomat_receive_buffer, 5, 4);
+        return ok_value;
+      } else {
+        const throw_value = PanicError_rust_to_js[diplomatRuntime.enumDiscriminant(wasm, diplomat_receive_buffer)];
+        wasm.diplomat_free(diplomat_receive_buffer, 5, 4);
+        throw new diplomatRuntime.FFIError(throw_value);
+      }
+    })();
+  }
+
+  static not_panicking(arg_x) {
+    return wasm.PanicOpaque_not_panicking(arg_x);
+  }
+}
@@ -0,0 +1,19 @@
+import { u32, f64 } from "./diplomat-runtime"
+
+/**
+ */
+export const ENABLED: boolean;
+
+/**
+ */
+export const GREETING: string;
+
+/**
+
+ * The length `ConstHolder::max_len` returns
+ */
+export const MAX_LEN: u32;
+
+/**
+ */
+export const RATIO: f64;
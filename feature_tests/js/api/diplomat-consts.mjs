@@ -0,0 +1,4 @@
+export const ENABLED = true;
+export const GREETING = "hello";
+export const MAX_LEN = 16;
+export const RATIO = 0.5;
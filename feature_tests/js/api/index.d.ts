@@ -7,6 +7,7 @@ export { BorrowedFields } from './BorrowedFields';
 export { BorrowedFieldsReturning } from './BorrowedFieldsReturning';
 export { BorrowedFieldsWithBounds } from './BorrowedFieldsWithBounds';
 export { Comparable } from './Comparable';
+export { ConstHolder } from './ConstHolder';
 export { ContiguousEnum } from './ContiguousEnum';
 export { ErrorEnum } from './ErrorEnum';
 export { ErrorStruct } from './ErrorStruct';
@@ -24,9 +25,12 @@ export { Opaque } from './Opaque';
 export { OptionOpaque } from './OptionOpaque';
 export { OptionOpaqueChar } from './OptionOpaqueChar';
 export { OptionStruct } from './OptionStruct';
+export { PanicError } from './PanicError';
+export { PanicOpaque } from './PanicOpaque';
 export { RefList } from './RefList';
 export { RefListParameter } from './RefListParameter';
 export { ResultOpaque } from './ResultOpaque';
 export { Two } from './Two';
 export { UnimportedEnum } from './UnimportedEnum';
 export { Unnamespaced } from './Unnamespaced';
+export * from './diplomat-consts';
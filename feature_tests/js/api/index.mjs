@@ -7,6 +7,7 @@ export { BorrowedFields } from './BorrowedFields.mjs';
 export { BorrowedFieldsReturning } from './BorrowedFieldsReturning.mjs';
 export { BorrowedFieldsWithBounds } from './BorrowedFieldsWithBounds.mjs';
 export { Comparable } from './Comparable.mjs';
+export { ConstHolder } from './ConstHolder.mjs';
 export { ContiguousEnum } from './ContiguousEnum.mjs';
 export { ErrorEnum } from './ErrorEnum.mjs';
 export { ErrorStruct } from './ErrorStruct.mjs';
@@ -24,9 +25,12 @@ export { Opaque } from './Opaque.mjs';
 export { OptionOpaque } from './OptionOpaque.mjs';
 export { OptionOpaqueChar } from './OptionOpaqueChar.mjs';
 export { OptionStruct } from './OptionStruct.mjs';
+export { PanicError } from './PanicError.mjs';
+export { PanicOpaque } from './PanicOpaque.mjs';
 export { RefList } from './RefList.mjs';
 export { RefListParameter } from './RefListParameter.mjs';
 export { ResultOpaque } from './ResultOpaque.mjs';
 export { Two } from './Two.mjs';
 export { UnimportedEnum } from './UnimportedEnum.mjs';
 export { Unnamespaced } from './Unnamespaced.mjs';
+export * from './diplomat-consts.mjs';
@@ -0,0 +1,18 @@
+plugins {
+    kotlin("jvm") version "1.9.22"
+}
+
+repositories {
+    mavenCentral()
+}
+
+dependencies {
+    implementation("net.java.dev.jna:jna:5.14.0")
+    testImplementation(kotlin("test"))
+}
+
+tasks.test {
+    useJUnitPlatform()
+    // Where `cargo build -p diplomat-feature-tests` puts the library
+    systemProperty("jna.library.path", "$rootDir/../../target/debug")
+}
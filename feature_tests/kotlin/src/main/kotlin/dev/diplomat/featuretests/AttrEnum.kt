@@ -0,0 +1,19 @@
+// generated by diplomat-tool
+@file:Suppress("unused", "UNUSED_PARAMETER", "UNUSED_VARIABLE", "FunctionName", "ClassName")
+
+package dev.diplomat.featuretests
+
+import com.sun.jna.Pointer
+import com.sun.jna.Structure
+import com.sun.jna.Union
+import java.lang.ref.Reference
+
+enum class AttrEnum(internal val value: Int) {
+    A(0),
+    B(1),
+    C(2);
+
+    companion object {
+        internal fun fromNative(native: Int): AttrEnum = values().first { it.value == native }
+    }
+}
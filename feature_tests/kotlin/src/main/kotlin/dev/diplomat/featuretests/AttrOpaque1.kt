@@ -0,0 +1,64 @@
+// generated by diplomat-tool
+@file:Suppress("unused", "UNUSED_PARAMETER", "UNUSED_VARIABLE", "FunctionName", "ClassName")
+
+package dev.diplomat.featuretests
+
+import com.sun.jna.Pointer
+import com.sun.jna.Structure
+import com.sun.jna.Union
+import java.lang.ref.Reference
+
+class AttrOpaque1 internal constructor(
+    internal val handle: Pointer,
+    owned: Boolean,
+    /** Objects this may borrow from, kept alive for as long as this is */
+    internal val edges: List<Any>,
+) : AutoCloseable {
+    private val cleanable = if (owned) DiplomatRuntime.CLEANER.register(this, Destroy(handle)) else null
+
+    private class Destroy(val handle: Pointer) : Runnable {
+        override fun run() = lib.namespace_AttrOpaque1_destroy(handle)
+    }
+
+    /** Releases the Rust object if this owns it. It must not be used afterwards. */
+    override fun close() {
+        cleanable?.clean()
+    }
+
+    fun methodDisabledcpp() {
+        lib.namespace_AttrOpaque1_method_disabledcpp(handle)
+        Reference.reachabilityFence(this)
+    }
+
+    fun useUnnamespaced(un: Unnamespaced) {
+        lib.namespace_AttrOpaque1_use_unnamespaced(handle, un.handle)
+        Reference.reachabilityFence(this)
+        Reference.reachabilityFence(un)
+    }
+
+    fun useNamespaced(n: AttrEnum) {
+        lib.namespace_AttrOpaque1_use_namespaced(handle, n.value)
+        Reference.reachabilityFence(this)
+    }
+
+    val abirenamed: UByte
+        get() {
+            val result = lib.renamed_on_abi_only(handle)
+            Reference.reachabilityFence(this)
+            return result.toUByte()
+        }
+
+    val method: UByte
+        get() {
+            val result = lib.namespace_AttrOpaque1_method(handle)
+            Reference.reachabilityFence(this)
+            return result.toUByte()
+        }
+
+    companion object {
+        operator fun invoke(): AttrOpaque1 {
+            val result = lib.namespace_AttrOpaque1_new()
+            return AttrOpaque1(result!!, true, listOf())
+        }
+    }
+}
@@ -0,0 +1,37 @@
+// generated by diplomat-tool
+@file:Suppress("unused", "UNUSED_PARAMETER", "UNUSED_VARIABLE", "FunctionName", "ClassName")
+
+package dev.diplomat.featuretests
+
+import com.sun.jna.Pointer
+import com.sun.jna.Structure
+import com.sun.jna.Union
+import java.lang.ref.Reference
+
+data class BorrowedFields(
+    var a: String,
+    var b: String,
+    var c: String,
+) {
+    internal fun toNative(keep: MutableList<Any>): BorrowedFieldsNative.ByValue {
+        val native = BorrowedFieldsNative.ByValue()
+        native.a = DiplomatRuntime.str16(this.a, keep)
+        native.b = DiplomatRuntime.str8(this.b, keep)
+        native.c = DiplomatRuntime.str8(this.c, keep)
+        return native
+    }
+
+    companion object {
+        internal fun fromNative(native: BorrowedFieldsNative, edges: List<Any>): BorrowedFields = BorrowedFields(DiplomatRuntime.readStr16(native.a, false), DiplomatRuntime.readStr8(native.b, false), DiplomatRuntime.readStr8(native.c, false))
+    }
+}
+
+internal open class BorrowedFieldsNative : Structure() {
+    @JvmField var a: DiplomatSlice = DiplomatSlice()
+    @JvmField var b: DiplomatSlice = DiplomatSlice()
+    @JvmField var c: DiplomatSlice = DiplomatSlice()
+
+    override fun getFieldOrder() = listOf("a", "b", "c")
+
+    class ByValue : BorrowedFieldsNative(), Structure.ByValue
+}
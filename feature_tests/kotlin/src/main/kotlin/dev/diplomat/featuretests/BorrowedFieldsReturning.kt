@@ -0,0 +1,31 @@
+// generated by diplomat-tool
+@file:Suppress("unused", "UNUSED_PARAMETER", "UNUSED_VARIABLE", "FunctionName", "ClassName")
+
+package dev.diplomat.featuretests
+
+import com.sun.jna.Pointer
+import com.sun.jna.Structure
+import com.sun.jna.Union
+import java.lang.ref.Reference
+
+data class BorrowedFieldsReturning(
+    var bytes: String,
+) {
+    internal fun toNative(keep: MutableList<Any>): BorrowedFieldsReturningNative.ByValue {
+        val native = BorrowedFieldsReturningNative.ByValue()
+        native.bytes = DiplomatRuntime.str8(this.bytes, keep)
+        return native
+    }
+
+    companion object {
+        internal fun fromNative(native: BorrowedFieldsReturningNative, edges: List<Any>): BorrowedFieldsReturning = BorrowedFieldsReturning(DiplomatRuntime.readStr8(native.bytes, false))
+    }
+}
+
+internal open class BorrowedFieldsReturningNative : Structure() {
+    @JvmField var bytes: DiplomatSlice = DiplomatSlice()
+
+    override fun getFieldOrder() = listOf("bytes")
+
+    class ByValue : BorrowedFieldsReturningNative(), Structure.ByValue
+}
@@ -0,0 +1,37 @@
+// generated by diplomat-tool
+@file:Suppress("unused", "UNUSED_PARAMETER", "UNUSED_VARIABLE", "FunctionName", "ClassName")
+
+package dev.diplomat.featuretests
+
+import com.sun.jna.Pointer
+import com.sun.jna.Structure
+import com.sun.jna.Union
+import java.lang.ref.Reference
+
+data class BorrowedFieldsWithBounds(
+    var fieldA: String,
+    var fieldB: String,
+    var fieldC: String,
+) {
+    internal fun toNative(keep: MutableList<Any>): BorrowedFieldsWithBoundsNative.ByValue {
+        val native = BorrowedFieldsWithBoundsNative.ByValue()
+        native.fieldA = DiplomatRuntime.str16(this.fieldA, keep)
+        native.fieldB = DiplomatRuntime.str8(this.fieldB, keep)
+        native.fieldC = DiplomatRuntime.str8(this.fieldC, keep)
+        return native
+    }
+
+    companion object {
+        internal fun fromNative(native: BorrowedFieldsWithBoundsNative, edges: List<Any>): BorrowedFieldsWithBounds = BorrowedFieldsWithBounds(DiplomatRuntime.readStr16(native.fieldA, false), DiplomatRuntime.readStr8(native.fieldB, false), DiplomatRuntime.readStr8(native.fieldC, false))
+    }
+}
+
+internal open class BorrowedFieldsWithBoundsNative : Structure() {
+    @JvmField var fieldA: DiplomatSlice = DiplomatSlice()
+    @JvmField var fieldB: DiplomatSlice = DiplomatSlice()
+    @JvmField var fieldC: DiplomatSlice = DiplomatSlice()
+
+    override fun getFieldOrder() = listOf("fieldA", "fieldB", "fieldC")
+
+    class ByValue : BorrowedFieldsWithBoundsNative(), Structure.ByValue
+}
@@ -0,0 +1,41 @@
+// generated by diplomat-tool
+@file:Suppress("unused", "UNUSED_PARAMETER", "UNUSED_VARIABLE", "FunctionName", "ClassName")
+
+package dev.diplomat.featuretests
+
+import com.sun.jna.Pointer
+import com.sun.jna.Structure
+import com.sun.jna.Union
+import java.lang.ref.Reference
+
+class Comparable internal constructor(
+    internal val handle: Pointer,
+    owned: Boolean,
+    /** Objects this may borrow from, kept alive for as long as this is */
+    internal val edges: List<Any>,
+) : AutoCloseable, kotlin.Comparable<Comparable> {
+    private val cleanable = if (owned) DiplomatRuntime.CLEANER.register(this, Destroy(handle)) else null
+
+    private class Destroy(val handle: Pointer) : Runnable {
+        override fun run() = lib.namespace_Comparable_destroy(handle)
+    }
+
+    /** Releases the Rust object if this owns it. It must not be used afterwards. */
+    override fun close() {
+        cleanable?.clean()
+    }
+
+    override fun compareTo(other: Comparable): Int {
+        val result = lib.namespace_Comparable_cmp(handle, other.handle)
+        Reference.reachabilityFence(this)
+        Reference.reachabilityFence(other)
+        return result.toInt()
+    }
+
+    companion object {
+        fun new(int: UByte): Comparable {
+            val result = lib.namespace_Comparable_new(int.toByte())
+            return Comparable(result!!, true, listOf())
+        }
+    }
+}
@@ -0,0 +1,20 @@
+// generated by diplomat-tool
+@file:Suppress("unused", "UNUSED_PARAMETER", "UNUSED_VARIABLE", "FunctionName", "ClassName")
+
+package dev.diplomat.featuretests
+
+import com.sun.jna.Pointer
+import com.sun.jna.Structure
+import com.sun.jna.Union
+import java.lang.ref.Reference
+
+enum class ContiguousEnum(internal val value: Int) {
+    C(0),
+    D(1),
+    E(2),
+    F(3);
+
+    companion object {
+        internal fun fromNative(native: Int): ContiguousEnum = values().first { it.value == native }
+    }
+}
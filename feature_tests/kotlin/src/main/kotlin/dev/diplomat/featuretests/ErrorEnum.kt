@@ -0,0 +1,18 @@
+// generated by diplomat-tool
+@file:Suppress("unused", "UNUSED_PARAMETER", "UNUSED_VARIABLE", "FunctionName", "ClassName")
+
+package dev.diplomat.featuretests
+
+import com.sun.jna.Pointer
+import com.sun.jna.Structure
+import com.sun.jna.Union
+import java.lang.ref.Reference
+
+enum class ErrorEnum(internal val value: Int) {
+    FOO(0),
+    BAR(1);
+
+    companion object {
+        internal fun fromNative(native: Int): ErrorEnum = values().first { it.value == native }
+    }
+}
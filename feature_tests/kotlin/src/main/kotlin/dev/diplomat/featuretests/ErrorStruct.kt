@@ -0,0 +1,34 @@
+// generated by diplomat-tool
+@file:Suppress("unused", "UNUSED_PARAMETER", "UNUSED_VARIABLE", "FunctionName", "ClassName")
+
+package dev.diplomat.featuretests
+
+import com.sun.jna.Pointer
+import com.sun.jna.Structure
+import com.sun.jna.Union
+import java.lang.ref.Reference
+
+data class ErrorStruct(
+    var i: Int,
+    var j: Int,
+) {
+    internal fun toNative(keep: MutableList<Any>): ErrorStructNative.ByValue {
+        val native = ErrorStructNative.ByValue()
+        native.i = this.i
+        native.j = this.j
+        return native
+    }
+
+    companion object {
+        internal fun fromNative(native: ErrorStructNative, edges: List<Any>): ErrorStruct = ErrorStruct(native.i, native.j)
+    }
+}
+
+internal open class ErrorStructNative : Structure() {
+    @JvmField var i: Int = 0
+    @JvmField var j: Int = 0
+
+    override fun getFieldOrder() = listOf("i", "j")
+
+    class ByValue : ErrorStructNative(), Structure.ByValue
+}
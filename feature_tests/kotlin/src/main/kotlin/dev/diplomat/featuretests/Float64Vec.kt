@@ -0,0 +1,136 @@
+// generated by diplomat-tool
+@file:Suppress("unused", "UNUSED_PARAMETER", "UNUSED_VARIABLE", "FunctionName", "ClassName")
+
+package dev.diplomat.featuretests
+
+import com.sun.jna.Pointer
+import com.sun.jna.Structure
+import com.sun.jna.Union
+import java.lang.ref.Reference
+
+class Float64Vec internal constructor(
+    internal val handle: Pointer,
+    owned: Boolean,
+    /** Objects this may borrow from, kept alive for as long as this is */
+    internal val edges: List<Any>,
+) : AutoCloseable {
+    private val cleanable = if (owned) DiplomatRuntime.CLEANER.register(this, Destroy(handle)) else null
+
+    private class Destroy(val handle: Pointer) : Runnable {
+        override fun run() = lib.Float64Vec_destroy(handle)
+    }
+
+    /** Releases the Rust object if this owns it. It must not be used afterwards. */
+    override fun close() {
+        cleanable?.clean()
+    }
+
+    fun fillSlice(v: DoubleArray) {
+        val keep = mutableListOf<Any>()
+        val vSlice = DiplomatRuntime.doubles(v, keep)
+        lib.Float64Vec_fill_slice(handle, vSlice.data, vSlice.len)
+        DiplomatRuntime.copyBack(vSlice, v)
+        Reference.reachabilityFence(this)
+        Reference.reachabilityFence(keep)
+    }
+
+    fun setValue(newSlice: DoubleArray) {
+        val keep = mutableListOf<Any>()
+        val newSliceSlice = DiplomatRuntime.doubles(newSlice, keep)
+        lib.Float64Vec_set_value(handle, newSliceSlice.data, newSliceSlice.len)
+        Reference.reachabilityFence(this)
+        Reference.reachabilityFence(keep)
+    }
+
+    override fun toString(): String {
+        val writeable = DiplomatWriteable()
+        lib.Float64Vec_to_string(handle, writeable.handle)
+        Reference.reachabilityFence(this)
+        return writeable.finalizeString()
+    }
+
+    fun borrow(): DoubleArray {
+        val result = lib.Float64Vec_borrow(handle)
+        Reference.reachabilityFence(this)
+        return DiplomatRuntime.readDoubles(result, false)
+    }
+
+    operator fun get(i: ULong): Double {
+        val result = lib.Float64Vec_get(handle, SizeT(i.toLong()))
+        Reference.reachabilityFence(this)
+        if (result.isOk == 0.toByte()) {
+            throw IndexOutOfBoundsException()
+        }
+        return (result.union.readField("ok") as Double)
+    }
+
+    val asBoxedSlice: DoubleArray
+        get() {
+            val result = lib.Float64Vec_as_boxed_slice(handle)
+            Reference.reachabilityFence(this)
+            return DiplomatRuntime.readDoubles(result, true)
+        }
+
+    val asSlice: DoubleArray
+        get() {
+            val result = lib.Float64Vec_as_slice(handle)
+            Reference.reachabilityFence(this)
+            return DiplomatRuntime.readDoubles(result, false)
+        }
+
+    companion object {
+        fun bool(v: BooleanArray): Float64Vec {
+            val keep = mutableListOf<Any>()
+            val vSlice = DiplomatRuntime.booleans(v, keep)
+            val result = lib.Float64Vec_new_bool(vSlice.data, vSlice.len)
+            Reference.reachabilityFence(keep)
+            return Float64Vec(result!!, true, listOf())
+        }
+
+        fun i16(v: ShortArray): Float64Vec {
+            val keep = mutableListOf<Any>()
+            val vSlice = DiplomatRuntime.shorts(v, keep)
+            val result = lib.Float64Vec_new_i16(vSlice.data, vSlice.len)
+            Reference.reachabilityFence(keep)
+            return Float64Vec(result!!, true, listOf())
+        }
+
+        fun u16(v: ShortArray): Float64Vec {
+            val keep = mutableListOf<Any>()
+            val vSlice = DiplomatRuntime.shorts(v, keep)
+            val result = lib.Float64Vec_new_u16(vSlice.data, vSlice.len)
+            Reference.reachabilityFence(keep)
+            return Float64Vec(result!!, true, listOf())
+        }
+
+        fun isize(v: LongArray): Float64Vec {
+            val keep = mutableListOf<Any>()
+            val vSlice = DiplomatRuntime.sizes(v, keep)
+            val result = lib.Float64Vec_new_isize(vSlice.data, vSlice.len)
+            Reference.reachabilityFence(keep)
+            return Float64Vec(result!!, true, listOf())
+        }
+
+        fun usize(v: LongArray): Float64Vec {
+            val keep = mutableListOf<Any>()
+            val vSlice = DiplomatRuntime.sizes(v, keep)
+            val result = lib.Float64Vec_new_usize(vSlice.data, vSlice.len)
+            Reference.reachabilityFence(keep)
+            return Float64Vec(result!!, true, listOf())
+        }
+
+        fun f64BeBytes(v: ByteArray): Float64Vec {
+            val keep = mutableListOf<Any>()
+            val vSlice = DiplomatRuntime.bytes(v, keep)
+            val result = lib.Float64Vec_new_f64_be_bytes(vSlice.data, vSlice.len)
+            Reference.reachabilityFence(keep)
+            return Float64Vec(result!!, true, listOf())
+        }
+
+        operator fun invoke(v: DoubleArray): Float64Vec {
+            val vSlice = DiplomatRuntime.doubles(v, null)
+            val result = lib.Float64Vec_new_from_owned(vSlice.data, vSlice.len)
+            return Float64Vec(result!!, true, listOf())
+        }
+    }
+}
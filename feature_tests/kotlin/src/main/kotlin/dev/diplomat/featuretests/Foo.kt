@@ -0,0 +1,74 @@
+// generated by diplomat-tool
+@file:Suppress("unused", "UNUSED_PARAMETER", "UNUSED_VARIABLE", "FunctionName", "ClassName")
+
+package dev.diplomat.featuretests
+
+import com.sun.jna.Pointer
+import com.sun.jna.Structure
+import com.sun.jna.Union
+import java.lang.ref.Reference
+
+class Foo internal constructor(
+    internal val handle: Pointer,
+    owned: Boolean,
+    /** Objects this may borrow from, kept alive for as long as this is */
+    internal val edges: List<Any>,
+) : AutoCloseable {
+    private val cleanable = if (owned) DiplomatRuntime.CLEANER.register(this, Destroy(handle)) else null
+
+    private class Destroy(val handle: Pointer) : Runnable {
+        override fun run() = lib.Foo_destroy(handle)
+    }
+
+    /** Releases the Rust object if this owns it. It must not be used afterwards. */
+    override fun close() {
+        cleanable?.clean()
+    }
+
+    fun asReturning(): BorrowedFieldsReturning {
+        val result = lib.Foo_as_returning(handle)
+        Reference.reachabilityFence(this)
+        return BorrowedFieldsReturning.fromNative(result, listOfNotNull<Any>(this))
+    }
+
+    val bar: Bar
+        get() {
+            val result = lib.Foo_get_bar(handle)
+            Reference.reachabilityFence(this)
+            return Bar(result!!, true, listOfNotNull<Any>(this))
+        }
+
+    companion object {
+        operator fun invoke(x: String): Foo {
+            val keep = mutableListOf<Any>()
+            val xSlice = DiplomatRuntime.str8(x, keep)
+            val result = lib.Foo_new(xSlice.data, xSlice.len)
+            Reference.reachabilityFence(keep)
+            return Foo(result!!, true, listOfNotNull<Any>(x, keep))
+        }
+
+        fun static(x: String): Foo {
+            val keep = mutableListOf<Any>()
+            val xSlice = DiplomatRuntime.str8(x, keep)
+            val result = lib.Foo_new_static(xSlice.data, xSlice.len)
+            Reference.reachabilityFence(keep)
+            return Foo(result!!, true, listOfNotNull<Any>(x, keep))
+        }
+
+        fun extractFromFields(fields: BorrowedFields): Foo {
+            val keep = mutableListOf<Any>()
+            val result = lib.Foo_extract_from_fields(fields.toNative(keep))
+            Reference.reachabilityFence(keep)
+            return Foo(result!!, true, listOfNotNull<Any>(fields, keep))
+        }
+
+        /** Test that the extraction logic correctly pins the right fields */
+        fun extractFromBounds(bounds: BorrowedFieldsWithBounds, anotherString: String): Foo {
+            val keep = mutableListOf<Any>()
+            val anotherStringSlice = DiplomatRuntime.str8(anotherString, keep)
+            val result = lib.Foo_extract_from_bounds(bounds.toNative(keep), anotherStringSlice.data, anotherStringSlice.len)
+            Reference.reachabilityFence(keep)
+            return Foo(result!!, true, listOfNotNull<Any>(bounds, anotherString, keep))
+        }
+    }
+}
@@ -0,0 +1,34 @@
+// generated by diplomat-tool
+@file:Suppress("unused", "UNUSED_PARAMETER", "UNUSED_VARIABLE", "FunctionName", "ClassName")
+
+package dev.diplomat.featuretests
+
+import com.sun.jna.Pointer
+import com.sun.jna.Structure
+import com.sun.jna.Union
+import java.lang.ref.Reference
+
+data class ImportedStruct(
+    var foo: UnimportedEnum,
+    var count: UByte,
+) {
+    internal fun toNative(keep: MutableList<Any>): ImportedStructNative.ByValue {
+        val native = ImportedStructNative.ByValue()
+        native.foo = this.foo.value
+        native.count = this.count.toByte()
+        return native
+    }
+
+    companion object {
+        internal fun fromNative(native: ImportedStructNative, edges: List<Any>): ImportedStruct = ImportedStruct(UnimportedEnum.fromNative(native.foo), native.count.toUByte())
+    }
+}
+
+internal open class ImportedStructNative : Structure() {
+    @JvmField var foo: Int = 0
+    @JvmField var count: Byte = 0
+
+    override fun getFieldOrder() = listOf("foo", "count")
+
+    class ByValue : ImportedStructNative(), Structure.ByValue
+}
@@ -0,0 +1,301 @@
+// generated by diplomat-tool
+@file:Suppress("unused", "UNUSED_PARAMETER", "UNUSED_VARIABLE", "FunctionName", "ClassName")
+
+package dev.diplomat.featuretests
+
+import com.sun.jna.Library
+import com.sun.jna.Native
+import com.sun.jna.Pointer
+import com.sun.jna.Structure
+import com.sun.jna.Union
+import java.lang.ref.Reference
+
+/** The functions exported by the native library */
+internal interface DiplomatLib : Library {
+    fun diplomat_alloc(size: SizeT, align: SizeT): Pointer?
+    fun diplomat_free(ptr: Pointer?, size: SizeT, align: SizeT)
+    fun diplomat_buffer_writeable_create(cap: SizeT): Pointer?
+    fun diplomat_buffer_writeable_get_bytes(writeable: Pointer?): Pointer?
+    fun diplomat_buffer_writeable_len(writeable: Pointer?): SizeT
+    fun diplomat_buffer_writeable_destroy(writeable: Pointer?)
+    // Only looked up if called, which needs the `catch_panics` feature of the runtime
+    fun diplomat_panicked(): Byte
+    fun diplomat_take_panic(writeable: Pointer?)
+    fun MyStruct_new(): MyStructNative.ByValue
+    fun MyStruct_into_a(self: MyStructNative.ByValue): Byte
+    fun namespace_AttrOpaque1_destroy(self: Pointer?)
+    fun namespace_AttrOpaque1_new(): Pointer?
+    fun namespace_AttrOpaque1_method(self: Pointer?): Byte
+    fun renamed_on_abi_only(self: Pointer?): Byte
+    fun namespace_AttrOpaque1_method_disabledcpp(self: Pointer?)
+    fun namespace_AttrOpaque1_use_unnamespaced(self: Pointer?, un: Pointer?)
+    fun namespace_AttrOpaque1_use_namespaced(self: Pointer?, n: Int)
+    fun namespace_AttrOpaque2_destroy(self: Pointer?)
+    fun namespace_Comparable_destroy(self: Pointer?)
+    fun namespace_Comparable_new(int: Byte): Pointer?
+    fun namespace_Comparable_cmp(self: Pointer?, other: Pointer?): Byte
+    fun namespace_MyIterable_destroy(self: Pointer?)
+    fun namespace_MyIterable_new(xData: Pointer?, xLen: SizeT): Pointer?
+    fun namespace_MyIterable_iter(self: Pointer?): Pointer?
+    fun namespace_MyIterator_destroy(self: Pointer?)
+    fun namespace_MyIterator_next(self: Pointer?): ResultUint8TVoidNative.ByValue
+    fun namespace_Unnamespaced_destroy(self: Pointer?)
+    fun namespace_Unnamespaced_make(e: Int): Pointer?
+    fun namespace_Unnamespaced_use_namespaced(self: Pointer?, n: Pointer?)
+    fun ConstHolder_destroy(self: Pointer?)
+    fun ConstHolder_max_len(): Int
+    fun Bar_destroy(self: Pointer?)
+    fun Bar_foo(self: Pointer?): Pointer?
+    fun Foo_destroy(self: Pointer?)
+    fun Foo_new(xData: Pointer?, xLen: SizeT): Pointer?
+    fun Foo_get_bar(self: Pointer?): Pointer?
+    fun Foo_new_static(xData: Pointer?, xLen: SizeT): Pointer?
+    fun Foo_as_returning(self: Pointer?): BorrowedFieldsReturningNative.ByValue
+    fun Foo_extract_from_fields(fields: BorrowedFieldsNative.ByValue): Pointer?
+    fun Foo_extract_from_bounds(bounds: BorrowedFieldsWithBoundsNative.ByValue, anotherStringData: Pointer?, anotherStringLen: SizeT): Pointer?
+    fun One_destroy(self: Pointer?)
+    fun One_transitivity(hold: Pointer?, nohold: Pointer?): Pointer?
+    fun One_cycle(hold: Pointer?, nohold: Pointer?): Pointer?
+    fun One_many_dependents(a: Pointer?, b: Pointer?, c: Pointer?, d: Pointer?, nohold: Pointer?): Pointer?
+    fun One_return_outlives_param(hold: Pointer?, nohold: Pointer?): Pointer?
+    fun One_diamond_top(top: Pointer?, left: Pointer?, right: Pointer?, bottom: Pointer?): Pointer?
+    fun One_diamond_left(top: Pointer?, left: Pointer?, right: Pointer?, bottom: Pointer?): Pointer?
+    fun One_diamond_right(top: Pointer?, left: Pointer?, right: Pointer?, bottom: Pointer?): Pointer?
+    fun One_diamond_bottom(top: Pointer?, left: Pointer?, right: Pointer?, bottom: Pointer?): Pointer?
+    fun One_diamond_and_nested_types(a: Pointer?, b: Pointer?, c: Pointer?, d: Pointer?, nohold: Pointer?): Pointer?
+    fun One_implicit_bounds(explicitHold: Pointer?, implicitHold: Pointer?, nohold: Pointer?): Pointer?
+    fun One_implicit_bounds_deep(explicit: Pointer?, implicit1: Pointer?, implicit2: Pointer?, nohold: Pointer?): Pointer?
+    fun Two_destroy(self: Pointer?)
+    fun OptionOpaque_destroy(self: Pointer?)
+    fun OptionOpaque_new(i: Int): Pointer?
+    fun OptionOpaque_new_none(): Pointer?
+    fun OptionOpaque_returns(): ResultOptionStructVoidNative.ByValue
+    fun OptionOpaque_new_struct(): OptionStructNative.ByValue
+    fun OptionOpaque_new_struct_nones(): OptionStructNative.ByValue
+    fun OptionOpaque_assert_integer(self: Pointer?, i: Int)
+    fun OptionOpaque_option_opaque_argument(arg: Pointer?): Byte
+    fun OptionOpaqueChar_destroy(self: Pointer?)
+    fun OptionOpaqueChar_assert_char(self: Pointer?, ch: Int)
+    fun PanicOpaque_destroy(self: Pointer?)
+    fun PanicOpaque_panics(messageData: Pointer?, messageLen: SizeT)
+    fun PanicOpaque_checked_divide(x: Int): ResultUint32TPanicErrorNative.ByValue
+    fun PanicOpaque_not_panicking(x: Int): Int
+    fun ResultOpaque_destroy(self: Pointer?)
+    fun ResultOpaque_new(i: Int): ResultBoxResultOpaqueErrorEnumNative.ByValue
+    fun ResultOpaque_new_failing_foo(): ResultBoxResultOpaqueErrorEnumNative.ByValue
+    fun ResultOpaque_new_failing_bar(): ResultBoxResultOpaqueErrorEnumNative.ByValue
+    fun ResultOpaque_new_failing_unit(): ResultBoxResultOpaqueVoidNative.ByValue
+    fun ResultOpaque_new_failing_struct(i: Int): ResultBoxResultOpaqueErrorStructNative.ByValue
+    fun ResultOpaque_new_in_err(i: Int): ResultVoidBoxResultOpaqueNative.ByValue
+    fun ResultOpaque_new_int(i: Int): ResultInt32TVoidNative.ByValue
+    fun ResultOpaque_new_in_enum_err(i: Int): ResultErrorEnumBoxResultOpaqueNative.ByValue
+    fun ResultOpaque_assert_integer(self: Pointer?, i: Int)
+    fun RefList_destroy(self: Pointer?)
+    fun RefList_node(data: Pointer?): Pointer?
+    fun RefListParameter_destroy(self: Pointer?)
+    fun Float64Vec_destroy(self: Pointer?)
+    fun Float64Vec_new_bool(vData: Pointer?, vLen: SizeT): Pointer?
+    fun Float64Vec_new_i16(vData: Pointer?, vLen: SizeT): Pointer?
+    fun Float64Vec_new_u16(vData: Pointer?, vLen: SizeT): Pointer?
+    fun Float64Vec_new_isize(vData: Pointer?, vLen: SizeT): Pointer?
+    fun Float64Vec_new_usize(vData: Pointer?, vLen: SizeT): Pointer?
+    fun Float64Vec_new_f64_be_bytes(vData: Pointer?, vLen: SizeT): Pointer?
+    fun Float64Vec_new_from_owned(vData: Pointer?, vLen: SizeT): Pointer?
+    fun Float64Vec_as_boxed_slice(self: Pointer?): DiplomatSlice.ByValue
+    fun Float64Vec_as_slice(self: Pointer?): DiplomatSlice.ByValue
+    fun Float64Vec_fill_slice(self: Pointer?, vData: Pointer?, vLen: SizeT)
+    fun Float64Vec_set_value(self: Pointer?, newSliceData: Pointer?, newSliceLen: SizeT)
+    fun Float64Vec_to_string(self: Pointer?, writeable: Pointer?)
+    fun Float64Vec_borrow(self: Pointer?): DiplomatSlice.ByValue
+    fun Float64Vec_get(self: Pointer?, i: SizeT): ResultDoubleVoidNative.ByValue
+    fun MyString_destroy(self: Pointer?)
+    fun MyString_new(vData: Pointer?, vLen: SizeT): Pointer?
+    fun MyString_new_unsafe(vData: Pointer?, vLen: SizeT): Pointer?
+    fun MyString_new_owned(vData: Pointer?, vLen: SizeT): Pointer?
+    fun MyString_new_from_first(vData: Pointer?, vLen: SizeT): Pointer?
+    fun MyString_set_str(self: Pointer?, newStrData: Pointer?, newStrLen: SizeT)
+    fun MyString_get_str(self: Pointer?, writeable: Pointer?)
+    fun Opaque_destroy(self: Pointer?)
+    fun Opaque_new(): Pointer?
+    fun Opaque_assert_struct(self: Pointer?, s: MyStructNative.ByValue)
+    fun Opaque_returns_usize(): SizeT
+    fun Opaque_returns_imported(): ImportedStructNative.ByValue
+    fun ShapeList_destroy(self: Pointer?)
+    fun ShapeList_new(): Pointer?
+    fun ShapeList_push(self: Pointer?, shape: ShapeNative.ByValue)
+    fun ShapeList_last(self: Pointer?): ShapeNative.ByValue
+    fun ShapeList_total_area(self: Pointer?): Double
+    fun MyEnum_into_value(self: Int): Byte
+}
+
+/**
+ * The native library, loaded on first use.
+ *
+ * The library name can be overridden with the `diplomat.library` system property.
+ */
+internal val lib: DiplomatLib by lazy {
+    Native.load(System.getProperty("diplomat.library", "diplomat_feature_tests"), DiplomatLib::class.java)
+}
+
+const val ENABLED: Boolean = true
+
+const val GREETING: String = "hello"
+
+/** The length `ConstHolder::max_len` returns */
+const val MAX_LEN: UInt = 16u
+
+const val RATIO: Double = 0.5
+
+internal open class ResultBoxResultOpaqueErrorEnumNative : Structure() {
+    @JvmField var union: ResultBoxResultOpaqueErrorEnumNativeUnion = ResultBoxResultOpaqueErrorEnumNativeUnion()
+    @JvmField var isOk: Byte = 0
+
+    override fun getFieldOrder() = listOf("union", "isOk")
+
+    class ByValue : ResultBoxResultOpaqueErrorEnumNative(), Structure.ByValue
+}
+
+internal class ResultBoxResultOpaqueErrorEnumNativeUnion : Union() {
+    @JvmField var ok: Pointer? = null
+    @JvmField var err: Int = 0
+
+    override fun getFieldOrder() = listOf("ok", "err")
+}
+
+internal open class ResultBoxResultOpaqueErrorStructNative : Structure() {
+    @JvmField var union: ResultBoxResultOpaqueErrorStructNativeUnion = ResultBoxResultOpaqueErrorStructNativeUnion()
+    @JvmField var isOk: Byte = 0
+
+    override fun getFieldOrder() = listOf("union", "isOk")
+
+    class ByValue : ResultBoxResultOpaqueErrorStructNative(), Structure.ByValue
+}
+
+internal class ResultBoxResultOpaqueErrorStructNativeUnion : Union() {
+    @JvmField var ok: Pointer? = null
+    @JvmField var err: ErrorStructNative = ErrorStructNative()
+
+    override fun getFieldOrder() = listOf("ok", "err")
+}
+
+internal open class ResultBoxResultOpaqueVoidNative : Structure() {
+    @JvmField var union: ResultBoxResultOpaqueVoidNativeUnion = ResultBoxResultOpaqueVoidNativeUnion()
+    @JvmField var isOk: Byte = 0
+
+    override fun getFieldOrder() = listOf("union", "isOk")
+
+    class ByValue : ResultBoxResultOpaqueVoidNative(), Structure.ByValue
+}
+
+internal class ResultBoxResultOpaqueVoidNativeUnion : Union() {
+    @JvmField var ok: Pointer? = null
+
+    override fun getFieldOrder() = listOf("ok")
+}
+
+internal open class ResultDoubleVoidNative : Structure() {
+    @JvmField var union: ResultDoubleVoidNativeUnion = ResultDoubleVoidNativeUnion()
+    @JvmField var isOk: Byte = 0
+
+    override fun getFieldOrder() = listOf("union", "isOk")
+
+    class ByValue : ResultDoubleVoidNative(), Structure.ByValue
+}
+
+internal class ResultDoubleVoidNativeUnion : Union() {
+    @JvmField var ok: Double = 0.0
+
+    override fun getFieldOrder() = listOf("ok")
+}
+
+internal open class ResultErrorEnumBoxResultOpaqueNative : Structure() {
+    @JvmField var union: ResultErrorEnumBoxResultOpaqueNativeUnion = ResultErrorEnumBoxResultOpaqueNativeUnion()
+    @JvmField var isOk: Byte = 0
+
+    override fun getFieldOrder() = listOf("union", "isOk")
+
+    class ByValue : ResultErrorEnumBoxResultOpaqueNative(), Structure.ByValue
+}
+
+internal class ResultErrorEnumBoxResultOpaqueNativeUnion : Union() {
+    @JvmField var ok: Int = 0
+    @JvmField var err: Pointer? = null
+
+    override fun getFieldOrder() = listOf("ok", "err")
+}
+
+internal open class ResultInt32TVoidNative : Structure() {
+    @JvmField var union: ResultInt32TVoidNativeUnion = ResultInt32TVoidNativeUnion()
+    @JvmField var isOk: Byte = 0
+
+    override fun getFieldOrder() = listOf("union", "isOk")
+
+    class ByValue : ResultInt32TVoidNative(), Structure.ByValue
+}
+
+internal class ResultInt32TVoidNativeUnion : Union() {
+    @JvmField var ok: Int = 0
+
+    override fun getFieldOrder() = listOf("ok")
+}
+
+internal open class ResultOptionStructVoidNative : Structure() {
+    @JvmField var union: ResultOptionStructVoidNativeUnion = ResultOptionStructVoidNativeUnion()
+    @JvmField var isOk: Byte = 0
+
+    override fun getFieldOrder() = listOf("union", "isOk")
+
+    class ByValue : ResultOptionStructVoidNative(), Structure.ByValue
+}
+
+internal class ResultOptionStructVoidNativeUnion : Union() {
+    @JvmField var ok: OptionStructNative = OptionStructNative()
+
+    override fun getFieldOrder() = listOf("ok")
+}
+
+internal open class ResultUint32TPanicErrorNative : Structure() {
+    @JvmField var union: ResultUint32TPanicErrorNativeUnion = ResultUint32TPanicErrorNativeUnion()
+    @JvmField var isOk: Byte = 0
+
+    override fun getFieldOrder() = listOf("union", "isOk")
+
+    class ByValue : ResultUint32TPanicErrorNative(), Structure.ByValue
+}
+
+internal class ResultUint32TPanicErrorNativeUnion : Union() {
+    @JvmField var ok: Int = 0
+    @JvmField var err: Int = 0
+
+    override fun getFieldOrder() = listOf("ok", "err")
+}
+
+internal open class ResultUint8TVoidNative : Structure() {
+    @JvmField var union: ResultUint8TVoidNativeUnion = ResultUint8TVoidNativeUnion()
+    @JvmField var isOk: Byte = 0
+
+    override fun getFieldOrder() = listOf("union", "isOk")
+
+    class ByValue : ResultUint8TVoidNative(), Structure.ByValue
+}
+
+internal class ResultUint8TVoidNativeUnion : Union() {
+    @JvmField var ok: Byte = 0
+
+    override fun getFieldOrder() = listOf("ok")
+}
+
+internal open class ResultVoidBoxResultOpaqueNative : Structure() {
+    @JvmField var union: ResultVoidBoxResultOpaqueNativeUnion = ResultVoidBoxResultOpaqueNativeUnion()
+    @JvmField var isOk: Byte = 0
+
+    override fun getFieldOrder() = listOf("union", "isOk")
+
+    class ByValue : ResultVoidBoxResultOpaqueNative(), Structure.ByValue
+}
+
+internal class ResultVoidBoxResultOpaqueNativeUnion : Union() {
+    @JvmField var err: Pointer? = null
+
+    override fun getFieldOrder() = listOf("err")
+}
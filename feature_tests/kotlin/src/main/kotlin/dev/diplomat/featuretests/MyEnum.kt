@@ -0,0 +1,27 @@
+// generated by diplomat-tool
+@file:Suppress("unused", "UNUSED_PARAMETER", "UNUSED_VARIABLE", "FunctionName", "ClassName")
+
+package dev.diplomat.featuretests
+
+import com.sun.jna.Pointer
+import com.sun.jna.Structure
+import com.sun.jna.Union
+import java.lang.ref.Reference
+
+enum class MyEnum(internal val value: Int) {
+    A(-2),
+    B(-1),
+    C(0),
+    D(1),
+    E(2),
+    F(3);
+
+    fun intoValue(): Byte {
+        val result = lib.MyEnum_into_value(value)
+        return result
+    }
+
+    companion object {
+        internal fun fromNative(native: Int): MyEnum = values().first { it.value == native }
+    }
+}
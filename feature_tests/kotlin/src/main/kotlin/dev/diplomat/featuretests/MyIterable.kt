@@ -0,0 +1,43 @@
+// generated by diplomat-tool
+@file:Suppress("unused", "UNUSED_PARAMETER", "UNUSED_VARIABLE", "FunctionName", "ClassName")
+
+package dev.diplomat.featuretests
+
+import com.sun.jna.Pointer
+import com.sun.jna.Structure
+import com.sun.jna.Union
+import java.lang.ref.Reference
+
+class MyIterable internal constructor(
+    internal val handle: Pointer,
+    owned: Boolean,
+    /** Objects this may borrow from, kept alive for as long as this is */
+    internal val edges: List<Any>,
+) : AutoCloseable, kotlin.collections.Iterable<UByte> {
+    private val cleanable = if (owned) DiplomatRuntime.CLEANER.register(this, Destroy(handle)) else null
+
+    private class Destroy(val handle: Pointer) : Runnable {
+        override fun run() = lib.namespace_MyIterable_destroy(handle)
+    }
+
+    /** Releases the Rust object if this owns it. It must not be used afterwards. */
+    override fun close() {
+        cleanable?.clean()
+    }
+
+    override fun iterator(): MyIterator {
+        val result = lib.namespace_MyIterable_iter(handle)
+        Reference.reachabilityFence(this)
+        return MyIterator(result!!, true, listOfNotNull<Any>(this))
+    }
+
+    companion object {
+        operator fun invoke(x: ByteArray): MyIterable {
+            val keep = mutableListOf<Any>()
+            val xSlice = DiplomatRuntime.bytes(x, keep)
+            val result = lib.namespace_MyIterable_new(xSlice.data, xSlice.len)
+            Reference.reachabilityFence(keep)
+            return MyIterable(result!!, true, listOf())
+        }
+    }
+}
@@ -0,0 +1,55 @@
+// generated by diplomat-tool
+@file:Suppress("unused", "UNUSED_PARAMETER", "UNUSED_VARIABLE", "FunctionName", "ClassName")
+
+package dev.diplomat.featuretests
+
+import com.sun.jna.Pointer
+import com.sun.jna.Structure
+import com.sun.jna.Union
+import java.lang.ref.Reference
+
+class MyIterator internal constructor(
+    internal val handle: Pointer,
+    owned: Boolean,
+    /** Objects this may borrow from, kept alive for as long as this is */
+    internal val edges: List<Any>,
+) : AutoCloseable, kotlin.collections.Iterator<UByte> {
+    private val cleanable = if (owned) DiplomatRuntime.CLEANER.register(this, Destroy(handle)) else null
+
+    private class Destroy(val handle: Pointer) : Runnable {
+        override fun run() = lib.namespace_MyIterator_destroy(handle)
+    }
+
+    /** Releases the Rust object if this owns it. It must not be used afterwards. */
+    override fun close() {
+        cleanable?.clean()
+    }
+
+    private var peeked: UByte? = null
+    private var hasPeeked = false
+
+    override fun hasNext(): Boolean {
+        if (!hasPeeked) {
+            peeked = nextOrNull()
+            hasPeeked = true
+        }
+        return peeked != null
+    }
+
+    override fun next(): UByte {
+        if (!hasNext()) {
+            throw NoSuchElementException()
+        }
+        hasPeeked = false
+        return peeked!!
+    }
+
+    private fun nextOrNull(): UByte? {
+        val result = lib.namespace_MyIterator_next(handle)
+        Reference.reachabilityFence(this)
+        if (result.isOk == 0.toByte()) {
+            return null
+        }
+        return (result.union.readField("ok") as Byte).toUByte()
+    }
+}
@@ -0,0 +1,74 @@
+// generated by diplomat-tool
+@file:Suppress("unused", "UNUSED_PARAMETER", "UNUSED_VARIABLE", "FunctionName", "ClassName")
+
+package dev.diplomat.featuretests
+
+import com.sun.jna.Pointer
+import com.sun.jna.Structure
+import com.sun.jna.Union
+import java.lang.ref.Reference
+
+class MyString internal constructor(
+    internal val handle: Pointer,
+    owned: Boolean,
+    /** Objects this may borrow from, kept alive for as long as this is */
+    internal val edges: List<Any>,
+) : AutoCloseable {
+    private val cleanable = if (owned) DiplomatRuntime.CLEANER.register(this, Destroy(handle)) else null
+
+    private class Destroy(val handle: Pointer) : Runnable {
+        override fun run() = lib.MyString_destroy(handle)
+    }
+
+    /** Releases the Rust object if this owns it. It must not be used afterwards. */
+    override fun close() {
+        cleanable?.clean()
+    }
+
+    var str: String
+        get() {
+            val writeable = DiplomatWriteable()
+            lib.MyString_get_str(handle, writeable.handle)
+            Reference.reachabilityFence(this)
+            return writeable.finalizeString()
+        }
+        set(newStr) {
+            val keep = mutableListOf<Any>()
+            val newStrSlice = DiplomatRuntime.str8(newStr, keep)
+            lib.MyString_set_str(handle, newStrSlice.data, newStrSlice.len)
+            Reference.reachabilityFence(this)
+            Reference.reachabilityFence(keep)
+        }
+
+    companion object {
+        operator fun invoke(v: String): MyString {
+            val keep = mutableListOf<Any>()
+            val vSlice = DiplomatRuntime.str8(v, keep)
+            val result = lib.MyString_new(vSlice.data, vSlice.len)
+            Reference.reachabilityFence(keep)
+            return MyString(result!!, true, listOf())
+        }
+
+        fun unsafe(v: String): MyString {
+            val keep = mutableListOf<Any>()
+            val vSlice = DiplomatRuntime.str8(v, keep)
+            val result = lib.MyString_new_unsafe(vSlice.data, vSlice.len)
+            Reference.reachabilityFence(keep)
+            return MyString(result!!, true, listOf())
+        }
+
+        fun newOwned(v: String): MyString {
+            val vSlice = DiplomatRuntime.str8(v, null)
+            val result = lib.MyString_new_owned(vSlice.data, vSlice.len)
+            return MyString(result!!, true, listOf())
+        }
+
+        fun newFromFirst(v: List<String>): MyString {
+            val keep = mutableListOf<Any>()
+            val vSlice = DiplomatRuntime.strs8(v, keep)
+            val result = lib.MyString_new_from_first(vSlice.data, vSlice.len)
+            Reference.reachabilityFence(keep)
+            return MyString(result!!, true, listOf())
+        }
+    }
+}
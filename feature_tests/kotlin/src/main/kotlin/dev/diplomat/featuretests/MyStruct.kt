@@ -0,0 +1,61 @@
+// generated by diplomat-tool
+@file:Suppress("unused", "UNUSED_PARAMETER", "UNUSED_VARIABLE", "FunctionName", "ClassName")
+
+package dev.diplomat.featuretests
+
+import com.sun.jna.Pointer
+import com.sun.jna.Structure
+import com.sun.jna.Union
+import java.lang.ref.Reference
+
+data class MyStruct(
+    var a: UByte,
+    var b: Boolean,
+    var c: UByte,
+    var d: ULong,
+    var e: Int,
+    var f: Int,
+    var g: MyEnum,
+) {
+    internal fun toNative(keep: MutableList<Any>): MyStructNative.ByValue {
+        val native = MyStructNative.ByValue()
+        native.a = this.a.toByte()
+        native.b = (if (this.b) 1 else 0).toByte()
+        native.c = this.c.toByte()
+        native.d = this.d.toLong()
+        native.e = this.e
+        native.f = this.f
+        native.g = this.g.value
+        return native
+    }
+
+    fun intoA(): UByte {
+        val keep = mutableListOf<Any>()
+        val result = lib.MyStruct_into_a(toNative(keep))
+        Reference.reachabilityFence(keep)
+        return result.toUByte()
+    }
+
+    companion object {
+        internal fun fromNative(native: MyStructNative, edges: List<Any>): MyStruct = MyStruct(native.a.toUByte(), (native.b != 0.toByte()), native.c.toUByte(), native.d.toULong(), native.e, native.f, MyEnum.fromNative(native.g))
+
+        operator fun invoke(): MyStruct {
+            val result = lib.MyStruct_new()
+            return MyStruct.fromNative(result, listOf())
+        }
+    }
+}
+
+internal open class MyStructNative : Structure() {
+    @JvmField var a: Byte = 0
+    @JvmField var b: Byte = 0
+    @JvmField var c: Byte = 0
+    @JvmField var d: Long = 0
+    @JvmField var e: Int = 0
+    @JvmField var f: Int = 0
+    @JvmField var g: Int = 0
+
+    override fun getFieldOrder() = listOf("a", "b", "c", "d", "e", "f", "g")
+
+    class ByValue : MyStructNative(), Structure.ByValue
+}
@@ -0,0 +1,37 @@
+// generated by diplomat-tool
+@file:Suppress("unused", "UNUSED_PARAMETER", "UNUSED_VARIABLE", "FunctionName", "ClassName")
+
+package dev.diplomat.featuretests
+
+import com.sun.jna.Pointer
+import com.sun.jna.Structure
+import com.sun.jna.Union
+import java.lang.ref.Reference
+
+data class NestedBorrowedFields(
+    var fields: BorrowedFields,
+    var bounds: BorrowedFieldsWithBounds,
+    var bounds2: BorrowedFieldsWithBounds,
+) {
+    internal fun toNative(keep: MutableList<Any>): NestedBorrowedFieldsNative.ByValue {
+        val native = NestedBorrowedFieldsNative.ByValue()
+        native.fields = this.fields.toNative(keep)
+        native.bounds = this.bounds.toNative(keep)
+        native.bounds2 = this.bounds2.toNative(keep)
+        return native
+    }
+
+    companion object {
+        internal fun fromNative(native: NestedBorrowedFieldsNative, edges: List<Any>): NestedBorrowedFields = NestedBorrowedFields(BorrowedFields.fromNative(native.fields, edges), BorrowedFieldsWithBounds.fromNative(native.bounds, edges), BorrowedFieldsWithBounds.fromNative(native.bounds2, edges))
+    }
+}
+
+internal open class NestedBorrowedFieldsNative : Structure() {
+    @JvmField var fields: BorrowedFieldsNative = BorrowedFieldsNative()
+    @JvmField var bounds: BorrowedFieldsWithBoundsNative = BorrowedFieldsWithBoundsNative()
+    @JvmField var bounds2: BorrowedFieldsWithBoundsNative = BorrowedFieldsWithBoundsNative()
+
+    override fun getFieldOrder() = listOf("fields", "bounds", "bounds2")
+
+    class ByValue : NestedBorrowedFieldsNative(), Structure.ByValue
+}
@@ -0,0 +1,123 @@
+// generated by diplomat-tool
+@file:Suppress("unused", "UNUSED_PARAMETER", "UNUSED_VARIABLE", "FunctionName", "ClassName")
+
+package dev.diplomat.featuretests
+
+import com.sun.jna.Pointer
+import com.sun.jna.Structure
+import com.sun.jna.Union
+import java.lang.ref.Reference
+
+class One internal constructor(
+    internal val handle: Pointer,
+    owned: Boolean,
+    /** Objects this may borrow from, kept alive for as long as this is */
+    internal val edges: List<Any>,
+) : AutoCloseable {
+    private val cleanable = if (owned) DiplomatRuntime.CLEANER.register(this, Destroy(handle)) else null
+
+    private class Destroy(val handle: Pointer) : Runnable {
+        override fun run() = lib.One_destroy(handle)
+    }
+
+    /** Releases the Rust object if this owns it. It must not be used afterwards. */
+    override fun close() {
+        cleanable?.clean()
+    }
+
+    companion object {
+        fun transitivity(hold: One, nohold: One): One {
+            val result = lib.One_transitivity(hold.handle, nohold.handle)
+            Reference.reachabilityFence(hold)
+            Reference.reachabilityFence(nohold)
+            return One(result!!, true, listOfNotNull<Any>(hold, nohold))
+        }
+
+        fun cycle(hold: Two, nohold: One): One {
+            val result = lib.One_cycle(hold.handle, nohold.handle)
+            Reference.reachabilityFence(hold)
+            Reference.reachabilityFence(nohold)
+            return One(result!!, true, listOfNotNull<Any>(hold, nohold))
+        }
+
+        fun manyDependents(a: One, b: One, c: Two, d: Two, nohold: Two): One {
+            val result = lib.One_many_dependents(a.handle, b.handle, c.handle, d.handle, nohold.handle)
+            Reference.reachabilityFence(a)
+            Reference.reachabilityFence(b)
+            Reference.reachabilityFence(c)
+            Reference.reachabilityFence(d)
+            Reference.reachabilityFence(nohold)
+            return One(result!!, true, listOfNotNull<Any>(a, b, c, d, nohold))
+        }
+
+        fun returnOutlivesParam(hold: Two, nohold: One): One {
+            val result = lib.One_return_outlives_param(hold.handle, nohold.handle)
+            Reference.reachabilityFence(hold)
+            Reference.reachabilityFence(nohold)
+            return One(result!!, true, listOfNotNull<Any>(hold, nohold))
+        }
+
+        fun diamondTop(top: One, left: One, right: One, bottom: One): One {
+            val result = lib.One_diamond_top(top.handle, left.handle, right.handle, bottom.handle)
+            Reference.reachabilityFence(top)
+            Reference.reachabilityFence(left)
+            Reference.reachabilityFence(right)
+            Reference.reachabilityFence(bottom)
+            return One(result!!, true, listOfNotNull<Any>(top, left, right, bottom))
+        }
+
+        fun diamondLeft(top: One, left: One, right: One, bottom: One): One {
+            val result = lib.One_diamond_left(top.handle, left.handle, right.handle, bottom.handle)
+            Reference.reachabilityFence(top)
+            Reference.reachabilityFence(left)
+            Reference.reachabilityFence(right)
+            Reference.reachabilityFence(bottom)
+            return One(result!!, true, listOfNotNull<Any>(top, left, right, bottom))
+        }
+
+        fun diamondRight(top: One, left: One, right: One, bottom: One): One {
+            val result = lib.One_diamond_right(top.handle, left.handle, right.handle, bottom.handle)
+            Reference.reachabilityFence(top)
+            Reference.reachabilityFence(left)
+            Reference.reachabilityFence(right)
+            Reference.reachabilityFence(bottom)
+            return One(result!!, true, listOfNotNull<Any>(top, left, right, bottom))
+        }
+
+        fun diamondBottom(top: One, left: One, right: One, bottom: One): One {
+            val result = lib.One_diamond_bottom(top.handle, left.handle, right.handle, bottom.handle)
+            Reference.reachabilityFence(top)
+            Reference.reachabilityFence(left)
+            Reference.reachabilityFence(right)
+            Reference.reachabilityFence(bottom)
+            return One(result!!, true, listOfNotNull<Any>(top, left, right, bottom))
+        }
+
+        fun diamondAndNestedTypes(a: One, b: One, c: One, d: One, nohold: One): One {
+            val result = lib.One_diamond_and_nested_types(a.handle, b.handle, c.handle, d.handle, nohold.handle)
+            Reference.reachabilityFence(a)
+            Reference.reachabilityFence(b)
+            Reference.reachabilityFence(c)
+            Reference.reachabilityFence(d)
+            Reference.reachabilityFence(nohold)
+            return One(result!!, true, listOfNotNull<Any>(a, b, c, d, nohold))
+        }
+
+        fun implicitBounds(explicitHold: One, implicitHold: One, nohold: One): One {
+            val result = lib.One_implicit_bounds(explicitHold.handle, implicitHold.handle, nohold.handle)
+            Reference.reachabilityFence(explicitHold)
+            Reference.reachabilityFence(implicitHold)
+            Reference.reachabilityFence(nohold)
+            return One(result!!, true, listOfNotNull<Any>(explicitHold, implicitHold, nohold))
+        }
+
+        fun implicitBoundsDeep(explicit: One, implicit1: One, implicit2: One, nohold: One): One {
+            val result = lib.One_implicit_bounds_deep(explicit.handle, implicit1.handle, implicit2.handle, nohold.handle)
+            Reference.reachabilityFence(explicit)
+            Reference.reachabilityFence(implicit1)
+            Reference.reachabilityFence(implicit2)
+            Reference.reachabilityFence(nohold)
+            return One(result!!, true, listOfNotNull<Any>(explicit, implicit1, implicit2, nohold))
+        }
+    }
+}
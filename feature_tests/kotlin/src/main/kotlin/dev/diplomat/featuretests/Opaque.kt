@@ -0,0 +1,58 @@
+// generated by diplomat-tool
+@file:Suppress("unused", "UNUSED_PARAMETER", "UNUSED_VARIABLE", "FunctionName", "ClassName")
+
+package dev.diplomat.featuretests
+
+import com.sun.jna.Pointer
+import com.sun.jna.Structure
+import com.sun.jna.Union
+import java.lang.ref.Reference
+
+class Opaque internal constructor(
+    internal val handle: Pointer,
+    owned: Boolean,
+    /** Objects this may borrow from, kept alive for as long as this is */
+    internal val edges: List<Any>,
+) : AutoCloseable {
+    private val cleanable = if (owned) DiplomatRuntime.CLEANER.register(this, Destroy(handle)) else null
+
+    private class Destroy(val handle: Pointer) : Runnable {
+        override fun run() = lib.Opaque_destroy(handle)
+    }
+
+    /** Releases the Rust object if this owns it. It must not be used afterwards. */
+    override fun close() {
+        cleanable?.clean()
+    }
+
+    /**
+     * See the [Rust documentation for `something`](https://docs.rs/Something/latest/struct.Something.html#method.something) for more information.
+     *
+     * See the [Rust documentation for `something_else`](https://docs.rs/Something/latest/struct.Something.html#method.something_else) for more information.
+     *
+     * Additional information: [1](https://docs.rs/Something/latest/struct.Something.html#method.something_small), [2](https://docs.rs/SomethingElse/latest/struct.SomethingElse.html#method.something)
+     */
+    fun assertStruct(s: MyStruct) {
+        val keep = mutableListOf<Any>()
+        lib.Opaque_assert_struct(handle, s.toNative(keep))
+        Reference.reachabilityFence(this)
+        Reference.reachabilityFence(keep)
+    }
+
+    companion object {
+        operator fun invoke(): Opaque {
+            val result = lib.Opaque_new()
+            return Opaque(result!!, true, listOf())
+        }
+
+        fun returnsUsize(): ULong {
+            val result = lib.Opaque_returns_usize()
+            return result.toLong().toULong()
+        }
+
+        fun returnsImported(): ImportedStruct {
+            val result = lib.Opaque_returns_imported()
+            return ImportedStruct.fromNative(result, listOf())
+        }
+    }
+}
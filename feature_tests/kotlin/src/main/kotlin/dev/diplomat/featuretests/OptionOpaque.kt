@@ -0,0 +1,68 @@
+// generated by diplomat-tool
+@file:Suppress("unused", "UNUSED_PARAMETER", "UNUSED_VARIABLE", "FunctionName", "ClassName")
+
+package dev.diplomat.featuretests
+
+import com.sun.jna.Pointer
+import com.sun.jna.Structure
+import com.sun.jna.Union
+import java.lang.ref.Reference
+
+class OptionOpaque internal constructor(
+    internal val handle: Pointer,
+    owned: Boolean,
+    /** Objects this may borrow from, kept alive for as long as this is */
+    internal val edges: List<Any>,
+) : AutoCloseable {
+    private val cleanable = if (owned) DiplomatRuntime.CLEANER.register(this, Destroy(handle)) else null
+
+    private class Destroy(val handle: Pointer) : Runnable {
+        override fun run() = lib.OptionOpaque_destroy(handle)
+    }
+
+    /** Releases the Rust object if this owns it. It must not be used afterwards. */
+    override fun close() {
+        cleanable?.clean()
+    }
+
+    fun assertInteger(i: Int) {
+        lib.OptionOpaque_assert_integer(handle, i)
+        Reference.reachabilityFence(this)
+    }
+
+    companion object {
+        fun new(i: Int): OptionOpaque? {
+            val result = lib.OptionOpaque_new(i)
+            return result?.let { OptionOpaque(it, true, listOf()) }
+        }
+
+        fun newNone(): OptionOpaque? {
+            val result = lib.OptionOpaque_new_none()
+            return result?.let { OptionOpaque(it, true, listOf()) }
+        }
+
+        fun returns(): OptionStruct? {
+            val result = lib.OptionOpaque_returns()
+            if (result.isOk == 0.toByte()) {
+                return null
+            }
+            return OptionStruct.fromNative((result.union.readField("ok") as OptionStructNative), listOf())
+        }
+
+        fun newStruct(): OptionStruct {
+            val result = lib.OptionOpaque_new_struct()
+            return OptionStruct.fromNative(result, listOf())
+        }
+
+        fun newStructNones(): OptionStruct {
+            val result = lib.OptionOpaque_new_struct_nones()
+            return OptionStruct.fromNative(result, listOf())
+        }
+
+        fun optionOpaqueArgument(arg: OptionOpaque?): Boolean {
+            val result = lib.OptionOpaque_option_opaque_argument(arg?.handle)
+            Reference.reachabilityFence(arg)
+            return (result != 0.toByte())
+        }
+    }
+}
@@ -0,0 +1,40 @@
+// generated by diplomat-tool
+@file:Suppress("unused", "UNUSED_PARAMETER", "UNUSED_VARIABLE", "FunctionName", "ClassName")
+
+package dev.diplomat.featuretests
+
+import com.sun.jna.Pointer
+import com.sun.jna.Structure
+import com.sun.jna.Union
+import java.lang.ref.Reference
+
+data class OptionStruct(
+    var a: OptionOpaque?,
+    var b: OptionOpaqueChar?,
+    var c: UInt,
+    var d: OptionOpaque?,
+) {
+    internal fun toNative(keep: MutableList<Any>): OptionStructNative.ByValue {
+        val native = OptionStructNative.ByValue()
+        native.a = this.a?.handle
+        native.b = this.b?.handle
+        native.c = this.c.toInt()
+        native.d = this.d?.handle
+        return native
+    }
+
+    companion object {
+        internal fun fromNative(native: OptionStructNative, edges: List<Any>): OptionStruct = OptionStruct(native.a?.let { OptionOpaque(it, true, listOf()) }, native.b?.let { OptionOpaqueChar(it, true, listOf()) }, native.c.toUInt(), native.d?.let { OptionOpaque(it, true, listOf()) })
+    }
+}
+
+internal open class OptionStructNative : Structure() {
+    @JvmField var a: Pointer? = null
+    @JvmField var b: Pointer? = null
+    @JvmField var c: Int = 0
+    @JvmField var d: Pointer? = null
+
+    override fun getFieldOrder() = listOf("a", "b", "c", "d")
+
+    class ByValue : OptionStructNative(), Structure.ByValue
+}
@@ -0,0 +1,62 @@
+// generated by diplomat-tool
+@file:Suppress("unused", "UNUSED_PARAMETER", "UNUSED_VARIABLE", "FunctionName", "ClassName")
+
+package dev.diplomat.featuretests
+
+import com.sun.jna.Pointer
+import com.sun.jna.Structure
+import com.sun.jna.Union
+import java.lang.ref.Reference
+
+class PanicOpaque internal constructor(
+    internal val handle: Pointer,
+    owned: Boolean,
+    /** Objects this may borrow from, kept alive for as long as this is */
+    internal val edges: List<Any>,
+) : AutoCloseable {
+    private val cleanable = if (owned) DiplomatRuntime.CLEANER.register(this, Destroy(handle)) else null
+
+    private class Destroy(val handle: Pointer) : Runnable {
+        override fun run() = lib.PanicOpaque_destroy(handle)
+    }
+
+    /** Releases the Rust object if this owns it. It must not be used afterwards. */
+    override fun close() {
+        cleanable?.clean()
+    }
+
+    companion object {
+        /**
+         * Panics, which foreign code is told about after the call
+         *
+         * @throws DiplomatPanic if it panics
+         */
+        fun panics(message: String) {
+            val keep = mutableListOf<Any>()
+            val messageSlice = DiplomatRuntime.str8(message, keep)
+            lib.PanicOpaque_panics(messageSlice.data, messageSlice.len)
+            DiplomatRuntime.checkPanic()
+            Reference.reachabilityFence(keep)
+        }
+
+        /**
+         * Panics when `x` is zero, which is reported as `PanicError::Panicked`
+         *
+         * @throws DiplomatException holding a `PanicError` on failure
+         */
+        fun checkedDivide(x: UInt): UInt {
+            val result = lib.PanicOpaque_checked_divide(x.toInt())
+            if (result.isOk == 0.toByte()) {
+                throw DiplomatException(PanicError.fromNative((result.union.readField("err") as Int)))
+            }
+            return (result.union.readField("ok") as Int).toUInt()
+        }
+
+        /** @throws DiplomatPanic if it panics */
+        fun notPanicking(x: UInt): UInt {
+            val result = lib.PanicOpaque_not_panicking(x.toInt())
+            DiplomatRuntime.checkPanic()
+            return result.toUInt()
+        }
+    }
+}
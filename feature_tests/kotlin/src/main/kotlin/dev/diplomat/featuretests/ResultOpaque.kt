@@ -0,0 +1,105 @@
+// generated by diplomat-tool
+@file:Suppress("unused", "UNUSED_PARAMETER", "UNUSED_VARIABLE", "FunctionName", "ClassName")
+
+package dev.diplomat.featuretests
+
+import com.sun.jna.Pointer
+import com.sun.jna.Structure
+import com.sun.jna.Union
+import java.lang.ref.Reference
+
+class ResultOpaque internal constructor(
+    internal val handle: Pointer,
+    owned: Boolean,
+    /** Objects this may borrow from, kept alive for as long as this is */
+    internal val edges: List<Any>,
+) : AutoCloseable {
+    private val cleanable = if (owned) DiplomatRuntime.CLEANER.register(this, Destroy(handle)) else null
+
+    private class Destroy(val handle: Pointer) : Runnable {
+        override fun run() = lib.ResultOpaque_destroy(handle)
+    }
+
+    /** Releases the Rust object if this owns it. It must not be used afterwards. */
+    override fun close() {
+        cleanable?.clean()
+    }
+
+    fun assertInteger(i: Int) {
+        lib.ResultOpaque_assert_integer(handle, i)
+        Reference.reachabilityFence(this)
+    }
+
+    companion object {
+        /** @throws DiplomatException holding a `ErrorEnum` on failure */
+        operator fun invoke(i: Int): ResultOpaque {
+            val result = lib.ResultOpaque_new(i)
+            if (result.isOk == 0.toByte()) {
+                throw DiplomatException(ErrorEnum.fromNative((result.union.readField("err") as Int)))
+            }
+            return ResultOpaque((result.union.readField("ok") as Pointer?)!!, true, listOf())
+        }
+
+        /** @throws DiplomatException holding a `ErrorEnum` on failure */
+        fun failingFoo(): ResultOpaque {
+            val result = lib.ResultOpaque_new_failing_foo()
+            if (result.isOk == 0.toByte()) {
+                throw DiplomatException(ErrorEnum.fromNative((result.union.readField("err") as Int)))
+            }
+            return ResultOpaque((result.union.readField("ok") as Pointer?)!!, true, listOf())
+        }
+
+        /** @throws DiplomatException holding a `ErrorEnum` on failure */
+        fun failingBar(): ResultOpaque {
+            val result = lib.ResultOpaque_new_failing_bar()
+            if (result.isOk == 0.toByte()) {
+                throw DiplomatException(ErrorEnum.fromNative((result.union.readField("err") as Int)))
+            }
+            return ResultOpaque((result.union.readField("ok") as Pointer?)!!, true, listOf())
+        }
+
+        /** @throws DiplomatException on failure */
+        fun newFailingUnit(): ResultOpaque {
+            val result = lib.ResultOpaque_new_failing_unit()
+            if (result.isOk == 0.toByte()) {
+                throw DiplomatException(null)
+            }
+            return ResultOpaque((result.union.readField("ok") as Pointer?)!!, true, listOf())
+        }
+
+        /** @throws DiplomatException holding a `ErrorStruct` on failure */
+        fun failingStruct(i: Int): ResultOpaque {
+            val result = lib.ResultOpaque_new_failing_struct(i)
+            if (result.isOk == 0.toByte()) {
+                throw DiplomatException(ErrorStruct.fromNative((result.union.readField("err") as ErrorStructNative), listOf()))
+            }
+            return ResultOpaque((result.union.readField("ok") as Pointer?)!!, true, listOf())
+        }
+
+        /** @throws DiplomatException holding a `ResultOpaque` on failure */
+        fun newInErr(i: Int) {
+            val result = lib.ResultOpaque_new_in_err(i)
+            if (result.isOk == 0.toByte()) {
+                throw DiplomatException(ResultOpaque((result.union.readField("err") as Pointer?)!!, true, listOf()))
+            }
+        }
+
+        /** @throws DiplomatException on failure */
+        fun newInt(i: Int): Int {
+            val result = lib.ResultOpaque_new_int(i)
+            if (result.isOk == 0.toByte()) {
+                throw DiplomatException(null)
+            }
+            return (result.union.readField("ok") as Int)
+        }
+
+        /** @throws DiplomatException holding a `ResultOpaque` on failure */
+        fun newInEnumErr(i: Int): ErrorEnum {
+            val result = lib.ResultOpaque_new_in_enum_err(i)
+            if (result.isOk == 0.toByte()) {
+                throw DiplomatException(ResultOpaque((result.union.readField("err") as Pointer?)!!, true, listOf()))
+            }
+            return ErrorEnum.fromNative((result.union.readField("ok") as Int))
+        }
+    }
+}
@@ -0,0 +1,239 @@
+// generated by diplomat-tool
+@file:Suppress("unused")
+
+package dev.diplomat.featuretests
+
+import com.sun.jna.IntegerType
+import com.sun.jna.Memory
+import com.sun.jna.Native
+import com.sun.jna.Pointer
+import com.sun.jna.Structure
+import java.lang.ref.Cleaner
+import java.lang.ref.Reference
+
+/** Thrown when a Rust method returns an error, which is available as [error]. */
+class DiplomatException(val error: Any?) : Exception(error?.toString())
+
+/** Thrown when a Rust method that catches panics panicked, with the panic's message */
+class DiplomatPanic(message: String) : RuntimeException(message)
+
+/** A C `size_t` */
+class SizeT(value: Long = 0) : IntegerType(Native.SIZE_T_SIZE, value, true)
+
+/** A C `ssize_t` */
+class SSizeT(value: Long = 0) : IntegerType(Native.SIZE_T_SIZE, value, false)
+
+/** A slice as laid out by Rust: a pointer and a length */
+internal open class DiplomatSlice : Structure() {
+    @JvmField var data: Pointer? = null
+    @JvmField var len: SizeT = SizeT()
+
+    override fun getFieldOrder() = listOf("data", "len")
+
+    class ByValue : DiplomatSlice(), Structure.ByValue
+}
+
+/** A buffer in Rust's memory that methods returning strings write into */
+internal class DiplomatWriteable {
+    val handle: Pointer = lib.diplomat_buffer_writeable_create(SizeT(0))!!
+
+    init {
+        val handle = handle
+        DiplomatRuntime.CLEANER.register(this) { lib.diplomat_buffer_writeable_destroy(handle) }
+    }
+
+    /** Decodes what was written to the buffer */
+    fun finalizeString(): String {
+        try {
+            val len = lib.diplomat_buffer_writeable_len(handle).toLong()
+            if (len == 0L) {
+                return ""
+            }
+            val bytes = lib.diplomat_buffer_writeable_get_bytes(handle)!!.getByteArray(0, len.toInt())
+            return String(bytes, Charsets.UTF_8)
+        } finally {
+            Reference.reachabilityFence(this)
+        }
+    }
+}
+
+internal object DiplomatRuntime {
+    /** Destroys Rust objects once their Kotlin wrappers are unreachable */
+    val CLEANER: Cleaner = Cleaner.create()
+
+    /** Throws a [DiplomatPanic] if the last call on this thread panicked */
+    fun checkPanic() {
+        if (lib.diplomat_panicked() != 0.toByte()) {
+            val writeable = DiplomatWriteable()
+            lib.diplomat_take_panic(writeable.handle)
+            throw DiplomatPanic(writeable.finalizeString())
+        }
+    }
+
+    /**
+     * Allocates memory for a slice.
+     *
+     * Borrowed slices are allocated by the JVM and added to `keep`, which the
+     * caller holds on to for as long as Rust may use them. If `keep` is null,
+     * the slice is owned by Rust, so it is allocated with Rust's allocator.
+     */
+    private fun alloc(size: Long, align: Int, keep: MutableList<Any>?): Pointer {
+        if (size == 0L) {
+            // Rust expects a dangling, aligned pointer for empty slices
+            return Pointer.createConstant(align.toLong())
+        }
+        if (keep == null) {
+            return lib.diplomat_alloc(SizeT(size), SizeT(align.toLong()))!!
+        }
+        val memory = Memory(size)
+        keep.add(memory)
+        return memory
+    }
+
+    private fun slice(data: Pointer, len: Int): DiplomatSlice {
+        val slice = DiplomatSlice()
+        slice.data = data
+        slice.len = SizeT(len.toLong())
+        return slice
+    }
+
+    fun str8(value: String, keep: MutableList<Any>?) = bytes(value.toByteArray(Charsets.UTF_8), keep)
+
+    fun str16(value: String, keep: MutableList<Any>?) = shorts(ShortArray(value.length) { value[it].code.toShort() }, keep)
+
+    fun bytes(value: ByteArray, keep: MutableList<Any>?): DiplomatSlice {
+        val data = alloc(value.size.toLong(), 1, keep)
+        if (value.isNotEmpty()) data.write(0, value, 0, value.size)
+        return slice(data, value.size)
+    }
+
+    fun booleans(value: BooleanArray, keep: MutableList<Any>?) = bytes(ByteArray(value.size) { if (value[it]) 1 else 0 }, keep)
+
+    fun shorts(value: ShortArray, keep: MutableList<Any>?): DiplomatSlice {
+        val data = alloc(2L * value.size, 2, keep)
+        if (value.isNotEmpty()) data.write(0, value, 0, value.size)
+        return slice(data, value.size)
+    }
+
+    fun ints(value: IntArray, keep: MutableList<Any>?): DiplomatSlice {
+        val data = alloc(4L * value.size, 4, keep)
+        if (value.isNotEmpty()) data.write(0, value, 0, value.size)
+        return slice(data, value.size)
+    }
+
+    fun longs(value: LongArray, keep: MutableList<Any>?): DiplomatSlice {
+        val data = alloc(8L * value.size, 8, keep)
+        if (value.isNotEmpty()) data.write(0, value, 0, value.size)
+        return slice(data, value.size)
+    }
+
+    fun sizes(value: LongArray, keep: MutableList<Any>?) =
+        if (Native.SIZE_T_SIZE == 8) longs(value, keep) else ints(IntArray(value.size) { value[it].toInt() }, keep)
+
+    fun floats(value: FloatArray, keep: MutableList<Any>?): DiplomatSlice {
+        val data = alloc(4L * value.size, 4, keep)
+        if (value.isNotEmpty()) data.write(0, value, 0, value.size)
+        return slice(data, value.size)
+    }
+
+    fun doubles(value: DoubleArray, keep: MutableList<Any>?): DiplomatSlice {
+        val data = alloc(8L * value.size, 8, keep)
+        if (value.isNotEmpty()) data.write(0, value, 0, value.size)
+        return slice(data, value.size)
+    }
+
+    fun strs8(values: List<String>, keep: MutableList<Any>) = strs(values.map { str8(it, keep) }, keep)
+
+    fun strs16(values: List<String>, keep: MutableList<Any>) = strs(values.map { str16(it, keep) }, keep)
+
+    private fun strs(slices: List<DiplomatSlice>, keep: MutableList<Any>): DiplomatSlice {
+        if (slices.isEmpty()) {
+            return slice(Pointer.createConstant(Native.POINTER_SIZE.toLong()), 0)
+        }
+        @Suppress("UNCHECKED_CAST")
+        val array = DiplomatSlice().toArray(slices.size) as Array<DiplomatSlice>
+        for ((i, slice) in slices.withIndex()) {
+            array[i].data = slice.data
+            array[i].len = slice.len
+            array[i].write()
+        }
+        keep.add(array)
+        return slice(array[0].pointer, slices.size)
+    }
+
+    /** Copies what Rust wrote into a mutable slice back into the array it was created from */
+    fun copyBack(slice: DiplomatSlice, array: ByteArray) {
+        if (array.isNotEmpty()) slice.data!!.read(0, array, 0, array.size)
+    }
+
+    fun copyBack(slice: DiplomatSlice, array: BooleanArray) {
+        val bytes = ByteArray(array.size)
+        copyBack(slice, bytes)
+        for (i in array.indices) array[i] = bytes[i] != 0.toByte()
+    }
+
+    fun copyBack(slice: DiplomatSlice, array: ShortArray) {
+        if (array.isNotEmpty()) slice.data!!.read(0, array, 0, array.size)
+    }
+
+    fun copyBack(slice: DiplomatSlice, array: IntArray) {
+        if (array.isNotEmpty()) slice.data!!.read(0, array, 0, array.size)
+    }
+
+    fun copyBack(slice: DiplomatSlice, array: LongArray) {
+        if (Native.SIZE_T_SIZE == 8 || array.isEmpty()) {
+            if (array.isNotEmpty()) slice.data!!.read(0, array, 0, array.size)
+        } else {
+            val ints = slice.data!!.getIntArray(0, array.size)
+            for (i in array.indices) array[i] = ints[i].toLong()
+        }
+    }
+
+    fun copyBack(slice: DiplomatSlice, array: FloatArray) {
+        if (array.isNotEmpty()) slice.data!!.read(0, array, 0, array.size)
+    }
+
+    fun copyBack(slice: DiplomatSlice, array: DoubleArray) {
+        if (array.isNotEmpty()) slice.data!!.read(0, array, 0, array.size)
+    }
+
+    /** Reads a slice returned by Rust, freeing it if it is owned */
+    private inline fun <T> read(slice: DiplomatSlice, owned: Boolean, elementSize: Int, empty: T, read: (Pointer, Int) -> T): T {
+        val len = slice.len.toInt()
+        val data = slice.data
+        if (len == 0 || data == null) {
+            return empty
+        }
+        try {
+            return read(data, len)
+        } finally {
+            if (owned) {
+                lib.diplomat_free(data, SizeT(len.toLong() * elementSize), SizeT(elementSize.toLong()))
+            }
+        }
+    }
+
+    fun readStr8(slice: DiplomatSlice, owned: Boolean) = String(readBytes(slice, owned), Charsets.UTF_8)
+
+    fun readStr16(slice: DiplomatSlice, owned: Boolean) = String(CharArray(slice.len.toInt()).also { chars ->
+        val shorts = readShorts(slice, owned)
+        for (i in shorts.indices) chars[i] = shorts[i].toInt().toChar()
+    })
+
+    fun readBytes(slice: DiplomatSlice, owned: Boolean) = read(slice, owned, 1, ByteArray(0)) { data, len -> data.getByteArray(0, len) }
+
+    fun readBooleans(slice: DiplomatSlice, owned: Boolean) = readBytes(slice, owned).let { bytes -> BooleanArray(bytes.size) { bytes[it] != 0.toByte() } }
+
+    fun readShorts(slice: DiplomatSlice, owned: Boolean) = read(slice, owned, 2, ShortArray(0)) { data, len -> data.getShortArray(0, len) }
+
+    fun readInts(slice: DiplomatSlice, owned: Boolean) = read(slice, owned, 4, IntArray(0)) { data, len -> data.getIntArray(0, len) }
+
+    fun readLongs(slice: DiplomatSlice, owned: Boolean) = read(slice, owned, 8, LongArray(0)) { data, len -> data.getLongArray(0, len) }
+
+    fun readSizes(slice: DiplomatSlice, owned: Boolean) =
+        if (Native.SIZE_T_SIZE == 8) readLongs(slice, owned) else readInts(slice, owned).let { ints -> LongArray(ints.size) { ints[it].toLong() } }
+
+    fun readFloats(slice: DiplomatSlice, owned: Boolean) = read(slice, owned, 4, FloatArray(0)) { data, len -> data.getFloatArray(0, len) }
+
+    fun readDoubles(slice: DiplomatSlice, owned: Boolean) = read(slice, owned, 8, DoubleArray(0)) { data, len -> data.getDoubleArray(0, len) }
+}
@@ -0,0 +1,93 @@
+// generated by diplomat-tool
+@file:Suppress("unused", "UNUSED_PARAMETER", "UNUSED_VARIABLE", "FunctionName", "ClassName")
+
+package dev.diplomat.featuretests
+
+import com.sun.jna.Pointer
+import com.sun.jna.Structure
+import com.sun.jna.Union
+import java.lang.ref.Reference
+
+sealed class Shape {
+    data class Circle(
+        val radius: Double,
+    ) : Shape()
+
+    data class Rect(
+        val width: Double,
+        val height: Double,
+    ) : Shape()
+
+    object Empty : Shape()
+
+    internal fun toNative(keep: MutableList<Any>): ShapeNative.ByValue {
+        val native = ShapeNative.ByValue()
+        when (this) {
+            is Circle -> {
+                native.tag = 0
+                val payload = ShapeCircleNative()
+                payload.radius = this.radius
+                native.payload.setType("circle")
+                native.payload.circle = payload
+            }
+            is Rect -> {
+                native.tag = 1
+                val payload = ShapeRectNative()
+                payload.width = this.width
+                payload.height = this.height
+                native.payload.setType("rect")
+                native.payload.rect = payload
+            }
+            is Empty -> {
+                native.tag = 2
+            }
+        }
+        return native
+    }
+
+    companion object {
+        internal fun fromNative(native: ShapeNative, edges: List<Any>): Shape = when (native.tag) {
+            0 -> (native.payload.readField("circle") as ShapeCircleNative).let { payload ->
+                Circle(payload.radius)
+            }
+            1 -> (native.payload.readField("rect") as ShapeRectNative).let { payload ->
+                Rect(payload.width, payload.height)
+            }
+            2 -> Empty
+            else -> throw IllegalStateException("Unknown Shape tag ${native.tag}")
+        }
+    }
+}
+
+internal open class ShapeNative : Structure() {
+    @JvmField var tag: Int = 0
+    @JvmField var payload: ShapePayloadNative = ShapePayloadNative()
+
+    override fun getFieldOrder() = listOf("tag", "payload")
+
+    class ByValue : ShapeNative(), Structure.ByValue
+}
+
+internal class ShapePayloadNative : Union() {
+    @JvmField var circle: ShapeCircleNative = ShapeCircleNative()
+    @JvmField var rect: ShapeRectNative = ShapeRectNative()
+
+    override fun getFieldOrder() = listOf("circle", "rect")
+}
+
+internal open class ShapeCircleNative : Structure() {
+    @JvmField var radius: Double = 0.0
+
+    override fun getFieldOrder() = listOf("radius")
+
+    class ByValue : ShapeCircleNative(), Structure.ByValue
+}
+
+internal open class ShapeRectNative : Structure() {
+    @JvmField var width: Double = 0.0
+    @JvmField var height: Double = 0.0
+
+    override fun getFieldOrder() = listOf("width", "height")
+
+    class ByValue : ShapeRectNative(), Structure.ByValue
+}
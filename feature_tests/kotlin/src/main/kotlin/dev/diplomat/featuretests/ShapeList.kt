@@ -0,0 +1,53 @@
+// generated by diplomat-tool
+@file:Suppress("unused", "UNUSED_PARAMETER", "UNUSED_VARIABLE", "FunctionName", "ClassName")
+
+package dev.diplomat.featuretests
+
+import com.sun.jna.Pointer
+import com.sun.jna.Structure
+import com.sun.jna.Union
+import java.lang.ref.Reference
+
+class ShapeList internal constructor(
+    internal val handle: Pointer,
+    owned: Boolean,
+    /** Objects this may borrow from, kept alive for as long as this is */
+    internal val edges: List<Any>,
+) : AutoCloseable {
+    private val cleanable = if (owned) DiplomatRuntime.CLEANER.register(this, Destroy(handle)) else null
+
+    private class Destroy(val handle: Pointer) : Runnable {
+        override fun run() = lib.ShapeList_destroy(handle)
+    }
+
+    /** Releases the Rust object if this owns it. It must not be used afterwards. */
+    override fun close() {
+        cleanable?.clean()
+    }
+
+    fun push(shape: Shape) {
+        val keep = mutableListOf<Any>()
+        lib.ShapeList_push(handle, shape.toNative(keep))
+        Reference.reachabilityFence(this)
+        Reference.reachabilityFence(keep)
+    }
+
+    fun last(): Shape {
+        val result = lib.ShapeList_last(handle)
+        Reference.reachabilityFence(this)
+        return Shape.fromNative(result, listOf())
+    }
+
+    fun totalArea(): Double {
+        val result = lib.ShapeList_total_area(handle)
+        Reference.reachabilityFence(this)
+        return result
+    }
+
+    companion object {
+        operator fun invoke(): ShapeList {
+            val result = lib.ShapeList_new()
+            return ShapeList(result!!, true, listOf())
+        }
+    }
+}
@@ -0,0 +1,40 @@
+// generated by diplomat-tool
+@file:Suppress("unused", "UNUSED_PARAMETER", "UNUSED_VARIABLE", "FunctionName", "ClassName")
+
+package dev.diplomat.featuretests
+
+import com.sun.jna.Pointer
+import com.sun.jna.Structure
+import com.sun.jna.Union
+import java.lang.ref.Reference
+
+class Unnamespaced internal constructor(
+    internal val handle: Pointer,
+    owned: Boolean,
+    /** Objects this may borrow from, kept alive for as long as this is */
+    internal val edges: List<Any>,
+) : AutoCloseable {
+    private val cleanable = if (owned) DiplomatRuntime.CLEANER.register(this, Destroy(handle)) else null
+
+    private class Destroy(val handle: Pointer) : Runnable {
+        override fun run() = lib.namespace_Unnamespaced_destroy(handle)
+    }
+
+    /** Releases the Rust object if this owns it. It must not be used afterwards. */
+    override fun close() {
+        cleanable?.clean()
+    }
+
+    fun useNamespaced(n: AttrOpaque1) {
+        lib.namespace_Unnamespaced_use_namespaced(handle, n.handle)
+        Reference.reachabilityFence(this)
+        Reference.reachabilityFence(n)
+    }
+
+    companion object {
+        fun make(e: AttrEnum): Unnamespaced {
+            val result = lib.namespace_Unnamespaced_make(e.value)
+            return Unnamespaced(result!!, true, listOf())
+        }
+    }
+}
@@ -0,0 +1,24 @@
+package dev.diplomat.featuretests
+
+import kotlin.test.Test
+import kotlin.test.assertEquals
+import kotlin.test.assertFailsWith
+
+class PanicsTest {
+    @Test
+    fun panicIsThrown() {
+        val panic = assertFailsWith<DiplomatPanic> { PanicOpaque.panics("oops") }
+        assertEquals("oops", panic.message)
+        // The panic was taken, so the next call doesn't throw it again
+        assertEquals(2u, PanicOpaque.notPanicking(1u))
+    }
+
+    @Test
+    fun panicInResultIsItsError() {
+        val panicked = assertFailsWith<DiplomatException> { PanicOpaque.checkedDivide(0u) }
+        assertEquals(PanicError.PANICKED, panicked.error)
+        val tooLarge = assertFailsWith<DiplomatException> { PanicOpaque.checkedDivide(200u) }
+        assertEquals(PanicError.TOO_LARGE, tooLarge.error)
+        assertEquals(20u, PanicOpaque.checkedDivide(5u))
+    }
+}
@@ -0,0 +1,7 @@
+"""Python bindings generated by diplomat-tool."""
+
+from ._runtime import DiplomatError, DiplomatPanic, load_library
+from ._lib import *
+from ._lib import __all__ as _all
+
+__all__ = ["DiplomatError", "DiplomatPanic", "load_library", *_all]
@@ -0,0 +1,1679 @@
+# generated by diplomat-tool
+
+from __future__ import annotations
+
+import ctypes
+import enum
+import typing
+
+from . import _runtime
+
+__all__ = [
+    "ImportedStruct",
+    "BorrowedFields",
+    "BorrowedFieldsReturning",
+    "BorrowedFieldsWithBounds",
+    "NestedBorrowedFields",
+    "ErrorStruct",
+    "MyStruct",
+    "OptionStruct",
+    "AttrOpaque1",
+    "AttrOpaque2",
+    "Comparable",
+    "MyIterable",
+    "MyIterator",
+    "Unnamespaced",
+    "ConstHolder",
+    "Bar",
+    "Foo",
+    "One",
+    "Two",
+    "OptionOpaque",
+    "OptionOpaqueChar",
+    "PanicOpaque",
+    "ResultOpaque",
+    "RefList",
+    "RefListParameter",
+    "Float64Vec",
+    "MyString",
+    "Opaque",
+    "ShapeList",
+    "AttrEnum",
+    "UnimportedEnum",
+    "PanicError",
+    "ErrorEnum",
+    "ContiguousEnum",
+    "MyEnum",
+    "Shape",
+    "ShapeCircle",
+    "ShapeRect",
+    "ShapeEmpty",
+    "ENABLED",
+    "GREETING",
+    "MAX_LEN",
+    "RATIO",
+]
+
+
+class _BorrowedFieldsFfi(ctypes.Structure):
+    pass
+
+
+class _BorrowedFieldsReturningFfi(ctypes.Structure):
+    pass
+
+
+class _BorrowedFieldsWithBoundsFfi(ctypes.Structure):
+    pass
+
+
+class _ErrorStructFfi(ctypes.Structure):
+    pass
+
+
+class _ImportedStructFfi(ctypes.Structure):
+    pass
+
+
+class _MyStructFfi(ctypes.Structure):
+    pass
+
+
+class _NestedBorrowedFieldsFfi(ctypes.Structure):
+    pass
+
+
+class _OptionStructFfi(ctypes.Structure):
+    pass
+
+
+class _ShapeCircleFfi(ctypes.Structure):
+    pass
+
+
+class _ShapeRectFfi(ctypes.Structure):
+    pass
+
+
+class _ShapeFfi(ctypes.Structure):
+    pass
+
+
+class _diplomat_result_ErrorEnum_box_ResultOpaque(ctypes.Structure):
+    pass
+
+
+class _diplomat_result_OptionStruct_void(ctypes.Structure):
+    pass
+
+
+class _diplomat_result_box_ResultOpaque_ErrorEnum(ctypes.Structure):
+    pass
+
+
+class _diplomat_result_box_ResultOpaque_ErrorStruct(ctypes.Structure):
+    pass
+
+
+class _diplomat_result_box_ResultOpaque_void(ctypes.Structure):
+    pass
+
+
+class _diplomat_result_double_void(ctypes.Structure):
+    pass
+
+
+class _diplomat_result_int32_t_void(ctypes.Structure):
+    pass
+
+
+class _diplomat_result_uint32_t_PanicError(ctypes.Structure):
+    pass
+
+
+class _diplomat_result_uint8_t_void(ctypes.Structure):
+    pass
+
+
+class _diplomat_result_void_box_ResultOpaque(ctypes.Structure):
+    pass
+
+# By-value fields need complete types, so fields are assigned in dependency order
+_BorrowedFieldsFfi._fields_ = [
+    ("a", _runtime.slice_type(ctypes.c_uint16)),
+    ("b", _runtime.slice_type(ctypes.c_uint8)),
+    ("c", _runtime.slice_type(ctypes.c_uint8)),
+]
+_BorrowedFieldsReturningFfi._fields_ = [
+    ("bytes", _runtime.slice_type(ctypes.c_uint8)),
+]
+_BorrowedFieldsWithBoundsFfi._fields_ = [
+    ("field_a", _runtime.slice_type(ctypes.c_uint16)),
+    ("field_b", _runtime.slice_type(ctypes.c_uint8)),
+    ("field_c", _runtime.slice_type(ctypes.c_uint8)),
+]
+_ErrorStructFfi._fields_ = [
+    ("i", ctypes.c_int32),
+    ("j", ctypes.c_int32),
+]
+_ImportedStructFfi._fields_ = [
+    ("foo", ctypes.c_int32),
+    ("count", ctypes.c_uint8),
+]
+_MyStructFfi._fields_ = [
+    ("a", ctypes.c_uint8),
+    ("b", ctypes.c_bool),
+    ("c", ctypes.c_uint8),
+    ("d", ctypes.c_uint64),
+    ("e", ctypes.c_int32),
+    ("f", ctypes.c_uint32),
+    ("g", ctypes.c_int32),
+]
+_NestedBorrowedFieldsFfi._fields_ = [
+    ("fields", _BorrowedFieldsFfi),
+    ("bounds", _BorrowedFieldsWithBoundsFfi),
+    ("bounds2", _BorrowedFieldsWithBoundsFfi),
+]
+_OptionStructFfi._fields_ = [
+    ("a", ctypes.c_void_p),
+    ("b", ctypes.c_void_p),
+    ("c", ctypes.c_uint32),
+    ("d", ctypes.c_void_p),
+]
+_ShapeCircleFfi._fields_ = [
+    ("radius", ctypes.c_double),
+]
+_ShapeRectFfi._fields_ = [
+    ("width", ctypes.c_double),
+    ("height", ctypes.c_double),
+]
+_ShapePayloadFfi = _runtime.union_type([_ShapeCircleFfi, _ShapeRectFfi])
+_ShapeFfi._fields_ = [
+    ("tag", ctypes.c_int32),
+    ("payload", _ShapePayloadFfi),
+]
+_diplomat_result_ErrorEnum_box_ResultOpaqueUnion = _runtime.union_type([ctypes.c_int32, ctypes.c_void_p])
+_diplomat_result_ErrorEnum_box_ResultOpaque._fields_ = [
+    ("union", _diplomat_result_ErrorEnum_box_ResultOpaqueUnion),
+    ("is_ok", ctypes.c_bool),
+]
+_diplomat_result_OptionStruct_voidUnion = _runtime.union_type([_OptionStructFfi])
+_diplomat_result_OptionStruct_void._fields_ = [
+    ("union", _diplomat_result_OptionStruct_voidUnion),
+    ("is_ok", ctypes.c_bool),
+]
+_diplomat_result_box_ResultOpaque_ErrorEnumUnion = _runtime.union_type([ctypes.c_void_p, ctypes.c_int32])
+_diplomat_result_box_ResultOpaque_ErrorEnum._fields_ = [
+    ("union", _diplomat_result_box_ResultOpaque_ErrorEnumUnion),
+    ("is_ok", ctypes.c_bool),
+]
+_diplomat_result_box_ResultOpaque_ErrorStructUnion = _runtime.union_type([ctypes.c_void_p, _ErrorStructFfi])
+_diplomat_result_box_ResultOpaque_ErrorStruct._fields_ = [
+    ("union", _diplomat_result_box_ResultOpaque_ErrorStructUnion),
+    ("is_ok", ctypes.c_bool),
+]
+_diplomat_result_box_ResultOpaque_voidUnion = _runtime.union_type([ctypes.c_void_p])
+_diplomat_result_box_ResultOpaque_void._fields_ = [
+    ("union", _diplomat_result_box_ResultOpaque_voidUnion),
+    ("is_ok", ctypes.c_bool),
+]
+_diplomat_result_double_voidUnion = _runtime.union_type([ctypes.c_double])
+_diplomat_result_double_void._fields_ = [
+    ("union", _diplomat_result_double_voidUnion),
+    ("is_ok", ctypes.c_bool),
+]
+_diplomat_result_int32_t_voidUnion = _runtime.union_type([ctypes.c_int32])
+_diplomat_result_int32_t_void._fields_ = [
+    ("union", _diplomat_result_int32_t_voidUnion),
+    ("is_ok", ctypes.c_bool),
+]
+_diplomat_result_uint32_t_PanicErrorUnion = _runtime.union_type([ctypes.c_uint32, ctypes.c_int32])
+_diplomat_result_uint32_t_PanicError._fields_ = [
+    ("union", _diplomat_result_uint32_t_PanicErrorUnion),
+    ("is_ok", ctypes.c_bool),
+]
+_diplomat_result_uint8_t_voidUnion = _runtime.union_type([ctypes.c_uint8])
+_diplomat_result_uint8_t_void._fields_ = [
+    ("union", _diplomat_result_uint8_t_voidUnion),
+    ("is_ok", ctypes.c_bool),
+]
+_diplomat_result_void_box_ResultOpaqueUnion = _runtime.union_type([ctypes.c_void_p])
+_diplomat_result_void_box_ResultOpaque._fields_ = [
+    ("union", _diplomat_result_void_box_ResultOpaqueUnion),
+    ("is_ok", ctypes.c_bool),
+]
+
+
+class ImportedStruct:
+
+    def __init__(self, foo: UnimportedEnum, count: int):
+        self.foo = foo
+        self.count = count
+
+    @classmethod
+    def _from_ffi(cls, ffi, edges):
+        self = object.__new__(cls)
+        self.foo = UnimportedEnum(ffi.foo)
+        self.count = ffi.count
+        return self
+
+    def _to_ffi(self, keep):
+        return _ImportedStructFfi(
+            int(self.foo),
+            self.count,
+        )
+
+    def __eq__(self, other):
+        if not isinstance(other, ImportedStruct):
+            return NotImplemented
+        return self.foo == other.foo and self.count == other.count
+
+    def __repr__(self):
+        return f"ImportedStruct(foo={self.foo!r}, count={self.count!r})"
+
+
+class BorrowedFields:
+
+    def __init__(self, a: str, b: str, c: str):
+        self.a = a
+        self.b = b
+        self.c = c
+
+    @classmethod
+    def _from_ffi(cls, ffi, edges):
+        self = object.__new__(cls)
+        self.a = _runtime.read_str16(ffi.a, False)
+        self.b = _runtime.read_str8(ffi.b, False)
+        self.c = _runtime.read_str8(ffi.c, False)
+        return self
+
+    def _to_ffi(self, keep):
+        return _BorrowedFieldsFfi(
+            _runtime.slice_type(ctypes.c_uint16)(*_runtime.str16(self.a, keep)),
+            _runtime.slice_type(ctypes.c_uint8)(*_runtime.str8(self.b, keep)),
+            _runtime.slice_type(ctypes.c_uint8)(*_runtime.str8(self.c, keep)),
+        )
+
+    def __eq__(self, other):
+        if not isinstance(other, BorrowedFields):
+            return NotImplemented
+        return self.a == other.a and self.b == other.b and self.c == other.c
+
+    def __repr__(self):
+        return f"BorrowedFields(a={self.a!r}, b={self.b!r}, c={self.c!r})"
+
+
+class BorrowedFieldsReturning:
+
+    def __init__(self, bytes: str):
+        self.bytes = bytes
+
+    @classmethod
+    def _from_ffi(cls, ffi, edges):
+        self = object.__new__(cls)
+        self.bytes = _runtime.read_str8(ffi.bytes, False)
+        return self
+
+    def _to_ffi(self, keep):
+        return _BorrowedFieldsReturningFfi(
+            _runtime.slice_type(ctypes.c_uint8)(*_runtime.str8(self.bytes, keep)),
+        )
+
+    def __eq__(self, other):
+        if not isinstance(other, BorrowedFieldsReturning):
+            return NotImplemented
+        return self.bytes == other.bytes
+
+    def __repr__(self):
+        return f"BorrowedFieldsReturning(bytes={self.bytes!r})"
+
+
+class BorrowedFieldsWithBounds:
+
+    def __init__(self, field_a: str, field_b: str, field_c: str):
+        self.field_a = field_a
+        self.field_b = field_b
+        self.field_c = field_c
+
+    @classmethod
+    def _from_ffi(cls, ffi, edges):
+        self = object.__new__(cls)
+        self.field_a = _runtime.read_str16(ffi.field_a, False)
+        self.field_b = _runtime.read_str8(ffi.field_b, False)
+        self.field_c = _runtime.read_str8(ffi.field_c, False)
+        return self
+
+    def _to_ffi(self, keep):
+        return _BorrowedFieldsWithBoundsFfi(
+            _runtime.slice_type(ctypes.c_uint16)(*_runtime.str16(self.field_a, keep)),
+            _runtime.slice_type(ctypes.c_uint8)(*_runtime.str8(self.field_b, keep)),
+            _runtime.slice_type(ctypes.c_uint8)(*_runtime.str8(self.field_c, keep)),
+        )
+
+    def __eq__(self, other):
+        if not isinstance(other, BorrowedFieldsWithBounds):
+            return NotImplemented
+        return self.field_a == other.field_a and self.field_b == other.field_b and self.field_c == other.field_c
+
+    def __repr__(self):
+        return f"BorrowedFieldsWithBounds(field_a={self.field_a!r}, field_b={self.field_b!r}, field_c={self.field_c!r})"
+
+
+class NestedBorrowedFields:
+
+    def __init__(self, fields: BorrowedFields, bounds: BorrowedFieldsWithBounds, bounds2: BorrowedFieldsWithBounds):
+        self.fields = fields
+        self.bounds = bounds
+        self.bounds2 = bounds2
+
+    @classmethod
+    def _from_ffi(cls, ffi, edges):
+        self = object.__new__(cls)
+        self.fields = BorrowedFields._from_ffi(ffi.fields, edges)
+        self.bounds = BorrowedFieldsWithBounds._from_ffi(ffi.bounds, edges)
+        self.bounds2 = BorrowedFieldsWithBounds._from_ffi(ffi.bounds2, edges)
+        return self
+
+    def _to_ffi(self, keep):
+        return _NestedBorrowedFieldsFfi(
+            self.fields._to_ffi(keep),
+            self.bounds._to_ffi(keep),
+            self.bounds2._to_ffi(keep),
+        )
+
+    def __eq__(self, other):
+        if not isinstance(other, NestedBorrowedFields):
+            return NotImplemented
+        return self.fields == other.fields and self.bounds == other.bounds and self.bounds2 == other.bounds2
+
+    def __repr__(self):
+        return f"NestedBorrowedFields(fields={self.fields!r}, bounds={self.bounds!r}, bounds2={self.bounds2!r})"
+
+
+class ErrorStruct:
+
+    def __init__(self, i: int, j: int):
+        self.i = i
+        self.j = j
+
+    @classmethod
+    def _from_ffi(cls, ffi, edges):
+        self = object.__new__(cls)
+        self.i = ffi.i
+        self.j = ffi.j
+        return self
+
+    def _to_ffi(self, keep):
+        return _ErrorStructFfi(
+            self.i,
+            self.j,
+        )
+
+    def __eq__(self, other):
+        if not isinstance(other, ErrorStruct):
+            return NotImplemented
+        return self.i == other.i and self.j == other.j
+
+    def __repr__(self):
+        return f"ErrorStruct(i={self.i!r}, j={self.j!r})"
+
+
+class MyStruct:
+
+    @classmethod
+    def _from_ffi(cls, ffi, edges):
+        self = object.__new__(cls)
+        self.a = ffi.a
+        self.b = ffi.b
+        self.c = ffi.c
+        self.d = ffi.d
+        self.e = ffi.e
+        self.f = chr(ffi.f)
+        self.g = MyEnum(ffi.g)
+        return self
+
+    def _to_ffi(self, keep):
+        return _MyStructFfi(
+            self.a,
+            self.b,
+            self.c,
+            self.d,
+            self.e,
+            ord(self.f),
+            int(self.g),
+        )
+
+    def __eq__(self, other):
+        if not isinstance(other, MyStruct):
+            return NotImplemented
+        return self.a == other.a and self.b == other.b and self.c == other.c and self.d == other.d and self.e == other.e and self.f == other.f and self.g == other.g
+
+    def __repr__(self):
+        return f"MyStruct(a={self.a!r}, b={self.b!r}, c={self.c!r}, d={self.d!r}, e={self.e!r}, f={self.f!r}, g={self.g!r})"
+
+    def __new__(cls, a: int = None, b: bool = None, c: int = None, d: int = None, e: int = None, f: str = None, g: MyEnum = None) -> MyStruct:
+        result = _runtime.lib().MyStruct_new()
+        self = MyStruct._from_ffi(result, [])
+        if a is not None:
+            self.a = a
+        if b is not None:
+            self.b = b
+        if c is not None:
+            self.c = c
+        if d is not None:
+            self.d = d
+        if e is not None:
+            self.e = e
+        if f is not None:
+            self.f = f
+        if g is not None:
+            self.g = g
+        return self
+
+    def into_a(self) -> int:
+        keep = []
+        result = _runtime.lib().MyStruct_into_a(self._to_ffi(keep))
+        return result
+
+
+class OptionStruct:
+
+    @classmethod
+    def _from_ffi(cls, ffi, edges):
+        self = object.__new__(cls)
+        self.a = (None if not ffi.a else OptionOpaque._from_ffi(ffi.a, True, []))
+        self.b = (None if not ffi.b else OptionOpaqueChar._from_ffi(ffi.b, True, []))
+        self.c = ffi.c
+        self.d = (None if not ffi.d else OptionOpaque._from_ffi(ffi.d, True, []))
+        return self
+
+    def _to_ffi(self, keep):
+        return _OptionStructFfi(
+            (self.a._ffi if self.a is not None else None),
+            (self.b._ffi if self.b is not None else None),
+            self.c,
+            (self.d._ffi if self.d is not None else None),
+        )
+
+    def __eq__(self, other):
+        if not isinstance(other, OptionStruct):
+            return NotImplemented
+        return self.a == other.a and self.b == other.b and self.c == other.c and self.d == other.d
+
+    def __repr__(self):
+        return f"OptionStruct(a={self.a!r}, b={self.b!r}, c={self.c!r}, d={self.d!r})"
+
+
+class AttrOpaque1:
+
+    @classmethod
+    def _from_ffi(cls, ffi, owned, edges):
+        self = object.__new__(cls)
+        self._ffi = ffi
+        self._owned = owned
+        # Objects this may borrow from, kept alive for as long as this is
+        self._edges = edges
+        return self
+
+    def __del__(self):
+        if getattr(self, "_owned", False):
+            _runtime.lib().namespace_AttrOpaque1_destroy(self._ffi)
+
+    def __new__(cls) -> AttrOpaque1:
+        result = _runtime.lib().namespace_AttrOpaque1_new()
+        return AttrOpaque1._from_ffi(result, True, [])
+
+    def _get_method(self) -> int:
+        result = _runtime.lib().namespace_AttrOpaque1_method(self._ffi)
+        return result
+
+    def _get_abirenamed(self) -> int:
+        result = _runtime.lib().renamed_on_abi_only(self._ffi)
+        return result
+
+    def method_disabledcpp(self) -> None:
+        _runtime.lib().namespace_AttrOpaque1_method_disabledcpp(self._ffi)
+
+    def use_unnamespaced(self, un: Unnamespaced) -> None:
+        _runtime.lib().namespace_AttrOpaque1_use_unnamespaced(self._ffi, un._ffi)
+
+    def use_namespaced(self, n: AttrEnum) -> None:
+        _runtime.lib().namespace_AttrOpaque1_use_namespaced(self._ffi, int(n))
+
+    abirenamed = property(_get_abirenamed, None)
+    method = property(_get_method, None)
+
+
+class AttrOpaque2:
+
+    def __init__(self, *args, **kwargs):
+        raise TypeError("AttrOpaque2 cannot be constructed directly")
+
+    @classmethod
+    def _from_ffi(cls, ffi, owned, edges):
+        self = object.__new__(cls)
+        self._ffi = ffi
+        self._owned = owned
+        # Objects this may borrow from, kept alive for as long as this is
+        self._edges = edges
+        return self
+
+    def __del__(self):
+        if getattr(self, "_owned", False):
+            _runtime.lib().namespace_AttrOpaque2_destroy(self._ffi)
+
+
+class Comparable:
+
+    def __init__(self, *args, **kwargs):
+        raise TypeError("Comparable cannot be constructed directly")
+
+    @classmethod
+    def _from_ffi(cls, ffi, owned, edges):
+        self = object.__new__(cls)
+        self._ffi = ffi
+        self._owned = owned
+        # Objects this may borrow from, kept alive for as long as this is
+        self._edges = edges
+        return self
+
+    def __del__(self):
+        if getattr(self, "_owned", False):
+            _runtime.lib().namespace_Comparable_destroy(self._ffi)
+
+    def __eq__(self, other):
+        if not isinstance(other, Comparable):
+            return NotImplemented
+        return self._compare(other) == 0
+
+    def __lt__(self, other):
+        if not isinstance(other, Comparable):
+            return NotImplemented
+        return self._compare(other) < 0
+
+    def __le__(self, other):
+        if not isinstance(other, Comparable):
+            return NotImplemented
+        return self._compare(other) <= 0
+
+    def __gt__(self, other):
+        if not isinstance(other, Comparable):
+            return NotImplemented
+        return self._compare(other) > 0
+
+    def __ge__(self, other):
+        if not isinstance(other, Comparable):
+            return NotImplemented
+        return self._compare(other) >= 0
+
+    @staticmethod
+    def new(int: int) -> Comparable:
+        result = _runtime.lib().namespace_Comparable_new(int)
+        return Comparable._from_ffi(result, True, [])
+
+    def _compare(self, other: Comparable) -> int:
+        result = _runtime.lib().namespace_Comparable_cmp(self._ffi, other._ffi)
+        return result
+
+
+class MyIterable:
+
+    @classmethod
+    def _from_ffi(cls, ffi, owned, edges):
+        self = object.__new__(cls)
+        self._ffi = ffi
+        self._owned = owned
+        # Objects this may borrow from, kept alive for as long as this is
+        self._edges = edges
+        return self
+
+    def __del__(self):
+        if getattr(self, "_owned", False):
+            _runtime.lib().namespace_MyIterable_destroy(self._ffi)
+
+    def __new__(cls, x: list[int]) -> MyIterable:
+        keep = []
+        result = _runtime.lib().namespace_MyIterable_new(*_runtime.prim_slice(ctypes.c_uint8, x, keep))
+        return MyIterable._from_ffi(result, True, [])
+
+    def __iter__(self) -> MyIterator:
+        result = _runtime.lib().namespace_MyIterable_iter(self._ffi)
+        return MyIterator._from_ffi(result, True, [self])
+
+
+class MyIterator:
+
+    def __init__(self, *args, **kwargs):
+        raise TypeError("MyIterator cannot be constructed directly")
+
+    @classmethod
+    def _from_ffi(cls, ffi, owned, edges):
+        self = object.__new__(cls)
+        self._ffi = ffi
+        self._owned = owned
+        # Objects this may borrow from, kept alive for as long as this is
+        self._edges = edges
+        return self
+
+    def __del__(self):
+        if getattr(self, "_owned", False):
+            _runtime.lib().namespace_MyIterator_destroy(self._ffi)
+
+    def __iter__(self):
+        return self
+
+    def __next__(self) -> int:
+        result = _runtime.lib().namespace_MyIterator_next(self._ffi)
+        if not result.is_ok:
+            raise StopIteration
+        return _runtime.read_union(result.union, ctypes.c_uint8)
+
+
+class Unnamespaced:
+
+    def __init__(self, *args, **kwargs):
+        raise TypeError("Unnamespaced cannot be constructed directly")
+
+    @classmethod
+    def _from_ffi(cls, ffi, owned, edges):
+        self = object.__new__(cls)
+        self._ffi = ffi
+        self._owned = owned
+        # Objects this may borrow from, kept alive for as long as this is
+        self._edges = edges
+        return self
+
+    def __del__(self):
+        if getattr(self, "_owned", False):
+            _runtime.lib().namespace_Unnamespaced_destroy(self._ffi)
+
+    @staticmethod
+    def make(e: AttrEnum) -> Unnamespaced:
+        result = _runtime.lib().namespace_Unnamespaced_make(int(e))
+        return Unnamespaced._from_ffi(result, True, [])
+
+    def use_namespaced(self, n: AttrOpaque1) -> None:
+        _runtime.lib().namespace_Unnamespaced_use_namespaced(self._ffi, n._ffi)
+
+
+class ConstHolder:
+
+    MIN_VALUE = -128
+
+    SEPARATOR = 124
+
+    def __init__(self, *args, **kwargs):
+        raise TypeError("ConstHolder cannot be constructed directly")
+
+    @classmethod
+    def _from_ffi(cls, ffi, owned, edges):
+        self = object.__new__(cls)
+        self._ffi = ffi
+        self._owned = owned
+        # Objects this may borrow from, kept alive for as long as this is
+        self._edges = edges
+        return self
+
+    def __del__(self):
+        if getattr(self, "_owned", False):
+            _runtime.lib().ConstHolder_destroy(self._ffi)
+
+    @staticmethod
+    def max_len() -> int:
+        result = _runtime.lib().ConstHolder_max_len()
+        return result
+
+
+class Bar:
+
+    def __init__(self, *args, **kwargs):
+        raise TypeError("Bar cannot be constructed directly")
+
+    @classmethod
+    def _from_ffi(cls, ffi, owned, edges):
+        self = object.__new__(cls)
+        self._ffi = ffi
+        self._owned = owned
+        # Objects this may borrow from, kept alive for as long as this is
+        self._edges = edges
+        return self
+
+    def __del__(self):
+        if getattr(self, "_owned", False):
+            _runtime.lib().Bar_destroy(self._ffi)
+
+    def _get_foo(self) -> Foo:
+        result = _runtime.lib().Bar_foo(self._ffi)
+        return Foo._from_ffi(result, False, [self])
+
+    foo = property(_get_foo, None)
+
+
+class Foo:
+
+    @classmethod
+    def _from_ffi(cls, ffi, owned, edges):
+        self = object.__new__(cls)
+        self._ffi = ffi
+        self._owned = owned
+        # Objects this may borrow from, kept alive for as long as this is
+        self._edges = edges
+        return self
+
+    def __del__(self):
+        if getattr(self, "_owned", False):
+            _runtime.lib().Foo_destroy(self._ffi)
+
+    def __new__(cls, x: str) -> Foo:
+        keep = []
+        result = _runtime.lib().Foo_new(*_runtime.str8(x, keep))
+        return Foo._from_ffi(result, True, [x, keep])
+
+    def _get_bar(self) -> Bar:
+        result = _runtime.lib().Foo_get_bar(self._ffi)
+        return Bar._from_ffi(result, True, [self])
+
+    @staticmethod
+    def static(x: str) -> Foo:
+        keep = []
+        result = _runtime.lib().Foo_new_static(*_runtime.str8(x, keep))
+        return Foo._from_ffi(result, True, [x, keep])
+
+    def as_returning(self) -> BorrowedFieldsReturning:
+        result = _runtime.lib().Foo_as_returning(self._ffi)
+        return BorrowedFieldsReturning._from_ffi(result, [self])
+
+    @staticmethod
+    def extract_from_fields(fields: BorrowedFields) -> Foo:
+        keep = []
+        result = _runtime.lib().Foo_extract_from_fields(fields._to_ffi(keep))
+        return Foo._from_ffi(result, True, [fields, keep])
+
+    @staticmethod
+    def extract_from_bounds(bounds: BorrowedFieldsWithBounds, another_string: str) -> Foo:
+        """Test that the extraction logic correctly pins the right fields"""
+        keep = []
+        result = _runtime.lib().Foo_extract_from_bounds(bounds._to_ffi(keep), *_runtime.str8(another_string, keep))
+        return Foo._from_ffi(result, True, [bounds, another_string, keep])
+
+    bar = property(_get_bar, None)
+
+
+class One:
+
+    def __init__(self, *args, **kwargs):
+        raise TypeError("One cannot be constructed directly")
+
+    @classmethod
+    def _from_ffi(cls, ffi, owned, edges):
+        self = object.__new__(cls)
+        self._ffi = ffi
+        self._owned = owned
+        # Objects this may borrow from, kept alive for as long as this is
+        self._edges = edges
+        return self
+
+    def __del__(self):
+        if getattr(self, "_owned", False):
+            _runtime.lib().One_destroy(self._ffi)
+
+    @staticmethod
+    def transitivity(hold: One, nohold: One) -> One:
+        result = _runtime.lib().One_transitivity(hold._ffi, nohold._ffi)
+        return One._from_ffi(result, True, [hold, nohold])
+
+    @staticmethod
+    def cycle(hold: Two, nohold: One) -> One:
+        result = _runtime.lib().One_cycle(hold._ffi, nohold._ffi)
+        return One._from_ffi(result, True, [hold, nohold])
+
+    @staticmethod
+    def many_dependents(a: One, b: One, c: Two, d: Two, nohold: Two) -> One:
+        result = _runtime.lib().One_many_dependents(a._ffi, b._ffi, c._ffi, d._ffi, nohold._ffi)
+        return One._from_ffi(result, True, [a, b, c, d, nohold])
+
+    @staticmethod
+    def return_outlives_param(hold: Two, nohold: One) -> One:
+        result = _runtime.lib().One_return_outlives_param(hold._ffi, nohold._ffi)
+        return One._from_ffi(result, True, [hold, nohold])
+
+    @staticmethod
+    def diamond_top(top: One, left: One, right: One, bottom: One) -> One:
+        result = _runtime.lib().One_diamond_top(top._ffi, left._ffi, right._ffi, bottom._ffi)
+        return One._from_ffi(result, True, [top, left, right, bottom])
+
+    @staticmethod
+    def diamond_left(top: One, left: One, right: One, bottom: One) -> One:
+        result = _runtime.lib().One_diamond_left(top._ffi, left._ffi, right._ffi, bottom._ffi)
+        return One._from_ffi(result, True, [top, left, right, bottom])
+
+    @staticmethod
+    def diamond_right(top: One, left: One, right: One, bottom: One) -> One:
+        result = _runtime.lib().One_diamond_right(top._ffi, left._ffi, right._ffi, bottom._ffi)
+        return One._from_ffi(result, True, [top, left, right, bottom])
+
+    @staticmethod
+    def diamond_bottom(top: One, left: One, right: One, bottom: One) -> One:
+        result = _runtime.lib().One_diamond_bottom(top._ffi, left._ffi, right._ffi, bottom._ffi)
+        return One._from_ffi(result, True, [top, left, right, bottom])
+
+    @staticmethod
+    def diamond_and_nested_types(a: One, b: One, c: One, d: One, nohold: One) -> One:
+        result = _runtime.lib().One_diamond_and_nested_types(a._ffi, b._ffi, c._ffi, d._ffi, nohold._ffi)
+        return One._from_ffi(result, True, [a, b, c, d, nohold])
+
+    @staticmethod
+    def implicit_bounds(explicit_hold: One, implicit_hold: One, nohold: One) -> One:
+        result = _runtime.lib().One_implicit_bounds(explicit_hold._ffi, implicit_hold._ffi, nohold._ffi)
+        return One._from_ffi(result, True, [explicit_hold, implicit_hold, nohold])
+
+    @staticmethod
+    def implicit_bounds_deep(explicit: One, implicit_1: One, implicit_2: One, nohold: One) -> One:
+        result = _runtime.lib().One_implicit_bounds_deep(explicit._ffi, implicit_1._ffi, implicit_2._ffi, nohold._ffi)
+        return One._from_ffi(result, True, [explicit, implicit_1, implicit_2, nohold])
+
+
+class Two:
+
+    def __init__(self, *args, **kwargs):
+        raise TypeError("Two cannot be constructed directly")
+
+    @classmethod
+    def _from_ffi(cls, ffi, owned, edges):
+        self = object.__new__(cls)
+        self._ffi = ffi
+        self._owned = owned
+        # Objects this may borrow from, kept alive for as long as this is
+        self._edges = edges
+        return self
+
+    def __del__(self):
+        if getattr(self, "_owned", False):
+            _runtime.lib().Two_destroy(self._ffi)
+
+
+class OptionOpaque:
+
+    def __init__(self, *args, **kwargs):
+        raise TypeError("OptionOpaque cannot be constructed directly")
+
+    @classmethod
+    def _from_ffi(cls, ffi, owned, edges):
+        self = object.__new__(cls)
+        self._ffi = ffi
+        self._owned = owned
+        # Objects this may borrow from, kept alive for as long as this is
+        self._edges = edges
+        return self
+
+    def __del__(self):
+        if getattr(self, "_owned", False):
+            _runtime.lib().OptionOpaque_destroy(self._ffi)
+
+    @staticmethod
+    def new(i: int) -> typing.Optional[OptionOpaque]:
+        result = _runtime.lib().OptionOpaque_new(i)
+        return (None if not result else OptionOpaque._from_ffi(result, True, []))
+
+    @staticmethod
+    def new_none() -> typing.Optional[OptionOpaque]:
+        result = _runtime.lib().OptionOpaque_new_none()
+        return (None if not result else OptionOpaque._from_ffi(result, True, []))
+
+    @staticmethod
+    def returns() -> typing.Optional[OptionStruct]:
+        result = _runtime.lib().OptionOpaque_returns()
+        if not result.is_ok:
+            return None
+        return OptionStruct._from_ffi(_runtime.read_union(result.union, _OptionStructFfi), [])
+
+    @staticmethod
+    def new_struct() -> OptionStruct:
+        result = _runtime.lib().OptionOpaque_new_struct()
+        return OptionStruct._from_ffi(result, [])
+
+    @staticmethod
+    def new_struct_nones() -> OptionStruct:
+        result = _runtime.lib().OptionOpaque_new_struct_nones()
+        return OptionStruct._from_ffi(result, [])
+
+    def assert_integer(self, i: int) -> None:
+        _runtime.lib().OptionOpaque_assert_integer(self._ffi, i)
+
+    @staticmethod
+    def option_opaque_argument(arg: typing.Optional[OptionOpaque]) -> bool:
+        result = _runtime.lib().OptionOpaque_option_opaque_argument((arg._ffi if arg is not None else None))
+        return result
+
+
+class OptionOpaqueChar:
+
+    def __init__(self, *args, **kwargs):
+        raise TypeError("OptionOpaqueChar cannot be constructed directly")
+
+    @classmethod
+    def _from_ffi(cls, ffi, owned, edges):
+        self = object.__new__(cls)
+        self._ffi = ffi
+        self._owned = owned
+        # Objects this may borrow from, kept alive for as long as this is
+        self._edges = edges
+        return self
+
+    def __del__(self):
+        if getattr(self, "_owned", False):
+            _runtime.lib().OptionOpaqueChar_destroy(self._ffi)
+
+    def assert_char(self, ch: str) -> None:
+        _runtime.lib().OptionOpaqueChar_assert_char(self._ffi, ord(ch))
+
+
+class PanicOpaque:
+
+    def __init__(self, *args, **kwargs):
+        raise TypeError("PanicOpaque cannot be constructed directly")
+
+    @classmethod
+    def _from_ffi(cls, ffi, owned, edges):
+        self = object.__new__(cls)
+        self._ffi = ffi
+        self._owned = owned
+        # Objects this may borrow from, kept alive for as long as this is
+        self._edges = edges
+        return self
+
+    def __del__(self):
+        if getattr(self, "_owned", False):
+            _runtime.lib().PanicOpaque_destroy(self._ffi)
+
+    @staticmethod
+    def panics(message: str) -> None:
+        """Panics, which foreign code is told about after the call
+
+        Raises `DiplomatPanic` if it panics.
+        """
+        keep = []
+        _runtime.lib().PanicOpaque_panics(*_runtime.str8(message, keep))
+        _runtime.check_panic()
+
+    @staticmethod
+    def checked_divide(x: int) -> int:
+        """Panics when `x` is zero, which is reported as `PanicError::Panicked`
+
+        Raises `DiplomatError` with a `PanicError` value on failure.
+        """
+        result = _runtime.lib().PanicOpaque_checked_divide(x)
+        if not result.is_ok:
+            raise _runtime.DiplomatError(PanicError(_runtime.read_union(result.union, ctypes.c_int32)))
+        return _runtime.read_union(result.union, ctypes.c_uint32)
+
+    @staticmethod
+    def not_panicking(x: int) -> int:
+        """Raises `DiplomatPanic` if it panics."""
+        result = _runtime.lib().PanicOpaque_not_panicking(x)
+        _runtime.check_panic()
+        return result
+
+
+class ResultOpaque:
+
+    @classmethod
+    def _from_ffi(cls, ffi, owned, edges):
+        self = object.__new__(cls)
+        self._ffi = ffi
+        self._owned = owned
+        # Objects this may borrow from, kept alive for as long as this is
+        self._edges = edges
+        return self
+
+    def __del__(self):
+        if getattr(self, "_owned", False):
+            _runtime.lib().ResultOpaque_destroy(self._ffi)
+
+    def __new__(cls, i: int) -> ResultOpaque:
+        """Raises `DiplomatError` with a `ErrorEnum` value on failure."""
+        result = _runtime.lib().ResultOpaque_new(i)
+        if not result.is_ok:
+            raise _runtime.DiplomatError(ErrorEnum(_runtime.read_union(result.union, ctypes.c_int32)))
+        return ResultOpaque._from_ffi(_runtime.read_union(result.union, ctypes.c_void_p), True, [])
+
+    @staticmethod
+    def failing_foo() -> ResultOpaque:
+        """Raises `DiplomatError` with a `ErrorEnum` value on failure."""
+        result = _runtime.lib().ResultOpaque_new_failing_foo()
+        if not result.is_ok:
+            raise _runtime.DiplomatError(ErrorEnum(_runtime.read_union(result.union, ctypes.c_int32)))
+        return ResultOpaque._from_ffi(_runtime.read_union(result.union, ctypes.c_void_p), True, [])
+
+    @staticmethod
+    def failing_bar() -> ResultOpaque:
+        """Raises `DiplomatError` with a `ErrorEnum` value on failure."""
+        result = _runtime.lib().ResultOpaque_new_failing_bar()
+        if not result.is_ok:
+            raise _runtime.DiplomatError(ErrorEnum(_runtime.read_union(result.union, ctypes.c_int32)))
+        return ResultOpaque._from_ffi(_runtime.read_union(result.union, ctypes.c_void_p), True, [])
+
+    @staticmethod
+    def new_failing_unit() -> ResultOpaque:
+        """Raises `DiplomatError` on failure."""
+        result = _runtime.lib().ResultOpaque_new_failing_unit()
+        if not result.is_ok:
+            raise _runtime.DiplomatError()
+        return ResultOpaque._from_ffi(_runtime.read_union(result.union, ctypes.c_void_p), True, [])
+
+    @staticmethod
+    def failing_struct(i: int) -> ResultOpaque:
+        """Raises `DiplomatError` with a `ErrorStruct` value on failure."""
+        result = _runtime.lib().ResultOpaque_new_failing_struct(i)
+        if not result.is_ok:
+            raise _runtime.DiplomatError(ErrorStruct._from_ffi(_runtime.read_union(result.union, _ErrorStructFfi), []))
+        return ResultOpaque._from_ffi(_runtime.read_union(result.union, ctypes.c_void_p), True, [])
+
+    @staticmethod
+    def new_in_err(i: int) -> None:
+        """Raises `DiplomatError` with a `ResultOpaque` value on failure."""
+        result = _runtime.lib().ResultOpaque_new_in_err(i)
+        if not result.is_ok:
+            raise _runtime.DiplomatError(ResultOpaque._from_ffi(_runtime.read_union(result.union, ctypes.c_void_p), True, []))
+
+    @staticmethod
+    def new_int(i: int) -> int:
+        """Raises `DiplomatError` on failure."""
+        result = _runtime.lib().ResultOpaque_new_int(i)
+        if not result.is_ok:
+            raise _runtime.DiplomatError()
+        return _runtime.read_union(result.union, ctypes.c_int32)
+
+    @staticmethod
+    def new_in_enum_err(i: int) -> ErrorEnum:
+        """Raises `DiplomatError` with a `ResultOpaque` value on failure."""
+        result = _runtime.lib().ResultOpaque_new_in_enum_err(i)
+        if not result.is_ok:
+            raise _runtime.DiplomatError(ResultOpaque._from_ffi(_runtime.read_union(result.union, ctypes.c_void_p), True, []))
+        return ErrorEnum(_runtime.read_union(result.union, ctypes.c_int32))
+
+    def assert_integer(self, i: int) -> None:
+        _runtime.lib().ResultOpaque_assert_integer(self._ffi, i)
+
+
+class RefList:
+
+    def __init__(self, *args, **kwargs):
+        raise TypeError("RefList cannot be constructed directly")
+
+    @classmethod
+    def _from_ffi(cls, ffi, owned, edges):
+        self = object.__new__(cls)
+        self._ffi = ffi
+        self._owned = owned
+        # Objects this may borrow from, kept alive for as long as this is
+        self._edges = edges
+        return self
+
+    def __del__(self):
+        if getattr(self, "_owned", False):
+            _runtime.lib().RefList_destroy(self._ffi)
+
+    @staticmethod
+    def node(data: RefListParameter) -> RefList:
+        result = _runtime.lib().RefList_node(data._ffi)
+        return RefList._from_ffi(result, True, [data])
+
+
+class RefListParameter:
+
+    def __init__(self, *args, **kwargs):
+        raise TypeError("RefListParameter cannot be constructed directly")
+
+    @classmethod
+    def _from_ffi(cls, ffi, owned, edges):
+        self = object.__new__(cls)
+        self._ffi = ffi
+        self._owned = owned
+        # Objects this may borrow from, kept alive for as long as this is
+        self._edges = edges
+        return self
+
+    def __del__(self):
+        if getattr(self, "_owned", False):
+            _runtime.lib().RefListParameter_destroy(self._ffi)
+
+
+class Float64Vec:
+
+    @classmethod
+    def _from_ffi(cls, ffi, owned, edges):
+        self = object.__new__(cls)
+        self._ffi = ffi
+        self._owned = owned
+        # Objects this may borrow from, kept alive for as long as this is
+        self._edges = edges
+        return self
+
+    def __del__(self):
+        if getattr(self, "_owned", False):
+            _runtime.lib().Float64Vec_destroy(self._ffi)
+
+    @staticmethod
+    def bool(v: list[bool]) -> Float64Vec:
+        keep = []
+        result = _runtime.lib().Float64Vec_new_bool(*_runtime.prim_slice(ctypes.c_bool, v, keep))
+        return Float64Vec._from_ffi(result, True, [])
+
+    @staticmethod
+    def i16(v: list[int]) -> Float64Vec:
+        keep = []
+        result = _runtime.lib().Float64Vec_new_i16(*_runtime.prim_slice(ctypes.c_int16, v, keep))
+        return Float64Vec._from_ffi(result, True, [])
+
+    @staticmethod
+    def u16(v: list[int]) -> Float64Vec:
+        keep = []
+        result = _runtime.lib().Float64Vec_new_u16(*_runtime.prim_slice(ctypes.c_uint16, v, keep))
+        return Float64Vec._from_ffi(result, True, [])
+
+    @staticmethod
+    def isize(v: list[int]) -> Float64Vec:
+        keep = []
+        result = _runtime.lib().Float64Vec_new_isize(*_runtime.prim_slice(ctypes.c_ssize_t, v, keep))
+        return Float64Vec._from_ffi(result, True, [])
+
+    @staticmethod
+    def usize(v: list[int]) -> Float64Vec:
+        keep = []
+        result = _runtime.lib().Float64Vec_new_usize(*_runtime.prim_slice(ctypes.c_size_t, v, keep))
+        return Float64Vec._from_ffi(result, True, [])
+
+    @staticmethod
+    def f64_be_bytes(v: bytes) -> Float64Vec:
+        keep = []
+        result = _runtime.lib().Float64Vec_new_f64_be_bytes(*_runtime.prim_slice(ctypes.c_uint8, v, keep))
+        return Float64Vec._from_ffi(result, True, [])
+
+    def __new__(cls, v: list[float]) -> Float64Vec:
+        result = _runtime.lib().Float64Vec_new_from_owned(*_runtime.prim_slice(ctypes.c_double, v))
+        return Float64Vec._from_ffi(result, True, [])
+
+    def _get_as_boxed_slice(self) -> list[float]:
+        result = _runtime.lib().Float64Vec_as_boxed_slice(self._ffi)
+        return _runtime.read_slice(result, True)
+
+    def _get_as_slice(self) -> list[float]:
+        result = _runtime.lib().Float64Vec_as_slice(self._ffi)
+        return _runtime.read_slice(result, False)
+
+    def fill_slice(self, v: list[float]) -> None:
+        keep = []
+        _runtime.lib().Float64Vec_fill_slice(self._ffi, *_runtime.prim_slice(ctypes.c_double, v, keep))
+
+    def set_value(self, new_slice: list[float]) -> None:
+        keep = []
+        _runtime.lib().Float64Vec_set_value(self._ffi, *_runtime.prim_slice(ctypes.c_double, new_slice, keep))
+
+    def __str__(self) -> str:
+        writeable = _runtime.Writeable()
+        _runtime.lib().Float64Vec_to_string(self._ffi, writeable.ffi)
+        return writeable.finalize()
+
+    def borrow(self) -> list[float]:
+        result = _runtime.lib().Float64Vec_borrow(self._ffi)
+        return _runtime.read_slice(result, False)
+
+    def __getitem__(self, i: int) -> float:
+        result = _runtime.lib().Float64Vec_get(self._ffi, i)
+        if not result.is_ok:
+            raise IndexError
+        return _runtime.read_union(result.union, ctypes.c_double)
+
+    as_boxed_slice = property(_get_as_boxed_slice, None)
+    as_slice = property(_get_as_slice, None)
+
+
+class MyString:
+
+    @classmethod
+    def _from_ffi(cls, ffi, owned, edges):
+        self = object.__new__(cls)
+        self._ffi = ffi
+        self._owned = owned
+        # Objects this may borrow from, kept alive for as long as this is
+        self._edges = edges
+        return self
+
+    def __del__(self):
+        if getattr(self, "_owned", False):
+            _runtime.lib().MyString_destroy(self._ffi)
+
+    def __new__(cls, v: str) -> MyString:
+        keep = []
+        result = _runtime.lib().MyString_new(*_runtime.str8(v, keep))
+        return MyString._from_ffi(result, True, [])
+
+    @staticmethod
+    def unsafe(v: str) -> MyString:
+        keep = []
+        result = _runtime.lib().MyString_new_unsafe(*_runtime.str8(v, keep))
+        return MyString._from_ffi(result, True, [])
+
+    @staticmethod
+    def new_owned(v: str) -> MyString:
+        result = _runtime.lib().MyString_new_owned(*_runtime.str8(v))
+        return MyString._from_ffi(result, True, [])
+
+    @staticmethod
+    def new_from_first(v: list[str]) -> MyString:
+        keep = []
+        result = _runtime.lib().MyString_new_from_first(*_runtime.strs8(v, keep))
+        return MyString._from_ffi(result, True, [])
+
+    def _set_str(self, new_str: str) -> None:
+        keep = []
+        _runtime.lib().MyString_set_str(self._ffi, *_runtime.str8(new_str, keep))
+
+    def _get_str(self) -> str:
+        writeable = _runtime.Writeable()
+        _runtime.lib().MyString_get_str(self._ffi, writeable.ffi)
+        return writeable.finalize()
+
+    str = property(_get_str, _set_str)
+
+
+class Opaque:
+
+    @classmethod
+    def _from_ffi(cls, ffi, owned, edges):
+        self = object.__new__(cls)
+        self._ffi = ffi
+        self._owned = owned
+        # Objects this may borrow from, kept alive for as long as this is
+        self._edges = edges
+        return self
+
+    def __del__(self):
+        if getattr(self, "_owned", False):
+            _runtime.lib().Opaque_destroy(self._ffi)
+
+    def __new__(cls) -> Opaque:
+        result = _runtime.lib().Opaque_new()
+        return Opaque._from_ffi(result, True, [])
+
+    def assert_struct(self, s: MyStruct) -> None:
+        """See the [Rust documentation for `something`](https://docs.rs/Something/latest/struct.Something.html#method.something) for more information.
+
+        See the [Rust documentation for `something_else`](https://docs.rs/Something/latest/struct.Something.html#method.something_else) for more information.
+
+        Additional information: [1](https://docs.rs/Something/latest/struct.Something.html#method.something_small), [2](https://docs.rs/SomethingElse/latest/struct.SomethingElse.html#method.something)
+        """
+        keep = []
+        _runtime.lib().Opaque_assert_struct(self._ffi, s._to_ffi(keep))
+
+    @staticmethod
+    def returns_usize() -> int:
+        result = _runtime.lib().Opaque_returns_usize()
+        return result
+
+    @staticmethod
+    def returns_imported() -> ImportedStruct:
+        result = _runtime.lib().Opaque_returns_imported()
+        return ImportedStruct._from_ffi(result, [])
+
+
+class ShapeList:
+
+    @classmethod
+    def _from_ffi(cls, ffi, owned, edges):
+        self = object.__new__(cls)
+        self._ffi = ffi
+        self._owned = owned
+        # Objects this may borrow from, kept alive for as long as this is
+        self._edges = edges
+        return self
+
+    def __del__(self):
+        if getattr(self, "_owned", False):
+            _runtime.lib().ShapeList_destroy(self._ffi)
+
+    def __new__(cls) -> ShapeList:
+        result = _runtime.lib().ShapeList_new()
+        return ShapeList._from_ffi(result, True, [])
+
+    def push(self, shape: Shape) -> None:
+        keep = []
+        _runtime.lib().ShapeList_push(self._ffi, shape._to_ffi(keep))
+
+    def last(self) -> Shape:
+        result = _runtime.lib().ShapeList_last(self._ffi)
+        return Shape._from_ffi(result, [])
+
+    def total_area(self) -> float:
+        result = _runtime.lib().ShapeList_total_area(self._ffi)
+        return result
+
+
+class AttrEnum(enum.IntEnum):
+    A = 0
+    B = 1
+    C = 2
+
+
+class UnimportedEnum(enum.IntEnum):
+    A = 0
+    B = 1
+    C = 2
+
+
+class PanicError(enum.IntEnum):
+    PANICKED = 0
+    TOO_LARGE = 1
+
+
+class ErrorEnum(enum.IntEnum):
+    FOO = 0
+    BAR = 1
+
+
+class ContiguousEnum(enum.IntEnum):
+    C = 0
+    D = 1
+    E = 2
+    F = 3
+
+
+class MyEnum(enum.IntEnum):
+    A = -2
+    B = -1
+    C = 0
+    D = 1
+    E = 2
+    F = 3
+
+    def into_value(self) -> int:
+        result = _runtime.lib().MyEnum_into_value(int(self))
+        return result
+
+
+class Shape:
+
+    @staticmethod
+    def _from_ffi(ffi, edges):
+        if ffi.tag == 0:
+            payload = _runtime.read_union(ffi.payload, _ShapeCircleFfi)
+            return ShapeCircle(payload.radius)
+        if ffi.tag == 1:
+            payload = _runtime.read_union(ffi.payload, _ShapeRectFfi)
+            return ShapeRect(payload.width, payload.height)
+        if ffi.tag == 2:
+            return ShapeEmpty()
+        raise ValueError(f"Unknown Shape tag {ffi.tag}")
+
+
+class ShapeCircle(Shape):
+
+    def __init__(self, radius: float):
+        self.radius = radius
+
+    def _to_ffi(self, keep):
+        return _ShapeFfi(0, _runtime.make_union(_ShapePayloadFfi, _ShapeCircleFfi(self.radius)))
+
+    def __eq__(self, other):
+        if not isinstance(other, ShapeCircle):
+            return NotImplemented
+        return self.radius == other.radius
+
+    def __repr__(self):
+        return f"ShapeCircle(radius={self.radius!r})"
+
+
+class ShapeRect(Shape):
+
+    def __init__(self, width: float, height: float):
+        self.width = width
+        self.height = height
+
+    def _to_ffi(self, keep):
+        return _ShapeFfi(1, _runtime.make_union(_ShapePayloadFfi, _ShapeRectFfi(self.width, self.height)))
+
+    def __eq__(self, other):
+        if not isinstance(other, ShapeRect):
+            return NotImplemented
+        return self.width == other.width and self.height == other.height
+
+    def __repr__(self):
+        return f"ShapeRect(width={self.width!r}, height={self.height!r})"
+
+
+class ShapeEmpty(Shape):
+
+    def _to_ffi(self, keep):
+        return _ShapeFfi(2)
+
+    def __eq__(self, other):
+        if not isinstance(other, ShapeEmpty):
+            return NotImplemented
+        return True
+
+    def __repr__(self):
+        return f"ShapeEmpty()"
+
+
+ENABLED = True
+GREETING = "hello"
+MAX_LEN = 16
+"""The length `ConstHolder::max_len` returns"""
+RATIO = 0.5
+
+
+def _declare(lib):
+    lib.MyStruct_new.argtypes = []
+    lib.MyStruct_new.restype = _MyStructFfi
+    lib.MyStruct_into_a.argtypes = [_MyStructFfi]
+    lib.MyStruct_into_a.restype = ctypes.c_uint8
+    lib.namespace_AttrOpaque1_new.argtypes = []
+    lib.namespace_AttrOpaque1_new.restype = ctypes.c_void_p
+    lib.namespace_AttrOpaque1_method.argtypes = [ctypes.c_void_p]
+    lib.namespace_AttrOpaque1_method.restype = ctypes.c_uint8
+    lib.renamed_on_abi_only.argtypes = [ctypes.c_void_p]
+    lib.renamed_on_abi_only.restype = ctypes.c_uint8
+    lib.namespace_AttrOpaque1_method_disabledcpp.argtypes = [ctypes.c_void_p]
+    lib.namespace_AttrOpaque1_method_disabledcpp.restype = None
+    lib.namespace_AttrOpaque1_use_unnamespaced.argtypes = [ctypes.c_void_p, ctypes.c_void_p]
+    lib.namespace_AttrOpaque1_use_unnamespaced.restype = None
+    lib.namespace_AttrOpaque1_use_namespaced.argtypes = [ctypes.c_void_p, ctypes.c_int32]
+    lib.namespace_AttrOpaque1_use_namespaced.restype = None
+    lib.namespace_AttrOpaque1_destroy.argtypes = [ctypes.c_void_p]
+    lib.namespace_AttrOpaque1_destroy.restype = None
+    lib.namespace_AttrOpaque2_destroy.argtypes = [ctypes.c_void_p]
+    lib.namespace_AttrOpaque2_destroy.restype = None
+    lib.namespace_Comparable_new.argtypes = [ctypes.c_uint8]
+    lib.namespace_Comparable_new.restype = ctypes.c_void_p
+    lib.namespace_Comparable_cmp.argtypes = [ctypes.c_void_p, ctypes.c_void_p]
+    lib.namespace_Comparable_cmp.restype = ctypes.c_int8
+    lib.namespace_Comparable_destroy.argtypes = [ctypes.c_void_p]
+    lib.namespace_Comparable_destroy.restype = None
+    lib.namespace_MyIterable_new.argtypes = [ctypes.POINTER(ctypes.c_uint8), ctypes.c_size_t]
+    lib.namespace_MyIterable_new.restype = ctypes.c_void_p
+    lib.namespace_MyIterable_iter.argtypes = [ctypes.c_void_p]
+    lib.namespace_MyIterable_iter.restype = ctypes.c_void_p
+    lib.namespace_MyIterable_destroy.argtypes = [ctypes.c_void_p]
+    lib.namespace_MyIterable_destroy.restype = None
+    lib.namespace_MyIterator_next.argtypes = [ctypes.c_void_p]
+    lib.namespace_MyIterator_next.restype = _diplomat_result_uint8_t_void
+    lib.namespace_MyIterator_destroy.argtypes = [ctypes.c_void_p]
+    lib.namespace_MyIterator_destroy.restype = None
+    lib.namespace_Unnamespaced_make.argtypes = [ctypes.c_int32]
+    lib.namespace_Unnamespaced_make.restype = ctypes.c_void_p
+    lib.namespace_Unnamespaced_use_namespaced.argtypes = [ctypes.c_void_p, ctypes.c_void_p]
+    lib.namespace_Unnamespaced_use_namespaced.restype = None
+    lib.namespace_Unnamespaced_destroy.argtypes = [ctypes.c_void_p]
+    lib.namespace_Unnamespaced_destroy.restype = None
+    lib.ConstHolder_max_len.argtypes = []
+    lib.ConstHolder_max_len.restype = ctypes.c_uint32
+    lib.ConstHolder_destroy.argtypes = [ctypes.c_void_p]
+    lib.ConstHolder_destroy.restype = None
+    lib.Bar_foo.argtypes = [ctypes.c_void_p]
+    lib.Bar_foo.restype = ctypes.c_void_p
+    lib.Bar_destroy.argtypes = [ctypes.c_void_p]
+    lib.Bar_destroy.restype = None
+    lib.Foo_new.argtypes = [ctypes.POINTER(ctypes.c_uint8), ctypes.c_size_t]
+    lib.Foo_new.restype = ctypes.c_void_p
+    lib.Foo_get_bar.argtypes = [ctypes.c_void_p]
+    lib.Foo_get_bar.restype = ctypes.c_void_p
+    lib.Foo_new_static.argtypes = [ctypes.POINTER(ctypes.c_uint8), ctypes.c_size_t]
+    lib.Foo_new_static.restype = ctypes.c_void_p
+    lib.Foo_as_returning.argtypes = [ctypes.c_void_p]
+    lib.Foo_as_returning.restype = _BorrowedFieldsReturningFfi
+    lib.Foo_extract_from_fields.argtypes = [_BorrowedFieldsFfi]
+    lib.Foo_extract_from_fields.restype = ctypes.c_void_p
+    lib.Foo_extract_from_bounds.argtypes = [_BorrowedFieldsWithBoundsFfi, ctypes.POINTER(ctypes.c_uint8), ctypes.c_size_t]
+    lib.Foo_extract_from_bounds.restype = ctypes.c_void_p
+    lib.Foo_destroy.argtypes = [ctypes.c_void_p]
+    lib.Foo_destroy.restype = None
+    lib.One_transitivity.argtypes = [ctypes.c_void_p, ctypes.c_void_p]
+    lib.One_transitivity.restype = ctypes.c_void_p
+    lib.One_cycle.argtypes = [ctypes.c_void_p, ctypes.c_void_p]
+    lib.One_cycle.restype = ctypes.c_void_p
+    lib.One_many_dependents.argtypes = [ctypes.c_void_p, ctypes.c_void_p, ctypes.c_void_p, ctypes.c_void_p, ctypes.c_void_p]
+    lib.One_many_dependents.restype = ctypes.c_void_p
+    lib.One_return_outlives_param.argtypes = [ctypes.c_void_p, ctypes.c_void_p]
+    lib.One_return_outlives_param.restype = ctypes.c_void_p
+    lib.One_diamond_top.argtypes = [ctypes.c_void_p, ctypes.c_void_p, ctypes.c_void_p, ctypes.c_void_p]
+    lib.One_diamond_top.restype = ctypes.c_void_p
+    lib.One_diamond_left.argtypes = [ctypes.c_void_p, ctypes.c_void_p, ctypes.c_void_p, ctypes.c_void_p]
+    lib.One_diamond_left.restype = ctypes.c_void_p
+    lib.One_diamond_right.argtypes = [ctypes.c_void_p, ctypes.c_void_p, ctypes.c_void_p, ctypes.c_void_p]
+    lib.One_diamond_right.restype = ctypes.c_void_p
+    lib.One_diamond_bottom.argtypes = [ctypes.c_void_p, ctypes.c_void_p, ctypes.c_void_p, ctypes.c_void_p]
+    lib.One_diamond_bottom.restype = ctypes.c_void_p
+    lib.One_diamond_and_nested_types.argtypes = [ctypes.c_void_p, ctypes.c_void_p, ctypes.c_void_p, ctypes.c_void_p, ctypes.c_void_p]
+    lib.One_diamond_and_nested_types.restype = ctypes.c_void_p
+    lib.One_implicit_bounds.argtypes = [ctypes.c_void_p, ctypes.c_void_p, ctypes.c_void_p]
+    lib.One_implicit_bounds.restype = ctypes.c_void_p
+    lib.One_implicit_bounds_deep.argtypes = [ctypes.c_void_p, ctypes.c_void_p, ctypes.c_void_p, ctypes.c_void_p]
+    lib.One_implicit_bounds_deep.restype = ctypes.c_void_p
+    lib.One_destroy.argtypes = [ctypes.c_void_p]
+    lib.One_destroy.restype = None
+    lib.Two_destroy.argtypes = [ctypes.c_void_p]
+    lib.Two_destroy.restype = None
+    lib.OptionOpaque_new.argtypes = [ctypes.c_int32]
+    lib.OptionOpaque_new.restype = ctypes.c_void_p
+    lib.OptionOpaque_new_none.argtypes = []
+    lib.OptionOpaque_new_none.restype = ctypes.c_void_p
+    lib.OptionOpaque_returns.argtypes = []
+    lib.OptionOpaque_returns.restype = _diplomat_result_OptionStruct_void
+    lib.OptionOpaque_new_struct.argtypes = []
+    lib.OptionOpaque_new_struct.restype = _OptionStructFfi
+    lib.OptionOpaque_new_struct_nones.argtypes = []
+    lib.OptionOpaque_new_struct_nones.restype = _OptionStructFfi
+    lib.OptionOpaque_assert_integer.argtypes = [ctypes.c_void_p, ctypes.c_int32]
+    lib.OptionOpaque_assert_integer.restype = None
+    lib.OptionOpaque_option_opaque_argument.argtypes = [ctypes.c_void_p]
+    lib.OptionOpaque_option_opaque_argument.restype = ctypes.c_bool
+    lib.OptionOpaque_destroy.argtypes = [ctypes.c_void_p]
+    lib.OptionOpaque_destroy.restype = None
+    lib.OptionOpaqueChar_assert_char.argtypes = [ctypes.c_void_p, ctypes.c_uint32]
+    lib.OptionOpaqueChar_assert_char.restype = None
+    lib.OptionOpaqueChar_destroy.argtypes = [ctypes.c_void_p]
+    lib.OptionOpaqueChar_destroy.restype = None
+    lib.PanicOpaque_panics.argtypes = [ctypes.POINTER(ctypes.c_uint8), ctypes.c_size_t]
+    lib.PanicOpaque_panics.restype = None
+    lib.PanicOpaque_checked_divide.argtypes = [ctypes.c_uint32]
+    lib.PanicOpaque_checked_divide.restype = _diplomat_result_uint32_t_PanicError
+    lib.PanicOpaque_not_panicking.argtypes = [ctypes.c_uint32]
+    lib.PanicOpaque_not_panicking.restype = ctypes.c_uint32
+    lib.PanicOpaque_destroy.argtypes = [ctypes.c_void_p]
+    lib.PanicOpaque_destroy.restype = None
+    lib.ResultOpaque_new.argtypes = [ctypes.c_int32]
+    lib.ResultOpaque_new.restype = _diplomat_result_box_ResultOpaque_ErrorEnum
+    lib.ResultOpaque_new_failing_foo.argtypes = []
+    lib.ResultOpaque_new_failing_foo.restype = _diplomat_result_box_ResultOpaque_ErrorEnum
+    lib.ResultOpaque_new_failing_bar.argtypes = []
+    lib.ResultOpaque_new_failing_bar.restype = _diplomat_result_box_ResultOpaque_ErrorEnum
+    lib.ResultOpaque_new_failing_unit.argtypes = []
+    lib.ResultOpaque_new_failing_unit.restype = _diplomat_result_box_ResultOpaque_void
+    lib.ResultOpaque_new_failing_struct.argtypes = [ctypes.c_int32]
+    lib.ResultOpaque_new_failing_struct.restype = _diplomat_result_box_ResultOpaque_ErrorStruct
+    lib.ResultOpaque_new_in_err.argtypes = [ctypes.c_int32]
+    lib.ResultOpaque_new_in_err.restype = _diplomat_result_void_box_ResultOpaque
+    lib.ResultOpaque_new_int.argtypes = [ctypes.c_int32]
+    lib.ResultOpaque_new_int.restype = _diplomat_result_int32_t_void
+    lib.ResultOpaque_new_in_enum_err.argtypes = [ctypes.c_int32]
+    lib.ResultOpaque_new_in_enum_err.restype = _diplomat_result_ErrorEnum_box_ResultOpaque
+    lib.ResultOpaque_assert_integer.argtypes = [ctypes.c_void_p, ctypes.c_int32]
+    lib.ResultOpaque_assert_integer.restype = None
+    lib.ResultOpaque_destroy.argtypes = [ctypes.c_void_p]
+    lib.ResultOpaque_destroy.restype = None
+    lib.RefList_node.argtypes = [ctypes.c_void_p]
+    lib.RefList_node.restype = ctypes.c_void_p
+    lib.RefList_destroy.argtypes = [ctypes.c_void_p]
+    lib.RefList_destroy.restype = None
+    lib.RefListParameter_destroy.argtypes = [ctypes.c_void_p]
+    lib.RefListParameter_destroy.restype = None
+    lib.Float64Vec_new_bool.argtypes = [ctypes.POINTER(ctypes.c_bool), ctypes.c_size_t]
+    lib.Float64Vec_new_bool.restype = ctypes.c_void_p
+    lib.Float64Vec_new_i16.argtypes = [ctypes.POINTER(ctypes.c_int16), ctypes.c_size_t]
+    lib.Float64Vec_new_i16.restype = ctypes.c_void_p
+    lib.Float64Vec_new_u16.argtypes = [ctypes.POINTER(ctypes.c_uint16), ctypes.c_size_t]
+    lib.Float64Vec_new_u16.restype = ctypes.c_void_p
+    lib.Float64Vec_new_isize.argtypes = [ctypes.POINTER(ctypes.c_ssize_t), ctypes.c_size_t]
+    lib.Float64Vec_new_isize.restype = ctypes.c_void_p
+    lib.Float64Vec_new_usize.argtypes = [ctypes.POINTER(ctypes.c_size_t), ctypes.c_size_t]
+    lib.Float64Vec_new_usize.restype = ctypes.c_void_p
+    lib.Float64Vec_new_f64_be_bytes.argtypes = [ctypes.POINTER(ctypes.c_uint8), ctypes.c_size_t]
+    lib.Float64Vec_new_f64_be_bytes.restype = ctypes.c_void_p
+    lib.Float64Vec_new_from_owned.argtypes = [ctypes.POINTER(ctypes.c_double), ctypes.c_size_t]
+    lib.Float64Vec_new_from_owned.restype = ctypes.c_void_p
+    lib.Float64Vec_as_boxed_slice.argtypes = [ctypes.c_void_p]
+    lib.Float64Vec_as_boxed_slice.restype = _runtime.slice_type(ctypes.c_double)
+    lib.Float64Vec_as_slice.argtypes = [ctypes.c_void_p]
+    lib.Float64Vec_as_slice.restype = _runtime.slice_type(ctypes.c_double)
+    lib.Float64Vec_fill_slice.argtypes = [ctypes.c_void_p, ctypes.POINTER(ctypes.c_double), ctypes.c_size_t]
+    lib.Float64Vec_fill_slice.restype = None
+    lib.Float64Vec_set_value.argtypes = [ctypes.c_void_p, ctypes.POINTER(ctypes.c_double), ctypes.c_size_t]
+    lib.Float64Vec_set_value.restype = None
+    lib.Float64Vec_to_string.argtypes = [ctypes.c_void_p, ctypes.c_void_p]
+    lib.Float64Vec_to_string.restype = None
+    lib.Float64Vec_borrow.argtypes = [ctypes.c_void_p]
+    lib.Float64Vec_borrow.restype = _runtime.slice_type(ctypes.c_double)
+    lib.Float64Vec_get.argtypes = [ctypes.c_void_p, ctypes.c_size_t]
+    lib.Float64Vec_get.restype = _diplomat_result_double_void
+    lib.Float64Vec_destroy.argtypes = [ctypes.c_void_p]
+    lib.Float64Vec_destroy.restype = None
+    lib.MyString_new.argtypes = [ctypes.POINTER(ctypes.c_uint8), ctypes.c_size_t]
+    lib.MyString_new.restype = ctypes.c_void_p
+    lib.MyString_new_unsafe.argtypes = [ctypes.POINTER(ctypes.c_uint8), ctypes.c_size_t]
+    lib.MyString_new_unsafe.restype = ctypes.c_void_p
+    lib.MyString_new_owned.argtypes = [ctypes.POINTER(ctypes.c_uint8), ctypes.c_size_t]
+    lib.MyString_new_owned.restype = ctypes.c_void_p
+    lib.MyString_new_from_first.argtypes = [ctypes.POINTER(_runtime.slice_type(ctypes.c_uint8)), ctypes.c_size_t]
+    lib.MyString_new_from_first.restype = ctypes.c_void_p
+    lib.MyString_set_str.argtypes = [ctypes.c_void_p, ctypes.POINTER(ctypes.c_uint8), ctypes.c_size_t]
+    lib.MyString_set_str.restype = None
+    lib.MyString_get_str.argtypes = [ctypes.c_void_p, ctypes.c_void_p]
+    lib.MyString_get_str.restype = None
+    lib.MyString_destroy.argtypes = [ctypes.c_void_p]
+    lib.MyString_destroy.restype = None
+    lib.Opaque_new.argtypes = []
+    lib.Opaque_new.restype = ctypes.c_void_p
+    lib.Opaque_assert_struct.argtypes = [ctypes.c_void_p, _MyStructFfi]
+    lib.Opaque_assert_struct.restype = None
+    lib.Opaque_returns_usize.argtypes = []
+    lib.Opaque_returns_usize.restype = ctypes.c_size_t
+    lib.Opaque_returns_imported.argtypes = []
+    lib.Opaque_returns_imported.restype = _ImportedStructFfi
+    lib.Opaque_destroy.argtypes = [ctypes.c_void_p]
+    lib.Opaque_destroy.restype = None
+    lib.ShapeList_new.argtypes = []
+    lib.ShapeList_new.restype = ctypes.c_void_p
+    lib.ShapeList_push.argtypes = [ctypes.c_void_p, _ShapeFfi]
+    lib.ShapeList_push.restype = None
+    lib.ShapeList_last.argtypes = [ctypes.c_void_p]
+    lib.ShapeList_last.restype = _ShapeFfi
+    lib.ShapeList_total_area.argtypes = [ctypes.c_void_p]
+    lib.ShapeList_total_area.restype = ctypes.c_double
+    lib.ShapeList_destroy.argtypes = [ctypes.c_void_p]
+    lib.ShapeList_destroy.restype = None
+    lib.MyEnum_into_value.argtypes = [ctypes.c_int32]
+    lib.MyEnum_into_value.restype = ctypes.c_int8
+
+
+_runtime.register_prototypes(_declare)
\ No newline at end of file
@@ -0,0 +1,25 @@
+import unittest
+
+from feature_tests import DiplomatError, DiplomatPanic, PanicError, PanicOpaque
+
+
+class PanicsTest(unittest.TestCase):
+    def test_panic_is_raised(self):
+        with self.assertRaises(DiplomatPanic) as raised:
+            PanicOpaque.panics("oops")
+        self.assertEqual(str(raised.exception), "oops")
+        # The panic was taken, so the next call doesn't raise it again
+        self.assertEqual(PanicOpaque.not_panicking(1), 2)
+
+    def test_panic_in_result_is_its_error(self):
+        with self.assertRaises(DiplomatError) as raised:
+            PanicOpaque.checked_divide(0)
+        self.assertEqual(raised.exception.value, PanicError.PANICKED)
+        with self.assertRaises(DiplomatError) as raised:
+            PanicOpaque.checked_divide(200)
+        self.assertEqual(raised.exception.value, PanicError.TOO_LARGE)
+        self.assertEqual(PanicOpaque.checked_divide(5), 20)
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,83 @@
+// generated by diplomat-tool
+
+// The bindings follow the API of the library rather than Rust's conventions
+#![allow(
+    clippy::inherent_to_string,
+    clippy::needless_lifetimes,
+    clippy::new_without_default,
+    clippy::result_unit_err,
+    clippy::should_implement_trait
+)]
+
+#[allow(unused_imports)]
+use super::diplomat_runtime::*;
+#[allow(unused_imports)]
+use super::*;
+
+#[repr(C)]
+pub struct AttrOpaque1 {
+    _data: [u8; 0],
+    _marker: std::marker::PhantomData<(*mut u8, std::marker::PhantomPinned)>,
+}
+
+impl std::fmt::Debug for AttrOpaque1 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("AttrOpaque1")
+    }
+}
+
+unsafe impl DiplomatOpaque for AttrOpaque1 {
+    unsafe fn destroy(this: *mut Self) {
+        namespace_AttrOpaque1_destroy(this)
+    }
+}
+
+impl AttrOpaque1 {
+    pub fn new() -> DiplomatBox<AttrOpaque1> {
+        unsafe {
+            namespace_AttrOpaque1_new()
+        }
+    }
+
+    pub fn method<'anon_0>(&'anon_0 self) -> u8 {
+        unsafe {
+            namespace_AttrOpaque1_method(self)
+        }
+    }
+
+    pub fn abirenamed<'anon_0>(&'anon_0 self) -> u8 {
+        unsafe {
+            renamed_on_abi_only(self)
+        }
+    }
+
+    pub fn method_disabledcpp<'anon_0>(&'anon_0 self) {
+        unsafe {
+            namespace_AttrOpaque1_method_disabledcpp(self)
+        }
+    }
+
+    pub fn use_unnamespaced<'anon_0, 'anon_1>(&'anon_0 self, _un: &'anon_1 Unnamespaced) {
+        unsafe {
+            namespace_AttrOpaque1_use_unnamespaced(self, _un)
+        }
+    }
+
+    pub fn use_namespaced<'anon_0>(&'anon_0 self, _n: AttrEnum) {
+        unsafe {
+            namespace_AttrOpaque1_use_namespaced(self, _n)
+        }
+    }
+}
+
+#[link(name = "diplomat_feature_tests")]
+#[allow(improper_ctypes, non_snake_case)]
+extern "C" {
+    fn namespace_AttrOpaque1_destroy(this: *mut AttrOpaque1);
+    fn namespace_AttrOpaque1_new() -> DiplomatBox<AttrOpaque1>;
+    fn namespace_AttrOpaque1_method<'anon_0>(this: &'anon_0 AttrOpaque1) -> u8;
+    fn renamed_on_abi_only<'anon_0>(this: &'anon_0 AttrOpaque1) -> u8;
+    fn namespace_AttrOpaque1_method_disabledcpp<'anon_0>(this: &'anon_0 AttrOpaque1);
+    fn namespace_AttrOpaque1_use_unnamespaced<'anon_0, 'anon_1>(this: &'anon_0 AttrOpaque1, _un: &'anon_1 Unnamespaced);
+    fn namespace_AttrOpaque1_use_namespaced<'anon_0>(this: &'anon_0 AttrOpaque1, _n: AttrEnum);
+}
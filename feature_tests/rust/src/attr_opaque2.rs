@@ -0,0 +1,39 @@
+// generated by diplomat-tool
+
+// The bindings follow the API of the library rather than Rust's conventions
+#![allow(
+    clippy::inherent_to_string,
+    clippy::needless_lifetimes,
+    clippy::new_without_default,
+    clippy::result_unit_err,
+    clippy::should_implement_trait
+)]
+
+#[allow(unused_imports)]
+use super::diplomat_runtime::*;
+#[allow(unused_imports)]
+use super::*;
+
+#[repr(C)]
+pub struct AttrOpaque2 {
+    _data: [u8; 0],
+    _marker: std::marker::PhantomData<(*mut u8, std::marker::PhantomPinned)>,
+}
+
+impl std::fmt::Debug for AttrOpaque2 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("AttrOpaque2")
+    }
+}
+
+unsafe impl DiplomatOpaque for AttrOpaque2 {
+    unsafe fn destroy(this: *mut Self) {
+        namespace_AttrOpaque2_destroy(this)
+    }
+}
+
+#[link(name = "diplomat_feature_tests")]
+#[allow(improper_ctypes, non_snake_case)]
+extern "C" {
+    fn namespace_AttrOpaque2_destroy(this: *mut AttrOpaque2);
+}
@@ -0,0 +1,51 @@
+// generated by diplomat-tool
+
+// The bindings follow the API of the library rather than Rust's conventions
+#![allow(
+    clippy::inherent_to_string,
+    clippy::needless_lifetimes,
+    clippy::new_without_default,
+    clippy::result_unit_err,
+    clippy::should_implement_trait
+)]
+
+#[allow(unused_imports)]
+use super::diplomat_runtime::*;
+#[allow(unused_imports)]
+use super::*;
+
+#[repr(C)]
+pub struct Bar<'b, 'a> {
+    _data: [u8; 0],
+    _marker: std::marker::PhantomData<(&'b (), &'a (), *mut u8, std::marker::PhantomPinned)>,
+}
+
+impl std::fmt::Debug for Bar<'_, '_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("Bar")
+    }
+}
+
+unsafe impl DiplomatOpaque for Bar<'_, '_> {
+    unsafe fn destroy(this: *mut Self) {
+        Bar_destroy(this)
+    }
+}
+
+impl<'b, 'a> Bar<'b, 'a> {
+    pub fn foo(&'b self) -> &'b Foo<'a>
+    where
+        'a: 'b,
+    {
+        unsafe {
+            Bar_foo(self)
+        }
+    }
+}
+
+#[link(name = "diplomat_feature_tests")]
+#[allow(improper_ctypes, non_snake_case)]
+extern "C" {
+    fn Bar_destroy(this: *mut Bar<'_, '_>);
+    fn Bar_foo<'b, 'a>(this: &'b Bar<'b, 'a>) -> &'b Foo<'a>;
+}
@@ -0,0 +1,23 @@
+// generated by diplomat-tool
+
+// The bindings follow the API of the library rather than Rust's conventions
+#![allow(
+    clippy::inherent_to_string,
+    clippy::needless_lifetimes,
+    clippy::new_without_default,
+    clippy::result_unit_err,
+    clippy::should_implement_trait
+)]
+
+#[allow(unused_imports)]
+use super::diplomat_runtime::*;
+#[allow(unused_imports)]
+use super::*;
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct BorrowedFields<'a> {
+    pub a: &'a [u16],
+    pub b: &'a [u8],
+    pub c: &'a str,
+}
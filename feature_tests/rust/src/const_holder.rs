@@ -0,0 +1,52 @@
+// generated by diplomat-tool
+
+// The bindings follow the API of the library rather than Rust's conventions
+#![allow(
+    clippy::inherent_to_string,
+    clippy::needless_lifetimes,
+    clippy::new_without_default,
+    clippy::result_unit_err,
+    clippy::should_implement_trait
+)]
+
+#[allow(unused_imports)]
+use super::diplomat_runtime::*;
+#[allow(unused_imports)]
+use super::*;
+
+#[repr(C)]
+pub struct ConstHolder {
+    _data: [u8; 0],
+    _marker: std::marker::PhantomData<(*mut u8, std::marker::PhantomPinned)>,
+}
+
+impl std::fmt::Debug for ConstHolder {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("ConstHolder")
+    }
+}
+
+unsafe impl DiplomatOpaque for ConstHolder {
+    unsafe fn destroy(this: *mut Self) {
+        ConstHolder_destroy(this)
+    }
+}
+
+impl ConstHolder {
+    pub const MIN_VALUE: i8 = -128;
+
+    pub const SEPARATOR: DiplomatChar = 124;
+
+    pub fn max_len() -> u32 {
+        unsafe {
+            ConstHolder_max_len()
+        }
+    }
+}
+
+#[link(name = "diplomat_feature_tests")]
+#[allow(improper_ctypes, non_snake_case)]
+extern "C" {
+    fn ConstHolder_destroy(this: *mut ConstHolder);
+    fn ConstHolder_max_len() -> u32;
+}
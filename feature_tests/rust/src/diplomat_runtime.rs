@@ -0,0 +1,196 @@
+// generated by diplomat-tool
+
+//! The types the C ABI of the library is made of
+// Not every library uses all of these
+#![allow(dead_code)]
+use std::ffi::c_void;
+use std::fmt;
+use std::mem::{self, ManuallyDrop};
+use std::ops::{Deref, DerefMut};
+use std::ptr::{self, NonNull};
+
+/// A Unicode scalar value as the library passes it, which isn't necessarily a valid `char`
+pub type DiplomatChar = u32;
+
+/// A type of the library that only exists behind a pointer
+///
+/// # Safety
+///
+/// `destroy` must free an object the library allocated.
+pub unsafe trait DiplomatOpaque {
+    #[doc(hidden)]
+    unsafe fn destroy(this: *mut Self);
+}
+
+/// An object of the library that is destroyed by the library when it's dropped
+///
+/// This has the layout of the `Box` the library returns it in.
+#[repr(transparent)]
+pub struct DiplomatBox<T: DiplomatOpaque>(NonNull<T>);
+
+impl<T: DiplomatOpaque> DiplomatBox<T> {
+    /// Take ownership of an object the library allocated
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must point to an object that was returned in a `Box` and isn't owned
+    /// by anything else.
+    pub unsafe fn from_raw(ptr: *mut T) -> Self {
+        DiplomatBox(NonNull::new_unchecked(ptr))
+    }
+
+    /// Give up ownership of the object, which is then no longer destroyed
+    pub fn into_raw(this: Self) -> *mut T {
+        let ptr = this.0.as_ptr();
+        mem::forget(this);
+        ptr
+    }
+}
+
+impl<T: DiplomatOpaque> Deref for DiplomatBox<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { self.0.as_ref() }
+    }
+}
+
+impl<T: DiplomatOpaque> DerefMut for DiplomatBox<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { self.0.as_mut() }
+    }
+}
+
+impl<T: DiplomatOpaque> Drop for DiplomatBox<T> {
+    fn drop(&mut self) {
+        unsafe { T::destroy(self.0.as_ptr()) }
+    }
+}
+
+impl<T: DiplomatOpaque + fmt::Debug> fmt::Debug for DiplomatBox<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+#[repr(C)]
+union DiplomatResultValue<T, E> {
+    ok: ManuallyDrop<T>,
+    err: ManuallyDrop<E>,
+}
+
+/// How the library returns `Result`s, and `Option`s of anything but objects
+#[repr(C)]
+pub(crate) struct DiplomatResult<T, E> {
+    value: DiplomatResultValue<T, E>,
+    is_ok: bool,
+}
+
+impl<T, E> DiplomatResult<T, E> {
+    pub(crate) fn into_result(self) -> Result<T, E> {
+        let DiplomatResult { value, is_ok } = self;
+        unsafe {
+            if is_ok {
+                Ok(ManuallyDrop::into_inner(value.ok))
+            } else {
+                Err(ManuallyDrop::into_inner(value.err))
+            }
+        }
+    }
+}
+
+/// A buffer the library writes strings to, which grows the buffer through the
+/// callbacks it's given
+#[repr(C)]
+pub struct DiplomatWriteable {
+    context: *mut c_void,
+    buf: *mut u8,
+    len: usize,
+    cap: usize,
+    flush: extern "C" fn(*mut DiplomatWriteable),
+    grow: extern "C" fn(*mut DiplomatWriteable, usize) -> bool,
+}
+
+impl DiplomatWriteable {
+    pub(crate) fn new() -> Self {
+        let mut buf = ManuallyDrop::new(Vec::<u8>::new());
+        DiplomatWriteable {
+            context: ptr::null_mut(),
+            buf: buf.as_mut_ptr(),
+            len: 0,
+            cap: buf.capacity(),
+            flush: writeable_flush,
+            grow: writeable_grow,
+        }
+    }
+
+    pub(crate) fn into_string(self) -> String {
+        let this = ManuallyDrop::new(self);
+        let bytes = unsafe { Vec::from_raw_parts(this.buf, this.len, this.cap) };
+        // The library writes through `fmt::Write`, which only writes UTF-8
+        String::from_utf8(bytes).unwrap_or_else(|e| String::from_utf8_lossy(e.as_bytes()).into_owned())
+    }
+}
+
+impl Drop for DiplomatWriteable {
+    fn drop(&mut self) {
+        unsafe { drop(Vec::from_raw_parts(self.buf, self.len, self.cap)) }
+    }
+}
+
+extern "C" fn writeable_flush(_: *mut DiplomatWriteable) {}
+
+extern "C" fn writeable_grow(this: *mut DiplomatWriteable, capacity: usize) -> bool {
+    let this = unsafe { &mut *this };
+    let mut buf = ManuallyDrop::new(unsafe { Vec::from_raw_parts(this.buf, this.len, this.cap) });
+    buf.reserve(capacity.saturating_sub(this.len));
+    this.buf = buf.as_mut_ptr();
+    this.cap = buf.capacity();
+    true
+}
+
+/// Copy a slice into memory the library allocated, for parameters the library
+/// takes ownership of
+pub(crate) fn alloc_slice<T: Copy>(slice: &[T]) -> (*mut T, usize) {
+    if slice.is_empty() {
+        return (NonNull::dangling().as_ptr(), 0);
+    }
+    unsafe {
+        let ptr = diplomat_alloc(mem::size_of_val(slice), mem::align_of::<T>()) as *mut T;
+        assert!(!ptr.is_null(), "the library failed to allocate");
+        ptr.copy_from_nonoverlapping(slice.as_ptr(), slice.len());
+        (ptr, slice.len())
+    }
+}
+
+/// Copy a slice the library returned ownership of, and free it
+pub(crate) unsafe fn take_slice<T: Copy>(slice: *mut [T]) -> Vec<T> {
+    let vec = (*slice).to_vec();
+    if !vec.is_empty() {
+        diplomat_free(slice as *mut u8, mem::size_of_val(&*slice), mem::align_of::<T>());
+    }
+    vec
+}
+
+/// Copy a string the library returned ownership of, and free it
+pub(crate) unsafe fn take_str(slice: *mut str) -> String {
+    String::from_utf8_unchecked(take_slice(slice as *mut [u8]))
+}
+
+/// Panic with the message of the panic the library caught in the last call on this
+/// thread, if there was one
+pub(crate) fn check_panic() {
+    if unsafe { diplomat_panicked() } {
+        let mut message = DiplomatWriteable::new();
+        unsafe { diplomat_take_panic(&mut message) };
+        panic!("{}", message.into_string());
+    }
+}
+
+#[link(name = "diplomat_feature_tests")]
+extern "C" {
+    fn diplomat_alloc(size: usize, align: usize) -> *mut u8;
+    fn diplomat_free(ptr: *mut u8, size: usize, align: usize);
+    fn diplomat_panicked() -> bool;
+    fn diplomat_take_panic(out: *mut DiplomatWriteable);
+}
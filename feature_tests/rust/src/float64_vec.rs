@@ -0,0 +1,141 @@
+// generated by diplomat-tool
+
+// The bindings follow the API of the library rather than Rust's conventions
+#![allow(
+    clippy::inherent_to_string,
+    clippy::needless_lifetimes,
+    clippy::new_without_default,
+    clippy::result_unit_err,
+    clippy::should_implement_trait
+)]
+
+#[allow(unused_imports)]
+use super::diplomat_runtime::*;
+#[allow(unused_imports)]
+use super::*;
+
+#[repr(C)]
+pub struct Float64Vec {
+    _data: [u8; 0],
+    _marker: std::marker::PhantomData<(*mut u8, std::marker::PhantomPinned)>,
+}
+
+impl std::fmt::Debug for Float64Vec {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("Float64Vec")
+    }
+}
+
+unsafe impl DiplomatOpaque for Float64Vec {
+    unsafe fn destroy(this: *mut Self) {
+        Float64Vec_destroy(this)
+    }
+}
+
+impl Float64Vec {
+    pub fn new<'anon_0>(v: &'anon_0 [f64]) -> DiplomatBox<Float64Vec> {
+        unsafe {
+            Float64Vec_new(v.as_ptr(), v.len())
+        }
+    }
+
+    pub fn new_bool<'anon_0>(v: &'anon_0 [bool]) -> DiplomatBox<Float64Vec> {
+        unsafe {
+            Float64Vec_new_bool(v.as_ptr(), v.len())
+        }
+    }
+
+    pub fn new_i16<'anon_0>(v: &'anon_0 [i16]) -> DiplomatBox<Float64Vec> {
+        unsafe {
+            Float64Vec_new_i16(v.as_ptr(), v.len())
+        }
+    }
+
+    pub fn new_u16<'anon_0>(v: &'anon_0 [u16]) -> DiplomatBox<Float64Vec> {
+        unsafe {
+            Float64Vec_new_u16(v.as_ptr(), v.len())
+        }
+    }
+
+    pub fn new_isize<'anon_0>(v: &'anon_0 [isize]) -> DiplomatBox<Float64Vec> {
+        unsafe {
+            Float64Vec_new_isize(v.as_ptr(), v.len())
+        }
+    }
+
+    pub fn new_usize<'anon_0>(v: &'anon_0 [usize]) -> DiplomatBox<Float64Vec> {
+        unsafe {
+            Float64Vec_new_usize(v.as_ptr(), v.len())
+        }
+    }
+
+    pub fn new_f64_be_bytes<'anon_0>(v: &'anon_0 [u8]) -> DiplomatBox<Float64Vec> {
+        unsafe {
+            Float64Vec_new_f64_be_bytes(v.as_ptr(), v.len())
+        }
+    }
+
+    pub fn as_boxed_slice<'anon_0>(&'anon_0 self) -> Vec<f64> {
+        unsafe {
+            take_slice(Float64Vec_as_boxed_slice(self))
+        }
+    }
+
+    pub fn as_slice<'a>(&'a self) -> &'a [f64] {
+        unsafe {
+            Float64Vec_as_slice(self)
+        }
+    }
+
+    pub fn fill_slice<'anon_0, 'anon_1>(&'anon_0 self, v: &'anon_1 mut [f64]) {
+        unsafe {
+            Float64Vec_fill_slice(self, v.as_mut_ptr(), v.len())
+        }
+    }
+
+    pub fn set_value<'anon_0, 'anon_1>(&'anon_0 mut self, new_slice: &'anon_1 [f64]) {
+        unsafe {
+            Float64Vec_set_value(self, new_slice.as_ptr(), new_slice.len())
+        }
+    }
+
+    pub fn to_string<'anon_0>(&'anon_0 self) -> String {
+        unsafe {
+            let mut diplomat_write = DiplomatWriteable::new();
+            Float64Vec_to_string(self, &mut diplomat_write);
+            diplomat_write.into_string()
+        }
+    }
+
+    pub fn borrow<'a>(&'a self) -> &'a [f64] {
+        unsafe {
+            Float64Vec_borrow(self)
+        }
+    }
+
+    pub fn get<'anon_0>(&'anon_0 self, i: usize) -> Option<f64> {
+        unsafe {
+            Float64Vec_get(self, i).into_result().ok()
+        }
+    }
+}
+
+#[link(name = "diplomat_feature_tests")]
+#[allow(improper_ctypes, non_snake_case)]
+extern "C" {
+    fn Float64Vec_destroy(this: *mut Float64Vec);
+    fn Float64Vec_new<'anon_0>(v_diplomat_data: *const f64, v_diplomat_len: usize) -> DiplomatBox<Float64Vec>;
+    fn Float64Vec_new_bool<'anon_0>(v_diplomat_data: *const bool, v_diplomat_len: usize) -> DiplomatBox<Float64Vec>;
+    fn Float64Vec_new_i16<'anon_0>(v_diplomat_data: *const i16, v_diplomat_len: usize) -> DiplomatBox<Float64Vec>;
+    fn Float64Vec_new_u16<'anon_0>(v_diplomat_data: *const u16, v_diplomat_len: usize) -> DiplomatBox<Float64Vec>;
+    fn Float64Vec_new_isize<'anon_0>(v_diplomat_data: *const isize, v_diplomat_len: usize) -> DiplomatBox<Float64Vec>;
+    fn Float64Vec_new_usize<'anon_0>(v_diplomat_data: *const usize, v_diplomat_len: usize) -> DiplomatBox<Float64Vec>;
+    fn Float64Vec_new_f64_be_bytes<'anon_0>(v_diplomat_data: *const u8, v_diplomat_len: usize) -> DiplomatBox<Float64Vec>;
+    fn Float64Vec_as_boxed_slice<'anon_0>(this: &'anon_0 Float64Vec) -> *mut [f64];
+    fn Float64Vec_as_slice<'a>(this: &'a Float64Vec) -> &'a [f64];
+    fn Float64Vec_fill_slice<'anon_0, 'anon_1>(this: &'anon_0 Float64Vec, v_diplomat_data: *mut f64, v_diplomat_len: usize);
+    fn Float64Vec_set_value<'anon_0, 'anon_1>(this: &'anon_0 mut Float64Vec, new_slice_diplomat_data: *const f64, new_slice_diplomat_len: usize);
+    fn Float64Vec_to_string<'anon_0>(this: &'anon_0 Float64Vec, write: &mut DiplomatWriteable);
+    fn Float64Vec_borrow<'a>(this: &'a Float64Vec) -> &'a [f64];
+    fn Float64Vec_get<'anon_0>(this: &'anon_0 Float64Vec, i: usize) -> DiplomatResult<f64, ()>;
+}
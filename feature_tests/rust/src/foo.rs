@@ -0,0 +1,96 @@
+// generated by diplomat-tool
+
+// The bindings follow the API of the library rather than Rust's conventions
+#![allow(
+    clippy::inherent_to_string,
+    clippy::needless_lifetimes,
+    clippy::new_without_default,
+    clippy::result_unit_err,
+    clippy::should_implement_trait
+)]
+
+#[allow(unused_imports)]
+use super::diplomat_runtime::*;
+#[allow(unused_imports)]
+use super::*;
+
+#[repr(C)]
+pub struct Foo<'a> {
+    _data: [u8; 0],
+    _marker: std::marker::PhantomData<(&'a (), *mut u8, std::marker::PhantomPinned)>,
+}
+
+impl std::fmt::Debug for Foo<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("Foo")
+    }
+}
+
+unsafe impl DiplomatOpaque for Foo<'_> {
+    unsafe fn destroy(this: *mut Self) {
+        Foo_destroy(this)
+    }
+}
+
+impl Foo<'_> {
+    pub fn new<'a>(x: &'a [u8]) -> DiplomatBox<Foo<'a>> {
+        unsafe {
+            Foo_new(x.as_ptr(), x.len())
+        }
+    }
+
+    pub fn new_static<'a>(x: &'static [u8]) -> DiplomatBox<Foo<'a>> {
+        unsafe {
+            Foo_new_static(x.as_ptr(), x.len())
+        }
+    }
+
+    pub fn extract_from_fields<'a>(fields: BorrowedFields<'a>) -> DiplomatBox<Foo<'a>> {
+        unsafe {
+            Foo_extract_from_fields(fields)
+        }
+    }
+
+    /// Test that the extraction logic correctly pins the right fields
+    pub fn extract_from_bounds<'a, 'x, 'y, 'z>(bounds: BorrowedFieldsWithBounds<'x, 'y, 'z>, another_string: &'a [u8]) -> DiplomatBox<Foo<'a>>
+    where
+        'y: 'a,
+        'y: 'x,
+        'z: 'y,
+        'z: 'a,
+        'z: 'x,
+    {
+        unsafe {
+            Foo_extract_from_bounds(bounds, another_string.as_ptr(), another_string.len())
+        }
+    }
+}
+
+impl<'a> Foo<'a> {
+    pub fn get_bar<'b>(&'b self) -> DiplomatBox<Bar<'b, 'a>>
+    where
+        'a: 'b,
+    {
+        unsafe {
+            Foo_get_bar(self)
+        }
+    }
+
+    pub fn as_returning<'anon_0>(&'anon_0 self) -> BorrowedFieldsReturning<'a> {
+        unsafe {
+            Foo_as_returning(self)
+        }
+    }
+}
+
+#[link(name = "diplomat_feature_tests")]
+#[allow(improper_ctypes, non_snake_case)]
+extern "C" {
+    fn Foo_destroy(this: *mut Foo<'_>);
+    fn Foo_new<'a>(x_diplomat_data: *const u8, x_diplomat_len: usize) -> DiplomatBox<Foo<'a>>;
+    fn Foo_get_bar<'a, 'b>(this: &'b Foo<'a>) -> DiplomatBox<Bar<'b, 'a>>;
+    fn Foo_new_static<'a>(x_diplomat_data: *const u8, x_diplomat_len: usize) -> DiplomatBox<Foo<'a>>;
+    fn Foo_as_returning<'a, 'anon_0>(this: &'anon_0 Foo<'a>) -> BorrowedFieldsReturning<'a>;
+    fn Foo_extract_from_fields<'a>(fields: BorrowedFields<'a>) -> DiplomatBox<Foo<'a>>;
+    fn Foo_extract_from_bounds<'a, 'x, 'y, 'z>(bounds: BorrowedFieldsWithBounds<'x, 'y, 'z>, another_string_diplomat_data: *const u8, another_string_diplomat_len: usize) -> DiplomatBox<Foo<'a>>;
+}
@@ -0,0 +1,80 @@
+// generated by diplomat-tool
+
+mod diplomat_runtime;
+mod attr_enum;
+mod attr_opaque1;
+mod attr_opaque2;
+mod bar;
+mod borrowed_fields;
+mod borrowed_fields_returning;
+mod borrowed_fields_with_bounds;
+mod const_holder;
+mod contiguous_enum;
+mod error_enum;
+mod error_struct;
+mod float64_vec;
+mod foo;
+mod imported_struct;
+mod my_enum;
+mod my_string;
+mod my_struct;
+mod nested_borrowed_fields;
+mod one;
+mod opaque;
+mod option_opaque;
+mod option_opaque_char;
+mod option_struct;
+mod panic_error;
+mod panic_opaque;
+mod ref_list;
+mod ref_list_parameter;
+mod result_opaque;
+mod shape;
+mod shape_list;
+mod two;
+mod unimported_enum;
+mod unnamespaced;
+
+pub use diplomat_runtime::{DiplomatBox, DiplomatChar, DiplomatOpaque};
+pub use attr_enum::AttrEnum;
+pub use attr_opaque1::AttrOpaque1;
+pub use attr_opaque2::AttrOpaque2;
+pub use bar::Bar;
+pub use borrowed_fields::BorrowedFields;
+pub use borrowed_fields_returning::BorrowedFieldsReturning;
+pub use borrowed_fields_with_bounds::BorrowedFieldsWithBounds;
+pub use const_holder::ConstHolder;
+pub use contiguous_enum::ContiguousEnum;
+pub use error_enum::ErrorEnum;
+pub use error_struct::ErrorStruct;
+pub use float64_vec::Float64Vec;
+pub use foo::Foo;
+pub use imported_struct::ImportedStruct;
+pub use my_enum::MyEnum;
+pub use my_string::MyString;
+pub use my_struct::MyStruct;
+pub use nested_borrowed_fields::NestedBorrowedFields;
+pub use one::One;
+pub use opaque::Opaque;
+pub use option_opaque::OptionOpaque;
+pub use option_opaque_char::OptionOpaqueChar;
+pub use option_struct::OptionStruct;
+pub use panic_error::PanicError;
+pub use panic_opaque::PanicOpaque;
+pub use ref_list::RefList;
+pub use ref_list_parameter::RefListParameter;
+pub use result_opaque::ResultOpaque;
+pub use shape::Shape;
+pub use shape_list::ShapeList;
+pub use two::Two;
+pub use unimported_enum::UnimportedEnum;
+pub use unnamespaced::Unnamespaced;
+
+pub const ENABLED: bool = true;
+
+pub const GREETING: &str = "hello";
+
+/// The length `ConstHolder::max_len` returns
+pub const MAX_LEN: u32 = 16;
+
+pub const RATIO: f64 = 0.5;
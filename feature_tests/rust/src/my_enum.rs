@@ -0,0 +1,40 @@
+// generated by diplomat-tool
+
+// The bindings follow the API of the library rather than Rust's conventions
+#![allow(
+    clippy::inherent_to_string,
+    clippy::needless_lifetimes,
+    clippy::new_without_default,
+    clippy::result_unit_err,
+    clippy::should_implement_trait
+)]
+
+#[allow(unused_imports)]
+use super::diplomat_runtime::*;
+#[allow(unused_imports)]
+use super::*;
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MyEnum {
+    A = -2,
+    B = -1,
+    C = 0,
+    D = 1,
+    E = 2,
+    F = 3,
+}
+
+impl MyEnum {
+    pub fn into_value(self) -> i8 {
+        unsafe {
+            MyEnum_into_value(self)
+        }
+    }
+}
+
+#[link(name = "diplomat_feature_tests")]
+#[allow(improper_ctypes, non_snake_case)]
+extern "C" {
+    fn MyEnum_into_value(this: MyEnum) -> i8;
+}
@@ -0,0 +1,86 @@
+// generated by diplomat-tool
+
+// The bindings follow the API of the library rather than Rust's conventions
+#![allow(
+    clippy::inherent_to_string,
+    clippy::needless_lifetimes,
+    clippy::new_without_default,
+    clippy::result_unit_err,
+    clippy::should_implement_trait
+)]
+
+#[allow(unused_imports)]
+use super::diplomat_runtime::*;
+#[allow(unused_imports)]
+use super::*;
+
+#[repr(C)]
+pub struct MyString {
+    _data: [u8; 0],
+    _marker: std::marker::PhantomData<(*mut u8, std::marker::PhantomPinned)>,
+}
+
+impl std::fmt::Debug for MyString {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("MyString")
+    }
+}
+
+unsafe impl DiplomatOpaque for MyString {
+    unsafe fn destroy(this: *mut Self) {
+        MyString_destroy(this)
+    }
+}
+
+impl MyString {
+    pub fn new<'anon_0>(v: &'anon_0 [u8]) -> DiplomatBox<MyString> {
+        unsafe {
+            MyString_new(v.as_ptr(), v.len())
+        }
+    }
+
+    pub fn new_unsafe<'anon_0>(v: &'anon_0 str) -> DiplomatBox<MyString> {
+        unsafe {
+            MyString_new_unsafe(v.as_ptr(), v.len())
+        }
+    }
+
+    pub fn new_owned(v: &[u8]) -> DiplomatBox<MyString> {
+        unsafe {
+            let (v_diplomat_data, v_diplomat_len) = alloc_slice(v);
+            MyString_new_owned(v_diplomat_data, v_diplomat_len)
+        }
+    }
+
+    pub fn new_from_first(v: &[&[u8]]) -> DiplomatBox<MyString> {
+        unsafe {
+            MyString_new_from_first(v.as_ptr(), v.len())
+        }
+    }
+
+    pub fn set_str<'anon_0, 'anon_1>(&'anon_0 mut self, new_str: &'anon_1 [u8]) {
+        unsafe {
+            MyString_set_str(self, new_str.as_ptr(), new_str.len())
+        }
+    }
+
+    pub fn get_str<'anon_0>(&'anon_0 self) -> String {
+        unsafe {
+            let mut diplomat_write = DiplomatWriteable::new();
+            MyString_get_str(self, &mut diplomat_write);
+            diplomat_write.into_string()
+        }
+    }
+}
+
+#[link(name = "diplomat_feature_tests")]
+#[allow(improper_ctypes, non_snake_case)]
+extern "C" {
+    fn MyString_destroy(this: *mut MyString);
+    fn MyString_new<'anon_0>(v_diplomat_data: *const u8, v_diplomat_len: usize) -> DiplomatBox<MyString>;
+    fn MyString_new_unsafe<'anon_0>(v_diplomat_data: *const u8, v_diplomat_len: usize) -> DiplomatBox<MyString>;
+    fn MyString_new_owned(v_diplomat_data: *mut u8, v_diplomat_len: usize) -> DiplomatBox<MyString>;
+    fn MyString_new_from_first(v_diplomat_data: *const &[u8], v_diplomat_len: usize) -> DiplomatBox<MyString>;
+    fn MyString_set_str<'anon_0, 'anon_1>(this: &'anon_0 mut MyString, new_str_diplomat_data: *const u8, new_str_diplomat_len: usize);
+    fn MyString_get_str<'anon_0>(this: &'anon_0 MyString, write: &mut DiplomatWriteable);
+}
@@ -0,0 +1,48 @@
+// generated by diplomat-tool
+
+// The bindings follow the API of the library rather than Rust's conventions
+#![allow(
+    clippy::inherent_to_string,
+    clippy::needless_lifetimes,
+    clippy::new_without_default,
+    clippy::result_unit_err,
+    clippy::should_implement_trait
+)]
+
+#[allow(unused_imports)]
+use super::diplomat_runtime::*;
+#[allow(unused_imports)]
+use super::*;
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct MyStruct {
+    pub a: u8,
+    pub b: bool,
+    pub c: u8,
+    pub d: u64,
+    pub e: i32,
+    pub f: DiplomatChar,
+    pub g: MyEnum,
+}
+
+impl MyStruct {
+    pub fn new() -> MyStruct {
+        unsafe {
+            MyStruct_new()
+        }
+    }
+
+    pub fn into_a(self) -> u8 {
+        unsafe {
+            MyStruct_into_a(self)
+        }
+    }
+}
+
+#[link(name = "diplomat_feature_tests")]
+#[allow(improper_ctypes, non_snake_case)]
+extern "C" {
+    fn MyStruct_new() -> MyStruct;
+    fn MyStruct_into_a(this: MyStruct) -> u8;
+}
@@ -0,0 +1,214 @@
+// generated by diplomat-tool
+
+// The bindings follow the API of the library rather than Rust's conventions
+#![allow(
+    clippy::inherent_to_string,
+    clippy::needless_lifetimes,
+    clippy::new_without_default,
+    clippy::result_unit_err,
+    clippy::should_implement_trait
+)]
+
+#[allow(unused_imports)]
+use super::diplomat_runtime::*;
+#[allow(unused_imports)]
+use super::*;
+
+#[repr(C)]
+pub struct One<'a> {
+    _data: [u8; 0],
+    _marker: std::marker::PhantomData<(&'a (), *mut u8, std::marker::PhantomPinned)>,
+}
+
+impl std::fmt::Debug for One<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("One")
+    }
+}
+
+unsafe impl DiplomatOpaque for One<'_> {
+    unsafe fn destroy(this: *mut Self) {
+        One_destroy(this)
+    }
+}
+
+impl One<'_> {
+    pub fn transitivity<'o, 'a, 'b, 'c, 'd, 'e, 'x, 'anon_0>(hold: &'x One<'e>, nohold: &'anon_0 One<'x>) -> DiplomatBox<One<'a>>
+    where
+        'b: 'a,
+        'c: 'b,
+        'c: 'a,
+        'd: 'c,
+        'd: 'b,
+        'd: 'a,
+        'e: 'x,
+        'e: 'd,
+        'e: 'c,
+        'e: 'b,
+        'e: 'a,
+    {
+        unsafe {
+            One_transitivity(hold, nohold)
+        }
+    }
+
+    pub fn cycle<'o, 'a, 'b, 'c, 'x, 'anon_0>(hold: &'anon_0 Two<'x, 'b>, nohold: &'x One<'x>) -> DiplomatBox<One<'a>>
+    where
+        'a: 'b,
+        'a: 'c,
+        'b: 'c,
+        'b: 'a,
+        'c: 'a,
+        'c: 'b,
+    {
+        unsafe {
+            One_cycle(hold, nohold)
+        }
+    }
+
+    pub fn many_dependents<'o, 'a, 'b, 'c, 'd, 'x, 'y, 'anon_0>(a: &'x One<'a>, b: &'b One<'a>, c: &'anon_0 Two<'x, 'c>, d: &'x Two<'d, 'y>, nohold: &'x Two<'x, 'y>) -> DiplomatBox<One<'a>>
+    where
+        'a: 'b,
+        'a: 'x,
+        'b: 'a,
+        'b: 'x,
+        'c: 'a,
+        'c: 'b,
+        'c: 'x,
+        'd: 'x,
+        'd: 'b,
+        'd: 'a,
+        'y: 'x,
+    {
+        unsafe {
+            One_many_dependents(a, b, c, d, nohold)
+        }
+    }
+
+    pub fn return_outlives_param<'o, 'short, 'long, 'anon_0>(hold: &'anon_0 Two<'long, 'short>, nohold: &'short One<'short>) -> DiplomatBox<One<'long>>
+    where
+        'long: 'short,
+    {
+        unsafe {
+            One_return_outlives_param(hold, nohold)
+        }
+    }
+
+    pub fn diamond_top<'o, 'top, 'left, 'right, 'bottom, 'anon_0, 'anon_1, 'anon_2, 'anon_3>(top: &'anon_0 One<'top>, left: &'anon_1 One<'left>, right: &'anon_2 One<'right>, bottom: &'anon_3 One<'bottom>) -> DiplomatBox<One<'top>>
+    where
+        'left: 'top,
+        'right: 'top,
+        'bottom: 'right,
+        'bottom: 'top,
+        'bottom: 'left,
+    {
+        unsafe {
+            One_diamond_top(top, left, right, bottom)
+        }
+    }
+
+    pub fn diamond_left<'o, 'top, 'left, 'right, 'bottom, 'anon_0, 'anon_1, 'anon_2, 'anon_3>(top: &'anon_0 One<'top>, left: &'anon_1 One<'left>, right: &'anon_2 One<'right>, bottom: &'anon_3 One<'bottom>) -> DiplomatBox<One<'left>>
+    where
+        'left: 'top,
+        'right: 'top,
+        'bottom: 'right,
+        'bottom: 'top,
+        'bottom: 'left,
+    {
+        unsafe {
+            One_diamond_left(top, left, right, bottom)
+        }
+    }
+
+    pub fn diamond_right<'o, 'top, 'left, 'right, 'bottom, 'anon_0, 'anon_1, 'anon_2, 'anon_3>(top: &'anon_0 One<'top>, left: &'anon_1 One<'left>, right: &'anon_2 One<'right>, bottom: &'anon_3 One<'bottom>) -> DiplomatBox<One<'right>>
+    where
+        'left: 'top,
+        'right: 'top,
+        'bottom: 'right,
+        'bottom: 'top,
+        'bottom: 'left,
+    {
+        unsafe {
+            One_diamond_right(top, left, right, bottom)
+        }
+    }
+
+    pub fn diamond_bottom<'o, 'top, 'left, 'right, 'bottom, 'anon_0, 'anon_1, 'anon_2, 'anon_3>(top: &'anon_0 One<'top>, left: &'anon_1 One<'left>, right: &'anon_2 One<'right>, bottom: &'anon_3 One<'bottom>) -> DiplomatBox<One<'bottom>>
+    where
+        'left: 'top,
+        'right: 'top,
+        'bottom: 'right,
+        'bottom: 'top,
+        'bottom: 'left,
+    {
+        unsafe {
+            One_diamond_bottom(top, left, right, bottom)
+        }
+    }
+
+    pub fn diamond_and_nested_types<'o, 'a, 'b, 'c, 'd, 'x, 'y, 'anon_0, 'anon_1, 'anon_2, 'anon_3>(a: &'anon_0 One<'a>, b: &'y One<'b>, c: &'anon_1 One<'c>, d: &'anon_2 One<'d>, nohold: &'anon_3 One<'x>) -> DiplomatBox<One<'a>>
+    where
+        'b: 'y,
+        'b: 'a,
+        'c: 'b,
+        'c: 'y,
+        'c: 'a,
+        'd: 'c,
+        'd: 'b,
+        'd: 'y,
+        'd: 'a,
+    {
+        unsafe {
+            One_diamond_and_nested_types(a, b, c, d, nohold)
+        }
+    }
+
+    pub fn implicit_bounds<'o, 'a, 'b, 'c, 'd, 'x, 'y, 'anon_0, 'anon_1>(explicit_hold: &'d One<'x>, implicit_hold: &'anon_0 One<'x>, nohold: &'anon_1 One<'y>) -> DiplomatBox<One<'a>>
+    where
+        'b: 'a,
+        'c: 'b,
+        'c: 'a,
+        'd: 'c,
+        'd: 'b,
+        'd: 'a,
+        'x: 'd,
+        'x: 'c,
+        'x: 'b,
+        'x: 'a,
+    {
+        unsafe {
+            One_implicit_bounds(explicit_hold, implicit_hold, nohold)
+        }
+    }
+
+    pub fn implicit_bounds_deep<'o, 'a, 'b, 'c, 'd, 'x>(explicit_: &'a One<'b>, implicit_1: &'b One<'c>, implicit_2: &'c One<'d>, nohold: &'x One<'x>) -> DiplomatBox<One<'a>>
+    where
+        'b: 'a,
+        'c: 'b,
+        'c: 'a,
+        'd: 'c,
+        'd: 'b,
+        'd: 'a,
+    {
+        unsafe {
+            One_implicit_bounds_deep(explicit_, implicit_1, implicit_2, nohold)
+        }
+    }
+}
+
+#[link(name = "diplomat_feature_tests")]
+#[allow(improper_ctypes, non_snake_case)]
+extern "C" {
+    fn One_destroy(this: *mut One<'_>);
+    fn One_transitivity<'o, 'a, 'b, 'c, 'd, 'e, 'x, 'anon_0>(hold: &'x One<'e>, nohold: &'anon_0 One<'x>) -> DiplomatBox<One<'a>>;
+    fn One_cycle<'o, 'a, 'b, 'c, 'x, 'anon_0>(hold: &'anon_0 Two<'x, 'b>, nohold: &'x One<'x>) -> DiplomatBox<One<'a>>;
+    fn One_many_dependents<'o, 'a, 'b, 'c, 'd, 'x, 'y, 'anon_0>(a: &'x One<'a>, b: &'b One<'a>, c: &'anon_0 Two<'x, 'c>, d: &'x Two<'d, 'y>, nohold: &'x Two<'x, 'y>) -> DiplomatBox<One<'a>>;
+    fn One_return_outlives_param<'o, 'short, 'long, 'anon_0>(hold: &'anon_0 Two<'long, 'short>, nohold: &'short One<'short>) -> DiplomatBox<One<'long>>;
+    fn One_diamond_top<'o, 'top, 'left, 'right, 'bottom, 'anon_0, 'anon_1, 'anon_2, 'anon_3>(top: &'anon_0 One<'top>, left: &'anon_1 One<'left>, right: &'anon_2 One<'right>, bottom: &'anon_3 One<'bottom>) -> DiplomatBox<One<'top>>;
+    fn One_diamond_left<'o, 'top, 'left, 'right, 'bottom, 'anon_0, 'anon_1, 'anon_2, 'anon_3>(top: &'anon_0 One<'top>, left: &'anon_1 One<'left>, right: &'anon_2 One<'right>, bottom: &'anon_3 One<'bottom>) -> DiplomatBox<One<'left>>;
+    fn One_diamond_right<'o, 'top, 'left, 'right, 'bottom, 'anon_0, 'anon_1, 'anon_2, 'anon_3>(top: &'anon_0 One<'top>, left: &'anon_1 One<'left>, right: &'anon_2 One<'right>, bottom: &'anon_3 One<'bottom>) -> DiplomatBox<One<'right>>;
+    fn One_diamond_bottom<'o, 'top, 'left, 'right, 'bottom, 'anon_0, 'anon_1, 'anon_2, 'anon_3>(top: &'anon_0 One<'top>, left: &'anon_1 One<'left>, right: &'anon_2 One<'right>, bottom: &'anon_3 One<'bottom>) -> DiplomatBox<One<'bottom>>;
+    fn One_diamond_and_nested_types<'o, 'a, 'b, 'c, 'd, 'x, 'y, 'anon_0, 'anon_1, 'anon_2, 'anon_3>(a: &'anon_0 One<'a>, b: &'y One<'b>, c: &'anon_1 One<'c>, d: &'anon_2 One<'d>, nohold: &'anon_3 One<'x>) -> DiplomatBox<One<'a>>;
+    fn One_implicit_bounds<'o, 'a, 'b, 'c, 'd, 'x, 'y, 'anon_0, 'anon_1>(explicit_hold: &'d One<'x>, implicit_hold: &'anon_0 One<'x>, nohold: &'anon_1 One<'y>) -> DiplomatBox<One<'a>>;
+    fn One_implicit_bounds_deep<'o, 'a, 'b, 'c, 'd, 'x>(explicit_: &'a One<'b>, implicit_1: &'b One<'c>, implicit_2: &'c One<'d>, nohold: &'x One<'x>) -> DiplomatBox<One<'a>>;
+}
@@ -0,0 +1,74 @@
+// generated by diplomat-tool
+
+// The bindings follow the API of the library rather than Rust's conventions
+#![allow(
+    clippy::inherent_to_string,
+    clippy::needless_lifetimes,
+    clippy::new_without_default,
+    clippy::result_unit_err,
+    clippy::should_implement_trait
+)]
+
+#[allow(unused_imports)]
+use super::diplomat_runtime::*;
+#[allow(unused_imports)]
+use super::*;
+
+#[repr(C)]
+pub struct Opaque {
+    _data: [u8; 0],
+    _marker: std::marker::PhantomData<(*mut u8, std::marker::PhantomPinned)>,
+}
+
+impl std::fmt::Debug for Opaque {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("Opaque")
+    }
+}
+
+unsafe impl DiplomatOpaque for Opaque {
+    unsafe fn destroy(this: *mut Self) {
+        Opaque_destroy(this)
+    }
+}
+
+impl Opaque {
+    pub fn new() -> DiplomatBox<Opaque> {
+        unsafe {
+            Opaque_new()
+        }
+    }
+
+    /// See the [Rust documentation for `something`](https://docs.rs/Something/latest/struct.Something.html#method.something) for more information.
+    ///
+    /// See the [Rust documentation for `something_else`](https://docs.rs/Something/latest/struct.Something.html#method.something_else) for more information.
+    ///
+    /// Additional information: [1](https://docs.rs/Something/latest/struct.Something.html#method.something_small), [2](https://docs.rs/SomethingElse/latest/struct.SomethingElse.html#method.something)
+    pub fn assert_struct<'anon_0>(&'anon_0 self, s: MyStruct) {
+        unsafe {
+            Opaque_assert_struct(self, s)
+        }
+    }
+
+    pub fn returns_usize() -> usize {
+        unsafe {
+            Opaque_returns_usize()
+        }
+    }
+
+    pub fn returns_imported() -> ImportedStruct {
+        unsafe {
+            Opaque_returns_imported()
+        }
+    }
+}
+
+#[link(name = "diplomat_feature_tests")]
+#[allow(improper_ctypes, non_snake_case)]
+extern "C" {
+    fn Opaque_destroy(this: *mut Opaque);
+    fn Opaque_new() -> DiplomatBox<Opaque>;
+    fn Opaque_assert_struct<'anon_0>(this: &'anon_0 Opaque, s: MyStruct);
+    fn Opaque_returns_usize() -> usize;
+    fn Opaque_returns_imported() -> ImportedStruct;
+}
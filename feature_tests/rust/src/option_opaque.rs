@@ -0,0 +1,90 @@
+// generated by diplomat-tool
+
+// The bindings follow the API of the library rather than Rust's conventions
+#![allow(
+    clippy::inherent_to_string,
+    clippy::needless_lifetimes,
+    clippy::new_without_default,
+    clippy::result_unit_err,
+    clippy::should_implement_trait
+)]
+
+#[allow(unused_imports)]
+use super::diplomat_runtime::*;
+#[allow(unused_imports)]
+use super::*;
+
+#[repr(C)]
+pub struct OptionOpaque {
+    _data: [u8; 0],
+    _marker: std::marker::PhantomData<(*mut u8, std::marker::PhantomPinned)>,
+}
+
+impl std::fmt::Debug for OptionOpaque {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("OptionOpaque")
+    }
+}
+
+unsafe impl DiplomatOpaque for OptionOpaque {
+    unsafe fn destroy(this: *mut Self) {
+        OptionOpaque_destroy(this)
+    }
+}
+
+impl OptionOpaque {
+    pub fn new(i: i32) -> Option<DiplomatBox<OptionOpaque>> {
+        unsafe {
+            OptionOpaque_new(i)
+        }
+    }
+
+    pub fn new_none() -> Option<DiplomatBox<OptionOpaque>> {
+        unsafe {
+            OptionOpaque_new_none()
+        }
+    }
+
+    pub fn returns() -> Option<OptionStruct> {
+        unsafe {
+            OptionOpaque_returns().into_result().ok()
+        }
+    }
+
+    pub fn new_struct() -> OptionStruct {
+        unsafe {
+            OptionOpaque_new_struct()
+        }
+    }
+
+    pub fn new_struct_nones() -> OptionStruct {
+        unsafe {
+            OptionOpaque_new_struct_nones()
+        }
+    }
+
+    pub fn assert_integer<'anon_0>(&'anon_0 self, i: i32) {
+        unsafe {
+            OptionOpaque_assert_integer(self, i)
+        }
+    }
+
+    pub fn option_opaque_argument<'anon_0>(arg: Option<&'anon_0 OptionOpaque>) -> bool {
+        unsafe {
+            OptionOpaque_option_opaque_argument(arg)
+        }
+    }
+}
+
+#[link(name = "diplomat_feature_tests")]
+#[allow(improper_ctypes, non_snake_case)]
+extern "C" {
+    fn OptionOpaque_destroy(this: *mut OptionOpaque);
+    fn OptionOpaque_new(i: i32) -> Option<DiplomatBox<OptionOpaque>>;
+    fn OptionOpaque_new_none() -> Option<DiplomatBox<OptionOpaque>>;
+    fn OptionOpaque_returns() -> DiplomatResult<OptionStruct, ()>;
+    fn OptionOpaque_new_struct() -> OptionStruct;
+    fn OptionOpaque_new_struct_nones() -> OptionStruct;
+    fn OptionOpaque_assert_integer<'anon_0>(this: &'anon_0 OptionOpaque, i: i32);
+    fn OptionOpaque_option_opaque_argument<'anon_0>(arg: Option<&'anon_0 OptionOpaque>) -> bool;
+}
@@ -0,0 +1,48 @@
+// generated by diplomat-tool
+
+// The bindings follow the API of the library rather than Rust's conventions
+#![allow(
+    clippy::inherent_to_string,
+    clippy::needless_lifetimes,
+    clippy::new_without_default,
+    clippy::result_unit_err,
+    clippy::should_implement_trait
+)]
+
+#[allow(unused_imports)]
+use super::diplomat_runtime::*;
+#[allow(unused_imports)]
+use super::*;
+
+#[repr(C)]
+pub struct OptionOpaqueChar {
+    _data: [u8; 0],
+    _marker: std::marker::PhantomData<(*mut u8, std::marker::PhantomPinned)>,
+}
+
+impl std::fmt::Debug for OptionOpaqueChar {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("OptionOpaqueChar")
+    }
+}
+
+unsafe impl DiplomatOpaque for OptionOpaqueChar {
+    unsafe fn destroy(this: *mut Self) {
+        OptionOpaqueChar_destroy(this)
+    }
+}
+
+impl OptionOpaqueChar {
+    pub fn assert_char<'anon_0>(&'anon_0 self, ch: DiplomatChar) {
+        unsafe {
+            OptionOpaqueChar_assert_char(self, ch)
+        }
+    }
+}
+
+#[link(name = "diplomat_feature_tests")]
+#[allow(improper_ctypes, non_snake_case)]
+extern "C" {
+    fn OptionOpaqueChar_destroy(this: *mut OptionOpaqueChar);
+    fn OptionOpaqueChar_assert_char<'anon_0>(this: &'anon_0 OptionOpaqueChar, ch: DiplomatChar);
+}
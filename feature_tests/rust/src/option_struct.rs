@@ -0,0 +1,24 @@
+// generated by diplomat-tool
+
+// The bindings follow the API of the library rather than Rust's conventions
+#![allow(
+    clippy::inherent_to_string,
+    clippy::needless_lifetimes,
+    clippy::new_without_default,
+    clippy::result_unit_err,
+    clippy::should_implement_trait
+)]
+
+#[allow(unused_imports)]
+use super::diplomat_runtime::*;
+#[allow(unused_imports)]
+use super::*;
+
+#[repr(C)]
+#[derive(Debug)]
+pub struct OptionStruct {
+    pub a: Option<DiplomatBox<OptionOpaque>>,
+    pub b: Option<DiplomatBox<OptionOpaqueChar>>,
+    pub c: u32,
+    pub d: Option<DiplomatBox<OptionOpaque>>,
+}
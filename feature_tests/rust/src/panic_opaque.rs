@@ -0,0 +1,67 @@
+// generated by diplomat-tool
+
+// The bindings follow the API of the library rather than Rust's conventions
+#![allow(
+    clippy::inherent_to_string,
+    clippy::needless_lifetimes,
+    clippy::new_without_default,
+    clippy::result_unit_err,
+    clippy::should_implement_trait
+)]
+
+#[allow(unused_imports)]
+use super::diplomat_runtime::*;
+#[allow(unused_imports)]
+use super::*;
+
+#[repr(C)]
+pub struct PanicOpaque {
+    _data: [u8; 0],
+    _marker: std::marker::PhantomData<(*mut u8, std::marker::PhantomPinned)>,
+}
+
+impl std::fmt::Debug for PanicOpaque {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("PanicOpaque")
+    }
+}
+
+unsafe impl DiplomatOpaque for PanicOpaque {
+    unsafe fn destroy(this: *mut Self) {
+        PanicOpaque_destroy(this)
+    }
+}
+
+impl PanicOpaque {
+    /// Panics, which foreign code is told about after the call
+    pub fn panics<'anon_0>(message: &'anon_0 [u8]) {
+        unsafe {
+            PanicOpaque_panics(message.as_ptr(), message.len());
+            check_panic()
+        }
+    }
+
+    /// Panics when `x` is zero, which is reported as `PanicError::Panicked`
+    pub fn checked_divide(x: u32) -> Result<u32, PanicError> {
+        unsafe {
+            PanicOpaque_checked_divide(x).into_result()
+        }
+    }
+
+    pub fn not_panicking(x: u32) -> u32 {
+        unsafe {
+            let diplomat_ret = PanicOpaque_not_panicking(x);
+            check_panic();
+            diplomat_ret
+        }
+    }
+}
+
+#[link(name = "diplomat_feature_tests")]
+#[allow(improper_ctypes, non_snake_case)]
+extern "C" {
+    fn PanicOpaque_destroy(this: *mut PanicOpaque);
+    fn PanicOpaque_panics<'anon_0>(message_diplomat_data: *const u8, message_diplomat_len: usize);
+    fn PanicOpaque_checked_divide(x: u32) -> DiplomatResult<u32, PanicError>;
+    fn PanicOpaque_not_panicking(x: u32) -> u32;
+}
@@ -0,0 +1,48 @@
+// generated by diplomat-tool
+
+// The bindings follow the API of the library rather than Rust's conventions
+#![allow(
+    clippy::inherent_to_string,
+    clippy::needless_lifetimes,
+    clippy::new_without_default,
+    clippy::result_unit_err,
+    clippy::should_implement_trait
+)]
+
+#[allow(unused_imports)]
+use super::diplomat_runtime::*;
+#[allow(unused_imports)]
+use super::*;
+
+#[repr(C)]
+pub struct RefList<'a> {
+    _data: [u8; 0],
+    _marker: std::marker::PhantomData<(&'a (), *mut u8, std::marker::PhantomPinned)>,
+}
+
+impl std::fmt::Debug for RefList<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("RefList")
+    }
+}
+
+unsafe impl DiplomatOpaque for RefList<'_> {
+    unsafe fn destroy(this: *mut Self) {
+        RefList_destroy(this)
+    }
+}
+
+impl RefList<'_> {
+    pub fn node<'b>(data: &'b RefListParameter) -> DiplomatBox<RefList<'b>> {
+        unsafe {
+            RefList_node(data)
+        }
+    }
+}
+
+#[link(name = "diplomat_feature_tests")]
+#[allow(improper_ctypes, non_snake_case)]
+extern "C" {
+    fn RefList_destroy(this: *mut RefList<'_>);
+    fn RefList_node<'b>(data: &'b RefListParameter) -> DiplomatBox<RefList<'b>>;
+}
@@ -0,0 +1,104 @@
+// generated by diplomat-tool
+
+// The bindings follow the API of the library rather than Rust's conventions
+#![allow(
+    clippy::inherent_to_string,
+    clippy::needless_lifetimes,
+    clippy::new_without_default,
+    clippy::result_unit_err,
+    clippy::should_implement_trait
+)]
+
+#[allow(unused_imports)]
+use super::diplomat_runtime::*;
+#[allow(unused_imports)]
+use super::*;
+
+#[repr(C)]
+pub struct ResultOpaque {
+    _data: [u8; 0],
+    _marker: std::marker::PhantomData<(*mut u8, std::marker::PhantomPinned)>,
+}
+
+impl std::fmt::Debug for ResultOpaque {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("ResultOpaque")
+    }
+}
+
+unsafe impl DiplomatOpaque for ResultOpaque {
+    unsafe fn destroy(this: *mut Self) {
+        ResultOpaque_destroy(this)
+    }
+}
+
+impl ResultOpaque {
+    pub fn new(i: i32) -> Result<DiplomatBox<ResultOpaque>, ErrorEnum> {
+        unsafe {
+            ResultOpaque_new(i).into_result()
+        }
+    }
+
+    pub fn new_failing_foo() -> Result<DiplomatBox<ResultOpaque>, ErrorEnum> {
+        unsafe {
+            ResultOpaque_new_failing_foo().into_result()
+        }
+    }
+
+    pub fn new_failing_bar() -> Result<DiplomatBox<ResultOpaque>, ErrorEnum> {
+        unsafe {
+            ResultOpaque_new_failing_bar().into_result()
+        }
+    }
+
+    pub fn new_failing_unit() -> Result<DiplomatBox<ResultOpaque>, ()> {
+        unsafe {
+            ResultOpaque_new_failing_unit().into_result()
+        }
+    }
+
+    pub fn new_failing_struct(i: i32) -> Result<DiplomatBox<ResultOpaque>, ErrorStruct> {
+        unsafe {
+            ResultOpaque_new_failing_struct(i).into_result()
+        }
+    }
+
+    pub fn new_in_err(i: i32) -> Result<(), DiplomatBox<ResultOpaque>> {
+        unsafe {
+            ResultOpaque_new_in_err(i).into_result()
+        }
+    }
+
+    pub fn new_int(i: i32) -> Result<i32, ()> {
+        unsafe {
+            ResultOpaque_new_int(i).into_result()
+        }
+    }
+
+    pub fn new_in_enum_err(i: i32) -> Result<ErrorEnum, DiplomatBox<ResultOpaque>> {
+        unsafe {
+            ResultOpaque_new_in_enum_err(i).into_result()
+        }
+    }
+
+    pub fn assert_integer<'anon_0>(&'anon_0 self, i: i32) {
+        unsafe {
+            ResultOpaque_assert_integer(self, i)
+        }
+    }
+}
+
+#[link(name = "diplomat_feature_tests")]
+#[allow(improper_ctypes, non_snake_case)]
+extern "C" {
+    fn ResultOpaque_destroy(this: *mut ResultOpaque);
+    fn ResultOpaque_new(i: i32) -> DiplomatResult<DiplomatBox<ResultOpaque>, ErrorEnum>;
+    fn ResultOpaque_new_failing_foo() -> DiplomatResult<DiplomatBox<ResultOpaque>, ErrorEnum>;
+    fn ResultOpaque_new_failing_bar() -> DiplomatResult<DiplomatBox<ResultOpaque>, ErrorEnum>;
+    fn ResultOpaque_new_failing_unit() -> DiplomatResult<DiplomatBox<ResultOpaque>, ()>;
+    fn ResultOpaque_new_failing_struct(i: i32) -> DiplomatResult<DiplomatBox<ResultOpaque>, ErrorStruct>;
+    fn ResultOpaque_new_in_err(i: i32) -> DiplomatResult<(), DiplomatBox<ResultOpaque>>;
+    fn ResultOpaque_new_int(i: i32) -> DiplomatResult<i32, ()>;
+    fn ResultOpaque_new_in_enum_err(i: i32) -> DiplomatResult<ErrorEnum, DiplomatBox<ResultOpaque>>;
+    fn ResultOpaque_assert_integer<'anon_0>(this: &'anon_0 ResultOpaque, i: i32);
+}
@@ -0,0 +1,69 @@
+// generated by diplomat-tool
+
+// The bindings follow the API of the library rather than Rust's conventions
+#![allow(
+    clippy::inherent_to_string,
+    clippy::needless_lifetimes,
+    clippy::new_without_default,
+    clippy::result_unit_err,
+    clippy::should_implement_trait
+)]
+
+#[allow(unused_imports)]
+use super::diplomat_runtime::*;
+#[allow(unused_imports)]
+use super::*;
+
+#[repr(C)]
+pub struct ShapeList {
+    _data: [u8; 0],
+    _marker: std::marker::PhantomData<(*mut u8, std::marker::PhantomPinned)>,
+}
+
+impl std::fmt::Debug for ShapeList {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("ShapeList")
+    }
+}
+
+unsafe impl DiplomatOpaque for ShapeList {
+    unsafe fn destroy(this: *mut Self) {
+        ShapeList_destroy(this)
+    }
+}
+
+impl ShapeList {
+    pub fn new() -> DiplomatBox<ShapeList> {
+        unsafe {
+            ShapeList_new()
+        }
+    }
+
+    pub fn push<'anon_0>(&'anon_0 mut self, shape: Shape) {
+        unsafe {
+            ShapeList_push(self, shape)
+        }
+    }
+
+    pub fn last<'anon_0>(&'anon_0 self) -> Shape {
+        unsafe {
+            ShapeList_last(self)
+        }
+    }
+
+    pub fn total_area<'anon_0>(&'anon_0 self) -> f64 {
+        unsafe {
+            ShapeList_total_area(self)
+        }
+    }
+}
+
+#[link(name = "diplomat_feature_tests")]
+#[allow(improper_ctypes, non_snake_case)]
+extern "C" {
+    fn ShapeList_destroy(this: *mut ShapeList);
+    fn ShapeList_new() -> DiplomatBox<ShapeList>;
+    fn ShapeList_push<'anon_0>(this: &'anon_0 mut ShapeList, shape: Shape);
+    fn ShapeList_last<'anon_0>(this: &'anon_0 ShapeList) -> Shape;
+    fn ShapeList_total_area<'anon_0>(this: &'anon_0 ShapeList) -> f64;
+}
@@ -0,0 +1,55 @@
+// generated by diplomat-tool
+
+// The bindings follow the API of the library rather than Rust's conventions
+#![allow(
+    clippy::inherent_to_string,
+    clippy::needless_lifetimes,
+    clippy::new_without_default,
+    clippy::result_unit_err,
+    clippy::should_implement_trait
+)]
+
+#[allow(unused_imports)]
+use super::diplomat_runtime::*;
+#[allow(unused_imports)]
+use super::*;
+
+#[repr(C)]
+pub struct Unnamespaced {
+    _data: [u8; 0],
+    _marker: std::marker::PhantomData<(*mut u8, std::marker::PhantomPinned)>,
+}
+
+impl std::fmt::Debug for Unnamespaced {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("Unnamespaced")
+    }
+}
+
+unsafe impl DiplomatOpaque for Unnamespaced {
+    unsafe fn destroy(this: *mut Self) {
+        namespace_Unnamespaced_destroy(this)
+    }
+}
+
+impl Unnamespaced {
+    pub fn make(_e: AttrEnum) -> DiplomatBox<Unnamespaced> {
+        unsafe {
+            namespace_Unnamespaced_make(_e)
+        }
+    }
+
+    pub fn use_namespaced<'anon_0, 'anon_1>(&'anon_0 self, _n: &'anon_1 AttrOpaque1) {
+        unsafe {
+            namespace_Unnamespaced_use_namespaced(self, _n)
+        }
+    }
+}
+
+#[link(name = "diplomat_feature_tests")]
+#[allow(improper_ctypes, non_snake_case)]
+extern "C" {
+    fn namespace_Unnamespaced_destroy(this: *mut Unnamespaced);
+    fn namespace_Unnamespaced_make(_e: AttrEnum) -> DiplomatBox<Unnamespaced>;
+    fn namespace_Unnamespaced_use_namespaced<'anon_0, 'anon_1>(this: &'anon_0 Unnamespaced, _n: &'anon_1 AttrOpaque1);
+}
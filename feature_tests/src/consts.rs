@@ -0,0 +1,20 @@
+#[diplomat::bridge]
+pub mod ffi {
+    /// The length `ConstHolder::max_len` returns
+    pub const MAX_LEN: u32 = 16;
+    pub const GREETING: &str = "hello";
+    pub const RATIO: f64 = 0.5;
+    pub const ENABLED: bool = true;
+
+    #[diplomat::opaque]
+    pub struct ConstHolder(u32);
+
+    impl ConstHolder {
+        pub const MIN_VALUE: i8 = -128;
+        pub const SEPARATOR: DiplomatChar = 124;
+
+        pub fn max_len() -> u32 {
+            MAX_LEN
+        }
+    }
+}
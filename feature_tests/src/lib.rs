@@ -12,10 +12,14 @@
 extern crate alloc;
 
 pub mod attrs;
+pub mod consts;
 pub mod imports;
 pub mod lifetimes;
 pub mod option;
+pub mod panics;
 pub mod result;
 pub mod selftype;
 pub mod slices;
 pub mod structs;
+pub mod tagged_unions;
+pub mod traits;
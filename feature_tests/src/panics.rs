@@ -0,0 +1,37 @@
+#[diplomat::bridge]
+pub mod ffi {
+    #[derive(Debug, PartialEq, Eq)]
+    pub enum PanicError {
+        Panicked,
+        TooLarge,
+    }
+
+    #[diplomat::opaque]
+    pub struct PanicOpaque;
+
+    #[diplomat::catch_panics]
+    impl PanicOpaque {
+        /// Panics, which foreign code is told about after the call
+        pub fn panics(message: &DiplomatStr) {
+            panic!("{}", String::from_utf8_lossy(message));
+        }
+
+        /// Panics when `x` is zero, which is reported as `PanicError::Panicked`
+        pub fn checked_divide(x: u32) -> Result<u32, PanicError> {
+            if x > 100 {
+                return Err(PanicError::TooLarge);
+            }
+            Ok(100 / x)
+        }
+
+        pub fn not_panicking(x: u32) -> u32 {
+            x + 1
+        }
+    }
+}
+
+impl diplomat_runtime::panic::FromPanic for ffi::PanicError {
+    fn from_panic(_message: &str) -> Self {
+        ffi::PanicError::Panicked
+    }
+}
@@ -0,0 +1,42 @@
+#[diplomat::bridge]
+pub mod ffi {
+    // The original backends and Go can't represent enums whose variants carry fields
+    #[diplomat::skip_if_ast]
+    #[diplomat::attr(go, disable)]
+    pub enum Shape {
+        Circle { radius: f64 },
+        Rect { width: f64, height: f64 },
+        Empty,
+    }
+
+    #[diplomat::opaque]
+    #[diplomat::skip_if_ast]
+    #[diplomat::attr(go, disable)]
+    pub struct ShapeList(Vec<Shape>);
+
+    impl ShapeList {
+        #[diplomat::attr(supports = constructors, constructor)]
+        pub fn new() -> Box<ShapeList> {
+            Box::new(ShapeList(Vec::new()))
+        }
+
+        pub fn push(&mut self, shape: Shape) {
+            self.0.push(shape);
+        }
+
+        pub fn last(&self) -> Shape {
+            self.0.last().copied().unwrap_or(Shape::Empty)
+        }
+
+        pub fn total_area(&self) -> f64 {
+            self.0
+                .iter()
+                .map(|shape| match *shape {
+                    Shape::Circle { radius } => core::f64::consts::PI * radius * radius,
+                    Shape::Rect { width, height } => width * height,
+                    Shape::Empty => 0.0,
+                })
+                .sum()
+        }
+    }
+}
@@ -0,0 +1,29 @@
+#[diplomat::bridge]
+pub mod ffi {
+    // Only the c2, cpp2 and dart backends can implement traits in the target language
+    #[diplomat::attr(not(any(c2, cpp2, dart)), disable)]
+    pub trait TesterTrait {
+        fn test_trait_fn(&self, x: u32) -> u32;
+        fn test_void_trait_fn(&self);
+    }
+
+    #[diplomat::opaque]
+    #[diplomat::skip_if_ast]
+    #[diplomat::attr(not(any(c2, cpp2, dart)), disable)]
+    pub struct TraitWrapper(Box<dyn TesterTrait>);
+
+    impl TraitWrapper {
+        pub fn test_with_trait(t: &dyn TesterTrait, x: u32) -> u32 {
+            t.test_void_trait_fn();
+            t.test_trait_fn(x)
+        }
+
+        pub fn from_trait(t: Box<dyn TesterTrait>) -> Box<TraitWrapper> {
+            Box::new(TraitWrapper(t))
+        }
+
+        pub fn call(&self, x: u32) -> u32 {
+            self.0.test_trait_fn(x)
+        }
+    }
+}
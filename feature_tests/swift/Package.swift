@@ -0,0 +1,12 @@
+// swift-tools-version:5.7
+import PackageDescription
+
+let package = Package(
+    name: "DiplomatFeatureTests",
+    targets: [
+        // Links the library built by `cargo build -p diplomat-feature-tests`
+        .systemLibrary(name: "DiplomatFeatureTestsFFI", path: "Sources/DiplomatFeatureTestsFFI"),
+        .target(name: "DiplomatFeatureTests", dependencies: ["DiplomatFeatureTestsFFI"]),
+        .testTarget(name: "DiplomatFeatureTestsTests", dependencies: ["DiplomatFeatureTests"]),
+    ]
+)
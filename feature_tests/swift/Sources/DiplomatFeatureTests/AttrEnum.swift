@@ -0,0 +1,17 @@
+// generated by diplomat-tool
+
+import DiplomatFeatureTestsFFI
+
+public enum AttrEnum: Int32 {
+    case a = 0
+    case b = 1
+    case c = 2
+
+    init(native: DiplomatFeatureTestsFFI.AttrEnum) {
+        self.init(rawValue: numericCast(native.rawValue))!
+    }
+
+    func toNative() -> DiplomatFeatureTestsFFI.AttrEnum {
+        return DiplomatFeatureTestsFFI.AttrEnum(rawValue: numericCast(rawValue))
+    }
+}
@@ -0,0 +1,49 @@
+// generated by diplomat-tool
+
+import DiplomatFeatureTestsFFI
+
+public final class AttrOpaque1 {
+    let handle: OpaquePointer
+    private let owned: Bool
+    /// Objects this may borrow from, kept alive for as long as this is
+    let edges: [Any]
+
+    init(handle: OpaquePointer, owned: Bool, edges: [Any]) {
+        self.handle = handle
+        self.owned = owned
+        self.edges = edges
+    }
+
+    deinit {
+        if owned {
+            namespace_AttrOpaque1_destroy(handle)
+        }
+    }
+
+    public convenience init() {
+        let result = namespace_AttrOpaque1_new()
+        self.init(handle: result, owned: true, edges: [])
+    }
+
+    public func methodDisabledcpp() {
+        namespace_AttrOpaque1_method_disabledcpp(handle)
+    }
+
+    public func useUnnamespaced(un: Unnamespaced) {
+        namespace_AttrOpaque1_use_unnamespaced(handle, un.handle)
+    }
+
+    public func useNamespaced(n: AttrEnum) {
+        namespace_AttrOpaque1_use_namespaced(handle, n.toNative())
+    }
+
+    public var abirenamed: UInt8 {
+        let result = renamed_on_abi_only(handle)
+        return result
+    }
+
+    public var method: UInt8 {
+        let result = namespace_AttrOpaque1_method(handle)
+        return result
+    }
+}
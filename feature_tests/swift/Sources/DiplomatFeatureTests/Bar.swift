@@ -0,0 +1,27 @@
+// generated by diplomat-tool
+
+import DiplomatFeatureTestsFFI
+
+public final class Bar {
+    let handle: OpaquePointer
+    private let owned: Bool
+    /// Objects this may borrow from, kept alive for as long as this is
+    let edges: [Any]
+
+    init(handle: OpaquePointer, owned: Bool, edges: [Any]) {
+        self.handle = handle
+        self.owned = owned
+        self.edges = edges
+    }
+
+    deinit {
+        if owned {
+            Bar_destroy(handle)
+        }
+    }
+
+    public var foo: Foo {
+        let result = Bar_foo(handle)
+        return Foo(handle: result, owned: false, edges: [self])
+    }
+}
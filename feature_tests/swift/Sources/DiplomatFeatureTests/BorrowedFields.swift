@@ -0,0 +1,30 @@
+// generated by diplomat-tool
+
+import DiplomatFeatureTestsFFI
+
+public struct BorrowedFields {
+    public var a: String
+    public var b: String
+    public var c: String
+
+    public init(a: String, b: String, c: String) {
+        self.a = a
+        self.b = b
+        self.c = c
+    }
+
+    init(native: DiplomatFeatureTestsFFI.BorrowedFields, edges: [Any]) {
+        self.init(a: DiplomatRuntime.readStr16(native.a.data, native.a.len, false), b: DiplomatRuntime.readStr8(native.b.data, native.b.len, false), c: DiplomatRuntime.readStr8(native.c.data, native.c.len, false))
+    }
+
+    func toNative(_ keep: DiplomatKeep) -> DiplomatFeatureTestsFFI.BorrowedFields {
+        var native = DiplomatFeatureTestsFFI.BorrowedFields()
+        let aSlice = DiplomatRuntime.str16(self.a, keep)
+        native.a = .init(data: aSlice.data, len: aSlice.len)
+        let bSlice = DiplomatRuntime.str8(self.b, keep)
+        native.b = .init(data: bSlice.data, len: bSlice.len)
+        let cSlice = DiplomatRuntime.str8(self.c, keep)
+        native.c = .init(data: cSlice.data, len: cSlice.len)
+        return native
+    }
+}
@@ -0,0 +1,22 @@
+// generated by diplomat-tool
+
+import DiplomatFeatureTestsFFI
+
+public struct BorrowedFieldsReturning {
+    public var bytes: String
+
+    public init(bytes: String) {
+        self.bytes = bytes
+    }
+
+    init(native: DiplomatFeatureTestsFFI.BorrowedFieldsReturning, edges: [Any]) {
+        self.init(bytes: DiplomatRuntime.readStr8(native.bytes.data, native.bytes.len, false))
+    }
+
+    func toNative(_ keep: DiplomatKeep) -> DiplomatFeatureTestsFFI.BorrowedFieldsReturning {
+        var native = DiplomatFeatureTestsFFI.BorrowedFieldsReturning()
+        let bytesSlice = DiplomatRuntime.str8(self.bytes, keep)
+        native.bytes = .init(data: bytesSlice.data, len: bytesSlice.len)
+        return native
+    }
+}
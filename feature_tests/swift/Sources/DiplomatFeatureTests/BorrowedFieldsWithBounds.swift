@@ -0,0 +1,30 @@
+// generated by diplomat-tool
+
+import DiplomatFeatureTestsFFI
+
+public struct BorrowedFieldsWithBounds {
+    public var fieldA: String
+    public var fieldB: String
+    public var fieldC: String
+
+    public init(fieldA: String, fieldB: String, fieldC: String) {
+        self.fieldA = fieldA
+        self.fieldB = fieldB
+        self.fieldC = fieldC
+    }
+
+    init(native: DiplomatFeatureTestsFFI.BorrowedFieldsWithBounds, edges: [Any]) {
+        self.init(fieldA: DiplomatRuntime.readStr16(native.field_a.data, native.field_a.len, false), fieldB: DiplomatRuntime.readStr8(native.field_b.data, native.field_b.len, false), fieldC: DiplomatRuntime.readStr8(native.field_c.data, native.field_c.len, false))
+    }
+
+    func toNative(_ keep: DiplomatKeep) -> DiplomatFeatureTestsFFI.BorrowedFieldsWithBounds {
+        var native = DiplomatFeatureTestsFFI.BorrowedFieldsWithBounds()
+        let fieldASlice = DiplomatRuntime.str16(self.fieldA, keep)
+        native.field_a = .init(data: fieldASlice.data, len: fieldASlice.len)
+        let fieldBSlice = DiplomatRuntime.str8(self.fieldB, keep)
+        native.field_b = .init(data: fieldBSlice.data, len: fieldBSlice.len)
+        let fieldCSlice = DiplomatRuntime.str8(self.fieldC, keep)
+        native.field_c = .init(data: fieldCSlice.data, len: fieldCSlice.len)
+        return native
+    }
+}
@@ -0,0 +1,40 @@
+// generated by diplomat-tool
+
+import DiplomatFeatureTestsFFI
+
+public final class Comparable: Swift.Comparable {
+    let handle: OpaquePointer
+    private let owned: Bool
+    /// Objects this may borrow from, kept alive for as long as this is
+    let edges: [Any]
+
+    init(handle: OpaquePointer, owned: Bool, edges: [Any]) {
+        self.handle = handle
+        self.owned = owned
+        self.edges = edges
+    }
+
+    deinit {
+        if owned {
+            namespace_Comparable_destroy(handle)
+        }
+    }
+
+    public static func new(int: UInt8) -> Comparable {
+        let result = namespace_Comparable_new(int)
+        return Comparable(handle: result, owned: true, edges: [])
+    }
+
+    public func cmp(other: Comparable) -> Int8 {
+        let result = namespace_Comparable_cmp(handle, other.handle)
+        return result
+    }
+
+    public static func < (lhs: Comparable, rhs: Comparable) -> Bool {
+        return lhs.cmp(other: rhs) < 0
+    }
+
+    public static func == (lhs: Comparable, rhs: Comparable) -> Bool {
+        return lhs.cmp(other: rhs) == 0
+    }
+}
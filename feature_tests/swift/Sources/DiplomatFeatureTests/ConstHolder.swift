@@ -0,0 +1,31 @@
+// generated by diplomat-tool
+
+import DiplomatFeatureTestsFFI
+
+public final class ConstHolder {
+    let handle: OpaquePointer
+    private let owned: Bool
+    /// Objects this may borrow from, kept alive for as long as this is
+    let edges: [Any]
+
+    init(handle: OpaquePointer, owned: Bool, edges: [Any]) {
+        self.handle = handle
+        self.owned = owned
+        self.edges = edges
+    }
+
+    deinit {
+        if owned {
+            ConstHolder_destroy(handle)
+        }
+    }
+
+    public static let minValue: Int8 = -128
+
+    public static let separator: Unicode.Scalar = 124
+
+    public static func maxLen() -> UInt32 {
+        let result = ConstHolder_max_len()
+        return result
+    }
+}
@@ -0,0 +1,12 @@
+// generated by diplomat-tool
+
+import DiplomatFeatureTestsFFI
+
+public let enabled: Bool = true
+
+public let greeting: String = "hello"
+
+/// The length `ConstHolder::max_len` returns
+public let maxLen: UInt32 = 16
+
+public let ratio: Double = 0.5
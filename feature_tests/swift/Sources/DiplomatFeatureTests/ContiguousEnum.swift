@@ -0,0 +1,18 @@
+// generated by diplomat-tool
+
+import DiplomatFeatureTestsFFI
+
+public enum ContiguousEnum: Int32 {
+    case c = 0
+    case d = 1
+    case e = 2
+    case f = 3
+
+    init(native: DiplomatFeatureTestsFFI.ContiguousEnum) {
+        self.init(rawValue: numericCast(native.rawValue))!
+    }
+
+    func toNative() -> DiplomatFeatureTestsFFI.ContiguousEnum {
+        return DiplomatFeatureTestsFFI.ContiguousEnum(rawValue: numericCast(rawValue))
+    }
+}
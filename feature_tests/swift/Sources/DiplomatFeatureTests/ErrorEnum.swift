@@ -0,0 +1,16 @@
+// generated by diplomat-tool
+
+import DiplomatFeatureTestsFFI
+
+public enum ErrorEnum: Int32 {
+    case foo = 0
+    case bar = 1
+
+    init(native: DiplomatFeatureTestsFFI.ErrorEnum) {
+        self.init(rawValue: numericCast(native.rawValue))!
+    }
+
+    func toNative() -> DiplomatFeatureTestsFFI.ErrorEnum {
+        return DiplomatFeatureTestsFFI.ErrorEnum(rawValue: numericCast(rawValue))
+    }
+}
@@ -0,0 +1,24 @@
+// generated by diplomat-tool
+
+import DiplomatFeatureTestsFFI
+
+public struct ErrorStruct {
+    public var i: Int32
+    public var j: Int32
+
+    public init(i: Int32, j: Int32) {
+        self.i = i
+        self.j = j
+    }
+
+    init(native: DiplomatFeatureTestsFFI.ErrorStruct, edges: [Any]) {
+        self.init(i: native.i, j: native.j)
+    }
+
+    func toNative(_ keep: DiplomatKeep) -> DiplomatFeatureTestsFFI.ErrorStruct {
+        var native = DiplomatFeatureTestsFFI.ErrorStruct()
+        native.i = self.i
+        native.j = self.j
+        return native
+    }
+}
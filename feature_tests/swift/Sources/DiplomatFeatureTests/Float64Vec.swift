@@ -0,0 +1,113 @@
+// generated by diplomat-tool
+
+import DiplomatFeatureTestsFFI
+
+public final class Float64Vec: Swift.CustomStringConvertible {
+    let handle: OpaquePointer
+    private let owned: Bool
+    /// Objects this may borrow from, kept alive for as long as this is
+    let edges: [Any]
+
+    init(handle: OpaquePointer, owned: Bool, edges: [Any]) {
+        self.handle = handle
+        self.owned = owned
+        self.edges = edges
+    }
+
+    deinit {
+        if owned {
+            Float64Vec_destroy(handle)
+        }
+    }
+
+    public convenience init(v: [Double]) {
+        let keep = DiplomatKeep()
+        let vSlice = DiplomatRuntime.slice(v, as: Double.self, keep)
+        let result = withExtendedLifetime(keep) { Float64Vec_new(vSlice.data, vSlice.len) }
+        self.init(handle: result, owned: true, edges: [])
+    }
+
+    public static func bool(v: [Bool]) -> Float64Vec {
+        let keep = DiplomatKeep()
+        let vSlice = DiplomatRuntime.slice(v, as: Bool.self, keep)
+        let result = withExtendedLifetime(keep) { Float64Vec_new_bool(vSlice.data, vSlice.len) }
+        return Float64Vec(handle: result, owned: true, edges: [])
+    }
+
+    public static func i16(v: [Int16]) -> Float64Vec {
+        let keep = DiplomatKeep()
+        let vSlice = DiplomatRuntime.slice(v, as: Int16.self, keep)
+        let result = withExtendedLifetime(keep) { Float64Vec_new_i16(vSlice.data, vSlice.len) }
+        return Float64Vec(handle: result, owned: true, edges: [])
+    }
+
+    public static func u16(v: [UInt16]) -> Float64Vec {
+        let keep = DiplomatKeep()
+        let vSlice = DiplomatRuntime.slice(v, as: UInt16.self, keep)
+        let result = withExtendedLifetime(keep) { Float64Vec_new_u16(vSlice.data, vSlice.len) }
+        return Float64Vec(handle: result, owned: true, edges: [])
+    }
+
+    public static func isize(v: [Int]) -> Float64Vec {
+        let keep = DiplomatKeep()
+        let vSlice = DiplomatRuntime.slice(v, as: Int.self, keep)
+        let result = withExtendedLifetime(keep) { Float64Vec_new_isize(vSlice.data, vSlice.len) }
+        return Float64Vec(handle: result, owned: true, edges: [])
+    }
+
+    public static func usize(v: [UInt]) -> Float64Vec {
+        let keep = DiplomatKeep()
+        let vSlice = DiplomatRuntime.slice(v, as: Int.self, keep)
+        let result = withExtendedLifetime(keep) { Float64Vec_new_usize(vSlice.data, vSlice.len) }
+        return Float64Vec(handle: result, owned: true, edges: [])
+    }
+
+    public static func f64BeBytes(v: [UInt8]) -> Float64Vec {
+        let keep = DiplomatKeep()
+        let vSlice = DiplomatRuntime.slice(v, as: UInt8.self, keep)
+        let result = withExtendedLifetime(keep) { Float64Vec_new_f64_be_bytes(vSlice.data, vSlice.len) }
+        return Float64Vec(handle: result, owned: true, edges: [])
+    }
+
+    public func fillSlice(v: inout [Double]) {
+        let keep = DiplomatKeep()
+        let vSlice = DiplomatRuntime.slice(v, as: Double.self, keep)
+        withExtendedLifetime(keep) { Float64Vec_fill_slice(handle, vSlice.data, vSlice.len) }
+        DiplomatRuntime.copyBack(vSlice, &v)
+    }
+
+    public func setValue(newSlice: [Double]) {
+        let keep = DiplomatKeep()
+        let newSliceSlice = DiplomatRuntime.slice(newSlice, as: Double.self, keep)
+        withExtendedLifetime(keep) { Float64Vec_set_value(handle, newSliceSlice.data, newSliceSlice.len) }
+    }
+
+    public var description: String {
+        let writeable = DiplomatWriteableBuffer()
+        Float64Vec_to_string(handle, writeable.handle)
+        return writeable.finalizeString()
+    }
+
+    public func borrow() -> [Double] {
+        let result = Float64Vec_borrow(handle)
+        return DiplomatRuntime.read(result.data, result.len, false, as: Double.self)
+    }
+
+    public subscript(i: UInt) -> Double? {
+        let result = Float64Vec_get(handle, Int(bitPattern: i))
+        if !result.is_ok {
+            return nil
+        }
+        return result.ok
+    }
+
+    public var asBoxedSlice: [Double] {
+        let result = Float64Vec_as_boxed_slice(handle)
+        return DiplomatRuntime.read(result.data, result.len, true, as: Double.self)
+    }
+
+    public var asSlice: [Double] {
+        let result = Float64Vec_as_slice(handle)
+        return DiplomatRuntime.read(result.data, result.len, false, as: Double.self)
+    }
+}
@@ -0,0 +1,60 @@
+// generated by diplomat-tool
+
+import DiplomatFeatureTestsFFI
+
+public final class Foo {
+    let handle: OpaquePointer
+    private let owned: Bool
+    /// Objects this may borrow from, kept alive for as long as this is
+    let edges: [Any]
+
+    init(handle: OpaquePointer, owned: Bool, edges: [Any]) {
+        self.handle = handle
+        self.owned = owned
+        self.edges = edges
+    }
+
+    deinit {
+        if owned {
+            Foo_destroy(handle)
+        }
+    }
+
+    public convenience init(x: String) {
+        let keep = DiplomatKeep()
+        let xSlice = DiplomatRuntime.str8(x, keep)
+        let result = withExtendedLifetime(keep) { Foo_new(xSlice.data, xSlice.len) }
+        self.init(handle: result, owned: true, edges: [x, keep])
+    }
+
+    public static func `static`(x: String) -> Foo {
+        let keep = DiplomatKeep()
+        let xSlice = DiplomatRuntime.str8(x, keep)
+        let result = withExtendedLifetime(keep) { Foo_new_static(xSlice.data, xSlice.len) }
+        return Foo(handle: result, owned: true, edges: [x, keep])
+    }
+
+    public func asReturning() -> BorrowedFieldsReturning {
+        let result = Foo_as_returning(handle)
+        return BorrowedFieldsReturning(native: result, edges: [self])
+    }
+
+    public static func extractFromFields(fields: BorrowedFields) -> Foo {
+        let keep = DiplomatKeep()
+        let result = withExtendedLifetime(keep) { Foo_extract_from_fields(fields.toNative(keep)) }
+        return Foo(handle: result, owned: true, edges: [fields, keep])
+    }
+
+    /// Test that the extraction logic correctly pins the right fields
+    public static func extractFromBounds(bounds: BorrowedFieldsWithBounds, anotherString: String) -> Foo {
+        let keep = DiplomatKeep()
+        let anotherStringSlice = DiplomatRuntime.str8(anotherString, keep)
+        let result = withExtendedLifetime(keep) { Foo_extract_from_bounds(bounds.toNative(keep), anotherStringSlice.data, anotherStringSlice.len) }
+        return Foo(handle: result, owned: true, edges: [bounds, anotherString, keep])
+    }
+
+    public var bar: Bar {
+        let result = Foo_get_bar(handle)
+        return Bar(handle: result, owned: true, edges: [self])
+    }
+}
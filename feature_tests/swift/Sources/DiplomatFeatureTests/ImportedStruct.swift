@@ -0,0 +1,24 @@
+// generated by diplomat-tool
+
+import DiplomatFeatureTestsFFI
+
+public struct ImportedStruct {
+    public var foo: UnimportedEnum
+    public var count: UInt8
+
+    public init(foo: UnimportedEnum, count: UInt8) {
+        self.foo = foo
+        self.count = count
+    }
+
+    init(native: DiplomatFeatureTestsFFI.ImportedStruct, edges: [Any]) {
+        self.init(foo: UnimportedEnum(native: native.foo), count: native.count)
+    }
+
+    func toNative(_ keep: DiplomatKeep) -> DiplomatFeatureTestsFFI.ImportedStruct {
+        var native = DiplomatFeatureTestsFFI.ImportedStruct()
+        native.foo = self.foo.toNative()
+        native.count = self.count
+        return native
+    }
+}
@@ -0,0 +1,25 @@
+// generated by diplomat-tool
+
+import DiplomatFeatureTestsFFI
+
+public enum MyEnum: Int32 {
+    case a = -2
+    case b = -1
+    case c = 0
+    case d = 1
+    case e = 2
+    case f = 3
+
+    init(native: DiplomatFeatureTestsFFI.MyEnum) {
+        self.init(rawValue: numericCast(native.rawValue))!
+    }
+
+    func toNative() -> DiplomatFeatureTestsFFI.MyEnum {
+        return DiplomatFeatureTestsFFI.MyEnum(rawValue: numericCast(rawValue))
+    }
+
+    public func intoValue() -> Int8 {
+        let result = MyEnum_into_value(toNative())
+        return result
+    }
+}
@@ -0,0 +1,34 @@
+// generated by diplomat-tool
+
+import DiplomatFeatureTestsFFI
+
+public final class MyIterable: Swift.Sequence {
+    let handle: OpaquePointer
+    private let owned: Bool
+    /// Objects this may borrow from, kept alive for as long as this is
+    let edges: [Any]
+
+    init(handle: OpaquePointer, owned: Bool, edges: [Any]) {
+        self.handle = handle
+        self.owned = owned
+        self.edges = edges
+    }
+
+    deinit {
+        if owned {
+            namespace_MyIterable_destroy(handle)
+        }
+    }
+
+    public convenience init(x: [UInt8]) {
+        let keep = DiplomatKeep()
+        let xSlice = DiplomatRuntime.slice(x, as: UInt8.self, keep)
+        let result = withExtendedLifetime(keep) { namespace_MyIterable_new(xSlice.data, xSlice.len) }
+        self.init(handle: result, owned: true, edges: [])
+    }
+
+    public func makeIterator() -> MyIterator {
+        let result = namespace_MyIterable_iter(handle)
+        return MyIterator(handle: result, owned: true, edges: [self])
+    }
+}
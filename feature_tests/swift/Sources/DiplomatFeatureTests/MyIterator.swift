@@ -0,0 +1,30 @@
+// generated by diplomat-tool
+
+import DiplomatFeatureTestsFFI
+
+public final class MyIterator: Swift.IteratorProtocol {
+    let handle: OpaquePointer
+    private let owned: Bool
+    /// Objects this may borrow from, kept alive for as long as this is
+    let edges: [Any]
+
+    init(handle: OpaquePointer, owned: Bool, edges: [Any]) {
+        self.handle = handle
+        self.owned = owned
+        self.edges = edges
+    }
+
+    deinit {
+        if owned {
+            namespace_MyIterator_destroy(handle)
+        }
+    }
+
+    public func next() -> UInt8? {
+        let result = namespace_MyIterator_next(handle)
+        if !result.is_ok {
+            return nil
+        }
+        return result.ok
+    }
+}
@@ -0,0 +1,62 @@
+// generated by diplomat-tool
+
+import DiplomatFeatureTestsFFI
+
+public final class MyString {
+    let handle: OpaquePointer
+    private let owned: Bool
+    /// Objects this may borrow from, kept alive for as long as this is
+    let edges: [Any]
+
+    init(handle: OpaquePointer, owned: Bool, edges: [Any]) {
+        self.handle = handle
+        self.owned = owned
+        self.edges = edges
+    }
+
+    deinit {
+        if owned {
+            MyString_destroy(handle)
+        }
+    }
+
+    public convenience init(v: String) {
+        let keep = DiplomatKeep()
+        let vSlice = DiplomatRuntime.str8(v, keep)
+        let result = withExtendedLifetime(keep) { MyString_new(vSlice.data, vSlice.len) }
+        self.init(handle: result, owned: true, edges: [])
+    }
+
+    public static func unsafe(v: String) -> MyString {
+        let keep = DiplomatKeep()
+        let vSlice = DiplomatRuntime.str8(v, keep)
+        let result = withExtendedLifetime(keep) { MyString_new_unsafe(vSlice.data, vSlice.len) }
+        return MyString(handle: result, owned: true, edges: [])
+    }
+
+    public static func newOwned(v: String) -> MyString {
+        let vSlice = DiplomatRuntime.str8(v, nil)
+        let result = MyString_new_owned(vSlice.data, vSlice.len)
+        return MyString(handle: result, owned: true, edges: [])
+    }
+
+    public static func newFromFirst(v: [String]) -> MyString {
+        let keep = DiplomatKeep()
+        let vSlice = DiplomatRuntime.strs8(v, keep)
+        let result = withExtendedLifetime(keep) { MyString_new_from_first(vSlice.data, vSlice.len) }
+        return MyString(handle: result, owned: true, edges: [])
+    }
+
+    public var str: String {
+        get {
+            let writeable = DiplomatWriteableBuffer()
+            MyString_get_str(handle, writeable.handle)
+            return writeable.finalizeString()
+        }
+        set(newStr) {
+            let keep = DiplomatKeep()
+            let newStrSlice = DiplomatRuntime.str8(newStr, keep)
+            withExtendedLifetime(keep) { MyString_set_str(handle, newStrSlice.data, newStrSlice.len) }
+        }
+    }
+}
@@ -0,0 +1,50 @@
+// generated by diplomat-tool
+
+import DiplomatFeatureTestsFFI
+
+public struct MyStruct {
+    public var a: UInt8
+    public var b: Bool
+    public var c: UInt8
+    public var d: UInt64
+    public var e: Int32
+    public var f: Unicode.Scalar
+    public var g: MyEnum
+
+    public init(a: UInt8, b: Bool, c: UInt8, d: UInt64, e: Int32, f: Unicode.Scalar, g: MyEnum) {
+        self.a = a
+        self.b = b
+        self.c = c
+        self.d = d
+        self.e = e
+        self.f = f
+        self.g = g
+    }
+
+    init(native: DiplomatFeatureTestsFFI.MyStruct, edges: [Any]) {
+        self.init(a: native.a, b: native.b, c: native.c, d: native.d, e: native.e, f: Unicode.Scalar(native.f)!, g: MyEnum(native: native.g))
+    }
+
+    func toNative(_ keep: DiplomatKeep) -> DiplomatFeatureTestsFFI.MyStruct {
+        var native = DiplomatFeatureTestsFFI.MyStruct()
+        native.a = self.a
+        native.b = self.b
+        native.c = self.c
+        native.d = self.d
+        native.e = self.e
+        native.f = self.f.value
+        native.g = self.g.toNative()
+        return native
+    }
+
+    public init() {
+        let result = MyStruct_new()
+        self = MyStruct(native: result, edges: [])
+    }
+
+    public func intoA() -> UInt8 {
+        let keep = DiplomatKeep()
+        let result = withExtendedLifetime(keep) { MyStruct_into_a(toNative(keep)) }
+        return result
+    }
+}
@@ -0,0 +1,27 @@
+// generated by diplomat-tool
+
+import DiplomatFeatureTestsFFI
+
+public struct NestedBorrowedFields {
+    public var fields: BorrowedFields
+    public var bounds: BorrowedFieldsWithBounds
+    public var bounds2: BorrowedFieldsWithBounds
+
+    public init(fields: BorrowedFields, bounds: BorrowedFieldsWithBounds, bounds2: BorrowedFieldsWithBounds) {
+        self.fields = fields
+        self.bounds = bounds
+        self.bounds2 = bounds2
+    }
+
+    init(native: DiplomatFeatureTestsFFI.NestedBorrowedFields, edges: [Any]) {
+        self.init(fields: BorrowedFields(native: native.fields, edges: edges), bounds: BorrowedFieldsWithBounds(native: native.bounds, edges: edges), bounds2: BorrowedFieldsWithBounds(native: native.bounds2, edges: edges))
+    }
+
+    func toNative(_ keep: DiplomatKeep) -> DiplomatFeatureTestsFFI.NestedBorrowedFields {
+        var native = DiplomatFeatureTestsFFI.NestedBorrowedFields()
+        native.fields = self.fields.toNative(keep)
+        native.bounds = self.bounds.toNative(keep)
+        native.bounds2 = self.bounds2.toNative(keep)
+        return native
+    }
+}
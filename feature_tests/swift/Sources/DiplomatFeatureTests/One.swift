@@ -0,0 +1,77 @@
+// generated by diplomat-tool
+
+import DiplomatFeatureTestsFFI
+
+public final class One {
+    let handle: OpaquePointer
+    private let owned: Bool
+    /// Objects this may borrow from, kept alive for as long as this is
+    let edges: [Any]
+
+    init(handle: OpaquePointer, owned: Bool, edges: [Any]) {
+        self.handle = handle
+        self.owned = owned
+        self.edges = edges
+    }
+
+    deinit {
+        if owned {
+            One_destroy(handle)
+        }
+    }
+
+    public static func transitivity(hold: One, nohold: One) -> One {
+        let result = One_transitivity(hold.handle, nohold.handle)
+        return One(handle: result, owned: true, edges: [hold, nohold])
+    }
+
+    public static func cycle(hold: Two, nohold: One) -> One {
+        let result = One_cycle(hold.handle, nohold.handle)
+        return One(handle: result, owned: true, edges: [hold, nohold])
+    }
+
+    public static func manyDependents(a: One, b: One, c: Two, d: Two, nohold: Two) -> One {
+        let result = One_many_dependents(a.handle, b.handle, c.handle, d.handle, nohold.handle)
+        return One(handle: result, owned: true, edges: [a, b, c, d, nohold])
+    }
+
+    public static func returnOutlivesParam(hold: Two, nohold: One) -> One {
+        let result = One_return_outlives_param(hold.handle, nohold.handle)
+        return One(handle: result, owned: true, edges: [hold, nohold])
+    }
+
+    public static func diamondTop(top: One, left: One, right: One, bottom: One) -> One {
+        let result = One_diamond_top(top.handle, left.handle, right.handle, bottom.handle)
+        return One(handle: result, owned: true, edges: [top, left, right, bottom])
+    }
+
+    public static func diamondLeft(top: One, left: One, right: One, bottom: One) -> One {
+        let result = One_diamond_left(top.handle, left.handle, right.handle, bottom.handle)
+        return One(handle: result, owned: true, edges: [top, left, right, bottom])
+    }
+
+    public static func diamondRight(top: One, left: One, right: One, bottom: One) -> One {
+        let result = One_diamond_right(top.handle, left.handle, right.handle, bottom.handle)
+        return One(handle: result, owned: true, edges: [top, left, right, bottom])
+    }
+
+    public static func diamondBottom(top: One, left: One, right: One, bottom: One) -> One {
+        let result = One_diamond_bottom(top.handle, left.handle, right.handle, bottom.handle)
+        return One(handle: result, owned: true, edges: [top, left, right, bottom])
+    }
+
+    public static func diamondAndNestedTypes(a: One, b: One, c: One, d: One, nohold: One) -> One {
+        let result = One_diamond_and_nested_types(a.handle, b.handle, c.handle, d.handle, nohold.handle)
+        return One(handle: result, owned: true, edges: [a, b, c, d, nohold])
+    }
+
+    public static func implicitBounds(explicitHold: One, implicitHold: One, nohold: One) -> One {
+        let result = One_implicit_bounds(explicitHold.handle, implicitHold.handle, nohold.handle)
+        return One(handle: result, owned: true, edges: [explicitHold, implicitHold, nohold])
+    }
+
+    public static func implicitBoundsDeep(explicit: One, implicit1: One, implicit2: One, nohold: One) -> One {
+        let result = One_implicit_bounds_deep(explicit.handle, implicit1.handle, implicit2.handle, nohold.handle)
+        return One(handle: result, owned: true, edges: [explicit, implicit1, implicit2, nohold])
+    }
+}
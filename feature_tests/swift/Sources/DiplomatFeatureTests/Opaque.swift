@@ -0,0 +1,47 @@
+// generated by diplomat-tool
+
+import DiplomatFeatureTestsFFI
+
+public final class Opaque {
+    let handle: OpaquePointer
+    private let owned: Bool
+    /// Objects this may borrow from, kept alive for as long as this is
+    let edges: [Any]
+
+    init(handle: OpaquePointer, owned: Bool, edges: [Any]) {
+        self.handle = handle
+        self.owned = owned
+        self.edges = edges
+    }
+
+    deinit {
+        if owned {
+            Opaque_destroy(handle)
+        }
+    }
+
+    public convenience init() {
+        let result = Opaque_new()
+        self.init(handle: result, owned: true, edges: [])
+    }
+
+    /// See the [Rust documentation for `something`](https://docs.rs/Something/latest/struct.Something.html#method.something) for more information.
+    ///
+    /// See the [Rust documentation for `something_else`](https://docs.rs/Something/latest/struct.Something.html#method.something_else) for more information.
+    ///
+    /// Additional information: [1](https://docs.rs/Something/latest/struct.Something.html#method.something_small), [2](https://docs.rs/SomethingElse/latest/struct.SomethingElse.html#method.something)
+    public func assertStruct(s: MyStruct) {
+        let keep = DiplomatKeep()
+        withExtendedLifetime(keep) { Opaque_assert_struct(handle, s.toNative(keep)) }
+    }
+
+    public static func returnsUsize() -> UInt {
+        let result = Opaque_returns_usize()
+        return UInt(bitPattern: result)
+    }
+
+    public static func returnsImported() -> ImportedStruct {
+        let result = Opaque_returns_imported()
+        return ImportedStruct(native: result, edges: [])
+    }
+}
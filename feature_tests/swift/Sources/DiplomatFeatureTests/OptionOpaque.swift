@@ -0,0 +1,59 @@
+// generated by diplomat-tool
+
+import DiplomatFeatureTestsFFI
+
+public final class OptionOpaque {
+    let handle: OpaquePointer
+    private let owned: Bool
+    /// Objects this may borrow from, kept alive for as long as this is
+    let edges: [Any]
+
+    init(handle: OpaquePointer, owned: Bool, edges: [Any]) {
+        self.handle = handle
+        self.owned = owned
+        self.edges = edges
+    }
+
+    deinit {
+        if owned {
+            OptionOpaque_destroy(handle)
+        }
+    }
+
+    public static func new(i: Int32) -> OptionOpaque? {
+        let result = OptionOpaque_new(i)
+        return result.map { OptionOpaque(handle: $0, owned: true, edges: []) }
+    }
+
+    public static func newNone() -> OptionOpaque? {
+        let result = OptionOpaque_new_none()
+        return result.map { OptionOpaque(handle: $0, owned: true, edges: []) }
+    }
+
+    public static func returns() -> OptionStruct? {
+        let result = OptionOpaque_returns()
+        if !result.is_ok {
+            return nil
+        }
+        return OptionStruct(native: result.ok, edges: [])
+    }
+
+    public static func newStruct() -> OptionStruct {
+        let result = OptionOpaque_new_struct()
+        return OptionStruct(native: result, edges: [])
+    }
+
+    public static func newStructNones() -> OptionStruct {
+        let result = OptionOpaque_new_struct_nones()
+        return OptionStruct(native: result, edges: [])
+    }
+
+    public func assertInteger(i: Int32) {
+        OptionOpaque_assert_integer(handle, i)
+    }
+
+    public static func optionOpaqueArgument(arg: OptionOpaque?) -> Bool {
+        let result = OptionOpaque_option_opaque_argument(arg?.handle)
+        return result
+    }
+}
@@ -0,0 +1,26 @@
+// generated by diplomat-tool
+
+import DiplomatFeatureTestsFFI
+
+public final class OptionOpaqueChar {
+    let handle: OpaquePointer
+    private let owned: Bool
+    /// Objects this may borrow from, kept alive for as long as this is
+    let edges: [Any]
+
+    init(handle: OpaquePointer, owned: Bool, edges: [Any]) {
+        self.handle = handle
+        self.owned = owned
+        self.edges = edges
+    }
+
+    deinit {
+        if owned {
+            OptionOpaqueChar_destroy(handle)
+        }
+    }
+
+    public func assertChar(ch: Unicode.Scalar) {
+        OptionOpaqueChar_assert_char(handle, ch.value)
+    }
+}
@@ -0,0 +1,30 @@
+// generated by diplomat-tool
+
+import DiplomatFeatureTestsFFI
+
+public struct OptionStruct {
+    public var a: OptionOpaque?
+    public var b: OptionOpaqueChar?
+    public var c: UInt32
+    public var d: OptionOpaque?
+
+    public init(a: OptionOpaque?, b: OptionOpaqueChar?, c: UInt32, d: OptionOpaque?) {
+        self.a = a
+        self.b = b
+        self.c = c
+        self.d = d
+    }
+
+    init(native: DiplomatFeatureTestsFFI.OptionStruct, edges: [Any]) {
+        self.init(a: native.a.map { OptionOpaque(handle: $0, owned: true, edges: []) }, b: native.b.map { OptionOpaqueChar(handle: $0, owned: true, edges: []) }, c: native.c, d: native.d.map { OptionOpaque(handle: $0, owned: true, edges: []) })
+    }
+
+    func toNative(_ keep: DiplomatKeep) -> DiplomatFeatureTestsFFI.OptionStruct {
+        var native = DiplomatFeatureTestsFFI.OptionStruct()
+        native.a = self.a?.handle
+        native.b = self.b?.handle
+        native.c = self.c
+        native.d = self.d?.handle
+        return native
+    }
+}
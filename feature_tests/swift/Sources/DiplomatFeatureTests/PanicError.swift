@@ -0,0 +1,16 @@
+// generated by diplomat-tool
+
+import DiplomatFeatureTestsFFI
+
+public enum PanicError: Int32 {
+    case panicked = 0
+    case tooLarge = 1
+
+    init(native: DiplomatFeatureTestsFFI.PanicError) {
+        self.init(rawValue: numericCast(native.rawValue))!
+    }
+
+    func toNative() -> DiplomatFeatureTestsFFI.PanicError {
+        return DiplomatFeatureTestsFFI.PanicError(rawValue: numericCast(rawValue))
+    }
+}
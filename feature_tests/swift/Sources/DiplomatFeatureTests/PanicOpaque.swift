@@ -0,0 +1,50 @@
+// generated by diplomat-tool
+
+import DiplomatFeatureTestsFFI
+
+public final class PanicOpaque {
+    let handle: OpaquePointer
+    private let owned: Bool
+    /// Objects this may borrow from, kept alive for as long as this is
+    let edges: [Any]
+
+    init(handle: OpaquePointer, owned: Bool, edges: [Any]) {
+        self.handle = handle
+        self.owned = owned
+        self.edges = edges
+    }
+
+    deinit {
+        if owned {
+            PanicOpaque_destroy(handle)
+        }
+    }
+
+    /// Panics, which foreign code is told about after the call
+    ///
+    /// - Throws: `DiplomatPanic` if it panics
+    public static func panics(message: String) throws {
+        let keep = DiplomatKeep()
+        let messageSlice = DiplomatRuntime.str8(message, keep)
+        withExtendedLifetime(keep) { PanicOpaque_panics(messageSlice.data, messageSlice.len) }
+        try DiplomatRuntime.checkPanic()
+    }
+
+    /// Panics when `x` is zero, which is reported as `PanicError::Panicked`
+    ///
+    /// - Throws: `DiplomatError` holding a `PanicError` on failure
+    public static func checkedDivide(x: UInt32) throws -> UInt32 {
+        let result = PanicOpaque_checked_divide(x)
+        if !result.is_ok {
+            throw DiplomatError(error: PanicError(native: result.err))
+        }
+        return result.ok
+    }
+
+    /// - Throws: `DiplomatPanic` if it panics
+    public static func notPanicking(x: UInt32) throws -> UInt32 {
+        let result = PanicOpaque_not_panicking(x)
+        try DiplomatRuntime.checkPanic()
+        return result
+    }
+}
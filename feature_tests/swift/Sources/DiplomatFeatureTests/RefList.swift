@@ -0,0 +1,27 @@
+// generated by diplomat-tool
+
+import DiplomatFeatureTestsFFI
+
+public final class RefList {
+    let handle: OpaquePointer
+    private let owned: Bool
+    /// Objects this may borrow from, kept alive for as long as this is
+    let edges: [Any]
+
+    init(handle: OpaquePointer, owned: Bool, edges: [Any]) {
+        self.handle = handle
+        self.owned = owned
+        self.edges = edges
+    }
+
+    deinit {
+        if owned {
+            RefList_destroy(handle)
+        }
+    }
+
+    public static func node(data: RefListParameter) -> RefList {
+        let result = RefList_node(data.handle)
+        return RefList(handle: result, owned: true, edges: [data])
+    }
+}
@@ -0,0 +1,97 @@
+// generated by diplomat-tool
+
+import DiplomatFeatureTestsFFI
+
+public final class ResultOpaque {
+    let handle: OpaquePointer
+    private let owned: Bool
+    /// Objects this may borrow from, kept alive for as long as this is
+    let edges: [Any]
+
+    init(handle: OpaquePointer, owned: Bool, edges: [Any]) {
+        self.handle = handle
+        self.owned = owned
+        self.edges = edges
+    }
+
+    deinit {
+        if owned {
+            ResultOpaque_destroy(handle)
+        }
+    }
+
+    /// - Throws: `DiplomatError` holding a `ErrorEnum` on failure
+    public convenience init(i: Int32) throws {
+        let result = ResultOpaque_new(i)
+        if !result.is_ok {
+            throw DiplomatError(error: ErrorEnum(native: result.err))
+        }
+        self.init(handle: result.ok, owned: true, edges: [])
+    }
+
+    /// - Throws: `DiplomatError` holding a `ErrorEnum` on failure
+    public static func failingFoo() throws -> ResultOpaque {
+        let result = ResultOpaque_new_failing_foo()
+        if !result.is_ok {
+            throw DiplomatError(error: ErrorEnum(native: result.err))
+        }
+        return ResultOpaque(handle: result.ok, owned: true, edges: [])
+    }
+
+    /// - Throws: `DiplomatError` holding a `ErrorEnum` on failure
+    public static func failingBar() throws -> ResultOpaque {
+        let result = ResultOpaque_new_failing_bar()
+        if !result.is_ok {
+            throw DiplomatError(error: ErrorEnum(native: result.err))
+        }
+        return ResultOpaque(handle: result.ok, owned: true, edges: [])
+    }
+
+    /// - Throws: `DiplomatError` on failure
+    public static func newFailingUnit() throws -> ResultOpaque {
+        let result = ResultOpaque_new_failing_unit()
+        if !result.is_ok {
+            throw DiplomatError(error: nil)
+        }
+        return ResultOpaque(handle: result.ok, owned: true, edges: [])
+    }
+
+    /// - Throws: `DiplomatError` holding a `ErrorStruct` on failure
+    public static func failingStruct(i: Int32) throws -> ResultOpaque {
+        let result = ResultOpaque_new_failing_struct(i)
+        if !result.is_ok {
+            throw DiplomatError(error: ErrorStruct(native: result.err, edges: []))
+        }
+        return ResultOpaque(handle: result.ok, owned: true, edges: [])
+    }
+
+    /// - Throws: `DiplomatError` holding a `ResultOpaque` on failure
+    public static func newInErr(i: Int32) throws {
+        let result = ResultOpaque_new_in_err(i)
+        if !result.is_ok {
+            throw DiplomatError(error: ResultOpaque(handle: result.err, owned: true, edges: []))
+        }
+    }
+
+    /// - Throws: `DiplomatError` on failure
+    public static func newInt(i: Int32) throws -> Int32 {
+        let result = ResultOpaque_new_int(i)
+        if !result.is_ok {
+            throw DiplomatError(error: nil)
+        }
+        return result.ok
+    }
+
+    /// - Throws: `DiplomatError` holding a `ResultOpaque` on failure
+    public static func newInEnumErr(i: Int32) throws -> ErrorEnum {
+        let result = ResultOpaque_new_in_enum_err(i)
+        if !result.is_ok {
+            throw DiplomatError(error: ResultOpaque(handle: result.err, owned: true, edges: []))
+        }
+        return ErrorEnum(native: result.ok)
+    }
+
+    public func assertInteger(i: Int32) {
+        ResultOpaque_assert_integer(handle, i)
+    }
+}
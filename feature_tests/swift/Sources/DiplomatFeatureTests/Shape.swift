@@ -0,0 +1,38 @@
+// generated by diplomat-tool
+
+import DiplomatFeatureTestsFFI
+
+public enum Shape {
+    case circle(radius: Double)
+    case rect(width: Double, height: Double)
+    case empty
+
+    init(native: DiplomatFeatureTestsFFI.Shape, edges: [Any]) {
+        switch native.tag.rawValue {
+        case 0:
+            self = .circle(radius: native.Circle.radius)
+        case 1:
+            self = .rect(width: native.Rect.width, height: native.Rect.height)
+        case 2:
+            self = .empty
+        default:
+            fatalError("Unknown Shape tag \(native.tag.rawValue)")
+        }
+    }
+
+    func toNative(_ keep: DiplomatKeep) -> DiplomatFeatureTestsFFI.Shape {
+        var native = DiplomatFeatureTestsFFI.Shape()
+        switch self {
+        case let .circle(radius):
+            native.tag = .init(rawValue: 0)
+            native.Circle.radius = radius
+        case let .rect(width, height):
+            native.tag = .init(rawValue: 1)
+            native.Rect.width = width
+            native.Rect.height = height
+        case .empty:
+            native.tag = .init(rawValue: 2)
+        }
+        return native
+    }
+}
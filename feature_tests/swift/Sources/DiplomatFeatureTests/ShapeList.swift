@@ -0,0 +1,42 @@
+// generated by diplomat-tool
+
+import DiplomatFeatureTestsFFI
+
+public final class ShapeList {
+    let handle: OpaquePointer
+    private let owned: Bool
+    /// Objects this may borrow from, kept alive for as long as this is
+    let edges: [Any]
+
+    init(handle: OpaquePointer, owned: Bool, edges: [Any]) {
+        self.handle = handle
+        self.owned = owned
+        self.edges = edges
+    }
+
+    deinit {
+        if owned {
+            ShapeList_destroy(handle)
+        }
+    }
+
+    public convenience init() {
+        let result = ShapeList_new()
+        self.init(handle: result, owned: true, edges: [])
+    }
+
+    public func push(shape: Shape) {
+        let keep = DiplomatKeep()
+        withExtendedLifetime(keep) { ShapeList_push(handle, shape.toNative(keep)) }
+    }
+
+    public func last() -> Shape {
+        let result = ShapeList_last(handle)
+        return Shape(native: result, edges: [])
+    }
+
+    public func totalArea() -> Double {
+        let result = ShapeList_total_area(handle)
+        return result
+    }
+}
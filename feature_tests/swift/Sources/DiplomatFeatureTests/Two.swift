@@ -0,0 +1,22 @@
+// generated by diplomat-tool
+
+import DiplomatFeatureTestsFFI
+
+public final class Two {
+    let handle: OpaquePointer
+    private let owned: Bool
+    /// Objects this may borrow from, kept alive for as long as this is
+    let edges: [Any]
+
+    init(handle: OpaquePointer, owned: Bool, edges: [Any]) {
+        self.handle = handle
+        self.owned = owned
+        self.edges = edges
+    }
+
+    deinit {
+        if owned {
+            Two_destroy(handle)
+        }
+    }
+}
@@ -0,0 +1,17 @@
+// generated by diplomat-tool
+
+import DiplomatFeatureTestsFFI
+
+public enum UnimportedEnum: Int32 {
+    case a = 0
+    case b = 1
+    case c = 2
+
+    init(native: DiplomatFeatureTestsFFI.UnimportedEnum) {
+        self.init(rawValue: numericCast(native.rawValue))!
+    }
+
+    func toNative() -> DiplomatFeatureTestsFFI.UnimportedEnum {
+        return DiplomatFeatureTestsFFI.UnimportedEnum(rawValue: numericCast(rawValue))
+    }
+}
@@ -0,0 +1,31 @@
+// generated by diplomat-tool
+
+import DiplomatFeatureTestsFFI
+
+public final class Unnamespaced {
+    let handle: OpaquePointer
+    private let owned: Bool
+    /// Objects this may borrow from, kept alive for as long as this is
+    let edges: [Any]
+
+    init(handle: OpaquePointer, owned: Bool, edges: [Any]) {
+        self.handle = handle
+        self.owned = owned
+        self.edges = edges
+    }
+
+    deinit {
+        if owned {
+            namespace_Unnamespaced_destroy(handle)
+        }
+    }
+
+    public static func make(e: AttrEnum) -> Unnamespaced {
+        let result = namespace_Unnamespaced_make(e.toNative())
+        return Unnamespaced(handle: result, owned: true, edges: [])
+    }
+
+    public func useNamespaced(n: AttrOpaque1) {
+        namespace_Unnamespaced_use_namespaced(handle, n.handle)
+    }
+}
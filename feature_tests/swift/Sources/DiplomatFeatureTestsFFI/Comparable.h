@@ -0,0 +1,30 @@
+#ifndef Comparable_H
+#define Comparable_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+#include "Comparable.d.h"
+
+#ifdef __cplusplus
+namespace capi {
+extern "C" {
+#endif // __cplusplus
+
+
+Comparable* namespace_Comparable_new(uint8_t int);
+
+int8_t namespace_Comparable_cmp(const Comparable* self, const Comparable* other);
+
+void namespace_Comparable_destroy(Comparable* self);
+
+
+#ifdef __cplusplus
+} // extern "C"
+} // namespace capi
+#endif // __cplusplus
+
+#endif // Comparable_H
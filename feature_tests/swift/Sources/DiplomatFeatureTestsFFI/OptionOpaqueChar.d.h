@@ -0,0 +1,24 @@
+#ifndef OptionOpaqueChar_D_H
+#define OptionOpaqueChar_D_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+
+#ifdef __cplusplus
+namespace capi {
+extern "C" {
+#endif // __cplusplus
+
+
+typedef struct OptionOpaqueChar OptionOpaqueChar;
+
+
+#ifdef __cplusplus
+} // extern "C"
+} // namespace capi
+#endif // __cplusplus
+
+#endif // OptionOpaqueChar_D_H
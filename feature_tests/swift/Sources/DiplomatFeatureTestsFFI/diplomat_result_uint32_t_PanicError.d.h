@@ -0,0 +1,30 @@
+#ifndef diplomat_result_uint32_t_PanicError_D_H
+#define diplomat_result_uint32_t_PanicError_D_H
+
+#include <stdio.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "diplomat_runtime.h"
+#include "PanicError.d.h"
+
+#ifdef __cplusplus
+namespace capi {
+extern "C" {
+#endif // __cplusplus
+
+
+typedef struct diplomat_result_uint32_t_PanicError {
+  union {
+    uint32_t ok;
+    PanicError err;
+  };
+  bool is_ok;
+} diplomat_result_uint32_t_PanicError;
+
+#ifdef __cplusplus
+} // extern "C"
+} // namespace capi
+#endif // __cplusplus
+
+#endif // diplomat_result_uint32_t_PanicError_D_H
@@ -0,0 +1,22 @@
+import XCTest
+import DiplomatFeatureTests
+
+final class PanicsTests: XCTestCase {
+    func testPanicIsThrown() throws {
+        XCTAssertThrowsError(try PanicOpaque.panics(message: "oops")) { error in
+            XCTAssertEqual((error as? DiplomatPanic)?.message, "oops")
+        }
+        // The panic was taken, so the next call doesn't throw it again
+        XCTAssertEqual(try PanicOpaque.notPanicking(x: 1), 2)
+    }
+
+    func testPanicInResultIsItsError() throws {
+        XCTAssertThrowsError(try PanicOpaque.checkedDivide(x: 0)) { error in
+            XCTAssertEqual((error as? DiplomatError)?.error as? PanicError, .panicked)
+        }
+        XCTAssertThrowsError(try PanicOpaque.checkedDivide(x: 200)) { error in
+            XCTAssertEqual((error as? DiplomatError)?.error as? PanicError, .tooLarge)
+        }
+        XCTAssertEqual(try PanicOpaque.checkedDivide(x: 5), 20)
+    }
+}
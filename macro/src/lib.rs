@@ -6,6 +6,7 @@ use diplomat_core::ast;
 
 mod enum_convert;
 mod transparent_convert;
+mod wit;
 
 fn cfgs_to_stream(attrs: &[Attribute]) -> proc_macro2::TokenStream {
     attrs
@@ -53,6 +54,19 @@ fn gen_params_at_boundary(ty: &ast::TypeName, name: &ast::Ident, mut on_expanded
                     Ident::new(&format!("{}_diplomat_len", name), Span::call_site())
                 );
             }
+            ast::TypeName::Box(inner) | ast::TypeName::Reference(_, _, inner)
+                if matches!(inner.as_ref(), ast::TypeName::DynTrait(_)) =>
+            {
+                let ast::TypeName::DynTrait(path) = inner.as_ref() else {
+                    unreachable!()
+                };
+                // Trait objects cross the boundary as their vtable, by value
+                let vtable = gen_trait_vtable_path(path);
+                on_expanded_closure_params(
+                    parse2(quote! { #vtable }).unwrap(),
+                    Ident::new(name.as_str(), Span::call_site()),
+                )
+            }
             o => on_expanded_closure_params(
                 o.to_syn(),
                 Ident::new(name.as_str(), Span::call_site())
@@ -270,6 +284,17 @@ fn gen_params_invocation(param: &ast::Param, expanded_params: &mut Vec<Expr>) {
             let param = &param.name;
             expanded_params.push(parse2(quote!(#param.into())).unwrap());
         }
+        ast::TypeName::Box(inner) if matches!(inner.as_ref(), ast::TypeName::DynTrait(_)) => {
+            let param = &param.name;
+            expanded_params.push(parse2(quote!(alloc::boxed::Box::new(#param))).unwrap());
+        }
+        ast::TypeName::Reference(_, _, inner)
+            if matches!(inner.as_ref(), ast::TypeName::DynTrait(_)) =>
+        {
+            // A borrowed trait object is still owned by the caller, so it must not be destroyed here
+            let param = &param.name;
+            expanded_params.push(parse2(quote!(&*core::mem::ManuallyDrop::new(#param))).unwrap());
+        }
         _ => {
             expanded_params.push(Expr::Path(ExprPath {
                 attrs: vec![],
@@ -280,6 +305,108 @@ fn gen_params_invocation(param: &ast::Param, expanded_params: &mut Vec<Expr>) {
     }
 }
 
+/// The path of the `#[repr(C)]` vtable struct generated for a trait.
+fn gen_trait_vtable_path(path: &ast::PathType) -> syn::Path {
+    let mut path = path.path.to_syn();
+    let last = path.segments.last_mut().unwrap();
+    last.ident = Ident::new(&format!("{}_VTable", last.ident), Span::call_site());
+    path
+}
+
+/// Generate the vtable struct for a trait, which foreign code fills in with a context
+/// pointer, one function pointer per method, and an optional destructor for the context.
+///
+/// The vtable implements the trait by calling through the function pointers, so it can
+/// be passed to Rust code as a `Box<dyn Trait>` or `&dyn Trait`.
+fn gen_trait_vtable(trt: &ast::Trait) -> Vec<Item> {
+    let trait_ident = Ident::new(trt.name.as_str(), Span::call_site());
+    let vtable_ident = Ident::new(&format!("{}_VTable", trt.name), Span::call_site());
+    let cfg = cfgs_to_stream(&trt.attrs.cfg);
+
+    let mut fields = vec![];
+    let mut impl_methods = vec![];
+    for m in &trt.methods {
+        let method_ident = Ident::new(m.name.as_str(), Span::call_site());
+
+        let mut fn_params: Vec<Type> = vec![parse_quote! { *mut core::ffi::c_void }];
+        let mut call_args: Vec<Expr> = vec![parse_quote! { self.ctx }];
+        let mut sig_params = vec![];
+        for p in &m.params {
+            gen_params_at_boundary(&p.ty, &p.name, |ty, _| fn_params.push(ty));
+
+            let param_ident = Ident::new(p.name.as_str(), Span::call_site());
+            match &p.ty {
+                ast::TypeName::StrReference(..)
+                | ast::TypeName::PrimitiveSlice(..)
+                | ast::TypeName::StrSlice(..) => {
+                    call_args.push(parse_quote! { #param_ident.as_ptr() });
+                    call_args.push(parse_quote! { #param_ident.len() });
+                }
+                _ => call_args.push(parse_quote! { #param_ident }),
+            }
+
+            let param_ty = p.ty.to_syn();
+            sig_params.push(quote! { #param_ident: #param_ty });
+        }
+
+        let return_tokens = match &m.return_type {
+            Some(return_type) => {
+                let return_type = return_type.to_syn();
+                quote! { -> #return_type }
+            }
+            None => quote! {},
+        };
+
+        let (for_lifetimes, lifetimes) = if m.lifetime_env.is_empty() {
+            (quote! {}, quote! {})
+        } else {
+            let lifetime_env = &m.lifetime_env;
+            (quote! { for<#lifetime_env> }, quote! { <#lifetime_env> })
+        };
+
+        let method_cfg = cfgs_to_stream(&m.attrs.cfg);
+        fields.push(quote! {
+            #method_cfg
+            pub #method_ident: #for_lifetimes extern "C" fn(#(#fn_params),*) #return_tokens
+        });
+        impl_methods.push(quote! {
+            #method_cfg
+            fn #method_ident #lifetimes(&self, #(#sig_params),*) #return_tokens {
+                (self.#method_ident)(#(#call_args),*)
+            }
+        });
+    }
+
+    vec![
+        syn::parse_quote! {
+            #[allow(non_camel_case_types)]
+            #[repr(C)]
+            #cfg
+            pub struct #vtable_ident {
+                pub ctx: *mut core::ffi::c_void,
+                #(#fields,)*
+                pub destroy: Option<extern "C" fn(*mut core::ffi::c_void)>,
+            }
+        },
+        syn::parse_quote! {
+            #cfg
+            impl #trait_ident for #vtable_ident {
+                #(#impl_methods)*
+            }
+        },
+        syn::parse_quote! {
+            #cfg
+            impl Drop for #vtable_ident {
+                fn drop(&mut self) {
+                    if let Some(destroy) = self.destroy {
+                        destroy(self.ctx);
+                    }
+                }
+            }
+        },
+    ]
+}
+
 fn gen_custom_type_this_ident() -> Pat {
     Pat::Ident(PatIdent {
         attrs: vec![],
@@ -343,34 +470,7 @@ fn gen_custom_type_field(m: &ast::Method) -> proc_macro2::TokenStream {
         if lifetime_env.is_empty() { quote! {} } else { quote! { for<#lifetime_env> } }
     };
 
-    let return_tokens = if let Some(return_type) = &m.return_type {
-        if let ast::TypeName::Result(ok, err, true) = return_type {
-            let ok = ok.to_syn();
-            let err = err.to_syn();
-            quote! { -> diplomat_runtime::DiplomatResult<#ok, #err> }
-        } else if let ast::TypeName::Ordering = return_type {
-            let return_type_syn = return_type.to_syn();
-            quote! { -> #return_type_syn }
-        } else if let ast::TypeName::Option(ty) = return_type {
-            match ty.as_ref() {
-                // pass by reference, Option becomes null
-                ast::TypeName::Box(..) | ast::TypeName::Reference(..) => {
-                    let return_type_syn = return_type.to_syn();
-                    quote! { -> #return_type_syn }
-                }
-                // anything else goes through DiplomatResult
-                _ => {
-                    let ty = ty.to_syn();
-                    quote! { -> diplomat_runtime::DiplomatResult<#ty, ()> }
-                }
-            }
-        } else {
-            let return_type_syn = return_type.to_syn();
-            quote! { -> #return_type_syn }
-        }
-    } else {
-        quote! {}
-    };
+    let (return_tokens, _) = gen_custom_type_return(m);
 
     syn::parse_quote! {
         #lifetimes extern "C" fn(#(#all_params),*) #return_tokens
@@ -401,71 +501,147 @@ fn gen_custom_type_method(strct: &ast::CustomType, m: &ast::Method) -> Item {
         quote! { #self_ident::#method_ident }
     };
 
-    let (return_tokens, maybe_into) = if let Some(return_type) = &m.return_type {
+    let (return_tokens, maybe_into) = gen_custom_type_return(m);
+
+    let writeable_flushes = m
+        .params
+        .iter()
+        .filter(|p| p.is_writeable())
+        .map(|p| {
+            let p = &p.name;
+            quote! { #p.flush(); }
+        })
+        .collect::<Vec<_>>();
+
+    let cfg = cfgs_to_stream(&m.attrs.cfg);
+
+    let body = if writeable_flushes.is_empty() {
+        quote! {
+            #method_invocation(#(#all_params_invocation),*) #maybe_into
+        }
+    } else {
+        quote! {
+            let ret = #method_invocation(#(#all_params_invocation),*);
+            #(#writeable_flushes)*
+            ret #maybe_into
+        }
+    };
+
+    let body = if m.attrs.catch_panics {
+        let (on_ok, on_panic) = match panic_fallback(m) {
+            PanicFallback::Error => (
+                quote! { ret },
+                quote! { Err(diplomat_runtime::panic::error_from_panic(panic)).into() },
+            ),
+            PanicFallback::Value(value) => (
+                quote! { ret },
+                quote! {{
+                    diplomat_runtime::panic::report_panic(panic);
+                    #value
+                }},
+            ),
+            PanicFallback::Uninit => (
+                quote! { core::mem::MaybeUninit::new(ret) },
+                quote! {{
+                    diplomat_runtime::panic::report_panic(panic);
+                    core::mem::MaybeUninit::zeroed()
+                }},
+            ),
+        };
+        quote! {
+            match std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| { #body })) {
+                Ok(ret) => #on_ok,
+                Err(panic) => #on_panic,
+            }
+        }
+    } else {
+        body
+    };
+
+    Item::Fn(syn::parse_quote! {
+        #[no_mangle]
+        #cfg
+        extern "C" fn #extern_ident #lifetimes(#(#all_params),*) #return_tokens {
+            #body
+        }
+    })
+}
+
+/// The return type of the C ABI function of a method, with the conversion from what the
+/// method returns
+fn gen_custom_type_return(
+    m: &ast::Method,
+) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    let (return_type, maybe_into) = if let Some(return_type) = &m.return_type {
         if let ast::TypeName::Result(ok, err, true) = return_type {
             let ok = ok.to_syn();
             let err = err.to_syn();
             (
-                quote! { -> diplomat_runtime::DiplomatResult<#ok, #err> },
+                quote! { diplomat_runtime::DiplomatResult<#ok, #err> },
                 quote! { .into() },
             )
         } else if let ast::TypeName::Ordering = return_type {
             let return_type_syn = return_type.to_syn();
-            (quote! { -> #return_type_syn }, quote! { as i8 })
+            (quote! { #return_type_syn }, quote! { as i8 })
         } else if let ast::TypeName::Option(ty) = return_type {
             match ty.as_ref() {
                 // pass by reference, Option becomes null
                 ast::TypeName::Box(..) | ast::TypeName::Reference(..) => {
                     let return_type_syn = return_type.to_syn();
-                    (quote! { -> #return_type_syn }, quote! {})
+                    (quote! { #return_type_syn }, quote! {})
                 }
                 // anything else goes through DiplomatResult
                 _ => {
                     let ty = ty.to_syn();
                     (
-                        quote! { -> diplomat_runtime::DiplomatResult<#ty, ()> },
+                        quote! { diplomat_runtime::DiplomatResult<#ty, ()> },
                         quote! { .ok_or(()).into() },
                     )
                 }
             }
         } else {
             let return_type_syn = return_type.to_syn();
-            (quote! { -> #return_type_syn }, quote! {})
+            (quote! { #return_type_syn }, quote! {})
         }
     } else {
-        (quote! {}, quote! {})
+        return (quote! {}, quote! {});
     };
 
-    let writeable_flushes = m
-        .params
-        .iter()
-        .filter(|p| p.is_writeable())
-        .map(|p| {
-            let p = &p.name;
-            quote! { #p.flush(); }
-        })
-        .collect::<Vec<_>>();
+    // The C ABI has no way of telling a value apart from one made up after a panic, so
+    // foreign code is told about the panic instead of using it
+    if m.attrs.catch_panics {
+        if let PanicFallback::Uninit = panic_fallback(m) {
+            return (
+                quote! { -> core::mem::MaybeUninit<#return_type> },
+                maybe_into,
+            );
+        }
+    }
+    (quote! { -> #return_type }, maybe_into)
+}
 
-    let cfg = cfgs_to_stream(&m.attrs.cfg);
+/// What the C ABI function of a `#[diplomat::catch_panics]` method returns when the
+/// method panicked
+enum PanicFallback {
+    /// The error the panic converts to, for methods returning a `Result`
+    Error,
+    /// A value that is valid to return, for methods returning nothing or an `Option`
+    Value(proc_macro2::TokenStream),
+    /// Zeroes, for methods returning anything else
+    Uninit,
+}
 
-    if writeable_flushes.is_empty() {
-        Item::Fn(syn::parse_quote! {
-            #[no_mangle]
-            #cfg
-            extern "C" fn #extern_ident #lifetimes(#(#all_params),*) #return_tokens {
-                #method_invocation(#(#all_params_invocation),*) #maybe_into
-            }
-        })
-    } else {
-        Item::Fn(syn::parse_quote! {
-            #[no_mangle]
-            #cfg
-            extern "C" fn #extern_ident #lifetimes(#(#all_params),*) #return_tokens {
-                let ret = #method_invocation(#(#all_params_invocation),*);
-                #(#writeable_flushes)*
-                ret #maybe_into
+fn panic_fallback(m: &ast::Method) -> PanicFallback {
+    match &m.return_type {
+        None => PanicFallback::Value(quote! {}),
+        Some(ast::TypeName::Result(..)) => PanicFallback::Error,
+        Some(ast::TypeName::Option(ty)) => match ty.as_ref() {
+            ast::TypeName::Box(..) | ast::TypeName::Reference(..) => {
+                PanicFallback::Value(quote! { None })
             }
-        })
+            _ => PanicFallback::Value(quote! { Err(()).into() }),
+        },
+        Some(_) => PanicFallback::Uninit,
     }
 }
 
@@ -504,6 +680,9 @@ impl AttributeInfo {
                         // diplomat-tool reads these, not diplomat::bridge.
                         // throw them away so rustc doesn't complain about unknown attributes
                         return false;
+                    } else if seg == "catch_panics" {
+                        // diplomat::bridge reads this through the AST
+                        return false;
                     } else if seg == "enum_convert" || seg == "transparent_convert" {
                         // diplomat::bridge doesn't read this, but it's handled separately
                         // as an attribute
@@ -526,7 +705,7 @@ impl AttributeInfo {
     }
 }
 
-fn gen_bridge(mut input: ItemMod, apiname_and_rs_entrypoint: Option<(Ident, Ident)>) -> ItemMod {
+fn gen_bridge(mut input: ItemMod, api: Option<ApiBridge>) -> ItemMod {
     let module = ast::Module::from_syn(&input, true);
     // Clean out any diplomat attributes so Rust doesn't get mad
     let _attrs = AttributeInfo::extract(&mut input.attrs);
@@ -534,6 +713,12 @@ fn gen_bridge(mut input: ItemMod, apiname_and_rs_entrypoint: Option<(Ident, Iden
 
     new_contents.push(parse2(quote! { use diplomat_runtime::*; }).unwrap());
 
+    // Items with errors are left out of the module, so we still generate code
+    // for everything else and report all the errors together
+    for error in module.all_errors() {
+        new_contents.push(Item::Verbatim(error.to_compile_error()));
+    }
+
     new_contents.iter_mut().for_each(|c| match c {
         Item::Struct(s) => {
             let info = AttributeInfo::extract(&mut s.attrs);
@@ -574,6 +759,8 @@ fn gen_bridge(mut input: ItemMod, apiname_and_rs_entrypoint: Option<(Ident, Iden
                     panic!("#[diplomat::opaque] not allowed on enum variants");
                 }
             }
+            // For data-carrying enums, `#[repr(C)]` lays the enum out as a C tagged union:
+            // a C-int-sized tag followed by a union of one struct per variant.
             *e = syn::parse_quote! {
                 #[repr(C)]
                 #[derive(Clone, Copy)]
@@ -582,6 +769,9 @@ fn gen_bridge(mut input: ItemMod, apiname_and_rs_entrypoint: Option<(Ident, Iden
         }
 
         Item::Impl(i) => {
+            // Only strips the attributes, like `#[diplomat::catch_panics]`, that the
+            // methods of the impl inherit through the AST
+            AttributeInfo::extract(&mut i.attrs);
             for item in &mut i.items {
                 if let syn::ImplItem::Fn(ref mut m) = *item {
                     let info = AttributeInfo::extract(&mut m.attrs);
@@ -591,9 +781,28 @@ fn gen_bridge(mut input: ItemMod, apiname_and_rs_entrypoint: Option<(Ident, Iden
                 }
             }
         }
+
+        Item::Trait(t) => {
+            let info = AttributeInfo::extract(&mut t.attrs);
+            if info.opaque {
+                panic!("#[diplomat::opaque] not allowed on traits")
+            }
+            for item in &mut t.items {
+                if let syn::TraitItem::Fn(ref mut m) = *item {
+                    let info = AttributeInfo::extract(&mut m.attrs);
+                    if info.opaque {
+                        panic!("#[diplomat::opaque] not allowed on methods")
+                    }
+                }
+            }
+        }
         _ => (),
     });
 
+    for trt in module.declared_traits.values() {
+        new_contents.extend(gen_trait_vtable(trt));
+    }
+
     for custom_type in module.declared_types.values() {
         custom_type.methods().iter().for_each(|m| {
             new_contents.push(gen_custom_type_method(custom_type, m));
@@ -623,8 +832,12 @@ fn gen_bridge(mut input: ItemMod, apiname_and_rs_entrypoint: Option<(Ident, Iden
         }));
     }
 
-    if let Some((apiname, rs_entrypoint)) = apiname_and_rs_entrypoint {
-        push_api_bridge(&module, &mut new_contents, apiname, rs_entrypoint);
+    if let Some(api) = api {
+        push_api_bridge(&module, &mut new_contents, api);
+    }
+
+    if cfg!(feature = "wit") {
+        new_contents.extend(wit::gen_exports(&module, &wit::interface_name()));
     }
 
     ItemMod {
@@ -639,6 +852,13 @@ fn gen_bridge(mut input: ItemMod, apiname_and_rs_entrypoint: Option<(Ident, Iden
 }
 
 /// Mark a module to be exposed through Diplomat-generated FFI.
+///
+/// With `#[diplomat::bridge(catch_panics)]`, or `#[diplomat::catch_panics]` on an impl or
+/// method, panics are caught at the FFI boundary and reported to the foreign code instead
+/// of unwinding into it. This needs the `catch_panics` feature of `diplomat-runtime`, and
+/// an `impl diplomat_runtime::panic::FromPanic for E` for every error type `E` a catching
+/// method returns in a `Result`. The impl must live outside the bridge module, since trait
+/// impls inside it fail with "Trait impls are not supported in Diplomat bridge modules".
 #[proc_macro_attribute]
 pub fn bridge(
     attr: proc_macro::TokenStream,
@@ -648,8 +868,12 @@ pub fn bridge(
     let mut refresh_api_fn = None;
     let mut additional_includes = vec![];
     let mut get_api_fn = None;
+    let mut api_layout = None;
+    let mut catch_panics = false;
 
     let args = parse_macro_input!(attr with punctuated::Punctuated::<Meta,syn::Token![,]>::parse_terminated);
+    let mut errors = vec![];
+    let expected_keys = "This macro only accepts `apiname`, `refresh_api_fn` `get_api_fn`, `additional_includes`, `api_layout` or `catch_panics`";
     args.into_iter().for_each(|arg| {
         match arg {
             Meta::NameValue(MetaNameValue { path, value, .. }) => {
@@ -664,30 +888,56 @@ pub fn bridge(
                                 } else if attr == "get_api_fn" {
                                     get_api_fn = Some(Ident::new(value.to_string().as_str(), Span::call_site()));
                                 } else {
-                                    panic!("This macro only accepts `apiname`, `refresh_api_fn` `get_api_fn` or `additional_includes`");
+                                    errors.push(Error::new_spanned(attr, expected_keys));
                                 }
                             } else {
-                                panic!("invalid macro attribute")
+                                errors.push(Error::new_spanned(path, "invalid macro attribute"));
                             }
                         },
+                        Expr::Lit(ExprLit { lit: Lit::Str(path), .. }) if attr == "api_layout" => {
+                            api_layout = Some(path);
+                        },
                         Expr::Array(ExprArray { elems, .. }) => {
                             elems.into_iter().for_each(|e| match e {
                                 Expr::Lit(ExprLit { lit, .. }) =>
                                     additional_includes.push(format!("{}", lit.to_token_stream())),
-                                x  => panic!("invalid additional_includes {}", x.to_token_stream()),
+                                x => errors.push(Error::new_spanned(&x, format!("invalid additional_includes {}", x.to_token_stream()))),
                             });
                         },
-                        _ => panic!("invalid macro attribute value {}", value.to_token_stream()),
+                        _ => errors.push(Error::new_spanned(&value, format!("invalid macro attribute value {}", value.to_token_stream()))),
                     },
-                    _ => panic!("This macro only accepts `apiname`, `refresh_api_fn` `get_api_fn` or `additional_includes`")
+                    _ => errors.push(Error::new_spanned(path, expected_keys)),
                 }
             }
-            _ => panic!("invalid macro attribute")
+            Meta::Path(path) if path.is_ident("catch_panics") => catch_panics = true,
+            arg => errors.push(Error::new_spanned(arg, "invalid macro attribute")),
+        }
+    });
+    let layout_file = api_layout.and_then(|path| match read_layout_file(&path) {
+        Ok(contents) => Some((path, contents)),
+        Err(e) => {
+            errors.push(e);
+            None
         }
     });
-    let expanded = gen_bridge(parse_macro_input!(input), apiname.zip(refresh_api_fn));
+    let api = apiname.zip(refresh_api_fn).map(|(apiname, rs_entrypoint)| ApiBridge {
+        apiname,
+        rs_entrypoint,
+        layout_file,
+    });
+    let mut input: ItemMod = parse_macro_input!(input);
+    // The same as putting `#[diplomat::catch_panics]` on the module, which the AST
+    // reads for every method
+    if catch_panics {
+        input.attrs.push(syn::parse_quote!(#[diplomat::catch_panics]));
+    }
+    let expanded = gen_bridge(input, api);
+    let errors = errors.iter().map(Error::to_compile_error);
     //println!("[RUST]\n{}", expanded.to_token_stream());
-    proc_macro::TokenStream::from(expanded.to_token_stream())
+    proc_macro::TokenStream::from(quote! {
+        #(#errors)*
+        #expanded
+    })
 }
 
 /// Generate From and Into implementations for a Diplomat enum
@@ -752,7 +1002,7 @@ mod tests {
     use syn::parse_quote;
     use tempfile::tempdir;
 
-    use super::gen_bridge;
+    use super::{gen_bridge, ApiBridge};
 
     fn rustfmt_code(code: &str) -> String {
         let dir = tempdir().unwrap();
@@ -894,6 +1144,90 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn mod_with_data_carrying_enum() {
+        insta::assert_snapshot!(rustfmt_code(
+            &gen_bridge(parse_quote! {
+                mod ffi {
+                    struct Point {
+                        x: f64,
+                        y: f64,
+                    }
+
+                    enum Shape {
+                        Circle { center: Point, r: f64 },
+                        Rect { w: f64, h: f64 },
+                        Empty,
+                    }
+
+                    impl Shape {
+                        pub fn area(self) -> f64 {
+                            unimplemented!()
+                        }
+                    }
+                }
+            }, None)
+            .to_token_stream()
+            .to_string()
+        ));
+    }
+
+    #[test]
+    fn mod_with_trait() {
+        insta::assert_snapshot!(rustfmt_code(
+            &gen_bridge(
+                parse_quote! {
+                    mod ffi {
+                        pub trait Logger {
+                            fn log(&self, level: u8, msg: &DiplomatStr);
+                            fn enabled(&self) -> bool;
+                        }
+
+                        #[diplomat::opaque]
+                        struct Worker;
+
+                        impl Worker {
+                            pub fn set_logger(&mut self, logger: Box<dyn Logger>) {
+                                unimplemented!()
+                            }
+
+                            pub fn run(&self, logger: &dyn Logger) {
+                                unimplemented!()
+                            }
+                        }
+                    }
+                },
+                None
+            )
+            .to_token_stream()
+            .to_string()
+        ));
+    }
+
+    #[test]
+    fn mod_with_errors() {
+        insta::assert_snapshot!(rustfmt_code(
+            &gen_bridge(parse_quote! {
+                mod ffi {
+                    struct Pair(u8, u8);
+
+                    impl Pair {
+                        pub fn new() {}
+                    }
+
+                    #[diplomat::opaque]
+                    struct Foo;
+
+                    impl Foo {
+                        pub fn bar(&self) {}
+                    }
+                }
+            }, None)
+            .to_token_stream()
+            .to_string()
+        ));
+    }
+
     #[test]
     fn mod_with_writeable_result() {
         insta::assert_snapshot!(rustfmt_code(
@@ -1048,24 +1382,272 @@ mod tests {
             .to_string()
         ));
     }
+
+    #[test]
+    fn catch_panics() {
+        let module: syn::ItemMod = parse_quote! {
+            mod ffi {
+                #[diplomat::opaque]
+                struct Foo(u8);
+
+                #[diplomat::catch_panics]
+                impl Foo {
+                    pub fn new(x: u8) -> Box<Foo> {
+                        unimplemented!()
+                    }
+
+                    pub fn set(&mut self, x: u8) {
+                        unimplemented!()
+                    }
+
+                    pub fn try_get(&self) -> Result<u8, ()> {
+                        unimplemented!()
+                    }
+
+                    pub fn maybe_get(&self) -> Option<u8> {
+                        unimplemented!()
+                    }
+
+                    pub fn maybe_self(&self) -> Option<&Foo> {
+                        unimplemented!()
+                    }
+
+                    pub fn describe(&self, to: &mut DiplomatWriteable) {
+                        unimplemented!()
+                    }
+                }
+            }
+        };
+
+        insta::assert_snapshot!(rustfmt_code(
+            &gen_bridge(module.clone(), None)
+                .to_token_stream()
+                .to_string()
+        ));
+
+        // The table has the signatures of the functions that return `MaybeUninit`
+        let api = ApiBridge {
+            apiname: parse_quote!(MyApi),
+            rs_entrypoint: parse_quote!(my_refresh_api),
+            layout_file: None,
+        };
+        insta::assert_snapshot!(rustfmt_code(
+            &gen_bridge(module, Some(api))
+                .to_token_stream()
+                .to_string()
+        ));
+    }
+
+    #[test]
+    fn wit_exports() {
+        let module = diplomat_core::ast::Module::from_syn(&parse_quote! {
+            mod ffi {
+                #[diplomat::opaque]
+                struct Counter(u32);
+
+                struct Point {
+                    x: i32,
+                    y: f64,
+                }
+
+                enum Direction {
+                    Up,
+                    Down,
+                }
+
+                impl Counter {
+                    #[diplomat::attr(*, constructor)]
+                    pub fn new(start: u32) -> Box<Counter> {
+                        unimplemented!()
+                    }
+
+                    pub fn add(&mut self, by: u32, label: &DiplomatStr, point: Point) -> Option<Point> {
+                        unimplemented!()
+                    }
+
+                    pub fn describe(&self, to: &mut DiplomatWriteable) -> Result<(), Direction> {
+                        unimplemented!()
+                    }
+
+                    pub fn try_clone(&self) -> Result<Box<Counter>, ()> {
+                        unimplemented!()
+                    }
+
+                    #[diplomat::attr(wit, disable)]
+                    pub fn disabled(&self) {}
+                }
+
+                impl Direction {
+                    pub fn flip(self) -> Direction {
+                        unimplemented!()
+                    }
+                }
+            }
+        }, true);
+        let items = super::wit::gen_exports(&module, "diplomat:test/test");
+        insta::assert_snapshot!(rustfmt_code(&quote::quote!(#(#items)*).to_string()));
+    }
+
+    #[test]
+    fn api_bridge() {
+        let module: syn::ItemMod = parse_quote! {
+            mod ffi {
+                #[diplomat::opaque]
+                struct Foo(u8);
+
+                impl Foo {
+                    pub fn new(x: u8) -> Box<Foo> {
+                        unimplemented!()
+                    }
+
+                    pub fn get(&self) -> u8 {
+                        unimplemented!()
+                    }
+                }
+            }
+        };
+        let api = |layout_file| ApiBridge {
+            apiname: parse_quote!(MyApi),
+            rs_entrypoint: parse_quote!(my_refresh_api),
+            layout_file,
+        };
+
+        insta::assert_snapshot!(rustfmt_code(
+            &gen_bridge(module.clone(), Some(api(None)))
+                .to_token_stream()
+                .to_string()
+        ));
+
+        // `old` is retired, and `new` and `get` go after the destructor
+        let layout = "type Foo\n  fn old() -> u8\n  drop Foo_destroy\n";
+        insta::assert_snapshot!(rustfmt_code(
+            &gen_bridge(
+                module,
+                Some(api(Some((parse_quote!("api.lock"), Some(layout.into())))))
+            )
+            .to_token_stream()
+            .to_string()
+        ));
+    }
+
+    #[test]
+    fn api_bridge_core_table() {
+        // Writeables and caught panics are handled through the runtime functions the
+        // core table has, so hosts that only have the table can call these
+        let module: syn::ItemMod = parse_quote! {
+            mod ffi {
+                #[diplomat::opaque]
+                struct Foo(u8);
+
+                #[diplomat::catch_panics]
+                impl Foo {
+                    pub fn describe(&self, to: &mut DiplomatWriteable) {
+                        unimplemented!()
+                    }
+                }
+            }
+        };
+        let api = ApiBridge {
+            apiname: parse_quote!(MyApi),
+            rs_entrypoint: parse_quote!(my_refresh_api),
+            layout_file: None,
+        };
+
+        insta::assert_snapshot!(rustfmt_code(
+            &gen_bridge(module, Some(api))
+                .to_token_stream()
+                .to_string()
+        ));
+    }
 }
 
-fn push_api_bridge(module: &ast::Module, new_contents: &mut Vec<Item>, apiname: Ident, rs_entrypoint: Ident) {
-    for custom_type in module.declared_types.values() {
-        let api_struct_ident = Ident::new(&format!("__{}_API__", custom_type.name().as_str()), Span::call_site());
-        let fields = custom_type.methods().iter().map(|m| -> Field {
-            let field_ident = Ident::new(m.name.as_str(), Span::call_site());
-            let field_ty = gen_custom_type_field(m);
-            syn::parse_quote! { pub #field_ident : #field_ty }
-        }).chain(std::iter::once({
-            // add destructor
-            let destroy_ident = Ident::new(custom_type.dtor_name().as_str(), Span::call_site());
-            let type_ident = custom_type.name();
-            let (lifetime_defs, lifetimes) = match custom_type.lifetimes() { None => (quote! {}, quote! {}),
-                Some(lifetime_env) => (quote! { for<#lifetime_env> }, lifetime_env.lifetimes_to_tokens())
-            };
-            syn::parse_quote! { pub #destroy_ident : #lifetime_defs extern "C" fn(this: Box<#type_ident #lifetimes>)}
-        }));
+/// The function table a bridge exports when it's declared with an `apiname`
+struct ApiBridge {
+    apiname: Ident,
+    rs_entrypoint: Ident,
+    /// The `api_layout` file the table keeps its layout in, with its contents if it
+    /// exists yet
+    layout_file: Option<(LitStr, Option<String>)>,
+}
+
+/// Read the `api_layout` file, which is relative to the crate being built
+fn read_layout_file(path: &LitStr) -> Result<Option<String>> {
+    let dir = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+    match std::fs::read_to_string(std::path::Path::new(&dir).join(path.value())) {
+        Ok(contents) => Ok(Some(contents)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(Error::new_spanned(path, format!("cannot read the API layout: {e}"))),
+    }
+}
+
+fn push_api_bridge(module: &ast::Module, new_contents: &mut Vec<Item>, api: ApiBridge) {
+    let ApiBridge { apiname, rs_entrypoint, layout_file } = api;
+    let types = module.declared_types.values();
+    let layout = match &layout_file {
+        None => ast::ApiLayout::new(types),
+        Some((path, contents)) => {
+            if contents.is_some() {
+                // So that the crate is rebuilt when the layout changes
+                new_contents.push(syn::parse_quote! {
+                    const _: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/", #path));
+                });
+            }
+            match ast::ApiLayout::append_only(types, contents.as_deref().unwrap_or_default()) {
+                Ok(layout) => layout,
+                Err(errors) => {
+                    for error in errors {
+                        new_contents.push(Item::Verbatim(Error::new_spanned(path, error).to_compile_error()));
+                    }
+                    return;
+                }
+            }
+        }
+    };
+
+    let mut api_fields: Vec<Field> = vec![];
+    let mut api_field_values: Vec<FieldValue> = vec![];
+    for ty_layout in &layout.types {
+        let api_field_ident = Ident::new(&ty_layout.name, Span::call_site());
+        let custom_type = match module.declared_types.get(&ast::Ident::from(ty_layout.name.clone())) {
+            Some(custom_type) if !ty_layout.retired => custom_type,
+            // Retired types are null
+            _ => {
+                api_fields.push(syn::parse_quote! { pub #api_field_ident : Option<&'static std::ffi::c_void> });
+                api_field_values.push(syn::parse_quote! { #api_field_ident : None });
+                continue;
+            }
+        };
+
+        let api_struct_ident = Ident::new(&format!("__{}_API__", ty_layout.name), Span::call_site());
+        let api_static_ident = Ident::new(&format!("__{}_API_TABLE__", ty_layout.name), Span::call_site());
+        let mut fields: Vec<Field> = vec![syn::parse_quote! { pub size: usize }];
+        let mut field_values: Vec<FieldValue> = vec![syn::parse_quote! { size: std::mem::size_of::<#api_struct_ident>() }];
+        for slot in &ty_layout.slots {
+            let field_ident = Ident::new(&slot.name, Span::call_site());
+            let method = custom_type.methods().iter().find(|m| m.name.as_str() == slot.name);
+            match (slot.retired, &slot.signature, method) {
+                (false, Some(_), Some(m)) => {
+                    let field_ty = gen_custom_type_field(m);
+                    let extern_ident = Ident::new(m.full_path_name.as_str(), Span::call_site());
+                    fields.push(syn::parse_quote! { pub #field_ident : #field_ty });
+                    field_values.push(syn::parse_quote! { #field_ident : #extern_ident });
+                }
+                (false, None, _) => {
+                    // add destructor
+                    let type_ident = custom_type.name();
+                    let (lifetime_defs, lifetimes) = match custom_type.lifetimes() { None => (quote! {}, quote! {}),
+                        Some(lifetime_env) => (quote! { for<#lifetime_env> }, lifetime_env.lifetimes_to_tokens())
+                    };
+                    fields.push(syn::parse_quote! { pub #field_ident : #lifetime_defs extern "C" fn(this: Box<#type_ident #lifetimes>) });
+                    field_values.push(syn::parse_quote! { #field_ident : #field_ident });
+                }
+                // Retired methods are null
+                _ => {
+                    fields.push(syn::parse_quote! { pub #field_ident : Option<extern "C" fn()> });
+                    field_values.push(syn::parse_quote! { #field_ident : None });
+                }
+            }
+        }
         new_contents.push(syn::parse_quote! {
             #[allow(non_camel_case_types)]
             #[allow(non_snake_case)]
@@ -1074,60 +1656,88 @@ fn push_api_bridge(module: &ast::Module, new_contents: &mut Vec<Item>, apiname:
                 #(#fields),*
             }
         });
-
-        let field_idents = custom_type.methods().iter().map(|m| -> FieldValue {
-            let field_ident = Ident::new(m.name.as_str(), Span::call_site());
-            let extern_ident = Ident::new(m.full_path_name.as_str(), Span::call_site());
-            syn::parse_quote! { #field_ident : #extern_ident }
-        }).chain(std::iter::once({
-            // add destructor
-            let destroy_ident = Ident::new(custom_type.dtor_name().as_str(), Span::call_site());
-            syn::parse_quote! { #destroy_ident : #destroy_ident }
-        }));
-        let api_method_ident = Ident::new(&format!("__get_{}_api__", custom_type.name().as_str()), Span::call_site());
         new_contents.push(syn::parse_quote! {
-            #[allow(non_snake_case)]
-            fn #api_method_ident() -> #api_struct_ident {
-                #api_struct_ident {
-                    #(#field_idents),*
-                }
-            }
+            #[allow(non_upper_case_globals)]
+            static #api_static_ident: #api_struct_ident = #api_struct_ident {
+                #(#field_values),*
+            };
         });
-    }
 
-    let api_fields = module.declared_types.values().map(|custom_type| -> Field {
-        let api_field_ident = Ident::new(custom_type.name().as_str(), Span::call_site());
-        let api_struct_ident = Ident::new(&format!("__{}_API__", custom_type.name().as_str()), Span::call_site());
-        syn::parse_quote! {
-            pub #api_field_ident : #api_struct_ident 
-        }
-    });
+        api_fields.push(syn::parse_quote! { pub #api_field_ident : &'static #api_struct_ident });
+        api_field_values.push(syn::parse_quote! { #api_field_ident : &#api_static_ident });
+    }
 
-    let api_field_idents = module.declared_types.values().map(|custom_type| -> FieldValue {
-        let api_field_ident = Ident::new(custom_type.name().as_str(), Span::call_site());
-        let api_method_ident = Ident::new(&format!("__get_{}_api__", custom_type.name().as_str()), Span::call_site());
-        syn::parse_quote! { #api_field_ident : #api_method_ident() }
-    });
+    let version = ast::API_LAYOUT_VERSION;
+    let append_only = u32::from(layout.append_only);
+    let hash = layout.hash();
+
+    // Only libraries that catch panics have the functions that report them
+    let catches_panics = module.declared_types.values()
+        .any(|ty| ty.methods().iter().any(|m| m.attrs.catch_panics));
+    let (panic_fields, panic_field_values) = if catches_panics {
+        (
+            quote! {
+                pub diplomat_panicked: extern "C" fn() -> bool,
+                pub diplomat_take_panic: extern "C" fn(out: &mut diplomat_runtime::DiplomatWriteable),
+            },
+            quote! {
+                diplomat_panicked: diplomat_runtime::panic::diplomat_panicked,
+                diplomat_take_panic: diplomat_runtime::panic::diplomat_take_panic,
+            },
+        )
+    } else {
+        (quote! {}, quote! {})
+    };
 
     new_contents.push(syn::parse_quote! {
         mod __core__ {
+            /// The start of the table, which hosts check before using anything else
+            #[allow(non_camel_case_types)]
+            #[repr(C)]
+            pub struct __API_Header__ {
+                pub size: usize,
+                pub version: u32,
+                /// Whether slots are only ever appended, in which case the hash changes
+                /// whenever they are and hosts check the sizes instead
+                pub append_only: u32,
+                pub hash: u64,
+            }
+
             #[allow(non_camel_case_types)]
             #[allow(non_snake_case)]
             #[repr(C)]
             pub struct __Core_API__ {
+                pub size: usize,
                 pub free: extern "C" fn(ptr: *mut std::ffi::c_void),
+                // The functions of diplomat-runtime, for hosts that don't link the library
+                pub diplomat_alloc: unsafe extern "C" fn(size: usize, align: usize) -> *mut u8,
+                pub diplomat_free: unsafe extern "C" fn(ptr: *mut u8, size: usize, align: usize),
+                pub diplomat_buffer_writeable_create: extern "C" fn(cap: usize) -> *mut diplomat_runtime::DiplomatWriteable,
+                pub diplomat_buffer_writeable_get_bytes: extern "C" fn(this: &diplomat_runtime::DiplomatWriteable) -> *mut u8,
+                pub diplomat_buffer_writeable_len: extern "C" fn(this: &diplomat_runtime::DiplomatWriteable) -> usize,
+                pub diplomat_buffer_writeable_destroy: unsafe extern "C" fn(this: *mut diplomat_runtime::DiplomatWriteable),
+                pub diplomat_simple_writeable: unsafe extern "C" fn(buf: *mut u8, buf_size: usize) -> diplomat_runtime::DiplomatWriteable,
+                #panic_fields
             }
 
-            #[no_mangle]
+            // Not exported, since it would take the place of the `free` of libc
             pub extern "C" fn free(ptr: *mut std::ffi::c_void) {
                 unsafe { drop(Box::from_raw(ptr)); }
             }
 
-            pub fn api_get_core() -> __Core_API__ {
-                __Core_API__ {
-                    free,
-                }
-            }
+            #[allow(non_upper_case_globals)]
+            pub static __Core_API_TABLE__: __Core_API__ = __Core_API__ {
+                size: std::mem::size_of::<__Core_API__>(),
+                free,
+                diplomat_alloc: diplomat_runtime::diplomat_alloc,
+                diplomat_free: diplomat_runtime::diplomat_free,
+                diplomat_buffer_writeable_create: diplomat_runtime::diplomat_buffer_writeable_create,
+                diplomat_buffer_writeable_get_bytes: diplomat_runtime::diplomat_buffer_writeable_get_bytes,
+                diplomat_buffer_writeable_len: diplomat_runtime::diplomat_buffer_writeable_len,
+                diplomat_buffer_writeable_destroy: diplomat_runtime::diplomat_buffer_writeable_destroy,
+                diplomat_simple_writeable: diplomat_runtime::diplomat_simple_writeable,
+                #panic_field_values
+            };
         }
     });
 
@@ -1136,18 +1746,30 @@ fn push_api_bridge(module: &ast::Module, new_contents: &mut Vec<Item>, apiname:
         #[allow(non_snake_case)]
         #[repr(C)]
         pub struct #apiname {
-            pub core: __core__::__Core_API__,
+            pub header: __core__::__API_Header__,
+            pub core: &'static __core__::__Core_API__,
             #(#api_fields),*
         }
     });
 
+    new_contents.push(syn::parse_quote! {
+        #[allow(non_upper_case_globals)]
+        static __API_TABLE__: #apiname = #apiname {
+            header: __core__::__API_Header__ {
+                size: std::mem::size_of::<#apiname>(),
+                version: #version,
+                append_only: #append_only,
+                hash: #hash,
+            },
+            core: &__core__::__Core_API_TABLE__,
+            #(#api_field_values),*
+        };
+    });
+
     new_contents.push(syn::parse_quote! {
         #[no_mangle]
-        pub extern "C" fn #rs_entrypoint() -> #apiname {
-            #apiname {
-                core: __core__::api_get_core(),
-                #(#api_field_idents),*
-            }
+        pub extern "C" fn #rs_entrypoint() -> &'static #apiname {
+            &__API_TABLE__
         }
     });
-}
\ No newline at end of file
+}
@@ -0,0 +1,1019 @@
+//! Adapters for the WebAssembly component model.
+//!
+//! With the `wit` feature, `#[diplomat::bridge]` also exports each method that the `wit` target
+//! of diplomat-tool puts in the generated world, under its component model name, lifting its
+//! parameters and lowering its results the way the canonical ABI lays them out.
+//!
+//! The two sides never see each other's output, so the rules for what gets exported here must
+//! be kept in sync with `tool/src/wit`.
+
+use std::collections::BTreeMap;
+
+use diplomat_core::ast;
+use diplomat_core::hir::{self, AttributeValidator};
+use heck::{ToKebabCase, ToSnakeCase};
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote, ToTokens};
+use syn::{Ident, Item};
+
+use crate::cfgs_to_stream;
+
+/// The canonical ABI passes parameters through memory past this many flat values
+const MAX_FLAT_PARAMS: usize = 16;
+
+/// The interface the library exports, which is named after its Cargo package.
+pub fn interface_name() -> String {
+    let package = std::env::var("CARGO_PKG_NAME")
+        .unwrap_or_default()
+        .to_kebab_case();
+    format!("diplomat:{package}/{package}")
+}
+
+/// The attribute validator of the `wit` target, which decides which items are disabled and
+/// which methods are constructors. Keep it the same as the one diplomat-tool uses.
+fn attribute_validator() -> hir::BasicAttributeValidator {
+    let mut validator = hir::BasicAttributeValidator::new("wit");
+    validator.support.disabling = true;
+    validator.support.constructors = true;
+    validator.support.named_constructors = true;
+    validator
+}
+
+/// Generate the adapters for all the exportable methods and opaques of a module
+pub fn gen_exports(module: &ast::Module, interface: &str) -> Vec<Item> {
+    let cx = WitContext {
+        module,
+        interface,
+        validator: attribute_validator(),
+    };
+
+    let mut items = vec![];
+    if cx.is_disabled(&module.attrs) {
+        return items;
+    }
+    for custom_type in module.declared_types.values() {
+        if !cx.is_exported(custom_type) {
+            continue;
+        }
+        if let ast::CustomType::Opaque(opaque) = custom_type {
+            items.push(cx.gen_dtor(opaque));
+        }
+        for method in custom_type.methods() {
+            if let Some(adapter) = cx.adapter(custom_type, method) {
+                items.extend(cx.gen_adapter(custom_type, method, &adapter));
+            }
+        }
+    }
+    items
+}
+
+/// The WIT types methods can use, as far as they can be resolved from inside the module
+enum WitType<'a> {
+    Primitive(ast::PrimitiveType),
+    /// An `s8`
+    Ordering,
+    Enum(&'a ast::Enum),
+    /// A struct, with the types of its fields
+    Record(&'a ast::Struct, Vec<WitType<'a>>),
+    /// An owned opaque, returned as an `own<T>`
+    Own(&'a ast::PathType),
+    /// A borrowed opaque, passed as a `borrow<T>`
+    Borrow(&'a ast::PathType, ast::Mutability),
+    Option(Box<WitType<'a>>),
+    Result(Option<Box<WitType<'a>>>, Option<Box<WitType<'a>>>),
+    /// A `string`, or a `list<u16>` for UTF-16
+    Str(ast::StringEncoding, Ownership),
+    List(ast::PrimitiveType, Ownership),
+    /// A `list<string>`, or a `list<list<u16>>` for UTF-16
+    StrList(ast::StringEncoding),
+    /// The `string` the contents of a writeable are returned as
+    Writeable,
+}
+
+/// How a `string` or `list` parameter is handed to the method
+#[derive(Clone, Copy)]
+enum Ownership {
+    /// Freed after the call
+    Borrowed,
+    /// Leaked, since the method may hold on to it
+    Static,
+    /// Moved into the method
+    Owned,
+}
+
+/// The flattened core wasm types of the canonical ABI
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum FlatType {
+    I32,
+    I64,
+    F32,
+    F64,
+}
+
+impl FlatType {
+    fn tokens(self) -> TokenStream {
+        match self {
+            FlatType::I32 => quote!(i32),
+            FlatType::I64 => quote!(i64),
+            FlatType::F32 => quote!(f32),
+            FlatType::F64 => quote!(f64),
+        }
+    }
+
+    /// The type a case of a `result` or `option` shares with the other case
+    fn join(self, other: FlatType) -> FlatType {
+        match (self, other) {
+            (a, b) if a == b => a,
+            (FlatType::I32, FlatType::F32) | (FlatType::F32, FlatType::I32) => FlatType::I32,
+            _ => FlatType::I64,
+        }
+    }
+}
+
+/// How a method is exported
+struct Adapter<'a> {
+    /// The name of the export, without the interface
+    name: String,
+    self_ty: Option<WitType<'a>>,
+    params: Vec<WitType<'a>>,
+    output: Option<WitType<'a>>,
+}
+
+struct WitContext<'a> {
+    module: &'a ast::Module,
+    interface: &'a str,
+    validator: hir::BasicAttributeValidator,
+}
+
+impl<'a> WitContext<'a> {
+    /// Whether a `#[diplomat::attr(..)]` with the given path applies to the `wit` target
+    fn has_attr(&self, attrs: &ast::Attrs, name: &str) -> bool {
+        attrs.attrs.iter().any(|attr| {
+            matches!(attr.meta, syn::Meta::Path(ref path) if path.is_ident(name))
+                && self.validator.satisfies_cfg(&attr.cfg).unwrap_or(false)
+        })
+    }
+
+    fn is_disabled(&self, attrs: &ast::Attrs) -> bool {
+        self.has_attr(attrs, "disable")
+    }
+
+    /// Find a type declared in this module. Types from other modules can't be
+    /// resolved here, so only opaques behind a pointer may be imported.
+    fn local_type(&self, path: &ast::PathType) -> Option<&'a ast::CustomType> {
+        match &path.path.elements[..] {
+            [name] => self.module.declared_types.get(name),
+            _ => None,
+        }
+    }
+
+    /// Whether a type is part of the generated world
+    fn is_exported(&self, custom_type: &'a ast::CustomType) -> bool {
+        if self.is_disabled(custom_type.attrs())
+            || custom_type
+                .lifetimes()
+                .map(|l| !l.is_empty())
+                .unwrap_or(false)
+        {
+            return false;
+        }
+        match custom_type {
+            ast::CustomType::Struct(strct) => self.record(strct).is_some(),
+            ast::CustomType::Enum(enm) => !enm.is_data_carrying(),
+            ast::CustomType::Opaque(_) => true,
+            _ => false,
+        }
+    }
+
+    /// A struct as a record, if all its fields are plain types. Records can't be empty.
+    fn record(&self, strct: &'a ast::Struct) -> Option<WitType<'a>> {
+        if strct.fields.is_empty() {
+            return None;
+        }
+        let fields = strct
+            .fields
+            .iter()
+            .map(|(_, ty, _)| self.plain_type(ty))
+            .collect::<Option<Vec<_>>>()?;
+        Some(WitType::Record(strct, fields))
+    }
+
+    /// Types that are copied by value in both directions
+    fn plain_type(&self, ty: &ast::TypeName) -> Option<WitType<'a>> {
+        match ty {
+            ast::TypeName::Primitive(ast::PrimitiveType::i128 | ast::PrimitiveType::u128) => None,
+            ast::TypeName::Primitive(prim) => Some(WitType::Primitive(*prim)),
+            ast::TypeName::Named(path) | ast::TypeName::SelfType(path) => {
+                let custom_type = self.local_type(path)?;
+                if !self.is_exported(custom_type) {
+                    return None;
+                }
+                match custom_type {
+                    ast::CustomType::Struct(strct) => self.record(strct),
+                    ast::CustomType::Enum(enm) => Some(WitType::Enum(enm)),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// An opaque behind a pointer, which may have been imported from another module
+    fn opaque_path(&self, ty: &'a ast::TypeName) -> Option<&'a ast::PathType> {
+        match ty {
+            ast::TypeName::Named(path) | ast::TypeName::SelfType(path) => {
+                if !path.lifetimes.is_empty() {
+                    return None;
+                }
+                match self.local_type(path) {
+                    Some(custom_type @ ast::CustomType::Opaque(_))
+                        if self.is_exported(custom_type) =>
+                    {
+                        Some(path)
+                    }
+                    Some(_) => None,
+                    None => Some(path),
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn param_type(&self, ty: &'a ast::TypeName) -> Option<WitType<'a>> {
+        let ownership = |lifetime: &ast::Lifetime| match lifetime {
+            ast::Lifetime::Static => Ownership::Static,
+            _ => Ownership::Borrowed,
+        };
+        match ty {
+            ast::TypeName::Reference(_, mutability, inner) => self
+                .opaque_path(inner)
+                .map(|path| WitType::Borrow(path, *mutability)),
+            ast::TypeName::Option(inner) => match &**inner {
+                ast::TypeName::Reference(_, mutability, inner) => self
+                    .opaque_path(inner)
+                    .map(|path| WitType::Option(Box::new(WitType::Borrow(path, *mutability)))),
+                _ => None,
+            },
+            ast::TypeName::StrReference(lifetime, encoding) => Some(WitType::Str(
+                *encoding,
+                lifetime.as_ref().map(ownership).unwrap_or(Ownership::Owned),
+            )),
+            ast::TypeName::PrimitiveSlice(None, prim) => {
+                self.plain_type(&ast::TypeName::Primitive(*prim))?;
+                Some(WitType::List(*prim, Ownership::Owned))
+            }
+            ast::TypeName::PrimitiveSlice(Some((lifetime, ast::Mutability::Immutable)), prim) => {
+                self.plain_type(&ast::TypeName::Primitive(*prim))?;
+                Some(WitType::List(*prim, ownership(lifetime)))
+            }
+            ast::TypeName::StrSlice(encoding) => Some(WitType::StrList(*encoding)),
+            _ => self.plain_type(ty),
+        }
+    }
+
+    /// Types that can be returned. The component model can't express results borrowing from
+    /// parameters, so those are left out.
+    fn output_type(&self, ty: &'a ast::TypeName) -> Option<WitType<'a>> {
+        match ty {
+            ast::TypeName::Ordering => Some(WitType::Ordering),
+            ast::TypeName::Box(inner) => self.opaque_path(inner).map(WitType::Own),
+            ast::TypeName::Option(inner) => match &**inner {
+                ast::TypeName::Box(inner) => self
+                    .opaque_path(inner)
+                    .map(|path| WitType::Option(Box::new(WitType::Own(path)))),
+                inner => self
+                    .plain_type(inner)
+                    .map(|ty| WitType::Option(Box::new(ty))),
+            },
+            ast::TypeName::Result(ok, err, true) => Some(WitType::Result(
+                self.result_case(ok)?,
+                self.result_case(err)?,
+            )),
+            _ => self.plain_type(ty),
+        }
+    }
+
+    /// The type of a case of a `result`, which may be empty
+    fn result_case(&self, ty: &'a ast::TypeName) -> Option<Option<Box<WitType<'a>>>> {
+        match ty {
+            ast::TypeName::Unit => Some(None),
+            ast::TypeName::Box(inner) => self
+                .opaque_path(inner)
+                .map(|path| Some(Box::new(WitType::Own(path)))),
+            ty => self.plain_type(ty).map(|ty| Some(Box::new(ty))),
+        }
+    }
+
+    /// Decide how a method is exported, if it can be
+    fn adapter(
+        &self,
+        custom_type: &'a ast::CustomType,
+        method: &'a ast::Method,
+    ) -> Option<Adapter<'a>> {
+        if self.is_disabled(&method.attrs) {
+            return None;
+        }
+
+        let self_ty = match &method.self_param {
+            None => None,
+            Some(self_param) => Some(match (custom_type, &self_param.reference) {
+                (ast::CustomType::Opaque(_), Some((_, mutability))) => {
+                    WitType::Borrow(&self_param.path_type, *mutability)
+                }
+                (ast::CustomType::Struct(strct), None) => self.record(strct)?,
+                (ast::CustomType::Enum(enm), None) => WitType::Enum(enm),
+                _ => return None,
+            }),
+        };
+
+        let mut params = vec![];
+        let mut writeable = false;
+        for param in &method.params {
+            if param.is_writeable() {
+                writeable = true;
+            } else {
+                params.push(self.param_type(&param.ty)?);
+            }
+        }
+
+        let output = match (&method.return_type, writeable) {
+            (None | Some(ast::TypeName::Unit), false) => None,
+            (None | Some(ast::TypeName::Unit), true) => Some(WitType::Writeable),
+            (Some(ast::TypeName::Result(ok, err, true)), true) if **ok == ast::TypeName::Unit => {
+                Some(WitType::Result(
+                    Some(Box::new(WitType::Writeable)),
+                    self.result_case(err)?,
+                ))
+            }
+            (Some(_), true) => return None,
+            (Some(ty), false) => Some(self.output_type(ty)?),
+        };
+
+        let flat_params = self_ty
+            .iter()
+            .chain(&params)
+            .map(|ty| ty.flat().len())
+            .sum::<usize>();
+        if flat_params > MAX_FLAT_PARAMS {
+            return None;
+        }
+
+        let type_name = custom_type.name().as_str().to_kebab_case();
+        let method_name = method.name.as_str().to_kebab_case();
+        let name = match custom_type {
+            ast::CustomType::Opaque(_) => {
+                let returns_self = matches!(
+                    &method.return_type,
+                    Some(ast::TypeName::Box(inner)) if matches!(
+                        &**inner,
+                        ast::TypeName::SelfType(_)
+                    ) || matches!(
+                        &**inner,
+                        ast::TypeName::Named(path) if path.path.elements.len() == 1
+                            && &path.path.elements[0] == custom_type.name()
+                    )
+                );
+                if self_ty.is_some() {
+                    format!("[method]{type_name}.{method_name}")
+                } else if returns_self && self.has_attr(&method.attrs, "constructor") {
+                    format!("[constructor]{type_name}")
+                } else {
+                    format!("[static]{type_name}.{method_name}")
+                }
+            }
+            _ => format!("{type_name}-{method_name}"),
+        };
+
+        Some(Adapter {
+            name,
+            self_ty,
+            params,
+            output,
+        })
+    }
+
+    /// The destructor the host calls once it drops the last handle to a resource
+    fn gen_dtor(&self, opaque: &ast::OpaqueStruct) -> Item {
+        let export_name = format!(
+            "{}#[dtor]{}",
+            self.interface,
+            opaque.name.as_str().to_kebab_case()
+        );
+        let ident = format_ident!("__wit_dtor_{}", opaque.name.as_str());
+        let ty = Ident::new(opaque.name.as_str(), Span::call_site());
+        let cfg = cfgs_to_stream(&opaque.attrs.cfg);
+        syn::parse_quote! {
+            #[cfg(target_arch = "wasm32")]
+            #cfg
+            #[allow(non_snake_case)]
+            #[export_name = #export_name]
+            extern "C" fn #ident(rep: i32) {
+                drop(unsafe { Box::from_raw(rep as usize as *mut #ty) });
+            }
+        }
+    }
+
+    fn gen_adapter(
+        &self,
+        custom_type: &ast::CustomType,
+        method: &ast::Method,
+        adapter: &Adapter,
+    ) -> Vec<Item> {
+        let export_name = format!("{}#{}", self.interface, adapter.name);
+        let ident = format_ident!("__wit_{}", method.full_path_name.as_str());
+        let cfg = cfgs_to_stream(&method.attrs.cfg);
+
+        let mut gen = BodyGen {
+            prelude: vec![],
+            resources: BTreeMap::new(),
+            flat_params: vec![],
+            counter: 0,
+        };
+
+        let self_expr = adapter.self_ty.as_ref().map(|ty| gen.lift(ty));
+        let mut args = vec![];
+        let mut params = adapter.params.iter();
+        let writeable = adapter
+            .output
+            .as_ref()
+            .filter(|ty| ty.has_writeable())
+            .map(|_| Ident::new("writeable", Span::call_site()));
+        for param in &method.params {
+            if param.is_writeable() {
+                args.push(quote!(writeable.get_mut()));
+            } else {
+                args.push(gen.lift(params.next().unwrap()));
+            }
+        }
+        if let Some(writeable) = &writeable {
+            gen.prelude.push(quote! {
+                let mut #writeable = diplomat_runtime::wit::WitWriteable::default();
+            });
+        }
+
+        let method_ident = Ident::new(method.name.as_str(), Span::call_site());
+        let type_ident = Ident::new(custom_type.name().as_str(), Span::call_site());
+        let call = match self_expr {
+            Some(this) => quote!((#this).#method_ident(#(#args),*)),
+            None => quote!(#type_ident::#method_ident(#(#args),*)),
+        };
+
+        let ret = Ident::new("ret", Span::call_site());
+        let (return_tokens, epilogue) = match &adapter.output {
+            None => (quote!(), quote!(#call;)),
+            Some(ty) if ty.flat().len() == 1 => {
+                let flat = ty.flat()[0].tokens();
+                let mut lowered = gen.lower(ty, &quote!(#ret));
+                if let WitType::Own(_) = ty {
+                    lowered = quote!(unsafe { #lowered });
+                }
+                (
+                    quote!(-> #flat),
+                    quote! {
+                        let #ret = #call;
+                        #lowered
+                    },
+                )
+            }
+            Some(ty) => {
+                // Results that don't fit in one value are returned through memory
+                let (size, _) = ty.size_align();
+                let ptr = Ident::new("ptr", Span::call_site());
+                let (call, value) = match &writeable {
+                    Some(writeable) if matches!(ty, WitType::Writeable) => {
+                        (quote!(#call;), quote!(#writeable))
+                    }
+                    _ => (quote!(let #ret = #call;), quote!(#ret)),
+                };
+                let store = gen.store(ty, &value, &ptr, 0, writeable.as_ref());
+                (
+                    quote!(-> i32),
+                    quote! {
+                        #call
+                        static RET_AREA: diplomat_runtime::wit::RetArea<#size> =
+                            diplomat_runtime::wit::RetArea::new();
+                        let #ptr = RET_AREA.as_ptr();
+                        unsafe {
+                            #store
+                        }
+                        #ptr as usize as i32
+                    },
+                )
+            }
+        };
+
+        let resources = gen.resources.iter().map(|(resource, ident)| {
+            let import_module = format!("[export]{}", self.interface);
+            let link_name = format!("[resource-new]{resource}");
+            quote! {
+                #[link(wasm_import_module = #import_module)]
+                extern "C" {
+                    #[link_name = #link_name]
+                    fn #ident(rep: i32) -> i32;
+                }
+            }
+        });
+        let flat_params = gen.flat_params.iter().map(|(ident, ty)| {
+            let ty = ty.tokens();
+            quote!(#ident: #ty)
+        });
+        let prelude = &gen.prelude;
+
+        let mut items = vec![syn::parse_quote! {
+            #[cfg(target_arch = "wasm32")]
+            #cfg
+            #[allow(non_snake_case)]
+            #[export_name = #export_name]
+            extern "C" fn #ident(#(#flat_params),*) #return_tokens {
+                #(#resources)*
+                #(#prelude)*
+                #epilogue
+            }
+        }];
+
+        // Strings returned through memory are freed once the host has copied them
+        if let Some(ty) = adapter.output.as_ref().filter(|ty| ty.has_writeable()) {
+            let post_name = format!("cabi_post_{export_name}");
+            let post_ident = format_ident!("__wit_post_{}", method.full_path_name.as_str());
+            let free = match ty {
+                WitType::Writeable => quote! {
+                    diplomat_runtime::wit::free_string(ptr);
+                },
+                WitType::Result(..) => {
+                    let offset = ty.payload_offset();
+                    quote! {
+                        if *ptr == 0 {
+                            diplomat_runtime::wit::free_string(ptr.add(#offset));
+                        }
+                    }
+                }
+                _ => unreachable!("writeables are only returned directly or in a result"),
+            };
+            items.push(syn::parse_quote! {
+                #[cfg(target_arch = "wasm32")]
+                #cfg
+                #[allow(non_snake_case)]
+                #[export_name = #post_name]
+                extern "C" fn #post_ident(ptr: i32) {
+                    let ptr = ptr as usize as *mut u8;
+                    unsafe {
+                        #free
+                    }
+                }
+            });
+        }
+
+        items
+    }
+}
+
+impl<'a> WitType<'a> {
+    fn flat(&self) -> Vec<FlatType> {
+        match self {
+            WitType::Primitive(prim) => vec![match prim {
+                ast::PrimitiveType::i64 | ast::PrimitiveType::u64 => FlatType::I64,
+                ast::PrimitiveType::f32 => FlatType::F32,
+                ast::PrimitiveType::f64 => FlatType::F64,
+                _ => FlatType::I32,
+            }],
+            WitType::Ordering | WitType::Enum(_) | WitType::Own(_) | WitType::Borrow(..) => {
+                vec![FlatType::I32]
+            }
+            WitType::Record(_, fields) => fields.iter().flat_map(WitType::flat).collect(),
+            WitType::Option(inner) => {
+                let mut flat = vec![FlatType::I32];
+                flat.extend(inner.flat());
+                flat
+            }
+            WitType::Result(ok, err) => {
+                let ok = ok.as_ref().map(|ty| ty.flat()).unwrap_or_default();
+                let err = err.as_ref().map(|ty| ty.flat()).unwrap_or_default();
+                let mut flat = vec![FlatType::I32];
+                for i in 0..ok.len().max(err.len()) {
+                    flat.push(match (ok.get(i), err.get(i)) {
+                        (Some(a), Some(b)) => a.join(*b),
+                        (Some(a), None) | (None, Some(a)) => *a,
+                        (None, None) => unreachable!(),
+                    });
+                }
+                flat
+            }
+            WitType::Str(..) | WitType::List(..) | WitType::StrList(_) | WitType::Writeable => {
+                vec![FlatType::I32, FlatType::I32]
+            }
+        }
+    }
+
+    /// The size and alignment in memory
+    fn size_align(&self) -> (usize, usize) {
+        match self {
+            WitType::Primitive(prim) => {
+                let size = match prim {
+                    ast::PrimitiveType::bool
+                    | ast::PrimitiveType::i8
+                    | ast::PrimitiveType::u8
+                    | ast::PrimitiveType::byte => 1,
+                    ast::PrimitiveType::i16 | ast::PrimitiveType::u16 => 2,
+                    ast::PrimitiveType::i64 | ast::PrimitiveType::u64 | ast::PrimitiveType::f64 => {
+                        8
+                    }
+                    _ => 4,
+                };
+                (size, size)
+            }
+            WitType::Ordering => (1, 1),
+            WitType::Enum(enm) => {
+                let size = discriminant_size(enm.variants.len());
+                (size, size)
+            }
+            WitType::Record(_, fields) => {
+                let (mut size, mut align) = (0, 1);
+                for field in fields {
+                    let (field_size, field_align) = field.size_align();
+                    size = align_to(size, field_align) + field_size;
+                    align = align.max(field_align);
+                }
+                (align_to(size, align), align)
+            }
+            WitType::Own(_) | WitType::Borrow(..) => (4, 4),
+            WitType::Option(_) | WitType::Result(..) => {
+                let (size, align) = self
+                    .cases()
+                    .iter()
+                    .flatten()
+                    .map(|ty| ty.size_align())
+                    .fold((0, 1), |(size, align), (s, a)| (size.max(s), align.max(a)));
+                (align_to(self.payload_offset() + size, align), align)
+            }
+            WitType::Str(..) | WitType::List(..) | WitType::StrList(_) | WitType::Writeable => {
+                (8, 4)
+            }
+        }
+    }
+
+    /// The payloads of an `option` or `result`
+    fn cases(&self) -> [Option<&WitType<'a>>; 2] {
+        match self {
+            WitType::Option(inner) => [None, Some(inner)],
+            WitType::Result(ok, err) => [ok.as_deref(), err.as_deref()],
+            _ => unreachable!("only options and results have cases"),
+        }
+    }
+
+    /// Where the payload of an `option` or `result` starts, after its one byte discriminant
+    fn payload_offset(&self) -> usize {
+        let align = self
+            .cases()
+            .iter()
+            .flatten()
+            .map(|ty| ty.size_align().1)
+            .max()
+            .unwrap_or(1);
+        align_to(1, align)
+    }
+
+    fn has_writeable(&self) -> bool {
+        match self {
+            WitType::Writeable => true,
+            WitType::Result(ok, _) => matches!(ok.as_deref(), Some(WitType::Writeable)),
+            _ => false,
+        }
+    }
+}
+
+fn discriminant_size(cases: usize) -> usize {
+    if cases <= 1 << 8 {
+        1
+    } else if cases <= 1 << 16 {
+        2
+    } else {
+        4
+    }
+}
+
+fn align_to(offset: usize, align: usize) -> usize {
+    (offset + align - 1) / align * align
+}
+
+/// Builds the body of an adapter
+struct BodyGen {
+    /// Statements lifting the parameters
+    prelude: Vec<TokenStream>,
+    /// The `[resource-new]` imports used, by resource name
+    resources: BTreeMap<String, Ident>,
+    flat_params: Vec<(Ident, FlatType)>,
+    counter: usize,
+}
+
+impl BodyGen {
+    fn fresh(&mut self, name: &str) -> Ident {
+        self.counter += 1;
+        format_ident!("{}{}", name, self.counter)
+    }
+
+    fn next_param(&mut self, ty: FlatType) -> Ident {
+        let ident = format_ident!("p{}", self.flat_params.len());
+        self.flat_params.push((ident.clone(), ty));
+        ident
+    }
+
+    /// Lift a parameter from the next flat parameters
+    fn lift(&mut self, ty: &WitType) -> TokenStream {
+        match ty {
+            WitType::Primitive(prim) => {
+                let flat = ty.flat()[0];
+                let p = self.next_param(flat);
+                match prim {
+                    ast::PrimitiveType::bool => quote!(#p != 0),
+                    ast::PrimitiveType::i32
+                    | ast::PrimitiveType::i64
+                    | ast::PrimitiveType::f32
+                    | ast::PrimitiveType::f64 => quote!(#p),
+                    ast::PrimitiveType::char => quote!(#p as u32),
+                    ast::PrimitiveType::byte => quote!(#p as u8),
+                    prim => {
+                        let rust = ast::TypeName::Primitive(*prim).to_syn();
+                        quote!(#p as #rust)
+                    }
+                }
+            }
+            WitType::Enum(enm) => {
+                let p = self.next_param(FlatType::I32);
+                let enum_ident = Ident::new(enm.name.as_str(), Span::call_site());
+                let arms = enm.variants.iter().enumerate().map(|(i, variant)| {
+                    let i = i as i32;
+                    let variant = Ident::new(variant.name.as_str(), Span::call_site());
+                    quote!(#i => #enum_ident::#variant,)
+                });
+                quote! {
+                    match #p {
+                        #(#arms)*
+                        _ => unreachable!(),
+                    }
+                }
+            }
+            WitType::Record(strct, fields) => {
+                let struct_ident = Ident::new(strct.name.as_str(), Span::call_site());
+                let fields = strct
+                    .fields
+                    .iter()
+                    .zip(fields)
+                    .map(|((name, ..), field)| {
+                        let name = Ident::new(name.as_str(), Span::call_site());
+                        let value = self.lift(field);
+                        quote!(#name: #value)
+                    })
+                    .collect::<Vec<_>>();
+                quote!(#struct_ident { #(#fields),* })
+            }
+            WitType::Borrow(path, mutability) => {
+                let p = self.next_param(FlatType::I32);
+                let ty = path.to_syn();
+                if mutability.is_mutable() {
+                    quote!(unsafe { &mut *(#p as usize as *mut #ty) })
+                } else {
+                    quote!(unsafe { &*(#p as usize as *const #ty) })
+                }
+            }
+            WitType::Option(inner) => {
+                let discriminant = self.next_param(FlatType::I32);
+                let inner = self.lift(inner);
+                quote!(if #discriminant == 0 { None } else { Some(#inner) })
+            }
+            WitType::Str(encoding, ownership) => {
+                let list = self.lift_list(match encoding {
+                    ast::StringEncoding::UnvalidatedUtf16 => quote!(u16),
+                    _ => quote!(u8),
+                });
+                let utf8 = matches!(encoding, ast::StringEncoding::Utf8);
+                match (ownership, utf8) {
+                    (Ownership::Borrowed, true) => quote!(#list.as_str()),
+                    (Ownership::Borrowed, false) => quote!(#list.as_slice()),
+                    (Ownership::Static, true) => quote!(#list.leak_str()),
+                    (Ownership::Static, false) => quote!(#list.leak()),
+                    (Ownership::Owned, true) => quote!(#list.into_boxed_str()),
+                    (Ownership::Owned, false) => quote!(#list.into_boxed()),
+                }
+            }
+            WitType::List(prim, ownership) => {
+                let list = self.lift_list(ast::TypeName::Primitive(*prim).to_syn());
+                match ownership {
+                    Ownership::Borrowed => quote!(#list.as_slice()),
+                    Ownership::Static => quote!(#list.leak()),
+                    Ownership::Owned => quote!(#list.into_boxed()),
+                }
+            }
+            WitType::StrList(encoding) => {
+                let (elem, getter) = match encoding {
+                    ast::StringEncoding::UnvalidatedUtf16 => (quote!(u16), quote!(slices)),
+                    ast::StringEncoding::Utf8 => (quote!(u8), quote!(strs)),
+                    _ => (quote!(u8), quote!(slices)),
+                };
+                let list = self.lift_list(quote!(diplomat_runtime::wit::WitList<#elem>));
+                let elements = self.fresh("elements");
+                self.prelude.push(quote! {
+                    let #elements = #list.#getter();
+                });
+                quote!(&#elements[..])
+            }
+            WitType::Ordering | WitType::Own(_) | WitType::Result(..) | WitType::Writeable => {
+                unreachable!("not a parameter type")
+            }
+        }
+    }
+
+    /// Take ownership of a list passed as a pointer and length
+    fn lift_list(&mut self, elem: impl quote::ToTokens) -> Ident {
+        let ptr = self.next_param(FlatType::I32);
+        let len = self.next_param(FlatType::I32);
+        let list = self.fresh("list");
+        self.prelude.push(quote! {
+            let #list = unsafe {
+                diplomat_runtime::wit::WitList::<#elem>::from_raw(#ptr, #len)
+            };
+        });
+        list
+    }
+
+    /// The `[resource-new]` import for a resource, which turns a pointer into a handle
+    fn resource_new(&mut self, path: &ast::PathType) -> Ident {
+        let name = path.path.elements.last().unwrap().as_str();
+        self.resources
+            .entry(name.to_kebab_case())
+            .or_insert_with(|| format_ident!("resource_new_{}", name.to_snake_case()))
+            .clone()
+    }
+
+    /// Lower a result that has a single flat value. Creating resources is unsafe.
+    fn lower(&mut self, ty: &WitType, value: &TokenStream) -> TokenStream {
+        match ty {
+            WitType::Primitive(prim) => {
+                let flat = ty.flat()[0].tokens();
+                match prim {
+                    ast::PrimitiveType::i32
+                    | ast::PrimitiveType::i64
+                    | ast::PrimitiveType::f32
+                    | ast::PrimitiveType::f64 => quote!(#value),
+                    _ => quote!(#value as #flat),
+                }
+            }
+            WitType::Ordering => quote!(#value as i32),
+            WitType::Enum(enm) => {
+                let enum_ident = Ident::new(enm.name.as_str(), Span::call_site());
+                let arms = enm.variants.iter().enumerate().map(|(i, variant)| {
+                    let i = i as i32;
+                    let variant = Ident::new(variant.name.as_str(), Span::call_site());
+                    quote!(#enum_ident::#variant => #i,)
+                });
+                quote! {
+                    match #value {
+                        #(#arms)*
+                    }
+                }
+            }
+            WitType::Record(strct, fields) => {
+                let name = Ident::new(strct.fields[0].0.as_str(), Span::call_site());
+                self.lower(&fields[0], &quote!(#value.#name))
+            }
+            WitType::Own(path) => {
+                let resource_new = self.resource_new(path);
+                quote!(#resource_new(Box::into_raw(#value) as usize as i32))
+            }
+            // A `result` without payloads is just its discriminant
+            WitType::Result(None, None) => quote! {
+                match #value {
+                    Ok(()) => 0,
+                    Err(()) => 1,
+                }
+            },
+            _ => unreachable!("only has a single flat value if it is a scalar"),
+        }
+    }
+
+    /// Store a result in memory at `ptr + offset`, in an unsafe context
+    fn store(
+        &mut self,
+        ty: &WitType,
+        value: &TokenStream,
+        ptr: &Ident,
+        offset: usize,
+        writeable: Option<&Ident>,
+    ) -> TokenStream {
+        let at = |memory: TokenStream| quote!(*(#ptr.add(#offset) as *mut #memory));
+        match ty {
+            WitType::Primitive(prim) => {
+                let memory = match prim {
+                    ast::PrimitiveType::bool => quote!(u8),
+                    ast::PrimitiveType::isize => quote!(i32),
+                    ast::PrimitiveType::usize => quote!(u32),
+                    prim => {
+                        // Everything else is stored as is
+                        let place =
+                            at(ast::TypeName::Primitive(*prim).to_syn().into_token_stream());
+                        return quote!(#place = #value;);
+                    }
+                };
+                let place = at(memory.clone());
+                quote!(#place = #value as #memory;)
+            }
+            WitType::Ordering => {
+                let place = at(quote!(i8));
+                quote!(#place = #value as i8;)
+            }
+            WitType::Enum(enm) => {
+                let memory = match discriminant_size(enm.variants.len()) {
+                    1 => quote!(u8),
+                    2 => quote!(u16),
+                    _ => quote!(u32),
+                };
+                let place = at(memory);
+                let lowered = self.lower(ty, value);
+                quote!(#place = (#lowered) as _;)
+            }
+            WitType::Record(strct, fields) => {
+                let record = self.fresh("record");
+                let mut field_offset = offset;
+                let mut stores = vec![];
+                for ((name, ..), field) in strct.fields.iter().zip(fields) {
+                    let (size, align) = field.size_align();
+                    field_offset = align_to(field_offset, align);
+                    let name = Ident::new(name.as_str(), Span::call_site());
+                    stores.push(self.store(field, &quote!(#record.#name), ptr, field_offset, None));
+                    field_offset += size;
+                }
+                quote! {
+                    let #record = #value;
+                    #(#stores)*
+                }
+            }
+            WitType::Own(_) => {
+                let place = at(quote!(i32));
+                let handle = self.lower(ty, value);
+                quote!(#place = #handle;)
+            }
+            WitType::Option(inner) => {
+                let payload = self.fresh("payload");
+                let discriminant = at(quote!(u8));
+                let store = self.store(
+                    inner,
+                    &quote!(#payload),
+                    ptr,
+                    offset + ty.payload_offset(),
+                    None,
+                );
+                quote! {
+                    match #value {
+                        None => #discriminant = 0,
+                        Some(#payload) => {
+                            #discriminant = 1;
+                            #store
+                        }
+                    }
+                }
+            }
+            WitType::Result(ok, err) => {
+                let discriminant = at(quote!(u8));
+                let payload_offset = offset + ty.payload_offset();
+                let arm = |case: Option<&WitType>, this: &mut Self| match case {
+                    None => (quote!(_), quote!()),
+                    Some(WitType::Writeable) => (
+                        quote!(_),
+                        this.store(
+                            &WitType::Writeable,
+                            &quote!(),
+                            ptr,
+                            payload_offset,
+                            writeable,
+                        ),
+                    ),
+                    Some(case) => {
+                        let payload = this.fresh("payload");
+                        let store = this.store(case, &quote!(#payload), ptr, payload_offset, None);
+                        (quote!(#payload), store)
+                    }
+                };
+                let (ok_pattern, ok_store) = arm(ok.as_deref(), self);
+                let (err_pattern, err_store) = arm(err.as_deref(), self);
+                quote! {
+                    match #value {
+                        Ok(#ok_pattern) => {
+                            #discriminant = 0;
+                            #ok_store
+                        }
+                        Err(#err_pattern) => {
+                            #discriminant = 1;
+                            #err_store
+                        }
+                    }
+                }
+            }
+            WitType::Writeable => {
+                let writeable = writeable.expect("writeable results need a writeable");
+                quote!(#writeable.store(#ptr.add(#offset));)
+            }
+            WitType::Borrow(..) | WitType::Str(..) | WitType::List(..) | WitType::StrList(_) => {
+                unreachable!("not a result type")
+            }
+        }
+    }
+}
@@ -1,4 +1,4 @@
-#![cfg_attr(not(any(target_arch = "wasm32")), no_std)]
+#![cfg_attr(not(any(target_arch = "wasm32", feature = "catch_panics")), no_std)]
 
 extern crate alloc;
 
@@ -9,11 +9,21 @@ use alloc::alloc::Layout;
 mod wasm_glue;
 
 mod writeable;
-pub use writeable::DiplomatWriteable;
+pub use writeable::{
+    diplomat_buffer_writeable_create, diplomat_buffer_writeable_destroy,
+    diplomat_buffer_writeable_get_bytes, diplomat_buffer_writeable_len, diplomat_simple_writeable,
+    DiplomatWriteable,
+};
 
 mod result;
 pub use result::DiplomatResult;
 
+#[cfg(feature = "catch_panics")]
+pub mod panic;
+
+#[cfg(feature = "wit")]
+pub mod wit;
+
 /// Like [`char`], but unvalidated.
 pub type DiplomatChar = u32;
 
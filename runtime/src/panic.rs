@@ -0,0 +1,70 @@
+//! Panics that the C ABI functions of `#[diplomat::catch_panics]` methods caught.
+//!
+//! A panic in a method returning a `Result` becomes its error, through [`FromPanic`].
+//! Panics in other methods are kept on the thread they happened on, where foreign code
+//! checks for them with [`diplomat_panicked()`] after each call, and takes their
+//! message with [`diplomat_take_panic()`].
+
+use crate::DiplomatWriteable;
+use alloc::boxed::Box;
+use alloc::string::String;
+use core::any::Any;
+use core::cell::RefCell;
+use core::fmt::Write;
+
+std::thread_local! {
+    /// The message of the last panic caught on this thread, until it is taken
+    static PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// An error type that panics in methods returning `Result<T, Self>` are converted to.
+///
+/// Implement this to pick the error (variant) panics are reported as.
+pub trait FromPanic {
+    fn from_panic(message: &str) -> Self;
+}
+
+impl FromPanic for () {
+    fn from_panic(_: &str) {}
+}
+
+/// The message a panic was started with, if it has one
+pub fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(s) = payload.downcast_ref::<&'static str>() {
+        s
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s
+    } else {
+        "Box<dyn Any>"
+    }
+}
+
+/// Called by the C ABI functions when a method that can't return an error panicked.
+#[doc(hidden)]
+pub fn report_panic(payload: Box<dyn Any + Send>) {
+    let message = panic_message(&*payload).into();
+    PANIC.with(|panic| *panic.borrow_mut() = Some(message));
+}
+
+/// Called by the C ABI functions when a method returning a `Result` panicked.
+#[doc(hidden)]
+pub fn error_from_panic<E: FromPanic>(payload: Box<dyn Any + Send>) -> E {
+    E::from_panic(panic_message(&*payload))
+}
+
+/// Whether a panic was caught on this thread, and its message wasn't taken yet
+#[no_mangle]
+pub extern "C" fn diplomat_panicked() -> bool {
+    PANIC.with(|panic| panic.borrow().is_some())
+}
+
+/// Write the message of the panic caught on this thread to `out`, and forget the panic.
+///
+/// Doesn't write anything if there wasn't one.
+#[no_mangle]
+pub extern "C" fn diplomat_take_panic(out: &mut DiplomatWriteable) {
+    if let Some(message) = PANIC.with(|panic| panic.borrow_mut().take()) {
+        let _ = out.write_str(&message);
+        out.flush();
+    }
+}
@@ -0,0 +1,215 @@
+//! Support code for the WebAssembly component model adapters that `#[diplomat::bridge]`
+//! exports when the `wit` feature of the `diplomat` macro is enabled.
+//!
+//! Together with the world generated by `diplomat-tool wit`, these let a library built for
+//! `wasm32` be turned into a component with `wasm-tools component new`.
+
+use alloc::alloc::Layout;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::cell::UnsafeCell;
+use core::mem::{self, MaybeUninit};
+use core::{ptr, slice, str};
+
+use crate::writeable::{
+    diplomat_buffer_writeable_create, diplomat_buffer_writeable_destroy,
+    diplomat_buffer_writeable_get_bytes, diplomat_buffer_writeable_len,
+};
+use crate::DiplomatWriteable;
+
+/// The allocator the host uses to copy strings and lists into the memory of the component.
+///
+/// # Safety
+/// - `old_ptr` must either be null with `old_len == 0` or have been returned by this
+///   function with the same `old_len` and `align`.
+#[cfg(target_arch = "wasm32")]
+#[no_mangle]
+pub unsafe extern "C" fn cabi_realloc(
+    old_ptr: *mut u8,
+    old_len: usize,
+    align: usize,
+    new_len: usize,
+) -> *mut u8 {
+    let layout = Layout::from_size_align(new_len, align).unwrap();
+    if new_len == 0 {
+        if old_len != 0 {
+            alloc::alloc::dealloc(old_ptr, Layout::from_size_align(old_len, align).unwrap());
+        }
+        // Empty lists only need a well-aligned pointer
+        return align as *mut u8;
+    }
+    let ptr = if old_len == 0 {
+        alloc::alloc::alloc(layout)
+    } else {
+        alloc::alloc::realloc(
+            old_ptr,
+            Layout::from_size_align(old_len, align).unwrap(),
+            new_len,
+        )
+    };
+    if ptr.is_null() {
+        alloc::alloc::handle_alloc_error(layout);
+    }
+    ptr
+}
+
+/// A `list<T>` (or a `string`, for `T = u8`) the host copied into our memory.
+///
+/// The callee owns the lists passed to it, so the buffer is freed when this is dropped,
+/// unless it is turned into a `Box` or leaked first.
+///
+/// This has the same layout as a `list<T>` in memory, so a `list<list<T>>` can be read
+/// as a list of `WitList<T>`s.
+#[repr(C)]
+pub struct WitList<T> {
+    ptr: *mut T,
+    len: usize,
+}
+
+impl<T> WitList<T> {
+    /// # Safety
+    /// - `ptr` and `len` must describe a `list<T>` that was passed to an export
+    pub unsafe fn from_raw(ptr: i32, len: i32) -> Self {
+        WitList {
+            ptr: ptr as usize as *mut T,
+            len: len as usize,
+        }
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        if self.len == 0 {
+            &[]
+        } else {
+            unsafe { slice::from_raw_parts(self.ptr, self.len) }
+        }
+    }
+
+    /// Keep the list alive forever, for `'static` parameters
+    pub fn leak(self) -> &'static [T] {
+        let this = mem::ManuallyDrop::new(self);
+        if this.len == 0 {
+            &[]
+        } else {
+            unsafe { slice::from_raw_parts(this.ptr, this.len) }
+        }
+    }
+
+    /// Take ownership of the list, for owned parameters
+    pub fn into_boxed(self) -> Box<[T]> {
+        let this = mem::ManuallyDrop::new(self);
+        if this.len == 0 {
+            Vec::new().into_boxed_slice()
+        } else {
+            // The host allocated the list through `cabi_realloc()` with the
+            // size and alignment of `[T; len]`
+            unsafe { Box::from_raw(ptr::slice_from_raw_parts_mut(this.ptr, this.len)) }
+        }
+    }
+}
+
+impl<T> WitList<WitList<T>> {
+    /// The elements of a `list<list<T>>`
+    pub fn slices(&self) -> Vec<&[T]> {
+        self.as_slice().iter().map(WitList::as_slice).collect()
+    }
+}
+
+// The canonical ABI guarantees that strings it passes in are valid UTF-8
+impl WitList<u8> {
+    pub fn as_str(&self) -> &str {
+        unsafe { str::from_utf8_unchecked(self.as_slice()) }
+    }
+
+    pub fn leak_str(self) -> &'static str {
+        unsafe { str::from_utf8_unchecked(self.leak()) }
+    }
+
+    pub fn into_boxed_str(self) -> Box<str> {
+        unsafe { alloc::str::from_boxed_utf8_unchecked(self.into_boxed()) }
+    }
+}
+
+impl WitList<WitList<u8>> {
+    /// The elements of a `list<string>`
+    pub fn strs(&self) -> Vec<&str> {
+        self.as_slice().iter().map(WitList::as_str).collect()
+    }
+}
+
+impl<T> Drop for WitList<T> {
+    fn drop(&mut self) {
+        if self.len != 0 {
+            unsafe {
+                ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.ptr, self.len));
+                alloc::alloc::dealloc(self.ptr as *mut u8, Layout::array::<T>(self.len).unwrap());
+            }
+        }
+    }
+}
+
+/// Space for results that don't fit in a single return value.
+///
+/// The host reads the results before calling the post-return function of the export,
+/// so a single static area per export is enough.
+#[repr(C, align(8))]
+pub struct RetArea<const N: usize>(UnsafeCell<MaybeUninit<[u8; N]>>);
+
+// Component instances are not reentrant, so the area is never used concurrently
+unsafe impl<const N: usize> Sync for RetArea<N> {}
+
+impl<const N: usize> RetArea<N> {
+    #[allow(clippy::new_without_default)] // only used in statics
+    pub const fn new() -> Self {
+        RetArea(UnsafeCell::new(MaybeUninit::uninit()))
+    }
+
+    pub fn as_ptr(&self) -> *mut u8 {
+        self.0.get() as *mut u8
+    }
+}
+
+/// A [`DiplomatWriteable`] whose contents are returned to the host as a `string`
+pub struct WitWriteable(*mut DiplomatWriteable);
+
+impl Default for WitWriteable {
+    fn default() -> Self {
+        WitWriteable(diplomat_buffer_writeable_create(0))
+    }
+}
+
+impl WitWriteable {
+    pub fn get_mut(&mut self) -> &mut DiplomatWriteable {
+        unsafe { &mut *self.0 }
+    }
+
+    /// Write the contents as a `string` at `ptr`, to be freed by [`free_string()`] in
+    /// the post-return function.
+    ///
+    /// # Safety
+    /// - `ptr` must be valid for writing a `string`
+    pub unsafe fn store(self, ptr: *mut u8) {
+        let bytes = slice::from_raw_parts(
+            diplomat_buffer_writeable_get_bytes(&*self.0),
+            diplomat_buffer_writeable_len(&*self.0),
+        );
+        let string = Box::into_raw(Box::<[u8]>::from(bytes));
+        *(ptr as *mut i32) = string as *mut u8 as usize as i32;
+        *(ptr.add(4) as *mut i32) = bytes.len() as i32;
+    }
+}
+
+impl Drop for WitWriteable {
+    fn drop(&mut self) {
+        unsafe { diplomat_buffer_writeable_destroy(self.0) }
+    }
+}
+
+/// Free a `string` written by [`WitWriteable::store()`].
+///
+/// # Safety
+/// - `ptr` must point to a `string` written by [`WitWriteable::store()`]
+pub unsafe fn free_string(ptr: *mut u8) {
+    let data = *(ptr as *const i32) as usize as *mut u8;
+    let len = *(ptr.add(4) as *const i32) as usize;
+    drop(Box::from_raw(ptr::slice_from_raw_parts_mut(data, len)));
+}
@@ -0,0 +1,62 @@
+//! The layout of the function table bridges declared with an `apiname` export, which
+//! `api.h` has to match. In append-only mode it is kept in a layout file, which is
+//! updated here after generating and read by the bridge macro when the crate is built.
+
+use crate::check::{self, Mismatch};
+use crate::{ApiInfo, Error, Event};
+use diplomat_core::ast::{ApiLayout, CustomType, ModSymbol};
+use diplomat_core::Env;
+use std::fs;
+use std::io;
+
+/// The layout of the table of `env`, following the layout file of `api` if it has one.
+///
+/// Like the macro, this expects one bridge module to declare the table, so the types
+/// of the bridge crate are sorted by name the way that module has them.
+pub(crate) fn load(env: &Env, api: &ApiInfo) -> Result<ApiLayout, Error> {
+    let mut types: Vec<&CustomType> = env
+        .iter_items()
+        .filter_map(|(path, _, symbol)| match symbol {
+            ModSymbol::CustomType(ty) if env.crate_of(path).is_none() => Some(ty),
+            _ => None,
+        })
+        .collect();
+    types.sort_by(|a, b| a.name().cmp(b.name()));
+
+    let path = match api.layout_file {
+        Some(path) => path,
+        None => return Ok(ApiLayout::new(types)),
+    };
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e.into()),
+    };
+    ApiLayout::append_only(types, &contents).map_err(|errors| Error::ApiLayout {
+        path: path.to_owned(),
+        errors,
+    })
+}
+
+/// Write `layout` to the layout file of `api`, if it has one, or with `check`, compare
+/// it with the one on disk instead
+pub(crate) fn save(
+    api: &ApiInfo,
+    layout: &ApiLayout,
+    check: bool,
+    on_event: &mut dyn FnMut(Event),
+) -> Result<Option<Mismatch>, Error> {
+    let path = match api.layout_file {
+        Some(path) => path,
+        None => return Ok(None),
+    };
+    let text = layout.to_string();
+    let mismatch = check::compare_file(path.to_owned(), &text)?;
+    if check || mismatch.is_none() {
+        return Ok(mismatch);
+    }
+
+    fs::write(path, text)?;
+    on_event(Event::LayoutUpdated(path));
+    Ok(None)
+}
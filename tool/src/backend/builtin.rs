@@ -0,0 +1,454 @@
+//! The backends that ship with `diplomat-tool`
+
+use super::{collect_errors, Backend, Config, Errors, Registry};
+use crate::common::FileMap;
+use crate::{c, c2, cpp, cpp2, dart, dotnet, go, js, kotlin, python, rust, swift, util, wit};
+use diplomat_core::hir::{BackendAttrSupport, TypeContext};
+use serde::de::DeserializeOwned;
+use std::collections::HashMap;
+
+pub(super) fn register_all(registry: &mut Registry) {
+    registry
+        .register(Js)
+        .register(Dart)
+        .register(Python)
+        .register(Kotlin)
+        .register(Swift)
+        .register(Go)
+        .register(Rust)
+        .register(Wit)
+        .register(C)
+        .register(Cpp)
+        .register(Dotnet)
+        .register(C2)
+        .register(CppC2)
+        .register(Cpp2);
+}
+
+/// What the backends generating idiomatic wrappers support
+fn wrapper_support() -> BackendAttrSupport {
+    let mut support = BackendAttrSupport::default();
+    support.renaming = true;
+    support.disabling = true;
+    support.constructors = true;
+    support.named_constructors = true;
+    support.fallible_constructors = true;
+    support.accessors = true;
+    support.stringifiers = true;
+    support.comparators = true;
+    support.iterators = true;
+    support.iterables = true;
+    support.indexing = true;
+    support
+}
+
+/// What the C and C++ backends support
+fn c_support(renaming: bool) -> BackendAttrSupport {
+    let mut support = BackendAttrSupport::default();
+    // C backends cannot rename types using backend attributes
+    // In the future we may add a c_rename attribute
+    support.renaming = renaming;
+    support.namespacing = renaming;
+    support.memory_sharing = true;
+    support.disabling = true;
+    support
+}
+
+/// Generate the C headers, which some backends ship along with their own files
+fn gen_c(tcx: &TypeContext, config: &Config) -> (HashMap<String, String>, Errors) {
+    let mut context = c2::CContext::new(tcx, FileMap::default());
+    context.formatter = c2::CFormatter::new(tcx).with_crate_name(config.crate_name);
+    context.run(config.api_info.zip(config.api_layout));
+    let errors = collect_errors(context.errors.take_all());
+    (context.files.take_files(), errors)
+}
+
+/// Fail if any of the steps of generating had errors
+fn finish(files: HashMap<String, String>, errors: Errors) -> Result<FileMap, Errors> {
+    if errors.is_empty() {
+        Ok(FileMap::new(files))
+    } else {
+        Err(errors)
+    }
+}
+
+/// Read the backend-specific options, or use the backend's defaults if there aren't any
+fn library_config<C: DeserializeOwned + Default>(config: &Config) -> Result<C, Errors> {
+    match config.library_config {
+        Some(options) => options.clone().try_into().map_err(|err| {
+            vec![(
+                "library configuration".to_string(),
+                format!("Unable to parse library configuration: {err}"),
+            )]
+        }),
+        None => Ok(C::default()),
+    }
+}
+
+/// The error for a code generator that failed to write to its files
+fn fmt_error(_: std::fmt::Error) -> Errors {
+    vec![(
+        "bindings".to_string(),
+        "A formatting error occurred while writing the bindings".to_string(),
+    )]
+}
+
+/// Run one of the original code generators, which write to a map of files
+fn gen_ast(
+    gen: impl FnOnce(&mut HashMap<String, String>) -> std::fmt::Result,
+) -> Result<FileMap, Errors> {
+    let mut files = HashMap::new();
+    gen(&mut files).map_err(fmt_error)?;
+    Ok(FileMap::new(files))
+}
+
+struct Js;
+
+impl Backend for Js {
+    fn name(&self) -> &str {
+        "js"
+    }
+    fn attrs_supported(&self) -> BackendAttrSupport {
+        BackendAttrSupport::default()
+    }
+    fn uses_ast(&self) -> bool {
+        true
+    }
+    fn generate(&self, _tcx: &TypeContext, config: &Config) -> Result<FileMap, Errors> {
+        util::check_ast_support(config.env, self.name())?;
+        gen_ast(|files| js::gen_bindings(config.env, files, Some(config.docs_url_gen)))
+    }
+    fn generate_docs(
+        &self,
+        _tcx: &TypeContext,
+        config: &Config,
+    ) -> Option<Result<FileMap, Errors>> {
+        if let Err(errors) = util::check_ast_support(config.env, self.name()) {
+            return Some(Err(errors));
+        }
+        Some(gen_ast(|files| {
+            js::docs::gen_docs(config.env, files, config.docs_url_gen)
+        }))
+    }
+}
+
+struct Dart;
+
+impl Backend for Dart {
+    fn name(&self) -> &str {
+        "dart"
+    }
+    fn attrs_supported(&self) -> BackendAttrSupport {
+        wrapper_support()
+    }
+    fn generate(&self, tcx: &TypeContext, config: &Config) -> Result<FileMap, Errors> {
+        dart::run(tcx, config.docs_url_gen, config.strip_prefix.clone()).map_err(collect_errors)
+    }
+}
+
+struct Python;
+
+impl Backend for Python {
+    fn name(&self) -> &str {
+        "python"
+    }
+    fn attrs_supported(&self) -> BackendAttrSupport {
+        wrapper_support()
+    }
+    fn generate(&self, tcx: &TypeContext, config: &Config) -> Result<FileMap, Errors> {
+        python::run(tcx, config.docs_url_gen, config.strip_prefix.clone()).map_err(collect_errors)
+    }
+}
+
+struct Kotlin;
+
+impl Backend for Kotlin {
+    fn name(&self) -> &str {
+        "kotlin"
+    }
+    fn attrs_supported(&self) -> BackendAttrSupport {
+        let mut support = wrapper_support();
+        support.namespacing = true;
+        support
+    }
+    fn generate(&self, tcx: &TypeContext, config: &Config) -> Result<FileMap, Errors> {
+        kotlin::run(
+            tcx,
+            library_config::<kotlin::config::LibraryConfig>(config)?,
+            config.docs_url_gen,
+            config.strip_prefix.clone(),
+        )
+        .map_err(collect_errors)
+    }
+}
+
+struct Swift;
+
+impl Backend for Swift {
+    fn name(&self) -> &str {
+        "swift"
+    }
+    fn other_backend_names(&self) -> &[&str] {
+        &["c"]
+    }
+    fn attrs_supported(&self) -> BackendAttrSupport {
+        let mut support = wrapper_support();
+        support.memory_sharing = true;
+        support
+    }
+    fn generate(&self, tcx: &TypeContext, config: &Config) -> Result<FileMap, Errors> {
+        // The Swift wrappers call the C API directly, so the C headers are generated first
+        let library_config = library_config::<swift::config::LibraryConfig>(config)?;
+        let (c_files, mut errors) = gen_c(tcx, config);
+        match swift::run(
+            tcx,
+            c_files,
+            library_config,
+            config.docs_url_gen,
+            config.strip_prefix.clone(),
+        ) {
+            Ok(mut files) => finish(files.take_files(), errors),
+            Err(swift_errors) => {
+                errors.extend(collect_errors(swift_errors));
+                Err(errors)
+            }
+        }
+    }
+}
+
+struct Go;
+
+impl Backend for Go {
+    fn name(&self) -> &str {
+        "go"
+    }
+    fn other_backend_names(&self) -> &[&str] {
+        &["c"]
+    }
+    fn attrs_supported(&self) -> BackendAttrSupport {
+        let mut support = wrapper_support();
+        support.memory_sharing = true;
+        support
+    }
+    fn generate(&self, tcx: &TypeContext, config: &Config) -> Result<FileMap, Errors> {
+        // The Go wrappers call the C API directly, so the C headers are generated first
+        let library_config = library_config::<go::config::LibraryConfig>(config)?;
+        let (c_files, mut errors) = gen_c(tcx, config);
+        match go::run(
+            tcx,
+            c_files,
+            library_config,
+            config.docs_url_gen,
+            config.strip_prefix.clone(),
+        ) {
+            Ok(mut files) => finish(files.take_files(), errors),
+            Err(go_errors) => {
+                errors.extend(collect_errors(go_errors));
+                Err(errors)
+            }
+        }
+    }
+}
+
+struct Rust;
+
+impl Backend for Rust {
+    fn name(&self) -> &str {
+        "rust"
+    }
+    fn attrs_supported(&self) -> BackendAttrSupport {
+        let mut support = BackendAttrSupport::default();
+        support.renaming = true;
+        support.disabling = true;
+        support.memory_sharing = true;
+        support
+    }
+    fn generate(&self, tcx: &TypeContext, config: &Config) -> Result<FileMap, Errors> {
+        rust::run(
+            tcx,
+            library_config::<rust::config::LibraryConfig>(config)?,
+            config.docs_url_gen,
+            config.strip_prefix.clone(),
+            config.api_info.zip(config.api_layout),
+        )
+        .map_err(collect_errors)
+    }
+}
+
+struct Wit;
+
+impl Backend for Wit {
+    fn name(&self) -> &str {
+        "wit"
+    }
+    fn attrs_supported(&self) -> BackendAttrSupport {
+        // The bridge macro decides what to export with the same attribute validator,
+        // so this has to stay in sync with `macro/src/wit.rs`
+        let mut support = BackendAttrSupport::default();
+        support.disabling = true;
+        support.constructors = true;
+        support.named_constructors = true;
+        support
+    }
+    fn generate(&self, tcx: &TypeContext, config: &Config) -> Result<FileMap, Errors> {
+        Ok(wit::run(
+            tcx,
+            config.env,
+            library_config::<wit::config::LibraryConfig>(config)?,
+            config.docs_url_gen,
+        ))
+    }
+}
+
+struct C;
+
+impl Backend for C {
+    fn name(&self) -> &str {
+        "c"
+    }
+    fn attrs_supported(&self) -> BackendAttrSupport {
+        BackendAttrSupport::default()
+    }
+    fn uses_ast(&self) -> bool {
+        true
+    }
+    fn generate(&self, _tcx: &TypeContext, config: &Config) -> Result<FileMap, Errors> {
+        util::check_ast_support(config.env, self.name())?;
+        gen_ast(|files| c::gen_bindings(config.env, files))
+    }
+}
+
+struct Cpp;
+
+impl Backend for Cpp {
+    fn name(&self) -> &str {
+        "cpp"
+    }
+    fn attrs_supported(&self) -> BackendAttrSupport {
+        BackendAttrSupport::default()
+    }
+    fn uses_ast(&self) -> bool {
+        true
+    }
+    fn generate(&self, _tcx: &TypeContext, config: &Config) -> Result<FileMap, Errors> {
+        util::check_ast_support(config.env, self.name())?;
+        let library_config = library_config::<cpp::config::LibraryConfig>(config)?;
+        gen_ast(|files| {
+            c::gen_bindings(config.env, files)?;
+            cpp::gen_bindings(config.env, library_config, config.docs_url_gen, files)
+        })
+    }
+    fn generate_docs(
+        &self,
+        _tcx: &TypeContext,
+        config: &Config,
+    ) -> Option<Result<FileMap, Errors>> {
+        if let Err(errors) = util::check_ast_support(config.env, self.name()) {
+            return Some(Err(errors));
+        }
+        let library_config = match library_config::<cpp::config::LibraryConfig>(config) {
+            Ok(library_config) => library_config,
+            Err(errors) => return Some(Err(errors)),
+        };
+        Some(gen_ast(|files| {
+            cpp::docs::gen_docs(config.env, library_config, files, config.docs_url_gen)
+        }))
+    }
+}
+
+struct Dotnet;
+
+impl Backend for Dotnet {
+    fn name(&self) -> &str {
+        "dotnet"
+    }
+    fn attrs_supported(&self) -> BackendAttrSupport {
+        BackendAttrSupport::default()
+    }
+    fn uses_ast(&self) -> bool {
+        true
+    }
+    fn generate(&self, _tcx: &TypeContext, config: &Config) -> Result<FileMap, Errors> {
+        util::check_ast_support(config.env, self.name())?;
+        let library_config = library_config::<dotnet::config::LibraryConfig>(config)?;
+        gen_ast(|files| {
+            dotnet::gen_bindings(config.env, library_config, config.docs_url_gen, files)
+        })
+    }
+}
+
+struct C2;
+
+impl Backend for C2 {
+    fn name(&self) -> &str {
+        "c2"
+    }
+    fn other_backend_names(&self) -> &[&str] {
+        &["c"]
+    }
+    fn attrs_supported(&self) -> BackendAttrSupport {
+        c_support(false)
+    }
+    fn imports_external_types(&self) -> bool {
+        // Headers are included by file name, so adding the include folder of the
+        // other crate is enough
+        true
+    }
+    fn generate(&self, tcx: &TypeContext, config: &Config) -> Result<FileMap, Errors> {
+        let (files, errors) = gen_c(tcx, config);
+        finish(files, errors)
+    }
+}
+
+/// The C headers from the HIR with the C++ headers from the AST. This is a testing backend,
+/// we're not going to treat it as a real c/cpp backend since the AST C++ backend doesn't know
+/// about attributes.
+struct CppC2;
+
+impl Backend for CppC2 {
+    fn name(&self) -> &str {
+        "cpp-c2"
+    }
+    fn other_backend_names(&self) -> &[&str] {
+        &["cpp"]
+    }
+    fn attrs_supported(&self) -> BackendAttrSupport {
+        c_support(true)
+    }
+    fn generate(&self, tcx: &TypeContext, config: &Config) -> Result<FileMap, Errors> {
+        util::check_ast_support(config.env, "cpp")?;
+        let library_config = library_config::<cpp::config::LibraryConfig>(config)?;
+        let (mut files, errors) = gen_c(tcx, config);
+        cpp::gen_bindings(config.env, library_config, config.docs_url_gen, &mut files)
+            .map_err(fmt_error)?;
+        finish(files, errors)
+    }
+    fn generate_docs(&self, tcx: &TypeContext, config: &Config) -> Option<Result<FileMap, Errors>> {
+        Cpp.generate_docs(tcx, config)
+    }
+}
+
+struct Cpp2;
+
+impl Backend for Cpp2 {
+    fn name(&self) -> &str {
+        "cpp2"
+    }
+    fn other_backend_names(&self) -> &[&str] {
+        &["cpp"]
+    }
+    fn attrs_supported(&self) -> BackendAttrSupport {
+        c_support(true)
+    }
+    fn imports_external_types(&self) -> bool {
+        true
+    }
+    fn generate(&self, tcx: &TypeContext, config: &Config) -> Result<FileMap, Errors> {
+        let (mut files, mut errors) = gen_c(tcx, config);
+        let mut context = cpp2::Cpp2Context::new(tcx, FileMap::default());
+        context.run(config.api_info);
+        files.extend(context.c.files.take_files());
+        errors.extend(collect_errors(context.errors.take_all()));
+        finish(files, errors)
+    }
+}
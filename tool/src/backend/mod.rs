@@ -0,0 +1,228 @@
+//! The interface between `diplomat-tool` and the code generators for each target.
+//!
+//! Every target is a [`Backend`]. [`gen`](crate::gen) looks the target up in a [`Registry`],
+//! lowers the bridge crate with the attributes the backend supports, and writes out the
+//! files it generates. Crates using `diplomat-tool` as a library can register their own
+//! backends next to (or instead of) the built-in ones.
+
+mod builtin;
+
+use crate::common::FileMap;
+use crate::ApiInfo;
+use diplomat_core::ast::{ApiLayout, DocsUrlGenerator};
+use diplomat_core::hir::{self, BackendAttrSupport, TypeContext};
+use diplomat_core::Env;
+use std::fmt;
+
+/// Errors found whilst generating code, as pairs of the item they were found in
+/// and the error message
+pub type Errors = Vec<(String, String)>;
+
+/// Everything a backend gets besides the lowered bridge crate
+#[non_exhaustive]
+pub struct Config<'a> {
+    /// The bridge crate before lowering, for backends that haven't moved to the HIR yet
+    pub env: &'a Env,
+    /// The backend-specific options, from the file passed with `-l` or the
+    /// `options` of the target in `diplomat.toml`
+    pub library_config: Option<&'a toml::Value>,
+    pub docs_url_gen: &'a DocsUrlGenerator,
+    /// A prefix to strip from type names
+    pub strip_prefix: Option<String>,
+    /// The name of the bridge crate as it is written in Rust paths, if its `Cargo.toml`
+    /// was found
+    pub crate_name: Option<&'a str>,
+    pub api_info: Option<&'a ApiInfo<'a>>,
+    /// The layout of the function table of [`Config::api_info`]
+    pub api_layout: Option<&'a ApiLayout>,
+}
+
+/// A code generator for a target language
+pub trait Backend {
+    /// The name the backend is selected with on the command line, which is also the
+    /// name `#[diplomat::attr]`s refer to it by
+    fn name(&self) -> &str;
+
+    /// Other names the backend can be selected with
+    fn aliases(&self) -> &[&str] {
+        &[]
+    }
+
+    /// Names of other backends whose `#[diplomat::attr]`s also apply to this one,
+    /// like `c` for a backend that generates the C headers along with its own files
+    fn other_backend_names(&self) -> &[&str] {
+        &[]
+    }
+
+    /// The attributes this backend handles. Using any other attribute for it is an error.
+    fn attrs_supported(&self) -> BackendAttrSupport;
+
+    /// Generate the bindings, returning the files to write keyed by their path
+    /// relative to the out folder
+    fn generate(&self, tcx: &TypeContext, config: &Config) -> Result<FileMap, Errors>;
+
+    /// Generate documentation for the bindings, or `None` if the backend doesn't support that
+    fn generate_docs(
+        &self,
+        _tcx: &TypeContext,
+        _config: &Config,
+    ) -> Option<Result<FileMap, Errors>> {
+        None
+    }
+
+    /// Whether the bindings can import the types that come from the bridges of other
+    /// crates (see [`TypeContext::external_crate`]) from the bindings of those crates.
+    /// Only these backends can generate bridge crates that use such types.
+    fn imports_external_types(&self) -> bool {
+        false
+    }
+
+    /// Whether the backend only reads [`Config::env`], in which case the bridge crate isn't
+    /// lowered and it gets an empty [`TypeContext`]. Only the original backends do this.
+    #[doc(hidden)]
+    fn uses_ast(&self) -> bool {
+        false
+    }
+
+    /// The attribute validator to lower the bridge crate with for this backend
+    fn attr_validator(&self) -> hir::BasicAttributeValidator {
+        let mut validator = hir::BasicAttributeValidator::new(self.name());
+        validator.other_backend_names = self
+            .other_backend_names()
+            .iter()
+            .map(|name| name.to_string())
+            .collect();
+        validator.support = self.attrs_supported();
+        validator
+    }
+}
+
+/// The set of backends `diplomat-tool` can generate code with
+pub struct Registry {
+    backends: Vec<Box<dyn Backend>>,
+}
+
+impl Default for Registry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Registry {
+    /// A registry of all the built-in backends
+    pub fn new() -> Self {
+        let mut registry = Self::empty();
+        builtin::register_all(&mut registry);
+        registry
+    }
+
+    /// A registry without any backends
+    pub fn empty() -> Self {
+        Self { backends: vec![] }
+    }
+
+    /// Add a backend. If it has the same name or alias as a backend that is already registered,
+    /// it takes precedence over that one.
+    pub fn register(&mut self, backend: impl Backend + 'static) -> &mut Self {
+        self.backends.push(Box::new(backend));
+        self
+    }
+
+    /// Look up a backend by its name or one of its aliases
+    pub fn get(&self, name: &str) -> Option<&dyn Backend> {
+        self.backends
+            .iter()
+            .rev()
+            .find(|backend| backend.name() == name || backend.aliases().contains(&name))
+            .map(|backend| &**backend)
+    }
+
+    /// The names of all registered backends, in the order they were registered
+    pub fn names(&self) -> impl Iterator<Item = &str> + '_ {
+        self.backends.iter().map(|backend| backend.name())
+    }
+}
+
+/// Convert the errors of a code generator into [`Errors`]
+pub fn collect_errors(
+    errors: impl IntoIterator<Item = (impl fmt::Display, impl fmt::Display)>,
+) -> Errors {
+    errors
+        .into_iter()
+        .map(|(context, error)| (context.to_string(), error.to_string()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Backend, Config, Errors, Registry};
+    use crate::common::FileMap;
+    use crate::Error;
+    use diplomat_core::hir::{BackendAttrSupport, TypeContext};
+
+    /// A backend that only has a name and aliases
+    struct Named(&'static str, &'static [&'static str]);
+
+    impl Backend for Named {
+        fn name(&self) -> &str {
+            self.0
+        }
+        fn aliases(&self) -> &[&str] {
+            self.1
+        }
+        fn attrs_supported(&self) -> BackendAttrSupport {
+            BackendAttrSupport::default()
+        }
+        fn generate(&self, _tcx: &TypeContext, _config: &Config) -> Result<FileMap, Errors> {
+            Ok(FileMap::default())
+        }
+    }
+
+    #[test]
+    fn test_builtin_names() {
+        let registry = Registry::new();
+        assert_eq!(
+            registry.names().collect::<Vec<_>>(),
+            [
+                "js", "dart", "python", "kotlin", "swift", "go", "rust", "wit", "c", "cpp",
+                "dotnet", "c2", "cpp-c2", "cpp2"
+            ]
+        );
+        for name in registry.names() {
+            assert_eq!(registry.get(name).unwrap().name(), name);
+        }
+    }
+
+    #[test]
+    fn test_unknown_name() {
+        let registry = Registry::new();
+        assert!(registry.get("cobol").is_none());
+        match crate::find_backend(&registry, "cobol") {
+            Err(Error::UnknownTarget { target, available }) => {
+                assert_eq!(target, "cobol");
+                assert_eq!(available, registry.names().collect::<Vec<_>>());
+            }
+            _ => panic!("expected an unknown target error"),
+        }
+        assert!(Registry::empty().get("c2").is_none());
+    }
+
+    #[test]
+    fn test_register() {
+        let mut registry = Registry::empty();
+        registry
+            .register(Named("first", &["one"]))
+            .register(Named("second", &[]));
+        assert_eq!(registry.names().collect::<Vec<_>>(), ["first", "second"]);
+        assert_eq!(registry.get("one").unwrap().name(), "first");
+
+        // Later backends take precedence over the ones whose name or alias they share
+        registry.register(Named("third", &["first"]));
+        assert_eq!(registry.get("first").unwrap().name(), "third");
+        assert_eq!(registry.get("one").unwrap().name(), "first");
+        assert_eq!(
+            registry.names().collect::<Vec<_>>(),
+            ["first", "second", "third"]
+        );
+    }
+}
@@ -95,11 +95,11 @@ fn gen_struct_header(
             writeln!(out)?;
             writeln!(out, "typedef enum {} {{", enm.name)?;
             let mut enum_body_out = indented(out).with_str("  ");
-            for (name, discriminant, _, _attrs) in enm.variants.iter() {
+            for variant in enm.variants.iter() {
                 writeln!(
                     &mut enum_body_out,
                     "{}_{} = {},",
-                    enm.name, name, discriminant
+                    enm.name, variant.name, variant.discriminant
                 )?;
             }
             writeln!(out, "}} {};", enm.name)?;
@@ -2,9 +2,10 @@
 
 use super::ty::ResultType;
 use diplomat_core::hir::{
-    self, OpaqueOwner, StringEncoding, StructPathLike, Type, TypeContext, TypeId
+    self, OpaqueOwner, StringEncoding, StructPathLike, TraitId, Type, TypeContext, TypeId
 };
 use std::borrow::Cow;
+use std::fmt::Write;
 
 /// This type mediates all formatting
 ///
@@ -17,11 +18,23 @@ use std::borrow::Cow;
 /// of C types and methods.
 pub struct CFormatter<'tcx> {
     tcx: &'tcx TypeContext,
+    crate_name: Option<String>,
 }
 
 impl<'tcx> CFormatter<'tcx> {
     pub fn new(tcx: &'tcx TypeContext) -> Self {
-        Self { tcx }
+        Self {
+            tcx,
+            crate_name: None,
+        }
+    }
+    /// Prefix module-level consts with the name of the bridge crate, so that they
+    /// don't clash with the macros of other libraries
+    pub fn with_crate_name(self, crate_name: Option<&str>) -> Self {
+        Self {
+            crate_name: crate_name.map(Into::into),
+            ..self
+        }
     }
     pub fn tcx(&self) -> &'tcx TypeContext {
         self.tcx
@@ -56,6 +69,32 @@ impl<'tcx> CFormatter<'tcx> {
         let type_name = self.fmt_type_name(id);
         format!("{type_name}.h")
     }
+    /// Resolve and format the name of the vtable struct a trait is passed as
+    pub fn fmt_trait_vtable_name(&self, id: TraitId) -> String {
+        let trait_name = self.tcx.resolve_trait(id).name.as_str();
+        format!("{trait_name}_VTable")
+    }
+    /// Resolve and format the name of a trait for use in header names
+    ///
+    /// Traits only get a decl header, since they have no functions of their own.
+    pub fn fmt_trait_decl_header_path(&self, id: TraitId) -> String {
+        let trait_name = self.tcx.resolve_trait(id).name.as_str();
+        format!("{trait_name}.d.h")
+    }
+    /// Format the name of a const, prefixed with its type's name if it is an associated const
+    /// and with the crate name if it is declared in a module
+    pub fn fmt_const_name(&self, ty: Option<TypeId>, def: &hir::ConstDef) -> String {
+        let const_name = def.name.as_str();
+        match (ty, &self.crate_name) {
+            (Some(ty), _) => format!("{}_{const_name}", self.fmt_type_name(ty)),
+            (None, Some(crate_name)) => format!("{crate_name}_{const_name}"),
+            (None, None) => const_name.into(),
+        }
+    }
+    /// The header holding consts declared at the top level of a module
+    pub fn fmt_consts_header_path(&self) -> String {
+        "diplomat_consts.h".into()
+    }
     /// Resolve and format the name of a type for use in header names: result version
     pub fn fmt_result_header_path(&self, type_name: &str) -> String {
         format!("{type_name}.d.h")
@@ -69,6 +108,14 @@ impl<'tcx> CFormatter<'tcx> {
         let variant_name = variant.name.as_str();
         format!("{type_name}_{variant_name}").into()
     }
+    /// Format the name of the tag enum of a data-carrying enum
+    pub fn fmt_enum_tag_name(&self, type_name: &str) -> String {
+        format!("{type_name}_Tag")
+    }
+    /// Format the name of the union member holding a variant's payload
+    pub fn fmt_enum_payload_name<'a>(&self, variant: &'a hir::EnumVariant) -> Cow<'a, str> {
+        variant.name.as_str().into()
+    }
     /// Format a field name or parameter name
     // might need splitting in the future if we decide to support renames here
     pub fn fmt_param_name<'a>(&self, ident: &'a str) -> Cow<'a, str> {
@@ -79,10 +126,25 @@ impl<'tcx> CFormatter<'tcx> {
     pub fn fmt_method_name(&self, ty: TypeId, method: &hir::Method, external_name: bool) -> String {
         let ty_name = self.fmt_type_name(ty);
         let method_name = method.name.as_str();
-        let put_together = if external_name { format!("{ty_name}_{method_name}") } else { method_name.to_string() };
+        let put_together = if external_name { format!("{ty_name}_{method_name}") } else { self.fmt_api_slot_name(method_name).into_owned() };
         method.attrs.abi_rename.apply(put_together.into()).into()
     }
 
+    /// Format the name of a slot of an API table. The slots are fields of a struct
+    /// that C++ includes too, so C++ keywords get a trailing underscore.
+    pub fn fmt_api_slot_name<'a>(&self, name: &'a str) -> Cow<'a, str> {
+        const CPP_KEYWORDS: &[&str] = &[
+            "auto", "catch", "class", "default", "delete", "explicit", "export", "friend",
+            "inline", "namespace", "new", "operator", "private", "protected", "public",
+            "template", "this", "throw", "try", "typename", "using", "virtual",
+        ];
+        if CPP_KEYWORDS.contains(&name) {
+            format!("{name}_").into()
+        } else {
+            name.into()
+        }
+    }
+
     /// Resolve and format a type's destructor
     pub fn fmt_dtor_name(&self, ty: TypeId) -> String {
         let ty_name = self.fmt_type_name(ty);
@@ -181,6 +243,61 @@ impl<'tcx> CFormatter<'tcx> {
         }
     }
 
+    /// Format the value of a numeric or `bool` const as a C expression of its type
+    pub fn fmt_const_value(&self, def: &hir::ConstDef) -> String {
+        use diplomat_core::hir::{ConstType, ConstValue, FloatType, IntSizeType, IntType, PrimitiveType};
+        let ConstType::Primitive(prim) = def.ty else {
+            panic!("string consts are not C expressions");
+        };
+        match (&def.value, prim) {
+            (ConstValue::Bool(b), _) => b.to_string(),
+            (ConstValue::Float(f), prim) => {
+                let suffix = if let PrimitiveType::Float(FloatType::F32) = prim { "f" } else { "" };
+                if f.starts_with('-') {
+                    format!("({f}{suffix})")
+                } else {
+                    format!("{f}{suffix}")
+                }
+            }
+            (ConstValue::Int(i), _) => {
+                let suffix = match prim {
+                    PrimitiveType::Int(IntType::U64) | PrimitiveType::IntSize(IntSizeType::Usize) => "ULL",
+                    PrimitiveType::Int(IntType::I64) | PrimitiveType::IntSize(IntSizeType::Isize) => "LL",
+                    _ => "",
+                };
+                let c_ty = self.fmt_primitive_as_c(prim);
+                // The smallest i64 can't be written as a negated literal, since the literal overflows
+                if i == "-9223372036854775808" {
+                    format!("(({c_ty})(-9223372036854775807LL - 1))")
+                } else {
+                    format!("(({c_ty}){i}{suffix})")
+                }
+            }
+            _ => unreachable!("unknown AST/HIR variant"),
+        }
+    }
+
+    /// Format a string as a C string literal, escaping anything that isn't printable ASCII
+    pub fn fmt_string_literal(&self, s: &str) -> String {
+        let mut out = String::with_capacity(s.len() + 2);
+        out.push('"');
+        for b in s.bytes() {
+            match b {
+                b'"' => out.push_str("\\\""),
+                b'\\' => out.push_str("\\\\"),
+                b'\n' => out.push_str("\\n"),
+                b'\r' => out.push_str("\\r"),
+                b'\t' => out.push_str("\\t"),
+                // Octal escapes are at most three digits, so unlike hex escapes
+                // they can't swallow a following character
+                b' '..=b'~' => out.push(b as char),
+                _ => write!(out, "\\{b:03o}").unwrap(),
+            }
+        }
+        out.push('"');
+        out
+    }
+
     pub fn fmt_function_as_c(&self, param_name: Option<&str>, output: &Cow<'_, str>, inputs: &Vec<(Cow<'_, str>, Option<Cow<'_, str>>)>) -> Cow<'_, str> {
         format!("{}(*{})({})", output, param_name.unwrap_or_default(), inputs.iter().map(|(ty, id)| match id {
             Some(id) => format!("{} {}", ty, id),
@@ -1,3 +1,7 @@
+#[cfg(test)]
+#[macro_use]
+mod test_util;
+
 mod formatter;
 mod header;
 mod ty;
@@ -6,23 +10,63 @@ pub use self::formatter::CFormatter;
 
 use crate::common::{ErrorStore, FileMap};
 use crate::ApiInfo;
+use diplomat_core::ast::{ApiLayout, API_LAYOUT_VERSION};
 use diplomat_core::hir::TypeContext;
 use std::cell::RefCell;
 use std::collections::HashMap;
 
-fn render_api(apiname: &str, entrypoint: &str, ty_names: &[&str]) -> String {
+/// The declarations of `diplomat_runtime.h` that need the library to be linked
+const RUNTIME_H_LINKED_DECLS: &str = "DiplomatWriteable diplomat_simple_writeable(char* buf, size_t buf_size);\n";
+
+/// The functions of `diplomat_runtime::panic`, which only libraries that catch panics have
+const RUNTIME_H_PANIC_DECLS: &str = "bool diplomat_panicked();\nvoid diplomat_take_panic(DiplomatWriteable* out);\n";
+
+/// Render `api.h`, which declares the API table the library returns from `entrypoint`.
+///
+/// `ty_names` has the name of each type of `layout`, or `None` if the headers don't have
+/// the type and the table only has a pointer in its place. `catches_panics` adds the
+/// functions that report caught panics to the core table.
+fn render_api(apiname: &str, entrypoint: &str, layout: &ApiLayout, ty_names: &[Option<&str>], catches_panics: bool) -> String {
     let includes = ty_names.iter()
+        .flatten()
         .map(|n| format!("#include \"{}.h\"", n))
         .collect::<Vec<_>>()
         .join("\n");
 
-    let members = ty_names.iter()
-        .map(|n| format!("  __{0}_API__ {};", n))
+    let members = layout.types.iter().zip(ty_names)
+        .map(|(ty, name)| match name {
+            Some(n) => format!("  const __{0}_API__* {0};", n),
+            None => format!("  const void* {};", ty.name),
+        })
         .collect::<Vec<_>>()
         .join("\n");
+
+    let panic_slots = if catches_panics {
+        "\n  bool (*diplomat_panicked)();\n  void (*diplomat_take_panic)(DiplomatWriteable* out);"
+    } else {
+        ""
+    };
+
+    let prefix = apiname.to_uppercase();
+    let version = API_LAYOUT_VERSION;
+    let hash = layout.hash();
+    // With an append-only layout, a library with more slots than the headers
+    // know about still works. The core table only ever grows, so its size is
+    // checked either way.
+    let compatible = if layout.append_only {
+        let sizes = std::iter::once(format!("api->header.size >= sizeof({apiname})"))
+            .chain(std::iter::once("api->core->size >= sizeof(__Core_API__)".to_string()))
+            .chain(ty_names.iter().flatten().map(|n| format!("api->{n}->size >= sizeof(__{n}_API__)")))
+            .collect::<Vec<_>>()
+            .join("\n    && ");
+        format!("api->header.version == {prefix}_API_VERSION\n    && api->header.append_only\n    && {sizes}")
+    } else {
+        format!("api->header.version == {prefix}_API_VERSION\n    && api->header.hash == {prefix}_API_HASH\n    && api->core->size >= sizeof(__Core_API__)")
+    };
     format!(r##"#ifndef API_{apiname}_H
 #define API_{apiname}_H
 
+#include "diplomat_runtime.h"
 {includes}
 
 #ifdef __cplusplus
@@ -30,18 +74,46 @@ namespace capi {{
 extern "C" {{
 #endif // __cplusplus
 
-struct __Core_API__ {{
+// The version of the table header and the hash of the table layout these headers
+// were generated for
+#define {prefix}_API_VERSION {version}
+#define {prefix}_API_HASH 0x{hash:016x}ULL
+
+typedef struct __API_Header__ {{
+  size_t size;
+  uint32_t version;
+  uint32_t append_only;
+  uint64_t hash;
+}} __API_Header__;
+
+// The functions of diplomat-runtime, which hosts call through the table instead of
+// linking the library
+typedef struct __Core_API__ {{
+  size_t size;
   void(*free)(void* ptr);
-}};
+  uint8_t* (*diplomat_alloc)(size_t size, size_t align);
+  void (*diplomat_free)(uint8_t* ptr, size_t size, size_t align);
+  DiplomatWriteable* (*diplomat_buffer_writeable_create)(size_t cap);
+  uint8_t* (*diplomat_buffer_writeable_get_bytes)(const DiplomatWriteable* self);
+  size_t (*diplomat_buffer_writeable_len)(const DiplomatWriteable* self);
+  void (*diplomat_buffer_writeable_destroy)(DiplomatWriteable* self);
+  DiplomatWriteable (*diplomat_simple_writeable)(char* buf, size_t buf_size);{panic_slots}
+}} __Core_API__;
 
-struct {apiname}
+typedef struct {apiname}
 {{
-  __Core_API__ core;
+  __API_Header__ header;
+  const __Core_API__* core;
 {members}
-}};
+}} {apiname};
 
 const {apiname}* {entrypoint}();
 
+// Whether the table a library returned can be used through these headers
+static inline bool {apiname}_is_compatible(const {apiname}* api) {{
+  return {compatible};
+}}
+
 #ifdef __cplusplus
 }} // extern "C"
 }} // namespace capi
@@ -77,28 +149,188 @@ impl<'tcx> CContext<'tcx> {
     /// Run file generation
     ///
     /// Will populate self.files as a result
-    pub fn run(&self, api_info: Option<&ApiInfo>) {
+    pub fn run(&self, api: Option<(&ApiInfo, &ApiLayout)>) {
+        // With an API table, hosts don't link the library, and call the runtime
+        // through the `core` table instead
+        let runtime = match api {
+            Some(_) => crate::c::RUNTIME_H.replace(RUNTIME_H_LINKED_DECLS, ""),
+            None if self.tcx.catches_panics() => crate::c::RUNTIME_H.replace(
+                RUNTIME_H_LINKED_DECLS,
+                &format!("{RUNTIME_H_LINKED_DECLS}{RUNTIME_H_PANIC_DECLS}"),
+            ),
+            None => crate::c::RUNTIME_H.into(),
+        };
         self.files
-            .add_file("diplomat_runtime.h".into(), crate::c::RUNTIME_H.into());
+            .add_file("diplomat_runtime.h".into(), runtime);
+        // The headers of types from other crates are generated with those crates
         for (id, ty) in self.tcx.all_types() {
-            self.gen_ty(id, ty)
+            if self.tcx.external_crate(id).is_none() {
+                self.gen_ty(id, ty, api.map(|(_, layout)| layout))
+            }
+        }
+
+        for (id, trt) in self.tcx.all_traits() {
+            if self.tcx.external_trait_crate(id).is_none() {
+                self.gen_trait(id, trt)
+            }
+        }
+
+        if !self.tcx.consts().is_empty() {
+            self.gen_module_consts(self.tcx.consts())
         }
 
         for (result_name, result_ty) in self.result_store.borrow().iter() {
             self.gen_result(result_name, *result_ty)
         }
 
-        if let Some(ApiInfo { apiname, refresh_api_fn: entrypoint, .. }) = api_info { 
-            let ty_names = self.tcx.all_types()
-                .filter_map(|(_, ty)| if ty.attrs().disable || ty.methods().is_empty() { None } else { Some(ty.name().as_str()) })
+        if let Some((ApiInfo { apiname, refresh_api_fn: entrypoint, .. }, layout)) = api {
+            let ty_names = layout.types.iter()
+                .map(|ty_layout| self.tcx.all_types()
+                    .find(|(id, ty)| ty.name().as_str() == ty_layout.name && self.tcx.external_crate(*id).is_none())
+                    .filter(|(_, ty)| !ty.attrs().disable && !ty_layout.retired)
+                    .map(|(_, ty)| ty.name().as_str()))
                 .collect::<Vec<_>>();
 
             self.files.add_file(
                 "api.h".into(),
-                render_api(apiname, entrypoint, &ty_names)
+                render_api(apiname, entrypoint, layout, &ty_names, self.tcx.catches_panics())
             );
         }
     }
 
     // further methods can be found in ty.rs and formatter.rs
 }
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_tagged_union() {
+        test_file! {
+            #[diplomat::bridge]
+            mod ffi {
+                pub struct Point {
+                    pub x: i32,
+                    pub y: i32,
+                }
+
+                pub enum Shape {
+                    Circle { radius: f64 },
+                    Dot { center: Point },
+                    Empty,
+                }
+
+                #[diplomat::opaque]
+                pub struct Canvas;
+
+                impl Canvas {
+                    pub fn draw(&mut self, shape: Shape) {}
+                    pub fn last(&self) -> Shape {
+                        unimplemented!()
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_trait() {
+        test_file! {
+            #[diplomat::bridge]
+            mod ffi {
+                pub trait Logger {
+                    fn log(&self, level: u8) -> i32;
+                    fn flush(&self);
+                }
+
+                #[diplomat::opaque]
+                pub struct Service;
+
+                impl Service {
+                    pub fn run(&self, logger: &dyn Logger) {}
+                    pub fn set_logger(&mut self, logger: Box<dyn Logger>) {}
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_consts() {
+        test_file! {
+            #[diplomat::bridge]
+            mod ffi {
+                /// The largest length
+                pub const MAX_LEN: u32 = 16;
+                pub const NAME: &str = "demo";
+                pub const RATIO: f64 = 0.5;
+
+                #[diplomat::opaque]
+                pub struct Thing;
+
+                impl Thing {
+                    pub const SIZE: i8 = -3;
+                    pub const ENABLED: bool = true;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_catch_panics() {
+        test_file! {
+            #[diplomat::bridge]
+            mod ffi {
+                pub enum MyError {
+                    Panicked,
+                    Other,
+                }
+
+                #[diplomat::opaque]
+                pub struct Thing;
+
+                #[diplomat::catch_panics]
+                impl Thing {
+                    pub fn might_panic(x: u32) -> u32 {
+                        unimplemented!()
+                    }
+                    pub fn might_fail(x: u32) -> Result<u32, MyError> {
+                        unimplemented!()
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_types_from_other_crates() {
+        test_file! {
+            dep other_crate {
+                #[diplomat::bridge]
+                mod ffi {
+                    #[diplomat::opaque]
+                    pub struct Other;
+
+                    pub struct OtherStruct {
+                        pub x: u8,
+                    }
+                }
+            }
+
+            #[diplomat::bridge]
+            mod ffi {
+                use other_crate::ffi::{Other, OtherStruct};
+
+                #[diplomat::opaque]
+                pub struct Mine;
+
+                impl Mine {
+                    pub fn make_other(&self, s: OtherStruct) -> Box<Other> {
+                        unimplemented!()
+                    }
+                    pub fn use_other(&self, other: &Other) -> OtherStruct {
+                        unimplemented!()
+                    }
+                }
+            }
+        }
+    }
+}
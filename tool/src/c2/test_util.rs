@@ -0,0 +1,72 @@
+/// A macro to test that the C headers match the output from diplomat. It checks the output
+/// against an [insta] snapshot, leaving out the runtime. The bridge crate is named `demo`.
+///
+/// # Usage
+/// ```
+/// test_file! {
+///     #[diplomat::bridge]
+///     mod ffi {
+///         enum MyEnum {
+///             A, B, C
+///         }
+///     }
+/// }
+/// ```
+///
+/// The bridge can use the types of the bridge of another crate, given before it:
+/// ```
+/// test_file! {
+///     dep other_crate {
+///         #[diplomat::bridge]
+///         mod ffi {
+///             #[diplomat::opaque]
+///             struct Other;
+///         }
+///     }
+///
+///     #[diplomat::bridge]
+///     mod ffi {
+///         use other_crate::ffi::Other;
+///     }
+/// }
+/// ```
+macro_rules! test_file {
+    (dep $dep_name:ident { $($dep:tt)* } $($file:tt)*) => {
+        let dep: syn::File = syn::parse_quote! { $($dep)* };
+        let dep = diplomat_core::ast::File::from(&dep);
+        test_file!(@crates [(stringify!($dep_name), &dep)] $($file)*);
+    };
+    (@crates $crates:tt $($file:tt)*) => {
+        let parsed: syn::File = syn::parse_quote! { $($file)* };
+        let custom_types = diplomat_core::ast::File::from(&parsed);
+        let env = custom_types.all_types_with_crates($crates);
+
+        let registry = crate::backend::Registry::new();
+        let backend = registry.get("c2").unwrap();
+        let tcx = diplomat_core::hir::TypeContext::from_ast(&env, backend.attr_validator())
+            .unwrap_or_else(|e| panic!("lowering failed: {e:?}"));
+        let config = crate::backend::Config {
+            env: &env,
+            library_config: None,
+            docs_url_gen: &Default::default(),
+            strip_prefix: None,
+            crate_name: Some("demo"),
+            api_info: None,
+            api_layout: None,
+        };
+
+        let mut out_texts = backend.generate(&tcx, &config).unwrap().take_files();
+
+        out_texts.retain(|k, _| k.ends_with(".h"));
+        out_texts.remove("diplomat_runtime.h");
+
+        for out in out_texts.keys() {
+            insta::with_settings!({ snapshot_suffix => out.clone() }, {
+                insta::assert_snapshot!(out_texts.get(out).unwrap())
+            });
+        }
+    };
+    ($($file:tt)*) => {
+        test_file!(@crates [] $($file)*);
+    };
+}
@@ -1,12 +1,15 @@
 use super::header::Header;
 use super::CContext;
-use diplomat_core::hir::{self, OpaqueOwner, StructPathLike, TyPosition, Type, TypeDef, TypeId};
+use diplomat_core::ast::{ApiLayout, ApiTypeLayout};
+use diplomat_core::hir::{
+    self, OpaqueOwner, StructPathLike, TraitId, TyPosition, Type, TypeDef, TypeId,
+};
 use std::borrow::Cow;
 use std::cell::RefCell;
 use std::fmt::Write;
 
 impl<'tcx> super::CContext<'tcx> {
-    pub fn gen_ty(&self, id: TypeId, ty: TypeDef<'tcx>) {
+    pub fn gen_ty(&self, id: TypeId, ty: TypeDef<'tcx>, api_layout: Option<&ApiLayout>) {
         if ty.attrs().disable {
             // Skip type if disabled
             return;
@@ -31,7 +34,7 @@ impl<'tcx> super::CContext<'tcx> {
             _ => unreachable!("unknown AST/HIR variant"),
         }
 
-        let mut method_generated = false;
+        context.gen_consts(Some(id), ty.consts());
 
         for method in ty.methods() {
             if method.attrs.disable {
@@ -43,19 +46,18 @@ impl<'tcx> super::CContext<'tcx> {
                 method.name.as_str().into(),
             );
             context.gen_method(id, method, false);
-
-            method_generated = true;
         }
 
         if let TypeDef::Opaque(_) = ty {
             context.gen_dtor(id, false);
-
-            method_generated = true;
         }
 
-        // Only generate the api if a method was generated
-        if method_generated {
-            context.gen_api(id, ty);
+        let ty_layout = api_layout
+            .into_iter()
+            .flat_map(|layout| &layout.types)
+            .find(|ty_layout| ty_layout.name == ty.name().as_str());
+        if let Some(ty_layout) = ty_layout {
+            context.gen_api(id, ty, ty_layout);
         }
 
         // In some cases like generating decls for `self` parameters,
@@ -74,6 +76,54 @@ impl<'tcx> super::CContext<'tcx> {
             .add_file(impl_header_path, context.impl_header.borrow().to_string());
     }
 
+    pub fn gen_trait(&self, id: TraitId, def: &'tcx hir::TraitDef) {
+        if def.attrs.disable {
+            return;
+        }
+        let _guard = self.errors.set_context_ty(def.name.as_str().into());
+        let header_path = self.formatter.fmt_trait_decl_header_path(id);
+        let header = Header::new(header_path.clone());
+        let impl_header = Header::new("".to_string());
+        let mut context = TyGenContext {
+            cx: self,
+            decl_header: RefCell::new(header),
+            impl_header: RefCell::new(impl_header),
+        };
+        context.gen_trait_def(def, id);
+
+        // The vtable's function pointers are declared like method parameters, whose
+        // includes end up in the (discarded) impl header. Only the decl headers are
+        // needed to complete the vtable definition.
+        let includes = std::mem::take(&mut context.impl_header.borrow_mut().includes);
+        context
+            .decl_header
+            .borrow_mut()
+            .includes
+            .extend(includes.into_iter().filter(|i| i.ends_with(".d.h")));
+        context
+            .decl_header
+            .borrow_mut()
+            .includes
+            .remove(&*header_path);
+
+        self.files
+            .add_file(header_path, context.decl_header.borrow().to_string());
+    }
+
+    /// Consts declared at the top level of a module all go in a single header
+    pub fn gen_module_consts(&self, consts: &'tcx [hir::ConstDef]) {
+        let header_path = self.formatter.fmt_consts_header_path();
+        let header = Header::new(header_path.clone());
+        let dummy_header = Header::new("".to_string());
+        let mut context = TyGenContext {
+            cx: self,
+            decl_header: RefCell::new(header),
+            impl_header: RefCell::new(dummy_header),
+        };
+        context.gen_consts(None, consts);
+        self.files.add_file(header_path, context.decl_header.borrow().to_string());
+    }
+
     pub fn gen_result(&self, name: &str, ty: ResultType) {
         let _guard = self
             .errors
@@ -116,6 +166,9 @@ pub struct TyGenContext<'ccx, 'tcx> {
 
 impl<'ccx, 'tcx: 'ccx> TyGenContext<'ccx, 'tcx> {
     pub fn gen_enum_def(&mut self, def: &'tcx hir::EnumDef, id: TypeId) {
+        if def.is_data_carrying() {
+            return self.gen_tagged_union_def(def, id);
+        }
         let ty_name = self.cx.formatter.fmt_type_name(id);
         writeln!(self.decl_header.borrow_mut(), "typedef enum {ty_name} {{").unwrap();
         for variant in def.variants.iter() {
@@ -126,6 +179,101 @@ impl<'ccx, 'tcx: 'ccx> TyGenContext<'ccx, 'tcx> {
         write!(self.decl_header.borrow_mut(), "}} {ty_name};\n\n").unwrap();
     }
 
+    /// Data-carrying enums are `#[repr(C)]` on the Rust side, which matches a tag
+    /// enum followed by an anonymous union of per-variant structs.
+    pub fn gen_tagged_union_def(&mut self, def: &'tcx hir::EnumDef, id: TypeId) {
+        let ty_name = self.cx.formatter.fmt_type_name(id);
+        let tag_name = self.cx.formatter.fmt_enum_tag_name(&ty_name);
+        writeln!(self.decl_header.borrow_mut(), "typedef enum {tag_name} {{").unwrap();
+        for variant in def.variants.iter() {
+            let enum_variant = self.cx.formatter.fmt_enum_variant(&ty_name, variant);
+            let discriminant = variant.discriminant;
+            writeln!(self.decl_header.borrow_mut(), "\t{enum_variant} = {discriminant},").unwrap();
+        }
+        write!(self.decl_header.borrow_mut(), "}} {tag_name};\n\n").unwrap();
+
+        writeln!(self.decl_header.borrow_mut(), "typedef struct {ty_name} {{").unwrap();
+        writeln!(self.decl_header.borrow_mut(), "\t{tag_name} tag;").unwrap();
+        writeln!(self.decl_header.borrow_mut(), "\tunion {{").unwrap();
+        for variant in def.variants.iter().filter(|v| !v.fields.is_empty()) {
+            writeln!(self.decl_header.borrow_mut(), "\t\tstruct {{").unwrap();
+            for field in variant.fields.iter() {
+                let decls = self.gen_ty_decl(&field.ty, field.name.as_str(), true);
+                for (decl_ty, decl_name) in decls {
+                    writeln!(self.decl_header.borrow_mut(), "\t\t\t{decl_ty} {decl_name};").unwrap();
+                }
+            }
+            let payload_name = self.cx.formatter.fmt_enum_payload_name(variant);
+            writeln!(self.decl_header.borrow_mut(), "\t\t}} {payload_name};").unwrap();
+        }
+        writeln!(self.decl_header.borrow_mut(), "\t}};").unwrap();
+        write!(self.decl_header.borrow_mut(), "}} {ty_name};\n\n").unwrap();
+    }
+
+    /// Traits are passed as a vtable: a context pointer, a function pointer per
+    /// method taking that context, and an optional destructor for the context.
+    pub fn gen_trait_def(&mut self, def: &'tcx hir::TraitDef, id: TraitId) {
+        let vtable_name = self.cx.formatter.fmt_trait_vtable_name(id);
+        writeln!(
+            self.decl_header.borrow_mut(),
+            "typedef struct {vtable_name} {{"
+        )
+        .unwrap();
+        writeln!(self.decl_header.borrow_mut(), "\tvoid* ctx;").unwrap();
+        for method in def.methods.iter() {
+            let return_ty = match method.output {
+                Some(ref o) => self.gen_ty_name(o, false, None),
+                None => "void".into(),
+            };
+            let mut params = vec!["void* ctx".to_string()];
+            for param in method.params.iter() {
+                for (decl_ty, decl_name) in self.gen_ty_decl(&param.ty, param.name.as_str(), false)
+                {
+                    params.push(format!("{decl_ty} {decl_name}"));
+                }
+            }
+            let method_name = method.name.as_str();
+            let params = params.join(", ");
+            writeln!(
+                self.decl_header.borrow_mut(),
+                "\t{return_ty} (*{method_name})({params});"
+            )
+            .unwrap();
+        }
+        writeln!(
+            self.decl_header.borrow_mut(),
+            "\tvoid (*destroy)(void* ctx);"
+        )
+        .unwrap();
+        write!(self.decl_header.borrow_mut(), "}} {vtable_name};\n\n").unwrap();
+    }
+
+    /// Numeric consts become macros so that they can be used in constant expressions,
+    /// strings become `static` arrays.
+    pub fn gen_consts(&mut self, ty: Option<TypeId>, consts: &'tcx [hir::ConstDef]) {
+        if consts.is_empty() {
+            return;
+        }
+        for def in consts {
+            let const_name = self.cx.formatter.fmt_const_name(ty, def);
+            match (def.ty, &def.value) {
+                (hir::ConstType::Str, hir::ConstValue::Str(s)) => {
+                    let literal = self.cx.formatter.fmt_string_literal(s);
+                    writeln!(
+                        self.decl_header.borrow_mut(),
+                        "static const char {const_name}[] = {literal};"
+                    )
+                    .unwrap();
+                }
+                _ => {
+                    let value = self.cx.formatter.fmt_const_value(def);
+                    writeln!(self.decl_header.borrow_mut(), "#define {const_name} {value}").unwrap();
+                }
+            }
+        }
+        writeln!(self.decl_header.borrow_mut()).unwrap();
+    }
+
     pub fn gen_opaque_def(&mut self, _def: &'tcx hir::OpaqueDef, id: TypeId) {
         let ty_name = self.cx.formatter.fmt_type_name(id);
         write!(self.decl_header.borrow_mut(), "typedef struct {ty_name} {ty_name};\n\n").unwrap();
@@ -144,21 +292,32 @@ impl<'ccx, 'tcx: 'ccx> TyGenContext<'ccx, 'tcx> {
         write!(self.decl_header.borrow_mut(), "}} {ty_name};\n\n").unwrap();
     }
 
-    pub fn gen_api(&mut self, id: TypeId, ty: TypeDef<'tcx>) {
-        write!(self.impl_header.borrow_mut(), "\nstruct __{}_API__\n{{\n", ty.name().as_str()).unwrap();
-        for method in ty.methods() {
-            if method.attrs.disable {
-                // Skip method if disabled
-                continue;
+    /// Generate the struct of function pointers the API table has for this type, with
+    /// the slots in the order of `ty_layout`
+    pub fn gen_api(&mut self, id: TypeId, ty: TypeDef<'tcx>, ty_layout: &ApiTypeLayout) {
+        let api_name = format!("__{}_API__", ty.name().as_str());
+        write!(self.impl_header.borrow_mut(), "\ntypedef struct {api_name}\n{{\n  size_t size;\n").unwrap();
+        for slot in &ty_layout.slots {
+            let method = ty.methods().iter().find(|m| m.name.as_str() == slot.name);
+            match (slot.retired, &slot.signature, method) {
+                (false, Some(_), Some(method)) if !method.attrs.disable => {
+                    self.gen_method(id, method, true)
+                }
+                (false, None, _) if matches!(ty, TypeDef::Opaque(_)) => self.gen_dtor(id, true),
+                (false, None, _) => {
+                    let dtor_name = self.cx.formatter.fmt_dtor_name(id);
+                    writeln!(self.impl_header.borrow_mut(), "  void (*{dtor_name})(void* self);").unwrap();
+                }
+                // Methods that were retired or that C doesn't have keep their
+                // place, so that the slots after them line up
+                _ => {
+                    let slot_name = self.cx.formatter.fmt_api_slot_name(&slot.name);
+                    writeln!(self.impl_header.borrow_mut(), "  void (*{slot_name})(void);").unwrap()
+                }
             }
-            self.gen_method(id, method, true);
         }
 
-        if let TypeDef::Opaque(_) = ty {
-            self.gen_dtor(id, true);
-        }
-
-        write!(self.impl_header.borrow_mut(), "}};\n\n").unwrap();
+        write!(self.impl_header.borrow_mut(), "}} {api_name};\n\n").unwrap();
     }
 
     pub fn gen_method(&mut self, id: TypeId, method: &'tcx hir::Method, is_api: bool) {
@@ -442,6 +601,27 @@ impl<'ccx, 'tcx: 'ccx> TyGenContext<'ccx, 'tcx> {
                     .collect::<Vec<_>>();
                 (None, self.cx.formatter.fmt_function_as_c(param_name, output, &inputs))
             },
+            Type::Trait(ref t) => {
+                let def = t.resolve(self.cx.tcx);
+                if def.attrs.disable {
+                    self.cx
+                        .errors
+                        .push_error(format!("Found usage of disabled trait {}", def.name))
+                }
+                let header = if is_decl {
+                    &self.decl_header
+                } else {
+                    &self.impl_header
+                };
+                header
+                    .borrow_mut()
+                    .includes
+                    .insert(self.cx.formatter.fmt_trait_decl_header_path(t.tcx_id));
+                (
+                    None,
+                    self.cx.formatter.fmt_trait_vtable_name(t.tcx_id).into(),
+                )
+            }
             _ => unreachable!("unknown AST/HIR variant"),
         };
         // Todo(breaking): We can remove this requirement
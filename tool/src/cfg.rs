@@ -0,0 +1,138 @@
+//! The cfg set the `#[cfg]`s on bridge items are evaluated against, so that no bindings
+//! are generated for items that aren't compiled into the library.
+
+use crate::Error;
+use diplomat_core::ast::CfgSet;
+use serde::Deserialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// How the library the bindings are for is built, as the `--cfg`, `--features` and
+/// `--cargo-metadata` options or the `[build]` table of `diplomat.toml`.
+///
+/// When none of them are given, `#[cfg]`s aren't evaluated and every item is kept.
+#[derive(Clone, Debug, Default, Deserialize, clap::Args)]
+#[serde(deny_unknown_fields)]
+pub struct CfgOptions {
+    /// A cfg the library is built with, `name` or `name="value"` like `rustc --cfg`.
+    #[clap(long = "cfg")]
+    #[serde(default)]
+    pub cfg: Vec<String>,
+
+    /// The cargo features the bridge crate is built with.
+    #[clap(long, value_delimiter = ',')]
+    #[serde(default)]
+    pub features: Vec<String>,
+
+    /// A file with the output of `cargo metadata --format-version 1`, to enable the
+    /// features cargo resolved for the bridge crate.
+    #[clap(long, value_parser)]
+    pub cargo_metadata: Option<PathBuf>,
+}
+
+impl CfgOptions {
+    /// The cfg set for the bridge crate rooted at `entry`, or `None` if no options were given
+    pub fn resolve(&self, entry: &Path) -> Result<Option<CfgSet>, Error> {
+        if self.cfg.is_empty() && self.features.is_empty() && self.cargo_metadata.is_none() {
+            return Ok(None);
+        }
+        let mut set = CfgSet::new();
+        for cfg in &self.cfg {
+            set.insert_str(cfg).map_err(|e| Error::InvalidCfg {
+                cfg: cfg.clone(),
+                message: e.to_string(),
+            })?;
+        }
+        for feature in &self.features {
+            set.insert_feature(feature.as_str());
+        }
+        if let Some(path) = &self.cargo_metadata {
+            let features = resolved_features(path, entry).map_err(|message| Error::Load {
+                path: path.clone(),
+                message,
+            })?;
+            for feature in features {
+                set.insert_feature(feature);
+            }
+        }
+        Ok(Some(set))
+    }
+}
+
+/// The features cargo resolved for the package whose library is rooted at `entry`,
+/// or for the root package if no package is
+fn resolved_features(path: &Path, entry: &Path) -> Result<Vec<String>, String> {
+    let contents = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    let metadata: serde_json::Value = serde_json::from_str(&contents).map_err(|e| e.to_string())?;
+
+    let entry = fs::canonicalize(entry).ok();
+    let package_id = metadata["packages"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .find(|package| {
+            package["targets"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .any(|target| {
+                    let src_path = target["src_path"].as_str().map(Path::new);
+                    entry.is_some() && src_path.and_then(|p| fs::canonicalize(p).ok()) == entry
+                })
+        })
+        .map(|package| &package["id"])
+        .unwrap_or(&metadata["resolve"]["root"]);
+    let package_id = package_id
+        .as_str()
+        .ok_or("no package has the entry file as a target, and there is no root package")?;
+
+    let nodes = metadata["resolve"]["nodes"]
+        .as_array()
+        .ok_or("no resolved dependency graph, was it generated with --no-deps?")?;
+    let node = nodes
+        .iter()
+        .find(|node| node["id"].as_str() == Some(package_id))
+        .ok_or_else(|| format!("package {package_id} is not in the dependency graph"))?;
+    Ok(node["features"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|feature| feature.as_str().map(ToOwned::to_owned))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::resolved_features;
+    use std::fs;
+
+    #[test]
+    fn test_resolved_features() {
+        let dir = std::env::temp_dir().join(format!("diplomat-cfg-{}", std::process::id()));
+        fs::create_dir_all(dir.join("src")).unwrap();
+        let entry = dir.join("src/lib.rs");
+        fs::write(&entry, "").unwrap();
+        let metadata = serde_json::json!({
+            "packages": [
+                { "id": "dep 1.0.0", "targets": [{ "src_path": "/dep/src/lib.rs" }] },
+                { "id": "ffi 1.0.0", "targets": [{ "src_path": entry }] },
+            ],
+            "resolve": {
+                "root": null,
+                "nodes": [
+                    { "id": "dep 1.0.0", "features": ["std"] },
+                    { "id": "ffi 1.0.0", "features": ["buffers", "default"] },
+                ],
+            },
+        });
+        let path = dir.join("metadata.json");
+        fs::write(&path, metadata.to_string()).unwrap();
+
+        let features = resolved_features(&path, &entry);
+        let missing = resolved_features(&path, &dir.join("src/other.rs"));
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(features.unwrap(), ["buffers", "default"]);
+        assert!(missing.is_err());
+    }
+}
@@ -0,0 +1,175 @@
+//! `--check`, which generates the bindings in memory and compares them with the files
+//! on disk instead of writing them, to catch bindings nobody regenerated.
+
+use crate::output;
+use similar::TextDiff;
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// A file on disk that doesn't match what would be generated
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Mismatch {
+    /// The file would be generated but doesn't exist
+    Added(PathBuf),
+    /// The file exists but would be generated differently
+    Changed {
+        path: PathBuf,
+        /// A unified diff from the file on disk to the generated one
+        diff: String,
+    },
+    /// The manifest of the out folder lists the file, but it isn't generated anymore
+    Stale(PathBuf),
+}
+
+impl Mismatch {
+    pub fn path(&self) -> &Path {
+        match self {
+            Mismatch::Added(path) | Mismatch::Changed { path, .. } | Mismatch::Stale(path) => path,
+        }
+    }
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Mismatch::Added(path) => write!(f, "added: {}", path.display()),
+            Mismatch::Changed { path, diff } => {
+                write!(f, "changed: {}\n{}", path.display(), diff.trim_end())
+            }
+            Mismatch::Stale(path) => write!(f, "stale: {}", path.display()),
+        }
+    }
+}
+
+/// Compare the files generated into `out_folder` with the ones in it.
+///
+/// Like when writing the files, only those listed in the manifest of `out_folder` can
+/// be stale, so that hand-written files next to the generated ones are left alone.
+pub(crate) fn compare(
+    out_folder: &Path,
+    files: &HashMap<String, String>,
+) -> io::Result<Vec<Mismatch>> {
+    let mut mismatches = Vec::new();
+
+    // Sorted, so that the report doesn't depend on the order the backend generated files in
+    let generated: BTreeSet<PathBuf> = files.keys().map(|path| out_folder.join(path)).collect();
+    for (subpath, text) in files {
+        mismatches.extend(compare_file(out_folder.join(subpath), text)?);
+    }
+
+    mismatches.extend(
+        output::read_manifest(out_folder)?
+            .into_iter()
+            .map(|subpath| out_folder.join(subpath))
+            .filter(|path| !generated.contains(path) && path.is_file())
+            .map(Mismatch::Stale),
+    );
+
+    mismatches.sort_by(|a, b| a.path().cmp(b.path()));
+    Ok(mismatches)
+}
+
+/// Compare the file at `path` with `text`
+pub(crate) fn compare_file(path: PathBuf, text: &str) -> io::Result<Option<Mismatch>> {
+    let on_disk = match fs::read(&path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Some(Mismatch::Added(path))),
+        Err(e) => return Err(e),
+    };
+    if on_disk == text.as_bytes() {
+        return Ok(None);
+    }
+    let on_disk = String::from_utf8_lossy(&on_disk);
+    let name = path.display().to_string();
+    let diff = TextDiff::from_lines(&*on_disk, text)
+        .unified_diff()
+        .header(&name, &name)
+        .to_string();
+    Ok(Some(Mismatch::Changed { path, diff }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{compare, Mismatch};
+    use crate::output::{add_manifest, write_files};
+    use std::collections::HashMap;
+    use std::fs;
+
+    fn files(files: &[(&str, &str)]) -> HashMap<String, String> {
+        files
+            .iter()
+            .map(|(path, text)| (path.to_string(), text.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_compare() {
+        let out = std::env::temp_dir().join(format!("diplomat-check-{}", std::process::id()));
+        fs::create_dir_all(out.join("sub")).unwrap();
+        fs::write(out.join("same.h"), "same\n").unwrap();
+        fs::write(out.join("sub/changed.h"), "old\n").unwrap();
+        fs::write(out.join("stale.h"), "stale\n").unwrap();
+        let mut previous = files(&[("same.h", ""), ("sub/changed.h", ""), ("stale.h", "")]);
+        add_manifest(&mut previous);
+        fs::write(
+            out.join(".diplomat-manifest"),
+            &previous[".diplomat-manifest"],
+        )
+        .unwrap();
+
+        let mut files = files(&[
+            ("same.h", "same\n"),
+            ("sub/changed.h", "new\n"),
+            ("added.h", "added\n"),
+        ]);
+        add_manifest(&mut files);
+        let mismatches = compare(&out, &files).unwrap();
+        fs::remove_dir_all(&out).unwrap();
+
+        assert_eq!(mismatches.len(), 4, "{mismatches:?}");
+        assert!(
+            matches!(&mismatches[0], Mismatch::Changed { path, .. } if path.ends_with(".diplomat-manifest"))
+        );
+        assert!(matches!(&mismatches[1], Mismatch::Added(path) if path.ends_with("added.h")));
+        assert!(matches!(&mismatches[2], Mismatch::Stale(path) if path.ends_with("stale.h")));
+        match &mismatches[3] {
+            Mismatch::Changed { path, diff } => {
+                assert!(path.ends_with("sub/changed.h"));
+                assert!(diff.contains("-old\n+new\n"));
+            }
+            mismatch => panic!("expected a changed file, got {mismatch:?}"),
+        }
+    }
+
+    #[test]
+    fn test_handwritten_file_is_not_stale() {
+        let out =
+            std::env::temp_dir().join(format!("diplomat-check-handwritten-{}", std::process::id()));
+        fs::create_dir_all(&out).unwrap();
+        let mut generated = files(&[("Type.h", "type\n"), ("Removed.h", "removed\n")]);
+        add_manifest(&mut generated);
+        write_files(&out, generated, &mut |_| {}).unwrap();
+        fs::write(out.join("Handwritten.h"), "mine\n").unwrap();
+
+        let mut up_to_date = files(&[("Type.h", "type\n"), ("Removed.h", "removed\n")]);
+        add_manifest(&mut up_to_date);
+        let up_to_date = compare(&out, &up_to_date).unwrap();
+
+        let mut regenerated = files(&[("Type.h", "type\n")]);
+        add_manifest(&mut regenerated);
+        let stale: Vec<_> = compare(&out, &regenerated)
+            .unwrap()
+            .into_iter()
+            .filter(|mismatch| matches!(mismatch, Mismatch::Stale(_)))
+            .collect();
+        fs::remove_dir_all(&out).unwrap();
+
+        assert!(up_to_date.is_empty(), "{up_to_date:?}");
+        assert_eq!(stale.len(), 1, "{stale:?}");
+        assert!(stale[0].path().ends_with("Removed.h"));
+    }
+}
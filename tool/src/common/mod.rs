@@ -6,10 +6,13 @@
 //! should live in the c2 module, not here.
 
 use core::mem;
+use serde::de::DeserializeOwned;
 use std::borrow::Cow;
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::fmt;
+use std::fs;
+use std::path::Path;
 
 /// This type abstracts over files being written to.
 #[derive(Default, Debug)]
@@ -116,3 +119,9 @@ impl<'a, 'tcx, E> Drop for ErrorContextGuard<'a, 'tcx, E> {
         let _ = mem::replace(&mut *self.0.context.borrow_mut(), mem::take(&mut self.1));
     }
 }
+
+/// Read the backend-specific configuration file passed with `-l`
+pub fn read_library_config<C: DeserializeOwned>(path: &Path) -> Result<C, String> {
+    let contents = fs::read_to_string(path).map_err(|err| err.to_string())?;
+    toml::from_str(&contents).map_err(|err| err.to_string())
+}
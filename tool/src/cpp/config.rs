@@ -25,8 +25,8 @@ pub struct LibraryConfig {
     pub unique_ptr: LibraryType,
 }
 
-impl LibraryConfig {
-    pub fn default() -> LibraryConfig {
+impl Default for LibraryConfig {
+    fn default() -> LibraryConfig {
         LibraryConfig {
             headers: vec!["#include <optional>".into()],
             nullopt: CallableLibraryType {
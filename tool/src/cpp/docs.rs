@@ -1,8 +1,5 @@
-use colored::*;
 use diplomat_core::Env;
 use std::fmt::Write;
-use std::fs;
-use std::path::Path;
 use std::{collections::HashMap, fmt};
 
 use diplomat_core::ast;
@@ -14,29 +11,10 @@ use crate::docs_util::{CppRst, FromMarkdown};
 /// Generate RST-formatted Sphinx docs for all FFI types.
 pub fn gen_docs(
     env: &Env,
-    library_config_path: Option<&Path>,
+    library_config: LibraryConfig,
     outs: &mut HashMap<String, String>,
     docs_url_gen: &ast::DocsUrlGenerator,
 ) -> fmt::Result {
-    let mut library_config = LibraryConfig::default();
-    if let Some(path) = library_config_path {
-        // Should be fine, we've already verified the path
-        if let Ok(contents) = fs::read_to_string(path) {
-            match toml::from_str(&contents) {
-                Ok(config) => library_config = config,
-                Err(err) => {
-                    eprintln!(
-                        "{}Unable to parse library configuration file: {:?}\n{}",
-                        "Error: ".red().bold(),
-                        path,
-                        err,
-                    );
-                    std::process::exit(1);
-                }
-            }
-        }
-    }
-
     let index_out = outs.entry("index.rst".to_string()).or_default();
     writeln!(index_out, "Documentation")?;
     writeln!(index_out, "=============")?;
@@ -77,6 +55,9 @@ pub fn gen_docs(
 
             for item in module.items() {
                 if let ast::ModSymbol::CustomType(ref typ) = item {
+                    if typ.attrs().skip_if_ast {
+                        continue;
+                    }
                     writeln!(out)?;
                     gen_custom_type_docs(out, typ, in_path, env, &library_config, docs_url_gen)?;
                 }
@@ -317,20 +298,20 @@ pub fn gen_field_docs<W: fmt::Write>(
 
 pub fn gen_enum_variant_docs<W: fmt::Write>(
     out: &mut W,
-    variant: &(ast::Ident, isize, ast::Docs, ast::Attrs),
+    variant: &ast::EnumVariant,
     in_path: &ast::Path,
     env: &Env,
     docs_url_gen: &ast::DocsUrlGenerator,
 ) -> fmt::Result {
-    write!(out, ".. cpp:enumerator:: {}", variant.0)?;
+    write!(out, ".. cpp:enumerator:: {}", variant.name)?;
 
     writeln!(out)?;
 
-    if !variant.2.is_empty() {
+    if !variant.docs.is_empty() {
         let mut enum_indented = indented(out).with_str("    ");
         CppRst::from_markdown(
             &variant
-                .2
+                .docs
                 .to_markdown(docs_url_gen, ast::MarkdownStyle::RstCompat),
             in_path,
             env,
@@ -1,9 +1,6 @@
-use colored::*;
 use std::collections::{HashMap, HashSet};
 use std::fmt;
 use std::fmt::Write;
-use std::fs;
-use std::path::Path;
 
 use diplomat_core::ast;
 use diplomat_core::Env;
@@ -24,7 +21,7 @@ mod conversions;
 
 pub mod docs;
 
-mod config;
+pub mod config;
 
 mod util;
 
@@ -52,32 +49,13 @@ fn render_header(typ_name: &ast::Ident, headers: &[String]) -> String {
 
 pub fn gen_bindings(
     env: &Env,
-    library_config_path: Option<&Path>,
+    library_config: config::LibraryConfig,
     docs_url_gen: &ast::DocsUrlGenerator,
     outs: &mut HashMap<String, String>,
 ) -> fmt::Result {
     // Note: Assumes the existence of C bindings!
     // This must be called alongside c::gen_bindings
 
-    let mut library_config = config::LibraryConfig::default();
-    if let Some(path) = library_config_path {
-        // Should be fine, we've already verified the path
-        if let Ok(contents) = fs::read_to_string(path) {
-            match toml::from_str(&contents) {
-                Ok(config) => library_config = config,
-                Err(err) => {
-                    eprintln!(
-                        "{}Unable to parse library configuration file: {:?}\n{}",
-                        "Error: ".red().bold(),
-                        path,
-                        err,
-                    );
-                    std::process::exit(1);
-                }
-            }
-        }
-    }
-
     let diplomat_runtime_out = outs.entry("diplomat_runtime.hpp".to_string()).or_default();
     write!(diplomat_runtime_out, "{RUNTIME_HPP}")?;
 
@@ -104,12 +82,18 @@ pub fn gen_bindings(
                 )?;
                 writeln!(out, "enum struct {} {{", enm.name)?;
                 let mut enm_indent = indented(out).with_str("  ");
-                for (name, discriminant, docs, _attrs) in enm.variants.iter() {
+                for variant in enm.variants.iter() {
                     gen_comment_block(
                         &mut enm_indent,
-                        &docs.to_markdown(docs_url_gen, ast::MarkdownStyle::Normal),
+                        &variant
+                            .docs
+                            .to_markdown(docs_url_gen, ast::MarkdownStyle::Normal),
+                    )?;
+                    writeln!(
+                        &mut enm_indent,
+                        "{} = {},",
+                        variant.name, variant.discriminant
                     )?;
-                    writeln!(&mut enm_indent, "{name} = {discriminant},")?;
                 }
                 writeln!(out, "}};")?;
             }
@@ -1,6 +1,7 @@
 #ifndef DIPLOMAT_RUNTIME_CPP_H
 #define DIPLOMAT_RUNTIME_CPP_H
 
+#include <stdexcept>
 #include <string>
 #include <variant>
 #include <array>
@@ -41,6 +42,12 @@ inline capi::DiplomatWriteable WriteableFromString(std::string& string) {
   return w;
 };
 
+// Thrown when a method of the library panicked, with the message of the panic
+class panic : public std::runtime_error {
+ public:
+  explicit panic(const std::string& message) : std::runtime_error(message) {}
+};
+
 template<typename T> struct WriteableTrait {
   // static inline capi::DiplomatWriteable Construct(T& t);
 };
@@ -1,7 +1,7 @@
 //! This module contains functions for formatting types
 
 use crate::{c2::CFormatter, ApiInfo};
-use diplomat_core::hir::{self, StringEncoding, TypeContext, TypeId};
+use diplomat_core::hir::{self, StringEncoding, TraitId, TypeContext, TypeId};
 use std::borrow::Cow;
 
 /// This type mediates all formatting
@@ -47,6 +47,23 @@ impl<'tcx> Cpp2Formatter<'tcx> {
         }
     }
 
+    /// Resolve and format a trait for use in code (without the namespace)
+    pub fn fmt_trait_name_unnamespaced(&self, id: TraitId) -> Cow<'tcx, str> {
+        let resolved = self.c.tcx().resolve_trait(id);
+
+        resolved.attrs.rename.apply(resolved.name.as_str().into())
+    }
+    /// Resolve and format a trait for use in code
+    pub fn fmt_trait_name(&self, id: TraitId) -> Cow<'tcx, str> {
+        let resolved = self.c.tcx().resolve_trait(id);
+        let name = resolved.attrs.rename.apply(resolved.name.as_str().into());
+        if let Some(ref ns) = resolved.attrs.namespace {
+            format!("{ns}::{name}").into()
+        } else {
+            name
+        }
+    }
+
     /// Resolve and format a named type for use in diagnostics
     /// (don't apply rename rules and such)
     pub fn fmt_type_name_diagnostics(&self, id: TypeId) -> Cow<'tcx, str> {
@@ -63,7 +80,25 @@ impl<'tcx> Cpp2Formatter<'tcx> {
         let type_name = self.fmt_type_name_unnamespaced(id);
         format!("{type_name}.hpp")
     }
+    /// Format the name of the header of the loader of an API table
+    pub fn fmt_loader_header_path(&self, apiname: &str) -> String {
+        format!("{apiname}Loader.hpp")
+    }
+
+    /// Resolve and format the name of a trait for use in header names
+    pub fn fmt_trait_decl_header_path(&self, id: TraitId) -> String {
+        let trait_name = self.fmt_trait_name_unnamespaced(id);
+        format!("{trait_name}.d.hpp")
+    }
+    /// Resolve and format the name of a trait for use in header names
+    pub fn fmt_trait_impl_header_path(&self, id: TraitId) -> String {
+        let trait_name = self.fmt_trait_name_unnamespaced(id);
+        format!("{trait_name}.hpp")
+    }
 
+    pub fn fmt_c_trait_decl_header_path(&self, id: TraitId) -> String {
+        self.c.fmt_trait_decl_header_path(id)
+    }
     pub fn fmt_c_decl_header_path(&self, id: TypeId) -> String {
         self.c.fmt_decl_header_path(id)
     }
@@ -85,6 +120,10 @@ impl<'tcx> Cpp2Formatter<'tcx> {
     ) -> Cow<'tcx, str> {
         self.c.fmt_enum_variant(ctype, variant)
     }
+    /// Format the name of the C union member holding a variant's payload
+    pub fn fmt_c_enum_payload_name(&self, variant: &'tcx hir::EnumVariant) -> Cow<'tcx, str> {
+        self.c.fmt_enum_payload_name(variant)
+    }
     /// Format a field name or parameter name
     // might need splitting in the future if we decide to support renames here
     pub fn fmt_param_name<'a>(&self, ident: &'a str) -> Cow<'a, str> {
@@ -95,6 +134,10 @@ impl<'tcx> Cpp2Formatter<'tcx> {
         format!("capi::{}", self.c.fmt_type_name(id)).into()
     }
 
+    pub fn fmt_c_trait_vtable_name(&self, id: TraitId) -> String {
+        format!("capi::{}", self.c.fmt_trait_vtable_name(id))
+    }
+
     pub fn fmt_c_ptr<'a>(&self, ident: &'a str, mutability: hir::Mutability) -> Cow<'a, str> {
         self.c.fmt_ptr(ident, mutability)
     }
@@ -157,8 +200,15 @@ impl<'tcx> Cpp2Formatter<'tcx> {
 
     /// Format a method
     pub fn fmt_method_name<'a>(&self, method: &'a hir::Method) -> Cow<'a, str> {
-        let name = method.attrs.rename.apply(method.name.as_str().into());
+        self.fmt_method_ident(method.attrs.rename.apply(method.name.as_str().into()))
+    }
+
+    /// Format a trait method
+    pub fn fmt_trait_method_name<'a>(&self, method: &'a hir::TraitMethod) -> Cow<'a, str> {
+        self.fmt_method_ident(method.attrs.rename.apply(method.name.as_str().into()))
+    }
 
+    fn fmt_method_ident<'a>(&self, name: Cow<'a, str>) -> Cow<'a, str> {
         // TODO(#60): handle other keywords
         if name == "new" {
             "new_".into()
@@ -172,7 +222,7 @@ impl<'tcx> Cpp2Formatter<'tcx> {
     pub fn fmt_c_method_name<'a>(&self, ty: TypeId, method: &'a hir::Method, api_info: Option<&ApiInfo>) -> Cow<'a, str> {
         if let Some(info) = api_info {
             let ty_name = self.fmt_type_name(ty);
-            format!("{}().{}.{}", info.get_api_fn, ty_name, self.c.fmt_method_name(ty, method, false)).into()
+            format!("{}().{}->{}", info.get_api_fn, ty_name, self.c.fmt_method_name(ty, method, false)).into()
         } else {
             format!("capi::{}", self.c.fmt_method_name(ty, method, true)).into()
         }
@@ -180,11 +230,34 @@ impl<'tcx> Cpp2Formatter<'tcx> {
     pub fn fmt_c_dtor_name<'a>(&self, ty: TypeId, api_info: Option<&ApiInfo>) -> Cow<'a, str> {
         if let Some(info) = api_info {
             let ty_name = self.fmt_type_name(ty);
-            format!("{}().{}.{}", info.get_api_fn, ty_name, self.c.fmt_dtor_name(ty)).into()
+            format!("{}().{}->{}", info.get_api_fn, ty_name, self.c.fmt_dtor_name(ty)).into()
         } else {
             format!("capi::{}", self.c.fmt_dtor_name(ty)).into()
         }
     }
+    /// Format the name of a const
+    pub fn fmt_const_name<'a>(&self, def: &'a hir::ConstDef) -> Cow<'a, str> {
+        def.attrs.rename.apply(def.name.as_str().into())
+    }
+    /// Format the type a const is declared with
+    pub fn fmt_const_type(&self, def: &hir::ConstDef) -> Cow<'static, str> {
+        match def.ty {
+            hir::ConstType::Primitive(prim) => self.fmt_primitive_as_c(prim),
+            hir::ConstType::Str => self.fmt_borrowed_str(StringEncoding::Utf8),
+            _ => unreachable!("unknown AST/HIR variant"),
+        }
+    }
+    /// Format the value of a const as a constant expression
+    pub fn fmt_const_value(&self, def: &hir::ConstDef) -> String {
+        match def.value {
+            hir::ConstValue::Str(ref s) => self.c.fmt_string_literal(s),
+            _ => self.c.fmt_const_value(def),
+        }
+    }
+    /// The header holding consts declared at the top level of a module
+    pub fn fmt_consts_header_path(&self) -> String {
+        "diplomat_consts.hpp".into()
+    }
     /// Get the primitive type as a C type
     pub fn fmt_primitive_as_c(&self, prim: hir::PrimitiveType) -> Cow<'static, str> {
         self.c.fmt_primitive_as_c(prim)
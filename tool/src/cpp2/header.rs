@@ -1,5 +1,5 @@
 use askama::Template;
-use diplomat_core::hir::TypeDef;
+use diplomat_core::hir::{TraitDef, TypeDef};
 use std::borrow::Cow;
 use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::{self, Write};
@@ -95,6 +95,22 @@ impl Header {
         let ns = def.attrs().namespace.clone();
         self.forwards.entry(ns).or_default().insert(forward);
     }
+    pub fn append_trait_forward(&mut self, def: &TraitDef, trait_name_unnamespaced: &str) {
+        let ns = def.attrs.namespace.clone();
+        self.forwards
+            .entry(ns)
+            .or_default()
+            .insert(Forward::Class(trait_name_unnamespaced.into()));
+    }
+    pub fn rm_trait_forward(&mut self, def: &TraitDef, trait_name_unnamespaced: &str) {
+        let ns = &def.attrs.namespace;
+        if let Some(ns_table) = self.forwards.get_mut(ns) {
+            ns_table.remove(&Forward::Class(trait_name_unnamespaced.into()));
+            if ns_table.is_empty() {
+                self.forwards.remove(ns);
+            }
+        }
+    }
     pub fn rm_forward(&mut self, def: TypeDef, ty_name_unnamespaced: &str) {
         let ns = &def.attrs().namespace;
         let forward = Self::forward_for(def, ty_name_unnamespaced);
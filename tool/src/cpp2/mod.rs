@@ -1,8 +1,13 @@
+#[cfg(test)]
+#[macro_use]
+mod test_util;
+
 mod formatter;
 mod header;
 mod ty;
 
 use crate::{c2::CContext, common::{ErrorStore, FileMap}, ApiInfo};
+use askama::Template;
 use diplomat_core::hir::TypeContext;
 use formatter::Cpp2Formatter;
 
@@ -33,10 +38,226 @@ impl<'tcx> Cpp2Context<'tcx> {
             "diplomat_runtime.hpp".into(),
             crate::cpp::RUNTIME_HPP.into(),
         );
+        // The headers of types from other crates are generated with those crates
         for (id, ty) in self.tcx.all_types() {
-            self.gen_ty(id, ty, api_info)
+            if self.tcx.external_crate(id).is_none() {
+                self.gen_ty(id, ty, api_info)
+            }
+        }
+
+        for (id, trt) in self.tcx.all_traits() {
+            if self.tcx.external_trait_crate(id).is_none() {
+                self.gen_trait(id, trt)
+            }
+        }
+
+        if !self.tcx.consts().is_empty() {
+            self.gen_module_consts(self.tcx.consts())
+        }
+
+        if let Some(info) = api_info.filter(|info| info.loader) {
+            self.gen_loader(info)
         }
     }
 
+    /// Generate the class that opens the library and hands the wrappers its API table
+    fn gen_loader(&self, info: &ApiInfo) {
+        #[derive(Template)]
+        #[template(path = "cpp2/loader.h.jinja", escape = "none")]
+        struct LoaderTemplate<'a> {
+            apiname: &'a str,
+            refresh_api_fn: &'a str,
+            get_api_fn: &'a str,
+        }
+
+        let loader = LoaderTemplate {
+            apiname: info.apiname,
+            refresh_api_fn: info.refresh_api_fn,
+            get_api_fn: info.get_api_fn,
+        }
+        .render()
+        .unwrap();
+        self.c.files.add_file(self.formatter.fmt_loader_header_path(info.apiname), loader);
+    }
+
     // further methods can be found in ty.rs and formatter.rs
 }
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_tagged_union() {
+        test_file! {
+            #[diplomat::bridge]
+            mod ffi {
+                pub struct Point {
+                    pub x: i32,
+                    pub y: i32,
+                }
+
+                pub enum Shape {
+                    Circle { radius: f64 },
+                    Dot { center: Point },
+                    Empty,
+                }
+
+                #[diplomat::opaque]
+                pub struct Canvas;
+
+                impl Canvas {
+                    pub fn draw(&mut self, shape: Shape) {}
+                    pub fn last(&self) -> Shape {
+                        unimplemented!()
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_trait() {
+        test_file! {
+            #[diplomat::bridge]
+            mod ffi {
+                pub trait Logger {
+                    fn log(&self, level: u8) -> i32;
+                    fn flush(&self);
+                }
+
+                #[diplomat::opaque]
+                pub struct Service;
+
+                impl Service {
+                    pub fn run(&self, logger: &dyn Logger) {}
+                    pub fn set_logger(&mut self, logger: Box<dyn Logger>) {}
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_consts() {
+        test_file! {
+            #[diplomat::bridge]
+            mod ffi {
+                /// The largest length
+                pub const MAX_LEN: u32 = 16;
+                pub const NAME: &str = "demo";
+                pub const RATIO: f64 = 0.5;
+
+                #[diplomat::opaque]
+                pub struct Thing;
+
+                impl Thing {
+                    pub const SIZE: i8 = -3;
+                    pub const ENABLED: bool = true;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_catch_panics() {
+        test_file! {
+            #[diplomat::bridge]
+            mod ffi {
+                pub enum MyError {
+                    Panicked,
+                    Other,
+                }
+
+                #[diplomat::opaque]
+                pub struct Thing;
+
+                #[diplomat::catch_panics]
+                impl Thing {
+                    pub fn might_panic(x: u32) -> u32 {
+                        unimplemented!()
+                    }
+                    pub fn might_fail(x: u32) -> Result<u32, MyError> {
+                        unimplemented!()
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_api_loader() {
+        test_file! {
+            api_loader
+
+            #[diplomat::bridge]
+            mod ffi {
+                #[diplomat::opaque]
+                pub struct Counter(u32);
+
+                impl Counter {
+                    pub fn new(start: u32) -> Box<Counter> {
+                        unimplemented!()
+                    }
+                    pub fn increment(&mut self) -> u32 {
+                        unimplemented!()
+                    }
+                    pub fn describe(&self, write: &mut DiplomatWriteable) {}
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_api_core_calls() {
+        // Without linking the library, the runtime functions are called through the core table
+        test_file! {
+            api_loader
+
+            #[diplomat::bridge]
+            mod ffi {
+                #[diplomat::opaque]
+                pub struct Thing;
+
+                #[diplomat::catch_panics]
+                impl Thing {
+                    pub fn might_panic(x: u32) -> u32 {
+                        unimplemented!()
+                    }
+                    pub fn describe(&self, write: &mut DiplomatWriteable) {}
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_types_from_other_crates() {
+        test_file! {
+            dep other_crate {
+                #[diplomat::bridge]
+                mod ffi {
+                    #[diplomat::opaque]
+                    pub struct Other;
+
+                    pub struct OtherStruct {
+                        pub x: u8,
+                    }
+                }
+            }
+
+            #[diplomat::bridge]
+            mod ffi {
+                use other_crate::ffi::{Other, OtherStruct};
+
+                #[diplomat::opaque]
+                pub struct Mine;
+
+                impl Mine {
+                    pub fn make_other(&self, s: OtherStruct) -> Box<Other> {
+                        unimplemented!()
+                    }
+                    pub fn use_other(&self, other: &Other) -> OtherStruct {
+                        unimplemented!()
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,99 @@
+/// A macro to test that the C++ headers match the output from diplomat. It checks the output
+/// against an [insta] snapshot, leaving out the C headers and the runtime.
+///
+/// # Usage
+/// ```
+/// test_file! {
+///     #[diplomat::bridge]
+///     mod ffi {
+///         enum MyEnum {
+///             A, B, C
+///         }
+///     }
+/// }
+/// ```
+///
+/// The bridge can use the types of the bridge of another crate, given before it:
+/// ```
+/// test_file! {
+///     dep other_crate {
+///         #[diplomat::bridge]
+///         mod ffi {
+///             #[diplomat::opaque]
+///             struct Other;
+///         }
+///     }
+///
+///     #[diplomat::bridge]
+///     mod ffi {
+///         use other_crate::ffi::Other;
+///     }
+/// }
+/// ```
+///
+/// Starting with `api_loader`, the wrappers call the library through the table of an API,
+/// which they get from a generated loader:
+/// ```
+/// test_file! {
+///     api_loader
+///
+///     #[diplomat::bridge]
+///     mod ffi {
+///         #[diplomat::opaque]
+///         struct Opaque;
+///     }
+/// }
+/// ```
+macro_rules! test_file {
+    (dep $dep_name:ident { $($dep:tt)* } $($file:tt)*) => {
+        let dep: syn::File = syn::parse_quote! { $($dep)* };
+        let dep = diplomat_core::ast::File::from(&dep);
+        test_file!(@crates [(stringify!($dep_name), &dep)] None; $($file)*);
+    };
+    (api_loader $($file:tt)*) => {
+        let api_info = crate::ApiInfo {
+            apiname: "TestApi",
+            refresh_api_fn: "refresh_test_api",
+            get_api_fn: "get_test_api",
+            additional_includes: &[],
+            layout_file: None,
+            loader: true,
+        };
+        test_file!(@crates [] Some(&api_info); $($file)*);
+    };
+    (@crates $crates:tt $api_info:expr; $($file:tt)*) => {
+        let parsed: syn::File = syn::parse_quote! { $($file)* };
+        let custom_types = diplomat_core::ast::File::from(&parsed);
+        let env = custom_types.all_types_with_crates($crates);
+
+        let registry = crate::backend::Registry::new();
+        let backend = registry.get("cpp2").unwrap();
+        let tcx = diplomat_core::hir::TypeContext::from_ast(&env, backend.attr_validator())
+            .unwrap_or_else(|e| panic!("lowering failed: {e:?}"));
+        let api_info: Option<&crate::ApiInfo> = $api_info;
+        let api_layout = api_info.map(|info| crate::api_layout::load(&env, info).unwrap());
+        let config = crate::backend::Config {
+            env: &env,
+            library_config: None,
+            docs_url_gen: &Default::default(),
+            strip_prefix: None,
+            crate_name: None,
+            api_info,
+            api_layout: api_layout.as_ref(),
+        };
+
+        let mut out_texts = backend.generate(&tcx, &config).unwrap().take_files();
+
+        out_texts.retain(|k, _| k.ends_with(".hpp"));
+        out_texts.remove("diplomat_runtime.hpp");
+
+        for out in out_texts.keys() {
+            insta::with_settings!({ snapshot_suffix => out.clone() }, {
+                insta::assert_snapshot!(out_texts.get(out).unwrap())
+            });
+        }
+    };
+    ($($file:tt)*) => {
+        test_file!(@crates [] None; $($file)*);
+    };
+}
@@ -5,10 +5,11 @@ use super::Cpp2Context;
 use super::Cpp2Formatter;
 use askama::Template;
 use diplomat_core::hir::{
-    self, Mutability, OpaqueOwner, ReturnType, SelfType, StructPathLike, SuccessType, TyPosition,
-    Type, TypeDef, TypeId,
+    self, Mutability, OpaqueOwner, ReturnType, SelfType, StructPathLike, SuccessType, TraitId,
+    TyPosition, Type, TypeDef, TypeId,
 };
 use std::borrow::Cow;
+use std::collections::BTreeMap;
 use std::fmt::Write;
 
 impl<'tcx> super::Cpp2Context<'tcx> {
@@ -56,6 +57,17 @@ impl<'tcx> super::Cpp2Context<'tcx> {
         let c_impl_header_path = self.formatter.fmt_c_impl_header_path(id);
         context.impl_header.includes.insert(c_impl_header_path);
 
+        // The wrappers get the API table from `get_api_fn`, which these define
+        if let Some(info) = api_info {
+            for include in info.additional_includes {
+                context.impl_header.includes.insert(include.to_string());
+            }
+            if info.loader {
+                let loader_header_path = self.formatter.fmt_loader_header_path(info.apiname);
+                context.impl_header.includes.insert(loader_header_path);
+            }
+        }
+
         self.c.files
             .add_file(decl_header_path, decl_header.to_string());
         self.c.files
@@ -63,6 +75,76 @@ impl<'tcx> super::Cpp2Context<'tcx> {
     }
 }
 
+impl<'tcx> super::Cpp2Context<'tcx> {
+    pub fn gen_trait(&self, id: TraitId, def: &'tcx hir::TraitDef) {
+        if def.attrs.disable {
+            return;
+        }
+        let trait_name_unnamespaced = self.formatter.fmt_trait_name_unnamespaced(id);
+        let decl_header_path = self.formatter.fmt_trait_decl_header_path(id);
+        let mut decl_header = Header::new(decl_header_path.clone());
+        let impl_header_path = self.formatter.fmt_trait_impl_header_path(id);
+        let mut impl_header = Header::new(impl_header_path.clone());
+
+        let mut context = TyGenContext {
+            cx: self,
+            decl_header: &mut decl_header,
+            impl_header: &mut impl_header,
+        };
+        let guard = self.errors.set_context_ty(def.name.as_str().into());
+        context.gen_trait_def(def, id);
+        drop(guard);
+
+        context.decl_header.rm_trait_forward(def, &trait_name_unnamespaced);
+        context.impl_header.rm_trait_forward(def, &trait_name_unnamespaced);
+        context.decl_header.includes.remove(&*decl_header_path);
+        context.impl_header.includes.remove(&*impl_header_path);
+        context.impl_header.includes.remove(&*decl_header_path);
+
+        context.impl_header.decl_include = Some(decl_header_path.clone());
+
+        let c_decl_header_path = self.formatter.fmt_c_trait_decl_header_path(id);
+        context.decl_header.includes.insert(c_decl_header_path);
+
+        self.c.files
+            .add_file(decl_header_path, decl_header.to_string());
+        self.c.files
+            .add_file(impl_header_path, impl_header.to_string());
+    }
+
+    /// Consts declared at the top level of a module all go in a single header,
+    /// as `inline constexpr` variables in their namespace.
+    pub fn gen_module_consts(&self, consts: &'tcx [hir::ConstDef]) {
+        let header_path = self.formatter.fmt_consts_header_path();
+        let mut header = Header::new(header_path.clone());
+
+        let mut by_namespace = BTreeMap::<Option<&str>, Vec<&hir::ConstDef>>::new();
+        for def in consts {
+            by_namespace
+                .entry(def.attrs.namespace.as_deref())
+                .or_default()
+                .push(def);
+        }
+        for (namespace, defs) in by_namespace {
+            if let Some(ns) = namespace {
+                writeln!(header, "namespace {ns} {{").unwrap();
+            }
+            for def in defs {
+                let type_name = self.formatter.fmt_const_type(def);
+                let name = self.formatter.fmt_const_name(def);
+                let value = self.formatter.fmt_const_value(def);
+                writeln!(header, "inline constexpr {type_name} {name} = {value};").unwrap();
+            }
+            if namespace.is_some() {
+                writeln!(header, "}}").unwrap();
+            }
+            writeln!(header).unwrap();
+        }
+
+        self.c.files.add_file(header_path, header.to_string());
+    }
+}
+
 /// An expression with a corresponding variable name, such as a struct field or a function parameter.
 struct NamedExpression<'a> {
     var_name: Cow<'a, str>,
@@ -103,6 +185,54 @@ struct MethodInfo<'a> {
     /// the C function return value is saved to a variable named `result` or that the
     /// writeable, if present, is saved to a variable named `output`.
     c_to_cpp_return_expression: Option<Cow<'a, str>>,
+    /// If a panic in the method is reported instead of returned as its error, where
+    /// the functions that report it are called from (`capi::` or the core table)
+    panic_fns: Option<Cow<'a, str>>,
+}
+
+/// Everything needed for rendering a trait method and the thunk that calls it from C.
+struct TraitMethodInfo<'a> {
+    /// The C++ method name
+    method_name: Cow<'a, str>,
+    /// The C++ return type
+    return_ty: Cow<'a, str>,
+    /// The C return type of the thunk
+    c_return_ty: Cow<'a, str>,
+    /// Type declarations for the C++ parameters
+    param_decls: Vec<NamedType<'a>>,
+    /// Type declarations for the C parameters of the thunk
+    c_param_decls: Vec<NamedType<'a>>,
+    /// C conversion code for each parameter of the C++ method
+    c_to_cpp_params: Vec<Cow<'a, str>>,
+    /// If the method has a return value, the C++ code converting it back to C. Assumes that
+    /// the C++ method return value is saved to a variable named `result`.
+    cpp_to_c_return_expression: Option<Cow<'a, str>>,
+}
+
+/// Everything needed for rendering a variant of a data-carrying enum.
+struct VariantInfo<'a> {
+    /// The name of the C++ struct holding the variant's fields
+    name: Cow<'a, str>,
+    /// The C tag value for this variant
+    c_variant: Cow<'a, str>,
+    /// The name of the C union member holding the payload
+    payload_name: Cow<'a, str>,
+    /// Type declarations for the C++ fields
+    fields: Vec<NamedType<'a>>,
+    /// C++ conversion code for each field of the C payload
+    cpp_to_c_fields: Vec<NamedExpression<'a>>,
+    /// C conversion code for each field of the C++ payload
+    c_to_cpp_fields: Vec<NamedExpression<'a>>,
+}
+
+/// Everything needed for rendering a `constexpr` const.
+struct ConstInfo<'a> {
+    /// The C++ type of the const
+    type_name: Cow<'a, str>,
+    /// The C++ name of the const
+    name: Cow<'a, str>,
+    /// The value as a C++ constant expression
+    value: String,
 }
 
 /// Everyrhing needed for rendering a lifted lambda
@@ -134,6 +264,9 @@ impl<'ccx, 'tcx: 'ccx, 'header> TyGenContext<'ccx, 'tcx, 'header> {
     /// behave more like an upgraded C++ type. We don't use `enum class` because methods
     /// cannot be added to it.
     pub fn gen_enum_def(&mut self, ty: &'tcx hir::EnumDef, id: TypeId, api_info: Option<&ApiInfo>) {
+        if ty.is_data_carrying() {
+            return self.gen_tagged_union_def(ty, id, api_info);
+        }
         let type_name = self.cx.formatter.fmt_type_name(id);
         let type_name_unnamespaced = self.cx.formatter.fmt_type_name_unnamespaced(id);
         let ctype = self.cx.formatter.fmt_c_type_name(id);
@@ -144,6 +277,12 @@ impl<'ccx, 'tcx: 'ccx, 'header> TyGenContext<'ccx, 'tcx, 'header> {
             .flat_map(|method| self.gen_method_info(id, method, api_info))
             .collect::<Vec<_>>();
 
+        let consts = ty
+            .consts
+            .iter()
+            .map(|def| self.gen_const_info(def))
+            .collect::<Vec<_>>();
+
         #[derive(Template)]
         #[template(path = "cpp2/enum_decl.h.jinja", escape = "none")]
         struct DeclTemplate<'a> {
@@ -152,6 +291,7 @@ impl<'ccx, 'tcx: 'ccx, 'header> TyGenContext<'ccx, 'tcx, 'header> {
             type_name: &'a str,
             ctype: &'a str,
             methods: &'a [MethodInfo<'a>],
+            consts: &'a [ConstInfo<'a>],
             namespace: Option<&'a str>,
             type_name_unnamespaced: &'a str,
         }
@@ -162,6 +302,7 @@ impl<'ccx, 'tcx: 'ccx, 'header> TyGenContext<'ccx, 'tcx, 'header> {
             type_name: &type_name,
             ctype: &ctype,
             methods: methods.as_slice(),
+            consts: consts.as_slice(),
             namespace: ty.attrs.namespace.as_deref(),
             type_name_unnamespaced: &type_name_unnamespaced,
         }
@@ -195,6 +336,102 @@ impl<'ccx, 'tcx: 'ccx, 'header> TyGenContext<'ccx, 'tcx, 'header> {
             .insert(self.cx.formatter.fmt_c_decl_header_path(id));
     }
 
+    /// Adds a data-carrying enum definition to the current decl and impl headers.
+    ///
+    /// Each variant becomes a nested struct, and the enum holds a `std::variant` of them.
+    pub fn gen_tagged_union_def(&mut self, ty: &'tcx hir::EnumDef, id: TypeId, api_info: Option<&ApiInfo>) {
+        let type_name = self.cx.formatter.fmt_type_name(id);
+        let type_name_unnamespaced = self.cx.formatter.fmt_type_name_unnamespaced(id);
+        let ctype = self.cx.formatter.fmt_c_type_name(id);
+
+        let variants = ty
+            .variants
+            .iter()
+            .map(|variant| {
+                let payload_name = self.cx.formatter.fmt_c_enum_payload_name(variant);
+                let c_access = format!("c_enum.{payload_name}.");
+                VariantInfo {
+                    name: self.cx.formatter.fmt_enum_variant(variant),
+                    c_variant: self.cx.formatter.fmt_c_enum_variant(&ctype, variant),
+                    fields: variant
+                        .fields
+                        .iter()
+                        .map(|field| self.gen_ty_decl(&field.ty, field.name.as_str()))
+                        .collect(),
+                    cpp_to_c_fields: variant
+                        .fields
+                        .iter()
+                        .flat_map(|field| self.gen_cpp_to_c_for_field("payload.", field))
+                        .collect(),
+                    c_to_cpp_fields: variant
+                        .fields
+                        .iter()
+                        .map(|field| self.gen_c_to_cpp_for_field(&c_access, field))
+                        .collect(),
+                    payload_name,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let methods = ty
+            .methods
+            .iter()
+            .flat_map(|method| self.gen_method_info(id, method, api_info))
+            .collect::<Vec<_>>();
+
+        let consts = ty
+            .consts
+            .iter()
+            .map(|def| self.gen_const_info(def))
+            .collect::<Vec<_>>();
+
+        #[derive(Template)]
+        #[template(path = "cpp2/tagged_union_decl.h.jinja", escape = "none")]
+        struct DeclTemplate<'a> {
+            type_name: &'a str,
+            ctype: &'a str,
+            variants: &'a [VariantInfo<'a>],
+            methods: &'a [MethodInfo<'a>],
+            consts: &'a [ConstInfo<'a>],
+            namespace: Option<&'a str>,
+            type_name_unnamespaced: &'a str,
+        }
+
+        DeclTemplate {
+            type_name: &type_name,
+            ctype: &ctype,
+            variants: variants.as_slice(),
+            methods: methods.as_slice(),
+            consts: consts.as_slice(),
+            namespace: ty.attrs.namespace.as_deref(),
+            type_name_unnamespaced: &type_name_unnamespaced,
+        }
+        .render_into(self.decl_header)
+        .unwrap();
+
+        #[derive(Template)]
+        #[template(path = "cpp2/tagged_union_impl.h.jinja", escape = "none")]
+        struct ImplTemplate<'a> {
+            type_name: &'a str,
+            ctype: &'a str,
+            variants: &'a [VariantInfo<'a>],
+            methods: &'a [MethodInfo<'a>],
+        }
+
+        ImplTemplate {
+            type_name: &type_name,
+            ctype: &ctype,
+            variants: variants.as_slice(),
+            methods: methods.as_slice(),
+        }
+        .render_into(self.impl_header)
+        .unwrap();
+
+        self.decl_header
+            .includes
+            .insert(self.cx.formatter.fmt_c_decl_header_path(id));
+    }
+
     pub fn gen_opaque_def(&mut self, ty: &'tcx hir::OpaqueDef, id: TypeId, api_info: Option<&ApiInfo>) {
         let type_name = self.cx.formatter.fmt_type_name(id);
         let type_name_unnamespaced = self.cx.formatter.fmt_type_name_unnamespaced(id);
@@ -207,6 +444,12 @@ impl<'ccx, 'tcx: 'ccx, 'header> TyGenContext<'ccx, 'tcx, 'header> {
             .flat_map(|method| self.gen_method_info(id, method, api_info))
             .collect::<Vec<_>>();
 
+        let consts = ty
+            .consts
+            .iter()
+            .map(|def| self.gen_const_info(def))
+            .collect::<Vec<_>>();
+
         let lifted = methods.iter()
             .flat_map(|m| {
                 m.method.params.iter().filter_map(move |p| match &p.ty {
@@ -249,6 +492,7 @@ impl<'ccx, 'tcx: 'ccx, 'header> TyGenContext<'ccx, 'tcx, 'header> {
             type_name: &'a str,
             ctype: &'a str,
             methods: &'a [MethodInfo<'a>],
+            consts: &'a [ConstInfo<'a>],
             namespace: Option<&'a str>,
             type_name_unnamespaced: &'a str,
         }
@@ -259,17 +503,13 @@ impl<'ccx, 'tcx: 'ccx, 'header> TyGenContext<'ccx, 'tcx, 'header> {
             type_name: &type_name,
             ctype: &ctype,
             methods: methods.as_slice(),
+            consts: consts.as_slice(),
             namespace: ty.attrs.namespace.as_deref(),
             type_name_unnamespaced: &type_name_unnamespaced,
         }
         .render_into(self.decl_header)
         .unwrap();
 
-        if let Some(info) = api_info {
-            info.additional_includes.iter().for_each(|inc| {
-                writeln!(self.impl_header, "#include \"{}\"", inc).unwrap();
-            });
-        }
         writeln!(self.impl_header, "").unwrap();
 
         #[derive(Template)]
@@ -330,6 +570,12 @@ impl<'ccx, 'tcx: 'ccx, 'header> TyGenContext<'ccx, 'tcx, 'header> {
             .flat_map(|method| self.gen_method_info(id, method, api_info))
             .collect::<Vec<_>>();
 
+        let consts = def
+            .consts
+            .iter()
+            .map(|def| self.gen_const_info(def))
+            .collect::<Vec<_>>();
+
         #[derive(Template)]
         #[template(path = "cpp2/struct_decl.h.jinja", escape = "none")]
         struct DeclTemplate<'a> {
@@ -339,6 +585,7 @@ impl<'ccx, 'tcx: 'ccx, 'header> TyGenContext<'ccx, 'tcx, 'header> {
             ctype: &'a str,
             fields: &'a [NamedType<'a>],
             methods: &'a [MethodInfo<'a>],
+            consts: &'a [ConstInfo<'a>],
             namespace: Option<&'a str>,
             type_name_unnamespaced: &'a str,
         }
@@ -350,6 +597,7 @@ impl<'ccx, 'tcx: 'ccx, 'header> TyGenContext<'ccx, 'tcx, 'header> {
             ctype: &ctype,
             fields: field_decls.as_slice(),
             methods: methods.as_slice(),
+            consts: consts.as_slice(),
             namespace: def.attrs.namespace.as_deref(),
             type_name_unnamespaced: &type_name_unnamespaced,
         }
@@ -381,6 +629,180 @@ impl<'ccx, 'tcx: 'ccx, 'header> TyGenContext<'ccx, 'tcx, 'header> {
         .unwrap();
     }
 
+    /// Adds a trait definition to the current decl and impl headers.
+    ///
+    /// The trait is an abstract class for users to implement. Converting it to C produces
+    /// a vtable whose function pointers are static thunks calling the virtual methods.
+    pub fn gen_trait_def(&mut self, def: &'tcx hir::TraitDef, id: TraitId) {
+        let type_name = self.cx.formatter.fmt_trait_name(id);
+        let type_name_unnamespaced = self.cx.formatter.fmt_trait_name_unnamespaced(id);
+        let vtable = self.cx.formatter.fmt_c_trait_vtable_name(id);
+
+        let methods = def
+            .methods
+            .iter()
+            .flat_map(|method| self.gen_trait_method_info(&type_name, method))
+            .collect::<Vec<_>>();
+
+        #[derive(Template)]
+        #[template(path = "cpp2/trait_decl.h.jinja", escape = "none")]
+        struct DeclTemplate<'a> {
+            vtable: &'a str,
+            methods: &'a [TraitMethodInfo<'a>],
+            namespace: Option<&'a str>,
+            type_name_unnamespaced: &'a str,
+        }
+
+        DeclTemplate {
+            vtable: &vtable,
+            methods: methods.as_slice(),
+            namespace: def.attrs.namespace.as_deref(),
+            type_name_unnamespaced: &type_name_unnamespaced,
+        }
+        .render_into(self.decl_header)
+        .unwrap();
+
+        #[derive(Template)]
+        #[template(path = "cpp2/trait_impl.h.jinja", escape = "none")]
+        struct ImplTemplate<'a> {
+            type_name: &'a str,
+            vtable: &'a str,
+            methods: &'a [TraitMethodInfo<'a>],
+        }
+
+        ImplTemplate {
+            type_name: &type_name,
+            vtable: &vtable,
+            methods: methods.as_slice(),
+        }
+        .render_into(self.impl_header)
+        .unwrap();
+    }
+
+    fn gen_trait_method_info(
+        &mut self,
+        type_name: &str,
+        method: &'tcx hir::TraitMethod,
+    ) -> Option<TraitMethodInfo<'ccx>> {
+        if method.attrs.disable {
+            return None;
+        }
+        let _guard = self
+            .cx
+            .errors
+            .set_context_method(type_name.to_owned().into(), method.name.as_str().into());
+        let method_name = self.cx.formatter.fmt_trait_method_name(method);
+        let mut param_decls = Vec::new();
+        let mut c_param_decls = Vec::new();
+        let mut c_to_cpp_params = Vec::new();
+
+        for param in method.params.iter() {
+            param_decls.push(self.gen_ty_decl(&param.ty, param.name.as_str()));
+            let var_name = self.cx.formatter.fmt_param_name(param.name.as_str());
+            c_param_decls.extend(self.gen_c_ty_decl(&param.ty, &var_name));
+            c_to_cpp_params.push(self.gen_c_to_cpp_expr_for_type(&param.ty, var_name));
+        }
+
+        let (return_ty, c_return_ty, cpp_to_c_return_expression) = match method.output {
+            Some(ref o) => {
+                let return_ty = self.gen_type_name(o, None);
+                let c_return_ty = self
+                    .gen_c_ty_decl(o, "")
+                    .into_iter()
+                    .next()
+                    .map(|decl| decl.type_name)
+                    .unwrap_or("void".into());
+                let expression = match o {
+                    Type::Opaque(op) if op.owner.is_owned() && op.is_optional() => {
+                        "result ? result.release()->AsFFI() : nullptr".into()
+                    }
+                    Type::Opaque(op) if op.owner.is_owned() => {
+                        "result.release()->AsFFI()".into()
+                    }
+                    _ => self
+                        .gen_cpp_to_c_expr_for_type(o, &method_name, "result".into())
+                        .into_iter()
+                        .map(|PartiallyNamedExpression { expression, .. }| expression)
+                        .next()
+                        .unwrap_or("result".into()),
+                };
+                (return_ty, c_return_ty, Some(expression))
+            }
+            None => ("void".into(), "void".into(), None),
+        };
+
+        Some(TraitMethodInfo {
+            method_name,
+            return_ty,
+            c_return_ty,
+            param_decls,
+            c_param_decls,
+            c_to_cpp_params,
+            cpp_to_c_return_expression,
+        })
+    }
+
+    /// Generates the C types of a trait method parameter, as received by its thunk.
+    ///
+    /// Slices are split into `_data` and `_len` parameters.
+    fn gen_c_ty_decl<'a>(&self, ty: &Type, var_name: &str) -> Vec<NamedType<'a>> {
+        let single = |type_name: Cow<'a, str>| {
+            vec![NamedType {
+                var_name: var_name.to_owned().into(),
+                type_name,
+            }]
+        };
+        match *ty {
+            Type::Primitive(prim) => single(self.cx.formatter.fmt_primitive_as_c(prim)),
+            Type::Opaque(ref op) => {
+                let ctype = self.cx.formatter.fmt_c_type_name(op.tcx_id.into());
+                let mutability = op.owner.mutability().unwrap_or(hir::Mutability::Mutable);
+                single(self.cx.formatter.fmt_c_ptr(&ctype, mutability).into_owned().into())
+            }
+            Type::Struct(ref st) => single(self.cx.formatter.fmt_c_type_name(st.id())),
+            Type::Enum(ref e) => single(self.cx.formatter.fmt_c_type_name(e.tcx_id.into())),
+            Type::Slice(ref slice) => {
+                let data_ty: Cow<str> = match *slice {
+                    hir::Slice::Str(_, hir::StringEncoding::UnvalidatedUtf16) => {
+                        "const char16_t*".into()
+                    }
+                    hir::Slice::Str(..) => "const char*".into(),
+                    hir::Slice::Primitive(b, p) => {
+                        let prim = self.cx.formatter.fmt_primitive_as_c(p);
+                        let mutability =
+                            b.map(|b| b.mutability).unwrap_or(hir::Mutability::Mutable);
+                        self.cx.formatter.fmt_c_ptr(&prim, mutability).into_owned().into()
+                    }
+                    _ => {
+                        self.cx.errors.push_error(format!(
+                            "Unsupported slice {var_name} in trait method, only strings and primitive slices can be passed to foreign code"
+                        ));
+                        "void*".into()
+                    }
+                };
+                vec![
+                    NamedType {
+                        var_name: format!("{var_name}_data").into(),
+                        type_name: data_ty,
+                    },
+                    NamedType {
+                        var_name: format!("{var_name}_len").into(),
+                        type_name: "size_t".into(),
+                    },
+                ]
+            }
+            _ => unreachable!("unknown AST/HIR variant"),
+        }
+    }
+
+    fn gen_const_info(&self, def: &'tcx hir::ConstDef) -> ConstInfo<'tcx> {
+        ConstInfo {
+            type_name: self.cx.formatter.fmt_const_type(def),
+            name: self.cx.formatter.fmt_const_name(def),
+            value: self.cx.formatter.fmt_const_value(def),
+        }
+    }
+
     fn gen_method_info(
         &mut self,
         id: TypeId,
@@ -425,6 +847,15 @@ impl<'ccx, 'tcx: 'ccx, 'header> TyGenContext<'ccx, 'tcx, 'header> {
         let c_to_cpp_return_expression: Option<Cow<str>> =
             self.gen_c_to_cpp_for_return_type(&method.output, "result".into());
 
+        // Methods returning a `Result` get the panic as their error
+        let panic_fns = if !method.attrs.catch_panics || matches!(method.output, ReturnType::Fallible(..)) {
+            None
+        } else if let Some(info) = api_info {
+            Some(format!("{}().core->", info.get_api_fn).into())
+        } else {
+            Some("capi::".into())
+        };
+
         let pre_qualifiers = if method.param_self.is_none() {
             vec!["static".into()]
         } else {
@@ -448,6 +879,7 @@ impl<'ccx, 'tcx: 'ccx, 'header> TyGenContext<'ccx, 'tcx, 'header> {
             param_decls,
             cpp_to_c_params,
             c_to_cpp_return_expression,
+            panic_fns,
         })
     }
 
@@ -572,6 +1004,29 @@ impl<'ccx, 'tcx: 'ccx, 'header> TyGenContext<'ccx, 'tcx, 'header> {
                     .collect::<Vec<_>>();
                 self.cx.formatter.fmt_function_as_c(param_name, &output, &inputs)
             },
+            Type::Trait(ref t) => {
+                let type_name = self.cx.formatter.fmt_trait_name(t.tcx_id);
+                let type_name_unnamespaced = self.cx.formatter.fmt_trait_name_unnamespaced(t.tcx_id);
+                let def = t.resolve(self.cx.tcx);
+                if def.attrs.disable {
+                    self.cx
+                        .errors
+                        .push_error(format!("Found usage of disabled trait {type_name}"))
+                }
+                let ret = if t.is_owned {
+                    self.cx.formatter.fmt_owned(&type_name)
+                } else {
+                    self.cx.formatter.fmt_borrowed(&type_name, hir::Mutability::Immutable)
+                };
+                let ret = ret.into_owned().into();
+
+                self.decl_header
+                    .append_trait_forward(def, &type_name_unnamespaced);
+                self.impl_header
+                    .includes
+                    .insert(self.cx.formatter.fmt_trait_impl_header_path(t.tcx_id));
+                ret
+            }
             _ => unreachable!("unknown AST/HIR variant"),
         }
     }
@@ -674,6 +1129,19 @@ impl<'ccx, 'tcx: 'ccx, 'header> TyGenContext<'ccx, 'tcx, 'header> {
                     }
                 ]
             }
+            Type::Trait(ref t) if t.is_owned => {
+                let type_name = self.cx.formatter.fmt_trait_name(t.tcx_id);
+                vec![PartiallyNamedExpression {
+                    suffix: "".into(),
+                    expression: format!("{type_name}::AsFFI(std::move({cpp_name}))").into(),
+                }]
+            }
+            Type::Trait(..) => {
+                vec![PartiallyNamedExpression {
+                    suffix: "".into(),
+                    expression: format!("{cpp_name}.AsFFI()").into(),
+                }]
+            }
             _ => unreachable!("unknown AST/HIR variant"),
         }
     }
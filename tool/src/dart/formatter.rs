@@ -2,8 +2,8 @@
 
 use crate::c2::CFormatter;
 use diplomat_core::ast::{DocsUrlGenerator, MarkdownStyle};
-use diplomat_core::hir::{self, TypeContext, TypeId};
-use heck::ToLowerCamelCase;
+use diplomat_core::hir::{self, TraitId, TypeContext, TypeId};
+use heck::{ToLowerCamelCase, ToUpperCamelCase};
 use std::borrow::Cow;
 
 /// This type mediates all formatting
@@ -104,6 +104,28 @@ impl<'tcx> DartFormatter<'tcx> {
         resolved.attrs().rename.apply(candidate)
     }
 
+    /// Resolve and format a trait for use in code
+    pub fn fmt_trait_name(&self, id: TraitId) -> Cow<'tcx, str> {
+        let resolved = self.c.tcx().resolve_trait(id);
+
+        let candidate: Cow<str> = if let Some(strip_prefix) = self.strip_prefix.as_ref() {
+            resolved
+                .name
+                .as_str()
+                .strip_prefix(strip_prefix)
+                .unwrap_or(resolved.name.as_str())
+                .into()
+        } else {
+            resolved.name.as_str().into()
+        };
+
+        if DISALLOWED_CORE_TYPES.contains(&&*candidate) {
+            panic!("{candidate:?} is not a valid Dart type name. Please rename.");
+        }
+
+        resolved.attrs.rename.apply(candidate)
+    }
+
     /// Resolve and format a named type for use in diagnostics
     /// (don't apply rename rules and such)
     pub fn fmt_type_name_diagnostics(&self, id: TypeId) -> Cow<'tcx, str> {
@@ -116,6 +138,16 @@ impl<'tcx> DartFormatter<'tcx> {
         variant.attrs.rename.apply(name)
     }
 
+    /// Format the name of the subclass representing a variant of a data-carrying enum.
+    pub fn fmt_enum_variant_class_name(
+        &self,
+        type_name: &str,
+        variant: &'tcx hir::EnumVariant,
+    ) -> String {
+        let name = variant.attrs.rename.apply(variant.name.as_str().into());
+        format!("{type_name}{}", name.to_upper_camel_case())
+    }
+
     /// Format a field name or parameter name
     // might need splitting in the future if we decide to support renames here
     pub fn fmt_param_name<'a>(&self, ident: &'a str) -> Cow<'a, str> {
@@ -141,6 +173,20 @@ impl<'tcx> DartFormatter<'tcx> {
         }
     }
 
+    /// Format a trait method
+    pub fn fmt_trait_method_name(&self, method: &hir::TraitMethod) -> String {
+        let name = method
+            .attrs
+            .rename
+            .apply(method.name.as_str().into())
+            .to_lower_camel_case();
+        if INVALID_METHOD_NAMES.contains(&&*name) {
+            format!("{name}_")
+        } else {
+            name
+        }
+    }
+
     pub fn fmt_constructor_name(&self, name: &Option<String>, method: &hir::Method) -> String {
         let name = method
             .attrs
@@ -169,6 +215,61 @@ impl<'tcx> DartFormatter<'tcx> {
         }
     }
 
+    /// Format the name of a const, which Dart spells in lowerCamelCase
+    pub fn fmt_const_name(&self, def: &hir::ConstDef) -> String {
+        let name = def
+            .attrs
+            .rename
+            .apply(def.name.as_str().into())
+            .to_lower_camel_case();
+        if INVALID_FIELD_NAMES.contains(&name.as_str()) {
+            format!("{name}_")
+        } else {
+            name
+        }
+    }
+
+    pub fn fmt_const_type(&self, def: &hir::ConstDef) -> &'static str {
+        match def.ty {
+            hir::ConstType::Primitive(prim) => self.fmt_primitive_as_ffi(prim, true),
+            hir::ConstType::Str => self.fmt_string(),
+            _ => unreachable!("unknown AST/HIR variant"),
+        }
+    }
+
+    /// Format the value of a const as a Dart literal
+    pub fn fmt_const_value(&self, def: &hir::ConstDef) -> String {
+        match def.value {
+            hir::ConstValue::Bool(b) => b.to_string(),
+            // Dart ints are 64-bit signed, but hex literals may use all 64 bits
+            hir::ConstValue::Int(ref i) => match i.parse::<u64>() {
+                Ok(u) if u > i64::MAX as u64 => format!("0x{u:X}"),
+                _ => i.clone(),
+            },
+            hir::ConstValue::Float(ref f) => f.clone(),
+            hir::ConstValue::Str(ref s) => {
+                let mut out = String::with_capacity(s.len() + 2);
+                out.push('\'');
+                for c in s.chars() {
+                    match c {
+                        '\'' | '\\' | '$' => {
+                            out.push('\\');
+                            out.push(c);
+                        }
+                        '\n' => out.push_str("\\n"),
+                        '\r' => out.push_str("\\r"),
+                        '\t' => out.push_str("\\t"),
+                        c if c.is_control() => out.push_str(&format!("\\u{{{:x}}}", c as u32)),
+                        c => out.push(c),
+                    }
+                }
+                out.push('\'');
+                out
+            }
+            _ => unreachable!("unknown AST/HIR variant"),
+        }
+    }
+
     pub fn fmt_c_method_name<'a>(&self, ty: TypeId, method: &'a hir::Method) -> Cow<'a, str> {
         self.c.fmt_method_name(ty, method, true).into()
     }
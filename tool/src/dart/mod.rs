@@ -7,13 +7,17 @@ use diplomat_core::hir::borrowing_param::{
 use diplomat_core::hir::TypeContext;
 use diplomat_core::hir::{
     self, Lifetime, LifetimeEnv, MaybeStatic, OpaqueOwner, ReturnType, SelfType, SpecialMethod,
-    SpecialMethodPresence, StructPathLike, SuccessType, TyPosition, Type, TypeDef, TypeId,
+    SpecialMethodPresence, StructPathLike, SuccessType, TraitId, TyPosition, Type, TypeDef, TypeId,
 };
 use formatter::DartFormatter;
 use std::borrow::Cow;
 use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::{Display, Write};
 
+#[cfg(test)]
+#[macro_use]
+mod test_util;
+
 mod formatter;
 
 /// Run file generation
@@ -63,6 +67,40 @@ pub fn run<'cx>(
         );
     }
 
+    for (id, trt) in tcx.all_traits() {
+        if trt.attrs.disable {
+            continue;
+        }
+
+        let (file_name, body) = tgcx.gen_trait(id, trt);
+
+        directives.insert(formatter.fmt_part(&file_name));
+
+        files.add_file(
+            file_name,
+            render_class(
+                body,
+                BTreeSet::from_iter([formatter.fmt_part_of_lib()]),
+                Default::default(),
+            ),
+        );
+    }
+
+    if !tcx.consts().is_empty() {
+        let (file_name, body) = tgcx.gen_module_consts(tcx.consts());
+
+        directives.insert(formatter.fmt_part(&file_name));
+
+        files.add_file(
+            file_name,
+            render_class(
+                body,
+                BTreeSet::from_iter([formatter.fmt_part_of_lib()]),
+                Default::default(),
+            ),
+        );
+    }
+
     directives.insert(formatter.fmt_import(
         "dart:core",
         Some("show int, double, bool, String, Object, override"),
@@ -141,7 +179,191 @@ impl<'a, 'cx> TyGenContext<'a, 'cx> {
         )
     }
 
+    fn gen_trait(&mut self, id: TraitId, def: &'cx hir::TraitDef) -> (String, String) {
+        let _guard = self.errors.set_context_ty(def.name.as_str().into());
+
+        let type_name = self.formatter.fmt_trait_name(id);
+
+        let methods = def
+            .methods
+            .iter()
+            .flat_map(|method| self.gen_trait_method_info(&type_name, method))
+            .collect::<Vec<_>>();
+
+        #[derive(Template)]
+        #[template(path = "dart/trait.dart.jinja", escape = "none")]
+        struct ImplTemplate<'a> {
+            type_name: &'a str,
+            methods: &'a [TraitMethodInfo<'a>],
+            docs: String,
+        }
+
+        (
+            self.formatter.fmt_file_name(&type_name),
+            ImplTemplate {
+                type_name: &type_name,
+                methods: methods.as_slice(),
+                docs: self.formatter.fmt_docs(&def.docs),
+            }
+            .render()
+            .unwrap(),
+        )
+    }
+
+    /// Foreign implementations of a trait are called through static thunks, which
+    /// receive C types and have to convert them for the Dart implementation.
+    fn gen_trait_method_info(
+        &mut self,
+        type_name: &str,
+        method: &'cx hir::TraitMethod,
+    ) -> Option<TraitMethodInfo<'cx>> {
+        if method.attrs.disable {
+            return None;
+        }
+
+        let _guard = self
+            .errors
+            .set_context_method(type_name.to_owned().into(), method.name.as_str().into());
+
+        let mut param_decls_dart = Vec::new();
+        let mut param_types_ffi = vec![self.formatter.fmt_pointer(self.formatter.fmt_ffi_void())];
+        let mut param_decls_ffi_cast = Vec::new();
+        let mut param_conversions = Vec::new();
+
+        for param in method.params.iter() {
+            let param_name = self.formatter.fmt_param_name(param.name.as_str());
+            param_decls_dart.push(format!("{} {param_name}", self.gen_type_name(&param.ty)));
+
+            match param.ty {
+                Type::Primitive(..) | Type::Enum(..) if !is_data_carrying(self.tcx, &param.ty) => {
+                    param_types_ffi.push(self.gen_type_name_ffi(&param.ty, false).into_owned());
+                    param_decls_ffi_cast.push(format!(
+                        "{} {param_name}",
+                        self.gen_type_name_ffi(&param.ty, true)
+                    ));
+                    param_conversions.push(self.gen_c_to_dart_for_type(
+                        &param.ty,
+                        param_name.clone(),
+                        &method.lifetime_env,
+                    ));
+                }
+                Type::Slice(hir::Slice::Str(_, encoding)) => {
+                    let data_ty = self.gen_type_name_ffi(&param.ty, false);
+                    param_types_ffi.push(self.formatter.fmt_pointer(&data_ty));
+                    param_types_ffi.push(self.formatter.fmt_usize(false).into());
+                    param_decls_ffi_cast.push(format!(
+                        "{} {param_name}Data",
+                        self.formatter.fmt_pointer(&data_ty)
+                    ));
+                    param_decls_ffi_cast.push(format!(
+                        "{} {param_name}Length",
+                        self.formatter.fmt_usize(true)
+                    ));
+                    param_conversions.push(match encoding {
+                        hir::StringEncoding::UnvalidatedUtf16 => format!(
+                            "core.String.fromCharCodes({param_name}Data.asTypedList({param_name}Length))"
+                        ),
+                        _ => format!(
+                            "Utf8Decoder().convert({param_name}Data.asTypedList({param_name}Length))"
+                        ),
+                    }.into());
+                }
+                _ => {
+                    self.errors.push_error(format!(
+                        "Unsupported parameter {param_name} in trait method, Dart implementations can only receive primitives, enums and strings"
+                    ));
+                    return None;
+                }
+            }
+        }
+
+        let (return_ty, return_ty_ffi, return_ty_ffi_cast, return_expression, exceptional_return) =
+            match method.output {
+                None => (
+                    self.formatter.fmt_void().into(),
+                    self.formatter.fmt_ffi_void().into(),
+                    self.formatter.fmt_void().into(),
+                    None,
+                    None,
+                ),
+                Some(ref o @ (Type::Primitive(..) | Type::Enum(..)))
+                    if !is_data_carrying(self.tcx, o) =>
+                {
+                    let exceptional_return = match o {
+                        Type::Primitive(hir::PrimitiveType::Bool) => "false",
+                        Type::Primitive(hir::PrimitiveType::Float(..)) => "0.0",
+                        _ => "0",
+                    };
+                    (
+                        self.gen_type_name(o),
+                        self.gen_type_name_ffi(o, false),
+                        self.gen_type_name_ffi(o, true),
+                        Some(self.gen_dart_to_c_for_type(o, "result".into(), None)),
+                        Some(exceptional_return),
+                    )
+                }
+                Some(ref o) => {
+                    self.errors.push_error(format!(
+                        "Unsupported return type {} in trait method, Dart implementations can only return primitives and enums",
+                        self.gen_type_name(o)
+                    ));
+                    return None;
+                }
+            };
+
+        Some(TraitMethodInfo {
+            name: self.formatter.fmt_trait_method_name(method),
+            c_name: method.name.as_str(),
+            docs: self.formatter.fmt_docs(&method.docs),
+            params: param_decls_dart.join(", "),
+            return_ty,
+            native_signature: format!(
+                "{return_ty_ffi} Function({})",
+                param_types_ffi.join(", ")
+            ),
+            return_ty_ffi_cast,
+            param_decls_ffi_cast,
+            param_conversions,
+            return_expression,
+            exceptional_return,
+        })
+    }
+
+    fn gen_const_info(&self, def: &hir::ConstDef) -> ConstInfo {
+        ConstInfo {
+            name: self.formatter.fmt_const_name(def),
+            type_name: self.formatter.fmt_const_type(def),
+            value: self.formatter.fmt_const_value(def),
+            docs: self.formatter.fmt_docs(&def.docs),
+        }
+    }
+
+    fn gen_consts(&self, consts: &[hir::ConstDef]) -> Vec<ConstInfo> {
+        consts.iter().map(|c| self.gen_const_info(c)).collect()
+    }
+
+    /// Consts declared at the top level of the bridge module become top-level Dart consts.
+    fn gen_module_consts(&self, consts: &[hir::ConstDef]) -> (String, String) {
+        #[derive(Template)]
+        #[template(path = "dart/consts.dart.jinja", escape = "none")]
+        struct ConstsTemplate {
+            consts: Vec<ConstInfo>,
+        }
+
+        (
+            self.formatter.fmt_file_name("diplomat_consts"),
+            ConstsTemplate {
+                consts: self.gen_consts(consts),
+            }
+            .render()
+            .unwrap(),
+        )
+    }
+
     fn gen_enum(&mut self, ty: &'cx hir::EnumDef, id: TypeId, type_name: &str) -> String {
+        if ty.is_data_carrying() {
+            return self.gen_tagged_union(ty, id, type_name);
+        }
         let methods = ty
             .methods
             .iter()
@@ -157,6 +379,7 @@ impl<'a, 'cx> TyGenContext<'a, 'cx> {
             fmt: &'a DartFormatter<'a>,
             type_name: &'a str,
             methods: &'a [MethodInfo<'a>],
+            consts: Vec<ConstInfo>,
             docs: String,
             is_contiguous: bool,
             special: SpecialMethodGenInfo<'a>,
@@ -167,6 +390,7 @@ impl<'a, 'cx> TyGenContext<'a, 'cx> {
             fmt: self.formatter,
             type_name,
             methods: methods.as_slice(),
+            consts: self.gen_consts(&ty.consts),
             docs: self.formatter.fmt_docs(&ty.docs),
             is_contiguous: is_contiguous_enum(ty),
             special,
@@ -175,6 +399,98 @@ impl<'a, 'cx> TyGenContext<'a, 'cx> {
         .unwrap()
     }
 
+    /// Data-carrying enums become a sealed class with one subclass per variant,
+    /// backed by a struct holding the tag and a union of the variant payloads.
+    fn gen_tagged_union(&mut self, ty: &'cx hir::EnumDef, id: TypeId, type_name: &str) -> String {
+        // Enums have no lifetimes, payloads can only borrow 'static data
+        let lifetimes = LifetimeEnv::default();
+
+        let variants = ty
+            .variants
+            .iter()
+            .map(|variant| {
+                let payload_name = self.formatter.fmt_param_name(variant.name.as_str());
+                let fields = variant
+                    .fields
+                    .iter()
+                    .map(|field| {
+                        let name = self.formatter.fmt_param_name(field.name.as_str());
+                        if matches!(field.ty, Type::Slice(..)) || field.ty.lifetimes().next().is_some() {
+                            self.errors.push_error(format!(
+                                "Borrowed data in enum payloads is not supported in Dart: {}::{}",
+                                variant.name, field.name
+                            ));
+                        }
+                        let annotation = match field.ty {
+                            hir::Type::Primitive(p) => Some(self.formatter.fmt_primitive_as_ffi(p, false)),
+                            hir::Type::Enum(ref e) if !e.resolve(self.tcx).is_data_carrying() => {
+                                Some(self.formatter.fmt_enum_as_ffi(false))
+                            }
+                            _ => None,
+                        };
+                        let dart_to_c = self.gen_dart_to_c_for_type(&field.ty, name.clone(), None);
+                        FieldInfo {
+                            ty: &field.ty,
+                            annotation,
+                            ffi_cast_type_name: self.gen_type_name_ffi(&field.ty, true),
+                            dart_type_name: self.gen_type_name(&field.ty),
+                            c_to_dart: self.gen_c_to_dart_for_type(
+                                &field.ty,
+                                format!("ffi.payload.{payload_name}.{name}").into(),
+                                &lifetimes,
+                            ),
+                            dart_to_c: vec![format!(
+                                "struct.payload.{payload_name}.{name} = {dart_to_c};"
+                            )],
+                            maybe_struct_borrow_info: None,
+                            name,
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                VariantInfo {
+                    class_name: self.formatter.fmt_enum_variant_class_name(type_name, variant),
+                    payload_class_name: format!("_{type_name}{}Ffi", variant.name),
+                    docs: self.formatter.fmt_docs(&variant.docs),
+                    discriminant: variant.discriminant,
+                    payload_name,
+                    fields,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let methods = ty
+            .methods
+            .iter()
+            .flat_map(|method| self.gen_method_info(id, method, type_name))
+            .collect::<Vec<_>>();
+
+        let special = self.gen_special_method_info(&ty.special_method_presence);
+
+        #[derive(Template)]
+        #[template(path = "dart/tagged_union.dart.jinja", escape = "none")]
+        struct ImplTemplate<'a> {
+            type_name: &'a str,
+            variants: &'a [VariantInfo<'a>],
+            methods: &'a [MethodInfo<'a>],
+            consts: Vec<ConstInfo>,
+            docs: String,
+            tag_annotation: &'static str,
+            special: SpecialMethodGenInfo<'a>,
+        }
+
+        ImplTemplate {
+            type_name,
+            variants: variants.as_slice(),
+            methods: methods.as_slice(),
+            consts: self.gen_consts(&ty.consts),
+            docs: self.formatter.fmt_docs(&ty.docs),
+            tag_annotation: self.formatter.fmt_enum_as_ffi(false),
+            special,
+        }
+        .render()
+        .unwrap()
+    }
+
     fn gen_opaque_def(&mut self, ty: &'cx hir::OpaqueDef, id: TypeId, type_name: &str) -> String {
         let methods = ty
             .methods
@@ -190,6 +506,7 @@ impl<'a, 'cx> TyGenContext<'a, 'cx> {
         struct ImplTemplate<'a> {
             type_name: &'a str,
             methods: &'a [MethodInfo<'a>],
+            consts: Vec<ConstInfo>,
             docs: String,
             destructor: String,
             lifetimes: &'a LifetimeEnv,
@@ -199,6 +516,7 @@ impl<'a, 'cx> TyGenContext<'a, 'cx> {
         ImplTemplate {
             type_name,
             methods: methods.as_slice(),
+            consts: self.gen_consts(&ty.consts),
             destructor,
             docs: self.formatter.fmt_docs(&ty.docs),
             lifetimes: &ty.lifetimes,
@@ -224,7 +542,9 @@ impl<'a, 'cx> TyGenContext<'a, 'cx> {
 
                 let annotation = match field.ty {
                     hir::Type::Primitive(p) => Some(self.formatter.fmt_primitive_as_ffi(p, false)),
-                    hir::Type::Enum(_) => Some(self.formatter.fmt_enum_as_ffi(false)),
+                    hir::Type::Enum(ref e) if !e.resolve(self.tcx).is_data_carrying() => {
+                        Some(self.formatter.fmt_enum_as_ffi(false))
+                    }
                     _ => None,
                 };
 
@@ -352,6 +672,7 @@ impl<'a, 'cx> TyGenContext<'a, 'cx> {
             mutable: bool,
             fields: Vec<FieldInfo<'a, P>>,
             methods: Vec<MethodInfo<'a>>,
+            consts: Vec<ConstInfo>,
             docs: String,
             lifetimes: &'a LifetimeEnv,
             special: SpecialMethodGenInfo<'a>,
@@ -363,6 +684,7 @@ impl<'a, 'cx> TyGenContext<'a, 'cx> {
             mutable,
             fields,
             methods,
+            consts: self.gen_consts(&ty.consts),
             docs: self.formatter.fmt_docs(&ty.docs),
             lifetimes: &ty.lifetimes,
             special,
@@ -397,6 +719,8 @@ impl<'a, 'cx> TyGenContext<'a, 'cx> {
         let mut param_conversions = Vec::new();
 
         let mut needs_temp_arena = false;
+        // Native calls that may call back into Dart can't be leaf calls
+        let mut is_leaf = true;
 
         if let Some(param_self) = method.param_self.as_ref() {
             visitor.visit_param(&param_self.ty.clone().into(), "this");
@@ -405,8 +729,12 @@ impl<'a, 'cx> TyGenContext<'a, 'cx> {
             param_types_ffi_cast.push(self.gen_self_type_name_ffi(&param_self.ty, true));
             param_conversions.push(self.gen_dart_to_c_self(&param_self.ty));
             param_names_ffi.push("self".into());
-            if matches!(param_self.ty, hir::SelfType::Struct(..)) {
-                needs_temp_arena = true;
+            match param_self.ty {
+                hir::SelfType::Struct(..) => needs_temp_arena = true,
+                hir::SelfType::Enum(ref e) if e.resolve(self.tcx).is_data_carrying() => {
+                    needs_temp_arena = true
+                }
+                _ => (),
             }
         }
 
@@ -461,8 +789,16 @@ impl<'a, 'cx> TyGenContext<'a, 'cx> {
                     is_borrowed,
                 });
             } else {
-                if let hir::Type::Struct(..) = param.ty {
-                    needs_temp_arena = true;
+                match param.ty {
+                    hir::Type::Struct(..) => needs_temp_arena = true,
+                    hir::Type::Enum(ref e) if e.resolve(self.tcx).is_data_carrying() => {
+                        needs_temp_arena = true
+                    }
+                    hir::Type::Trait(..) => {
+                        needs_temp_arena = true;
+                        is_leaf = false;
+                    }
+                    _ => (),
                 }
                 let struct_borrow_info =
                     if let ParamBorrowInfo::Struct(param_info) = param_borrow_kind {
@@ -511,6 +847,20 @@ impl<'a, 'cx> TyGenContext<'a, 'cx> {
         let return_expression =
             self.gen_c_to_dart_for_return_type(&method.output, &method.lifetime_env);
 
+        // Methods returning a `Result` get the panic as their error
+        let checks_panic = method.attrs.catch_panics
+            && !matches!(method.output, hir::ReturnType::Fallible(..));
+        if checks_panic {
+            self.helper_classes.insert(
+                "writeable".into(),
+                include_str!("../../templates/dart/writeable.dart").into(),
+            );
+            self.helper_classes.insert(
+                "panic".into(),
+                include_str!("../../templates/dart/panic.dart").into(),
+            );
+        }
+
         let params = param_decls_dart.join(", ");
 
         let declaration = match &method.attrs.special_method {
@@ -553,6 +903,9 @@ impl<'a, 'cx> TyGenContext<'a, 'cx> {
             )
             .unwrap();
         }
+        if checks_panic {
+            docs.push_str("\n///\n/// Throws [StateError] if it panics.");
+        }
 
         Some(MethodInfo {
             method,
@@ -566,8 +919,10 @@ impl<'a, 'cx> TyGenContext<'a, 'cx> {
             return_type_ffi_cast,
             slice_params,
             needs_temp_arena,
+            is_leaf,
             param_conversions,
             return_expression,
+            checks_panic,
             lifetimes: &method.lifetime_env,
             method_lifetimes_map: visitor.borrow_map(),
         })
@@ -650,6 +1005,14 @@ impl<'a, 'cx> TyGenContext<'a, 'cx> {
                 self.formatter.fmt_primitive_list_type(p).into()
             }
             Type::Slice(hir::Slice::Strs(..)) => "core.List<core.String>".into(),
+            Type::Trait(ref t) => {
+                let type_name = self.formatter.fmt_trait_name(t.tcx_id);
+                if t.resolve(self.tcx).attrs.disable {
+                    self.errors
+                        .push_error(format!("Found usage of disabled trait {type_name}"))
+                }
+                type_name
+            }
             _ => unreachable!("unknown AST/HIR variant"),
         }
     }
@@ -714,7 +1077,11 @@ impl<'a, 'cx> TyGenContext<'a, 'cx> {
                     self.errors
                         .push_error(format!("Found usage of disabled type {type_name}"))
                 }
-                self.formatter.fmt_enum_as_ffi(cast).into()
+                if e.resolve(self.tcx).is_data_carrying() {
+                    format!("_{type_name}Ffi").into()
+                } else {
+                    self.formatter.fmt_enum_as_ffi(cast).into()
+                }
             }
             Type::Slice(hir::Slice::Str(
                 _,
@@ -731,6 +1098,14 @@ impl<'a, 'cx> TyGenContext<'a, 'cx> {
                 _ => "_SliceUtf16",
             }
             .into(),
+            Type::Trait(ref t) => {
+                let type_name = self.formatter.fmt_trait_name(t.tcx_id);
+                if t.resolve(self.tcx).attrs.disable {
+                    self.errors
+                        .push_error(format!("Found usage of disabled trait {type_name}"))
+                }
+                format!("_{type_name}VTable").into()
+            }
             _ => unreachable!("unknown AST/HIR variant"),
         }
     }
@@ -773,6 +1148,9 @@ impl<'a, 'cx> TyGenContext<'a, 'cx> {
                 .fmt_pointer(self.formatter.fmt_opaque())
                 .into(),
             SelfType::Struct(s) => format!("_{}Ffi", s.resolve(self.tcx).name.as_str()).into(),
+            SelfType::Enum(e) if e.resolve(self.tcx).is_data_carrying() => {
+                format!("_{}Ffi", e.resolve(self.tcx).name.as_str()).into()
+            }
             SelfType::Enum(_) => self.formatter.fmt_enum_as_ffi(cast).into(),
             _ => unreachable!("unknown AST/HIR variant"),
         }
@@ -781,6 +1159,9 @@ impl<'a, 'cx> TyGenContext<'a, 'cx> {
     /// Generates an FFI expression for a self type.
     fn gen_dart_to_c_self(&self, ty: &SelfType) -> Cow<'static, str> {
         match *ty {
+            SelfType::Enum(ref e) if e.resolve(self.tcx).is_data_carrying() => {
+                "_toFfi(temp)".into()
+            }
             SelfType::Enum(ref e) if is_contiguous_enum(e.resolve(self.tcx)) => "index".into(),
             SelfType::Struct(..) => "_toFfi(temp)".into(),
             SelfType::Opaque(..) | SelfType::Enum(..) => "_ffi".into(),
@@ -804,6 +1185,9 @@ impl<'a, 'cx> TyGenContext<'a, 'cx> {
                 "{dart_name}?._ffi ?? ffi.Pointer.fromAddress(0)"
             )
             .into(),
+            Type::Enum(ref e) if e.resolve(self.tcx).is_data_carrying() => {
+                format!("{dart_name}._toFfi(temp)").into()
+            }
             Type::Enum(ref e) if is_contiguous_enum(e.resolve(self.tcx)) => {
                 format!("{dart_name}.index").into()
             }
@@ -823,6 +1207,12 @@ impl<'a, 'cx> TyGenContext<'a, 'cx> {
                 view = self.formatter.fmt_primitive_list_view(p)
             )
             .into(),
+            Type::Trait(ref t) => format!(
+                "_{type_name}VTable._fromDart({dart_name}, temp, owned: {owned})",
+                type_name = self.formatter.fmt_trait_name(t.tcx_id),
+                owned = t.is_owned,
+            )
+            .into(),
             _ => unreachable!("unknown AST/HIR variant"),
         }
     }
@@ -920,6 +1310,11 @@ impl<'a, 'cx> TyGenContext<'a, 'cx> {
 
                 format!("{type_name}._fromFfi({var_name}{edges})").into()
             }
+            Type::Enum(ref e) if e.resolve(self.tcx).is_data_carrying() => {
+                let id = e.tcx_id.into();
+                let type_name = self.formatter.fmt_type_name(id);
+                format!("{type_name}._fromFfi({var_name})").into()
+            }
             Type::Enum(ref e) if is_contiguous_enum(e.resolve(self.tcx)) => {
                 let id = e.tcx_id.into();
                 let type_name = self.formatter.fmt_type_name(id);
@@ -1134,7 +1529,7 @@ impl<'a, 'cx> TyGenContext<'a, 'cx> {
                         hir::OutType::Primitive(p) => {
                             format!("@{}()\n", self.formatter.fmt_primitive_as_ffi(*p, false))
                         }
-                        hir::OutType::Enum(_) =>
+                        hir::OutType::Enum(e) if !e.resolve(self.tcx).is_data_carrying() =>
                             format!("@{}()\n", self.formatter.fmt_enum_as_ffi(false)),
                         _ => String::new(),
                     },
@@ -1164,6 +1559,10 @@ impl<'a, 'cx> TyGenContext<'a, 'cx> {
     }
 }
 
+fn is_data_carrying<P: TyPosition>(tcx: &TypeContext, ty: &Type<P>) -> bool {
+    matches!(ty, Type::Enum(e) if e.resolve(tcx).is_data_carrying())
+}
+
 fn is_contiguous_enum(ty: &hir::EnumDef) -> bool {
     ty.variants
         .iter()
@@ -1195,6 +1594,8 @@ struct MethodInfo<'a> {
     /// The invocation of the Rust method might need temporary allocations,
     /// for which we use a Dart Arena type.
     needs_temp_arena: bool,
+    /// Whether the native call can be a leaf call, i.e. never calls back into Dart.
+    is_leaf: bool,
 
     /// Conversion code for each parameter
     param_conversions: Vec<Cow<'a, str>>,
@@ -1203,6 +1604,9 @@ struct MethodInfo<'a> {
     /// the C function return value is saved to a variable named `result` or that the
     /// writeable, if present, is saved to a variable named `writeable`.
     return_expression: Option<Cow<'a, str>>,
+    /// Whether a panic in the method is reported instead of returned as its error,
+    /// and is thrown as a `StateError` after the call
+    checks_panic: bool,
 
     lifetimes: &'a LifetimeEnv,
     /// Maps each (used in the output) method lifetime to a list of parameters
@@ -1212,6 +1616,33 @@ struct MethodInfo<'a> {
     method_lifetimes_map: BTreeMap<Lifetime, BorrowedLifetimeInfo<'a>>,
 }
 
+/// Everything needed for rendering a trait method and the thunk that calls it from C.
+struct TraitMethodInfo<'a> {
+    /// The Dart method name
+    name: String,
+    /// The name of the vtable field and thunk
+    c_name: &'a str,
+    /// Docs
+    docs: String,
+    /// The Dart parameter declarations
+    params: String,
+    /// The Dart return type
+    return_ty: Cow<'a, str>,
+    /// The `dart:ffi` signature of the vtable's function pointer
+    native_signature: String,
+    /// The Dart return type of the thunk
+    return_ty_ffi_cast: Cow<'a, str>,
+    /// The Dart parameter declarations of the thunk, after the context
+    param_decls_ffi_cast: Vec<String>,
+    /// Conversion code for each parameter of the Dart method
+    param_conversions: Vec<Cow<'a, str>>,
+    /// If the method has a return value, the Dart code converting it back to C. Assumes that
+    /// the Dart method return value is saved to a variable named `result`.
+    return_expression: Option<Cow<'a, str>>,
+    /// What the thunk returns if the Dart implementation throws
+    exceptional_return: Option<&'static str>,
+}
+
 struct SliceParam<'a> {
     /// The name of the parameter
     param_name: Cow<'a, str>,
@@ -1233,6 +1664,27 @@ struct FieldInfo<'a, P: TyPosition> {
     maybe_struct_borrow_info: Option<StructBorrowInfo<'a>>,
 }
 
+/// Everything needed for rendering a const, either as a static member or at the top level.
+struct ConstInfo {
+    name: String,
+    type_name: &'static str,
+    value: String,
+    docs: String,
+}
+
+/// Everything needed for rendering a variant of a data-carrying enum.
+struct VariantInfo<'a> {
+    /// The name of the Dart subclass for this variant
+    class_name: String,
+    /// The name of the FFI struct holding the payload
+    payload_class_name: String,
+    /// The name of the payload's member in the FFI union
+    payload_name: Cow<'a, str>,
+    docs: String,
+    discriminant: isize,
+    fields: Vec<FieldInfo<'a, hir::Everywhere>>,
+}
+
 // Helpers used in templates (Askama has restrictions on Rust syntax)
 
 fn display_lifetime_edge<'a>(edge: &'a LifetimeEdge) -> Cow<'a, str> {
@@ -1303,3 +1755,103 @@ struct SpecialMethodGenInfo<'a> {
     /// Whether it is an iterable, and the type it iterates over
     iterable: Option<Cow<'a, str>>,
 }
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_tagged_union() {
+        test_file! {
+            #[diplomat::bridge]
+            mod ffi {
+                pub struct Point {
+                    pub x: i32,
+                    pub y: i32,
+                }
+
+                pub enum Shape {
+                    Circle { radius: f64 },
+                    Dot { center: Point },
+                    Empty,
+                }
+
+                #[diplomat::opaque]
+                pub struct Canvas;
+
+                impl Canvas {
+                    pub fn draw(&mut self, shape: Shape) {}
+                    pub fn last(&self) -> Shape {
+                        unimplemented!()
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_trait() {
+        test_file! {
+            #[diplomat::bridge]
+            mod ffi {
+                pub trait Logger {
+                    fn log(&self, level: u8) -> i32;
+                    fn flush(&self);
+                }
+
+                #[diplomat::opaque]
+                pub struct Service;
+
+                impl Service {
+                    pub fn run(&self, logger: &dyn Logger) {}
+                    pub fn set_logger(&mut self, logger: Box<dyn Logger>) {}
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_consts() {
+        test_file! {
+            #[diplomat::bridge]
+            mod ffi {
+                /// The largest length
+                pub const MAX_LEN: u32 = 16;
+                pub const NAME: &str = "demo";
+                pub const RATIO: f64 = 0.5;
+
+                #[diplomat::opaque]
+                pub struct Thing;
+
+                impl Thing {
+                    pub const SIZE: i8 = -3;
+                    pub const ENABLED: bool = true;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_catch_panics() {
+        test_file! {
+            #[diplomat::bridge]
+            mod ffi {
+                pub enum MyError {
+                    Panicked,
+                    Other,
+                }
+
+                #[diplomat::opaque]
+                pub struct Thing;
+
+                #[diplomat::catch_panics]
+                impl Thing {
+                    pub fn might_panic(x: u32) -> u32 {
+                        unimplemented!()
+                    }
+                    pub fn might_fail(x: u32) -> Result<u32, MyError> {
+                        unimplemented!()
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,46 @@
+/// A macro to test that the dart files match the output from diplomat. It checks the output
+/// against an [insta] snapshot, leaving out the library file with the runtime.
+///
+/// # Usage
+/// ```
+/// test_file! {
+///     #[diplomat::bridge]
+///     mod ffi {
+///         enum MyEnum {
+///             A, B, C
+///         }
+///     }
+/// }
+/// ```
+macro_rules! test_file {
+    ($($file:tt)*) => {
+        let parsed: syn::File = syn::parse_quote! { $($file)* };
+        let custom_types = diplomat_core::ast::File::from(&parsed);
+        let env = custom_types.all_types();
+
+        let registry = crate::backend::Registry::new();
+        let backend = registry.get("dart").unwrap();
+        let tcx = diplomat_core::hir::TypeContext::from_ast(&env, backend.attr_validator())
+            .unwrap_or_else(|e| panic!("lowering failed: {e:?}"));
+        let config = crate::backend::Config {
+            env: &env,
+            library_config: None,
+            docs_url_gen: &Default::default(),
+            strip_prefix: None,
+            crate_name: None,
+            api_info: None,
+            api_layout: None,
+        };
+
+        let mut out_texts = backend.generate(&tcx, &config).unwrap().take_files();
+
+        out_texts.retain(|k, _| k.ends_with(".dart"));
+        out_texts.remove("lib.g.dart");
+
+        for out in out_texts.keys() {
+            insta::with_settings!({ snapshot_suffix => out.clone() }, {
+                insta::assert_snapshot!(out_texts.get(out).unwrap())
+            });
+        }
+    }
+}
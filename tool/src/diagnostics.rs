@@ -0,0 +1,387 @@
+//! Rendering of lowering errors, either as rustc-style snippets pointing at the
+//! offending source or as one JSON object per line for editors and CI.
+//!
+//! Errors are rendered to strings for the caller to print. The parts of rustc-style
+//! snippets that rustc highlights go through a [`Style`] function, so that the caller
+//! decides whether they are colored.
+
+use diplomat_core::hir::{ErrorAndContext, SourceSpan};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+/// How diagnostics are printed, as selected by `--message-format`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum MessageFormat {
+    /// rustc-style messages with source snippets.
+    Human,
+    /// One JSON object per diagnostic, one per line.
+    Json,
+}
+
+/// The parts of a rustc-style message that can be highlighted.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Style {
+    /// The `error` header and the carets under the offending source.
+    Error,
+    /// The message and the note saying which item the error is in.
+    Emphasis,
+    /// The line number, the arrow and the bars in front of the source.
+    Gutter,
+}
+
+/// Renders lowering errors, resolving their spans against the source files the
+/// bridge crate was parsed from.
+#[derive(Clone, Debug)]
+pub struct Diagnostics {
+    format: MessageFormat,
+    sources: Vec<(PathBuf, String)>,
+}
+
+/// A single message to print, from either AST construction or lowering.
+struct Diagnostic<'a> {
+    /// The stable error code, only known for lowering errors.
+    code: Option<&'a str>,
+    kind: &'a str,
+    message: String,
+    item: Option<&'a str>,
+    subitem: Option<&'a str>,
+    span: Option<SourceSpan>,
+}
+
+impl<'a> Diagnostic<'a> {
+    fn from_lowering((ctx, err): &'a ErrorAndContext) -> Self {
+        Diagnostic {
+            code: Some(err.code()),
+            kind: err.kind.name(),
+            message: err.message.clone(),
+            item: Some(ctx.item()),
+            subitem: ctx.subitem(),
+            span: ctx.span(),
+        }
+    }
+
+    fn from_ast(error: &syn::Error) -> Self {
+        Diagnostic {
+            code: None,
+            kind: "ast",
+            message: error.to_string(),
+            item: None,
+            subitem: None,
+            span: SourceSpan::from_span(error.span()),
+        }
+    }
+}
+
+/// A span resolved to the file it came from.
+struct Location<'a> {
+    path: &'a Path,
+    contents: &'a str,
+    span: SourceSpan,
+}
+
+impl Diagnostics {
+    pub fn new(format: MessageFormat) -> Self {
+        Diagnostics {
+            format,
+            sources: Vec::new(),
+        }
+    }
+
+    /// Register a file that was loaded while parsing the bridge crate.
+    pub fn add_source(&mut self, path: &Path, contents: String) {
+        self.sources.push((path.to_owned(), contents));
+    }
+
+    /// Take over the sources of `other`, like those of another crate.
+    pub fn extend(&mut self, other: Diagnostics) {
+        self.sources.extend(other.sources);
+    }
+
+    /// The files that were loaded while parsing the bridge crate.
+    pub fn source_paths(&self) -> impl Iterator<Item = &Path> {
+        self.sources.iter().map(|(path, _)| path.as_path())
+    }
+
+    /// Render all lowering errors in the selected format, passing the parts of
+    /// rustc-style messages to highlight through `style`.
+    pub fn render(
+        &self,
+        errors: &[ErrorAndContext],
+        style: &dyn Fn(Style, &str) -> String,
+    ) -> String {
+        errors
+            .iter()
+            .map(|error| self.render_one(&Diagnostic::from_lowering(error), style))
+            .collect()
+    }
+
+    /// Render errors found while building the AST in the selected format, passing the
+    /// parts of rustc-style messages to highlight through `style`.
+    pub fn render_ast_errors(
+        &self,
+        errors: &[&syn::Error],
+        style: &dyn Fn(Style, &str) -> String,
+    ) -> String {
+        // A single `syn::Error` may hold several messages
+        errors
+            .iter()
+            .flat_map(|e| e.into_iter())
+            .map(|error| self.render_one(&Diagnostic::from_ast(&error), style))
+            .collect()
+    }
+
+    fn render_one(&self, diagnostic: &Diagnostic, style: &dyn Fn(Style, &str) -> String) -> String {
+        match self.format {
+            MessageFormat::Human => self.render_human(diagnostic, style),
+            MessageFormat::Json => self.render_json(diagnostic),
+        }
+    }
+
+    /// Spans only carry line and byte positions, so find the loaded file they
+    /// are consistent with.
+    fn locate(&self, span: SourceSpan) -> Option<Location<'_>> {
+        self.sources.iter().find_map(|(path, contents)| {
+            contents.get(span.start_byte..span.end_byte)?;
+            let before = &contents[..span.start_byte];
+            let line = before.matches('\n').count() + 1;
+            let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+            let column = before[line_start..].chars().count();
+            if line == span.start_line && column == span.start_column {
+                Some(Location {
+                    path,
+                    contents,
+                    span,
+                })
+            } else {
+                None
+            }
+        })
+    }
+
+    fn render_human(
+        &self,
+        diagnostic: &Diagnostic,
+        style: &dyn Fn(Style, &str) -> String,
+    ) -> String {
+        let mut out = String::new();
+        let header = match diagnostic.code {
+            Some(code) => format!("error[{code}]"),
+            None => "error".into(),
+        };
+        let _ = writeln!(
+            out,
+            "{}{}",
+            style(Style::Error, &header),
+            style(Style::Emphasis, &format!(": {}", diagnostic.message))
+        );
+        let context = match (diagnostic.item, diagnostic.subitem) {
+            (Some(item), Some(subitem)) => Some(format!("{item}::{subitem}")),
+            (Some(item), None) => Some(item.to_owned()),
+            _ => None,
+        };
+        let location = diagnostic.span.and_then(|span| self.locate(span));
+        if let Some(Location {
+            path,
+            contents,
+            span,
+        }) = location
+        {
+            let line_no = span.start_line.to_string();
+            let gutter = " ".repeat(line_no.len());
+            let line = contents
+                .lines()
+                .nth(span.start_line - 1)
+                .unwrap_or_default();
+            let end_column = if span.end_line == span.start_line {
+                span.end_column
+            } else {
+                line.chars().count()
+            };
+            // Keep tabs in the padding so the carets line up with the source
+            let padding: String = line
+                .chars()
+                .take(span.start_column)
+                .map(|c| if c == '\t' { '\t' } else { ' ' })
+                .collect();
+            let carets = "^".repeat(end_column.saturating_sub(span.start_column).max(1));
+
+            let _ = writeln!(
+                out,
+                "{gutter}{} {}:{}:{}",
+                style(Style::Gutter, "-->"),
+                path.display(),
+                span.start_line,
+                span.start_column + 1
+            );
+            let _ = writeln!(out, "{gutter} {}", style(Style::Gutter, "|"));
+            let _ = writeln!(
+                out,
+                "{} {line}",
+                style(Style::Gutter, &format!("{line_no} |"))
+            );
+            let _ = writeln!(
+                out,
+                "{gutter} {} {padding}{}",
+                style(Style::Gutter, "|"),
+                style(Style::Error, &carets)
+            );
+            if let Some(context) = context {
+                let _ = writeln!(out, "{gutter} {}", style(Style::Gutter, "|"));
+                let _ = writeln!(
+                    out,
+                    "{gutter} {} in {context}",
+                    style(Style::Emphasis, "= note:")
+                );
+            }
+        } else if let Some(context) = context {
+            let _ = writeln!(out, "  {} in {context}", style(Style::Emphasis, "= note:"));
+        }
+        out.push('\n');
+        out
+    }
+
+    fn render_json(&self, diagnostic: &Diagnostic) -> String {
+        let mut out = String::new();
+        let _ = write!(
+            out,
+            r#"{{"code":{},"kind":{},"message":{},"item":{},"subitem":{},"span":"#,
+            json_opt_str(diagnostic.code),
+            json_str(diagnostic.kind),
+            json_str(&diagnostic.message),
+            json_opt_str(diagnostic.item),
+            json_opt_str(diagnostic.subitem),
+        );
+        match diagnostic.span.and_then(|span| self.locate(span)) {
+            Some(Location { path, span, .. }) => {
+                let _ = write!(
+                    out,
+                    r#"{{"file":{},"line_start":{},"column_start":{},"line_end":{},"column_end":{}}}"#,
+                    json_str(&path.display().to_string()),
+                    span.start_line,
+                    span.start_column + 1,
+                    span.end_line,
+                    span.end_column + 1,
+                );
+            }
+            None => out.push_str("null"),
+        }
+        out.push_str("}\n");
+        out
+    }
+}
+
+fn json_opt_str(s: Option<&str>) -> String {
+    s.map(json_str).unwrap_or_else(|| "null".into())
+}
+
+fn json_str(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Diagnostics, MessageFormat, Style};
+    use diplomat_core::{ast, hir};
+    use std::path::Path;
+
+    fn plain(_: Style, s: &str) -> String {
+        s.to_owned()
+    }
+
+    #[test]
+    fn test_lowering_errors() {
+        // Parsing from a string (rather than with `quote!`) gives us real spans
+        let source = r#"#[diplomat::bridge]
+mod ffi {
+    #[diplomat::opaque]
+    pub struct Foo(u8);
+
+    impl Foo {
+        pub fn by_val(x: Foo) {}
+    }
+}
+"#;
+        let file = ast::File::from(&syn::parse_file(source).unwrap());
+        let env = file.all_types();
+        let errors =
+            match hir::TypeContext::from_ast(&env, hir::BasicAttributeValidator::new("tests")) {
+                Ok(_) => panic!("expected lowering errors"),
+                Err(errors) => errors,
+            };
+
+        let mut out = String::new();
+        for format in [MessageFormat::Human, MessageFormat::Json] {
+            let mut diagnostics = Diagnostics::new(format);
+            diagnostics.add_source(Path::new("src/lib.rs"), source.into());
+            out.push_str(&diagnostics.render(&errors, &plain));
+        }
+        insta::assert_snapshot!(out);
+    }
+
+    #[test]
+    fn test_unresolved_type() {
+        let source = r#"#[diplomat::bridge]
+mod ffi {
+    #[diplomat::opaque]
+    pub struct Foo(u8);
+
+    impl Foo {
+        pub fn take(&self, x: &Missing) {}
+    }
+}
+"#;
+        let file = ast::File::from(&syn::parse_file(source).unwrap());
+        let errors = match hir::TypeContext::check_paths(&file.all_types()) {
+            Ok(_) => panic!("expected an unresolved type"),
+            Err(errors) => errors,
+        };
+
+        let mut diagnostics = Diagnostics::new(MessageFormat::Human);
+        diagnostics.add_source(Path::new("src/lib.rs"), source.into());
+        insta::assert_snapshot!(diagnostics.render(&errors, &plain));
+    }
+
+    #[test]
+    fn test_ast_errors() {
+        let source = r#"#[diplomat::bridge]
+mod ffi {
+    pub struct Pair(u8, u8);
+
+    pub enum Shape {
+        Circle(f64),
+    }
+
+    impl Pair {
+        pub fn new() {}
+    }
+}
+"#;
+        let file = ast::File::from(&syn::parse_file(source).unwrap());
+
+        let mut out = String::new();
+        for format in [MessageFormat::Human, MessageFormat::Json] {
+            let mut diagnostics = Diagnostics::new(format);
+            diagnostics.add_source(Path::new("src/lib.rs"), source.into());
+            let errors = file.all_errors();
+            out.push_str(&diagnostics.render_ast_errors(&errors, &plain));
+        }
+        insta::assert_snapshot!(out);
+    }
+}
@@ -0,0 +1,949 @@
+//! Comparison of two versions of a bridge crate, for `diplomat-tool diff`.
+//!
+//! Both versions are lowered to the HIR and compared item by item. Every difference is
+//! classified by who it breaks: consumers still linking against the old binary
+//! (ABI-breaking), consumers regenerating their bindings (API-breaking), or nobody.
+
+use crate::c2::CFormatter;
+use diplomat_core::hir::{
+    self, MaybeStatic, OpaqueOwner, ReturnType, SelfType, StructPathLike, SuccessType, TyPosition,
+    Type, TypeContext, TypeDef, TypeId,
+};
+use std::collections::HashMap;
+use std::fmt;
+
+/// Who a change breaks
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    /// Binaries built against the old version can't use the new one
+    AbiBreaking,
+    /// Code written against bindings of the old version won't work with regenerated bindings
+    ApiBreaking,
+    Compatible,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Severity::AbiBreaking => "ABI-breaking",
+            Severity::ApiBreaking => "API-breaking",
+            Severity::Compatible => "compatible",
+        })
+    }
+}
+
+/// A difference between the two versions
+#[derive(Debug)]
+pub struct Change {
+    pub severity: Severity,
+    /// The item that changed, like `Type` or `Type::method`
+    pub item: String,
+    pub message: String,
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:<12}  `{}`: {}",
+            self.severity, self.item, self.message
+        )
+    }
+}
+
+/// The attribute validator both versions are lowered with.
+///
+/// The C ABI doesn't depend on the backend, so every attribute is accepted and none of
+/// them are applied, except for `abi_rename` which all backends respect.
+pub fn attribute_validator() -> hir::BasicAttributeValidator {
+    let mut validator = hir::BasicAttributeValidator::new("diff");
+    validator.support.disabling = true;
+    validator.support.renaming = true;
+    validator.support.namespacing = true;
+    validator.support.constructors = true;
+    validator.support.named_constructors = true;
+    validator.support.fallible_constructors = true;
+    validator.support.accessors = true;
+    validator.support.stringifiers = true;
+    validator.support.comparators = true;
+    validator.support.memory_sharing = true;
+    validator.support.iterators = true;
+    validator.support.iterables = true;
+    validator.support.indexing = true;
+    validator
+}
+
+/// Compare two versions of a bridge crate
+pub fn compare(old: &TypeContext, new: &TypeContext) -> Vec<Change> {
+    let mut cx = DiffContext {
+        old,
+        new,
+        old_c: CFormatter::new(old),
+        new_c: CFormatter::new(new),
+        changes: vec![],
+    };
+
+    let new_types = new
+        .all_types()
+        .map(|(id, ty)| (ty.name().as_str(), (id, ty)))
+        .collect::<HashMap<_, _>>();
+    for (old_id, old_ty) in old.all_types() {
+        match new_types.get(old_ty.name().as_str()) {
+            Some(&(new_id, new_ty)) => cx.compare_types(old_id, old_ty, new_id, new_ty),
+            None => cx.push(
+                Severity::AbiBreaking,
+                old_ty.name().as_str(),
+                "type removed".into(),
+            ),
+        }
+    }
+    let old_types = old
+        .all_types()
+        .map(|(_, ty)| ty.name().as_str())
+        .collect::<Vec<_>>();
+    for (_, new_ty) in new.all_types() {
+        if !old_types.contains(&new_ty.name().as_str()) {
+            cx.push(
+                Severity::Compatible,
+                new_ty.name().as_str(),
+                "type added".into(),
+            );
+        }
+    }
+
+    let new_traits = new
+        .all_traits()
+        .map(|(_, trt)| (trt.name.as_str(), trt))
+        .collect::<HashMap<_, _>>();
+    for (_, old_trait) in old.all_traits() {
+        match new_traits.get(old_trait.name.as_str()) {
+            Some(new_trait) => cx.compare_traits(old_trait, new_trait),
+            None => cx.push(
+                Severity::AbiBreaking,
+                old_trait.name.as_str(),
+                "trait removed".into(),
+            ),
+        }
+    }
+
+    cx.compare_consts(None, old.consts(), new.consts());
+
+    cx.changes
+}
+
+/// A type as it crosses the FFI boundary, and as bindings expose it
+#[derive(PartialEq, Eq)]
+struct Desc {
+    /// Leaves out everything that doesn't change the ABI, like mutability and lifetimes
+    abi: String,
+    api: String,
+}
+
+impl Desc {
+    fn same(s: impl Into<String>) -> Self {
+        let s = s.into();
+        Desc {
+            abi: s.clone(),
+            api: s,
+        }
+    }
+}
+
+/// Names lifetimes in the order they first appear, so that renaming
+/// a lifetime isn't a change
+#[derive(Default)]
+struct LifetimeNames(HashMap<hir::Lifetime, usize>);
+
+impl LifetimeNames {
+    fn fmt(&mut self, lifetime: MaybeStatic<hir::Lifetime>) -> String {
+        match lifetime {
+            MaybeStatic::Static => "'static".into(),
+            MaybeStatic::NonStatic(lifetime) => {
+                let next = self.0.len();
+                let index = *self.0.entry(lifetime).or_insert(next);
+                match u8::try_from(index).ok().filter(|i| *i < 26) {
+                    Some(i) => format!("'{}", (b'a' + i) as char),
+                    None => format!("'l{index}"),
+                }
+            }
+        }
+    }
+
+    fn fmt_all(&mut self, lifetimes: impl Iterator<Item = MaybeStatic<hir::Lifetime>>) -> String {
+        let lifetimes = lifetimes.map(|lt| self.fmt(lt)).collect::<Vec<_>>();
+        if lifetimes.is_empty() {
+            String::new()
+        } else {
+            format!("<{}>", lifetimes.join(", "))
+        }
+    }
+}
+
+/// The signature of a method, as a list of named parts that can be compared separately
+struct Signature {
+    symbol: String,
+    parts: Vec<(String, Desc)>,
+}
+
+struct DiffContext<'tcx> {
+    old: &'tcx TypeContext,
+    new: &'tcx TypeContext,
+    old_c: CFormatter<'tcx>,
+    new_c: CFormatter<'tcx>,
+    changes: Vec<Change>,
+}
+
+impl<'tcx> DiffContext<'tcx> {
+    fn push(&mut self, severity: Severity, item: impl Into<String>, message: String) {
+        self.changes.push(Change {
+            severity,
+            item: item.into(),
+            message,
+        });
+    }
+
+    fn compare_types(
+        &mut self,
+        old_id: TypeId,
+        old_ty: TypeDef<'tcx>,
+        new_id: TypeId,
+        new_ty: TypeDef<'tcx>,
+    ) {
+        let name = old_ty.name().as_str();
+        match (old_ty, new_ty) {
+            (TypeDef::Struct(old), TypeDef::Struct(new)) => {
+                self.compare_structs(name, old, new);
+            }
+            (TypeDef::OutStruct(old), TypeDef::OutStruct(new)) => {
+                self.compare_structs(name, old, new);
+            }
+            (TypeDef::Struct(old), TypeDef::OutStruct(new)) => {
+                self.push(
+                    Severity::ApiBreaking,
+                    name,
+                    "struct can now only be returned".into(),
+                );
+                self.compare_structs(name, old, new);
+            }
+            (TypeDef::OutStruct(old), TypeDef::Struct(new)) => {
+                self.compare_structs(name, old, new);
+            }
+            (TypeDef::Opaque(old), TypeDef::Opaque(new)) => {
+                self.compare_lifetime_count(
+                    name,
+                    old.lifetimes.num_lifetimes(),
+                    new.lifetimes.num_lifetimes(),
+                );
+                let old_dtor = self.old_c.fmt_dtor_name(old_id);
+                let new_dtor = self.new_c.fmt_dtor_name(new_id);
+                if old_dtor != new_dtor {
+                    self.push(
+                        Severity::AbiBreaking,
+                        name,
+                        format!("destructor exported as `{new_dtor}` instead of `{old_dtor}`"),
+                    );
+                }
+            }
+            (TypeDef::Enum(old), TypeDef::Enum(new)) => self.compare_enums(name, old, new),
+            _ => {
+                self.push(
+                    Severity::AbiBreaking,
+                    name,
+                    format!(
+                        "changed from {} to {}",
+                        kind_name(old_ty),
+                        kind_name(new_ty)
+                    ),
+                );
+                return;
+            }
+        }
+
+        self.compare_methods(name, old_id, old_ty, new_id, new_ty);
+        self.compare_consts(Some(name), old_ty.consts(), new_ty.consts());
+    }
+
+    fn compare_lifetime_count(&mut self, name: &str, old: usize, new: usize) {
+        if old != new {
+            self.push(
+                Severity::ApiBreaking,
+                name,
+                format!("takes {new} lifetime parameters instead of {old}"),
+            );
+        }
+    }
+
+    fn compare_structs<P: TyPosition, Q: TyPosition>(
+        &mut self,
+        name: &str,
+        old: &hir::StructDef<P>,
+        new: &hir::StructDef<Q>,
+    ) {
+        self.compare_lifetime_count(
+            name,
+            old.lifetimes.num_lifetimes(),
+            new.lifetimes.num_lifetimes(),
+        );
+        let old_fields = self.fields(self.old, &old.fields);
+        let new_fields = self.fields(self.new, &new.fields);
+        self.compare_fields(name, &old_fields, &new_fields);
+    }
+
+    fn fields<P: TyPosition>(
+        &self,
+        tcx: &TypeContext,
+        fields: &[hir::StructField<P>],
+    ) -> Vec<(String, Desc)> {
+        // Struct lifetimes are declared on the struct, so they are shared by all fields
+        let mut lifetimes = LifetimeNames::default();
+        fields
+            .iter()
+            .map(|field| {
+                (
+                    field.name.as_str().to_owned(),
+                    fmt_type(tcx, &field.ty, &mut lifetimes),
+                )
+            })
+            .collect()
+    }
+
+    /// Fields are laid out in order, so any change to them besides renames changes the layout
+    fn compare_fields(&mut self, item: &str, old: &[(String, Desc)], new: &[(String, Desc)]) {
+        let position = |fields: &[(String, Desc)], name: &str| {
+            fields.iter().position(|(field, _)| field == name)
+        };
+        // Swapping two fields of the same type keeps the layout, but not what the bytes mean
+        let reordered = old.len() == new.len()
+            && old.iter().all(|(field, _)| position(new, field).is_some())
+            && old.iter().zip(new).any(|((old, _), (new, _))| old != new);
+        let same_layout = old.len() == new.len()
+            && old
+                .iter()
+                .zip(new)
+                .all(|((_, old), (_, new))| old.abi == new.abi);
+
+        if same_layout && !reordered {
+            for ((old_name, old_ty), (new_name, new_ty)) in old.iter().zip(new) {
+                if old_name != new_name {
+                    self.push(
+                        Severity::ApiBreaking,
+                        item,
+                        format!("field `{old_name}` renamed to `{new_name}`"),
+                    );
+                } else if old_ty.api != new_ty.api {
+                    self.push(
+                        Severity::ApiBreaking,
+                        item,
+                        format!(
+                            "field `{old_name}` changed from `{}` to `{}`",
+                            old_ty.api, new_ty.api
+                        ),
+                    );
+                }
+            }
+            return;
+        }
+
+        if reordered {
+            self.push(Severity::AbiBreaking, item, "fields reordered".into());
+        }
+        for (field, old_ty) in old {
+            let new_ty = match position(new, field) {
+                Some(i) => &new[i].1,
+                None => {
+                    self.push(
+                        Severity::AbiBreaking,
+                        item,
+                        format!("field `{field}` removed"),
+                    );
+                    continue;
+                }
+            };
+            let severity = if old_ty.abi != new_ty.abi {
+                Severity::AbiBreaking
+            } else if old_ty.api != new_ty.api {
+                Severity::ApiBreaking
+            } else {
+                continue;
+            };
+            let (old_ty, new_ty) = match severity {
+                Severity::AbiBreaking => (&old_ty.abi, &new_ty.abi),
+                _ => (&old_ty.api, &new_ty.api),
+            };
+            self.push(
+                severity,
+                item,
+                format!("field `{field}` changed from `{old_ty}` to `{new_ty}`"),
+            );
+        }
+        for (field, _) in new {
+            if position(old, field).is_none() {
+                self.push(
+                    Severity::AbiBreaking,
+                    item,
+                    format!("field `{field}` added"),
+                );
+            }
+        }
+    }
+
+    fn compare_enums(&mut self, name: &str, old: &hir::EnumDef, new: &hir::EnumDef) {
+        for old_variant in &old.variants {
+            let item = format!("{name}::{}", old_variant.name);
+            let new_variant = match new
+                .variants
+                .iter()
+                .find(|v| v.name.as_str() == old_variant.name.as_str())
+            {
+                Some(variant) => variant,
+                None => {
+                    self.push(Severity::AbiBreaking, item, "variant removed".into());
+                    continue;
+                }
+            };
+            if old_variant.discriminant != new_variant.discriminant {
+                self.push(
+                    Severity::AbiBreaking,
+                    &item,
+                    format!(
+                        "discriminant changed from {} to {}",
+                        old_variant.discriminant, new_variant.discriminant
+                    ),
+                );
+            }
+            let old_fields = self.fields(self.old, &old_variant.fields);
+            let new_fields = self.fields(self.new, &new_variant.fields);
+            self.compare_fields(&item, &old_fields, &new_fields);
+        }
+        for new_variant in &new.variants {
+            if !old
+                .variants
+                .iter()
+                .any(|v| v.name.as_str() == new_variant.name.as_str())
+            {
+                self.push(
+                    Severity::Compatible,
+                    format!("{name}::{}", new_variant.name),
+                    "variant added".into(),
+                );
+            }
+        }
+    }
+
+    fn compare_methods(
+        &mut self,
+        name: &str,
+        old_id: TypeId,
+        old_ty: TypeDef<'tcx>,
+        new_id: TypeId,
+        new_ty: TypeDef<'tcx>,
+    ) {
+        for old_method in old_ty.methods() {
+            let item = format!("{name}::{}", old_method.name);
+            let new_method = match new_ty
+                .methods()
+                .iter()
+                .find(|m| m.name.as_str() == old_method.name.as_str())
+            {
+                Some(method) => method,
+                None => {
+                    self.push(Severity::AbiBreaking, item, "method removed".into());
+                    continue;
+                }
+            };
+            let old_sig = signature(self.old, &self.old_c, old_id, old_method);
+            let new_sig = signature(self.new, &self.new_c, new_id, new_method);
+            if old_sig.symbol != new_sig.symbol {
+                self.push(
+                    Severity::AbiBreaking,
+                    &item,
+                    format!(
+                        "exported as `{}` instead of `{}`",
+                        new_sig.symbol, old_sig.symbol
+                    ),
+                );
+            }
+            self.compare_signatures(&item, &old_sig.parts, &new_sig.parts);
+
+            let special = |method: &hir::Method| format!("{:?}", method.attrs.special_method);
+            if special(old_method) != special(new_method) {
+                self.push(
+                    Severity::ApiBreaking,
+                    &item,
+                    format!(
+                        "special method changed from {} to {}",
+                        special(old_method),
+                        special(new_method)
+                    ),
+                );
+            }
+        }
+        for new_method in new_ty.methods() {
+            if !old_ty
+                .methods()
+                .iter()
+                .any(|m| m.name.as_str() == new_method.name.as_str())
+            {
+                self.push(
+                    Severity::Compatible,
+                    format!("{name}::{}", new_method.name),
+                    "method added".into(),
+                );
+            }
+        }
+    }
+
+    fn compare_signatures(&mut self, item: &str, old: &[(String, Desc)], new: &[(String, Desc)]) {
+        if old.len() != new.len() {
+            // The first part is `self` and the last the return type
+            self.push(
+                Severity::AbiBreaking,
+                item,
+                format!(
+                    "takes {} parameters instead of {}",
+                    new.len() - 2,
+                    old.len() - 2
+                ),
+            );
+            return;
+        }
+        for ((old_name, old_ty), (new_name, new_ty)) in old.iter().zip(new) {
+            let severity = if old_ty.abi != new_ty.abi {
+                Severity::AbiBreaking
+            } else if old_ty.api != new_ty.api {
+                Severity::ApiBreaking
+            } else {
+                if old_name != new_name {
+                    self.push(
+                        Severity::Compatible,
+                        item,
+                        format!("parameter `{old_name}` renamed to `{new_name}`"),
+                    );
+                }
+                continue;
+            };
+            let (old_ty, new_ty) = match severity {
+                Severity::AbiBreaking => (&old_ty.abi, &new_ty.abi),
+                _ => (&old_ty.api, &new_ty.api),
+            };
+            let part = match old_name.as_str() {
+                "self" | "return type" => old_name.clone(),
+                _ => format!("parameter `{old_name}`"),
+            };
+            self.push(
+                severity,
+                item,
+                format!("{part} changed from `{old_ty}` to `{new_ty}`"),
+            );
+        }
+    }
+
+    /// Traits are passed as vtables, so their methods can't change at all
+    fn compare_traits(&mut self, old: &hir::TraitDef, new: &hir::TraitDef) {
+        let methods = |tcx: &TypeContext, trt: &hir::TraitDef| {
+            trt.methods
+                .iter()
+                .map(|method| {
+                    let mut lifetimes = LifetimeNames::default();
+                    let params = method
+                        .params
+                        .iter()
+                        .map(|param| fmt_type(tcx, &param.ty, &mut lifetimes).api)
+                        .collect::<Vec<_>>();
+                    let output = match &method.output {
+                        Some(ty) => format!(" -> {}", fmt_type(tcx, ty, &mut lifetimes).api),
+                        None => String::new(),
+                    };
+                    format!("fn {}({}){output}", method.name, params.join(", "))
+                })
+                .collect::<Vec<_>>()
+        };
+        let old_methods = methods(self.old, old);
+        let new_methods = methods(self.new, new);
+        if old_methods != new_methods {
+            self.push(
+                Severity::AbiBreaking,
+                old.name.as_str(),
+                format!(
+                    "vtable changed from `{}` to `{}`",
+                    old_methods.join("; "),
+                    new_methods.join("; ")
+                ),
+            );
+        }
+    }
+
+    /// Consts are generated into the bindings, so they can't break binaries
+    fn compare_consts(&mut self, ty: Option<&str>, old: &[hir::ConstDef], new: &[hir::ConstDef]) {
+        let item = |def: &hir::ConstDef| match ty {
+            Some(ty) => format!("{ty}::{}", def.name),
+            None => def.name.as_str().to_owned(),
+        };
+        for old_const in old {
+            match new
+                .iter()
+                .find(|c| c.name.as_str() == old_const.name.as_str())
+            {
+                None => self.push(
+                    Severity::ApiBreaking,
+                    item(old_const),
+                    "const removed".into(),
+                ),
+                Some(new_const) if fmt_const_type(old_const) != fmt_const_type(new_const) => self
+                    .push(
+                        Severity::ApiBreaking,
+                        item(old_const),
+                        format!(
+                            "type changed from `{}` to `{}`",
+                            fmt_const_type(old_const),
+                            fmt_const_type(new_const)
+                        ),
+                    ),
+                Some(new_const) if old_const.value != new_const.value => self.push(
+                    Severity::Compatible,
+                    item(old_const),
+                    "value changed".into(),
+                ),
+                Some(_) => {}
+            }
+        }
+        for new_const in new {
+            if !old
+                .iter()
+                .any(|c| c.name.as_str() == new_const.name.as_str())
+            {
+                self.push(Severity::Compatible, item(new_const), "const added".into());
+            }
+        }
+    }
+}
+
+fn kind_name(ty: TypeDef) -> &'static str {
+    match ty {
+        TypeDef::Struct(_) => "a struct",
+        TypeDef::OutStruct(_) => "an output struct",
+        TypeDef::Opaque(_) => "an opaque",
+        TypeDef::Enum(_) => "an enum",
+        _ => unreachable!("unknown AST/HIR variant"),
+    }
+}
+
+fn fmt_const_type(def: &hir::ConstDef) -> &'static str {
+    match def.ty {
+        hir::ConstType::Primitive(prim) => prim.as_str(),
+        hir::ConstType::Str => "&str",
+        _ => unreachable!("unknown AST/HIR variant"),
+    }
+}
+
+/// The symbol and parts of the signature of a method: `self`, the parameters in order,
+/// and the return type
+fn signature(tcx: &TypeContext, c: &CFormatter, id: TypeId, method: &hir::Method) -> Signature {
+    let mut lifetimes = LifetimeNames::default();
+    let mut parts = vec![];
+
+    let self_ty = match &method.param_self {
+        None => Desc::same("none"),
+        Some(param_self) => match &param_self.ty {
+            SelfType::Opaque(path) => {
+                let lifetime = lifetimes.fmt(path.owner.lifetime);
+                let generics = lifetimes.fmt_all(path.lifetimes.lifetimes());
+                let name = tcx.resolve_type(path.tcx_id.into()).name();
+                Desc {
+                    abi: format!("&{name}"),
+                    api: format!(
+                        "&{lifetime} {}{name}{generics}",
+                        mutability(path.owner.mutability)
+                    ),
+                }
+            }
+            SelfType::Struct(path) => fmt_type(
+                tcx,
+                &Type::<hir::Everywhere>::Struct(path.clone()),
+                &mut lifetimes,
+            ),
+            SelfType::Enum(path) => fmt_type(
+                tcx,
+                &Type::<hir::Everywhere>::Enum(path.clone()),
+                &mut lifetimes,
+            ),
+            _ => unreachable!("unknown AST/HIR variant"),
+        },
+    };
+    parts.push(("self".to_owned(), self_ty));
+    for param in &method.params {
+        parts.push((
+            param.name.as_str().to_owned(),
+            fmt_type(tcx, &param.ty, &mut lifetimes),
+        ));
+    }
+
+    let success = |ty: &SuccessType, lifetimes: &mut LifetimeNames| match ty {
+        SuccessType::Writeable => Desc::same("DiplomatWriteable"),
+        SuccessType::OutType(ty) => fmt_type(tcx, ty, lifetimes),
+        _ => Desc::same("()"),
+    };
+    let output = match &method.output {
+        ReturnType::Infallible(ty) => success(ty, &mut lifetimes),
+        ReturnType::Nullable(ty) => wrap("Option", success(ty, &mut lifetimes)),
+        ReturnType::Fallible(ok, err) => {
+            let ok = success(ok, &mut lifetimes);
+            let err = match err {
+                Some(err) => fmt_type(tcx, err, &mut lifetimes),
+                None => Desc::same("()"),
+            };
+            Desc {
+                abi: format!("Result<{}, {}>", ok.abi, err.abi),
+                api: format!("Result<{}, {}>", ok.api, err.api),
+            }
+        }
+    };
+    parts.push(("return type".to_owned(), output));
+
+    Signature {
+        symbol: c.fmt_method_name(id, method, true),
+        parts,
+    }
+}
+
+fn wrap(wrapper: &str, inner: Desc) -> Desc {
+    Desc {
+        abi: format!("{wrapper}<{}>", inner.abi),
+        api: format!("{wrapper}<{}>", inner.api),
+    }
+}
+
+fn mutability(mutability: hir::Mutability) -> &'static str {
+    if mutability.is_mutable() {
+        "mut "
+    } else {
+        ""
+    }
+}
+
+/// Describe a type the way it is written in the bridge crate
+fn fmt_type<P: TyPosition>(tcx: &TypeContext, ty: &Type<P>, lifetimes: &mut LifetimeNames) -> Desc {
+    match ty {
+        // `byte` only changes how bindings present the value
+        Type::Primitive(hir::PrimitiveType::Byte) => Desc {
+            abi: "u8".into(),
+            api: "byte".into(),
+        },
+        Type::Primitive(prim) => Desc::same(prim.as_str()),
+        Type::Opaque(path) => {
+            let name = tcx.resolve_type(path.tcx_id.into()).name();
+            let (abi, api) = match path.owner.mutability() {
+                // Dropping the value is the caller's job, so owning it is part of the ABI
+                None => (
+                    format!("Box<{name}>"),
+                    format!(
+                        "Box<{name}{}>",
+                        lifetimes.fmt_all(path.lifetimes.lifetimes())
+                    ),
+                ),
+                Some(mutability) => {
+                    let lifetime = path
+                        .owner
+                        .lifetime()
+                        .map(|lt| format!("{} ", lifetimes.fmt(lt)))
+                        .unwrap_or_default();
+                    (
+                        format!("&{name}"),
+                        format!(
+                            "&{lifetime}{}{name}{}",
+                            self::mutability(mutability),
+                            lifetimes.fmt_all(path.lifetimes.lifetimes())
+                        ),
+                    )
+                }
+            };
+            let desc = Desc { abi, api };
+            // Null pointers are only allowed through `Option`
+            if path.is_optional() {
+                wrap("Option", desc)
+            } else {
+                desc
+            }
+        }
+        Type::Struct(path) => {
+            let name = tcx.resolve_type(path.id()).name();
+            Desc {
+                abi: name.to_string(),
+                api: format!("{name}{}", lifetimes.fmt_all(path.lifetimes().lifetimes())),
+            }
+        }
+        Type::Enum(path) => Desc::same(tcx.resolve_type(path.tcx_id.into()).name().as_str()),
+        Type::Slice(slice) => {
+            let (borrow, inner) = match slice {
+                hir::Slice::Str(lifetime, encoding) => (
+                    lifetime.map(|lt| (lt, hir::Mutability::Immutable)),
+                    fmt_str(*encoding),
+                ),
+                hir::Slice::Primitive(borrow, prim) => (
+                    borrow.map(|b| (b.lifetime, b.mutability)),
+                    format!("[{}]", prim.as_str()),
+                ),
+                hir::Slice::Strs(encoding) => {
+                    return Desc::same(format!("&[&{}]", fmt_str(*encoding)))
+                }
+                _ => unreachable!("unknown AST/HIR variant"),
+            };
+            match borrow {
+                None => Desc::same(format!("Box<{inner}>")),
+                Some((lifetime, mutability)) => Desc {
+                    // Writing through a slice is only possible if it isn't shared
+                    abi: format!("&{}{inner}", self::mutability(mutability)),
+                    api: format!(
+                        "&{} {}{inner}",
+                        lifetimes.fmt(lifetime),
+                        self::mutability(mutability)
+                    ),
+                },
+            }
+        }
+        Type::Func(func) => {
+            let inputs = func
+                .inputs
+                .iter()
+                .map(|(ty, _)| fmt_type(tcx, ty, lifetimes))
+                .collect::<Vec<_>>();
+            let output = func.output.as_ref().map(|ty| fmt_type(tcx, ty, lifetimes));
+            let fmt = |abi: bool| {
+                let pick = |desc: &Desc| {
+                    if abi {
+                        desc.abi.clone()
+                    } else {
+                        desc.api.clone()
+                    }
+                };
+                let output = output
+                    .as_ref()
+                    .map(|ty| format!(" -> {}", pick(ty)))
+                    .unwrap_or_default();
+                format!(
+                    "impl Fn({}){output}",
+                    inputs.iter().map(pick).collect::<Vec<_>>().join(", ")
+                )
+            };
+            Desc {
+                abi: fmt(true),
+                api: fmt(false),
+            }
+        }
+        Type::Trait(path) => {
+            let name = &tcx.resolve_trait(path.tcx_id).name;
+            if path.is_owned {
+                Desc::same(format!("Box<dyn {name}>"))
+            } else {
+                Desc::same(format!("&dyn {name}"))
+            }
+        }
+        _ => unreachable!("unknown AST/HIR variant"),
+    }
+}
+
+fn fmt_str(encoding: hir::StringEncoding) -> String {
+    match encoding {
+        hir::StringEncoding::Utf8 => "str",
+        hir::StringEncoding::UnvalidatedUtf8 => "DiplomatStr",
+        hir::StringEncoding::UnvalidatedUtf16 => "DiplomatStr16",
+        _ => unreachable!("unknown AST/HIR variant"),
+    }
+    .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{attribute_validator, compare, Change};
+    use diplomat_core::{ast, hir};
+
+    fn lower(source: &str) -> hir::TypeContext {
+        let file = ast::File::from(&syn::parse_file(source).unwrap());
+        hir::TypeContext::from_ast(&file.all_types(), attribute_validator())
+            .unwrap_or_else(|e| panic!("lowering failed: {e:?}"))
+    }
+
+    fn render(changes: &[Change]) -> String {
+        changes.iter().map(|change| format!("{change}\n")).collect()
+    }
+
+    #[test]
+    fn test_changes() {
+        let old = r#"#[diplomat::bridge]
+mod ffi {
+    pub struct Point {
+        pub x: i32,
+        pub y: i32,
+    }
+
+    pub struct Renamed {
+        pub a: u8,
+    }
+
+    pub enum Color {
+        Red = 0,
+        Green = 1,
+    }
+
+    #[diplomat::opaque]
+    pub struct Counter(u32);
+
+    #[diplomat::opaque]
+    pub struct Gone;
+
+    impl Counter {
+        pub fn new(start: u32) -> Box<Counter> {
+            unimplemented!()
+        }
+        pub fn add(&mut self, amount: u32) {}
+        pub fn label<'a>(&'a self, other: &'a Counter) -> &'a Counter {
+            unimplemented!()
+        }
+        #[diplomat::abi_rename = "counter_get"]
+        pub fn get(&self) -> u32 {
+            unimplemented!()
+        }
+        pub fn reset(&mut self) {}
+    }
+}
+"#;
+        let new = r#"#[diplomat::bridge]
+mod ffi {
+    pub struct Point {
+        pub y: i32,
+        pub x: i32,
+    }
+
+    pub struct Renamed {
+        pub b: u8,
+    }
+
+    pub enum Color {
+        Red = 0,
+        Green = 2,
+        Blue = 3,
+    }
+
+    #[diplomat::opaque]
+    pub struct Counter(u32);
+
+    impl Counter {
+        pub fn new(initial: u32) -> Box<Counter> {
+            unimplemented!()
+        }
+        pub fn add(&mut self, amount: u64) {}
+        pub fn label<'a, 'b>(&'a self, other: &'b Counter) -> &'b Counter {
+            unimplemented!()
+        }
+        pub fn get(&self) -> u32 {
+            unimplemented!()
+        }
+        pub fn reset(&self) {}
+        pub fn double(&mut self) {}
+    }
+}
+"#;
+
+        insta::assert_snapshot!(render(&compare(&lower(old), &lower(new))));
+    }
+}
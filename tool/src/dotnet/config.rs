@@ -12,8 +12,8 @@ pub struct LibraryConfig {
     pub properties: PropertiesConfig,
 }
 
-impl LibraryConfig {
-    pub fn default() -> LibraryConfig {
+impl Default for LibraryConfig {
+    fn default() -> LibraryConfig {
         LibraryConfig {
             namespace: "Interop".to_owned(),
             native_lib: "rust".to_owned(),
@@ -62,6 +62,8 @@ pub fn gen(
             out.scope(|out| {
                 writeln!(out, "private unsafe Raw.{}* _inner;", opaque.name)?;
 
+                gen_consts(&opaque.consts, in_path, env, docs_url_gen, out)?;
+
                 let properties = collect_properties(&opaque.methods, in_path, env, library_config);
                 for property in properties {
                     gen_property_for_getters_setters(&property, out)?;
@@ -126,6 +128,8 @@ pub fn gen(
             out.scope(|out| {
                 writeln!(out, "private Raw.{} _inner;", strct.name)?;
 
+                gen_consts(&strct.consts, in_path, env, docs_url_gen, out)?;
+
                 for (name, typ, doc) in strct.fields.iter() {
                     gen_property_for_field(name, doc, typ, in_path, env, docs_url_gen, out)?;
                 }
@@ -174,16 +178,34 @@ pub fn gen(
             )?;
             writeln!(out, "public enum {}", enm.name)?;
             out.scope(|out| {
-                for (name, discriminant, docs, _attrs) in enm.variants.iter() {
+                for variant in enm.variants.iter() {
                     gen_doc_block(
                         out,
-                        &docs.to_markdown(docs_url_gen, ast::MarkdownStyle::Normal),
+                        &variant
+                            .docs
+                            .to_markdown(docs_url_gen, ast::MarkdownStyle::Normal),
                     )?;
-                    writeln!(out, "{name} = {discriminant},")?;
+                    writeln!(out, "{} = {},", variant.name, variant.discriminant)?;
                 }
 
                 Ok(())
             })?;
+
+            // C# enums can't have members, so consts go in a companion class
+            if enm.consts.iter().any(|c| !c.attrs.skip_if_ast) {
+                writeln!(out)?;
+                writeln!(out, "public static class {}Constants", enm.name)?;
+                out.scope(|out| {
+                    let consts = enm.consts.iter().filter(|c| !c.attrs.skip_if_ast);
+                    for (i, konst) in consts.enumerate() {
+                        if i > 0 {
+                            writeln!(out)?;
+                        }
+                        gen_const(konst, in_path, env, docs_url_gen, out)?;
+                    }
+                    Ok(())
+                })?;
+            }
         }
         &_ => unreachable!("unknown AST/HIR variant"),
     }
@@ -191,6 +213,90 @@ pub fn gen(
     Ok(())
 }
 
+/// Generates the `Constants` class holding the consts declared at the top level of the module.
+pub fn gen_module_consts(
+    consts: &[(ast::Path, &ast::Const)],
+    env: &Env,
+    docs_url_gen: &ast::DocsUrlGenerator,
+    out: &mut CodeWriter,
+) -> fmt::Result {
+    writeln!(out)?;
+    writeln!(out, "public static class Constants")?;
+    out.scope(|out| {
+        for (i, (in_path, konst)) in consts.iter().enumerate() {
+            if i > 0 {
+                writeln!(out)?;
+            }
+            gen_const(konst, in_path, env, docs_url_gen, out)?;
+        }
+        Ok(())
+    })
+}
+
+fn gen_consts(
+    consts: &[ast::Const],
+    in_path: &ast::Path,
+    env: &Env,
+    docs_url_gen: &ast::DocsUrlGenerator,
+    out: &mut CodeWriter,
+) -> fmt::Result {
+    for konst in consts.iter().filter(|c| !c.attrs.skip_if_ast) {
+        writeln!(out)?;
+        gen_const(konst, in_path, env, docs_url_gen, out)?;
+    }
+    Ok(())
+}
+
+fn gen_const(
+    konst: &ast::Const,
+    in_path: &ast::Path,
+    env: &Env,
+    docs_url_gen: &ast::DocsUrlGenerator,
+    out: &mut CodeWriter,
+) -> fmt::Result {
+    gen_doc_block(
+        out,
+        &konst
+            .docs
+            .to_markdown(docs_url_gen, ast::MarkdownStyle::Normal),
+    )?;
+
+    let value = match (&konst.value, &konst.ty) {
+        (ast::ConstValue::Bool(b), _) => b.to_string(),
+        (ast::ConstValue::Float(f), ast::TypeName::Primitive(ast::PrimitiveType::f32)) => {
+            format!("{f}f")
+        }
+        (ast::ConstValue::Int(v) | ast::ConstValue::Float(v), _) => v.clone(),
+        (ast::ConstValue::Str(s), _) => {
+            let mut lit = String::with_capacity(s.len() + 2);
+            lit.push('"');
+            for c in s.chars() {
+                match c {
+                    '"' | '\\' => {
+                        lit.push('\\');
+                        lit.push(c);
+                    }
+                    '\n' => lit.push_str("\\n"),
+                    '\r' => lit.push_str("\\r"),
+                    '\t' => lit.push_str("\\t"),
+                    c if c.is_control() => write!(lit, "\\u{:04x}", c as u32)?,
+                    c => lit.push(c),
+                }
+            }
+            lit.push('"');
+            lit
+        }
+        _ => unreachable!("unknown AST/HIR variant"),
+    };
+
+    writeln!(
+        out,
+        "public const {} {} = {value};",
+        gen_type_name_to_string(&konst.ty, in_path, env)?,
+        konst.name.as_str().to_upper_camel_case()
+    )
+}
+
 fn gen_property_for_field(
     name: &ast::Ident,
     docs: &ast::Docs,
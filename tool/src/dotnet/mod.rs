@@ -1,17 +1,14 @@
 use std::collections::HashMap;
 use std::fmt;
 use std::fmt::Write;
-use std::fs;
-use std::path::Path;
 
-use colored::*;
 use diplomat_core::Env;
 
 use self::config::LibraryConfig;
 use crate::util::CodeWriter;
 use crate::util::SetOfAstTypes;
 
-mod config;
+pub mod config;
 mod conversions;
 mod idiomatic;
 mod raw;
@@ -26,26 +23,10 @@ const SCOPE_CLOSING: &str = "}";
 
 pub fn gen_bindings(
     env: &Env,
-    library_config_path: Option<&Path>,
+    library_config: LibraryConfig,
     docs_url_gen: &diplomat_core::ast::DocsUrlGenerator,
     outs: &mut HashMap<String, String>,
 ) -> fmt::Result {
-    let mut library_config = LibraryConfig::default();
-    if let Some(path) = library_config_path {
-        // Should be fine, we've already verified the path
-        let contents = fs::read_to_string(path).unwrap();
-        match toml::from_str(&contents) {
-            Ok(config) => library_config = config,
-            Err(err) => {
-                eprintln!(
-                    "{} Unable to parse library configuration file: {path:?}\n{err}",
-                    "Error:".red().bold(),
-                );
-                std::process::exit(1);
-            }
-        }
-    }
-
     let diplomat_runtime_out = outs.entry("DiplomatRuntime.cs".to_owned()).or_default();
     write!(
         diplomat_runtime_out,
@@ -114,6 +95,18 @@ pub fn gen_bindings(
             }
         }
 
+        let module_consts = crate::util::get_all_consts(env);
+        if !module_consts.is_empty() {
+            let mut out_buf = String::new();
+            let mut out = CodeWriter::new(&mut out_buf, INDENTATION, SCOPE_OPENING, SCOPE_CLOSING);
+            idiomatic::gen_header(&library_config, &mut out)?;
+            idiomatic::gen_module_consts(&module_consts, env, docs_url_gen, &mut out)?;
+
+            if outs.insert("Constants.cs".to_owned(), out_buf).is_some() {
+                panic!("file created twice: Constants.cs")
+            }
+        }
+
         for (in_path, typ) in errors {
             let idiomatic::ExceptionCtx { name, .. } =
                 idiomatic::error_type_to_exception_name(env, &library_config, typ, &in_path)?;
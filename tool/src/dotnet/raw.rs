@@ -136,12 +136,14 @@ pub fn gen<'ast>(
             )?;
             writeln!(out, "public enum {}", enm.name)?;
             out.scope(|out| {
-                for (name, discriminant, docs, _attrs) in enm.variants.iter() {
+                for variant in enm.variants.iter() {
                     gen_doc_block(
                         out,
-                        &docs.to_markdown(docs_url_gen, ast::MarkdownStyle::Normal),
+                        &variant
+                            .docs
+                            .to_markdown(docs_url_gen, ast::MarkdownStyle::Normal),
                     )?;
-                    writeln!(out, "{name} = {discriminant},")?;
+                    writeln!(out, "{} = {},", variant.name, variant.discriminant)?;
                 }
 
                 Ok(())
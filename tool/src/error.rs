@@ -0,0 +1,142 @@
+//! The error returned by [`gen`](crate::gen) and the other entry points of the library.
+//!
+//! Nothing here prints or exits, that is left to the caller. `diplomat-tool` itself
+//! prints lowering errors rendered with [`Diagnostics::render`] and everything else with
+//! the [`Display`](fmt::Display) impl.
+
+use crate::backend::Errors;
+use crate::check::Mismatch;
+use crate::diagnostics::Diagnostics;
+use diplomat_core::hir::ErrorAndContext;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Why generating bindings failed
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// A path passed in doesn't exist
+    MissingPath {
+        /// Which of the paths it is, as a sentence
+        message: &'static str,
+        /// The path, joined to the current directory if it was relative
+        path: PathBuf,
+    },
+    /// A file couldn't be read or parsed, like the bridge crate or `diplomat.toml`
+    Load { path: PathBuf, message: String },
+    /// No backend is registered for the target, or `diplomat.toml` has no target by that name
+    UnknownTarget {
+        target: String,
+        /// The names that would have been found
+        available: Vec<String>,
+    },
+    /// The bridge crate couldn't be lowered to the HIR
+    Lowering {
+        errors: Vec<ErrorAndContext>,
+        /// The sources the bridge crate was parsed from, to render the errors with
+        diagnostics: Diagnostics,
+    },
+    /// The backend found errors whilst generating code. Nothing was written.
+    Backend { target: String, errors: Errors },
+    /// A `--cfg` isn't `name` or `name="value"`
+    InvalidCfg { cfg: String, message: String },
+    /// The bridge crate uses types from other crates, which the backend cannot import
+    ExternalTypesUnsupported { target: String },
+    /// The function table cannot keep the layout of its layout file
+    ApiLayout { path: PathBuf, errors: Vec<String> },
+    /// Docs were requested from a backend that cannot generate them
+    DocsUnsupported { target: String },
+    /// `--check` found files on disk that don't match what would be generated
+    OutOfDate(Vec<Mismatch>),
+    /// Reading or writing the generated files failed
+    Io(io::Error),
+    /// Watching the bridge crate for changes failed
+    Watch(notify::Error),
+}
+
+impl Error {
+    pub(crate) fn missing_path(message: &'static str, path: &Path) -> Self {
+        let path = match std::env::current_dir() {
+            Ok(current_dir) => current_dir.join(path),
+            Err(_) => path.to_owned(),
+        };
+        Error::MissingPath { message, path }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::MissingPath { message, path } => write!(f, "{message}\n{}", path.display()),
+            Error::Load { path, message } => {
+                write!(f, "Could not load {}: {message}", path.display())
+            }
+            Error::UnknownTarget { target, available } => write!(
+                f,
+                "Unknown target {target}, expected one of: {}",
+                available.join(", ")
+            ),
+            Error::Lowering { errors, .. } => {
+                write!(f, "Found errors whilst lowering the bridge crate:")?;
+                for (context, error) in errors {
+                    write!(f, "\n\t{context}: {error}")?;
+                }
+                Ok(())
+            }
+            Error::Backend { target, errors } => {
+                write!(f, "Found errors whilst generating {target}:")?;
+                for (context, error) in errors {
+                    write!(f, "\n\t{context}: {error}")?;
+                }
+                write!(f, "\nNot generating files due to errors")
+            }
+            Error::InvalidCfg { cfg, message } => write!(f, "Invalid cfg `{cfg}`: {message}"),
+            Error::ExternalTypesUnsupported { target } => write!(
+                f,
+                "The {target} backend cannot use types from the bridges of other crates"
+            ),
+            Error::ApiLayout { path, errors } => {
+                write!(f, "The API table doesn't match the layout in {}:", path.display())?;
+                for error in errors {
+                    write!(f, "\n\t{error}")?;
+                }
+                Ok(())
+            }
+            Error::DocsUnsupported { target } => {
+                write!(f, "The {target} backend cannot generate docs")
+            }
+            Error::OutOfDate(mismatches) => {
+                write!(f, "The generated bindings are out of date:")?;
+                for mismatch in mismatches {
+                    write!(f, "\n{mismatch}")?;
+                }
+                Ok(())
+            }
+            Error::Io(e) => e.fmt(f),
+            Error::Watch(e) => write!(f, "Could not watch the bridge crate: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            Error::Watch(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+impl From<notify::Error> for Error {
+    fn from(e: notify::Error) -> Self {
+        Error::Watch(e)
+    }
+}
@@ -0,0 +1,35 @@
+//! What the library tells its caller about while generating bindings.
+//!
+//! Nothing in the library prints. The entry points pass these to a callback instead,
+//! which `diplomat-tool` itself uses to print progress and the errors found in the
+//! bridge crate. Callers that don't care can pass `&mut |_| {}`.
+
+use crate::diagnostics::Diagnostics;
+use std::path::Path;
+
+/// Something that happened while generating bindings
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Event<'a> {
+    /// Items of a bridge crate had errors. They were left out, and the bindings are
+    /// generated for the rest of the crate.
+    AstErrors {
+        errors: &'a [&'a syn::Error],
+        /// The sources the bridge crate was parsed from, to render the errors with
+        diagnostics: &'a Diagnostics,
+    },
+    /// The files of a target are about to be written
+    Generating {
+        target: &'a str,
+        /// Whether it is the docs rather than the bindings
+        docs: bool,
+    },
+    /// A file was written because it is new or its contents changed
+    Written(&'a Path),
+    /// A file that stopped being generated was deleted
+    Removed(&'a Path),
+    /// The layout file of the API table was updated
+    LayoutUpdated(&'a Path),
+    /// Generated, and waiting for one of this many files to change
+    Watching(usize),
+}
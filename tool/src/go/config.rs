@@ -0,0 +1,18 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct LibraryConfig {
+    /// The name of the generated Go package
+    pub package: String,
+    /// The name of the native library the package links against
+    pub native_lib: String,
+}
+
+impl Default for LibraryConfig {
+    fn default() -> LibraryConfig {
+        LibraryConfig {
+            package: "diplomat".to_owned(),
+            native_lib: "rust".to_owned(),
+        }
+    }
+}
@@ -0,0 +1,344 @@
+//! This module contains functions for formatting types
+
+use crate::c2::CFormatter;
+use diplomat_core::ast::{DocsUrlGenerator, MarkdownStyle};
+use diplomat_core::hir::{self, TypeContext, TypeId};
+use heck::{ToLowerCamelCase, ToUpperCamelCase};
+use std::borrow::Cow;
+
+/// This type mediates all formatting
+///
+/// All identifiers from the HIR should go through here before being formatted
+/// into the output: This makes it easy to handle reserved words or add rename support
+///
+/// If you find yourself needing an identifier formatted in a context not yet available here, please add a new method
+pub(super) struct GoFormatter<'tcx> {
+    c: CFormatter<'tcx>,
+    docs_url_generator: &'tcx DocsUrlGenerator,
+    strip_prefix: Option<String>,
+}
+
+/// Go's keywords
+const KEYWORDS: &[&str] = &[
+    "break",
+    "case",
+    "chan",
+    "const",
+    "continue",
+    "default",
+    "defer",
+    "else",
+    "fallthrough",
+    "for",
+    "func",
+    "go",
+    "goto",
+    "if",
+    "import",
+    "interface",
+    "map",
+    "package",
+    "range",
+    "return",
+    "select",
+    "struct",
+    "switch",
+    "type",
+    "var",
+];
+/// Names used by the generated code itself, which parameters must not shadow
+const RESERVED_NAMES: &[&str] = &[
+    "keep",
+    "result",
+    "writeable",
+    "edges",
+    "self",
+    "native",
+    "runtime",
+    "any",
+    "bool",
+    "byte",
+    "rune",
+    "string",
+    "int",
+    "int8",
+    "int16",
+    "int32",
+    "int64",
+    "uint",
+    "uint8",
+    "uint16",
+    "uint32",
+    "uint64",
+    "float32",
+    "float64",
+    "nil",
+    "true",
+    "false",
+];
+
+impl<'tcx> GoFormatter<'tcx> {
+    pub fn new(
+        tcx: &'tcx TypeContext,
+        docs_url_generator: &'tcx DocsUrlGenerator,
+        strip_prefix: Option<String>,
+    ) -> Self {
+        Self {
+            c: CFormatter::new(tcx),
+            docs_url_generator,
+            strip_prefix,
+        }
+    }
+
+    /// Format docs as the contents of a documentation comment
+    pub fn fmt_docs(&self, docs: &hir::Docs) -> String {
+        docs.to_markdown(self.docs_url_generator, MarkdownStyle::Normal)
+            .trim()
+            .replace(" \n", "\n")
+            .replace(
+                &format!("`{}", self.strip_prefix.as_deref().unwrap_or("")),
+                "`",
+            )
+    }
+
+    /// The path of the file holding a type
+    ///
+    /// Go gives suffixes like `_test` and `_windows` special meaning, so the
+    /// name is lowercased without adding underscores.
+    pub fn fmt_file_path(&self, id: TypeId) -> String {
+        format!("{}.go", self.fmt_type_name(id).to_lowercase())
+    }
+
+    /// The path of a C header, relative to the package
+    pub fn fmt_header_path(&self, file_name: &str) -> String {
+        format!("include/{file_name}")
+    }
+
+    /// The C header declaring the functions of a type
+    pub fn fmt_impl_header_name(&self, id: TypeId) -> String {
+        self.c.fmt_impl_header_path(id)
+    }
+
+    pub fn fmt_destructor_name(&self, id: TypeId) -> String {
+        self.c.fmt_dtor_name(id)
+    }
+
+    pub fn fmt_c_method_name<'a>(&self, ty: TypeId, method: &'a hir::Method) -> Cow<'a, str> {
+        self.c.fmt_method_name(ty, method, true).into()
+    }
+
+    /// Resolve and format a named type for use in code
+    pub fn fmt_type_name(&self, id: TypeId) -> Cow<'tcx, str> {
+        let resolved = self.c.tcx().resolve_type(id);
+
+        let candidate: Cow<str> = if let Some(strip_prefix) = self.strip_prefix.as_ref() {
+            resolved
+                .name()
+                .as_str()
+                .strip_prefix(strip_prefix)
+                .unwrap_or(resolved.name().as_str())
+                .into()
+        } else {
+            resolved.name().as_str().into()
+        };
+
+        resolved.attrs().rename.apply(candidate)
+    }
+
+    /// Resolve and format a named type for use in diagnostics
+    /// (don't apply rename rules and such)
+    pub fn fmt_type_name_diagnostics(&self, id: TypeId) -> Cow<'tcx, str> {
+        self.c.fmt_type_name_diagnostics(id)
+    }
+
+    /// Format the C type of a named type, as cgo exposes it
+    pub fn fmt_ffi_type_name(&self, id: TypeId) -> String {
+        format!("C.{}", self.c.fmt_type_name(id))
+    }
+
+    /// The unexported function wrapping a C value of a type in its Go type
+    pub fn fmt_wrap_fn_name(&self, id: TypeId) -> String {
+        format!("new{}", self.fmt_type_name(id))
+    }
+
+    /// The function a constructor becomes
+    pub fn fmt_constructor_fn_name(&self, type_name: &str) -> String {
+        format!("New{type_name}")
+    }
+
+    /// Go has no static methods, so they become functions prefixed with the type name
+    pub fn fmt_static_fn_name(&self, type_name: &str, method_name: &str) -> String {
+        format!("{type_name}{method_name}")
+    }
+
+    /// Format an enum variant, which is a constant prefixed with the type name
+    pub fn fmt_enum_variant(&self, type_name: &str, variant: &'tcx hir::EnumVariant) -> String {
+        let name = variant.attrs.rename.apply(variant.name.as_str().into());
+        format!("{type_name}{}", name.to_upper_camel_case())
+    }
+
+    /// Format a field name, which is exported
+    pub fn fmt_field_name(&self, ident: &str) -> String {
+        ident.to_upper_camel_case()
+    }
+
+    /// Format the name of a field of a C struct, which cgo prefixes with
+    /// an underscore if it is a Go keyword
+    pub fn fmt_ffi_field_name(&self, ident: &str) -> String {
+        let name = self.c.fmt_param_name(ident);
+        if KEYWORDS.contains(&&*name) {
+            format!("_{name}")
+        } else {
+            name.into_owned()
+        }
+    }
+
+    /// Format a parameter name, avoiding keywords and the names generated methods use
+    pub fn fmt_param_name(&self, ident: &str) -> String {
+        let name = ident.to_lower_camel_case();
+        if KEYWORDS.contains(&name.as_str()) || RESERVED_NAMES.contains(&name.as_str()) {
+            format!("{name}_")
+        } else {
+            name
+        }
+    }
+
+    /// Format a method, which is exported
+    pub fn fmt_method_name(&self, method: &hir::Method) -> String {
+        method
+            .attrs
+            .rename
+            .apply(method.name.as_str().into())
+            .to_upper_camel_case()
+    }
+
+    pub fn fmt_constructor_name(&self, name: &Option<String>, method: &hir::Method) -> String {
+        method
+            .attrs
+            .rename
+            .apply(name.as_deref().unwrap_or(method.name.as_str()).into())
+            .to_upper_camel_case()
+    }
+
+    pub fn fmt_accessor_name(&self, name: &Option<String>, method: &hir::Method) -> String {
+        method
+            .attrs
+            .rename
+            .apply(name.as_deref().unwrap_or(method.name.as_str()).into())
+            .to_upper_camel_case()
+    }
+
+    /// Format the name of a const, which is prefixed with the name of the type
+    /// it belongs to
+    pub fn fmt_const_name(&self, type_name: Option<&str>, def: &hir::ConstDef) -> String {
+        format!(
+            "{}{}",
+            type_name.unwrap_or(""),
+            def.attrs
+                .rename
+                .apply(def.name.as_str().into())
+                .to_upper_camel_case()
+        )
+    }
+
+    /// Format the value of a const as a Go literal
+    pub fn fmt_const_value(&self, def: &hir::ConstDef) -> String {
+        match def.value {
+            hir::ConstValue::Bool(b) => b.to_string(),
+            hir::ConstValue::Str(ref s) => self.fmt_string_literal(s),
+            hir::ConstValue::Int(ref n) | hir::ConstValue::Float(ref n) => n.clone(),
+            _ => unreachable!("unknown AST/HIR variant"),
+        }
+    }
+
+    /// The Go type of a const
+    pub fn fmt_const_type(&self, def: &hir::ConstDef) -> &'static str {
+        match def.ty {
+            hir::ConstType::Primitive(p) => self.fmt_primitive_as_go(p),
+            hir::ConstType::Str => self.fmt_string(),
+            _ => unreachable!("unknown AST/HIR variant"),
+        }
+    }
+
+    /// Format a string as a Go string literal
+    pub fn fmt_string_literal(&self, s: &str) -> String {
+        let mut out = String::with_capacity(s.len() + 2);
+        out.push('"');
+        for c in s.chars() {
+            match c {
+                '"' | '\\' => {
+                    out.push('\\');
+                    out.push(c);
+                }
+                '\n' => out.push_str("\\n"),
+                '\r' => out.push_str("\\r"),
+                '\t' => out.push_str("\\t"),
+                c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+                c => out.push(c),
+            }
+        }
+        out.push('"');
+        out
+    }
+
+    /// The Go type of a primitive
+    pub fn fmt_primitive_as_go(&self, prim: hir::PrimitiveType) -> &'static str {
+        use diplomat_core::hir::{FloatType, IntSizeType, IntType, PrimitiveType};
+        match prim {
+            PrimitiveType::Bool => "bool",
+            PrimitiveType::Char => "rune",
+            PrimitiveType::Int(IntType::I8) => "int8",
+            PrimitiveType::Int(IntType::U8) | PrimitiveType::Byte => "uint8",
+            PrimitiveType::Int(IntType::I16) => "int16",
+            PrimitiveType::Int(IntType::U16) => "uint16",
+            PrimitiveType::Int(IntType::I32) => "int32",
+            PrimitiveType::Int(IntType::U32) => "uint32",
+            PrimitiveType::Int(IntType::I64) => "int64",
+            PrimitiveType::Int(IntType::U64) => "uint64",
+            PrimitiveType::IntSize(IntSizeType::Isize) => "int",
+            PrimitiveType::IntSize(IntSizeType::Usize) => "uint",
+            PrimitiveType::Float(FloatType::F32) => "float32",
+            PrimitiveType::Float(FloatType::F64) => "float64",
+            PrimitiveType::Int128(_) => panic!("i128 not supported in Go"),
+        }
+    }
+
+    /// The C type of a primitive, as cgo exposes it
+    pub fn fmt_primitive_as_ffi(&self, prim: hir::PrimitiveType) -> String {
+        format!("C.{}", self.c.fmt_primitive_as_c(prim))
+    }
+
+    /// The runtime's named C struct with the same layout as the anonymous
+    /// struct a slice is returned in
+    pub fn fmt_slice_view(&self, slice: &hir::Slice) -> &'static str {
+        use diplomat_core::hir::{FloatType, IntSizeType, IntType, PrimitiveType};
+        match slice {
+            hir::Slice::Str(_, hir::StringEncoding::UnvalidatedUtf16) => "C.DiplomatU16StringView",
+            hir::Slice::Str(..) => "C.DiplomatStringView",
+            hir::Slice::Primitive(_, prim) => match prim {
+                PrimitiveType::Bool => "C.DiplomatBoolView",
+                PrimitiveType::Char => "C.DiplomatCharView",
+                PrimitiveType::Int(IntType::I8) => "C.DiplomatI8View",
+                PrimitiveType::Int(IntType::U8) | PrimitiveType::Byte => "C.DiplomatU8View",
+                PrimitiveType::Int(IntType::I16) => "C.DiplomatI16View",
+                PrimitiveType::Int(IntType::U16) => "C.DiplomatU16View",
+                PrimitiveType::Int(IntType::I32) => "C.DiplomatI32View",
+                PrimitiveType::Int(IntType::U32) => "C.DiplomatU32View",
+                PrimitiveType::Int(IntType::I64) => "C.DiplomatI64View",
+                PrimitiveType::Int(IntType::U64) => "C.DiplomatU64View",
+                PrimitiveType::IntSize(IntSizeType::Isize) => "C.DiplomatIsizeView",
+                PrimitiveType::IntSize(IntSizeType::Usize) => "C.DiplomatUsizeView",
+                PrimitiveType::Float(FloatType::F32) => "C.DiplomatF32View",
+                PrimitiveType::Float(FloatType::F64) => "C.DiplomatF64View",
+                PrimitiveType::Int128(_) => panic!("i128 not supported in Go"),
+            },
+            hir::Slice::Strs(hir::StringEncoding::UnvalidatedUtf16) => "C.DiplomatStrs16View",
+            hir::Slice::Strs(_) => "C.DiplomatStrs8View",
+            _ => unreachable!("unknown AST/HIR variant"),
+        }
+    }
+
+    pub fn fmt_string(&self) -> &'static str {
+        "string"
+    }
+}
@@ -0,0 +1,1062 @@
+//! Generates a Go package that calls the C API through cgo.
+//!
+//! Opaques become structs owning a pointer with a finalizer that destroys it, structs
+//! are copied to and from their C layout, and fallible methods return an `error`.
+//!
+//! Known limitations: traits and enums whose variants carry fields are not supported,
+//! and generating a bridge that uses them is an error. The C headers pass the fields of
+//! such enums in an anonymous union, which cgo only exposes as an opaque byte array.
+//! Disable them for Go with `#[diplomat::attr(go, disable)]`.
+
+use crate::common::{ErrorStore, FileMap};
+use askama::Template;
+use config::LibraryConfig;
+use diplomat_core::ast::DocsUrlGenerator;
+use diplomat_core::hir::borrowing_param::ParamBorrowInfo;
+use diplomat_core::hir::TypeContext;
+use diplomat_core::hir::{
+    self, MaybeStatic, OpaqueOwner, ReturnType, SelfType, SpecialMethod, StructPathLike,
+    SuccessType, TyPosition, Type, TypeDef, TypeId,
+};
+use formatter::GoFormatter;
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::Display;
+
+#[cfg(test)]
+#[macro_use]
+mod test_util;
+
+pub mod config;
+mod formatter;
+
+/// The header declaring the parts of the Rust runtime the C headers leave out
+const RUNTIME_HEADER: &str = "diplomat_go_runtime.h";
+
+/// Run file generation
+///
+/// `c_files` are the headers generated by the C backend, which the package
+/// includes through cgo.
+pub fn run<'cx>(
+    tcx: &'cx TypeContext,
+    c_files: HashMap<String, String>,
+    library_config: LibraryConfig,
+    docs_url_generator: &'cx DocsUrlGenerator,
+    strip_prefix: Option<String>,
+) -> Result<FileMap, Vec<(impl Display + 'cx, String)>> {
+    let formatter = GoFormatter::new(tcx, docs_url_generator, strip_prefix);
+
+    let files = FileMap::default();
+    let errors = ErrorStore::default();
+
+    for (name, contents) in c_files {
+        files.add_file(formatter.fmt_header_path(&name), contents);
+    }
+    files.add_file(
+        formatter.fmt_header_path(RUNTIME_HEADER),
+        include_str!("../../templates/go/diplomat_go_runtime.h").into(),
+    );
+
+    let mut tgcx = TyGenContext {
+        tcx,
+        errors: &errors,
+        formatter: &formatter,
+        imports: BTreeSet::new(),
+    };
+
+    for (id, ty) in tcx.all_types() {
+        if ty.attrs().disable {
+            continue;
+        }
+
+        let body = tgcx.gen(id);
+        files.add_file(
+            formatter.fmt_file_path(id),
+            FileTemplate {
+                package: &library_config.package,
+                header: Some(formatter.fmt_impl_header_name(id)),
+                imports: std::mem::take(&mut tgcx.imports),
+                body,
+            }
+            .render()
+            .unwrap(),
+        );
+    }
+
+    for (_, trt) in tcx.all_traits() {
+        if !trt.attrs.disable {
+            let _guard = errors.set_context_ty(trt.name.as_str().into());
+            errors.push_error(format!(
+                "Traits are not supported in Go yet, found {}",
+                trt.name
+            ));
+        }
+    }
+
+    if let Some(body) = tgcx.gen_consts(tcx.consts(), None) {
+        files.add_file(
+            "consts.go".into(),
+            FileTemplate {
+                package: &library_config.package,
+                header: None,
+                imports: BTreeSet::new(),
+                body,
+            }
+            .render()
+            .unwrap(),
+        );
+    }
+
+    #[derive(Template)]
+    #[template(path = "go/runtime.go.jinja", escape = "none")]
+    struct RuntimeTemplate<'a> {
+        package: &'a str,
+        native_lib: &'a str,
+        catches_panics: bool,
+    }
+
+    files.add_file(
+        "runtime.go".into(),
+        RuntimeTemplate {
+            package: &library_config.package,
+            native_lib: &library_config.native_lib,
+            catches_panics: tcx.catches_panics(),
+        }
+        .render()
+        .unwrap(),
+    );
+
+    let errors = errors.take_all();
+    if !errors.is_empty() {
+        Err(errors)
+    } else {
+        Ok(files)
+    }
+}
+
+#[derive(Template)]
+#[template(path = "go/file.go.jinja", escape = "none")]
+struct FileTemplate<'a> {
+    package: &'a str,
+    /// The C header cgo includes, if the file calls into C
+    header: Option<String>,
+    imports: BTreeSet<&'static str>,
+    body: String,
+}
+
+struct TyGenContext<'a, 'cx> {
+    tcx: &'cx TypeContext,
+    formatter: &'a GoFormatter<'cx>,
+    errors: &'a ErrorStore<'cx, String>,
+    /// The packages the file currently being generated imports
+    imports: BTreeSet<&'static str>,
+}
+
+impl<'a, 'cx> TyGenContext<'a, 'cx> {
+    /// Generates the Go code of a type
+    fn gen(&mut self, id: TypeId) -> String {
+        let ty = self.tcx.resolve_type(id);
+
+        let _guard = self.errors.set_context_ty(ty.name().as_str().into());
+
+        let name = self.formatter.fmt_type_name(id);
+        let (def, methods, consts) = match ty {
+            TypeDef::Enum(e) if e.is_data_carrying() => {
+                self.errors.push_error(
+                    "Enums whose variants carry fields are not supported in Go yet, \
+                     disable them with #[diplomat::attr(go, disable)]"
+                        .into(),
+                );
+                return String::new();
+            }
+            TypeDef::Enum(e) => (self.gen_enum(e, &name), &e.methods, &e.consts),
+            TypeDef::Opaque(o) => (self.gen_opaque_def(o, id, &name), &o.methods, &o.consts),
+            TypeDef::Struct(s) => (self.gen_struct_def(s, id, &name), &s.methods, &s.consts),
+            TypeDef::OutStruct(s) => (self.gen_struct_def(s, id, &name), &s.methods, &s.consts),
+            _ => unreachable!("unknown AST/HIR variant"),
+        };
+
+        let mut out = vec![def];
+        out.extend(self.gen_consts(consts, Some(&name)));
+        out.extend(
+            methods
+                .iter()
+                .filter_map(|method| self.gen_method(id, method, &name)),
+        );
+        out.join("\n\n")
+    }
+
+    /// Generates a block of consts, which are prefixed with the name of the
+    /// type they belong to
+    fn gen_consts(&self, consts: &[hir::ConstDef], type_name: Option<&str>) -> Option<String> {
+        if consts.is_empty() {
+            return None;
+        }
+
+        #[derive(Template)]
+        #[template(path = "go/consts.go.jinja", escape = "none")]
+        struct ConstsTemplate {
+            consts: Vec<ConstInfo>,
+        }
+
+        let mut consts = consts
+            .iter()
+            .map(|def| ConstInfo {
+                name: self.formatter.fmt_const_name(type_name, def),
+                docs: self.formatter.fmt_docs(&def.docs),
+                ty: self.formatter.fmt_const_type(def).into(),
+                value: self.formatter.fmt_const_value(def),
+            })
+            .collect::<Vec<_>>();
+        let padded = align(
+            &consts
+                .iter()
+                .map(|c| (!c.docs.is_empty(), c.name.as_str()))
+                .collect::<Vec<_>>(),
+        );
+        let padded_types = align(
+            &consts
+                .iter()
+                .map(|c| (!c.docs.is_empty(), c.ty.as_str()))
+                .collect::<Vec<_>>(),
+        );
+        for ((c, name), ty) in consts.iter_mut().zip(padded).zip(padded_types) {
+            c.name = name;
+            c.ty = ty;
+        }
+
+        Some(ConstsTemplate { consts }.render().unwrap())
+    }
+
+    fn gen_enum(&mut self, ty: &'cx hir::EnumDef, type_name: &str) -> String {
+        let mut variants = ty
+            .variants
+            .iter()
+            .map(|variant| EnumVariantInfo {
+                name: self.formatter.fmt_enum_variant(type_name, variant),
+                discriminant: variant.discriminant,
+                docs: self.formatter.fmt_docs(&variant.docs),
+            })
+            .collect::<Vec<_>>();
+        let padded = align(
+            &variants
+                .iter()
+                .map(|v| (!v.docs.is_empty(), v.name.as_str()))
+                .collect::<Vec<_>>(),
+        );
+        for (variant, name) in variants.iter_mut().zip(padded) {
+            variant.name = name;
+        }
+
+        #[derive(Template)]
+        #[template(path = "go/enum.go.jinja", escape = "none")]
+        struct ImplTemplate<'a> {
+            type_name: &'a str,
+            docs: String,
+            variants: Vec<EnumVariantInfo>,
+        }
+
+        ImplTemplate {
+            type_name,
+            docs: self.formatter.fmt_docs(&ty.docs),
+            variants,
+        }
+        .render()
+        .unwrap()
+    }
+
+    fn gen_opaque_def(&mut self, ty: &'cx hir::OpaqueDef, id: TypeId, type_name: &str) -> String {
+        self.imports.insert("runtime");
+
+        #[derive(Template)]
+        #[template(path = "go/opaque.go.jinja", escape = "none")]
+        struct ImplTemplate<'a> {
+            type_name: &'a str,
+            docs: String,
+            ffi_name: String,
+            wrap_fn: String,
+            destructor: String,
+        }
+
+        ImplTemplate {
+            type_name,
+            docs: self.formatter.fmt_docs(&ty.docs),
+            ffi_name: self.formatter.fmt_ffi_type_name(id),
+            wrap_fn: self.formatter.fmt_wrap_fn_name(id),
+            destructor: self.formatter.fmt_destructor_name(id),
+        }
+        .render()
+        .unwrap()
+    }
+
+    fn gen_struct_def<P: TyPosition>(
+        &mut self,
+        ty: &'cx hir::StructDef<P>,
+        id: TypeId,
+        type_name: &str,
+    ) -> String {
+        let mut fields = ty
+            .fields
+            .iter()
+            .map(|field| {
+                let name = self.formatter.fmt_field_name(field.name.as_str());
+                let ffi_field = format!(
+                    "native.{}",
+                    self.formatter.fmt_ffi_field_name(field.name.as_str())
+                );
+                FieldInfo {
+                    docs: self.formatter.fmt_docs(&field.docs),
+                    go_type: self.gen_type_name(&field.ty),
+                    go_to_native: self.gen_field_to_native(
+                        &field.ty,
+                        &format!("self.{name}"),
+                        &ffi_field,
+                    ),
+                    native_to_go: self.gen_native_to_go(&field.ty, &ffi_field, "edges"),
+                    padded_name: name.clone(),
+                    padded_key: format!("{name}:"),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // Line up the columns like gofmt does
+        let padded_names = align(
+            &fields
+                .iter()
+                .map(|f| (!f.docs.is_empty(), f.padded_name.as_str()))
+                .collect::<Vec<_>>(),
+        );
+        let padded_keys = align(
+            &fields
+                .iter()
+                .map(|f| (false, f.padded_key.as_str()))
+                .collect::<Vec<_>>(),
+        );
+        for ((field, name), key) in fields.iter_mut().zip(padded_names).zip(padded_keys) {
+            field.padded_name = name;
+            field.padded_key = key;
+        }
+
+        #[derive(Template)]
+        #[template(path = "go/struct.go.jinja", escape = "none")]
+        struct ImplTemplate<'a> {
+            type_name: &'a str,
+            docs: String,
+            ffi_name: String,
+            wrap_fn: String,
+            fields: Vec<FieldInfo>,
+        }
+
+        ImplTemplate {
+            type_name,
+            docs: self.formatter.fmt_docs(&ty.docs),
+            ffi_name: self.formatter.fmt_ffi_type_name(id),
+            wrap_fn: self.formatter.fmt_wrap_fn_name(id),
+            fields,
+        }
+        .render()
+        .unwrap()
+    }
+
+    fn gen_method(
+        &mut self,
+        id: TypeId,
+        method: &'cx hir::Method,
+        type_name: &str,
+    ) -> Option<String> {
+        if method.attrs.disable {
+            return None;
+        }
+
+        let mut visitor = method.borrowing_param_visitor(self.tcx);
+
+        let _guard = self.errors.set_context_method(
+            self.formatter.fmt_type_name_diagnostics(id),
+            method.name.as_str().into(),
+        );
+
+        let c_method_name = self.formatter.fmt_c_method_name(id, method);
+
+        let mut params = Vec::new();
+        let mut args = Vec::new();
+        // Slices are converted before the call
+        let mut before = Vec::new();
+        // Opaques must not be destroyed by their finalizers during the call
+        let mut keep_alive = Vec::new();
+        // Everything the return value may borrow from
+        let mut edges = Vec::new();
+        let mut needs_keep = false;
+        // Whether the return value borrows memory allocated in `keep`
+        let mut keep_is_borrowed = false;
+
+        if let Some(param_self) = method.param_self.as_ref() {
+            let borrow = visitor.visit_param(&param_self.ty.clone().into(), "self");
+            let arg = match param_self.ty {
+                SelfType::Opaque(_) => {
+                    keep_alive.push("self".to_owned());
+                    "self.handle".to_owned()
+                }
+                SelfType::Struct(_) => {
+                    needs_keep = true;
+                    keep_is_borrowed |= matches!(borrow, ParamBorrowInfo::Struct(_));
+                    "self.toNative(keep)".to_owned()
+                }
+                SelfType::Enum(ref e) => {
+                    format!(
+                        "{}(self)",
+                        self.formatter.fmt_ffi_type_name(e.tcx_id.into())
+                    )
+                }
+                _ => unreachable!("unknown AST/HIR variant"),
+            };
+            args.push(arg);
+            if !matches!(borrow, ParamBorrowInfo::NotBorrowed) {
+                edges.push("self".to_owned());
+            }
+        }
+
+        for param in method.params.iter() {
+            let param_name = self.formatter.fmt_param_name(param.name.as_str());
+            let borrow = visitor.visit_param(&param.ty, &param_name);
+            params.push(format!("{param_name} {}", self.gen_type_name(&param.ty)));
+
+            match param.ty {
+                Type::Slice(slice) => {
+                    // Two args on the ABI: pointer and length
+                    let keep = match (&borrow, slice.lifetime()) {
+                        // Rust frees owned slices itself, and never frees static ones
+                        (_, None | Some(MaybeStatic::Static)) => Some("nil"),
+                        // Memory Rust only reads during the call can be Go's own,
+                        // unless it holds pointers
+                        (ParamBorrowInfo::TemporarySlice, _)
+                            if !matches!(slice, hir::Slice::Strs(_)) =>
+                        {
+                            None
+                        }
+                        // Memory borrowed past the call can't be Go's, which
+                        // may move or free it
+                        _ => {
+                            needs_keep = true;
+                            keep_is_borrowed |= matches!(borrow, ParamBorrowInfo::BorrowedSlice);
+                            Some("keep")
+                        }
+                    };
+                    let conversion = self.gen_slice_to_native(&slice, &param_name, keep);
+                    before.push(format!("{param_name}Data, {param_name}Len := {conversion}"));
+                    args.push(format!("{param_name}Data"));
+                    args.push(format!("{param_name}Len"));
+                }
+                Type::Opaque(_) => {
+                    keep_alive.push(param_name.clone());
+                    args.push(self.gen_go_to_native(&param.ty, &param_name));
+                }
+                Type::Struct(_) => {
+                    needs_keep = true;
+                    keep_is_borrowed |= matches!(borrow, ParamBorrowInfo::Struct(_));
+                    args.push(self.gen_go_to_native(&param.ty, &param_name));
+                }
+                _ => args.push(self.gen_go_to_native(&param.ty, &param_name)),
+            }
+
+            // Borrowed slices live in `keep`
+            if !matches!(
+                borrow,
+                ParamBorrowInfo::NotBorrowed
+                    | ParamBorrowInfo::TemporarySlice
+                    | ParamBorrowInfo::BorrowedSlice
+            ) {
+                edges.push(param_name);
+            }
+        }
+
+        let mut body = Vec::new();
+        if needs_keep {
+            if keep_is_borrowed {
+                body.push("keep := newDiplomatKeep()".to_owned());
+                edges.push("keep".into());
+            } else {
+                body.push("keep := &diplomatKeep{}".to_owned());
+                body.push("defer keep.free()".to_owned());
+            }
+        }
+        if method.output.is_writeable() {
+            body.push("writeable := newDiplomatWriteable()".into());
+            body.push("defer writeable.free()".into());
+            args.push("writeable.handle".into());
+        }
+        body.extend(before);
+        // Methods returning a `Result` get the panic as their error
+        let checks_panic =
+            method.attrs.catch_panics && !matches!(method.output, ReturnType::Fallible(..));
+        if checks_panic {
+            // The panic is kept on the thread the call ran on
+            self.imports.insert("runtime");
+            body.push("runtime.LockOSThread()".into());
+            body.push("defer runtime.UnlockOSThread()".into());
+        }
+        let call = format!("C.{c_method_name}({})", args.join(", "));
+        if method.output.is_ffi_unit() {
+            body.push(call);
+        } else {
+            body.push(format!("result := {call}"));
+        }
+        if checks_panic {
+            body.push("diplomatCheckPanic()".into());
+        }
+        if !keep_alive.is_empty() {
+            self.imports.insert("runtime");
+        }
+        for value in keep_alive {
+            body.push(format!("runtime.KeepAlive({value})"));
+        }
+
+        let edges = if edges.is_empty() {
+            "nil".to_owned()
+        } else {
+            format!("[]any{{{}}}", edges.join(", "))
+        };
+        body.extend(self.gen_return(&method.output, &edges));
+
+        let is_static = method.param_self.is_none();
+        let name = match method.attrs.special_method {
+            Some(SpecialMethod::Constructor) => self.formatter.fmt_constructor_fn_name(type_name),
+            Some(SpecialMethod::NamedConstructor(ref name)) => self.formatter.fmt_static_fn_name(
+                type_name,
+                &self.formatter.fmt_constructor_name(name, method),
+            ),
+            Some(SpecialMethod::Getter(ref name)) => self.formatter.fmt_accessor_name(name, method),
+            Some(SpecialMethod::Setter(ref name)) => {
+                format!("Set{}", self.formatter.fmt_accessor_name(name, method))
+            }
+            // Implements `fmt.Stringer`
+            Some(SpecialMethod::Stringifier)
+                if !matches!(method.output, ReturnType::Fallible(..)) =>
+            {
+                "String".into()
+            }
+            Some(SpecialMethod::Iterator) => "Next".into(),
+            _ => self.formatter.fmt_method_name(method),
+        };
+        let name = match method.attrs.special_method {
+            Some(SpecialMethod::Constructor | SpecialMethod::NamedConstructor(_)) => name,
+            _ if is_static => self.formatter.fmt_static_fn_name(type_name, &name),
+            _ => name,
+        };
+
+        let return_ty = self.gen_return_type_name(&method.output);
+        let returns = if return_ty.is_empty() {
+            String::new()
+        } else {
+            format!(" {return_ty}")
+        };
+        let decl = if is_static {
+            format!("func {name}({}){returns}", params.join(", "))
+        } else {
+            let receiver = match id {
+                TypeId::Opaque(_) => format!("*{type_name}"),
+                _ => type_name.to_owned(),
+            };
+            format!(
+                "func (self {receiver}) {name}({}){returns}",
+                params.join(", ")
+            )
+        };
+
+        let mut docs = self.formatter.fmt_docs(&method.docs);
+        if let ReturnType::Fallible(_, Some(e)) = &method.output {
+            if !docs.is_empty() {
+                docs.push_str("\n\n");
+            }
+            docs.push_str(&format!(
+                "Failures are returned as a `*DiplomatError` holding a `{}`.",
+                self.gen_type_name(e)
+            ));
+        }
+        if checks_panic {
+            if !docs.is_empty() {
+                docs.push_str("\n\n");
+            }
+            docs.push_str("Panics with a `*DiplomatPanic` if the Rust method panics.");
+        }
+
+        Some(
+            MethodTemplate {
+                m: &MethodInfo { decl, docs, body },
+            }
+            .render()
+            .unwrap(),
+        )
+    }
+
+    /// Generates the statements converting the native return value of a
+    /// method, which is saved in `result` (or `writeable`), into the Go one.
+    fn gen_return(&mut self, output: &'cx ReturnType, edges: &str) -> Vec<String> {
+        let mut out = Vec::new();
+        match output {
+            ReturnType::Infallible(SuccessType::Unit) => {}
+            ReturnType::Infallible(SuccessType::Writeable) => {
+                out.push("return writeable.String()".into())
+            }
+            ReturnType::Infallible(SuccessType::OutType(o)) => out.push(format!(
+                "return {}",
+                self.gen_native_to_go(o, "result", edges)
+            )),
+            ReturnType::Nullable(SuccessType::Unit) => out.push("return result.is_ok".into()),
+            ReturnType::Fallible(ok, _) | ReturnType::Nullable(ok) => {
+                // The second value returned on success
+                let success = if let ReturnType::Nullable(_) = output {
+                    "true"
+                } else {
+                    "nil"
+                };
+                let failure = match output {
+                    ReturnType::Fallible(_, Some(e)) => format!(
+                        "&DiplomatError{{Value: {}}}",
+                        self.gen_native_to_go(e, "err", edges)
+                    ),
+                    ReturnType::Fallible(_, None) => "&DiplomatError{}".into(),
+                    _ => "false".into(),
+                };
+                let zero = match ok {
+                    SuccessType::Unit => None,
+                    SuccessType::Writeable => Some("\"\"".to_owned()),
+                    SuccessType::OutType(o) => Some(self.gen_zero_value(o)),
+                    _ => unreachable!("unknown AST/HIR variant"),
+                };
+
+                out.push("if !result.is_ok {".into());
+                if let ReturnType::Fallible(_, Some(e)) = output {
+                    out.push(format!(
+                        "\terr := diplomatResultValue[{}](&result)",
+                        self.gen_ffi_type_name(e)
+                    ));
+                }
+                match zero {
+                    Some(ref zero) => out.push(format!("\treturn {zero}, {failure}")),
+                    None => out.push(format!("\treturn {failure}")),
+                }
+                out.push("}".into());
+
+                match ok {
+                    SuccessType::Unit => out.push(format!("return {success}")),
+                    SuccessType::Writeable => {
+                        out.push(format!("return writeable.String(), {success}"))
+                    }
+                    SuccessType::OutType(o) => {
+                        out.push(format!(
+                            "ok := diplomatResultValue[{}](&result)",
+                            self.gen_ffi_type_name(o)
+                        ));
+                        out.push(format!(
+                            "return {}, {success}",
+                            self.gen_native_to_go(o, "ok", edges)
+                        ));
+                    }
+                    _ => unreachable!("unknown AST/HIR variant"),
+                }
+            }
+            _ => unreachable!("unknown AST/HIR variant"),
+        }
+        out
+    }
+
+    /// Generates a type's Go type.
+    fn gen_type_name<P: TyPosition>(&mut self, ty: &Type<P>) -> String {
+        match *ty {
+            Type::Primitive(prim) => self.formatter.fmt_primitive_as_go(prim).into(),
+            Type::Opaque(ref op) => format!("*{}", self.gen_named_type(op.tcx_id.into())),
+            Type::Struct(ref st) => self.gen_named_type(st.id()),
+            Type::Enum(ref e) => self.gen_named_type(e.tcx_id.into()),
+            Type::Slice(hir::Slice::Str(..)) => self.formatter.fmt_string().into(),
+            Type::Slice(hir::Slice::Primitive(_, p)) => {
+                format!("[]{}", self.formatter.fmt_primitive_as_go(p))
+            }
+            Type::Slice(hir::Slice::Strs(..)) => format!("[]{}", self.formatter.fmt_string()),
+            Type::Trait(ref t) => {
+                let def = t.resolve(self.tcx);
+                self.errors.push_error(format!(
+                    "Traits are not supported in Go, found {}",
+                    def.name
+                ));
+                "any".into()
+            }
+            Type::Func(..) => {
+                self.errors
+                    .push_error("Callbacks are not supported in Go".into());
+                "any".into()
+            }
+            _ => unreachable!("unknown AST/HIR variant"),
+        }
+    }
+
+    fn gen_named_type(&mut self, id: TypeId) -> String {
+        let type_name = self.formatter.fmt_type_name(id);
+        if self.tcx.resolve_type(id).attrs().disable {
+            self.errors
+                .push_error(format!("Found usage of disabled type {type_name}"))
+        }
+        type_name.into_owned()
+    }
+
+    /// Generates a return type's Go type, which is empty for methods returning nothing.
+    fn gen_return_type_name(&mut self, output: &ReturnType) -> String {
+        let ok = match output.success_type() {
+            SuccessType::Unit => None,
+            SuccessType::Writeable => Some(self.formatter.fmt_string().to_owned()),
+            SuccessType::OutType(o) => Some(self.gen_type_name(o)),
+            _ => unreachable!("unknown AST/HIR variant"),
+        };
+        match (output, ok) {
+            (ReturnType::Infallible(_), ok) => ok.unwrap_or_default(),
+            (ReturnType::Nullable(_), None) => "bool".into(),
+            (ReturnType::Nullable(_), Some(ok)) => format!("({ok}, bool)"),
+            (ReturnType::Fallible(..), None) => "error".into(),
+            (ReturnType::Fallible(..), Some(ok)) => format!("({ok}, error)"),
+        }
+    }
+
+    /// The Go zero value of a type, returned alongside errors
+    fn gen_zero_value<P: TyPosition>(&mut self, ty: &Type<P>) -> String {
+        match *ty {
+            Type::Primitive(hir::PrimitiveType::Bool) => "false".into(),
+            Type::Primitive(_) | Type::Enum(_) => "0".into(),
+            Type::Struct(_) => format!("{}{{}}", self.gen_type_name(ty)),
+            Type::Slice(hir::Slice::Str(..)) => "\"\"".into(),
+            _ => "nil".into(),
+        }
+    }
+
+    /// Generates the C type of a value, as cgo exposes it
+    fn gen_ffi_type_name<P: TyPosition>(&self, ty: &Type<P>) -> String {
+        match *ty {
+            Type::Primitive(prim) => self.formatter.fmt_primitive_as_ffi(prim),
+            Type::Opaque(ref op) => {
+                format!("*{}", self.formatter.fmt_ffi_type_name(op.tcx_id.into()))
+            }
+            Type::Struct(ref st) => self.formatter.fmt_ffi_type_name(st.id()),
+            Type::Enum(ref e) => self.formatter.fmt_ffi_type_name(e.tcx_id.into()),
+            Type::Slice(ref s) => self.formatter.fmt_slice_view(s).into(),
+            _ => "unsafe.Pointer".into(),
+        }
+    }
+
+    /// Generates the Go expression converting a slice into the pointer and
+    /// length Rust reads, which it evaluates to.
+    ///
+    /// With no `keep`, Rust reads Go's memory directly, otherwise the slice is
+    /// copied into `keep` (or Rust's allocator, if it is `nil`).
+    fn gen_slice_to_native(&self, slice: &hir::Slice, value: &str, keep: Option<&str>) -> String {
+        match (slice, keep) {
+            (hir::Slice::Str(_, hir::StringEncoding::UnvalidatedUtf16), None) => {
+                format!("diplomatStr16Ref({value})")
+            }
+            (hir::Slice::Str(_, hir::StringEncoding::UnvalidatedUtf16), Some(keep)) => {
+                format!("diplomatStr16({value}, {keep})")
+            }
+            (hir::Slice::Str(..), None) => format!("diplomatStrRef({value})"),
+            (hir::Slice::Str(..), Some(keep)) => format!("diplomatStr({value}, {keep})"),
+            (hir::Slice::Primitive(_, p), None) => format!(
+                "diplomatSliceRef[{}]({value})",
+                self.formatter.fmt_primitive_as_ffi(*p)
+            ),
+            (hir::Slice::Primitive(_, p), Some(keep)) => format!(
+                "diplomatSlice[{}]({value}, {keep})",
+                self.formatter.fmt_primitive_as_ffi(*p)
+            ),
+            (hir::Slice::Strs(hir::StringEncoding::UnvalidatedUtf16), Some(keep)) => {
+                format!("diplomatStrs16({value}, {keep})")
+            }
+            (hir::Slice::Strs(_), Some(keep)) => format!("diplomatStrs({value}, {keep})"),
+            // Lists of strings hold pointers, so they always live in C memory
+            (hir::Slice::Strs(_), None) => {
+                unreachable!("lists of strings can't be borrowed from Go")
+            }
+            _ => unreachable!("unknown AST/HIR variant"),
+        }
+    }
+
+    /// Generates the statement setting the field `field` of a C struct to `value`
+    fn gen_field_to_native<P: TyPosition>(
+        &mut self,
+        ty: &Type<P>,
+        value: &str,
+        field: &str,
+    ) -> String {
+        match *ty {
+            Type::Slice(ref s) => {
+                // Rust frees owned slices itself, and never frees static ones
+                let keep = match s.lifetime() {
+                    Some(MaybeStatic::NonStatic(_)) => "keep",
+                    _ => "nil",
+                };
+                format!(
+                    "{field}.data, {field}.len = {}",
+                    self.gen_slice_to_native(s, value, Some(keep))
+                )
+            }
+            _ => format!("{field} = {}", self.gen_go_to_native(ty, value)),
+        }
+    }
+
+    /// Generates the Go expression converting a Go value to its C type.
+    ///
+    /// Temporary allocations are added to `keep`.
+    fn gen_go_to_native<P: TyPosition>(&mut self, ty: &Type<P>, value: &str) -> String {
+        match *ty {
+            Type::Primitive(prim) => {
+                format!("{}({value})", self.formatter.fmt_primitive_as_ffi(prim))
+            }
+            Type::Opaque(ref op) if op.is_optional() => format!("{value}.toNative()"),
+            Type::Opaque(_) => format!("{value}.handle"),
+            Type::Struct(_) => format!("{value}.toNative(keep)"),
+            Type::Enum(ref e) => format!(
+                "{}({value})",
+                self.formatter.fmt_ffi_type_name(e.tcx_id.into())
+            ),
+            Type::Slice(ref s) => self.gen_slice_to_native(s, value, Some("keep")),
+            Type::Trait(_) | Type::Func(_) => {
+                // Already reported by gen_type_name
+                "nil".into()
+            }
+            _ => unreachable!("unknown AST/HIR variant"),
+        }
+    }
+
+    /// Generates the Go expression converting a C value to its Go type.
+    ///
+    /// `edges` is a Go slice of everything borrowed values may borrow from.
+    fn gen_native_to_go<P: TyPosition>(
+        &mut self,
+        ty: &Type<P>,
+        value: &str,
+        edges: &str,
+    ) -> String {
+        match *ty {
+            Type::Primitive(prim) => {
+                format!("{}({value})", self.formatter.fmt_primitive_as_go(prim))
+            }
+            Type::Opaque(ref op) => {
+                let owned = op.owner.is_owned();
+                // Owned opaques only need edges for their own lifetimes
+                let edges = if owned && op.lifetimes.lifetimes().len() == 0 {
+                    "nil"
+                } else {
+                    edges
+                };
+                format!(
+                    "{}({value}, {owned}, {edges})",
+                    self.formatter.fmt_wrap_fn_name(op.tcx_id.into())
+                )
+            }
+            Type::Struct(ref st) => {
+                format!(
+                    "{}({value}, {edges})",
+                    self.formatter.fmt_wrap_fn_name(st.id())
+                )
+            }
+            Type::Enum(ref e) => {
+                format!("{}({value})", self.formatter.fmt_type_name(e.tcx_id.into()))
+            }
+            Type::Slice(ref s) => {
+                // Slices without a lifetime are owned, so they are freed after copying
+                let owned = s.lifetime().is_none();
+                match s {
+                    hir::Slice::Str(_, hir::StringEncoding::UnvalidatedUtf16) => {
+                        format!("diplomatReadStr16({value}.data, {value}.len, {owned})")
+                    }
+                    hir::Slice::Str(..) => {
+                        format!("diplomatReadStr({value}.data, {value}.len, {owned})")
+                    }
+                    hir::Slice::Primitive(_, p) => format!(
+                        "diplomatRead[{}]({value}.data, {value}.len, {owned})",
+                        self.formatter.fmt_primitive_as_go(*p)
+                    ),
+                    _ => {
+                        self.errors
+                            .push_error("Returning string lists is not supported".into());
+                        "nil".into()
+                    }
+                }
+            }
+            Type::Trait(_) | Type::Func(_) => "nil".into(),
+            _ => unreachable!("unknown AST/HIR variant"),
+        }
+    }
+}
+
+/// Everything needed for rendering a method.
+struct MethodInfo {
+    /// The declaration, up to the body
+    decl: String,
+    docs: String,
+    /// The statements of the method body
+    body: Vec<String>,
+}
+
+#[derive(Template)]
+#[template(path = "go/method.go.jinja", escape = "none")]
+struct MethodTemplate<'a> {
+    m: &'a MethodInfo,
+}
+
+struct ConstInfo {
+    name: String,
+    docs: String,
+    ty: String,
+    value: String,
+}
+
+struct FieldInfo {
+    docs: String,
+    go_type: String,
+    /// The statement setting the field of the C struct `native`
+    go_to_native: String,
+    native_to_go: String,
+    /// The name, padded to line up the field types
+    padded_name: String,
+    /// The name and a colon, padded to line up the values of a composite literal
+    padded_key: String,
+}
+
+struct EnumVariantInfo {
+    name: String,
+    discriminant: isize,
+    docs: String,
+}
+
+/// Pads names to line up what follows them, like gofmt does.
+///
+/// A documented entry starts a new group of lines that are lined up.
+fn align(entries: &[(bool, &str)]) -> Vec<String> {
+    let mut widths = vec![0; entries.len()];
+    let mut start = 0;
+    for end in 1..=entries.len() {
+        if end == entries.len() || entries[end].0 {
+            let width = entries[start..end]
+                .iter()
+                .map(|(_, name)| name.len())
+                .max()
+                .unwrap_or(0);
+            widths[start..end].fill(width);
+            start = end;
+        }
+    }
+    entries
+        .iter()
+        .zip(widths)
+        .map(|((_, name), width)| format!("{name:width$}"))
+        .collect()
+}
+
+// Helpers used in templates (Askama has restrictions on Rust syntax)
+
+/// Format docs as a comment, including the trailing newline
+fn godoc(docs: &str) -> String {
+    let mut out = String::new();
+    for line in docs.lines() {
+        if line.is_empty() {
+            out.push_str("//\n");
+        } else {
+            out.push_str("// ");
+            out.push_str(line);
+            out.push('\n');
+        }
+    }
+    out
+}
+
+/// Indent every line with a tab, leaving blank lines empty
+fn indent(text: &str) -> String {
+    text.split('\n')
+        .map(|line| {
+            if line.is_empty() {
+                String::new()
+            } else {
+                format!("\t{line}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_strings() {
+        test_file! {
+            #[diplomat::bridge]
+            mod ffi {
+                #[diplomat::opaque]
+                pub struct Name(String);
+
+                impl Name {
+                    pub fn from_utf8(text: &DiplomatStr) -> Box<Name> {
+                        unimplemented!()
+                    }
+                    pub fn from_utf16(text: &DiplomatStr16) -> Box<Name> {
+                        unimplemented!()
+                    }
+                    pub fn from_parts(parts: &[&DiplomatStr]) -> Box<Name> {
+                        unimplemented!()
+                    }
+                    pub fn as_str<'a>(&'a self) -> &'a DiplomatStr {
+                        unimplemented!()
+                    }
+                    pub fn format(&self, write: &mut DiplomatWriteable) {}
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_slices() {
+        test_file! {
+            #[diplomat::bridge]
+            mod ffi {
+                #[diplomat::opaque]
+                pub struct Samples(Vec<f64>);
+
+                impl Samples {
+                    pub fn from_values(values: &[f64]) -> Box<Samples> {
+                        unimplemented!()
+                    }
+                    pub fn scale(values: &mut [f64], factor: f64) {}
+                    pub fn count_set(flags: &[bool]) -> usize {
+                        unimplemented!()
+                    }
+                    pub fn values<'a>(&'a self) -> &'a [f64] {
+                        unimplemented!()
+                    }
+                    pub fn to_bytes(&self) -> Box<[u8]> {
+                        unimplemented!()
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_catch_panics() {
+        test_file! {
+            #[diplomat::bridge]
+            mod ffi {
+                pub enum MyError {
+                    Panicked,
+                    Other,
+                }
+
+                #[diplomat::opaque]
+                pub struct Thing;
+
+                #[diplomat::catch_panics]
+                impl Thing {
+                    pub fn might_panic(x: u32) -> u32 {
+                        unimplemented!()
+                    }
+                    pub fn might_fail(x: u32) -> Result<u32, MyError> {
+                        unimplemented!()
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,705 @@
+//! A stable JSON dump of the HIR, for `diplomat-tool ir`.
+//!
+//! Generators that don't live in this crate can read this instead of linking
+//! against `diplomat_core`. The types here are a mirror of the HIR with every
+//! attribute already resolved for one backend and every ABI symbol already
+//! computed, so that consumers don't need to reimplement any of those rules.
+//!
+//! Types, traits and lifetimes are referred to by name. Lifetime names don't
+//! have a leading `'`, anonymous lifetimes are named `anon_0`, `anon_1`... and the
+//! static lifetime is named `static`.
+
+use crate::c2::CFormatter;
+use diplomat_core::ast::{DocsUrlGenerator, MarkdownStyle};
+use diplomat_core::hir::borrowing_param::LifetimeEdgeKind;
+use diplomat_core::hir::{
+    self, LifetimeEnv, MaybeStatic, OpaqueOwner, ReturnType, SpecialMethod, StringEncoding,
+    StructPathLike, SuccessType, TyPosition, TypeContext, TypeId,
+};
+use serde::Serialize;
+
+/// The version of the schema, bumped whenever a change to it could break consumers.
+///
+/// Adding new fields or new values for `kind` is not considered breaking.
+pub const IR_VERSION: u32 = 1;
+
+/// The attribute validator the IR is lowered with.
+///
+/// Which attributes apply is still decided by `backend`, so that a generator can
+/// be targeted with `#[diplomat::attr(...)]` like the built-in ones.
+pub fn attribute_validator(backend: &str) -> hir::BasicAttributeValidator {
+    let mut validator = hir::BasicAttributeValidator::new(backend);
+    validator.support.disabling = true;
+    validator.support.renaming = true;
+    validator.support.namespacing = true;
+    validator.support.constructors = true;
+    validator.support.named_constructors = true;
+    validator.support.fallible_constructors = true;
+    validator.support.accessors = true;
+    validator.support.stringifiers = true;
+    validator.support.comparators = true;
+    validator.support.memory_sharing = true;
+    validator.support.iterators = true;
+    validator.support.iterables = true;
+    validator.support.indexing = true;
+    validator
+}
+
+/// Serialize a lowered bridge crate
+pub fn to_json(tcx: &TypeContext, backend: &str, docs_url_gen: &DocsUrlGenerator) -> String {
+    let cx = IrContext {
+        tcx,
+        c: CFormatter::new(tcx),
+        docs_url_gen,
+    };
+    let ir = Ir {
+        version: IR_VERSION,
+        backend,
+        types: tcx
+            .all_types()
+            .map(|(id, ty)| cx.type_def(id, ty))
+            .collect(),
+        traits: tcx.all_traits().map(|(_, trt)| cx.trait_def(trt)).collect(),
+        consts: tcx.consts().iter().map(|c| cx.const_def(c)).collect(),
+    };
+    let mut out = serde_json::to_string_pretty(&ir).expect("IR is always serializable");
+    out.push('\n');
+    out
+}
+
+#[derive(Serialize)]
+struct Ir<'a> {
+    version: u32,
+    /// The backend name attributes were resolved for
+    backend: &'a str,
+    types: Vec<TypeDef<'a>>,
+    traits: Vec<TraitDef<'a>>,
+    /// Consts declared at the top level of a bridge module
+    consts: Vec<ConstDef<'a>>,
+}
+
+/// What all named items have in common
+#[derive(Serialize)]
+struct Item<'a> {
+    /// The name in the bridge crate
+    name: &'a str,
+    /// The name with `rename` applied, which is what bindings should expose
+    display_name: String,
+    docs: String,
+    /// Whether the item was disabled, in which case bindings should leave it out
+    disabled: bool,
+}
+
+#[derive(Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum TypeDef<'a> {
+    Struct(StructDef<'a>),
+    /// A struct that can only be returned
+    OutStruct(StructDef<'a>),
+    Opaque(OpaqueDef<'a>),
+    Enum(EnumDef<'a>),
+}
+
+#[derive(Serialize)]
+struct StructDef<'a> {
+    #[serde(flatten)]
+    item: Item<'a>,
+    namespace: Option<&'a str>,
+    /// The name of the struct in the C ABI
+    abi_name: String,
+    lifetimes: Vec<LifetimeDef>,
+    fields: Vec<Field<'a>>,
+    methods: Vec<Method<'a>>,
+    consts: Vec<ConstDef<'a>>,
+}
+
+#[derive(Serialize)]
+struct OpaqueDef<'a> {
+    #[serde(flatten)]
+    item: Item<'a>,
+    namespace: Option<&'a str>,
+    lifetimes: Vec<LifetimeDef>,
+    /// The symbol that frees a `Box` of this type
+    destructor: String,
+    methods: Vec<Method<'a>>,
+    consts: Vec<ConstDef<'a>>,
+}
+
+#[derive(Serialize)]
+struct EnumDef<'a> {
+    #[serde(flatten)]
+    item: Item<'a>,
+    namespace: Option<&'a str>,
+    /// The name of the enum in the C ABI
+    abi_name: String,
+    variants: Vec<Variant<'a>>,
+    methods: Vec<Method<'a>>,
+    consts: Vec<ConstDef<'a>>,
+}
+
+#[derive(Serialize)]
+struct Variant<'a> {
+    #[serde(flatten)]
+    item: Item<'a>,
+    discriminant: isize,
+    /// The payload of a data-carrying variant, which uses the lifetimes of no definition
+    fields: Vec<Field<'a>>,
+}
+
+#[derive(Serialize)]
+struct Field<'a> {
+    name: &'a str,
+    docs: String,
+    #[serde(rename = "type")]
+    ty: Type<'a>,
+}
+
+#[derive(Serialize)]
+struct TraitDef<'a> {
+    #[serde(flatten)]
+    item: Item<'a>,
+    methods: Vec<TraitMethod<'a>>,
+}
+
+#[derive(Serialize)]
+struct TraitMethod<'a> {
+    name: &'a str,
+    docs: String,
+    lifetimes: Vec<LifetimeDef>,
+    params: Vec<Param<'a>>,
+    output: Option<Type<'a>>,
+}
+
+#[derive(Serialize)]
+struct LifetimeDef {
+    name: String,
+    /// All lifetimes that outlive this one, through bounds like `'b: 'a`
+    longer: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct Method<'a> {
+    #[serde(flatten)]
+    item: Item<'a>,
+    /// The symbol of the function in the C ABI
+    abi_name: String,
+    special: Option<Special>,
+    lifetimes: Vec<LifetimeDef>,
+    #[serde(rename = "self")]
+    param_self: Option<Type<'a>>,
+    params: Vec<Param<'a>>,
+    output: Output<'a>,
+    /// For every lifetime of the output, the parameters it may borrow from
+    borrows: Vec<Borrow>,
+}
+
+#[derive(Serialize)]
+struct Param<'a> {
+    name: &'a str,
+    #[serde(rename = "type")]
+    ty: Type<'a>,
+}
+
+#[derive(Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum Special {
+    Constructor,
+    NamedConstructor { name: Option<String> },
+    Getter { name: Option<String> },
+    Setter { name: Option<String> },
+    Stringifier,
+    Comparison,
+    Iterator,
+    Iterable,
+    Indexer,
+}
+
+/// The return value of a method. `ok` is `null` for methods that return nothing,
+/// and `err` is `null` for `Result<T, ()>`
+#[derive(Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum Output<'a> {
+    Infallible {
+        ok: Option<Type<'a>>,
+    },
+    /// An `Option<T>`
+    Nullable {
+        ok: Option<Type<'a>>,
+    },
+    /// A `Result<T, E>`
+    Fallible {
+        ok: Option<Type<'a>>,
+        err: Option<Type<'a>>,
+    },
+}
+
+#[derive(Serialize)]
+struct Borrow {
+    lifetime: String,
+    edges: Vec<BorrowEdge>,
+}
+
+#[derive(Serialize)]
+struct BorrowEdge {
+    param: String,
+    #[serde(flatten)]
+    kind: BorrowEdgeKind,
+}
+
+#[derive(Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum BorrowEdgeKind {
+    Opaque,
+    Slice,
+    /// The parameter is a struct, and its fields with `lifetime` are borrowed
+    Struct {
+        lifetime: String,
+    },
+}
+
+#[derive(Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum Type<'a> {
+    /// `name` is the Rust name of the primitive, and `byte` for `DiplomatByte`
+    Primitive {
+        name: &'static str,
+    },
+    /// An opaque, which is borrowed if `lifetime` isn't `null` and boxed otherwise
+    Opaque {
+        name: &'a str,
+        optional: bool,
+        mutable: bool,
+        lifetime: Option<String>,
+        lifetimes: Vec<String>,
+    },
+    Struct {
+        name: &'a str,
+        lifetimes: Vec<String>,
+    },
+    Enum {
+        name: &'a str,
+    },
+    /// A string, which is borrowed if `lifetime` isn't `null` and boxed otherwise
+    Str {
+        encoding: &'static str,
+        lifetime: Option<String>,
+    },
+    /// A slice of primitives, which is borrowed if `lifetime` isn't `null` and boxed otherwise
+    Slice {
+        element: &'static str,
+        mutable: bool,
+        lifetime: Option<String>,
+    },
+    /// A slice of borrowed strings, which can only be a parameter
+    Strs {
+        encoding: &'static str,
+    },
+    /// A callback, which can only be a parameter
+    Function {
+        params: Vec<Type<'a>>,
+        output: Option<Box<Type<'a>>>,
+    },
+    /// A `&dyn Trait` or `Box<dyn Trait>`
+    Trait {
+        name: &'a str,
+        owned: bool,
+    },
+    /// A `DiplomatWriteable`, which can only be returned
+    Writeable,
+}
+
+/// A const of a type or of a bridge module
+#[derive(Serialize)]
+struct ConstDef<'a> {
+    #[serde(flatten)]
+    item: Item<'a>,
+    #[serde(rename = "type")]
+    ty: Type<'a>,
+    value: ConstValue<'a>,
+}
+
+/// Numbers are kept as their literal, so that consumers don't lose precision
+#[derive(Serialize)]
+#[serde(tag = "kind", content = "value", rename_all = "snake_case")]
+enum ConstValue<'a> {
+    Bool(bool),
+    Int(&'a str),
+    Float(&'a str),
+    Str(&'a str),
+}
+
+struct IrContext<'tcx> {
+    tcx: &'tcx TypeContext,
+    c: CFormatter<'tcx>,
+    docs_url_gen: &'tcx DocsUrlGenerator,
+}
+
+impl<'tcx> IrContext<'tcx> {
+    fn item(&self, name: &'tcx hir::IdentBuf, docs: &hir::Docs, attrs: &hir::Attrs) -> Item<'tcx> {
+        Item {
+            name: name.as_str(),
+            display_name: attrs.rename.apply(name.as_str().into()).into_owned(),
+            docs: self.docs(docs),
+            disabled: attrs.disable,
+        }
+    }
+
+    fn docs(&self, docs: &hir::Docs) -> String {
+        docs.to_markdown(self.docs_url_gen, MarkdownStyle::Normal)
+            .trim()
+            .to_owned()
+    }
+
+    fn type_def(&self, id: TypeId, ty: hir::TypeDef<'tcx>) -> TypeDef<'tcx> {
+        let abi_name = || {
+            ty.attrs()
+                .abi_rename
+                .apply(ty.name().as_str().into())
+                .into_owned()
+        };
+        let item = self.item(ty.name(), ty.docs(), ty.attrs());
+        let namespace = ty.attrs().namespace.as_deref();
+        let methods = ty
+            .methods()
+            .iter()
+            .map(|method| self.method(id, method))
+            .collect();
+        let consts = ty.consts().iter().map(|c| self.const_def(c)).collect();
+        match ty {
+            hir::TypeDef::Struct(def) => TypeDef::Struct(StructDef {
+                item,
+                namespace,
+                abi_name: abi_name(),
+                lifetimes: lifetime_defs(&def.lifetimes),
+                fields: self.fields(&def.fields, &def.lifetimes),
+                methods,
+                consts,
+            }),
+            hir::TypeDef::OutStruct(def) => TypeDef::OutStruct(StructDef {
+                item,
+                namespace,
+                abi_name: abi_name(),
+                lifetimes: lifetime_defs(&def.lifetimes),
+                fields: self.fields(&def.fields, &def.lifetimes),
+                methods,
+                consts,
+            }),
+            hir::TypeDef::Opaque(def) => TypeDef::Opaque(OpaqueDef {
+                item,
+                namespace,
+                lifetimes: lifetime_defs(&def.lifetimes),
+                destructor: self.c.fmt_dtor_name(id),
+                methods,
+                consts,
+            }),
+            hir::TypeDef::Enum(def) => TypeDef::Enum(EnumDef {
+                item,
+                namespace,
+                abi_name: abi_name(),
+                variants: def
+                    .variants
+                    .iter()
+                    .map(|variant| Variant {
+                        item: self.item(&variant.name, &variant.docs, &variant.attrs),
+                        discriminant: variant.discriminant,
+                        fields: self.fields(&variant.fields, &LifetimeEnv::default()),
+                    })
+                    .collect(),
+                methods,
+                consts,
+            }),
+            _ => unreachable!("unknown AST/HIR variant"),
+        }
+    }
+
+    fn fields<P: TyPosition>(
+        &self,
+        fields: &'tcx [hir::StructField<P>],
+        env: &LifetimeEnv,
+    ) -> Vec<Field<'tcx>> {
+        fields
+            .iter()
+            .map(|field| Field {
+                name: field.name.as_str(),
+                docs: self.docs(&field.docs),
+                ty: self.ty(&field.ty, env),
+            })
+            .collect()
+    }
+
+    fn trait_def(&self, def: &'tcx hir::TraitDef) -> TraitDef<'tcx> {
+        TraitDef {
+            item: self.item(&def.name, &def.docs, &def.attrs),
+            methods: def
+                .methods
+                .iter()
+                .map(|method| TraitMethod {
+                    name: method.name.as_str(),
+                    docs: self.docs(&method.docs),
+                    lifetimes: lifetime_defs(&method.lifetime_env),
+                    params: self.params(&method.params, &method.lifetime_env),
+                    output: method
+                        .output
+                        .as_ref()
+                        .map(|ty| self.ty(ty, &method.lifetime_env)),
+                })
+                .collect(),
+        }
+    }
+
+    fn params(&self, params: &'tcx [hir::Param], env: &LifetimeEnv) -> Vec<Param<'tcx>> {
+        params
+            .iter()
+            .map(|param| Param {
+                name: param.name.as_str(),
+                ty: self.ty(&param.ty, env),
+            })
+            .collect()
+    }
+
+    fn method(&self, id: TypeId, method: &'tcx hir::Method) -> Method<'tcx> {
+        let env = &method.lifetime_env;
+        let param_self = method
+            .param_self
+            .as_ref()
+            .map(|param_self| self.ty(&param_self.ty.clone().into(), env));
+
+        let mut visitor = method.borrowing_param_visitor(self.tcx);
+        if let Some(param_self) = &method.param_self {
+            visitor.visit_param(&param_self.ty.clone().into(), "self");
+        }
+        for param in &method.params {
+            visitor.visit_param(&param.ty, param.name.as_str());
+        }
+        let borrows = visitor
+            .borrow_map()
+            .into_iter()
+            .map(|(lifetime, info)| Borrow {
+                lifetime: env.fmt_lifetime(lifetime).into_owned(),
+                edges: info
+                    .incoming_edges
+                    .into_iter()
+                    .map(|edge| BorrowEdge {
+                        kind: match edge.kind {
+                            LifetimeEdgeKind::OpaqueParam => BorrowEdgeKind::Opaque,
+                            LifetimeEdgeKind::SliceParam => BorrowEdgeKind::Slice,
+                            LifetimeEdgeKind::StructLifetime(env, lifetime) => {
+                                BorrowEdgeKind::Struct {
+                                    lifetime: env.fmt_lifetime(lifetime).into_owned(),
+                                }
+                            }
+                            _ => unreachable!("unknown AST/HIR variant"),
+                        },
+                        param: edge.param_name,
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        let success = |ty: &'tcx SuccessType| match ty {
+            SuccessType::Writeable => Some(Type::Writeable),
+            SuccessType::OutType(ty) => Some(self.ty(ty, env)),
+            _ => None,
+        };
+        let output = match &method.output {
+            ReturnType::Infallible(ok) => Output::Infallible { ok: success(ok) },
+            ReturnType::Nullable(ok) => Output::Nullable { ok: success(ok) },
+            ReturnType::Fallible(ok, err) => Output::Fallible {
+                ok: success(ok),
+                err: err.as_ref().map(|err| self.ty(err, env)),
+            },
+        };
+
+        Method {
+            item: self.item(&method.name, &method.docs, &method.attrs),
+            abi_name: self.c.fmt_method_name(id, method, true),
+            special: method.attrs.special_method.as_ref().map(special),
+            lifetimes: lifetime_defs(env),
+            param_self,
+            params: self.params(&method.params, env),
+            output,
+            borrows,
+        }
+    }
+
+    fn const_def(&self, def: &'tcx hir::ConstDef) -> ConstDef<'tcx> {
+        ConstDef {
+            item: self.item(&def.name, &def.docs, &def.attrs),
+            ty: match def.ty {
+                hir::ConstType::Primitive(prim) => Type::Primitive {
+                    name: prim.as_str(),
+                },
+                hir::ConstType::Str => Type::Str {
+                    encoding: encoding(StringEncoding::Utf8),
+                    lifetime: Some("static".into()),
+                },
+                _ => unreachable!("unknown AST/HIR variant"),
+            },
+            value: match &def.value {
+                hir::ConstValue::Bool(b) => ConstValue::Bool(*b),
+                hir::ConstValue::Int(i) => ConstValue::Int(i),
+                hir::ConstValue::Float(f) => ConstValue::Float(f),
+                hir::ConstValue::Str(s) => ConstValue::Str(s),
+                _ => unreachable!("unknown AST/HIR variant"),
+            },
+        }
+    }
+
+    fn type_name(&self, id: TypeId) -> &'tcx str {
+        self.tcx.resolve_type(id).name().as_str()
+    }
+
+    fn ty<P: TyPosition>(&self, ty: &hir::Type<P>, env: &LifetimeEnv) -> Type<'tcx> {
+        match ty {
+            hir::Type::Primitive(prim) => Type::Primitive {
+                name: prim.as_str(),
+            },
+            hir::Type::Opaque(path) => Type::Opaque {
+                name: self.type_name(path.tcx_id.into()),
+                optional: path.is_optional(),
+                mutable: path
+                    .owner
+                    .mutability()
+                    .map(|m| m.is_mutable())
+                    .unwrap_or_default(),
+                lifetime: path.owner.lifetime().map(|lt| fmt_lifetime(env, lt)),
+                lifetimes: path
+                    .lifetimes
+                    .lifetimes()
+                    .map(|lt| fmt_lifetime(env, lt))
+                    .collect(),
+            },
+            hir::Type::Struct(path) => Type::Struct {
+                name: self.type_name(path.id()),
+                lifetimes: path
+                    .lifetimes()
+                    .lifetimes()
+                    .map(|lt| fmt_lifetime(env, lt))
+                    .collect(),
+            },
+            hir::Type::Enum(path) => Type::Enum {
+                name: self.type_name(path.tcx_id.into()),
+            },
+            hir::Type::Slice(hir::Slice::Str(lifetime, enc)) => Type::Str {
+                encoding: encoding(*enc),
+                lifetime: lifetime.map(|lt| fmt_lifetime(env, lt)),
+            },
+            hir::Type::Slice(hir::Slice::Primitive(borrow, prim)) => Type::Slice {
+                element: prim.as_str(),
+                mutable: borrow
+                    .map(|b| b.mutability.is_mutable())
+                    .unwrap_or_default(),
+                lifetime: borrow.map(|b| fmt_lifetime(env, b.lifetime)),
+            },
+            hir::Type::Slice(hir::Slice::Strs(enc)) => Type::Strs {
+                encoding: encoding(*enc),
+            },
+            hir::Type::Func(func) => Type::Function {
+                params: func.inputs.iter().map(|(ty, _)| self.ty(ty, env)).collect(),
+                output: func.output.as_ref().map(|ty| Box::new(self.ty(ty, env))),
+            },
+            hir::Type::Trait(path) => Type::Trait {
+                name: self.tcx.resolve_trait(path.tcx_id).name.as_str(),
+                owned: path.is_owned,
+            },
+            _ => unreachable!("unknown AST/HIR variant"),
+        }
+    }
+}
+
+fn lifetime_defs(env: &LifetimeEnv) -> Vec<LifetimeDef> {
+    env.all_lifetimes()
+        .map(|lt| LifetimeDef {
+            name: env.fmt_lifetime(lt).into_owned(),
+            longer: env
+                .all_longer_lifetimes(lt)
+                .filter(|longer| *longer != lt)
+                .map(|longer| env.fmt_lifetime(longer).into_owned())
+                .collect(),
+        })
+        .collect()
+}
+
+fn fmt_lifetime(env: &LifetimeEnv, lifetime: MaybeStatic<hir::Lifetime>) -> String {
+    match lifetime {
+        MaybeStatic::Static => "static".into(),
+        MaybeStatic::NonStatic(lt) => env.fmt_lifetime(lt).into_owned(),
+    }
+}
+
+fn encoding(encoding: StringEncoding) -> &'static str {
+    match encoding {
+        StringEncoding::Utf8 => "utf8",
+        StringEncoding::UnvalidatedUtf8 => "unvalidated_utf8",
+        StringEncoding::UnvalidatedUtf16 => "unvalidated_utf16",
+        _ => unreachable!("unknown AST/HIR variant"),
+    }
+}
+
+fn special(special: &SpecialMethod) -> Special {
+    match special {
+        SpecialMethod::Constructor => Special::Constructor,
+        SpecialMethod::NamedConstructor(name) => Special::NamedConstructor { name: name.clone() },
+        SpecialMethod::Getter(name) => Special::Getter { name: name.clone() },
+        SpecialMethod::Setter(name) => Special::Setter { name: name.clone() },
+        SpecialMethod::Stringifier => Special::Stringifier,
+        SpecialMethod::Comparison => Special::Comparison,
+        SpecialMethod::Iterator => Special::Iterator,
+        SpecialMethod::Iterable => Special::Iterable,
+        SpecialMethod::Indexer => Special::Indexer,
+        _ => unreachable!("unknown AST/HIR variant"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{attribute_validator, to_json};
+    use diplomat_core::{ast, hir};
+
+    #[test]
+    fn test_ir() {
+        let source = r#"#[diplomat::bridge]
+mod ffi {
+    /// A pair of numbers
+    #[diplomat::attr(ir, rename = "Pair")]
+    pub struct Point {
+        pub x: i32,
+        pub y: i32,
+    }
+
+    pub const LIMIT: u8 = 10;
+
+    pub enum Color {
+        Red = 0,
+        Green = 2,
+    }
+
+    #[diplomat::opaque]
+    #[diplomat::abi_rename = "my_{0}"]
+    pub struct Counter(u32);
+
+    #[diplomat::opaque]
+    pub struct Name<'a>(&'a str);
+
+    impl Counter {
+        #[diplomat::attr(*, constructor)]
+        pub fn new(start: u32) -> Box<Counter> {
+            unimplemented!()
+        }
+        #[diplomat::attr(*, disable)]
+        pub fn add(&mut self, amount: u32) {}
+        pub fn name<'a>(&'a self, fallback: &'a DiplomatStr) -> Option<Box<Name<'a>>> {
+            unimplemented!()
+        }
+        pub fn format(&self, point: Point, write: &mut DiplomatWriteable) -> Result<(), Color> {
+            unimplemented!()
+        }
+    }
+}
+"#;
+        let file = ast::File::from(&syn::parse_file(source).unwrap());
+        let tcx = hir::TypeContext::from_ast(&file.all_types(), attribute_validator("ir"))
+            .unwrap_or_else(|e| panic!("lowering failed: {e:?}"));
+        insta::assert_snapshot!(to_json(&tcx, "ir", &Default::default()));
+    }
+}
@@ -39,6 +39,8 @@ pub fn gen_bindings(
     let mut all_types = util::get_all_custom_types(env);
     all_types.sort_by_key(|t| t.1.name());
 
+    let module_consts = util::get_all_consts(env);
+
     let index_ts = outs.entry("index.d.ts".to_string()).or_default();
     writeln!(
         index_ts,
@@ -46,7 +48,14 @@ pub fn gen_bindings(
     )?;
     for (_, custom_type) in &all_types {
         let name = custom_type.name();
-        writeln!(index_ts, "export {{ {name} }} from './{name}';",)?;
+        writeln!(
+            index_ts,
+            "export {{ {} }} from './{name}';",
+            Csv(exported_names(custom_type))
+        )?;
+    }
+    if !module_consts.is_empty() {
+        writeln!(index_ts, "export * from './diplomat-consts';")?;
     }
 
     let index_js = outs.entry("index.mjs".to_string()).or_default();
@@ -56,7 +65,14 @@ pub fn gen_bindings(
     )?;
     for (_, custom_type) in &all_types {
         let name = custom_type.name();
-        writeln!(index_js, "export {{ {name} }} from './{name}.mjs';",)?;
+        writeln!(
+            index_js,
+            "export {{ {} }} from './{name}.mjs';",
+            Csv(exported_names(custom_type))
+        )?;
+    }
+    if !module_consts.is_empty() {
+        writeln!(index_js, "export * from './diplomat-consts.mjs';")?;
     }
 
     for (in_path, custom_type) in &all_types {
@@ -99,25 +115,7 @@ pub fn gen_bindings(
             writeln!(
                 out,
                 "import {{ {} }} from \"./diplomat-runtime\"",
-                Csv(imports.ts_primitives.iter().map(|prim| match prim {
-                    ast::PrimitiveType::i8 => "i8",
-                    ast::PrimitiveType::u8 | ast::PrimitiveType::byte => "u8",
-                    ast::PrimitiveType::i16 => "i16",
-                    ast::PrimitiveType::u16 => "u16",
-                    ast::PrimitiveType::i32 => "i32",
-                    ast::PrimitiveType::u32 => "u32",
-                    ast::PrimitiveType::i64 => "i64",
-                    ast::PrimitiveType::u64 => "u64",
-                    ast::PrimitiveType::i128 => panic!("i128 is unsupported"),
-                    ast::PrimitiveType::u128 => panic!("u128 is unsupported"),
-                    ast::PrimitiveType::isize => "isize",
-                    ast::PrimitiveType::usize => "usize",
-                    ast::PrimitiveType::f32 => "f32",
-                    ast::PrimitiveType::f64 => "f64",
-                    ast::PrimitiveType::char => "char",
-                    ast::PrimitiveType::bool =>
-                        unreachable!("bools aren't added because TypeScript has `boolean`"),
-                }))
+                Csv(imports.ts_primitives.iter().copied().map(ts_primitive_name))
             )?;
         }
         if imports.ts_ffierror {
@@ -131,9 +129,90 @@ pub fn gen_bindings(
         gen_ts_custom_type_declaration(out, custom_type, in_path, env, docs_url_gen)?;
     }
 
+    if !module_consts.is_empty() {
+        let out = outs.entry("diplomat-consts.mjs".to_string()).or_default();
+        for (_, konst) in &module_consts {
+            writeln!(
+                out,
+                "export const {} = {};",
+                konst.name,
+                gen_const_value(konst)
+            )?;
+        }
+
+        let out = outs.entry("diplomat-consts.d.ts".to_string()).or_default();
+        let mut ts_primitives: Vec<ast::PrimitiveType> = module_consts
+            .iter()
+            .filter_map(|(_, konst)| match konst.ty {
+                ast::TypeName::Primitive(ast::PrimitiveType::bool) => None,
+                ast::TypeName::Primitive(prim) => Some(prim),
+                _ => None,
+            })
+            .collect();
+        ts_primitives.sort_by_key(|p| *p as u8);
+        ts_primitives.dedup_by_key(|p| *p as u8);
+        if !ts_primitives.is_empty() {
+            writeln!(
+                out,
+                "import {{ {} }} from \"./diplomat-runtime\"",
+                Csv(ts_primitives.into_iter().map(ts_primitive_name))
+            )?;
+        }
+        for (in_path, konst) in &module_consts {
+            writeln!(out)?;
+            gen_ts_const_declaration(
+                &format!("export const {}", konst.name),
+                konst,
+                in_path,
+                env,
+                docs_url_gen,
+                out,
+            )?;
+        }
+    }
+
     Ok(())
 }
 
+/// The name of the alias for a primitive declared in `diplomat-runtime.d.ts`.
+fn ts_primitive_name(prim: ast::PrimitiveType) -> &'static str {
+    match prim {
+        ast::PrimitiveType::i8 => "i8",
+        ast::PrimitiveType::u8 | ast::PrimitiveType::byte => "u8",
+        ast::PrimitiveType::i16 => "i16",
+        ast::PrimitiveType::u16 => "u16",
+        ast::PrimitiveType::i32 => "i32",
+        ast::PrimitiveType::u32 => "u32",
+        ast::PrimitiveType::i64 => "i64",
+        ast::PrimitiveType::u64 => "u64",
+        ast::PrimitiveType::i128 => panic!("i128 is unsupported"),
+        ast::PrimitiveType::u128 => panic!("u128 is unsupported"),
+        ast::PrimitiveType::isize => "isize",
+        ast::PrimitiveType::usize => "usize",
+        ast::PrimitiveType::f32 => "f32",
+        ast::PrimitiveType::f64 => "f64",
+        ast::PrimitiveType::char => "char",
+        ast::PrimitiveType::bool => {
+            unreachable!("bools aren't added because TypeScript has `boolean`")
+        }
+    }
+}
+
+/// The names a type's module exports: the type itself, and for enums, any associated
+/// consts, which are exported alongside rather than attached to the enum object.
+fn exported_names(custom_type: &ast::CustomType) -> Vec<String> {
+    let mut names = vec![custom_type.name().to_string()];
+    if let ast::CustomType::Enum(enm) = custom_type {
+        names.extend(
+            enm.consts
+                .iter()
+                .filter(|c| !c.attrs.skip_if_ast)
+                .map(|c| format!("{}_{}", enm.name, c.name)),
+        );
+    }
+    names
+}
+
 /// A struct for detecting all the the imports required for .mjs and d.ts files.
 #[derive(Default)]
 struct Imports<'env> {
@@ -181,6 +260,10 @@ impl<'env> Imports<'env> {
             }
         }
 
+        for konst in custom_type.consts() {
+            this.collect_usages(&konst.ty, in_path, env, TypePosition::Param);
+        }
+
         for method in custom_type.methods() {
             for param in method.params.iter() {
                 this.collect_usages(&param.ty, in_path, env, TypePosition::Param);
@@ -41,11 +41,9 @@ pub fn gen_struct<W: fmt::Write>(
                 "export const {}_js_to_rust = {};",
                 enm.name,
                 display::block(|mut f| {
-                    enm.variants
-                        .iter()
-                        .try_for_each(|(name, discriminant, _, _attrs)| {
-                            writeln!(f, "\"{name}\": {discriminant},")
-                        })
+                    enm.variants.iter().try_for_each(|variant| {
+                        writeln!(f, "\"{}\": {},", variant.name, variant.discriminant)
+                    })
                 })
             )?;
             writeln!(out)?;
@@ -54,11 +52,9 @@ pub fn gen_struct<W: fmt::Write>(
                 "export const {}_rust_to_js = {};",
                 enm.name,
                 display::block(|mut f| {
-                    enm.variants
-                        .iter()
-                        .try_for_each(|(name, discriminant, _, _attrs)| {
-                            writeln!(f, "[{discriminant}]: \"{name}\",")
-                        })
+                    enm.variants.iter().try_for_each(|variant| {
+                        writeln!(f, "[{}]: \"{}\",", variant.discriminant, variant.name)
+                    })
                 })
             )?;
             writeln!(out)?;
@@ -69,9 +65,22 @@ pub fn gen_struct<W: fmt::Write>(
                 display::block(|mut f| {
                     enm.variants
                         .iter()
-                        .try_for_each(|(name, ..)| writeln!(f, "\"{name}\": \"{name}\","))
+                        .try_for_each(|variant| writeln!(f, "\"{0}\": \"{0}\",", variant.name))
                 })
-            )
+            )?;
+
+            // Enums are plain objects, so their consts can't live on them
+            for konst in enm.consts.iter().filter(|c| !c.attrs.skip_if_ast) {
+                writeln!(out)?;
+                writeln!(
+                    out,
+                    "export const {}_{} = {};",
+                    enm.name,
+                    konst.name,
+                    gen_const_value(konst)
+                )?;
+            }
+            Ok(())
         }
         ast::CustomType::Struct(strct) => {
             writeln!(
@@ -129,6 +138,8 @@ pub fn gen_struct<W: fmt::Write>(
                         })
                     )?;
 
+                    gen_static_consts(&strct.consts, &mut f)?;
+
                     for method in strct.methods.iter() {
                         writeln!(f)?;
                         gen_method(method, in_path, env, &mut f)?;
@@ -173,6 +184,8 @@ pub fn gen_struct<W: fmt::Write>(
                         }),
                     )?;
 
+                    gen_static_consts(&opaque.consts, &mut f)?;
+
                     for method in opaque.methods.iter() {
                         writeln!(f)?;
                         gen_method(method, in_path, env, &mut f)?;
@@ -186,6 +199,56 @@ pub fn gen_struct<W: fmt::Write>(
     }
 }
 
+/// Generates static class fields for the associated consts of a struct or opaque type.
+fn gen_static_consts<W: fmt::Write>(consts: &[ast::Const], out: &mut W) -> fmt::Result {
+    for konst in consts.iter().filter(|c| !c.attrs.skip_if_ast) {
+        writeln!(out)?;
+        writeln!(out, "static {} = {};", konst.name, gen_const_value(konst))?;
+    }
+    Ok(())
+}
+
+/// Generates the JS literal for the value of a const.
+///
+/// 64-bit integers are `BigInt`s and `char`s are strings, matching how values
+/// of those types are converted at the boundary.
+pub fn gen_const_value(konst: &ast::Const) -> String {
+    match (&konst.value, &konst.ty) {
+        (ast::ConstValue::Bool(b), _) => b.to_string(),
+        (
+            ast::ConstValue::Int(i),
+            ast::TypeName::Primitive(ast::PrimitiveType::i64 | ast::PrimitiveType::u64),
+        ) => format!("{i}n"),
+        (ast::ConstValue::Int(i), ast::TypeName::Primitive(ast::PrimitiveType::char)) => {
+            let code_point: u32 = i.parse().unwrap_or_default();
+            format!("\"\\u{{{code_point:x}}}\"")
+        }
+        (ast::ConstValue::Int(i), _) | (ast::ConstValue::Float(i), _) => i.clone(),
+        (ast::ConstValue::Str(s), _) => {
+            let mut out = String::with_capacity(s.len() + 2);
+            out.push('"');
+            for c in s.chars() {
+                match c {
+                    '"' | '\\' => {
+                        out.push('\\');
+                        out.push(c);
+                    }
+                    '\n' => out.push_str("\\n"),
+                    '\r' => out.push_str("\\r"),
+                    '\t' => out.push_str("\\t"),
+                    c if c.is_control() => {
+                        let _ = write!(out, "\\u{{{:x}}}", c as u32);
+                    }
+                    c => out.push(c),
+                }
+            }
+            out.push('"');
+            out
+        }
+        _ => unreachable!("unknown AST/HIR variant"),
+    }
+}
+
 /// Generates the contents of a JS method.
 ///
 /// # Examples
@@ -417,14 +480,16 @@ pub fn gen_ts_custom_type_declaration<W: fmt::Write>(
             "export enum {} {}",
             enm.name,
             display::block(|mut f| {
-                for (name, _, docs, _attrs) in enm.variants.iter() {
+                for variant in enm.variants.iter() {
                     if let Some(docs_url_gen) = docs_url_gen {
                         write!(
                             f,
                             "{}",
                             display::ts_doc(|mut f| {
                                 TsDoc::from_markdown(
-                                    &docs.to_markdown(docs_url_gen, ast::MarkdownStyle::Normal),
+                                    &variant
+                                        .docs
+                                        .to_markdown(docs_url_gen, ast::MarkdownStyle::Normal),
                                     in_path,
                                     env,
                                     &mut f,
@@ -432,11 +497,22 @@ pub fn gen_ts_custom_type_declaration<W: fmt::Write>(
                             })
                         )?;
                     }
-                    writeln!(f, "{name} = '{name}',")?;
+                    writeln!(f, "{0} = '{0}',", variant.name)?;
                 }
                 Ok(())
             })
         )?;
+        for konst in enm.consts.iter() {
+            writeln!(out)?;
+            gen_ts_const_declaration(
+                &format!("export const {}_{}", enm.name, konst.name),
+                konst,
+                in_path,
+                env,
+                docs_url_gen,
+                out,
+            )?;
+        }
     } else {
         writeln!(
             out,
@@ -456,6 +532,18 @@ pub fn gen_ts_custom_type_declaration<W: fmt::Write>(
                     }
                 }
 
+                for konst in custom_type.consts() {
+                    writeln!(f)?;
+                    gen_ts_const_declaration(
+                        &format!("static readonly {}", konst.name),
+                        konst,
+                        in_path,
+                        env,
+                        docs_url_gen,
+                        &mut f,
+                    )?;
+                }
+
                 for method in custom_type.methods() {
                     writeln!(f)?;
                     gen_ts_method_declaration(method, in_path, env, docs_url_gen, &mut f)?;
@@ -537,6 +625,40 @@ pub fn gen_ts_type<W: fmt::Write>(
     Ok(false)
 }
 
+/// Generates the declaration of a const, where `decl` is everything before the type.
+pub fn gen_ts_const_declaration<W: fmt::Write>(
+    decl: &str,
+    konst: &ast::Const,
+    in_path: &ast::Path,
+    env: &Env,
+    docs_url_gen: Option<&ast::DocsUrlGenerator>,
+    out: &mut W,
+) -> fmt::Result {
+    if konst.attrs.skip_if_ast {
+        return Ok(());
+    }
+
+    if let Some(docs_url_gen) = docs_url_gen {
+        write!(
+            out,
+            "{}",
+            display::ts_doc(|mut f| {
+                TsDoc::from_markdown(
+                    &konst
+                        .docs
+                        .to_markdown(docs_url_gen, ast::MarkdownStyle::Normal),
+                    in_path,
+                    env,
+                    &mut f,
+                )
+            })
+        )?;
+    }
+    write!(out, "{decl}: ")?;
+    gen_ts_type(out, &konst.ty, in_path, env)?;
+    writeln!(out, ";")
+}
+
 fn gen_ts_method_declaration<W: fmt::Write>(
     method: &ast::Method,
     in_path: &ast::Path,
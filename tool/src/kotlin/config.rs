@@ -0,0 +1,18 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct LibraryConfig {
+    /// The package of the runtime and of types without a namespace
+    pub package: String,
+    /// The name of the native library JNA loads
+    pub native_lib: String,
+}
+
+impl Default for LibraryConfig {
+    fn default() -> LibraryConfig {
+        LibraryConfig {
+            package: "interop".to_owned(),
+            native_lib: "rust".to_owned(),
+        }
+    }
+}
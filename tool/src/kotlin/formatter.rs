@@ -0,0 +1,393 @@
+//! This module contains functions for formatting types
+
+use crate::c2::CFormatter;
+use diplomat_core::ast::{DocsUrlGenerator, MarkdownStyle};
+use diplomat_core::hir::{self, TypeContext, TypeId};
+use heck::{ToLowerCamelCase, ToShoutySnakeCase, ToUpperCamelCase};
+use std::borrow::Cow;
+
+/// This type mediates all formatting
+///
+/// All identifiers from the HIR should go through here before being formatted
+/// into the output: This makes it easy to handle reserved words or add rename support
+///
+/// If you find yourself needing an identifier formatted in a context not yet available here, please add a new method
+pub(super) struct KotlinFormatter<'tcx> {
+    c: CFormatter<'tcx>,
+    docs_url_generator: &'tcx DocsUrlGenerator,
+    strip_prefix: Option<String>,
+    package: String,
+}
+
+/// Kotlin's hard keywords, which have to be escaped with backticks
+const KEYWORDS: &[&str] = &[
+    "as",
+    "break",
+    "class",
+    "continue",
+    "do",
+    "else",
+    "false",
+    "for",
+    "fun",
+    "if",
+    "in",
+    "interface",
+    "is",
+    "null",
+    "object",
+    "package",
+    "return",
+    "super",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typealias",
+    "typeof",
+    "val",
+    "var",
+    "when",
+    "while",
+];
+/// Names used by the generated code itself
+const RESERVED_NAMES: &[&str] = &["keep", "result", "writeable", "edges", "handle", "native"];
+
+impl<'tcx> KotlinFormatter<'tcx> {
+    pub fn new(
+        tcx: &'tcx TypeContext,
+        docs_url_generator: &'tcx DocsUrlGenerator,
+        strip_prefix: Option<String>,
+        package: String,
+    ) -> Self {
+        Self {
+            c: CFormatter::new(tcx),
+            docs_url_generator,
+            strip_prefix,
+            package,
+        }
+    }
+
+    /// Format docs as the contents of a KDoc comment
+    pub fn fmt_docs(&self, docs: &hir::Docs) -> String {
+        docs.to_markdown(self.docs_url_generator, MarkdownStyle::Normal)
+            .trim()
+            .replace(" \n", "\n")
+            .replace("*/", "*&#47;")
+            .replace(
+                &format!("`{}", self.strip_prefix.as_deref().unwrap_or("")),
+                "`",
+            )
+    }
+
+    /// The package of the runtime and of types without a namespace
+    pub fn fmt_base_package(&self) -> &str {
+        &self.package
+    }
+
+    /// The package a type lives in, which is its namespace inside the base package
+    pub fn fmt_package(&self, id: TypeId) -> String {
+        match self.c.tcx().resolve_type(id).attrs().namespace {
+            Some(ref ns) => self.fmt_namespace_package(ns),
+            None => self.package.clone(),
+        }
+    }
+
+    /// The package of a namespace, e.g. `icu::list` becomes `{base}.icu.list`
+    pub fn fmt_namespace_package(&self, namespace: &str) -> String {
+        let mut package = self.package.clone();
+        for segment in namespace.split("::") {
+            package.push('.');
+            package.push_str(&escape(segment.to_lowercase()));
+        }
+        package
+    }
+
+    /// The path of the file holding a type
+    pub fn fmt_file_path(&self, id: TypeId) -> String {
+        let package = self.fmt_package(id);
+        format!(
+            "{}/{}.kt",
+            package.replace('.', "/").replace('`', ""),
+            self.fmt_type_name(id)
+        )
+    }
+
+    /// The path of a file in the base package
+    pub fn fmt_base_file_path(&self, file_name: &str) -> String {
+        format!("{}/{file_name}", self.package.replace('.', "/"))
+    }
+
+    pub fn fmt_destructor_name(&self, id: TypeId) -> String {
+        self.c.fmt_dtor_name(id)
+    }
+
+    pub fn fmt_c_method_name<'a>(&self, ty: TypeId, method: &'a hir::Method) -> Cow<'a, str> {
+        self.c.fmt_method_name(ty, method, true).into()
+    }
+
+    /// Resolve and format a named type for use in code
+    pub fn fmt_type_name(&self, id: TypeId) -> Cow<'tcx, str> {
+        let resolved = self.c.tcx().resolve_type(id);
+
+        let candidate: Cow<str> = if let Some(strip_prefix) = self.strip_prefix.as_ref() {
+            resolved
+                .name()
+                .as_str()
+                .strip_prefix(strip_prefix)
+                .unwrap_or(resolved.name().as_str())
+                .into()
+        } else {
+            resolved.name().as_str().into()
+        };
+
+        resolved.attrs().rename.apply(candidate)
+    }
+
+    /// Resolve and format a named type for use in diagnostics
+    /// (don't apply rename rules and such)
+    pub fn fmt_type_name_diagnostics(&self, id: TypeId) -> Cow<'tcx, str> {
+        self.c.fmt_type_name_diagnostics(id)
+    }
+
+    /// Format the name of the JNA `Structure` mirroring a struct or data-carrying enum
+    pub fn fmt_native_name(&self, type_name: &str) -> String {
+        format!("{type_name}Native")
+    }
+
+    /// Format the name of the JNA `Structure` holding the result of a fallible method
+    pub fn fmt_result_name<P: hir::TyPosition>(
+        &self,
+        ok: Option<&'tcx hir::Type<P>>,
+        err: Option<&'tcx hir::Type<P>>,
+    ) -> String {
+        let ok = ok.map(|o| self.c.fmt_type_name_uniquely(o));
+        let err = err.map(|e| self.c.fmt_type_name_uniquely(e));
+        format!(
+            "Result{}{}Native",
+            ok.as_deref().unwrap_or("void").to_upper_camel_case(),
+            err.as_deref().unwrap_or("void").to_upper_camel_case()
+        )
+    }
+
+    /// Format an enum variant, which Kotlin spells in SHOUTY_SNAKE_CASE
+    pub fn fmt_enum_variant(&self, variant: &'tcx hir::EnumVariant) -> String {
+        let name = variant.attrs.rename.apply(variant.name.as_str().into());
+        escape(name.to_shouty_snake_case())
+    }
+
+    /// Format the name of the subclass representing a variant of a data-carrying enum.
+    pub fn fmt_enum_variant_class_name(&self, variant: &'tcx hir::EnumVariant) -> String {
+        let name = variant.attrs.rename.apply(variant.name.as_str().into());
+        escape(name.to_upper_camel_case())
+    }
+
+    /// Format the name of a variant's payload in the JNA union
+    pub fn fmt_enum_payload_name(&self, variant: &hir::EnumVariant) -> String {
+        escape(variant.name.as_str().to_lower_camel_case())
+    }
+
+    /// Format a field name
+    pub fn fmt_field_name(&self, ident: &str) -> String {
+        escape(ident.to_lower_camel_case())
+    }
+
+    /// Format a parameter name, avoiding the names of locals in generated methods
+    pub fn fmt_param_name(&self, ident: &str) -> String {
+        let name = ident.to_lower_camel_case();
+        if RESERVED_NAMES.contains(&name.as_str()) {
+            format!("{name}_")
+        } else {
+            escape(name)
+        }
+    }
+
+    /// Format a method
+    pub fn fmt_method_name(&self, method: &hir::Method) -> String {
+        escape(
+            method
+                .attrs
+                .rename
+                .apply(method.name.as_str().into())
+                .to_lower_camel_case(),
+        )
+    }
+
+    pub fn fmt_constructor_name(&self, name: &Option<String>, method: &hir::Method) -> String {
+        escape(
+            method
+                .attrs
+                .rename
+                .apply(name.as_deref().unwrap_or(method.name.as_str()).into())
+                .to_lower_camel_case(),
+        )
+    }
+
+    pub fn fmt_accessor_name(&self, name: &Option<String>, method: &hir::Method) -> String {
+        escape(
+            method
+                .attrs
+                .rename
+                .apply(name.as_deref().unwrap_or(method.name.as_str()).into())
+                .to_lower_camel_case(),
+        )
+    }
+
+    /// Format the name of a const, which Kotlin spells in SHOUTY_SNAKE_CASE
+    pub fn fmt_const_name(&self, def: &hir::ConstDef) -> String {
+        escape(
+            def.attrs
+                .rename
+                .apply(def.name.as_str().into())
+                .to_shouty_snake_case(),
+        )
+    }
+
+    /// Format the value of a const as a Kotlin literal of its type
+    pub fn fmt_const_value(&self, def: &hir::ConstDef) -> String {
+        use diplomat_core::hir::{FloatType, IntSizeType, IntType, PrimitiveType};
+        match def.value {
+            hir::ConstValue::Bool(b) => b.to_string(),
+            hir::ConstValue::Str(ref s) => self.fmt_string_literal(s),
+            hir::ConstValue::Int(ref n) | hir::ConstValue::Float(ref n) => match def.ty {
+                hir::ConstType::Primitive(PrimitiveType::Float(FloatType::F32)) => format!("{n}f"),
+                hir::ConstType::Primitive(
+                    PrimitiveType::Int(IntType::U8 | IntType::U16 | IntType::U32 | IntType::U64)
+                    | PrimitiveType::IntSize(IntSizeType::Usize)
+                    | PrimitiveType::Byte,
+                ) => format!("{n}u"),
+                // Integer literals can't initialize a `Double`
+                hir::ConstType::Primitive(PrimitiveType::Float(FloatType::F64))
+                    if !n.contains(['.', 'e', 'E']) =>
+                {
+                    format!("{n}.0")
+                }
+                _ => n.clone(),
+            },
+            _ => unreachable!("unknown AST/HIR variant"),
+        }
+    }
+
+    /// The Kotlin type of a const
+    pub fn fmt_const_type(&self, def: &hir::ConstDef) -> &'static str {
+        match def.ty {
+            hir::ConstType::Primitive(p) => self.fmt_primitive_as_kotlin(p),
+            hir::ConstType::Str => self.fmt_string(),
+            _ => unreachable!("unknown AST/HIR variant"),
+        }
+    }
+
+    /// Format a string as a Kotlin string literal
+    pub fn fmt_string_literal(&self, s: &str) -> String {
+        let mut out = String::with_capacity(s.len() + 2);
+        out.push('"');
+        for c in s.chars() {
+            match c {
+                '"' | '\\' | '$' => {
+                    out.push('\\');
+                    out.push(c);
+                }
+                '\n' => out.push_str("\\n"),
+                '\r' => out.push_str("\\r"),
+                '\t' => out.push_str("\\t"),
+                c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+                c => out.push(c),
+            }
+        }
+        out.push('"');
+        out
+    }
+
+    /// The Kotlin type of a primitive
+    pub fn fmt_primitive_as_kotlin(&self, prim: hir::PrimitiveType) -> &'static str {
+        use diplomat_core::hir::{FloatType, IntSizeType, IntType, PrimitiveType};
+        match prim {
+            PrimitiveType::Bool => "Boolean",
+            // Code points don't fit into a UTF-16 `Char`
+            PrimitiveType::Char => "Int",
+            PrimitiveType::Int(IntType::I8) => "Byte",
+            PrimitiveType::Int(IntType::U8) | PrimitiveType::Byte => "UByte",
+            PrimitiveType::Int(IntType::I16) => "Short",
+            PrimitiveType::Int(IntType::U16) => "UShort",
+            PrimitiveType::Int(IntType::I32) => "Int",
+            PrimitiveType::Int(IntType::U32) => "UInt",
+            PrimitiveType::Int(IntType::I64) | PrimitiveType::IntSize(IntSizeType::Isize) => "Long",
+            PrimitiveType::Int(IntType::U64) | PrimitiveType::IntSize(IntSizeType::Usize) => {
+                "ULong"
+            }
+            PrimitiveType::Float(FloatType::F32) => "Float",
+            PrimitiveType::Float(FloatType::F64) => "Double",
+            PrimitiveType::Int128(_) => panic!("i128 not supported in Kotlin"),
+        }
+    }
+
+    /// The type JNA passes a primitive as
+    pub fn fmt_primitive_as_ffi(&self, prim: hir::PrimitiveType) -> &'static str {
+        use diplomat_core::hir::{FloatType, IntSizeType, IntType, PrimitiveType};
+        match prim {
+            // JNA maps `Boolean` to a 4-byte int
+            PrimitiveType::Bool => "Byte",
+            PrimitiveType::Char => "Int",
+            PrimitiveType::Int(IntType::I8 | IntType::U8) | PrimitiveType::Byte => "Byte",
+            PrimitiveType::Int(IntType::I16 | IntType::U16) => "Short",
+            PrimitiveType::Int(IntType::I32 | IntType::U32) => "Int",
+            PrimitiveType::Int(IntType::I64 | IntType::U64) => "Long",
+            PrimitiveType::IntSize(IntSizeType::Isize) => "SSizeT",
+            PrimitiveType::IntSize(IntSizeType::Usize) => "SizeT",
+            PrimitiveType::Float(FloatType::F32) => "Float",
+            PrimitiveType::Float(FloatType::F64) => "Double",
+            PrimitiveType::Int128(_) => panic!("i128 not supported in Kotlin"),
+        }
+    }
+
+    /// The Kotlin array type of a primitive slice, and the runtime functions
+    /// passing and reading it
+    pub fn fmt_primitive_slice(&self, prim: hir::PrimitiveType) -> (&'static str, &'static str) {
+        use diplomat_core::hir::{FloatType, IntSizeType, IntType, PrimitiveType};
+        match prim {
+            PrimitiveType::Bool => ("BooleanArray", "Booleans"),
+            PrimitiveType::Char => ("IntArray", "Ints"),
+            PrimitiveType::Int(IntType::I8 | IntType::U8) | PrimitiveType::Byte => {
+                ("ByteArray", "Bytes")
+            }
+            PrimitiveType::Int(IntType::I16 | IntType::U16) => ("ShortArray", "Shorts"),
+            PrimitiveType::Int(IntType::I32 | IntType::U32) => ("IntArray", "Ints"),
+            PrimitiveType::Int(IntType::I64 | IntType::U64) => ("LongArray", "Longs"),
+            PrimitiveType::IntSize(IntSizeType::Isize | IntSizeType::Usize) => {
+                ("LongArray", "Sizes")
+            }
+            PrimitiveType::Float(FloatType::F32) => ("FloatArray", "Floats"),
+            PrimitiveType::Float(FloatType::F64) => ("DoubleArray", "Doubles"),
+            PrimitiveType::Int128(_) => panic!("i128 not supported in Kotlin"),
+        }
+    }
+
+    /// The type JNA passes an enum's discriminant as
+    pub fn fmt_enum_as_ffi(&self) -> &'static str {
+        "Int"
+    }
+
+    pub fn fmt_opaque_as_ffi(&self) -> &'static str {
+        "Pointer?"
+    }
+
+    pub fn fmt_slice_as_ffi(&self) -> &'static str {
+        "DiplomatSlice"
+    }
+
+    pub fn fmt_string(&self) -> &'static str {
+        "String"
+    }
+
+    pub fn fmt_nullable(&self, ty: &str) -> String {
+        format!("{ty}?")
+    }
+}
+
+fn escape(name: String) -> String {
+    if KEYWORDS.contains(&name.as_str()) {
+        format!("`{name}`")
+    } else {
+        name
+    }
+}
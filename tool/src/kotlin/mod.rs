@@ -0,0 +1,1511 @@
+use crate::common::{ErrorStore, FileMap};
+use askama::Template;
+use config::LibraryConfig;
+use diplomat_core::ast::DocsUrlGenerator;
+use diplomat_core::hir::TypeContext;
+use diplomat_core::hir::{
+    self, OpaqueOwner, ReturnType, SelfType, SpecialMethod, StructPathLike, SuccessType,
+    TyPosition, Type, TypeDef, TypeId,
+};
+use formatter::KotlinFormatter;
+use heck::ToUpperCamelCase;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Display;
+
+#[cfg(test)]
+#[macro_use]
+mod test_util;
+
+pub mod config;
+mod formatter;
+
+/// The JNA classes used by generated files
+const JNA_IMPORTS: &[&str] = &[
+    "com.sun.jna.Pointer",
+    "com.sun.jna.Structure",
+    "com.sun.jna.Union",
+    "java.lang.ref.Reference",
+];
+
+/// Run file generation
+pub fn run<'cx>(
+    tcx: &'cx TypeContext,
+    library_config: LibraryConfig,
+    docs_url_generator: &'cx DocsUrlGenerator,
+    strip_prefix: Option<String>,
+) -> Result<FileMap, Vec<(impl Display + 'cx, String)>> {
+    let formatter = KotlinFormatter::new(
+        tcx,
+        docs_url_generator,
+        strip_prefix,
+        library_config.package,
+    );
+
+    let files = FileMap::default();
+    let errors = ErrorStore::default();
+
+    let mut tgcx = TyGenContext {
+        tcx,
+        errors: &errors,
+        formatter: &formatter,
+        result_natives: BTreeMap::new(),
+        functions: Vec::new(),
+    };
+
+    // Every file imports all packages, so that types can be referred to by name
+    let mut packages = BTreeSet::new();
+    packages.insert(formatter.fmt_base_package().to_owned());
+    for (id, ty) in tcx.all_types() {
+        if !ty.attrs().disable {
+            packages.insert(formatter.fmt_package(id));
+        }
+    }
+
+    for (id, ty) in tcx.all_types() {
+        if ty.attrs().disable {
+            continue;
+        }
+
+        let (body, natives) = tgcx.gen(id);
+        let package = formatter.fmt_package(id);
+        files.add_file(
+            formatter.fmt_file_path(id),
+            FileTemplate {
+                imports: imports(&packages, &package, &[]),
+                package,
+                body,
+                natives,
+            }
+            .render()
+            .unwrap(),
+        );
+    }
+
+    for (_, trt) in tcx.all_traits() {
+        if !trt.attrs.disable {
+            let _guard = errors.set_context_ty(trt.name.as_str().into());
+            errors.push_error(format!(
+                "Traits are not supported in Kotlin, found {}",
+                trt.name
+            ));
+        }
+    }
+
+    #[derive(Template)]
+    #[template(path = "kotlin/Lib.kt.jinja", escape = "none")]
+    struct LibTemplate<'a> {
+        functions: &'a [Function],
+        native_lib: &'a str,
+        consts: Vec<String>,
+    }
+
+    let base_package = formatter.fmt_base_package();
+    let lib = LibTemplate {
+        functions: &tgcx.functions,
+        native_lib: &library_config.native_lib,
+        consts: tgcx.gen_consts(tcx.consts()),
+    }
+    .render()
+    .unwrap();
+    files.add_file(
+        formatter.fmt_base_file_path("Lib.kt"),
+        FileTemplate {
+            package: base_package.to_owned(),
+            imports: imports(
+                &packages,
+                base_package,
+                &["com.sun.jna.Library", "com.sun.jna.Native"],
+            ),
+            body: lib,
+            natives: tgcx.result_natives.into_values().collect(),
+        }
+        .render()
+        .unwrap(),
+    );
+
+    #[derive(Template)]
+    #[template(path = "kotlin/Runtime.kt.jinja", escape = "none")]
+    struct RuntimeTemplate<'a> {
+        package: &'a str,
+    }
+
+    files.add_file(
+        formatter.fmt_base_file_path("Runtime.kt"),
+        RuntimeTemplate {
+            package: base_package,
+        }
+        .render()
+        .unwrap(),
+    );
+
+    let errors = errors.take_all();
+    if !errors.is_empty() {
+        Err(errors)
+    } else {
+        Ok(files)
+    }
+}
+
+/// The imports of a file in `package`
+fn imports(packages: &BTreeSet<String>, package: &str, extra: &[&str]) -> Vec<String> {
+    let mut imports: Vec<String> = extra
+        .iter()
+        .chain(JNA_IMPORTS)
+        .map(|i| i.to_string())
+        .collect();
+    imports.sort();
+    imports.extend(
+        packages
+            .iter()
+            .filter(|p| *p != package)
+            .map(|p| format!("{p}.*")),
+    );
+    imports
+}
+
+#[derive(Template)]
+#[template(path = "kotlin/file.kt.jinja", escape = "none")]
+struct FileTemplate {
+    package: String,
+    imports: Vec<String>,
+    body: String,
+    natives: Vec<String>,
+}
+
+struct TyGenContext<'a, 'cx> {
+    tcx: &'cx TypeContext,
+    formatter: &'a KotlinFormatter<'cx>,
+    errors: &'a ErrorStore<'cx, String>,
+    /// The JNA structures holding the results of fallible methods, by name
+    result_natives: BTreeMap<String, String>,
+    /// The signatures of all C functions that are called
+    functions: Vec<Function>,
+}
+
+impl<'a, 'cx> TyGenContext<'a, 'cx> {
+    /// Generates a type, returning its Kotlin code and the JNA structures it
+    /// is passed as
+    fn gen(&mut self, id: TypeId) -> (String, Vec<String>) {
+        let ty = self.tcx.resolve_type(id);
+
+        let _guard = self.errors.set_context_ty(ty.name().as_str().into());
+
+        let name = self.formatter.fmt_type_name(id);
+        match ty {
+            TypeDef::Enum(e) if e.is_data_carrying() => self.gen_tagged_union(e, id, &name),
+            TypeDef::Enum(e) => (self.gen_enum(e, id, &name), Vec::new()),
+            TypeDef::Opaque(o) => (self.gen_opaque_def(o, id, &name), Vec::new()),
+            TypeDef::Struct(s) => self.gen_struct_def(s, id, &name),
+            TypeDef::OutStruct(s) => self.gen_struct_def(s, id, &name),
+            _ => unreachable!("unknown AST/HIR variant"),
+        }
+    }
+
+    fn gen_const(&self, def: &hir::ConstDef) -> String {
+        format!(
+            "{}const val {}: {} = {}",
+            kdoc(&self.formatter.fmt_docs(&def.docs)),
+            self.formatter.fmt_const_name(def),
+            self.formatter.fmt_const_type(def),
+            self.formatter.fmt_const_value(def)
+        )
+    }
+
+    fn gen_consts(&self, consts: &[hir::ConstDef]) -> Vec<String> {
+        consts.iter().map(|c| self.gen_const(c)).collect()
+    }
+
+    fn gen_native(&self, name: &str, is_union: bool, fields: Vec<NativeField>) -> String {
+        #[derive(Template)]
+        #[template(path = "kotlin/native.kt.jinja", escape = "none")]
+        struct NativeTemplate<'a> {
+            name: &'a str,
+            is_union: bool,
+            fields: Vec<NativeField>,
+        }
+
+        NativeTemplate {
+            name,
+            is_union,
+            fields,
+        }
+        .render()
+        .unwrap()
+    }
+
+    fn gen_native_field<P: TyPosition>(&mut self, name: &str, ty: &Type<P>) -> NativeField {
+        NativeField {
+            name: name.to_owned(),
+            ty: self.gen_ffi_type(ty, false),
+            default: self.gen_ffi_default(ty),
+        }
+    }
+
+    fn gen_enum(&mut self, ty: &'cx hir::EnumDef, id: TypeId, type_name: &str) -> String {
+        let variants = ty
+            .variants
+            .iter()
+            .map(|variant| EnumVariantInfo {
+                name: self.formatter.fmt_enum_variant(variant),
+                discriminant: variant.discriminant,
+                docs: self.formatter.fmt_docs(&variant.docs),
+            })
+            .collect::<Vec<_>>();
+
+        let mut members = self.gen_members(id, &ty.methods, type_name);
+        members.companion.insert(
+            0,
+            format!(
+                "internal fun fromNative(native: Int): {type_name} = values().first {{ it.value == native }}"
+            ),
+        );
+        members.add_consts(self.gen_consts(&ty.consts));
+
+        #[derive(Template)]
+        #[template(path = "kotlin/enum.kt.jinja", escape = "none")]
+        struct ImplTemplate<'a> {
+            type_name: &'a str,
+            docs: String,
+            supertypes: Vec<String>,
+            variants: Vec<EnumVariantInfo>,
+            members: Vec<String>,
+            companion: Vec<String>,
+        }
+
+        ImplTemplate {
+            type_name,
+            docs: self.formatter.fmt_docs(&ty.docs),
+            supertypes: members.supertypes,
+            variants,
+            members: members.members,
+            companion: members.companion,
+        }
+        .render()
+        .unwrap()
+    }
+
+    /// Data-carrying enums become a sealed class with one subclass per variant,
+    /// passed as a structure holding the tag and a union of the variant payloads.
+    fn gen_tagged_union(
+        &mut self,
+        ty: &'cx hir::EnumDef,
+        id: TypeId,
+        type_name: &str,
+    ) -> (String, Vec<String>) {
+        let native_name = self.formatter.fmt_native_name(type_name);
+        let payload_native_name = self
+            .formatter
+            .fmt_native_name(&format!("{type_name}Payload"));
+
+        let mut natives = Vec::new();
+        let mut payload_fields = Vec::new();
+        let mut from_native = Vec::new();
+        let variants = ty
+            .variants
+            .iter()
+            .map(|variant| {
+                let class_name = self.formatter.fmt_enum_variant_class_name(variant);
+                let payload_name = self.formatter.fmt_enum_payload_name(variant);
+                let variant_native_name = self
+                    .formatter
+                    .fmt_native_name(&format!("{type_name}{class_name}"));
+
+                let mut native_fields = Vec::new();
+                let fields = variant
+                    .fields
+                    .iter()
+                    .map(|field| {
+                        let name = self.formatter.fmt_field_name(field.name.as_str());
+                        if field.ty.lifetimes().next().is_some() {
+                            self.errors.push_error(format!(
+                                "Borrowed data in enum payloads is not supported in Kotlin: {}::{}",
+                                variant.name, field.name
+                            ));
+                        }
+                        native_fields.push(self.gen_native_field(&name, &field.ty));
+                        FieldInfo {
+                            kotlin_type: self.gen_type_name(&field.ty),
+                            kotlin_to_native: self
+                                .gen_kotlin_to_native(&field.ty, &format!("this.{name}")),
+                            native_to_kotlin: self.gen_native_to_kotlin(
+                                &field.ty,
+                                &format!("payload.{name}"),
+                                "edges",
+                            ),
+                            name,
+                        }
+                    })
+                    .collect::<Vec<_>>();
+
+                if fields.is_empty() {
+                    from_native.push(format!("{} -> {class_name}", variant.discriminant));
+                } else {
+                    from_native.push(format!(
+                        "{} -> (native.payload.readField(\"{}\") as {variant_native_name}).let {{ payload ->\n    {class_name}({})\n}}",
+                        variant.discriminant,
+                        unescape(&payload_name),
+                        fields
+                            .iter()
+                            .map(|f| f.native_to_kotlin.as_str())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ));
+                    natives.push(self.gen_native(&variant_native_name, false, native_fields));
+                    payload_fields.push(NativeField {
+                        name: payload_name.clone(),
+                        ty: variant_native_name.clone(),
+                        default: format!("{variant_native_name}()"),
+                    });
+                }
+
+                TaggedVariantInfo {
+                    class_name,
+                    docs: self.formatter.fmt_docs(&variant.docs),
+                    discriminant: variant.discriminant,
+                    native_name: variant_native_name,
+                    payload_name,
+                    fields,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let mut fields = vec![NativeField {
+            name: "tag".into(),
+            ty: self.formatter.fmt_enum_as_ffi().into(),
+            default: "0".into(),
+        }];
+        // JNA unions need at least one field
+        if !payload_fields.is_empty() {
+            fields.push(NativeField {
+                name: "payload".into(),
+                ty: payload_native_name.clone(),
+                default: format!("{payload_native_name}()"),
+            });
+            natives.insert(
+                0,
+                self.gen_native(&payload_native_name, true, payload_fields),
+            );
+        }
+        natives.insert(0, self.gen_native(&native_name, false, fields));
+
+        from_native.push(format!(
+            "else -> throw IllegalStateException(\"Unknown {type_name} tag ${{native.tag}}\")"
+        ));
+        let mut members = self.gen_members(id, &ty.methods, type_name);
+        members.companion.insert(
+            0,
+            format!(
+                "internal fun fromNative(native: {native_name}, edges: List<Any>): {type_name} = when (native.tag) {{\n{}\n}}",
+                indent(&from_native.join("\n"), 4)
+            ),
+        );
+        members.add_consts(self.gen_consts(&ty.consts));
+
+        #[derive(Template)]
+        #[template(path = "kotlin/tagged_union.kt.jinja", escape = "none")]
+        struct ImplTemplate<'a> {
+            type_name: &'a str,
+            docs: String,
+            supertypes: Vec<String>,
+            native_name: &'a str,
+            variants: Vec<TaggedVariantInfo>,
+            members: Vec<String>,
+            companion: Vec<String>,
+        }
+
+        let body = ImplTemplate {
+            type_name,
+            docs: self.formatter.fmt_docs(&ty.docs),
+            supertypes: members.supertypes,
+            native_name: &native_name,
+            variants,
+            members: members.members,
+            companion: members.companion,
+        }
+        .render()
+        .unwrap();
+        (body, natives)
+    }
+
+    fn gen_opaque_def(&mut self, ty: &'cx hir::OpaqueDef, id: TypeId, type_name: &str) -> String {
+        let destructor = self.formatter.fmt_destructor_name(id);
+        self.functions.push(Function {
+            c_name: destructor.clone(),
+            params: vec![format!("self: {}", self.formatter.fmt_opaque_as_ffi())],
+            return_ty: "Unit".into(),
+        });
+
+        let mut members = self.gen_members(id, &ty.methods, type_name);
+        members.add_consts(self.gen_consts(&ty.consts));
+        let mut supertypes = vec!["AutoCloseable".to_owned()];
+        supertypes.extend(members.supertypes);
+
+        #[derive(Template)]
+        #[template(path = "kotlin/opaque.kt.jinja", escape = "none")]
+        struct ImplTemplate<'a> {
+            type_name: &'a str,
+            docs: String,
+            supertypes: Vec<String>,
+            destructor: String,
+            members: Vec<String>,
+            companion: Vec<String>,
+        }
+
+        ImplTemplate {
+            type_name,
+            docs: self.formatter.fmt_docs(&ty.docs),
+            supertypes,
+            destructor,
+            members: members.members,
+            companion: members.companion,
+        }
+        .render()
+        .unwrap()
+    }
+
+    fn gen_struct_def<P: TyPosition>(
+        &mut self,
+        ty: &'cx hir::StructDef<P>,
+        id: TypeId,
+        type_name: &str,
+    ) -> (String, Vec<String>) {
+        let native_name = self.formatter.fmt_native_name(type_name);
+
+        let mut native_fields = Vec::new();
+        let fields = ty
+            .fields
+            .iter()
+            .map(|field| {
+                let name = self.formatter.fmt_field_name(field.name.as_str());
+                native_fields.push(self.gen_native_field(&name, &field.ty));
+                FieldInfo {
+                    kotlin_type: self.gen_type_name(&field.ty),
+                    kotlin_to_native: self.gen_kotlin_to_native(&field.ty, &format!("this.{name}")),
+                    native_to_kotlin: self.gen_native_to_kotlin(
+                        &field.ty,
+                        &format!("native.{name}"),
+                        "edges",
+                    ),
+                    name,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let mut members = self.gen_members(id, &ty.methods, type_name);
+        members.companion.insert(
+            0,
+            format!(
+                "internal fun fromNative(native: {native_name}, edges: List<Any>): {type_name} = {type_name}({})",
+                fields
+                    .iter()
+                    .map(|f| f.native_to_kotlin.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        );
+        members.add_consts(self.gen_consts(&ty.consts));
+
+        #[derive(Template)]
+        #[template(path = "kotlin/struct.kt.jinja", escape = "none")]
+        struct ImplTemplate<'a> {
+            type_name: &'a str,
+            docs: String,
+            supertypes: Vec<String>,
+            native_name: &'a str,
+            fields: Vec<FieldInfo>,
+            members: Vec<String>,
+            companion: Vec<String>,
+        }
+
+        let body = ImplTemplate {
+            type_name,
+            docs: self.formatter.fmt_docs(&ty.docs),
+            supertypes: members.supertypes,
+            native_name: &native_name,
+            fields,
+            members: members.members,
+            companion: members.companion,
+        }
+        .render()
+        .unwrap();
+        let native = self.gen_native(&native_name, false, native_fields);
+        (body, vec![native])
+    }
+
+    /// Generates the members of a type, sorting them into the class body and
+    /// its companion object, and the interfaces its special methods implement
+    fn gen_members(
+        &mut self,
+        id: TypeId,
+        methods: &'cx [hir::Method],
+        type_name: &str,
+    ) -> MembersInfo {
+        let mut out = MembersInfo::default();
+        let mut properties = BTreeMap::<String, PropertyInfo>::new();
+
+        for method in methods {
+            let info = match self.gen_method_info(id, method) {
+                Some(info) => info,
+                None => continue,
+            };
+            let is_static = method.param_self.is_none();
+            match method.attrs.special_method {
+                Some(SpecialMethod::Getter(ref name) | SpecialMethod::Setter(ref name)) => {
+                    let name = self.formatter.fmt_accessor_name(name, method);
+                    let property = properties.entry(name.clone()).or_insert(PropertyInfo {
+                        name,
+                        is_static,
+                        getter: None,
+                        setter: None,
+                    });
+                    if let Some(SpecialMethod::Getter(_)) = method.attrs.special_method {
+                        property.getter = Some(info);
+                    } else {
+                        property.setter = Some(info);
+                    }
+                    continue;
+                }
+                // The standard interfaces are qualified, since types may share their names
+                Some(SpecialMethod::Comparison) => {
+                    out.supertypes
+                        .push(format!("kotlin.Comparable<{type_name}>"));
+                }
+                Some(SpecialMethod::Iterator) => {
+                    let item = info.return_ty.trim_end_matches('?').to_owned();
+                    out.members.push(format!(
+                        "private var peeked: {item}? = null\nprivate var hasPeeked = false"
+                    ));
+                    out.members.push(
+                        "override fun hasNext(): Boolean {\n    if (!hasPeeked) {\n        peeked = nextOrNull()\n        hasPeeked = true\n    }\n    return peeked != null\n}".into(),
+                    );
+                    out.members.push(format!(
+                        "override fun next(): {item} {{\n    if (!hasNext()) {{\n        throw NoSuchElementException()\n    }}\n    hasPeeked = false\n    return peeked!!\n}}"
+                    ));
+                    out.supertypes
+                        .push(format!("kotlin.collections.Iterator<{item}>"));
+                }
+                Some(SpecialMethod::Iterable) => {
+                    if let Some(item) = self.gen_iterable_item(method) {
+                        out.supertypes
+                            .push(format!("kotlin.collections.Iterable<{item}>"));
+                    }
+                }
+                _ => {}
+            }
+            let rendered = MethodTemplate { m: &info }.render().unwrap();
+            match method.attrs.special_method {
+                Some(SpecialMethod::Constructor | SpecialMethod::NamedConstructor(_)) => {
+                    out.companion.push(rendered)
+                }
+                _ if is_static => out.companion.push(rendered),
+                _ => out.members.push(rendered),
+            }
+        }
+
+        for (_, property) in properties {
+            let rendered = match property.getter {
+                Some(getter) => {
+                    let docs = if getter.docs.is_empty() {
+                        property
+                            .setter
+                            .as_ref()
+                            .map(|s| s.docs.clone())
+                            .unwrap_or_default()
+                    } else {
+                        getter.docs
+                    };
+                    PropertyTemplate {
+                        p: &PropertyGenInfo {
+                            name: property.name,
+                            ty: getter.return_ty,
+                            docs,
+                            getter_body: getter.body,
+                            setter: property.setter.map(|setter| SetterInfo {
+                                param: setter.param_names.into_iter().next().unwrap_or_default(),
+                                body: setter.body,
+                            }),
+                        },
+                    }
+                    .render()
+                    .unwrap()
+                }
+                // Without a getter, setters stay methods
+                None => match property.setter {
+                    Some(setter) => MethodTemplate { m: &setter }.render().unwrap(),
+                    None => continue,
+                },
+            };
+            if property.is_static {
+                out.companion.push(rendered);
+            } else {
+                out.members.push(rendered);
+            }
+        }
+
+        out
+    }
+
+    /// The item type of the iterator an iterable method returns
+    fn gen_iterable_item(&mut self, method: &'cx hir::Method) -> Option<String> {
+        let op = match method.output.success_type() {
+            SuccessType::OutType(Type::Opaque(op)) => op,
+            _ => return None,
+        };
+        let iterator = op.resolve(self.tcx);
+        let item = iterator.special_method_presence.iterator.as_ref()?;
+        Some(
+            self.gen_type_name_for_success(item)
+                .trim_end_matches('?')
+                .to_owned(),
+        )
+    }
+
+    fn gen_method_info(&mut self, id: TypeId, method: &'cx hir::Method) -> Option<MethodInfo> {
+        if method.attrs.disable {
+            return None;
+        }
+
+        let _guard = self.errors.set_context_method(
+            self.formatter.fmt_type_name_diagnostics(id),
+            method.name.as_str().into(),
+        );
+
+        let c_method_name = self.formatter.fmt_c_method_name(id, method);
+        let special_method = method.attrs.special_method.as_ref();
+
+        let mut params = Vec::new();
+        let mut param_names = Vec::new();
+        let mut ffi_params = Vec::new();
+        let mut args = Vec::new();
+        // Slices are converted before the call, and mutable ones copied back after it
+        let mut before = Vec::new();
+        let mut after = Vec::new();
+        // Objects whose Rust memory is used by the call, which must not be
+        // cleaned up before it returns
+        let mut fences = Vec::new();
+        // Everything the return value may borrow from
+        let mut edges = Vec::new();
+        let mut needs_keep = false;
+
+        if let Some(param_self) = method.param_self.as_ref() {
+            let (ffi_ty, arg) = match param_self.ty {
+                SelfType::Opaque(_) => {
+                    fences.push("this".to_owned());
+                    (self.formatter.fmt_opaque_as_ffi().to_owned(), "handle")
+                }
+                SelfType::Struct(ref s) => {
+                    needs_keep = true;
+                    let name = self.formatter.fmt_type_name(s.id());
+                    (
+                        format!("{}.ByValue", self.formatter.fmt_native_name(&name)),
+                        "toNative(keep)",
+                    )
+                }
+                SelfType::Enum(ref e) if e.resolve(self.tcx).is_data_carrying() => {
+                    needs_keep = true;
+                    let name = self.formatter.fmt_type_name(e.tcx_id.into());
+                    (
+                        format!("{}.ByValue", self.formatter.fmt_native_name(&name)),
+                        "toNative(keep)",
+                    )
+                }
+                SelfType::Enum(_) => (self.formatter.fmt_enum_as_ffi().to_owned(), "value"),
+                _ => unreachable!("unknown AST/HIR variant"),
+            };
+            ffi_params.push(format!("self: {ffi_ty}"));
+            args.push(arg.to_owned());
+            edges.push("this".to_owned());
+        }
+
+        for param in method.params.iter() {
+            let param_name = self.formatter.fmt_param_name(param.name.as_str());
+            params.push(format!("{param_name}: {}", self.gen_type_name(&param.ty)));
+
+            if let Type::Slice(slice) = param.ty {
+                // Two args on the ABI: pointer and length
+                let local = format!("{}Slice", unescape(&param_name));
+                before.push(format!(
+                    "val {local} = {}",
+                    self.gen_slice_to_native(&slice, &param_name)
+                ));
+                ffi_params.push(format!("{}Data: Pointer?", unescape(&param_name)));
+                ffi_params.push(format!("{}Len: SizeT", unescape(&param_name)));
+                args.push(format!("{local}.data"));
+                args.push(format!("{local}.len"));
+                if let hir::Slice::Primitive(Some(borrow), _) = slice {
+                    if borrow.mutability.is_mutable() {
+                        after.push(format!("DiplomatRuntime.copyBack({local}, {param_name})"));
+                    }
+                }
+                needs_keep |= slice.lifetime().is_some();
+            } else {
+                needs_keep |= self.needs_keep(&param.ty);
+                ffi_params.push(format!(
+                    "{}: {}",
+                    unescape(&param_name),
+                    self.gen_ffi_type(&param.ty, true)
+                ));
+                args.push(self.gen_kotlin_to_native(&param.ty, &param_name));
+                if let Type::Opaque(_) = param.ty {
+                    fences.push(param_name.clone());
+                }
+            }
+
+            if !matches!(param.ty, Type::Primitive(_) | Type::Enum(_)) {
+                edges.push(param_name.clone());
+            }
+            param_names.push(param_name);
+        }
+
+        if method.output.is_writeable() {
+            ffi_params.push("writeable: Pointer?".into());
+            args.push("writeable.handle".into());
+        }
+
+        let borrows = method
+            .output
+            .success_type()
+            .as_type()
+            .map(|o| o.lifetimes().next().is_some())
+            .unwrap_or(false);
+        let edges = if borrows {
+            if needs_keep {
+                edges.push("keep".into());
+            }
+            format!("listOfNotNull<Any>({})", edges.join(", "))
+        } else {
+            "listOf()".into()
+        };
+
+        let return_ffi = self.gen_return_type_ffi(&method.output);
+        self.functions.push(Function {
+            c_name: c_method_name.to_string(),
+            params: ffi_params,
+            return_ty: return_ffi,
+        });
+
+        let mut body = Vec::new();
+        if needs_keep {
+            body.push("val keep = mutableListOf<Any>()".to_owned());
+            fences.push("keep".into());
+        }
+        if method.output.is_writeable() {
+            body.push("val writeable = DiplomatWriteable()".into());
+        }
+        body.extend(before);
+        let call = format!("lib.{c_method_name}({})", args.join(", "));
+        if method.output.is_ffi_unit() {
+            body.push(call);
+        } else {
+            body.push(format!("val result = {call}"));
+        }
+        // Methods returning a `Result` get the panic as their error
+        let checks_panic =
+            method.attrs.catch_panics && !matches!(method.output, ReturnType::Fallible(..));
+        if checks_panic {
+            body.push("DiplomatRuntime.checkPanic()".into());
+        }
+        body.extend(after);
+        body.extend(
+            fences
+                .iter()
+                .map(|f| format!("Reference.reachabilityFence({f})")),
+        );
+
+        let on_none = match special_method {
+            Some(SpecialMethod::Indexer) => "throw IndexOutOfBoundsException()",
+            _ => "return null",
+        };
+        body.extend(self.gen_return(&method.output, &edges, on_none));
+        if let Some(SpecialMethod::Comparison) = special_method {
+            if let Some(ret) = body.last_mut() {
+                ret.push_str(".toInt()");
+            }
+        }
+
+        let mut modifiers = "";
+        let name = match special_method {
+            // Enums can't be constructed with `invoke`, so their constructors are named
+            Some(SpecialMethod::Constructor) if matches!(id, TypeId::Enum(_)) => {
+                self.formatter.fmt_method_name(method)
+            }
+            Some(SpecialMethod::Constructor) => {
+                modifiers = "operator ";
+                "invoke".into()
+            }
+            Some(SpecialMethod::NamedConstructor(name)) => {
+                self.formatter.fmt_constructor_name(name, method)
+            }
+            Some(SpecialMethod::Getter(name)) => format!(
+                "get{}",
+                self.formatter
+                    .fmt_accessor_name(name, method)
+                    .to_upper_camel_case()
+            ),
+            Some(SpecialMethod::Setter(name)) => format!(
+                "set{}",
+                self.formatter
+                    .fmt_accessor_name(name, method)
+                    .to_upper_camel_case()
+            ),
+            Some(SpecialMethod::Stringifier) => {
+                modifiers = "override ";
+                "toString".into()
+            }
+            Some(SpecialMethod::Comparison) => {
+                modifiers = "override ";
+                "compareTo".into()
+            }
+            Some(SpecialMethod::Iterator) => {
+                modifiers = "private ";
+                "nextOrNull".into()
+            }
+            Some(SpecialMethod::Iterable) => {
+                modifiers = "override ";
+                "iterator".into()
+            }
+            Some(SpecialMethod::Indexer) => {
+                modifiers = "operator ";
+                "get".into()
+            }
+            None => self.formatter.fmt_method_name(method),
+            Some(special) => unimplemented!("Found unknown special method type {special:?}"),
+        };
+
+        let return_ty = match special_method {
+            Some(SpecialMethod::Comparison) => "Int".into(),
+            Some(SpecialMethod::Indexer) => self
+                .gen_type_name_for_success(method.output.success_type())
+                .trim_end_matches('?')
+                .to_owned(),
+            _ => self.gen_return_type_name(&method.output),
+        };
+
+        let mut docs = self.formatter.fmt_docs(&method.docs);
+        if let ReturnType::Fallible(_, e) = &method.output {
+            if !docs.is_empty() {
+                docs.push_str("\n\n");
+            }
+            match e {
+                Some(e) => docs.push_str(&format!(
+                    "@throws DiplomatException holding a `{}` on failure",
+                    self.gen_type_name(e)
+                )),
+                None => docs.push_str("@throws DiplomatException on failure"),
+            }
+        }
+        if checks_panic {
+            if !docs.is_empty() {
+                docs.push_str("\n\n");
+            }
+            docs.push_str("@throws DiplomatPanic if it panics");
+        }
+
+        Some(MethodInfo {
+            name,
+            modifiers,
+            params,
+            param_names,
+            return_ty,
+            docs,
+            body,
+        })
+    }
+
+    /// Generates the statements converting the native return value of a
+    /// method, which is saved in `result` (or `writeable`), into the Kotlin one.
+    ///
+    /// `on_none` is run when a nullable method returns nothing.
+    fn gen_return(&mut self, output: &'cx ReturnType, edges: &str, on_none: &str) -> Vec<String> {
+        let mut out = Vec::new();
+        let ok_value = match output {
+            ReturnType::Infallible(SuccessType::Unit) => None,
+            ReturnType::Infallible(SuccessType::Writeable) => {
+                Some("writeable.finalizeString()".into())
+            }
+            ReturnType::Infallible(SuccessType::OutType(o)) => {
+                let value = self.gen_native_to_kotlin(o, "result", edges);
+                match o {
+                    Type::Opaque(op) if op.is_optional() && on_none.starts_with("throw") => {
+                        Some(format!("{value} ?: {on_none}"))
+                    }
+                    _ => Some(value),
+                }
+            }
+            ReturnType::Nullable(SuccessType::Unit) => Some("result.isOk != 0.toByte()".into()),
+            ReturnType::Fallible(ok, _) | ReturnType::Nullable(ok) => {
+                out.push("if (result.isOk == 0.toByte()) {".into());
+                out.push(match output {
+                    ReturnType::Fallible(_, Some(e)) => {
+                        let err = self.gen_read_union("err", e);
+                        format!(
+                            "    throw DiplomatException({})",
+                            self.gen_native_to_kotlin(e, &err, edges)
+                        )
+                    }
+                    ReturnType::Fallible(_, None) => "    throw DiplomatException(null)".into(),
+                    _ => format!("    {on_none}"),
+                });
+                out.push("}".into());
+                match ok {
+                    SuccessType::Unit => None,
+                    SuccessType::Writeable => Some("writeable.finalizeString()".into()),
+                    SuccessType::OutType(o) => {
+                        let ok = self.gen_read_union("ok", o);
+                        Some(self.gen_native_to_kotlin(o, &ok, edges))
+                    }
+                    _ => unreachable!("unknown AST/HIR variant"),
+                }
+            }
+            _ => unreachable!("unknown AST/HIR variant"),
+        };
+        if let Some(value) = ok_value {
+            out.push(format!("return {value}"));
+        }
+        out
+    }
+
+    /// Whether passing this type needs temporary allocations
+    fn needs_keep<P: TyPosition>(&self, ty: &Type<P>) -> bool {
+        match ty {
+            Type::Struct(_) => true,
+            Type::Enum(e) => e.resolve(self.tcx).is_data_carrying(),
+            Type::Slice(s) => s.lifetime().is_some(),
+            _ => false,
+        }
+    }
+
+    /// Generates a type's Kotlin type.
+    fn gen_type_name<P: TyPosition>(&mut self, ty: &Type<P>) -> String {
+        match *ty {
+            Type::Primitive(prim) => self.formatter.fmt_primitive_as_kotlin(prim).into(),
+            Type::Opaque(ref op) => {
+                let type_name = self.gen_named_type(op.tcx_id.into());
+                if op.is_optional() {
+                    self.formatter.fmt_nullable(&type_name)
+                } else {
+                    type_name
+                }
+            }
+            Type::Struct(ref st) => self.gen_named_type(st.id()),
+            Type::Enum(ref e) => self.gen_named_type(e.tcx_id.into()),
+            Type::Slice(hir::Slice::Str(..)) => self.formatter.fmt_string().into(),
+            Type::Slice(hir::Slice::Primitive(_, p)) => {
+                self.formatter.fmt_primitive_slice(p).0.into()
+            }
+            Type::Slice(hir::Slice::Strs(..)) => {
+                format!("List<{}>", self.formatter.fmt_string())
+            }
+            Type::Trait(ref t) => {
+                let def = t.resolve(self.tcx);
+                self.errors.push_error(format!(
+                    "Traits are not supported in Kotlin, found {}",
+                    def.name
+                ));
+                "Any?".into()
+            }
+            Type::Func(..) => {
+                self.errors
+                    .push_error("Callbacks are not supported in Kotlin".into());
+                "Any?".into()
+            }
+            _ => unreachable!("unknown AST/HIR variant"),
+        }
+    }
+
+    fn gen_named_type(&mut self, id: TypeId) -> String {
+        let type_name = self.formatter.fmt_type_name(id);
+        if self.tcx.resolve_type(id).attrs().disable {
+            self.errors
+                .push_error(format!("Found usage of disabled type {type_name}"))
+        }
+        type_name.into_owned()
+    }
+
+    fn gen_type_name_for_success(&mut self, success: &SuccessType) -> String {
+        match success {
+            SuccessType::Unit => "Unit".into(),
+            SuccessType::Writeable => self.formatter.fmt_string().into(),
+            SuccessType::OutType(o) => self.gen_type_name(o),
+            _ => unreachable!("unknown AST/HIR variant"),
+        }
+    }
+
+    /// Generates a return type's Kotlin type.
+    fn gen_return_type_name(&mut self, output: &ReturnType) -> String {
+        match output {
+            ReturnType::Infallible(ok) | ReturnType::Fallible(ok, _) => {
+                self.gen_type_name_for_success(ok)
+            }
+            ReturnType::Nullable(SuccessType::Unit) => "Boolean".into(),
+            ReturnType::Nullable(ok) => {
+                let ok = self.gen_type_name_for_success(ok);
+                if ok.ends_with('?') {
+                    ok
+                } else {
+                    self.formatter.fmt_nullable(&ok)
+                }
+            }
+        }
+    }
+
+    /// Generates the type JNA passes a type as.
+    ///
+    /// Structures are embedded in other structures, and passed `ByValue` to
+    /// and from functions.
+    fn gen_ffi_type<P: TyPosition>(&mut self, ty: &Type<P>, by_value: bool) -> String {
+        let by_value = if by_value { ".ByValue" } else { "" };
+        match *ty {
+            Type::Primitive(prim) => self.formatter.fmt_primitive_as_ffi(prim).into(),
+            Type::Opaque(_) | Type::Trait(_) | Type::Func(_) => {
+                self.formatter.fmt_opaque_as_ffi().into()
+            }
+            Type::Struct(ref st) => {
+                let name = self.formatter.fmt_type_name(st.id());
+                format!("{}{by_value}", self.formatter.fmt_native_name(&name))
+            }
+            Type::Enum(ref e) if e.resolve(self.tcx).is_data_carrying() => {
+                let name = self.formatter.fmt_type_name(e.tcx_id.into());
+                format!("{}{by_value}", self.formatter.fmt_native_name(&name))
+            }
+            Type::Enum(_) => self.formatter.fmt_enum_as_ffi().into(),
+            Type::Slice(_) => format!("{}{by_value}", self.formatter.fmt_slice_as_ffi()),
+            _ => unreachable!("unknown AST/HIR variant"),
+        }
+    }
+
+    /// Generates the initial value of a structure field.
+    fn gen_ffi_default<P: TyPosition>(&mut self, ty: &Type<P>) -> String {
+        use diplomat_core::hir::{FloatType, IntSizeType, PrimitiveType};
+        match *ty {
+            Type::Primitive(PrimitiveType::Float(FloatType::F32)) => "0f".into(),
+            Type::Primitive(PrimitiveType::Float(FloatType::F64)) => "0.0".into(),
+            Type::Primitive(PrimitiveType::IntSize(IntSizeType::Isize)) => "SSizeT()".into(),
+            Type::Primitive(PrimitiveType::IntSize(IntSizeType::Usize)) => "SizeT()".into(),
+            Type::Primitive(_) | Type::Enum(_) if !self.needs_keep(ty) => "0".into(),
+            Type::Opaque(_) | Type::Trait(_) | Type::Func(_) => "null".into(),
+            _ => format!("{}()", self.gen_ffi_type(ty, false)),
+        }
+    }
+
+    /// Generates the return type of a C function, declaring the structure
+    /// holding its result if it is fallible.
+    fn gen_return_type_ffi(&mut self, output: &'cx ReturnType) -> String {
+        match output {
+            ReturnType::Infallible(SuccessType::OutType(o)) => self.gen_ffi_type(o, true),
+            ReturnType::Infallible(_) => "Unit".into(),
+            ReturnType::Fallible(ok, _) | ReturnType::Nullable(ok) => {
+                let ok = ok.as_type();
+                let err = match output {
+                    ReturnType::Fallible(_, err) => err.as_ref(),
+                    _ => None,
+                };
+                let name = self.formatter.fmt_result_name(ok, err);
+                if !self.result_natives.contains_key(&name) {
+                    let union_name = format!("{name}Union");
+                    let mut union_fields = Vec::new();
+                    for (field, ty) in [("ok", ok), ("err", err)] {
+                        if let Some(ty) = ty {
+                            union_fields.push(self.gen_native_field(field, ty));
+                        }
+                    }
+                    let mut fields = Vec::new();
+                    // JNA unions need at least one field
+                    if !union_fields.is_empty() {
+                        fields.push(NativeField {
+                            name: "union".into(),
+                            ty: union_name.clone(),
+                            default: format!("{union_name}()"),
+                        });
+                        let union = self.gen_native(&union_name, true, union_fields);
+                        self.result_natives.insert(union_name, union);
+                    }
+                    fields.push(NativeField {
+                        name: "isOk".into(),
+                        ty: "Byte".into(),
+                        default: "0".into(),
+                    });
+                    let native = self.gen_native(&name, false, fields);
+                    self.result_natives.insert(name.clone(), native);
+                }
+                format!("{name}.ByValue")
+            }
+        }
+    }
+
+    /// Generates the Kotlin expression passing a slice, which evaluates to a
+    /// `DiplomatSlice`.
+    fn gen_slice_to_native(&self, slice: &hir::Slice, value: &str) -> String {
+        // Owned slices are allocated by Rust, everything else lives in `keep`
+        let keep = if slice.lifetime().is_some() {
+            "keep"
+        } else {
+            "null"
+        };
+        let function = match *slice {
+            hir::Slice::Str(_, hir::StringEncoding::UnvalidatedUtf16) => "str16".into(),
+            hir::Slice::Str(..) => "str8".into(),
+            hir::Slice::Primitive(_, p) => {
+                let suffix = self.formatter.fmt_primitive_slice(p).1;
+                suffix[..1].to_lowercase() + &suffix[1..]
+            }
+            hir::Slice::Strs(hir::StringEncoding::UnvalidatedUtf16) => "strs16".into(),
+            hir::Slice::Strs(_) => "strs8".into(),
+            _ => unreachable!("unknown AST/HIR variant"),
+        };
+        format!("DiplomatRuntime.{function}({value}, {keep})")
+    }
+
+    /// Generates the Kotlin expression reading a member of the union in `result`.
+    fn gen_read_union<P: TyPosition>(&mut self, member: &str, ty: &Type<P>) -> String {
+        format!(
+            "(result.union.readField(\"{member}\") as {})",
+            self.gen_ffi_type(ty, false)
+        )
+    }
+
+    /// Generates the Kotlin expression converting a Kotlin value to its native type.
+    ///
+    /// Temporary allocations are added to `keep`.
+    fn gen_kotlin_to_native<P: TyPosition>(&mut self, ty: &Type<P>, value: &str) -> String {
+        use diplomat_core::hir::{IntSizeType, IntType, PrimitiveType};
+        match *ty {
+            Type::Primitive(PrimitiveType::Bool) => format!("(if ({value}) 1 else 0).toByte()"),
+            Type::Primitive(PrimitiveType::Int(IntType::U8) | PrimitiveType::Byte) => {
+                format!("{value}.toByte()")
+            }
+            Type::Primitive(PrimitiveType::Int(IntType::U16)) => format!("{value}.toShort()"),
+            Type::Primitive(PrimitiveType::Int(IntType::U32)) => format!("{value}.toInt()"),
+            Type::Primitive(PrimitiveType::Int(IntType::U64)) => format!("{value}.toLong()"),
+            Type::Primitive(PrimitiveType::IntSize(IntSizeType::Usize)) => {
+                format!("SizeT({value}.toLong())")
+            }
+            Type::Primitive(PrimitiveType::IntSize(IntSizeType::Isize)) => {
+                format!("SSizeT({value})")
+            }
+            Type::Primitive(_) => value.into(),
+            Type::Opaque(ref op) if op.is_optional() => format!("{value}?.handle"),
+            Type::Opaque(_) => format!("{value}.handle"),
+            Type::Struct(_) => format!("{value}.toNative(keep)"),
+            Type::Enum(ref e) if e.resolve(self.tcx).is_data_carrying() => {
+                format!("{value}.toNative(keep)")
+            }
+            Type::Enum(_) => format!("{value}.value"),
+            Type::Slice(ref s) => self.gen_slice_to_native(s, value),
+            Type::Trait(_) | Type::Func(_) => {
+                // Already reported by gen_type_name
+                "null".into()
+            }
+            _ => unreachable!("unknown AST/HIR variant"),
+        }
+    }
+
+    /// Generates the Kotlin expression converting a native value to its Kotlin type.
+    ///
+    /// `edges` is a Kotlin list of everything borrowed values may borrow from.
+    fn gen_native_to_kotlin<P: TyPosition>(
+        &mut self,
+        ty: &Type<P>,
+        value: &str,
+        edges: &str,
+    ) -> String {
+        use diplomat_core::hir::{IntSizeType, IntType, PrimitiveType};
+        match *ty {
+            Type::Primitive(PrimitiveType::Bool) => format!("({value} != 0.toByte())"),
+            Type::Primitive(PrimitiveType::Int(IntType::U8) | PrimitiveType::Byte) => {
+                format!("{value}.toUByte()")
+            }
+            Type::Primitive(PrimitiveType::Int(IntType::U16)) => format!("{value}.toUShort()"),
+            Type::Primitive(PrimitiveType::Int(IntType::U32)) => format!("{value}.toUInt()"),
+            Type::Primitive(PrimitiveType::Int(IntType::U64)) => format!("{value}.toULong()"),
+            Type::Primitive(PrimitiveType::IntSize(IntSizeType::Usize)) => {
+                format!("{value}.toLong().toULong()")
+            }
+            Type::Primitive(PrimitiveType::IntSize(IntSizeType::Isize)) => {
+                format!("{value}.toLong()")
+            }
+            Type::Primitive(_) => value.into(),
+            Type::Opaque(ref op) => {
+                let type_name = self.formatter.fmt_type_name(op.tcx_id.into());
+                let owned = op.owner.is_owned();
+                // Owned opaques only need edges for their own lifetimes
+                let edges = if owned && op.lifetimes.lifetimes().len() == 0 {
+                    "listOf()"
+                } else {
+                    edges
+                };
+                if op.is_optional() {
+                    format!("{value}?.let {{ {type_name}(it, {owned}, {edges}) }}")
+                } else {
+                    format!("{type_name}({value}!!, {owned}, {edges})")
+                }
+            }
+            Type::Struct(ref st) => {
+                let type_name = self.formatter.fmt_type_name(st.id());
+                format!("{type_name}.fromNative({value}, {edges})")
+            }
+            Type::Enum(ref e) => {
+                let type_name = self.formatter.fmt_type_name(e.tcx_id.into());
+                if e.resolve(self.tcx).is_data_carrying() {
+                    format!("{type_name}.fromNative({value}, {edges})")
+                } else {
+                    format!("{type_name}.fromNative({value})")
+                }
+            }
+            Type::Slice(ref s) => {
+                // Slices without a lifetime are owned, so they are freed after copying
+                let owned = s.lifetime().is_none();
+                let read = match s {
+                    hir::Slice::Str(_, hir::StringEncoding::UnvalidatedUtf16) => "Str16",
+                    hir::Slice::Str(..) => "Str8",
+                    hir::Slice::Primitive(_, p) => self.formatter.fmt_primitive_slice(*p).1,
+                    _ => {
+                        self.errors
+                            .push_error("Returning string lists is not supported".into());
+                        "Str8"
+                    }
+                };
+                format!("DiplomatRuntime.read{read}({value}, {owned})")
+            }
+            Type::Trait(_) | Type::Func(_) => "null".into(),
+            _ => unreachable!("unknown AST/HIR variant"),
+        }
+    }
+}
+
+/// The signature of a C function, as declared on the JNA library interface.
+struct Function {
+    c_name: String,
+    /// The parameter declarations
+    params: Vec<String>,
+    return_ty: String,
+}
+
+/// A field of a JNA structure.
+struct NativeField {
+    name: String,
+    ty: String,
+    default: String,
+}
+
+/// The rendered members of a type.
+#[derive(Default)]
+struct MembersInfo {
+    /// Instance members
+    members: Vec<String>,
+    /// Members of the companion object, such as constructors and static methods
+    companion: Vec<String>,
+    /// The interfaces special methods implement
+    supertypes: Vec<String>,
+}
+
+impl MembersInfo {
+    fn add_consts(&mut self, consts: Vec<String>) {
+        self.companion.splice(0..0, consts);
+    }
+}
+
+/// Everything needed for rendering a method.
+struct MethodInfo {
+    /// The Kotlin method name
+    name: String,
+    /// Modifiers such as `override `, including the trailing space
+    modifiers: &'static str,
+    /// The parameter declarations
+    params: Vec<String>,
+    param_names: Vec<String>,
+    return_ty: String,
+    docs: String,
+    /// The statements of the method body
+    body: Vec<String>,
+}
+
+#[derive(Template)]
+#[template(path = "kotlin/method.kt.jinja", escape = "none")]
+struct MethodTemplate<'a> {
+    m: &'a MethodInfo,
+}
+
+/// The getter and/or setter methods making up a property.
+struct PropertyInfo {
+    name: String,
+    is_static: bool,
+    getter: Option<MethodInfo>,
+    setter: Option<MethodInfo>,
+}
+
+/// Everything needed for rendering a property.
+struct PropertyGenInfo {
+    name: String,
+    ty: String,
+    docs: String,
+    getter_body: Vec<String>,
+    setter: Option<SetterInfo>,
+}
+
+struct SetterInfo {
+    /// The name of the new value
+    param: String,
+    body: Vec<String>,
+}
+
+#[derive(Template)]
+#[template(path = "kotlin/property.kt.jinja", escape = "none")]
+struct PropertyTemplate<'a> {
+    p: &'a PropertyGenInfo,
+}
+
+struct FieldInfo {
+    name: String,
+    kotlin_type: String,
+    kotlin_to_native: String,
+    native_to_kotlin: String,
+}
+
+struct EnumVariantInfo {
+    name: String,
+    discriminant: isize,
+    docs: String,
+}
+
+/// Everything needed for rendering a variant of a data-carrying enum.
+struct TaggedVariantInfo {
+    /// The name of the Kotlin subclass for this variant
+    class_name: String,
+    docs: String,
+    discriminant: isize,
+    /// The name of the JNA structure holding the payload
+    native_name: String,
+    /// The name of the payload in the JNA union
+    payload_name: String,
+    fields: Vec<FieldInfo>,
+}
+
+// Helpers used in templates (Askama has restrictions on Rust syntax)
+
+/// Format docs as a KDoc comment, including the trailing newline
+fn kdoc(docs: &str) -> String {
+    if docs.is_empty() {
+        return String::new();
+    }
+    if !docs.contains('\n') {
+        return format!("/** {docs} */\n");
+    }
+    let mut out = "/**\n".to_owned();
+    for line in docs.lines() {
+        if line.is_empty() {
+            out.push_str(" *\n");
+        } else {
+            out.push_str(" * ");
+            out.push_str(line);
+            out.push('\n');
+        }
+    }
+    out.push_str(" */\n");
+    out
+}
+
+/// Indent every line, leaving blank lines empty
+fn indent(text: &str, width: usize) -> String {
+    let indent = " ".repeat(width);
+    text.split('\n')
+        .map(|line| {
+            if line.is_empty() {
+                String::new()
+            } else {
+                format!("{indent}{line}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The name of a field as JNA sees it, without the backticks escaping keywords
+fn unescape(name: &str) -> &str {
+    name.trim_matches('`')
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_slice_params() {
+        test_file! {
+            #[diplomat::bridge]
+            mod ffi {
+                #[diplomat::opaque]
+                pub struct Buffer;
+
+                impl Buffer {
+                    pub fn from_bytes(data: &[u8]) -> Box<Buffer> {
+                        unimplemented!()
+                    }
+                    pub fn scale(&self, values: &mut [f64], factor: f64) {}
+                    pub fn count_set(flags: &[bool]) -> usize {
+                        unimplemented!()
+                    }
+                    pub fn from_utf8(text: &DiplomatStr) -> Box<Buffer> {
+                        unimplemented!()
+                    }
+                    pub fn from_utf16(text: &DiplomatStr16) -> Box<Buffer> {
+                        unimplemented!()
+                    }
+                    pub fn join(parts: &[&DiplomatStr]) -> Box<Buffer> {
+                        unimplemented!()
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_slice_returns() {
+        test_file! {
+            #[diplomat::bridge]
+            mod ffi {
+                #[diplomat::opaque]
+                pub struct Buffer(Vec<u8>);
+
+                impl Buffer {
+                    pub fn bytes<'a>(&'a self) -> &'a [u8] {
+                        unimplemented!()
+                    }
+                    pub fn text<'a>(&'a self) -> &'a DiplomatStr {
+                        unimplemented!()
+                    }
+                    pub fn copy(&self) -> Box<[u8]> {
+                        unimplemented!()
+                    }
+                    pub fn wide_copy(&self) -> Box<[u16]> {
+                        unimplemented!()
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_catch_panics() {
+        test_file! {
+            #[diplomat::bridge]
+            mod ffi {
+                pub enum MyError {
+                    Panicked,
+                    Other,
+                }
+
+                #[diplomat::opaque]
+                pub struct Thing;
+
+                #[diplomat::catch_panics]
+                impl Thing {
+                    pub fn might_panic(x: u32) -> u32 {
+                        unimplemented!()
+                    }
+                    pub fn might_fail(x: u32) -> Result<u32, MyError> {
+                        unimplemented!()
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,6 +1,7 @@
 // Enable once https://github.com/rust-lang/rust/issues/89554 is stable
 // #![deny(non_exhaustive_omitted_patterns)] // diplomat_core uses non_exhaustive a lot; we should never miss its patterns
 
+pub mod backend;
 #[doc(hidden)]
 pub mod c;
 #[doc(hidden)]
@@ -16,267 +17,585 @@ pub mod dart;
 #[doc(hidden)]
 pub mod dotnet;
 #[doc(hidden)]
+pub mod go;
+#[doc(hidden)]
 pub mod js;
+#[doc(hidden)]
+pub mod kotlin;
+pub mod project;
+#[doc(hidden)]
+pub mod python;
+#[doc(hidden)]
+pub mod rust;
+#[doc(hidden)]
+pub mod swift;
+#[doc(hidden)]
+pub mod wit;
 
+mod api_layout;
+mod cfg;
+mod check;
+mod diagnostics;
+mod diff;
+mod ir;
 mod docs_util;
+mod error;
+mod event;
 mod layout;
+mod output;
 mod util;
+mod watch;
 
-use colored::*;
-use core::panic;
-use diplomat_core::{ast, hir};
-use std::collections::HashMap;
-use std::fs::File;
-use std::io::Write;
-use std::path::Path;
+use diplomat_core::{ast, hir, Env};
+use std::collections::{hash_map, BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
 
 pub use ast::DocsUrlGenerator;
+pub use backend::{Backend, Config, Registry};
+pub use cfg::CfgOptions;
+pub use check::Mismatch;
+pub use common::FileMap;
+pub use diagnostics::{Diagnostics, MessageFormat, Style};
+pub use diff::{Change, Severity};
+pub use error::Error;
+pub use event::Event;
+pub use ir::IR_VERSION;
+pub use project::Project;
+pub use watch::watch_project;
 
 pub struct ApiInfo<'a> {
     pub apiname: &'a str,
     pub refresh_api_fn: &'a str,
     pub get_api_fn: &'a str,
     pub additional_includes: &'a [&'a str],
+    /// The file the layout of the table is kept in, which makes it append-only. It's the
+    /// same file the `api_layout` of the bridge names.
+    pub layout_file: Option<&'a Path>,
+    /// Whether cpp2 generates a loader that opens the library at runtime and can reload
+    /// it, which the wrappers get the table from instead of `get_api_fn` being defined
+    /// in one of the `additional_includes`
+    pub loader: bool,
 }
 
-/// Provide nice error messages if a folder doesn't exist.
-fn exit_if_path_missing(path: &Path, message: &str) {
-    if !path.exists() {
-        let current_dir = std::env::current_dir().expect("Filed to load current directory.");
-        eprintln!(
-            "{}{}\n{}",
-            "Error: ".red().bold(),
-            message,
-            format!("{}", Path::new(&current_dir).join(path).display()).red()
-        );
-        std::process::exit(1);
+/// Fail with a nice error message if a path doesn't exist.
+fn check_path_exists(path: &Path, message: &'static str) -> Result<(), Error> {
+    if path.exists() {
+        Ok(())
+    } else {
+        Err(Error::missing_path(message, path))
     }
 }
 
+/// Lower the bridge crate, keeping the sources around if that fails so that the
+/// errors can be shown with snippets
+fn lower(
+    env: &Env,
+    attr_validator: impl hir::AttributeValidator + 'static,
+    diagnostics: &Diagnostics,
+) -> Result<hir::TypeContext, Error> {
+    hir::TypeContext::from_ast(env, attr_validator).map_err(|errors| Error::Lowering {
+        errors,
+        diagnostics: diagnostics.clone(),
+    })
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn gen(
     entry: &Path,
+    cfg: Option<&ast::CfgSet>,
+    crates: &[(String, PathBuf)],
     target_language: &str,
+    registry: &Registry,
     out_folder: &Path,
     docs_out_folder: Option<&Path>,
     docs_url_gen: &ast::DocsUrlGenerator,
     library_config: Option<&Path>,
-    silent: bool,
+    on_event: impl FnMut(Event),
+    check: bool,
     strip_prefix: Option<String>,
     api_info: Option<ApiInfo>,
-) -> std::io::Result<()> {
-    // Check that user-provided paths exist. Exit early with a nice error message
-    // if anything doesn't exist.
-    exit_if_path_missing(
+    message_format: MessageFormat,
+) -> Result<(), Error> {
+    let (diplomat_file, mut diagnostics) = load_entry(entry, cfg, message_format)?;
+    let crates = load_crates(crates, message_format, &mut diagnostics)?;
+    let crate_name = crate_name(entry);
+    gen_from_file(diplomat_file, &crates, crate_name.as_deref(), target_language, registry, out_folder, docs_out_folder, docs_url_gen, library_config, on_event, check, strip_prefix, api_info, &diagnostics)
+}
+
+/// Parse the bridge crate rooted at `entry`, keeping the loaded sources
+/// around so that lowering errors can show snippets.
+///
+/// With a `cfg`, the items whose `#[cfg]`s don't hold for it are left out.
+fn load_entry(entry: &Path, cfg: Option<&ast::CfgSet>, message_format: MessageFormat) -> Result<(ast::File, Diagnostics), Error> {
+    check_path_exists(
         entry,
         if entry.file_name().map(|e| e == "lib.rs").unwrap_or_default() {
             "Could not find the lib.rs file to process."
         } else {
             "The entry file does not exist."
         },
-    );
-    let lib_file = syn_inline_mod::parse_and_inline_modules(entry);
-    let diplomat_file = ast::File::from(&lib_file);
-    gen_from_file(diplomat_file, target_language, out_folder, docs_out_folder, docs_url_gen, library_config, silent, strip_prefix, api_info)
+    )?;
+    let mut diagnostics = Diagnostics::new(message_format);
+    let (lib_file, _) = syn_inline_mod::InlinerBuilder::default()
+        .inline_with_callback(entry, |path, contents| {
+            diagnostics.add_source(path, contents)
+        })
+        .map_err(|e| Error::Load {
+            path: entry.to_owned(),
+            message: e.to_string(),
+        })?
+        .into_output_and_errors();
+    let diplomat_file = match cfg {
+        Some(cfg) => ast::File::from_syn_with_cfg(&lib_file, cfg),
+        None => ast::File::from(&lib_file),
+    };
+    Ok((diplomat_file, diagnostics))
+}
+
+/// The name of the crate `entry` is in, from the closest `Cargo.toml` above it, as it
+/// is written in Rust paths
+fn crate_name(entry: &Path) -> Option<String> {
+    let entry = entry.canonicalize().ok()?;
+    let manifest = entry
+        .ancestors()
+        .skip(1)
+        .map(|dir| dir.join("Cargo.toml"))
+        .find(|manifest| manifest.is_file())?;
+    let manifest: toml::Value = toml::from_str(&std::fs::read_to_string(manifest).ok()?).ok()?;
+    let name = manifest.get("package")?.get("name")?.as_str()?;
+    Some(name.replace('-', "_"))
+}
+
+/// Parse the bridges of the other crates the bridge crate uses types from, keyed by
+/// crate name, adding their sources to `diagnostics`
+fn load_crates(
+    crates: &[(String, PathBuf)],
+    message_format: MessageFormat,
+    diagnostics: &mut Diagnostics,
+) -> Result<Vec<(String, ast::File)>, Error> {
+    crates
+        .iter()
+        .map(|(name, entry)| {
+            // The cfg set is the one of the bridge crate, so it doesn't apply
+            let (file, crate_diagnostics) = load_entry(entry, None, message_format)?;
+            diagnostics.extend(crate_diagnostics);
+            Ok((name.clone(), file))
+        })
+        .collect()
+}
+
+/// Compare two versions of a bridge crate, returning how they differ.
+///
+/// Any of the changes that isn't [`Severity::Compatible`] breaks binaries or
+/// bindings built against the old version.
+pub fn diff(
+    old_entry: &Path,
+    new_entry: &Path,
+    message_format: MessageFormat,
+    mut on_event: impl FnMut(Event),
+) -> Result<Vec<Change>, Error> {
+    let mut lower_entry = |entry: &Path| {
+        let (diplomat_file, diagnostics) = load_entry(entry, None, message_format)?;
+        report_ast_errors(&diplomat_file, &[], &diagnostics, &mut on_event);
+        lower(&diplomat_file.all_types(), diff::attribute_validator(), &diagnostics)
+    };
+    let old = lower_entry(old_entry)?;
+    let new = lower_entry(new_entry)?;
+    Ok(diff::compare(&old, &new))
+}
+
+/// Write the fully lowered bridge crate rooted at `entry` to `out` as JSON,
+/// resolving attributes for the backend named `backend`.
+///
+/// See the `ir` module for the schema.
+pub fn ir(
+    entry: &Path,
+    cfg: Option<&ast::CfgSet>,
+    out: &Path,
+    backend: &str,
+    docs_url_gen: &ast::DocsUrlGenerator,
+    message_format: MessageFormat,
+    mut on_event: impl FnMut(Event),
+) -> Result<(), Error> {
+    let (diplomat_file, diagnostics) = load_entry(entry, cfg, message_format)?;
+    report_ast_errors(&diplomat_file, &[], &diagnostics, &mut on_event);
+    let tcx = lower(
+        &diplomat_file.all_types(),
+        ir::attribute_validator(backend),
+        &diagnostics,
+    )?;
+    std::fs::write(out, ir::to_json(&tcx, backend, docs_url_gen))?;
+    Ok(())
 }
 
 #[allow(clippy::too_many_arguments)]
 pub fn gen_from_file(
     diplomat_file: ast::File,
+    crates: &[(String, ast::File)],
+    crate_name: Option<&str>,
     target_language: &str,
+    registry: &Registry,
     out_folder: &Path,
     docs_out_folder: Option<&Path>,
     docs_url_gen: &ast::DocsUrlGenerator,
     library_config: Option<&Path>,
-    silent: bool,
+    mut on_event: impl FnMut(Event),
+    check: bool,
     strip_prefix: Option<String>,
     api_info: Option<ApiInfo>,
-) -> std::io::Result<()> {
-    exit_if_path_missing(out_folder, "The out folder does not exist.");
-    if let Some(docs_out_folder) = docs_out_folder {
-        exit_if_path_missing(docs_out_folder, "The docs folder does not exist.");
-    }
-    if let Some(library_config) = library_config {
-        exit_if_path_missing(
-            library_config,
-            "The library configuration file does not exist.",
-        );
-    }
-
-    let env = diplomat_file.all_types();
-
-    let mut out_texts: HashMap<String, String> = HashMap::new();
-
-    let mut errors_found = false;
-
-    match target_language {
-        "js" => js::gen_bindings(&env, &mut out_texts, Some(docs_url_gen)).unwrap(),
-        "dart" => {
-            let mut attr_validator = hir::BasicAttributeValidator::new("dart");
-            attr_validator.support.renaming = true;
-            attr_validator.support.disabling = true;
-            attr_validator.support.constructors = true;
-            attr_validator.support.named_constructors = true;
-            attr_validator.support.fallible_constructors = true;
-            attr_validator.support.accessors = true;
-            attr_validator.support.stringifiers = true;
-            attr_validator.support.comparators = true;
-            attr_validator.support.iterators = true;
-            attr_validator.support.iterables = true;
-            attr_validator.support.indexing = true;
-            let tcx = match hir::TypeContext::from_ast(&env, attr_validator) {
-                Ok(context) => context,
-                Err(e) => {
-                    for (ctx, err) in e {
-                        eprintln!("Lowering error in {ctx}: {err}");
-                    }
-                    std::process::exit(1);
-                }
-            };
-            match dart::run(&tcx, docs_url_gen, strip_prefix) {
-                Ok(mut files) => out_texts = files.take_files(),
-                Err(errors) => {
-                    eprintln!("Found errors whilst generating {target_language}:");
-                    for error in errors {
-                        eprintln!("\t{}: {}", error.0, error.1);
-                    }
-                    errors_found = true;
+    diagnostics: &Diagnostics,
+) -> Result<(), Error> {
+    let backend = find_backend(registry, target_language)?;
+    check_crates(backend, target_language, crates)?;
+    check_output_paths(out_folder, docs_out_folder)?;
+    let library_config = match library_config {
+        Some(path) => {
+            check_path_exists(path, "The library configuration file does not exist.")?;
+            let config = common::read_library_config::<toml::Value>(path).map_err(|message| {
+                Error::Load {
+                    path: path.to_owned(),
+                    message,
                 }
-            };
-        }
-        "c" => c::gen_bindings(&env, &mut out_texts).unwrap(),
-        "cpp" => {
-            c::gen_bindings(&env, &mut out_texts).unwrap();
-            cpp::gen_bindings(&env, library_config, docs_url_gen, &mut out_texts).unwrap()
-        }
-        "dotnet" => {
-            dotnet::gen_bindings(&env, library_config, docs_url_gen, &mut out_texts).unwrap()
+            })?;
+            Some(config)
         }
-        "c2" | "cpp-c2" | "cpp2" => {
-            let mut attr_validator = hir::BasicAttributeValidator::new(target_language);
-
-            if target_language == "c2" {
-                attr_validator.other_backend_names.push("c".into());
-            } else {
-                attr_validator.other_backend_names.push("cpp".into());
-                // C backends cannot rename types using backend attributes
-                // In the future we may add a c_rename attribute
-                attr_validator.support.renaming = true;
-
-                attr_validator.support.namespacing = true;
-            }
+        None => None,
+    };
 
-            attr_validator.support.memory_sharing = true;
-            attr_validator.support.disabling = true;
-            // cpp-c2 is a testing backend, we're not going to treat it as a real c/cpp backend
-            // since the ast-cpp backend doesn't know about attributes.
-
-            let tcx = match hir::TypeContext::from_ast(&env, attr_validator) {
-                Ok(context) => context,
-                Err(e) => {
-                    for (ctx, err) in e {
-                        eprintln!("Lowering error in {ctx}: {err}");
-                    }
-                    std::process::exit(1);
-                }
-            };
-            let files = common::FileMap::default();
-            let mut context = c2::CContext::new(&tcx, files);
-            context.run(api_info.as_ref());
+    report_ast_errors(&diplomat_file, crates, diagnostics, &mut on_event);
+    let env = all_types(&diplomat_file, crates);
+    let tcx = lower_for(backend, &env, &BTreeMap::new(), diagnostics)?;
+    let api_layout = match &api_info {
+        Some(api_info) => Some(api_layout::load(&env, api_info)?),
+        None => None,
+    };
 
-            let errors = context.errors.take_all();
+    let config = Config {
+        env: &env,
+        library_config: library_config.as_ref(),
+        docs_url_gen,
+        strip_prefix,
+        crate_name,
+        api_info: api_info.as_ref(),
+        api_layout: api_layout.as_ref(),
+    };
+    let mut mismatches = generate(
+        backend,
+        target_language,
+        &tcx,
+        &config,
+        out_folder,
+        docs_out_folder,
+        check,
+        &mut on_event,
+    )?;
+    if let Some((api_info, api_layout)) = api_info.as_ref().zip(api_layout.as_ref()) {
+        mismatches.extend(api_layout::save(api_info, api_layout, check, &mut on_event)?);
+    }
+    out_of_date(mismatches)
+}
 
-            if !errors.is_empty() {
-                eprintln!("Found errors whilst generating {target_language}:");
-                for error in errors {
-                    eprintln!("\t{}: {}", error.0, error.1);
-                }
-                errors_found = true;
-            }
+/// Generate the bindings configured in a `diplomat.toml`, parsing the bridge crate only once.
+///
+/// `targets` are the names of the targets in it to generate, or `None` to generate all of them.
+pub fn gen_project(
+    project_file: &Path,
+    targets: Option<&[String]>,
+    registry: &Registry,
+    mut on_event: impl FnMut(Event),
+    check: bool,
+    message_format: MessageFormat,
+) -> Result<(), Error> {
+    gen_project_from_sources(
+        project_file,
+        targets,
+        registry,
+        &mut on_event,
+        check,
+        message_format,
+        &mut Vec::new(),
+    )
+}
+
+/// [`gen_project`], recording the files it read in `sources` as it goes, so that
+/// they are known even if generating fails
+fn gen_project_from_sources(
+    project_file: &Path,
+    targets: Option<&[String]>,
+    registry: &Registry,
+    on_event: &mut dyn FnMut(Event),
+    check: bool,
+    message_format: MessageFormat,
+    sources: &mut Vec<PathBuf>,
+) -> Result<(), Error> {
+    let project = Project::load(project_file)?;
+    sources.push(project.entry.clone());
+    sources.extend(project.deps.values().cloned());
+    let names: Vec<&str> = match targets {
+        Some(targets) => targets.iter().map(String::as_str).collect(),
+        None => project.targets.keys().map(String::as_str).collect(),
+    };
+
+    // Check the whole project before generating anything, so that a typo in
+    // the last target doesn't leave the others regenerated
+    let mut resolved = Vec::new();
+    for name in names {
+        let target = project
+            .targets
+            .get(name)
+            .ok_or_else(|| Error::UnknownTarget {
+                target: name.to_owned(),
+                available: project.targets.keys().cloned().collect(),
+            })?;
+        let backend = find_backend(registry, target.backend(name))?;
+        if !project.deps.is_empty() && !backend.imports_external_types() {
+            return Err(Error::ExternalTypesUnsupported {
+                target: name.to_owned(),
+            });
+        }
+        check_output_paths(&target.out, target.docs.as_deref())?;
+        resolved.push((name, target, backend));
+    }
 
-            out_texts = context.files.take_files();
+    let cfg = project.build.resolve(&project.entry)?;
+    let (diplomat_file, mut diagnostics) = load_entry(&project.entry, cfg.as_ref(), message_format)?;
+    let deps: Vec<(String, PathBuf)> = project
+        .deps
+        .iter()
+        .map(|(name, entry)| (name.clone(), entry.clone()))
+        .collect();
+    let crates = load_crates(&deps, message_format, &mut diagnostics)?;
+    sources.extend(diagnostics.source_paths().map(Path::to_owned));
+    report_ast_errors(&diplomat_file, &crates, &diagnostics, on_event);
+    let env = all_types(&diplomat_file, &crates);
+    let crate_name = crate_name(&project.entry);
 
-            if target_language == "cpp-c2" {
-                cpp::gen_bindings(&env, library_config, docs_url_gen, &mut out_texts).unwrap()
+    // Which attributes apply depends on the backend and the cfg, but targets
+    // that agree on both can share a lowering
+    let mut lowered = HashMap::new();
+    let mut mismatches = Vec::new();
+    // Targets that share a layout file only update it once
+    let mut saved_layouts = HashSet::new();
+    for (name, target, backend) in resolved {
+        let tcx = match lowered.entry((backend.name(), &target.cfg)) {
+            hash_map::Entry::Occupied(entry) => entry.into_mut(),
+            hash_map::Entry::Vacant(entry) => {
+                entry.insert(lower_for(backend, &env, &target.cfg, &diagnostics)?)
             }
-            if target_language == "cpp2" {
-                let files = common::FileMap::default();
-                let mut context = cpp2::Cpp2Context::new(&tcx, files);
-                context.run(api_info.as_ref());
-                out_texts.extend(context.c.files.take_files());
-
-                let errors = context.errors.take_all();
-
-                if !errors.is_empty() {
-                    eprintln!("Found errors whilst generating {target_language}:");
-                    for error in errors {
-                        eprintln!("\t{}: {}", error.0, error.1);
-                    }
-                    errors_found = true;
-                }
+        };
+
+        let additional_includes: Vec<&str> = target
+            .api
+            .iter()
+            .flat_map(|api| &api.includes)
+            .map(String::as_str)
+            .collect();
+        let api_info = target.api.as_ref().map(|api| ApiInfo {
+            apiname: &api.name,
+            refresh_api_fn: &api.refresh_fn,
+            get_api_fn: &api.get_fn,
+            additional_includes: &additional_includes,
+            layout_file: api.layout.as_deref(),
+            loader: api.loader,
+        });
+        let api_layout = match &api_info {
+            Some(api_info) => Some(api_layout::load(&env, api_info)?),
+            None => None,
+        };
+        let docs_url_gen = project.docs_url_gen(target);
+        let config = Config {
+            env: &env,
+            library_config: target.options.as_ref(),
+            docs_url_gen: &docs_url_gen,
+            strip_prefix: target.strip_prefix.clone(),
+            crate_name: crate_name.as_deref(),
+            api_info: api_info.as_ref(),
+            api_layout: api_layout.as_ref(),
+        };
+        mismatches.extend(generate(
+            backend,
+            name,
+            tcx,
+            &config,
+            &target.out,
+            target.docs.as_deref(),
+            check,
+            on_event,
+        )?);
+        if let Some((api_info, api_layout)) = api_info.as_ref().zip(api_layout.as_ref()) {
+            let layout_file = target.api.as_ref().and_then(|api| api.layout.as_deref());
+            if saved_layouts.insert(layout_file) {
+                mismatches.extend(api_layout::save(api_info, api_layout, check, on_event)?);
             }
         }
-        o => panic!("Unknown target: {}", o),
     }
 
-    if errors_found {
-        eprintln!("Not generating files due to errors");
-        // Eventually this should use eyre or something
-        std::process::exit(1);
+    out_of_date(mismatches)
+}
+
+fn find_backend<'a>(registry: &'a Registry, name: &str) -> Result<&'a dyn Backend, Error> {
+    registry.get(name).ok_or_else(|| Error::UnknownTarget {
+        target: name.to_owned(),
+        available: registry.names().map(ToOwned::to_owned).collect(),
+    })
+}
+
+fn check_crates(
+    backend: &dyn Backend,
+    target: &str,
+    crates: &[(String, ast::File)],
+) -> Result<(), Error> {
+    if crates.is_empty() || backend.imports_external_types() {
+        Ok(())
+    } else {
+        Err(Error::ExternalTypesUnsupported {
+            target: target.to_owned(),
+        })
     }
+}
 
-    if !silent {
-        println!(
-            "{}",
-            format!("Generating {} bindings:", target_language)
-                .green()
-                .bold()
-        );
+fn check_output_paths(out_folder: &Path, docs_out_folder: Option<&Path>) -> Result<(), Error> {
+    check_path_exists(out_folder, "The out folder does not exist.")?;
+    if let Some(docs_out_folder) = docs_out_folder {
+        check_path_exists(docs_out_folder, "The docs folder does not exist.")?;
     }
+    Ok(())
+}
 
-    for (subpath, text) in out_texts {
-        let out_path = out_folder.join(subpath);
-        let parent = out_path.parent().unwrap();
-        std::fs::create_dir_all(parent).unwrap();
-        let mut out_file = File::create(&out_path)?;
-        out_file.write_all(text.as_bytes())?;
-        if !silent {
-            println!("{}", format!("  {}", out_path.display()).dimmed());
-        }
+/// Items with errors are left out of the AST, so we can still generate
+/// bindings for the rest of the crate
+fn report_ast_errors(
+    diplomat_file: &ast::File,
+    crates: &[(String, ast::File)],
+    diagnostics: &Diagnostics,
+    on_event: &mut dyn FnMut(Event),
+) {
+    let ast_errors: Vec<&syn::Error> = crates
+        .iter()
+        .flat_map(|(_, file)| file.all_errors())
+        .chain(diplomat_file.all_errors())
+        .collect();
+    if !ast_errors.is_empty() {
+        on_event(Event::AstErrors {
+            errors: &ast_errors,
+            diagnostics,
+        });
     }
+}
 
-    if let Some(docs_out_folder) = docs_out_folder {
-        if !silent {
-            println!(
-                "{}",
-                format!("Generating {} docs:", target_language)
-                    .green()
-                    .bold()
-            );
-        }
+/// The types of the bridge crate and of the bridges of the `crates` it uses types from
+fn all_types(diplomat_file: &ast::File, crates: &[(String, ast::File)]) -> Env {
+    diplomat_file.all_types_with_crates(crates.iter().map(|(name, file)| (name.as_str(), file)))
+}
 
-        let mut docs_out_texts: HashMap<String, String> = HashMap::new();
+/// Lower the bridge crate for `backend`, with `cfg` deciding which
+/// `#[diplomat::attr(name = value, ...)]`s apply
+fn lower_for(
+    backend: &dyn Backend,
+    env: &Env,
+    cfg: &BTreeMap<String, Vec<String>>,
+    diagnostics: &Diagnostics,
+) -> Result<hir::TypeContext, Error> {
+    // Backends that only read the AST don't validate attributes, so their
+    // bridge crates might not lower. They still need every type to exist.
+    if backend.uses_ast() {
+        hir::TypeContext::check_paths(env).map_err(|errors| Error::Lowering {
+            errors,
+            diagnostics: diagnostics.clone(),
+        })?;
+        return lower(&Env::default(), backend.attr_validator(), diagnostics);
+    }
+    let mut validator = backend.attr_validator();
+    if !cfg.is_empty() {
+        let cfg = cfg.clone();
+        let backend_is_name_value = validator.is_name_value.take();
+        validator.is_name_value = Some(Box::new(move |name, value| {
+            cfg.get(name)
+                .map(|values| values.iter().any(|v| v == value))
+                .unwrap_or_default()
+                || backend_is_name_value
+                    .as_ref()
+                    .map(|is_name_value| is_name_value(name, value))
+                    .unwrap_or_default()
+        }));
+    }
+    lower(env, validator, diagnostics)
+}
 
-        match target_language {
-            "js" => js::docs::gen_docs(&env, &mut docs_out_texts, docs_url_gen).unwrap(),
-            "cpp" | "cpp-c2" => {
-                cpp::docs::gen_docs(&env, library_config, &mut docs_out_texts, docs_url_gen)
-                    .unwrap()
-            }
-            "c" => todo!("Docs generation for C"),
-            "dotnet" => todo!("Docs generation for .NET?"),
-            o => panic!("Unknown target: {}", o),
-        }
+/// Generate the bindings (and docs, if there is a folder for them) and write them out.
+/// Nothing is written if the backend finds any errors.
+///
+/// With `check`, nothing is written at all, and the files on disk that don't match
+/// what would have been written are returned instead.
+#[allow(clippy::too_many_arguments)]
+fn generate(
+    backend: &dyn Backend,
+    target_language: &str,
+    tcx: &hir::TypeContext,
+    config: &Config,
+    out_folder: &Path,
+    docs_out_folder: Option<&Path>,
+    check: bool,
+    on_event: &mut dyn FnMut(Event),
+) -> Result<Vec<Mismatch>, Error> {
+    let backend_error = |errors| Error::Backend {
+        target: target_language.to_owned(),
+        errors,
+    };
 
-        for (subpath, text) in docs_out_texts {
-            let out_path = docs_out_folder.join(subpath);
-            let mut out_file = File::create(&out_path)?;
-            out_file.write_all(text.as_bytes())?;
-            if !silent {
-                println!("{}", format!("  {}", out_path.display()).dimmed());
+    let mut out_texts = backend
+        .generate(tcx, config)
+        .map_err(backend_error)?
+        .take_files();
+    output::add_manifest(&mut out_texts);
+
+    // Generate the docs before writing anything, so that nothing is written
+    // if they fail
+    let docs_out_texts = match docs_out_folder {
+        Some(docs_out_folder) => match backend.generate_docs(tcx, config) {
+            Some(files) => {
+                let mut docs_out_texts = files.map_err(backend_error)?.take_files();
+                output::add_manifest(&mut docs_out_texts);
+                Some((docs_out_folder, docs_out_texts))
+            }
+            None => {
+                return Err(Error::DocsUnsupported {
+                    target: target_language.to_owned(),
+                })
             }
+        },
+        None => None,
+    };
+
+    if check {
+        let mut mismatches = check::compare(out_folder, &out_texts)?;
+        if let Some((docs_out_folder, docs_out_texts)) = docs_out_texts {
+            mismatches.extend(check::compare(docs_out_folder, &docs_out_texts)?);
         }
+        return Ok(mismatches);
     }
 
-    Ok(())
+    on_event(Event::Generating {
+        target: target_language,
+        docs: false,
+    });
+    output::write_files(out_folder, out_texts, on_event)?;
+
+    if let Some((docs_out_folder, docs_out_texts)) = docs_out_texts {
+        on_event(Event::Generating {
+            target: target_language,
+            docs: true,
+        });
+        output::write_files(docs_out_folder, docs_out_texts, on_event)?;
+    }
+
+    Ok(Vec::new())
+}
+
+fn out_of_date(mismatches: Vec<Mismatch>) -> Result<(), Error> {
+    if mismatches.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::OutOfDate(mismatches))
+    }
 }
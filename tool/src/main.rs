@@ -1,21 +1,49 @@
-use clap::Parser;
-use diplomat_tool::ApiInfo;
+use clap::{Args, CommandFactory, FromArgMatches, Parser, Subcommand};
+use colored::*;
+use diplomat_tool::{ApiInfo, CfgOptions, Error, Event, MessageFormat, Severity, Style};
 use std::path::PathBuf;
+use std::process::ExitCode;
 
 /// diplomat-tool CLI options, as parsed by [clap-derive].
 #[derive(Debug, Parser)]
 #[clap(
     name = "diplomat-tool",
-    about = "Generate bindings to a target language"
+    about = "Generate bindings to a target language",
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true
 )]
+struct Cli {
+    #[clap(subcommand)]
+    command: Option<Command>,
+
+    // Without a subcommand, the bindings of a single target are generated
+    #[clap(flatten)]
+    opt: Opt,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Check a new version of a bridge crate for breaking changes
+    Diff(DiffOpt),
+    /// Generate the bindings configured in a project file
+    Generate(GenerateOpt),
+    /// Regenerate the bindings configured in a project file on changes
+    Watch(WatchOpt),
+    /// Write the lowered bridge crate as JSON
+    Ir(IrOpt),
+}
+
+/// Options for generating the bindings of a single target.
+#[derive(Debug, Args)]
 struct Opt {
-    /// The target language, "js", "c", "cpp" or "dotnet" (C#).
-    #[clap()]
-    target_language: String,
+    // Only optional when there is a subcommand. The help, which lists the backends
+    // of the registry, is added in `run()`
+    #[clap(required = true)]
+    target_language: Option<String>,
 
     /// The folder that stores the bindings.
-    #[clap(value_parser)]
-    out_folder: PathBuf,
+    #[clap(required = true, value_parser)]
+    out_folder: Option<PathBuf>,
 
     #[clap(short, long, value_parser)]
     docs: Option<PathBuf>,
@@ -27,6 +55,14 @@ struct Opt {
     #[clap(short, long, value_parser, default_value = "src/lib.rs")]
     entry: PathBuf,
 
+    #[clap(flatten)]
+    cfg: CfgOptions,
+
+    /// Another crate whose bridge types the bridge crate uses, as `name=path/to/lib.rs`.
+    /// Its types are imported from its own bindings instead of being generated.
+    #[clap(long = "dep", value_parser = parse_dep)]
+    deps: Vec<(String, PathBuf)>,
+
     /// The path to an optional config file to override code generation defaults.
     /// This is currently used by the cpp generator to allow for code to be
     /// different libraries.
@@ -36,6 +72,14 @@ struct Opt {
     #[clap(short = 's', long)]
     silent: bool,
 
+    /// Don't write anything, fail if the files on disk aren't what would be generated.
+    #[clap(long)]
+    check: bool,
+
+    /// How to print errors found in the bridge crate.
+    #[clap(long, value_enum, default_value = "human")]
+    message_format: MessageFormat,
+
     #[clap()]
     apiname: Option<String>,
 
@@ -47,12 +91,264 @@ struct Opt {
 
     #[clap()]
     additional_includes: Option<Vec<String>>,
+
+    /// The file the layout of the API table is kept in, which makes the layout
+    /// append-only. The `api_layout` of the bridge should name the same file.
+    #[clap(long, value_parser)]
+    api_layout: Option<PathBuf>,
+
+    /// Generate a class that opens the library at runtime and can reload it, which the
+    /// cpp2 wrappers get the API table from.
+    #[clap(long)]
+    api_loader: bool,
+}
+
+/// Options of `diplomat-tool diff`, which checks a new version of a bridge
+/// crate for changes that break bindings or binaries built against the old one.
+#[derive(Debug, Args)]
+struct DiffOpt {
+    /// The path to the lib.rs file of the old version.
+    #[clap(value_parser)]
+    old_entry: PathBuf,
+
+    /// The path to the lib.rs file of the new version.
+    #[clap(value_parser)]
+    new_entry: PathBuf,
+
+    /// How to print errors found in the bridge crates.
+    #[clap(long, value_enum, default_value = "human")]
+    message_format: MessageFormat,
+}
+
+/// Options of `diplomat-tool ir`, which dumps the lowered bridge crate as JSON
+/// for generators that live outside of diplomat-tool.
+#[derive(Debug, Args)]
+struct IrOpt {
+    /// The file to write the JSON to.
+    #[clap(value_parser)]
+    out: PathBuf,
+
+    /// The path to the lib.rs file.
+    #[clap(short, long, value_parser, default_value = "src/lib.rs")]
+    entry: PathBuf,
+
+    /// The backend name to resolve `#[diplomat::attr]`s for.
+    #[clap(short, long, default_value = "ir")]
+    backend: String,
+
+    #[clap(flatten)]
+    cfg: CfgOptions,
+
+    /// How to print errors found in the bridge crate.
+    #[clap(long, value_enum, default_value = "human")]
+    message_format: MessageFormat,
+}
+
+/// Options of `diplomat-tool generate`, which generates the bindings configured
+/// in a `diplomat.toml` project file.
+#[derive(Debug, Args)]
+struct GenerateOpt {
+    /// The names of the targets to generate.
+    #[clap(required_unless_present = "all")]
+    targets: Vec<String>,
+
+    /// Generate all targets of the project.
+    #[clap(long, conflicts_with = "targets")]
+    all: bool,
+
+    /// The path to the project file.
+    #[clap(short, long, value_parser, default_value = "diplomat.toml")]
+    config: PathBuf,
+
+    #[clap(short = 's', long)]
+    silent: bool,
+
+    /// Don't write anything, fail if the files on disk aren't what would be generated.
+    #[clap(long)]
+    check: bool,
+
+    /// How to print errors found in the bridge crate.
+    #[clap(long, value_enum, default_value = "human")]
+    message_format: MessageFormat,
 }
 
-fn main() -> std::io::Result<()> {
-    let opt = Opt::parse();
+/// Options of `diplomat-tool watch`, which generates the bindings configured in a
+/// `diplomat.toml` project file again whenever the bridge crate changes.
+#[derive(Debug, Args)]
+struct WatchOpt {
+    /// The names of the targets to generate.
+    #[clap(required_unless_present = "all")]
+    targets: Vec<String>,
+
+    /// Generate all targets of the project.
+    #[clap(long, conflicts_with = "targets")]
+    all: bool,
+
+    /// The path to the project file.
+    #[clap(short, long, value_parser, default_value = "diplomat.toml")]
+    config: PathBuf,
+
+    #[clap(short = 's', long)]
+    silent: bool,
+
+    /// How to print errors found in the bridge crate.
+    #[clap(long, value_enum, default_value = "human")]
+    message_format: MessageFormat,
+}
+
+fn parse_dep(dep: &str) -> Result<(String, PathBuf), String> {
+    match dep.split_once('=') {
+        Some((name, entry)) if !name.is_empty() => Ok((name.to_owned(), PathBuf::from(entry))),
+        _ => Err("expected `name=path/to/lib.rs`".to_owned()),
+    }
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(code) => code,
+        Err(error) => {
+            report(error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn report(error: Error) {
+    match error {
+        Error::Lowering {
+            errors,
+            diagnostics,
+        } => eprint!("{}", diagnostics.render(&errors, &style)),
+        error => eprintln!("{}{error}", "Error: ".red().bold()),
+    }
+}
+
+/// Highlight the parts of errors in the bridge crate the way rustc does
+fn style(style: Style, s: &str) -> String {
+    match style {
+        Style::Error => s.red().bold(),
+        Style::Emphasis => s.bold(),
+        Style::Gutter => s.blue().bold(),
+        _ => s.normal(),
+    }
+    .to_string()
+}
+
+/// Print what the library is doing, unless `silent`. Errors in the bridge crate are
+/// printed either way.
+fn log(silent: bool) -> impl FnMut(Event) {
+    move |event| match event {
+        Event::AstErrors {
+            errors,
+            diagnostics,
+        } => eprint!("{}", diagnostics.render_ast_errors(errors, &style)),
+        _ if silent => {}
+        Event::Generating { target, docs } => {
+            let what = if docs { "docs" } else { "bindings" };
+            println!("{}", format!("Generating {target} {what}:").green().bold());
+        }
+        Event::Written(path) => println!("{}", format!("  {}", path.display()).dimmed()),
+        Event::Removed(path) => {
+            println!("{}", format!("  removed {}", path.display()).dimmed())
+        }
+        Event::LayoutUpdated(path) => println!(
+            "{}",
+            format!("Updated the API layout in {}", path.display()).dimmed()
+        ),
+        Event::Watching(files) => {
+            println!("{}", format!("Watching {files} files for changes").dimmed())
+        }
+        _ => {}
+    }
+}
+
+fn run() -> Result<ExitCode, Error> {
+    let registry = diplomat_tool::Registry::new();
+    let names = registry.names().collect::<Vec<_>>().join(", ");
+    let matches = Cli::command()
+        .mut_args(|arg| match arg.get_id().as_str() {
+            "target_language" => arg.help(format!("The target language, one of: {names}")),
+            _ => arg,
+        })
+        .get_matches();
+    let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|err| err.exit());
+    match cli.command {
+        Some(Command::Diff(opt)) => {
+            let changes = diplomat_tool::diff(
+                &opt.old_entry,
+                &opt.new_entry,
+                opt.message_format,
+                log(false),
+            )?;
+            for change in &changes {
+                // Pad before coloring so the escape codes don't count
+                let severity = format!("{:<12}", change.severity);
+                let severity = match change.severity {
+                    Severity::AbiBreaking => severity.red(),
+                    Severity::ApiBreaking => severity.yellow(),
+                    Severity::Compatible => severity.green(),
+                };
+                println!("{}  `{}`: {}", severity.bold(), change.item, change.message);
+            }
+            let count = |severity| changes.iter().filter(|c| c.severity == severity).count();
+            println!(
+                "{} ABI-breaking, {} API-breaking and {} compatible changes",
+                count(Severity::AbiBreaking),
+                count(Severity::ApiBreaking),
+                count(Severity::Compatible),
+            );
+            let breaking = changes.iter().any(|c| c.severity != Severity::Compatible);
+            return Ok(if breaking {
+                ExitCode::FAILURE
+            } else {
+                ExitCode::SUCCESS
+            });
+        }
+        Some(Command::Generate(opt)) => {
+            diplomat_tool::gen_project(
+                &opt.config,
+                if opt.all { None } else { Some(&opt.targets) },
+                &registry,
+                log(opt.silent),
+                opt.check,
+                opt.message_format,
+            )?;
+            return Ok(ExitCode::SUCCESS);
+        }
+        Some(Command::Watch(opt)) => {
+            diplomat_tool::watch_project(
+                &opt.config,
+                if opt.all { None } else { Some(&opt.targets) },
+                &registry,
+                log(opt.silent),
+                opt.message_format,
+                report,
+            )?;
+            return Ok(ExitCode::SUCCESS);
+        }
+        Some(Command::Ir(opt)) => {
+            diplomat_tool::ir(
+                &opt.entry,
+                opt.cfg.resolve(&opt.entry)?.as_ref(),
+                &opt.out,
+                &opt.backend,
+                &Default::default(),
+                opt.message_format,
+                log(false),
+            )?;
+            return Ok(ExitCode::SUCCESS);
+        }
+        None => {}
+    }
+
+    let opt = cli.opt;
+    // clap requires both without a subcommand
+    let target_language = opt.target_language.as_deref().unwrap();
+    let out_folder = opt.out_folder.as_deref().unwrap();
 
-    let additional_includes = opt.additional_includes.as_ref().map(|v| v.iter().map(|i| i.as_str()).collect::<Vec<_>>());
+    // With the loader, the wrappers get the table from it rather than from an include
+    let additional_includes = opt.additional_includes.as_ref().map(|v| v.iter().map(|i| i.as_str()).collect::<Vec<_>>())
+        .or_else(|| opt.api_loader.then(Vec::new));
 
     let api_info = match opt.apiname.as_ref().zip(opt.refresh_api_fn.as_ref()).zip(opt.get_api_fn.as_ref()).zip(additional_includes.as_ref()) {
         None => None,
@@ -62,14 +358,19 @@ fn main() -> std::io::Result<()> {
                 refresh_api_fn: refresh_api_fn,
                 get_api_fn: get_api_fn,
                 additional_includes: additional_includes.as_ref(),
+                layout_file: opt.api_layout.as_deref(),
+                loader: opt.api_loader,
             })
         }
     };
 
     diplomat_tool::gen(
         &opt.entry,
-        &opt.target_language,
-        &opt.out_folder,
+        opt.cfg.resolve(&opt.entry)?.as_ref(),
+        &opt.deps,
+        target_language,
+        &registry,
+        out_folder,
         opt.docs.as_deref(),
         &diplomat_core::ast::DocsUrlGenerator::with_base_urls(
             opt.docs_base_urls
@@ -92,8 +393,11 @@ fn main() -> std::io::Result<()> {
                 .collect(),
         ),
         opt.library_config.as_deref(),
-        opt.silent,
+        log(opt.silent),
+        opt.check,
         None,
         api_info,
-    )
+        opt.message_format,
+    )?;
+    Ok(ExitCode::SUCCESS)
 }
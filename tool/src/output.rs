@@ -0,0 +1,155 @@
+//! Writing generated files to an out folder.
+//!
+//! Files are only written when their contents change, so that build systems don't
+//! rebuild everything depending on them each time bindings are generated. Each out
+//! folder gets a manifest listing the files generated into it, so that files that are
+//! no longer generated, like the headers of a removed type, can be deleted on the next
+//! run. Only files in the manifest are ever deleted.
+
+use crate::Event;
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::io;
+use std::path::{Component, Path};
+
+/// The name of the manifest in each out folder
+pub(crate) const MANIFEST: &str = ".diplomat-manifest";
+
+/// Add the manifest listing `files` to them
+pub(crate) fn add_manifest(files: &mut HashMap<String, String>) {
+    let mut paths: Vec<&String> = files.keys().collect();
+    paths.sort();
+    let mut manifest = String::from(
+        "# Generated by diplomat-tool. The files listed here are deleted when they stop being generated.\n",
+    );
+    for path in paths {
+        manifest.push_str(path);
+        manifest.push('\n');
+    }
+    files.insert(MANIFEST.to_owned(), manifest);
+}
+
+/// The files listed in the manifest of `out_folder`, if it has one
+pub(crate) fn read_manifest(out_folder: &Path) -> io::Result<Vec<String>> {
+    let manifest = match fs::read_to_string(out_folder.join(MANIFEST)) {
+        Ok(manifest) => manifest,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+    Ok(manifest
+        .lines()
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        // Never delete anything outside of the out folder, whatever the manifest says
+        .filter(|line| {
+            Path::new(line)
+                .components()
+                .all(|c| matches!(c, Component::Normal(_)))
+        })
+        .map(ToOwned::to_owned)
+        .collect())
+}
+
+/// Write `files` to `out_folder`, skipping the ones that haven't changed, and delete
+/// the files the previous manifest lists that aren't in `files`
+pub(crate) fn write_files(
+    out_folder: &Path,
+    files: HashMap<String, String>,
+    on_event: &mut dyn FnMut(Event),
+) -> io::Result<()> {
+    let previous = read_manifest(out_folder)?;
+    // Sorted, so that the log doesn't depend on the order the backend generated files in
+    let files: BTreeMap<String, String> = files.into_iter().collect();
+
+    for (subpath, text) in &files {
+        let out_path = out_folder.join(subpath);
+        if let Ok(contents) = fs::read(&out_path) {
+            if contents == text.as_bytes() {
+                continue;
+            }
+        }
+        if let Some(parent) = out_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&out_path, text)?;
+        on_event(Event::Written(&out_path));
+    }
+
+    for subpath in previous {
+        if files.contains_key(&subpath) {
+            continue;
+        }
+        let out_path = out_folder.join(&subpath);
+        match fs::remove_file(&out_path) {
+            Ok(()) => {}
+            Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(e),
+        }
+        on_event(Event::Removed(&out_path));
+        // Remove the folders that are left empty, like the package of a removed type
+        let mut folder = out_path.parent();
+        while let Some(parent) = folder {
+            if parent == out_folder || fs::remove_dir(parent).is_err() {
+                break;
+            }
+            folder = parent.parent();
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{add_manifest, write_files, MANIFEST};
+    use crate::Event;
+    use std::collections::HashMap;
+    use std::fs;
+
+    fn files(files: &[(&str, &str)]) -> HashMap<String, String> {
+        let mut files = files
+            .iter()
+            .map(|(path, text)| (path.to_string(), text.to_string()))
+            .collect();
+        add_manifest(&mut files);
+        files
+    }
+
+    #[test]
+    fn test_write_files() {
+        let out = std::env::temp_dir().join(format!("diplomat-output-{}", std::process::id()));
+        fs::create_dir_all(&out).unwrap();
+        fs::write(out.join("handwritten.h"), "mine\n").unwrap();
+
+        write_files(
+            &out,
+            files(&[("kept.h", "kept\n"), ("removed/Type.h", "removed\n")]),
+            &mut |_| {},
+        )
+        .unwrap();
+        insta::assert_snapshot!(fs::read_to_string(out.join(MANIFEST)).unwrap());
+        let modified = fs::metadata(out.join("kept.h"))
+            .unwrap()
+            .modified()
+            .unwrap();
+
+        let mut removed = Vec::new();
+        write_files(&out, files(&[("kept.h", "kept\n")]), &mut |event| {
+            if let Event::Removed(path) = event {
+                removed.push(path.to_owned());
+            }
+        })
+        .unwrap();
+        let unchanged = fs::metadata(out.join("kept.h"))
+            .unwrap()
+            .modified()
+            .unwrap();
+        let removed_folder = out.join("removed").exists();
+        let handwritten = out.join("handwritten.h").exists();
+        fs::remove_dir_all(&out).unwrap();
+
+        assert_eq!(modified, unchanged);
+        assert_eq!(removed, [out.join("removed/Type.h")]);
+        assert!(!removed_folder);
+        assert!(handwritten);
+    }
+}
@@ -0,0 +1,236 @@
+//! `diplomat.toml`, which configures every set of bindings a project generates so that
+//! they can all be generated in one go with `diplomat-tool generate --all`.
+//!
+//! ```toml
+//! # The bridge crate, relative to this file
+//! entry = "src/lib.rs"
+//!
+//! # What `-u` would be on the command line, with `*` for the default
+//! [docs_base_urls]
+//! "*" = "https://docs.rs/my-crate/latest/"
+//! icu = "https://unicode-org.github.io/icu4x-docs/doc/"
+//!
+//! # Other crates whose bridge types are used, by crate name. Their bindings
+//! # are generated with them, these only import them.
+//! [deps]
+//! my_crate_core = "../core/src/lib.rs"
+//!
+//! # How the library is built, to leave out the items it doesn't have
+//! [build]
+//! features = ["buffers"]
+//! cfg = ['target_os = "linux"']
+//! # The output of `cargo metadata --format-version 1`, for the resolved features
+//! cargo_metadata = "target/metadata.json"
+//!
+//! [targets.cpp]
+//! out = "cpp/include"
+//! docs = "cpp/docs/source"
+//!
+//! # Added to the ones above, taking precedence
+//! [targets.cpp.docs_base_urls]
+//! "*" = "https://docs.rs/my-crate-cpp/latest/"
+//!
+//! # The section name is the backend unless one is given, so that a backend
+//! # can be used more than once
+//! [targets.cpp-mozilla]
+//! backend = "cpp"
+//! out = "cpp-mozilla/include"
+//! # Enables `#[diplomat::attr(vendor = mozilla, ...)]`
+//! cfg = { vendor = ["mozilla"] }
+//!
+//! # What the file passed with `-l` would hold
+//! [targets.cpp-mozilla.options]
+//! headers = ["#include \"mozilla/Maybe.h\""]
+//!
+//! [targets.c2]
+//! out = "c2/include"
+//! strip_prefix = "ICU4X"
+//! api = { name = "ICU4X", refresh_fn = "icu4x_refresh_api", get_fn = "icu4x_get_api" }
+//!
+//! # A table whose layout is only ever appended to, kept in a file that the
+//! # `api_layout` of the bridge names as well
+//! [targets.c2-plugin]
+//! backend = "c2"
+//! out = "c2-plugin/include"
+//! api = { name = "Plugin", refresh_fn = "plugin_refresh_api", get_fn = "plugin_get_api", layout = "api.lock" }
+//!
+//! # Wrappers that call into a library the host opens at runtime with `PluginLoader`,
+//! # and can reload when it is rebuilt
+//! [targets.cpp2-plugin]
+//! backend = "cpp2"
+//! out = "cpp2-plugin/include"
+//! api = { name = "Plugin", refresh_fn = "plugin_refresh_api", get_fn = "plugin_get_api", layout = "api.lock", loader = true }
+//! ```
+
+use crate::{CfgOptions, Error};
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The contents of `diplomat.toml`
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Project {
+    /// The path to the lib.rs file of the bridge crate
+    #[serde(default = "default_entry")]
+    pub entry: PathBuf,
+    /// Base URLs of the docs of other crates, keyed by crate name, or `*` for the default
+    #[serde(default)]
+    pub docs_base_urls: BTreeMap<String, String>,
+    /// The lib.rs files of the other crates whose bridge types the bridge crate uses,
+    /// keyed by crate name
+    #[serde(default)]
+    pub deps: BTreeMap<String, PathBuf>,
+    /// What the `#[cfg]`s on bridge items are evaluated against
+    #[serde(default)]
+    pub build: CfgOptions,
+    /// The bindings to generate, keyed by name
+    #[serde(default)]
+    pub targets: BTreeMap<String, Target>,
+}
+
+/// One set of bindings in `diplomat.toml`
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Target {
+    /// The backend to generate the bindings with. Defaults to the name of the target.
+    pub backend: Option<String>,
+    /// The folder that stores the bindings
+    pub out: PathBuf,
+    /// The folder that stores the docs, if the docs should be generated
+    pub docs: Option<PathBuf>,
+    /// Base URLs of the docs of other crates for this target, taking precedence
+    /// over the ones of the project
+    #[serde(default)]
+    pub docs_base_urls: BTreeMap<String, String>,
+    /// A prefix to strip from type names
+    pub strip_prefix: Option<String>,
+    /// The function table the C headers load the API through
+    pub api: Option<Api>,
+    /// The values `#[diplomat::attr(name = value, ...)]` is satisfied by, keyed by name
+    #[serde(default)]
+    pub cfg: BTreeMap<String, Vec<String>>,
+    /// The backend-specific options that would otherwise be passed in a file with `-l`
+    pub options: Option<toml::Value>,
+}
+
+/// What would be the trailing arguments of `diplomat-tool` on the command line
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Api {
+    pub name: String,
+    pub refresh_fn: String,
+    pub get_fn: String,
+    #[serde(default)]
+    pub includes: Vec<String>,
+    /// The layout file of the table, which makes its layout append-only
+    pub layout: Option<PathBuf>,
+    /// Whether cpp2 generates a loader class to open and reload the library at runtime
+    #[serde(default)]
+    pub loader: bool,
+}
+
+fn default_entry() -> PathBuf {
+    PathBuf::from("src/lib.rs")
+}
+
+impl Project {
+    /// Read a project file. Relative paths in it are made relative to the folder it is in.
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let load_error = |message: String| Error::Load {
+            path: path.to_owned(),
+            message,
+        };
+        let contents = fs::read_to_string(path).map_err(|e| load_error(e.to_string()))?;
+        let mut project: Project =
+            toml::from_str(&contents).map_err(|e| load_error(e.to_string()))?;
+
+        let root = path.parent().unwrap_or_else(|| Path::new(""));
+        project.entry = root.join(&project.entry);
+        for entry in project.deps.values_mut() {
+            *entry = root.join(&*entry);
+        }
+        project.build.cargo_metadata = project
+            .build
+            .cargo_metadata
+            .as_ref()
+            .map(|metadata| root.join(metadata));
+        for target in project.targets.values_mut() {
+            target.out = root.join(&target.out);
+            target.docs = target.docs.as_ref().map(|docs| root.join(docs));
+            if let Some(api) = &mut target.api {
+                api.layout = api.layout.as_ref().map(|layout| root.join(layout));
+            }
+        }
+        Ok(project)
+    }
+
+    /// The docs URL generator for the `docs_base_urls` of the project and of `target`
+    pub fn docs_url_gen(&self, target: &Target) -> crate::DocsUrlGenerator {
+        let mut base_urls: HashMap<String, String> = self
+            .docs_base_urls
+            .iter()
+            .chain(&target.docs_base_urls)
+            .map(|(krate, url)| (krate.clone(), url.clone()))
+            .collect();
+        let default_url = base_urls.remove("*");
+        crate::DocsUrlGenerator::with_base_urls(default_url, base_urls)
+    }
+}
+
+impl Target {
+    /// The name of the backend to use for the target called `name`
+    pub fn backend<'a>(&'a self, name: &'a str) -> &'a str {
+        self.backend.as_deref().unwrap_or(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Project;
+
+    #[test]
+    fn test_targets() {
+        let project: Project = toml::from_str(
+            r##"
+            [deps]
+            my_crate_core = "../core/src/lib.rs"
+
+            [build]
+            features = ["buffers"]
+
+            [targets.cpp]
+            out = "cpp/include"
+
+            [targets.cpp-mozilla]
+            backend = "cpp"
+            out = "cpp-mozilla/include"
+            cfg = { vendor = ["mozilla"] }
+            options = { headers = ["#include \"mozilla/Maybe.h\""] }
+            "##,
+        )
+        .unwrap();
+        assert_eq!(project.entry.to_str(), Some("src/lib.rs"));
+        assert_eq!(project.build.features, ["buffers"]);
+        assert_eq!(
+            project.deps["my_crate_core"].to_str(),
+            Some("../core/src/lib.rs")
+        );
+        assert!(project.build.cfg.is_empty() && project.build.cargo_metadata.is_none());
+
+        let cpp = &project.targets["cpp"];
+        assert_eq!(cpp.backend("cpp"), "cpp");
+        assert!(cpp.cfg.is_empty() && cpp.options.is_none());
+
+        let mozilla = &project.targets["cpp-mozilla"];
+        assert_eq!(mozilla.backend("cpp-mozilla"), "cpp");
+        assert_eq!(mozilla.cfg["vendor"], ["mozilla"]);
+        assert!(mozilla.options.as_ref().unwrap().get("headers").is_some());
+
+        assert!(
+            toml::from_str::<Project>("[targets.cpp]\nout = \"a\"\nlibrary_config = \"b\"")
+                .is_err()
+        );
+    }
+}
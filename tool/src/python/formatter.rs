@@ -0,0 +1,294 @@
+//! This module contains functions for formatting types
+
+use crate::c2::CFormatter;
+use diplomat_core::ast::{DocsUrlGenerator, MarkdownStyle};
+use diplomat_core::hir::{self, TypeContext, TypeId};
+use heck::{ToShoutySnakeCase, ToSnakeCase};
+use std::borrow::Cow;
+
+/// This type mediates all formatting
+///
+/// All identifiers from the HIR should go through here before being formatted
+/// into the output: This makes it easy to handle reserved words or add rename support
+///
+/// If you find yourself needing an identifier formatted in a context not yet available here, please add a new method
+pub(super) struct PythonFormatter<'tcx> {
+    c: CFormatter<'tcx>,
+    docs_url_generator: &'tcx DocsUrlGenerator,
+    strip_prefix: Option<String>,
+}
+
+/// Python keywords (and soft keywords), which can't be used as identifiers
+const KEYWORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+    "with", "yield",
+];
+/// Names used by the generated code itself
+const RESERVED_NAMES: &[&str] = &["self", "cls", "keep", "result", "writeable", "edges"];
+
+impl<'tcx> PythonFormatter<'tcx> {
+    pub fn new(
+        tcx: &'tcx TypeContext,
+        docs_url_generator: &'tcx DocsUrlGenerator,
+        strip_prefix: Option<String>,
+    ) -> Self {
+        Self {
+            c: CFormatter::new(tcx),
+            docs_url_generator,
+            strip_prefix,
+        }
+    }
+
+    /// Format docs as the contents of a docstring
+    pub fn fmt_docs(&self, docs: &hir::Docs) -> String {
+        docs.to_markdown(self.docs_url_generator, MarkdownStyle::Normal)
+            .trim()
+            .replace(" \n", "\n")
+            .replace('\\', "\\\\")
+            .replace("\"\"\"", "\\\"\\\"\\\"")
+            .replace(
+                &format!("`{}", self.strip_prefix.as_deref().unwrap_or("")),
+                "`",
+            )
+    }
+
+    pub fn fmt_destructor_name(&self, id: TypeId) -> String {
+        self.c.fmt_dtor_name(id)
+    }
+
+    pub fn fmt_c_method_name<'a>(&self, ty: TypeId, method: &'a hir::Method) -> Cow<'a, str> {
+        self.c.fmt_method_name(ty, method, true).into()
+    }
+
+    /// Resolve and format a named type for use in code
+    pub fn fmt_type_name(&self, id: TypeId) -> Cow<'tcx, str> {
+        let resolved = self.c.tcx().resolve_type(id);
+
+        let candidate: Cow<str> = if let Some(strip_prefix) = self.strip_prefix.as_ref() {
+            resolved
+                .name()
+                .as_str()
+                .strip_prefix(strip_prefix)
+                .unwrap_or(resolved.name().as_str())
+                .into()
+        } else {
+            resolved.name().as_str().into()
+        };
+
+        resolved.attrs().rename.apply(candidate)
+    }
+
+    /// Resolve and format a named type for use in diagnostics
+    /// (don't apply rename rules and such)
+    pub fn fmt_type_name_diagnostics(&self, id: TypeId) -> Cow<'tcx, str> {
+        self.c.fmt_type_name_diagnostics(id)
+    }
+
+    /// Format the name of the `ctypes.Structure` mirroring a struct or data-carrying enum
+    pub fn fmt_ffi_struct_name(&self, type_name: &str) -> String {
+        format!("_{type_name}Ffi")
+    }
+
+    /// Format the name of the `ctypes.Structure` holding the result of a fallible method
+    pub fn fmt_result_name<P: hir::TyPosition>(
+        &self,
+        ok: Option<&'tcx hir::Type<P>>,
+        err: Option<&'tcx hir::Type<P>>,
+    ) -> String {
+        let ok = ok.map(|o| self.c.fmt_type_name_uniquely(o));
+        let err = err.map(|e| self.c.fmt_type_name_uniquely(e));
+        format!(
+            "_{}",
+            self.c.fmt_result_name(
+                ok.as_deref().unwrap_or("void"),
+                err.as_deref().unwrap_or("void")
+            )
+        )
+    }
+
+    /// Format an enum variant, which Python spells in SHOUTY_SNAKE_CASE
+    pub fn fmt_enum_variant(&self, variant: &'tcx hir::EnumVariant) -> String {
+        let name = variant.attrs.rename.apply(variant.name.as_str().into());
+        escape(name.to_shouty_snake_case())
+    }
+
+    /// Format the name of the subclass representing a variant of a data-carrying enum.
+    pub fn fmt_enum_variant_class_name(
+        &self,
+        type_name: &str,
+        variant: &'tcx hir::EnumVariant,
+    ) -> String {
+        let name = variant.attrs.rename.apply(variant.name.as_str().into());
+        format!("{type_name}{name}")
+    }
+
+    /// Format a field name
+    pub fn fmt_field_name(&self, ident: &str) -> String {
+        escape(ident.to_snake_case())
+    }
+
+    /// Format a parameter name, avoiding the names of locals in generated methods
+    pub fn fmt_param_name(&self, ident: &str) -> String {
+        let name = self.fmt_field_name(ident);
+        if RESERVED_NAMES.contains(&name.as_str()) {
+            format!("{name}_")
+        } else {
+            name
+        }
+    }
+
+    /// Format a method
+    pub fn fmt_method_name(&self, method: &hir::Method) -> String {
+        escape(
+            method
+                .attrs
+                .rename
+                .apply(method.name.as_str().into())
+                .to_snake_case(),
+        )
+    }
+
+    pub fn fmt_constructor_name(&self, name: &Option<String>, method: &hir::Method) -> String {
+        escape(
+            method
+                .attrs
+                .rename
+                .apply(name.as_deref().unwrap_or(method.name.as_str()).into())
+                .to_snake_case(),
+        )
+    }
+
+    pub fn fmt_accessor_name(&self, name: &Option<String>, method: &hir::Method) -> String {
+        escape(
+            method
+                .attrs
+                .rename
+                .apply(name.as_deref().unwrap_or(method.name.as_str()).into())
+                .to_snake_case(),
+        )
+    }
+
+    /// Format the name of a const, which Python spells in SHOUTY_SNAKE_CASE
+    pub fn fmt_const_name(&self, def: &hir::ConstDef) -> String {
+        escape(
+            def.attrs
+                .rename
+                .apply(def.name.as_str().into())
+                .to_shouty_snake_case(),
+        )
+    }
+
+    /// Format the value of a const as a Python literal
+    pub fn fmt_const_value(&self, def: &hir::ConstDef) -> String {
+        match def.value {
+            hir::ConstValue::Bool(true) => "True".into(),
+            hir::ConstValue::Bool(false) => "False".into(),
+            hir::ConstValue::Int(ref i) => i.clone(),
+            hir::ConstValue::Float(ref f) => f.clone(),
+            hir::ConstValue::Str(ref s) => self.fmt_string_literal(s),
+            _ => unreachable!("unknown AST/HIR variant"),
+        }
+    }
+
+    /// Format a string as a Python string literal
+    pub fn fmt_string_literal(&self, s: &str) -> String {
+        let mut out = String::with_capacity(s.len() + 2);
+        out.push('"');
+        for c in s.chars() {
+            match c {
+                '"' | '\\' => {
+                    out.push('\\');
+                    out.push(c);
+                }
+                '\n' => out.push_str("\\n"),
+                '\r' => out.push_str("\\r"),
+                '\t' => out.push_str("\\t"),
+                c if c.is_control() => out.push_str(&format!("\\U{:08x}", c as u32)),
+                c => out.push(c),
+            }
+        }
+        out.push('"');
+        out
+    }
+
+    /// The Python type annotation of a primitive
+    pub fn fmt_primitive_as_python(&self, prim: hir::PrimitiveType) -> &'static str {
+        use diplomat_core::hir::PrimitiveType;
+        match prim {
+            PrimitiveType::Bool => "bool",
+            PrimitiveType::Char => "str",
+            PrimitiveType::Int(_) | PrimitiveType::IntSize(_) | PrimitiveType::Byte => "int",
+            PrimitiveType::Float(_) => "float",
+            PrimitiveType::Int128(_) => panic!("i128 not supported in Python"),
+        }
+    }
+
+    /// The `ctypes` type of a primitive
+    pub fn fmt_primitive_as_ffi(&self, prim: hir::PrimitiveType) -> &'static str {
+        use diplomat_core::hir::{FloatType, IntSizeType, IntType, PrimitiveType};
+        match prim {
+            PrimitiveType::Bool => "ctypes.c_bool",
+            PrimitiveType::Char => "ctypes.c_uint32",
+            PrimitiveType::Int(IntType::I8) => "ctypes.c_int8",
+            PrimitiveType::Int(IntType::U8) | PrimitiveType::Byte => "ctypes.c_uint8",
+            PrimitiveType::Int(IntType::I16) => "ctypes.c_int16",
+            PrimitiveType::Int(IntType::U16) => "ctypes.c_uint16",
+            PrimitiveType::Int(IntType::I32) => "ctypes.c_int32",
+            PrimitiveType::Int(IntType::U32) => "ctypes.c_uint32",
+            PrimitiveType::Int(IntType::I64) => "ctypes.c_int64",
+            PrimitiveType::Int(IntType::U64) => "ctypes.c_uint64",
+            PrimitiveType::IntSize(IntSizeType::Isize) => "ctypes.c_ssize_t",
+            PrimitiveType::IntSize(IntSizeType::Usize) => "ctypes.c_size_t",
+            PrimitiveType::Float(FloatType::F32) => "ctypes.c_float",
+            PrimitiveType::Float(FloatType::F64) => "ctypes.c_double",
+            PrimitiveType::Int128(_) => panic!("i128 not supported in Python"),
+        }
+    }
+
+    /// The `ctypes` type of an enum's discriminant
+    pub fn fmt_enum_as_ffi(&self) -> &'static str {
+        self.fmt_primitive_as_ffi(hir::PrimitiveType::Int(hir::IntType::I32))
+    }
+
+    /// The `ctypes` element type of a string slice
+    pub fn fmt_string_element_as_ffi(&self, encoding: hir::StringEncoding) -> &'static str {
+        match encoding {
+            hir::StringEncoding::UnvalidatedUtf16 => "ctypes.c_uint16",
+            _ => "ctypes.c_uint8",
+        }
+    }
+
+    pub fn fmt_slice_type(&self, element: &str) -> String {
+        format!("_runtime.slice_type({element})")
+    }
+
+    pub fn fmt_pointer(&self, target: &str) -> String {
+        format!("ctypes.POINTER({target})")
+    }
+
+    pub fn fmt_usize(&self) -> &'static str {
+        self.fmt_primitive_as_ffi(hir::PrimitiveType::IntSize(hir::IntSizeType::Usize))
+    }
+
+    pub fn fmt_opaque_as_ffi(&self) -> &'static str {
+        "ctypes.c_void_p"
+    }
+
+    pub fn fmt_string(&self) -> &'static str {
+        "str"
+    }
+
+    pub fn fmt_optional(&self, ty: &str) -> String {
+        format!("typing.Optional[{ty}]")
+    }
+}
+
+fn escape(name: String) -> String {
+    if KEYWORDS.contains(&name.as_str()) {
+        format!("{name}_")
+    } else {
+        name
+    }
+}
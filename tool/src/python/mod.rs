@@ -0,0 +1,1292 @@
+use crate::common::{ErrorStore, FileMap};
+use askama::Template;
+use diplomat_core::ast::DocsUrlGenerator;
+use diplomat_core::hir::TypeContext;
+use diplomat_core::hir::{
+    self, OpaqueOwner, ReturnType, SelfType, SpecialMethod, SpecialMethodPresence, StructPathLike,
+    SuccessType, TyPosition, Type, TypeDef, TypeId,
+};
+use formatter::PythonFormatter;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Display;
+
+#[cfg(test)]
+#[macro_use]
+mod test_util;
+
+mod formatter;
+
+/// Run file generation
+pub fn run<'cx>(
+    tcx: &'cx TypeContext,
+    docs_url_generator: &'cx DocsUrlGenerator,
+    strip_prefix: Option<String>,
+) -> Result<FileMap, Vec<(impl Display + 'cx, String)>> {
+    let formatter = PythonFormatter::new(tcx, docs_url_generator, strip_prefix);
+
+    let files = FileMap::default();
+    let errors = ErrorStore::default();
+
+    let mut tgcx = TyGenContext {
+        tcx,
+        errors: &errors,
+        formatter: &formatter,
+        ffi_structs: BTreeMap::new(),
+        prototypes: Vec::new(),
+    };
+
+    let mut exports = Vec::new();
+    let mut bodies = Vec::new();
+
+    for (id, ty) in tcx.all_types() {
+        if ty.attrs().disable {
+            continue;
+        }
+
+        let (names, body) = tgcx.gen(id);
+        exports.extend(names);
+        bodies.push(body);
+    }
+
+    for (_, trt) in tcx.all_traits() {
+        if !trt.attrs.disable {
+            let _guard = errors.set_context_ty(trt.name.as_str().into());
+            errors.push_error(format!(
+                "Traits are not supported in Python, found {}",
+                trt.name
+            ));
+        }
+    }
+
+    let consts = tgcx.gen_consts(tcx.consts());
+    exports.extend(consts.iter().map(|c| c.name.clone()));
+
+    #[derive(Template)]
+    #[template(path = "python/lib.py.jinja", escape = "none")]
+    struct LibTemplate<'a> {
+        exports: Vec<String>,
+        ffi_structs: Vec<(&'a str, &'a FfiStruct)>,
+        bodies: Vec<String>,
+        consts: Vec<ConstInfo>,
+        prototypes: &'a [Prototype],
+    }
+
+    files.add_file(
+        "_lib.py".into(),
+        LibTemplate {
+            exports,
+            ffi_structs: tgcx.ffi_structs_in_dependency_order(),
+            bodies,
+            consts,
+            prototypes: &tgcx.prototypes,
+        }
+        .render()
+        .unwrap(),
+    );
+    files.add_file(
+        "_runtime.py".into(),
+        include_str!("../../templates/python/runtime.py").into(),
+    );
+    files.add_file(
+        "__init__.py".into(),
+        include_str!("../../templates/python/init.py").into(),
+    );
+
+    let errors = errors.take_all();
+    if !errors.is_empty() {
+        Err(errors)
+    } else {
+        Ok(files)
+    }
+}
+
+struct TyGenContext<'a, 'cx> {
+    tcx: &'cx TypeContext,
+    formatter: &'a PythonFormatter<'cx>,
+    errors: &'a ErrorStore<'cx, String>,
+    /// The `ctypes` structures and unions mirroring C types, by name
+    ffi_structs: BTreeMap<String, FfiStruct>,
+    /// The signatures of all C functions that are called
+    prototypes: Vec<Prototype>,
+}
+
+impl<'a, 'cx> TyGenContext<'a, 'cx> {
+    /// Generates a type, returning the names it exports and its Python code
+    fn gen(&mut self, id: TypeId) -> (Vec<String>, String) {
+        let ty = self.tcx.resolve_type(id);
+
+        let _guard = self.errors.set_context_ty(ty.name().as_str().into());
+
+        let name = self.formatter.fmt_type_name(id);
+        match ty {
+            TypeDef::Enum(e) if e.is_data_carrying() => self.gen_tagged_union(e, id, &name),
+            TypeDef::Enum(e) => (vec![name.to_string()], self.gen_enum(e, id, &name)),
+            TypeDef::Opaque(o) => (vec![name.to_string()], self.gen_opaque_def(o, id, &name)),
+            TypeDef::Struct(s) => (
+                vec![name.to_string()],
+                self.gen_struct_def(s, id, false, &name),
+            ),
+            TypeDef::OutStruct(s) => (
+                vec![name.to_string()],
+                self.gen_struct_def(s, id, true, &name),
+            ),
+            _ => unreachable!("unknown AST/HIR variant"),
+        }
+    }
+
+    /// Orders the FFI structs so that every struct comes after the structs it
+    /// holds by value.
+    fn ffi_structs_in_dependency_order(&self) -> Vec<(&str, &FfiStruct)> {
+        fn visit<'s>(
+            name: &'s str,
+            structs: &'s BTreeMap<String, FfiStruct>,
+            visited: &mut BTreeSet<&'s str>,
+            out: &mut Vec<(&'s str, &'s FfiStruct)>,
+        ) {
+            if !visited.insert(name) {
+                return;
+            }
+            let ffi = &structs[name];
+            for dep in ffi.deps.iter() {
+                visit(dep, structs, visited, out);
+            }
+            out.push((name, ffi));
+        }
+
+        let mut visited = BTreeSet::new();
+        let mut out = Vec::new();
+        for name in self.ffi_structs.keys() {
+            visit(name, &self.ffi_structs, &mut visited, &mut out);
+        }
+        out
+    }
+
+    fn gen_const_info(&self, def: &hir::ConstDef) -> ConstInfo {
+        ConstInfo {
+            name: self.formatter.fmt_const_name(def),
+            value: self.formatter.fmt_const_value(def),
+            docs: self.formatter.fmt_docs(&def.docs),
+        }
+    }
+
+    fn gen_consts(&self, consts: &[hir::ConstDef]) -> Vec<ConstInfo> {
+        consts.iter().map(|c| self.gen_const_info(c)).collect()
+    }
+
+    fn gen_enum(&mut self, ty: &'cx hir::EnumDef, id: TypeId, type_name: &str) -> String {
+        let variants = ty
+            .variants
+            .iter()
+            .map(|variant| EnumVariantInfo {
+                name: self.formatter.fmt_enum_variant(variant),
+                discriminant: variant.discriminant,
+                docs: self.formatter.fmt_docs(&variant.docs),
+            })
+            .collect::<Vec<_>>();
+
+        let (methods, properties) = self.gen_methods(id, &ty.methods, type_name);
+        let special = self.gen_special_method_info(&ty.special_method_presence);
+
+        #[derive(Template)]
+        #[template(path = "python/enum.py.jinja", escape = "none")]
+        struct ImplTemplate<'a> {
+            type_name: &'a str,
+            variants: Vec<EnumVariantInfo>,
+            methods: Vec<MethodInfo>,
+            properties: Vec<PropertyInfo>,
+            consts: Vec<ConstInfo>,
+            docs: String,
+            special: SpecialMethodGenInfo,
+        }
+
+        ImplTemplate {
+            type_name,
+            variants,
+            methods,
+            properties,
+            consts: self.gen_consts(&ty.consts),
+            docs: self.formatter.fmt_docs(&ty.docs),
+            special,
+        }
+        .render()
+        .unwrap()
+    }
+
+    /// Data-carrying enums become a base class with one subclass per variant,
+    /// backed by a structure holding the tag and a union of the variant payloads.
+    fn gen_tagged_union(
+        &mut self,
+        ty: &'cx hir::EnumDef,
+        id: TypeId,
+        type_name: &str,
+    ) -> (Vec<String>, String) {
+        let ffi_name = self.formatter.fmt_ffi_struct_name(type_name);
+        let payload_ffi_name = self
+            .formatter
+            .fmt_ffi_struct_name(&format!("{type_name}Payload"));
+
+        let mut payload_fields = Vec::new();
+        let variants = ty
+            .variants
+            .iter()
+            .map(|variant| {
+                let class_name = self
+                    .formatter
+                    .fmt_enum_variant_class_name(type_name, variant);
+                let variant_ffi_name = self.formatter.fmt_ffi_struct_name(&class_name);
+
+                let mut ffi_fields = Vec::new();
+                let mut deps = Vec::new();
+                let fields = variant
+                    .fields
+                    .iter()
+                    .map(|field| {
+                        let name = self.formatter.fmt_field_name(field.name.as_str());
+                        if field.ty.lifetimes().next().is_some() {
+                            self.errors.push_error(format!(
+                                "Borrowed data in enum payloads is not supported in Python: {}::{}",
+                                variant.name, field.name
+                            ));
+                        }
+                        ffi_fields.push((name.clone(), self.gen_ffi_type(&field.ty)));
+                        deps.extend(self.gen_ffi_dep(&field.ty));
+                        FieldInfo {
+                            python_type_name: self.gen_type_name(&field.ty),
+                            c_to_python: self.gen_c_to_python(
+                                &field.ty,
+                                &format!("payload.{name}"),
+                                "edges",
+                            ),
+                            python_to_c: self.gen_python_to_c(&field.ty, &format!("self.{name}")),
+                            name,
+                        }
+                    })
+                    .collect::<Vec<_>>();
+
+                if !fields.is_empty() {
+                    self.ffi_structs.insert(
+                        variant_ffi_name.clone(),
+                        FfiStruct {
+                            is_union: false,
+                            fields: ffi_fields,
+                            deps,
+                        },
+                    );
+                    payload_fields.push((class_name.clone(), variant_ffi_name.clone()));
+                }
+
+                TaggedVariantInfo {
+                    class_name,
+                    payload_ffi_name: variant_ffi_name,
+                    docs: self.formatter.fmt_docs(&variant.docs),
+                    discriminant: variant.discriminant,
+                    fields,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let payload_deps = payload_fields.iter().map(|(_, ty)| ty.clone()).collect();
+        self.ffi_structs.insert(
+            payload_ffi_name.clone(),
+            FfiStruct {
+                is_union: true,
+                fields: payload_fields,
+                deps: payload_deps,
+            },
+        );
+        self.ffi_structs.insert(
+            ffi_name.clone(),
+            FfiStruct {
+                is_union: false,
+                fields: vec![
+                    ("tag".into(), self.formatter.fmt_enum_as_ffi().into()),
+                    ("payload".into(), payload_ffi_name.clone()),
+                ],
+                deps: vec![payload_ffi_name.clone()],
+            },
+        );
+
+        let (methods, properties) = self.gen_methods(id, &ty.methods, type_name);
+        let special = self.gen_special_method_info(&ty.special_method_presence);
+
+        #[derive(Template)]
+        #[template(path = "python/tagged_union.py.jinja", escape = "none")]
+        struct ImplTemplate<'a> {
+            type_name: &'a str,
+            ffi_name: &'a str,
+            payload_ffi_name: &'a str,
+            variants: &'a [TaggedVariantInfo],
+            methods: Vec<MethodInfo>,
+            properties: Vec<PropertyInfo>,
+            consts: Vec<ConstInfo>,
+            docs: String,
+            special: SpecialMethodGenInfo,
+        }
+
+        let body = ImplTemplate {
+            type_name,
+            ffi_name: &ffi_name,
+            payload_ffi_name: &payload_ffi_name,
+            variants: &variants,
+            methods,
+            properties,
+            consts: self.gen_consts(&ty.consts),
+            docs: self.formatter.fmt_docs(&ty.docs),
+            special,
+        }
+        .render()
+        .unwrap();
+
+        let mut names = vec![type_name.to_owned()];
+        names.extend(variants.into_iter().map(|v| v.class_name));
+        (names, body)
+    }
+
+    fn gen_opaque_def(&mut self, ty: &'cx hir::OpaqueDef, id: TypeId, type_name: &str) -> String {
+        let (methods, properties) = self.gen_methods(id, &ty.methods, type_name);
+
+        let destructor = self.formatter.fmt_destructor_name(id);
+        self.prototypes.push(Prototype {
+            c_name: destructor.clone(),
+            argtypes: vec![self.formatter.fmt_opaque_as_ffi().into()],
+            restype: "None".into(),
+        });
+        let special = self.gen_special_method_info(&ty.special_method_presence);
+
+        #[derive(Template)]
+        #[template(path = "python/opaque.py.jinja", escape = "none")]
+        struct ImplTemplate<'a> {
+            type_name: &'a str,
+            has_constructor: bool,
+            methods: Vec<MethodInfo>,
+            properties: Vec<PropertyInfo>,
+            consts: Vec<ConstInfo>,
+            docs: String,
+            destructor: String,
+            special: SpecialMethodGenInfo,
+        }
+
+        ImplTemplate {
+            type_name,
+            has_constructor: methods.iter().any(|m| m.name == "__new__"),
+            methods,
+            properties,
+            consts: self.gen_consts(&ty.consts),
+            destructor,
+            docs: self.formatter.fmt_docs(&ty.docs),
+            special,
+        }
+        .render()
+        .unwrap()
+    }
+
+    fn gen_struct_def<P: TyPosition>(
+        &mut self,
+        ty: &'cx hir::StructDef<P>,
+        id: TypeId,
+        is_out: bool,
+        type_name: &str,
+    ) -> String {
+        let ffi_name = self.formatter.fmt_ffi_struct_name(type_name);
+
+        let mut ffi_fields = Vec::new();
+        let mut deps = Vec::new();
+        let fields = ty
+            .fields
+            .iter()
+            .map(|field| {
+                let name = self.formatter.fmt_field_name(field.name.as_str());
+                ffi_fields.push((name.clone(), self.gen_ffi_type(&field.ty)));
+                deps.extend(self.gen_ffi_dep(&field.ty));
+                FieldInfo {
+                    python_type_name: self.gen_type_name(&field.ty),
+                    c_to_python: self.gen_c_to_python(&field.ty, &format!("ffi.{name}"), "edges"),
+                    python_to_c: self.gen_python_to_c(&field.ty, &format!("self.{name}")),
+                    name,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        self.ffi_structs.insert(
+            ffi_name.clone(),
+            FfiStruct {
+                is_union: false,
+                fields: ffi_fields,
+                deps,
+            },
+        );
+
+        let (mut methods, properties) = self.gen_methods(id, &ty.methods, type_name);
+        let special = self.gen_special_method_info(&ty.special_method_presence);
+
+        // Non-out structs need to be constructible in Python
+        let default_constructor = if is_out {
+            false
+        } else if let Some(constructor) = methods.iter_mut().find(|m| m.name == "__new__") {
+            // A zero-arg constructor is repurposed with optional arguments for all fields
+            if constructor.params.len() == 1 {
+                constructor.params.extend(
+                    fields
+                        .iter()
+                        .map(|f| format!("{}: {} = None", f.name, f.python_type_name)),
+                );
+                let ret = constructor.body.pop().unwrap_or_default();
+                constructor.body.push(ret.replacen("return ", "self = ", 1));
+                for field in fields.iter() {
+                    let name = &field.name;
+                    constructor.body.push(format!("if {name} is not None:"));
+                    constructor.body.push(format!("    self.{name} = {name}"));
+                }
+                constructor.body.push("return self".into());
+            }
+            false
+        } else {
+            // Otherwise we create a constructor with required values for all fields.
+            true
+        };
+
+        #[derive(Template)]
+        #[template(path = "python/struct.py.jinja", escape = "none")]
+        struct ImplTemplate<'a> {
+            type_name: &'a str,
+            ffi_name: &'a str,
+            default_constructor: bool,
+            fields: Vec<FieldInfo>,
+            methods: Vec<MethodInfo>,
+            properties: Vec<PropertyInfo>,
+            consts: Vec<ConstInfo>,
+            docs: String,
+            special: SpecialMethodGenInfo,
+        }
+
+        ImplTemplate {
+            type_name,
+            ffi_name: &ffi_name,
+            default_constructor,
+            fields,
+            methods,
+            properties,
+            consts: self.gen_consts(&ty.consts),
+            docs: self.formatter.fmt_docs(&ty.docs),
+            special,
+        }
+        .render()
+        .unwrap()
+    }
+
+    /// Generates the methods of a type, and the properties their accessors make up
+    fn gen_methods(
+        &mut self,
+        id: TypeId,
+        methods: &'cx [hir::Method],
+        type_name: &str,
+    ) -> (Vec<MethodInfo>, Vec<PropertyInfo>) {
+        let mut properties = BTreeMap::<String, PropertyInfo>::new();
+        let methods = methods
+            .iter()
+            .flat_map(|method| {
+                let info = self.gen_method_info(id, method, type_name)?;
+                let (name, is_getter) = match method.attrs.special_method {
+                    Some(SpecialMethod::Getter(ref name)) => (name, true),
+                    Some(SpecialMethod::Setter(ref name)) => (name, false),
+                    _ => return Some(info),
+                };
+                let name = self.formatter.fmt_accessor_name(name, method);
+                let property = properties.entry(name.clone()).or_insert(PropertyInfo {
+                    name,
+                    getter: "None".into(),
+                    setter: "None".into(),
+                });
+                if is_getter {
+                    property.getter = info.name.clone();
+                } else {
+                    property.setter = info.name.clone();
+                }
+                Some(info)
+            })
+            .collect();
+        (methods, properties.into_values().collect())
+    }
+
+    fn gen_method_info(
+        &mut self,
+        id: TypeId,
+        method: &'cx hir::Method,
+        type_name: &str,
+    ) -> Option<MethodInfo> {
+        if method.attrs.disable {
+            return None;
+        }
+
+        let _guard = self.errors.set_context_method(
+            self.formatter.fmt_type_name_diagnostics(id),
+            method.name.as_str().into(),
+        );
+
+        let c_method_name = self.formatter.fmt_c_method_name(id, method);
+
+        // Enums can't override `__new__`, so their constructors are static methods
+        let special_method = match method.attrs.special_method {
+            Some(SpecialMethod::Constructor) if matches!(id, TypeId::Enum(_)) => None,
+            ref special => special.as_ref(),
+        };
+
+        let mut params = Vec::new();
+        let mut argtypes = Vec::new();
+        let mut args = Vec::new();
+        // Everything the return value may borrow from
+        let mut edges = Vec::new();
+        let mut needs_keep = false;
+
+        if let Some(param_self) = method.param_self.as_ref() {
+            let (argtype, arg) = match param_self.ty {
+                SelfType::Opaque(_) => (self.formatter.fmt_opaque_as_ffi().into(), "self._ffi"),
+                SelfType::Struct(ref s) => {
+                    needs_keep = true;
+                    let name = self.formatter.fmt_type_name(s.id());
+                    (
+                        self.formatter.fmt_ffi_struct_name(&name),
+                        "self._to_ffi(keep)",
+                    )
+                }
+                SelfType::Enum(ref e) if e.resolve(self.tcx).is_data_carrying() => {
+                    needs_keep = true;
+                    let name = self.formatter.fmt_type_name(e.tcx_id.into());
+                    (
+                        self.formatter.fmt_ffi_struct_name(&name),
+                        "self._to_ffi(keep)",
+                    )
+                }
+                SelfType::Enum(_) => (self.formatter.fmt_enum_as_ffi().into(), "int(self)"),
+                _ => unreachable!("unknown AST/HIR variant"),
+            };
+            params.push("self".to_owned());
+            argtypes.push(argtype);
+            args.push(arg.to_owned());
+            edges.push("self".to_owned());
+        } else if let Some(SpecialMethod::Constructor) = special_method {
+            params.push("cls".to_owned());
+        }
+
+        for param in method.params.iter() {
+            let param_name = self.formatter.fmt_param_name(param.name.as_str());
+            params.push(format!("{param_name}: {}", self.gen_type_name(&param.ty)));
+
+            if let Type::Slice(slice) = param.ty {
+                // Two args on the ABI: pointer and length
+                argtypes.push(self.formatter.fmt_pointer(&self.gen_slice_element(&slice)));
+                argtypes.push(self.formatter.fmt_usize().into());
+                args.push(format!("*{}", self.gen_slice_to_c(&slice, &param_name)));
+                needs_keep |= slice.lifetime().is_some();
+            } else {
+                needs_keep |= self.needs_keep(&param.ty);
+                argtypes.push(self.gen_ffi_type(&param.ty));
+                args.push(self.gen_python_to_c(&param.ty, &param_name));
+            }
+
+            if !matches!(param.ty, Type::Primitive(_) | Type::Enum(_)) {
+                edges.push(param_name);
+            }
+        }
+
+        if method.output.is_writeable() {
+            argtypes.push(self.formatter.fmt_opaque_as_ffi().into());
+            args.push("writeable.ffi".into());
+        }
+
+        let borrows = method
+            .output
+            .success_type()
+            .as_type()
+            .map(|o| o.lifetimes().next().is_some())
+            .unwrap_or(false);
+        let edges = if borrows {
+            if needs_keep {
+                edges.push("keep".into());
+            }
+            format!("[{}]", edges.join(", "))
+        } else {
+            "[]".into()
+        };
+
+        let restype = self.gen_return_type_ffi(&method.output);
+        self.prototypes.push(Prototype {
+            c_name: c_method_name.to_string(),
+            argtypes,
+            restype,
+        });
+
+        let mut body = Vec::new();
+        if needs_keep {
+            body.push("keep = []".to_owned());
+        }
+        if method.output.is_writeable() {
+            body.push("writeable = _runtime.Writeable()".into());
+        }
+        let call = format!("_runtime.lib().{c_method_name}({})", args.join(", "));
+        if method.output.is_ffi_unit() {
+            body.push(call);
+        } else {
+            body.push(format!("result = {call}"));
+        }
+        // Methods returning a `Result` get the panic as their error
+        let checks_panic =
+            method.attrs.catch_panics && !matches!(method.output, ReturnType::Fallible(..));
+        if checks_panic {
+            body.push("_runtime.check_panic()".into());
+        }
+
+        let none_error = match special_method {
+            Some(SpecialMethod::Iterator) => Some("StopIteration"),
+            Some(SpecialMethod::Indexer) => Some("IndexError"),
+            _ => None,
+        };
+        body.extend(self.gen_return(&method.output, &edges, none_error));
+
+        let mut decorators = Vec::new();
+        let name = match special_method {
+            Some(SpecialMethod::Constructor) => "__new__".into(),
+            Some(SpecialMethod::NamedConstructor(name)) => {
+                decorators.push("staticmethod");
+                self.formatter.fmt_constructor_name(name, method)
+            }
+            Some(SpecialMethod::Getter(name)) => {
+                format!("_get_{}", self.formatter.fmt_accessor_name(name, method))
+            }
+            Some(SpecialMethod::Setter(name)) => {
+                format!("_set_{}", self.formatter.fmt_accessor_name(name, method))
+            }
+            Some(SpecialMethod::Stringifier) => "__str__".into(),
+            Some(SpecialMethod::Comparison) => "_compare".into(),
+            Some(SpecialMethod::Iterator) => "__next__".into(),
+            Some(SpecialMethod::Iterable) => "__iter__".into(),
+            Some(SpecialMethod::Indexer) => "__getitem__".into(),
+            None if method.param_self.is_none() => {
+                decorators.push("staticmethod");
+                self.formatter.fmt_method_name(method)
+            }
+            None => self.formatter.fmt_method_name(method),
+            Some(special) => unimplemented!("Found unknown special method type {special:?}"),
+        };
+
+        let return_ty = match special_method {
+            Some(SpecialMethod::Constructor) => type_name.into(),
+            Some(SpecialMethod::Iterator | SpecialMethod::Indexer) => {
+                self.gen_type_name_for_success(method.output.success_type())
+            }
+            _ => self.gen_return_type_name(&method.output),
+        };
+
+        let mut docs = self.formatter.fmt_docs(&method.docs);
+        if let ReturnType::Fallible(_, e) = &method.output {
+            if !docs.is_empty() {
+                docs.push_str("\n\n");
+            }
+            match e {
+                Some(e) => docs.push_str(&format!(
+                    "Raises `DiplomatError` with a `{}` value on failure.",
+                    self.gen_type_name(e)
+                )),
+                None => docs.push_str("Raises `DiplomatError` on failure."),
+            }
+        }
+        if checks_panic {
+            if !docs.is_empty() {
+                docs.push_str("\n\n");
+            }
+            docs.push_str("Raises `DiplomatPanic` if it panics.");
+        }
+
+        Some(MethodInfo {
+            name,
+            decorators,
+            params,
+            return_ty,
+            docs,
+            body,
+        })
+    }
+
+    /// Generates the statements converting the C return value of a method,
+    /// which is saved in `result` (or `writeable`), into the Python one.
+    ///
+    /// Methods that return `None` to signal the end of iteration or a missing
+    /// item raise `none_error` instead.
+    fn gen_return(
+        &mut self,
+        output: &'cx ReturnType,
+        edges: &str,
+        none_error: Option<&str>,
+    ) -> Vec<String> {
+        let on_none = match none_error {
+            Some(error) => format!("raise {error}"),
+            None => "return None".into(),
+        };
+        let mut out = Vec::new();
+        let ok_value = match output {
+            ReturnType::Infallible(SuccessType::Unit) => None,
+            ReturnType::Infallible(SuccessType::Writeable) => Some("writeable.finalize()".into()),
+            ReturnType::Infallible(SuccessType::OutType(o)) => {
+                if let (Type::Opaque(op), Some(_)) = (o, none_error) {
+                    if op.is_optional() {
+                        out.push("if not result:".into());
+                        out.push(format!("    {on_none}"));
+                    }
+                }
+                Some(self.gen_c_to_python(o, "result", edges))
+            }
+            ReturnType::Fallible(ok, _) | ReturnType::Nullable(ok) => {
+                out.push("if not result.is_ok:".into());
+                out.push(match output {
+                    ReturnType::Fallible(_, Some(e)) => {
+                        let err = self.gen_read_union("result.union", e);
+                        format!(
+                            "    raise _runtime.DiplomatError({})",
+                            self.gen_c_to_python(e, &err, edges)
+                        )
+                    }
+                    ReturnType::Fallible(_, None) => "    raise _runtime.DiplomatError()".into(),
+                    _ => format!("    {on_none}"),
+                });
+                match ok {
+                    SuccessType::Unit if matches!(output, ReturnType::Nullable(_)) => {
+                        Some("True".into())
+                    }
+                    SuccessType::Unit => None,
+                    SuccessType::Writeable => Some("writeable.finalize()".into()),
+                    SuccessType::OutType(o) => {
+                        let ok = self.gen_read_union("result.union", o);
+                        Some(self.gen_c_to_python(o, &ok, edges))
+                    }
+                    _ => unreachable!("unknown AST/HIR variant"),
+                }
+            }
+            _ => unreachable!("unknown AST/HIR variant"),
+        };
+        if let Some(value) = ok_value {
+            out.push(format!("return {value}"));
+        }
+        out
+    }
+
+    fn gen_special_method_info(
+        &mut self,
+        special_method_presence: &SpecialMethodPresence,
+    ) -> SpecialMethodGenInfo {
+        SpecialMethodGenInfo {
+            comparator: special_method_presence.comparator,
+            iterator: special_method_presence.iterator.is_some(),
+        }
+    }
+
+    /// Whether passing this type needs temporary allocations
+    fn needs_keep<P: TyPosition>(&self, ty: &Type<P>) -> bool {
+        match ty {
+            Type::Struct(_) => true,
+            Type::Enum(e) => e.resolve(self.tcx).is_data_carrying(),
+            Type::Slice(s) => s.lifetime().is_some(),
+            _ => false,
+        }
+    }
+
+    /// Generates a type's Python type annotation.
+    fn gen_type_name<P: TyPosition>(&mut self, ty: &Type<P>) -> String {
+        match *ty {
+            Type::Primitive(prim) => self.formatter.fmt_primitive_as_python(prim).into(),
+            Type::Opaque(ref op) => {
+                let type_name = self.gen_named_type(op.tcx_id.into());
+                if op.is_optional() {
+                    self.formatter.fmt_optional(&type_name)
+                } else {
+                    type_name
+                }
+            }
+            Type::Struct(ref st) => self.gen_named_type(st.id()),
+            Type::Enum(ref e) => self.gen_named_type(e.tcx_id.into()),
+            Type::Slice(hir::Slice::Str(..)) => self.formatter.fmt_string().into(),
+            Type::Slice(hir::Slice::Primitive(_, hir::PrimitiveType::Byte)) => "bytes".into(),
+            Type::Slice(hir::Slice::Primitive(_, p)) => {
+                format!("list[{}]", self.formatter.fmt_primitive_as_python(p))
+            }
+            Type::Slice(hir::Slice::Strs(..)) => "list[str]".into(),
+            Type::Trait(ref t) => {
+                let def = t.resolve(self.tcx);
+                self.errors.push_error(format!(
+                    "Traits are not supported in Python, found {}",
+                    def.name
+                ));
+                "typing.Any".into()
+            }
+            Type::Func(..) => {
+                self.errors
+                    .push_error("Callbacks are not supported in Python".into());
+                "typing.Any".into()
+            }
+            _ => unreachable!("unknown AST/HIR variant"),
+        }
+    }
+
+    fn gen_named_type(&mut self, id: TypeId) -> String {
+        let type_name = self.formatter.fmt_type_name(id);
+        if self.tcx.resolve_type(id).attrs().disable {
+            self.errors
+                .push_error(format!("Found usage of disabled type {type_name}"))
+        }
+        type_name.into_owned()
+    }
+
+    fn gen_type_name_for_success(&mut self, success: &SuccessType) -> String {
+        match success {
+            SuccessType::Unit => "None".into(),
+            SuccessType::Writeable => self.formatter.fmt_string().into(),
+            SuccessType::OutType(o) => self.gen_type_name(o),
+            _ => unreachable!("unknown AST/HIR variant"),
+        }
+    }
+
+    /// Generates a return type's Python type annotation.
+    fn gen_return_type_name(&mut self, output: &ReturnType) -> String {
+        match output {
+            ReturnType::Infallible(ok) | ReturnType::Fallible(ok, _) => {
+                self.gen_type_name_for_success(ok)
+            }
+            ReturnType::Nullable(SuccessType::Unit) => "bool".into(),
+            ReturnType::Nullable(ok) => {
+                let ok = self.gen_type_name_for_success(ok);
+                self.formatter.fmt_optional(&ok)
+            }
+        }
+    }
+
+    /// Generates a type's `ctypes` type.
+    fn gen_ffi_type<P: TyPosition>(&mut self, ty: &Type<P>) -> String {
+        match *ty {
+            Type::Primitive(prim) => self.formatter.fmt_primitive_as_ffi(prim).into(),
+            Type::Opaque(_) | Type::Trait(_) | Type::Func(_) => {
+                self.formatter.fmt_opaque_as_ffi().into()
+            }
+            Type::Struct(ref st) => {
+                let name = self.formatter.fmt_type_name(st.id());
+                self.formatter.fmt_ffi_struct_name(&name)
+            }
+            Type::Enum(ref e) if e.resolve(self.tcx).is_data_carrying() => {
+                let name = self.formatter.fmt_type_name(e.tcx_id.into());
+                self.formatter.fmt_ffi_struct_name(&name)
+            }
+            Type::Enum(_) => self.formatter.fmt_enum_as_ffi().into(),
+            Type::Slice(ref s) => self.formatter.fmt_slice_type(&self.gen_slice_element(s)),
+            _ => unreachable!("unknown AST/HIR variant"),
+        }
+    }
+
+    /// The FFI struct a type is held in by value, if any
+    fn gen_ffi_dep<P: TyPosition>(&mut self, ty: &Type<P>) -> Option<String> {
+        match ty {
+            Type::Struct(_) => Some(self.gen_ffi_type(ty)),
+            Type::Enum(e) if e.resolve(self.tcx).is_data_carrying() => Some(self.gen_ffi_type(ty)),
+            _ => None,
+        }
+    }
+
+    /// Generates the `ctypes` element type of a slice.
+    fn gen_slice_element(&self, slice: &hir::Slice) -> String {
+        match *slice {
+            hir::Slice::Str(_, encoding) => {
+                self.formatter.fmt_string_element_as_ffi(encoding).into()
+            }
+            hir::Slice::Primitive(_, p) => self.formatter.fmt_primitive_as_ffi(p).into(),
+            hir::Slice::Strs(encoding) => self
+                .formatter
+                .fmt_slice_type(self.formatter.fmt_string_element_as_ffi(encoding)),
+            _ => unreachable!("unknown AST/HIR variant"),
+        }
+    }
+
+    /// Generates the `ctypes` return type of a method.
+    fn gen_return_type_ffi(&mut self, output: &'cx ReturnType) -> String {
+        match output {
+            ReturnType::Infallible(SuccessType::OutType(o)) => self.gen_ffi_type(o),
+            ReturnType::Infallible(_) => "None".into(),
+            ReturnType::Fallible(ok, _) | ReturnType::Nullable(ok) => {
+                let ok = ok.as_type();
+                let err = match output {
+                    ReturnType::Fallible(_, err) => err.as_ref(),
+                    _ => None,
+                };
+                let name = self.formatter.fmt_result_name(ok, err);
+                if !self.ffi_structs.contains_key(&name) {
+                    let union_name = format!("{name}Union");
+                    let mut union_fields = Vec::new();
+                    let mut deps = Vec::new();
+                    for (field, ty) in [("ok", ok), ("err", err)] {
+                        if let Some(ty) = ty {
+                            union_fields.push((field.to_owned(), self.gen_ffi_type(ty)));
+                            deps.extend(self.gen_ffi_dep(ty));
+                        }
+                    }
+                    let mut fields = Vec::new();
+                    let mut deps_of_result = Vec::new();
+                    if !union_fields.is_empty() {
+                        fields.push(("union".to_owned(), union_name.clone()));
+                        deps_of_result.push(union_name.clone());
+                        self.ffi_structs.insert(
+                            union_name.clone(),
+                            FfiStruct {
+                                is_union: true,
+                                fields: union_fields,
+                                deps,
+                            },
+                        );
+                    }
+                    fields.push(("is_ok".to_owned(), "ctypes.c_bool".to_owned()));
+                    self.ffi_structs.insert(
+                        name.clone(),
+                        FfiStruct {
+                            is_union: false,
+                            fields,
+                            deps: deps_of_result,
+                        },
+                    );
+                }
+                name
+            }
+        }
+    }
+
+    /// Generates the Python expression passing a slice, which evaluates to a
+    /// pointer and a length.
+    fn gen_slice_to_c(&self, slice: &hir::Slice, value: &str) -> String {
+        // Owned slices are allocated by Rust, everything else lives in `keep`
+        let keep = if slice.lifetime().is_some() {
+            ", keep"
+        } else {
+            ""
+        };
+        match *slice {
+            hir::Slice::Str(_, hir::StringEncoding::UnvalidatedUtf16) => {
+                format!("_runtime.str16({value}{keep})")
+            }
+            hir::Slice::Str(..) => format!("_runtime.str8({value}{keep})"),
+            hir::Slice::Primitive(_, p) => format!(
+                "_runtime.prim_slice({}, {value}{keep})",
+                self.formatter.fmt_primitive_as_ffi(p)
+            ),
+            hir::Slice::Strs(hir::StringEncoding::UnvalidatedUtf16) => {
+                format!("_runtime.strs16({value}{keep})")
+            }
+            hir::Slice::Strs(_) => format!("_runtime.strs8({value}{keep})"),
+            _ => unreachable!("unknown AST/HIR variant"),
+        }
+    }
+
+    /// Generates the Python expression reading a member of a union.
+    fn gen_read_union<P: TyPosition>(&mut self, union: &str, ty: &Type<P>) -> String {
+        format!("_runtime.read_union({union}, {})", self.gen_ffi_type(ty))
+    }
+
+    /// Generates the Python expression converting a Python value to its C type.
+    ///
+    /// Temporary allocations are appended to `keep`.
+    fn gen_python_to_c<P: TyPosition>(&mut self, ty: &Type<P>, value: &str) -> String {
+        match *ty {
+            Type::Primitive(hir::PrimitiveType::Char) => format!("ord({value})"),
+            Type::Primitive(_) => value.into(),
+            Type::Opaque(ref op) if op.is_optional() => {
+                format!("({value}._ffi if {value} is not None else None)")
+            }
+            Type::Opaque(_) => format!("{value}._ffi"),
+            Type::Struct(_) => format!("{value}._to_ffi(keep)"),
+            Type::Enum(ref e) if e.resolve(self.tcx).is_data_carrying() => {
+                format!("{value}._to_ffi(keep)")
+            }
+            Type::Enum(_) => format!("int({value})"),
+            Type::Slice(ref s) => format!(
+                "{}(*{})",
+                self.gen_ffi_type(ty),
+                self.gen_slice_to_c(s, value)
+            ),
+            Type::Trait(_) | Type::Func(_) => {
+                // Already reported by gen_type_name
+                "None".into()
+            }
+            _ => unreachable!("unknown AST/HIR variant"),
+        }
+    }
+
+    /// Generates the Python expression converting a C value to its Python type.
+    ///
+    /// `edges` is a Python list of everything borrowed values may borrow from.
+    fn gen_c_to_python<P: TyPosition>(&mut self, ty: &Type<P>, value: &str, edges: &str) -> String {
+        match *ty {
+            Type::Primitive(hir::PrimitiveType::Char) => format!("chr({value})"),
+            Type::Primitive(_) => value.into(),
+            Type::Opaque(ref op) => {
+                let type_name = self.formatter.fmt_type_name(op.tcx_id.into());
+                let owned = op.owner.is_owned();
+                // Owned opaques only need edges for their own lifetimes
+                let edges = if owned && op.lifetimes.lifetimes().len() == 0 {
+                    "[]"
+                } else {
+                    edges
+                };
+                let owned = if owned { "True" } else { "False" };
+                let construction = format!("{type_name}._from_ffi({value}, {owned}, {edges})");
+                if op.is_optional() {
+                    format!("(None if not {value} else {construction})")
+                } else {
+                    construction
+                }
+            }
+            Type::Struct(ref st) => {
+                let type_name = self.formatter.fmt_type_name(st.id());
+                format!("{type_name}._from_ffi({value}, {edges})")
+            }
+            Type::Enum(ref e) => {
+                let type_name = self.formatter.fmt_type_name(e.tcx_id.into());
+                if e.resolve(self.tcx).is_data_carrying() {
+                    format!("{type_name}._from_ffi({value}, {edges})")
+                } else {
+                    format!("{type_name}({value})")
+                }
+            }
+            Type::Slice(ref s) => {
+                // Slices without a lifetime are owned, so they are freed after copying
+                let owned = if s.lifetime().is_none() {
+                    "True"
+                } else {
+                    "False"
+                };
+                let read = match s {
+                    hir::Slice::Str(_, hir::StringEncoding::UnvalidatedUtf16) => "read_str16",
+                    hir::Slice::Str(..) => "read_str8",
+                    hir::Slice::Primitive(_, hir::PrimitiveType::Byte) => "read_bytes",
+                    hir::Slice::Primitive(..) => "read_slice",
+                    _ => {
+                        self.errors
+                            .push_error("Returning string lists is not supported".into());
+                        "read_slice"
+                    }
+                };
+                format!("_runtime.{read}({value}, {owned})")
+            }
+            Type::Trait(_) | Type::Func(_) => "None".into(),
+            _ => unreachable!("unknown AST/HIR variant"),
+        }
+    }
+}
+
+/// A `ctypes.Structure` mirroring a C struct or union.
+struct FfiStruct {
+    /// Unions are created by `_runtime.union_type` from their field types
+    is_union: bool,
+    /// The name and `ctypes` type of each field
+    fields: Vec<(String, String)>,
+    /// The FFI structs held by value, which have to be complete first
+    deps: Vec<String>,
+}
+
+/// The `ctypes` signature of a C function.
+struct Prototype {
+    c_name: String,
+    argtypes: Vec<String>,
+    restype: String,
+}
+
+/// Everything needed for rendering a method.
+struct MethodInfo {
+    /// The Python method name
+    name: String,
+    decorators: Vec<&'static str>,
+    /// The parameter declarations, including `self` or `cls`
+    params: Vec<String>,
+    /// The return type annotation
+    return_ty: String,
+    docs: String,
+    /// The statements of the method body
+    body: Vec<String>,
+}
+
+/// A property made up of a getter and/or a setter method.
+struct PropertyInfo {
+    name: String,
+    /// The getter method name, or `None`
+    getter: String,
+    /// The setter method name, or `None`
+    setter: String,
+}
+
+struct FieldInfo {
+    name: String,
+    python_type_name: String,
+    c_to_python: String,
+    python_to_c: String,
+}
+
+/// Everything needed for rendering a const, either as a class attribute or at the top level.
+struct ConstInfo {
+    name: String,
+    value: String,
+    docs: String,
+}
+
+struct EnumVariantInfo {
+    name: String,
+    discriminant: isize,
+    docs: String,
+}
+
+/// Everything needed for rendering a variant of a data-carrying enum.
+struct TaggedVariantInfo {
+    /// The name of the Python subclass for this variant
+    class_name: String,
+    /// The name of the FFI struct holding the payload
+    payload_ffi_name: String,
+    docs: String,
+    discriminant: isize,
+    fields: Vec<FieldInfo>,
+}
+
+#[derive(Default)]
+struct SpecialMethodGenInfo {
+    /// Whether it is a comparator
+    comparator: bool,
+    /// Whether it is an iterator
+    iterator: bool,
+}
+
+// Helpers used in templates (Askama has restrictions on Rust syntax)
+
+/// The rich comparison methods a comparator provides, and their operators
+const COMPARISONS: &[(&str, &str)] = &[
+    ("__eq__", "=="),
+    ("__lt__", "<"),
+    ("__le__", "<="),
+    ("__gt__", ">"),
+    ("__ge__", ">="),
+];
+
+/// Indent all but the first line of a docstring, leaving blank lines empty
+fn indent(docs: &str, width: usize) -> String {
+    let indent = " ".repeat(width);
+    let mut lines = docs.lines();
+    let mut out = lines.next().unwrap_or_default().to_owned();
+    for line in lines {
+        out.push('\n');
+        if !line.is_empty() {
+            out.push_str(&indent);
+            out.push_str(line);
+        }
+    }
+    if docs.contains('\n') {
+        out.push('\n');
+        out.push_str(&indent);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_struct_layout() {
+        test_file! {
+            #[diplomat::bridge]
+            mod ffi {
+                pub enum Unit {
+                    Meters,
+                    Feet,
+                }
+
+                pub struct Inner {
+                    pub flag: bool,
+                    pub unit: Unit,
+                }
+
+                pub struct Outer<'a> {
+                    pub small: u8,
+                    pub wide: f64,
+                    pub inner: Inner,
+                    pub name: &'a DiplomatStr,
+                    pub values: &'a [u32],
+                }
+
+                pub enum Reading {
+                    Distance { value: f64, unit: Unit },
+                    Nested { inner: Inner },
+                    Missing,
+                }
+
+                #[diplomat::opaque]
+                pub struct Sensor;
+
+                impl Sensor {
+                    pub fn record(&mut self, outer: Outer, reading: Reading) {}
+                    pub fn last(&self) -> Reading {
+                        unimplemented!()
+                    }
+                    pub fn inner(&self) -> Inner {
+                        unimplemented!()
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_ownership() {
+        test_file! {
+            #[diplomat::bridge]
+            mod ffi {
+                #[diplomat::opaque]
+                pub struct Buffer(Vec<u8>);
+
+                #[diplomat::opaque]
+                pub struct View;
+
+                impl Buffer {
+                    #[diplomat::attr(*, constructor)]
+                    pub fn new(data: &[u8]) -> Box<Buffer> {
+                        unimplemented!()
+                    }
+                    pub fn try_new(len: usize) -> Option<Box<Buffer>> {
+                        unimplemented!()
+                    }
+                    pub fn view<'a>(&'a self) -> &'a View {
+                        unimplemented!()
+                    }
+                    pub fn bytes<'a>(&'a self) -> &'a [u8] {
+                        unimplemented!()
+                    }
+                    pub fn copy(&self) -> Box<[u8]> {
+                        unimplemented!()
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_catch_panics() {
+        test_file! {
+            #[diplomat::bridge]
+            mod ffi {
+                pub enum MyError {
+                    Panicked,
+                    Other,
+                }
+
+                #[diplomat::opaque]
+                pub struct Thing;
+
+                #[diplomat::catch_panics]
+                impl Thing {
+                    pub fn might_panic(x: u32) -> u32 {
+                        unimplemented!()
+                    }
+                    pub fn might_fail(x: u32) -> Result<u32, MyError> {
+                        unimplemented!()
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct LibraryConfig {
+    /// The name of the native library the bindings link against, when they don't
+    /// call it through an API table
+    pub native_lib: String,
+}
+
+impl Default for LibraryConfig {
+    fn default() -> LibraryConfig {
+        LibraryConfig {
+            native_lib: "rust".to_owned(),
+        }
+    }
+}
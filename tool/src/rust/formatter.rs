@@ -0,0 +1,183 @@
+//! This module contains functions for formatting types
+
+use crate::c2::CFormatter;
+use diplomat_core::ast::{DocsUrlGenerator, MarkdownStyle};
+use diplomat_core::hir::{self, TypeContext, TypeId};
+use heck::ToSnakeCase;
+use std::borrow::Cow;
+
+/// This type mediates all formatting
+///
+/// All identifiers from the HIR should go through here before being formatted
+/// into the output: This makes it easy to handle reserved words or add rename support
+///
+/// If you find yourself needing an identifier formatted in a context not yet available here, please add a new method
+pub(super) struct RustFormatter<'tcx> {
+    c: CFormatter<'tcx>,
+    docs_url_generator: &'tcx DocsUrlGenerator,
+    strip_prefix: Option<String>,
+}
+
+/// Rust's keywords, which identifiers are escaped from as raw identifiers
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "box", "break", "const", "continue", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "gen", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut",
+    "pub", "ref", "return", "static", "struct", "trait", "true", "try", "type", "unsafe", "use",
+    "where", "while", "yield", "abstract", "become", "do", "final", "macro", "override", "priv",
+    "typeof", "unsized", "virtual",
+];
+
+/// Keywords that can't be raw identifiers either
+const PATH_KEYWORDS: &[&str] = &["self", "Self", "super", "crate"];
+
+impl<'tcx> RustFormatter<'tcx> {
+    pub fn new(
+        tcx: &'tcx TypeContext,
+        docs_url_generator: &'tcx DocsUrlGenerator,
+        strip_prefix: Option<String>,
+    ) -> Self {
+        Self {
+            c: CFormatter::new(tcx),
+            docs_url_generator,
+            strip_prefix,
+        }
+    }
+
+    /// Format docs as the contents of a documentation comment
+    pub fn fmt_docs(&self, docs: &hir::Docs) -> String {
+        docs.to_markdown(self.docs_url_generator, MarkdownStyle::Normal)
+            .trim()
+            .replace(" \n", "\n")
+            .replace(
+                &format!("`{}", self.strip_prefix.as_deref().unwrap_or("")),
+                "`",
+            )
+    }
+
+    /// The module holding a type
+    pub fn fmt_module_name(&self, id: TypeId) -> String {
+        self.fmt_type_name(id).to_snake_case()
+    }
+
+    /// The path of the file holding a type
+    pub fn fmt_file_path(&self, id: TypeId) -> String {
+        format!("{}.rs", self.fmt_module_name(id))
+    }
+
+    /// The symbol of a method in the C ABI
+    pub fn fmt_c_method_name(&self, ty: TypeId, method: &hir::Method) -> String {
+        self.c.fmt_method_name(ty, method, true)
+    }
+
+    /// The symbol of the destructor of a type in the C ABI
+    pub fn fmt_destructor_name(&self, id: TypeId) -> String {
+        self.c.fmt_dtor_name(id)
+    }
+
+    /// Resolve and format a named type for use in code
+    pub fn fmt_type_name(&self, id: TypeId) -> Cow<'tcx, str> {
+        let resolved = self.c.tcx().resolve_type(id);
+
+        let candidate: Cow<str> = if let Some(strip_prefix) = self.strip_prefix.as_ref() {
+            resolved
+                .name()
+                .as_str()
+                .strip_prefix(strip_prefix)
+                .unwrap_or(resolved.name().as_str())
+                .into()
+        } else {
+            resolved.name().as_str().into()
+        };
+
+        resolved.attrs().rename.apply(candidate)
+    }
+
+    /// Resolve and format a named type for use in diagnostics
+    /// (don't apply rename rules and such)
+    pub fn fmt_type_name_diagnostics(&self, id: TypeId) -> Cow<'tcx, str> {
+        self.c.fmt_type_name_diagnostics(id)
+    }
+
+    /// Format a method name
+    pub fn fmt_method_name(&self, method: &hir::Method) -> String {
+        let name = method
+            .attrs
+            .rename
+            .apply(method.name.as_str().into())
+            .to_snake_case();
+        self.fmt_identifier(name)
+    }
+
+    /// Format a parameter name, which is also used to name the arguments derived
+    /// from it, so keywords get a trailing underscore instead of becoming raw
+    /// identifiers
+    pub fn fmt_param_name(&self, ident: &str) -> String {
+        if KEYWORDS.contains(&ident) || PATH_KEYWORDS.contains(&ident) {
+            format!("{ident}_")
+        } else {
+            ident.into()
+        }
+    }
+
+    /// Format a field name
+    pub fn fmt_field_name(&self, ident: &str) -> String {
+        self.fmt_identifier(ident.into())
+    }
+
+    /// Format an enum variant
+    pub fn fmt_enum_variant(&self, variant: &'tcx hir::EnumVariant) -> String {
+        self.fmt_identifier(
+            variant
+                .attrs
+                .rename
+                .apply(variant.name.as_str().into())
+                .into(),
+        )
+    }
+
+    /// Format the name of a const
+    pub fn fmt_const_name(&self, def: &hir::ConstDef) -> String {
+        self.fmt_identifier(def.attrs.rename.apply(def.name.as_str().into()).into())
+    }
+
+    /// Format the value of a const as a Rust literal
+    pub fn fmt_const_value(&self, def: &hir::ConstDef) -> String {
+        match def.value {
+            hir::ConstValue::Bool(b) => b.to_string(),
+            // Debug formatting escapes a string the way Rust literals do
+            hir::ConstValue::Str(ref s) => format!("{s:?}"),
+            hir::ConstValue::Int(ref n) | hir::ConstValue::Float(ref n) => n.clone(),
+            _ => unreachable!("unknown AST/HIR variant"),
+        }
+    }
+
+    /// The Rust type of a const
+    pub fn fmt_const_type(&self, def: &hir::ConstDef) -> &'static str {
+        match def.ty {
+            hir::ConstType::Primitive(p) => self.fmt_primitive(p),
+            hir::ConstType::Str => "&str",
+            _ => unreachable!("unknown AST/HIR variant"),
+        }
+    }
+
+    /// The Rust type of a primitive, as the C ABI passes it
+    pub fn fmt_primitive(&self, prim: hir::PrimitiveType) -> &'static str {
+        match prim {
+            // Not necessarily a valid `char`
+            hir::PrimitiveType::Char => "DiplomatChar",
+            hir::PrimitiveType::Byte => "u8",
+            _ => prim.as_str(),
+        }
+    }
+
+    /// Escape identifiers that are keywords
+    fn fmt_identifier(&self, name: String) -> String {
+        if KEYWORDS.contains(&name.as_str()) {
+            format!("r#{name}")
+        } else if PATH_KEYWORDS.contains(&name.as_str()) {
+            format!("{name}_")
+        } else {
+            name
+        }
+    }
+}
@@ -0,0 +1,1095 @@
+//! Rust bindings for hosts that load a Diplomat library as a plugin, which call it
+//! through the C ABI the bridge macro exports instead of depending on the bridge crate
+
+use crate::common::{ErrorStore, FileMap};
+use crate::ApiInfo;
+use askama::Template;
+use config::LibraryConfig;
+use diplomat_core::ast::{ApiLayout, DocsUrlGenerator, API_LAYOUT_VERSION};
+use diplomat_core::hir::{
+    self, Lifetime, LifetimeEnv, MaybeStatic, OpaqueOwner, ReturnType, SelfType, StringEncoding,
+    StructPathLike, SuccessType, TyPosition, Type, TypeContext, TypeDef, TypeId,
+};
+use formatter::RustFormatter;
+use std::collections::HashMap;
+use std::fmt::Display;
+
+#[cfg(test)]
+#[macro_use]
+mod test_util;
+
+pub mod config;
+mod formatter;
+
+/// Run file generation
+///
+/// With `api`, the bindings call the library through the table of the API instead
+/// of linking against it, and hosts set the table once they have loaded the library.
+pub fn run<'cx>(
+    tcx: &'cx TypeContext,
+    library_config: LibraryConfig,
+    docs_url_generator: &'cx DocsUrlGenerator,
+    strip_prefix: Option<String>,
+    api: Option<(&ApiInfo, &ApiLayout)>,
+) -> Result<FileMap, Vec<(impl Display + 'cx, String)>> {
+    let formatter = RustFormatter::new(tcx, docs_url_generator, strip_prefix);
+
+    let files = FileMap::default();
+    let errors = ErrorStore::default();
+
+    let tgcx = TyGenContext {
+        tcx,
+        errors: &errors,
+        formatter: &formatter,
+        native_lib: &library_config.native_lib,
+        get_api_fn: api.map(|(info, _)| info.get_api_fn),
+    };
+
+    let mut modules = Vec::new();
+    for (id, ty) in tcx.all_types() {
+        if ty.attrs().disable {
+            continue;
+        }
+
+        files.add_file(formatter.fmt_file_path(id), tgcx.gen(id));
+        modules.push((formatter.fmt_module_name(id), formatter.fmt_type_name(id)));
+    }
+    modules.sort();
+
+    for (_, trt) in tcx.all_traits() {
+        if !trt.attrs.disable {
+            let _guard = errors.set_context_ty(trt.name.as_str().into());
+            errors.push_error(format!(
+                "Traits are not supported in Rust, found {}",
+                trt.name
+            ));
+        }
+    }
+
+    files.add_file(
+        "lib.rs".into(),
+        LibTemplate {
+            modules,
+            api: api.is_some(),
+            consts: tgcx.gen_consts(tcx.consts()),
+        }
+        .render()
+        .unwrap(),
+    );
+
+    files.add_file(
+        "diplomat_runtime.rs".into(),
+        RuntimeTemplate {
+            native_lib: &library_config.native_lib,
+            get_api_fn: tgcx.get_api_fn,
+            catches_panics: tcx.catches_panics(),
+        }
+        .render()
+        .unwrap(),
+    );
+
+    if let Some((info, layout)) = api {
+        files.add_file("diplomat_api.rs".into(), tgcx.gen_api(info, layout));
+    }
+
+    let errors = errors.take_all();
+    if !errors.is_empty() {
+        Err(errors)
+    } else {
+        Ok(files)
+    }
+}
+
+#[derive(Template)]
+#[template(path = "rust/lib.rs.jinja", escape = "none")]
+struct LibTemplate<'a> {
+    /// The module of each type, with the name of the type
+    modules: Vec<(String, std::borrow::Cow<'a, str>)>,
+    api: bool,
+    consts: Vec<String>,
+}
+
+#[derive(Template)]
+#[template(path = "rust/runtime.rs.jinja", escape = "none")]
+struct RuntimeTemplate<'a> {
+    native_lib: &'a str,
+    get_api_fn: Option<&'a str>,
+    /// Whether the library has the functions that report caught panics
+    catches_panics: bool,
+}
+
+struct TyGenContext<'a, 'cx> {
+    tcx: &'cx TypeContext,
+    formatter: &'a RustFormatter<'cx>,
+    errors: &'a ErrorStore<'cx, String>,
+    native_lib: &'a str,
+    /// The function returning the API table, if the library is called through one
+    get_api_fn: Option<&'a str>,
+}
+
+/// Where a type is used, which decides how owned slices are passed
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Usage {
+    Param,
+    Field,
+    /// A return value as the bindings return it
+    Return,
+    /// A return value as the C ABI returns it
+    FfiReturn,
+}
+
+impl<'a, 'cx> TyGenContext<'a, 'cx> {
+    /// Generates the file of a type
+    fn gen(&self, id: TypeId) -> String {
+        let ty = self.tcx.resolve_type(id);
+
+        let _guard = self.errors.set_context_ty(ty.name().as_str().into());
+
+        let name = self.formatter.fmt_type_name(id);
+        let (def, def_lifetimes, methods, consts) = match ty {
+            TypeDef::Enum(e) => (self.gen_enum(e, &name), None, &e.methods, &e.consts),
+            TypeDef::Opaque(o) => (
+                self.gen_opaque_def(o, id, &name),
+                Some(&o.lifetimes),
+                &o.methods,
+                &o.consts,
+            ),
+            TypeDef::Struct(s) => (
+                self.gen_struct_def(s, &name, true),
+                Some(&s.lifetimes),
+                &s.methods,
+                &s.consts,
+            ),
+            TypeDef::OutStruct(s) => (
+                self.gen_struct_def(s, &name, false),
+                Some(&s.lifetimes),
+                &s.methods,
+                &s.consts,
+            ),
+            _ => unreachable!("unknown AST/HIR variant"),
+        };
+        let impl_lifetimes = def_lifetimes.map(fmt_def_lifetimes).unwrap_or_default();
+
+        let mut ffi_fns = Vec::new();
+        if let TypeDef::Opaque(_) = ty {
+            ffi_fns.push(FfiFn {
+                name: self.formatter.fmt_destructor_name(id),
+                generics: String::new(),
+                args: "this".into(),
+                params: format!(
+                    "this: *mut {name}{}",
+                    fmt_generics(&vec!["'_".to_owned(); impl_lifetimes.len()])
+                ),
+                returns: String::new(),
+                slot: self.fmt_slot(id, self.formatter.fmt_destructor_name(id)),
+            });
+        }
+
+        // Types with lifetimes get the methods taking `self` in an `impl` naming
+        // them, and the others in one that doesn't, since their lifetimes have
+        // nothing to do with those of the type
+        let mut static_items = self.gen_consts(consts);
+        let mut self_methods = Vec::new();
+        for method in methods {
+            if method.attrs.disable {
+                continue;
+            }
+            let is_static = method.param_self.is_none();
+            let lifetimes = if is_static {
+                &[][..]
+            } else {
+                &impl_lifetimes[..]
+            };
+            let (method, ffi_fn) = self.gen_method(id, method, lifetimes);
+            if is_static || impl_lifetimes.is_empty() {
+                static_items.push(method);
+            } else {
+                self_methods.push(method);
+            }
+            ffi_fns.push(ffi_fn);
+        }
+
+        let names = impl_lifetimes
+            .iter()
+            .map(|lt| format!("'{lt}"))
+            .collect::<Vec<_>>();
+        let mut impls = vec![
+            ImplBlock {
+                header: format!(
+                    "impl {name}{}",
+                    fmt_generics(&vec!["'_".to_owned(); names.len()])
+                ),
+                items: static_items,
+            },
+            ImplBlock {
+                header: format!("impl{0} {name}{0}", fmt_generics(&names)),
+                items: self_methods,
+            },
+        ];
+        impls.retain(|block| !block.items.is_empty());
+
+        TypeTemplate {
+            def,
+            impls,
+            ffi_fns,
+            native_lib: self.native_lib,
+            api: self.get_api_fn,
+        }
+        .render()
+        .unwrap()
+    }
+
+    /// Generates consts, which are associated with the type they are declared on
+    fn gen_consts(&self, consts: &[hir::ConstDef]) -> Vec<String> {
+        consts
+            .iter()
+            .map(|def| {
+                format!(
+                    "{}pub const {}: {} = {};",
+                    rustdoc(&self.formatter.fmt_docs(&def.docs)),
+                    self.formatter.fmt_const_name(def),
+                    self.formatter.fmt_const_type(def),
+                    self.formatter.fmt_const_value(def),
+                )
+            })
+            .collect()
+    }
+
+    fn gen_enum(&self, ty: &'cx hir::EnumDef, type_name: &str) -> String {
+        let no_lifetimes = LifetimeNames::default();
+        let data_carrying = ty.is_data_carrying();
+        let variants = ty
+            .variants
+            .iter()
+            .map(|variant| {
+                let fields = variant
+                    .fields
+                    .iter()
+                    .map(|field| self.gen_field(field, &no_lifetimes))
+                    .collect::<Vec<_>>();
+                EnumVariantInfo {
+                    docs: self.formatter.fmt_docs(&variant.docs),
+                    name: self.formatter.fmt_enum_variant(variant),
+                    // Rust only allows explicit discriminants on enums carrying data
+                    // when they have a primitive representation
+                    discriminant: (!data_carrying).then(|| variant.discriminant),
+                    fields,
+                }
+            })
+            .collect();
+
+        #[derive(Template)]
+        #[template(path = "rust/enum.rs.jinja", escape = "none")]
+        struct ImplTemplate<'a> {
+            type_name: &'a str,
+            docs: String,
+            data_carrying: bool,
+            variants: Vec<EnumVariantInfo>,
+        }
+
+        ImplTemplate {
+            type_name,
+            docs: self.formatter.fmt_docs(&ty.docs),
+            data_carrying,
+            variants,
+        }
+        .render()
+        .unwrap()
+    }
+
+    fn gen_opaque_def(&self, ty: &'cx hir::OpaqueDef, id: TypeId, type_name: &str) -> String {
+        let lifetimes = fmt_def_lifetimes(&ty.lifetimes)
+            .into_iter()
+            .map(|lt| format!("'{lt}"))
+            .collect::<Vec<_>>();
+
+        #[derive(Template)]
+        #[template(path = "rust/opaque.rs.jinja", escape = "none")]
+        struct ImplTemplate<'a> {
+            type_name: &'a str,
+            docs: String,
+            lifetimes: Vec<String>,
+            generics: String,
+            anon_generics: String,
+            destructor: String,
+        }
+
+        ImplTemplate {
+            type_name,
+            docs: self.formatter.fmt_docs(&ty.docs),
+            generics: fmt_generics(&lifetimes),
+            anon_generics: fmt_generics(&vec!["'_".to_owned(); lifetimes.len()]),
+            lifetimes,
+            destructor: self.formatter.fmt_destructor_name(id),
+        }
+        .render()
+        .unwrap()
+    }
+
+    /// Generates a struct, which has the layout of the one in the bridge.
+    ///
+    /// Like there, structs that aren't only returned are `Copy`.
+    fn gen_struct_def<P: TyPosition>(
+        &self,
+        ty: &'cx hir::StructDef<P>,
+        type_name: &str,
+        copy: bool,
+    ) -> String {
+        let names = LifetimeNames::of_def(&ty.lifetimes);
+        let lifetimes = fmt_def_lifetimes(&ty.lifetimes)
+            .into_iter()
+            .map(|lt| format!("'{lt}"))
+            .collect::<Vec<_>>();
+        let fields = ty
+            .fields
+            .iter()
+            .map(|field| self.gen_field(field, &names))
+            .collect();
+
+        #[derive(Template)]
+        #[template(path = "rust/struct.rs.jinja", escape = "none")]
+        struct ImplTemplate<'a> {
+            type_name: &'a str,
+            docs: String,
+            generics: String,
+            copy: bool,
+            fields: Vec<FieldInfo>,
+        }
+
+        ImplTemplate {
+            type_name,
+            docs: self.formatter.fmt_docs(&ty.docs),
+            generics: fmt_generics(&lifetimes),
+            copy,
+            fields,
+        }
+        .render()
+        .unwrap()
+    }
+
+    fn gen_field<P: TyPosition>(
+        &self,
+        field: &'cx hir::StructField<P>,
+        lifetimes: &LifetimeNames,
+    ) -> FieldInfo {
+        FieldInfo {
+            docs: self.formatter.fmt_docs(&field.docs),
+            name: self.formatter.fmt_field_name(field.name.as_str()),
+            ty: self.gen_type(&field.ty, lifetimes, Usage::Field),
+        }
+    }
+
+    /// Generates a method, along with the declaration of the function it calls.
+    ///
+    /// `impl_lifetimes` are the lifetimes the `impl` block the method goes in names.
+    fn gen_method(
+        &self,
+        id: TypeId,
+        method: &'cx hir::Method,
+        impl_lifetimes: &[String],
+    ) -> (String, FfiFn) {
+        let _guard = self.errors.set_context_method(
+            self.formatter.fmt_type_name_diagnostics(id),
+            method.name.as_str().into(),
+        );
+
+        let lifetimes = MethodLifetimes::new(method, impl_lifetimes);
+        let names = &lifetimes.names;
+
+        let mut params = Vec::new();
+        let mut ffi_params = Vec::new();
+        let mut ffi_args = Vec::new();
+        let mut args = Vec::new();
+        // Owned slices are copied before the call
+        let mut body = Vec::new();
+
+        if let Some(param_self) = &method.param_self {
+            let (param, ffi_ty) = match &param_self.ty {
+                SelfType::Opaque(path) => {
+                    let borrow = path.owner;
+                    let mutability = if borrow.mutability.is_mutable() {
+                        " mut"
+                    } else {
+                        ""
+                    };
+                    let lifetime = names.fmt(borrow.lifetime);
+                    (
+                        format!("&{lifetime}{mutability} self"),
+                        format!(
+                            "&{lifetime}{mutability} {}",
+                            self.gen_path(path.tcx_id.into(), path.lifetimes.lifetimes(), names)
+                        ),
+                    )
+                }
+                SelfType::Struct(path) => (
+                    "self".to_owned(),
+                    self.gen_path(path.id(), path.lifetimes().lifetimes(), names),
+                ),
+                SelfType::Enum(path) => (
+                    "self".to_owned(),
+                    self.formatter.fmt_type_name(path.tcx_id.into()).into(),
+                ),
+                _ => unreachable!("unknown AST/HIR variant"),
+            };
+            params.push(param);
+            ffi_params.push(format!("this: {ffi_ty}"));
+            ffi_args.push("this".to_owned());
+            args.push("self".to_owned());
+        }
+
+        for param in &method.params {
+            let name = self.formatter.fmt_param_name(param.name.as_str());
+            params.push(format!(
+                "{name}: {}",
+                self.gen_type(&param.ty, names, Usage::Param)
+            ));
+
+            let slice = match param.ty {
+                Type::Slice(slice) => slice,
+                _ => {
+                    ffi_params.push(format!(
+                        "{name}: {}",
+                        self.gen_type(&param.ty, names, Usage::Param)
+                    ));
+                    ffi_args.push(name.clone());
+                    args.push(name);
+                    continue;
+                }
+            };
+
+            // Two args on the ABI: pointer and length
+            let data = format!("{name}_diplomat_data");
+            let len = format!("{name}_diplomat_len");
+            let (data_ty, data_arg) = match slice {
+                hir::Slice::Primitive(None, _) | hir::Slice::Str(None, _) => {
+                    let bytes = if let hir::Slice::Str(_, StringEncoding::Utf8) = slice {
+                        ".as_bytes()"
+                    } else {
+                        ""
+                    };
+                    body.push(format!("let ({data}, {len}) = alloc_slice({name}{bytes});"));
+                    (
+                        format!("*mut {}", self.gen_slice_element(&slice)),
+                        data.clone(),
+                    )
+                }
+                hir::Slice::Primitive(Some(borrow), p) if borrow.mutability.is_mutable() => (
+                    format!("*mut {}", self.formatter.fmt_primitive(p)),
+                    format!("{name}.as_mut_ptr()"),
+                ),
+                hir::Slice::Strs(encoding) => {
+                    let element = match encoding {
+                        StringEncoding::UnvalidatedUtf16 => "&[u16]",
+                        _ => "&[u8]",
+                    };
+                    let cast = match encoding {
+                        StringEncoding::Utf8 => format!(" as *const {element}"),
+                        _ => String::new(),
+                    };
+                    (
+                        format!("*const {element}"),
+                        format!("{name}.as_ptr(){cast}"),
+                    )
+                }
+                _ => (
+                    format!("*const {}", self.gen_slice_element(&slice)),
+                    format!("{name}.as_ptr()"),
+                ),
+            };
+            let len_arg = if data_arg == data {
+                len.clone()
+            } else {
+                format!("{name}.len()")
+            };
+            ffi_params.push(format!("{data}: {data_ty}"));
+            ffi_params.push(format!("{len}: usize"));
+            ffi_args.push(data);
+            ffi_args.push(len);
+            args.push(data_arg);
+            args.push(len_arg);
+        }
+
+        if method.output.is_writeable() {
+            body.insert(
+                0,
+                "let mut diplomat_write = DiplomatWriteable::new();".into(),
+            );
+            ffi_params.push("write: &mut DiplomatWriteable".into());
+            ffi_args.push("write".into());
+            args.push("&mut diplomat_write".into());
+        }
+
+        let c_name = self.formatter.fmt_c_method_name(id, method);
+        let call = format!("{c_name}({})", args.join(", "));
+        // Methods returning a `Result` get the panic as their error. Others are checked
+        // before their return value is converted, which might not be valid.
+        let checks_panic = method.attrs.catch_panics
+            && !matches!(method.output, ReturnType::Fallible(..));
+        let call = if checks_panic && !method.output.is_ffi_unit() {
+            body.push(format!("let diplomat_ret = {call};"));
+            "diplomat_ret".to_owned()
+        } else {
+            call
+        };
+        let mut result = match &method.output {
+            ReturnType::Infallible(SuccessType::Writeable) => {
+                body.push(format!("{call};"));
+                "diplomat_write.into_string()".to_owned()
+            }
+            ReturnType::Infallible(SuccessType::OutType(ty)) => {
+                self.gen_from_ffi(ty, &call).unwrap_or(call)
+            }
+            ReturnType::Infallible(_) => call,
+            ReturnType::Nullable(ok) => {
+                format!("{call}.into_result().ok(){}", self.gen_map_ok(ok))
+            }
+            ReturnType::Fallible(ok, err) => {
+                let map_err = err
+                    .as_ref()
+                    .and_then(|err| self.gen_from_ffi(err, "err"))
+                    .map(|conversion| format!(".map_err(|err| {conversion})"))
+                    .unwrap_or_default();
+                format!("{call}.into_result(){}{map_err}", self.gen_map_ok(ok))
+            }
+        };
+        if checks_panic {
+            if let ReturnType::Infallible(SuccessType::Unit) = method.output {
+                body.push(format!("{result};"));
+                result = "check_panic()".into();
+            } else {
+                body.push("check_panic();".into());
+            }
+        }
+        body.push(result);
+
+        let returns = self
+            .gen_return_type(&method.output, names, false)
+            .map(|ty| format!(" -> {ty}"))
+            .unwrap_or_default();
+        let ffi_returns = self
+            .gen_return_type(&method.output, names, true)
+            .map(|ty| format!(" -> {ty}"))
+            .unwrap_or_default();
+
+        let ffi_fn = FfiFn {
+            generics: fmt_generics(&lifetimes.all()),
+            params: ffi_params.join(", "),
+            args: ffi_args.join(", "),
+            returns: ffi_returns,
+            slot: self.fmt_slot(id, method.name.to_string()),
+            name: c_name,
+        };
+
+        let method = MethodTemplate {
+            docs: self.formatter.fmt_docs(&method.docs),
+            name: self.formatter.fmt_method_name(method),
+            generics: fmt_generics(&lifetimes.generics),
+            params: params.join(", "),
+            returns,
+            bounds: lifetimes.bounds,
+            body,
+        }
+        .render()
+        .unwrap();
+
+        (method, ffi_fn)
+    }
+
+    /// The slot of the API table a function of the type `id` is called through
+    fn fmt_slot(&self, id: TypeId, slot: String) -> String {
+        match self.get_api_fn {
+            Some(get_api_fn) => {
+                format!("{get_api_fn}().{}.{slot}", self.tcx.resolve_type(id).name())
+            }
+            None => String::new(),
+        }
+    }
+
+    /// Converts the `Ok`, `Some` value of a `Result`, `Option` the C ABI returned
+    fn gen_map_ok(&self, ok: &SuccessType) -> String {
+        match ok {
+            SuccessType::Writeable => ".map(|()| diplomat_write.into_string())".into(),
+            SuccessType::OutType(ty) => self
+                .gen_from_ffi(ty, "ok")
+                .map(|conversion| format!(".map(|ok| {conversion})"))
+                .unwrap_or_default(),
+            _ => String::new(),
+        }
+    }
+
+    /// Converts a value of `ty` the C ABI returned, if the bindings return it as
+    /// something else
+    fn gen_from_ffi(&self, ty: &hir::OutType, value: &str) -> Option<String> {
+        match ty {
+            Type::Slice(hir::Slice::Str(None, StringEncoding::Utf8)) => {
+                Some(format!("take_str({value})"))
+            }
+            Type::Slice(slice) if slice.lifetime().is_none() => {
+                Some(format!("take_slice({value})"))
+            }
+            _ => None,
+        }
+    }
+
+    /// The return type of a method, or `None` if it returns nothing.
+    ///
+    /// With `ffi`, the type the C ABI returns, which has `Result`s and `Option`s
+    /// of anything but opaques as `DiplomatResult`s and writes strings to a
+    /// `DiplomatWriteable` instead.
+    fn gen_return_type(
+        &self,
+        output: &ReturnType,
+        names: &LifetimeNames,
+        ffi: bool,
+    ) -> Option<String> {
+        let usage = if ffi { Usage::FfiReturn } else { Usage::Return };
+        let gen_ok = |ok: &SuccessType| match ok {
+            SuccessType::Writeable if !ffi => "String".to_owned(),
+            SuccessType::OutType(ty) => self.gen_type(ty, names, usage),
+            _ => "()".to_owned(),
+        };
+        match output {
+            ReturnType::Infallible(SuccessType::Unit) => None,
+            ReturnType::Infallible(SuccessType::Writeable) if ffi => None,
+            ReturnType::Infallible(ok) => Some(gen_ok(ok)),
+            ReturnType::Nullable(ok) if ffi => Some(format!("DiplomatResult<{}, ()>", gen_ok(ok))),
+            ReturnType::Nullable(ok) => Some(format!("Option<{}>", gen_ok(ok))),
+            ReturnType::Fallible(ok, err) => {
+                let err = err
+                    .as_ref()
+                    .map(|err| self.gen_type(err, names, usage))
+                    .unwrap_or_else(|| "()".into());
+                let result = if ffi { "DiplomatResult" } else { "Result" };
+                Some(format!("{result}<{}, {err}>", gen_ok(ok)))
+            }
+        }
+    }
+
+    /// The Rust type of `ty`, which is the one the C ABI uses for everything but
+    /// slices passed as parameters and owned slices
+    fn gen_type<P: TyPosition>(&self, ty: &Type<P>, names: &LifetimeNames, usage: Usage) -> String {
+        match ty {
+            Type::Primitive(prim) => self.formatter.fmt_primitive(*prim).into(),
+            Type::Opaque(path) => {
+                let opaque = self.gen_path(path.tcx_id.into(), path.lifetimes.lifetimes(), names);
+                let pointer = match (path.owner.mutability(), path.owner.lifetime()) {
+                    (Some(mutability), Some(lifetime)) => format!(
+                        "&{}{} {opaque}",
+                        names.fmt(lifetime),
+                        if mutability.is_mutable() { " mut" } else { "" }
+                    ),
+                    _ => format!("DiplomatBox<{opaque}>"),
+                };
+                if path.is_optional() {
+                    format!("Option<{pointer}>")
+                } else {
+                    pointer
+                }
+            }
+            Type::Struct(path) => self.gen_path(path.id(), path.lifetimes().lifetimes(), names),
+            Type::Enum(path) => self.formatter.fmt_type_name(path.tcx_id.into()).into(),
+            Type::Slice(slice) => self.gen_slice(slice, names, usage),
+            Type::Func(_) => {
+                self.errors
+                    .push_error("Callbacks are not supported in Rust".into());
+                "()".into()
+            }
+            Type::Trait(_) => {
+                self.errors
+                    .push_error("Traits are not supported in Rust".into());
+                "()".into()
+            }
+            _ => unreachable!("unknown AST/HIR variant"),
+        }
+    }
+
+    fn gen_slice(&self, slice: &hir::Slice, names: &LifetimeNames, usage: Usage) -> String {
+        let element = self.gen_slice_element(slice);
+        let unsized_ty = match slice {
+            hir::Slice::Str(_, StringEncoding::Utf8) => "str".to_owned(),
+            hir::Slice::Strs(encoding) => {
+                let inner = match encoding {
+                    StringEncoding::Utf8 => "str",
+                    StringEncoding::UnvalidatedUtf16 => "[u16]",
+                    _ => "[u8]",
+                };
+                return format!("&[&{inner}]");
+            }
+            _ => format!("[{element}]"),
+        };
+        let borrow = match slice {
+            hir::Slice::Primitive(Some(borrow), _) => {
+                Some((borrow.lifetime, borrow.mutability.is_mutable()))
+            }
+            hir::Slice::Str(Some(lifetime), _) => Some((*lifetime, false)),
+            _ => None,
+        };
+        match (borrow, usage) {
+            (Some((lifetime, mutable)), _) => format!(
+                "&{}{} {unsized_ty}",
+                names.fmt(lifetime),
+                if mutable { " mut" } else { "" }
+            ),
+            // Copied into memory the library owns
+            (None, Usage::Param) => format!("&{unsized_ty}"),
+            (None, Usage::FfiReturn) => format!("*mut {unsized_ty}"),
+            (None, Usage::Return) if unsized_ty == "str" => "String".into(),
+            (None, Usage::Return) => format!("Vec<{element}>"),
+            (None, Usage::Field) => {
+                self.errors
+                    .push_error("Owned slices can't be struct fields in Rust".into());
+                "()".into()
+            }
+        }
+    }
+
+    /// The type of the elements of a slice, as the C ABI passes them
+    fn gen_slice_element(&self, slice: &hir::Slice) -> &'static str {
+        match slice {
+            hir::Slice::Str(_, StringEncoding::UnvalidatedUtf16) => "u16",
+            hir::Slice::Str(..) => "u8",
+            hir::Slice::Primitive(_, prim) => self.formatter.fmt_primitive(*prim),
+            hir::Slice::Strs(_) => "&[u8]",
+            _ => unreachable!("unknown AST/HIR variant"),
+        }
+    }
+
+    /// A named type along with its lifetimes
+    fn gen_path(
+        &self,
+        id: TypeId,
+        lifetimes: impl ExactSizeIterator<Item = MaybeStatic<Lifetime>>,
+        names: &LifetimeNames,
+    ) -> String {
+        let lifetimes = lifetimes.map(|lt| names.fmt(lt)).collect::<Vec<_>>();
+        format!(
+            "{}{}",
+            self.formatter.fmt_type_name(id),
+            fmt_generics(&lifetimes)
+        )
+    }
+
+    /// Generates the table of the API and the functions hosts set it with
+    fn gen_api(&self, info: &ApiInfo, layout: &ApiLayout) -> String {
+        let ids = self
+            .tcx
+            .all_types()
+            .map(|(id, ty)| (ty.name().as_str(), (id, ty.attrs().disable)))
+            .collect::<HashMap<_, _>>();
+
+        // Types the bindings leave out only have a pointer in their place
+        let mut types = Vec::new();
+        for ty in &layout.types {
+            let table = match ids.get(ty.name.as_str()) {
+                Some((_, false)) if !ty.retired => Some(ApiTypeTable {
+                    slots: ty
+                        .slots
+                        .iter()
+                        .map(|slot| (slot.name.clone(), slot.retired))
+                        .collect(),
+                }),
+                _ => None,
+            };
+            types.push((ty.name.clone(), table));
+        }
+
+        #[derive(Template)]
+        #[template(path = "rust/api.rs.jinja", escape = "none")]
+        struct ApiTemplate<'a> {
+            apiname: &'a str,
+            refresh_api_fn: &'a str,
+            get_api_fn: &'a str,
+            version: u32,
+            hash: u64,
+            append_only: bool,
+            catches_panics: bool,
+            types: Vec<(String, Option<ApiTypeTable>)>,
+        }
+
+        ApiTemplate {
+            catches_panics: self.tcx.catches_panics(),
+            apiname: info.apiname,
+            refresh_api_fn: info.refresh_api_fn,
+            get_api_fn: info.get_api_fn,
+            version: API_LAYOUT_VERSION,
+            hash: layout.hash(),
+            append_only: layout.append_only,
+            types,
+        }
+        .render()
+        .unwrap()
+    }
+}
+
+/// The names of the lifetimes of a type definition
+fn fmt_def_lifetimes(env: &LifetimeEnv) -> Vec<String> {
+    env.all_lifetimes()
+        .map(|lt| env.fmt_lifetime(lt).into_owned())
+        .collect()
+}
+
+/// Generic parameters or arguments, if there are any
+fn fmt_generics(generics: &[String]) -> String {
+    if generics.is_empty() {
+        String::new()
+    } else {
+        format!("<{}>", generics.join(", "))
+    }
+}
+
+/// The names lifetimes are written with where a type is used
+#[derive(Default)]
+struct LifetimeNames(HashMap<Lifetime, String>);
+
+impl LifetimeNames {
+    fn of_def(env: &LifetimeEnv) -> Self {
+        Self(
+            env.all_lifetimes()
+                .map(|lt| (lt, env.fmt_lifetime(lt).into_owned()))
+                .collect(),
+        )
+    }
+
+    fn fmt(&self, lifetime: MaybeStatic<Lifetime>) -> String {
+        match lifetime {
+            MaybeStatic::Static => "'static".into(),
+            MaybeStatic::NonStatic(lt) => match self.0.get(&lt) {
+                Some(name) => format!("'{name}"),
+                None => "'_".into(),
+            },
+        }
+    }
+}
+
+/// The lifetimes of a method, which are named after those of the `impl` block the
+/// method is in where its `self` uses them
+struct MethodLifetimes {
+    names: LifetimeNames,
+    /// The lifetimes the method declares itself
+    generics: Vec<String>,
+    /// The bounds between the lifetimes, for the `where` clause
+    bounds: Vec<String>,
+    /// Every lifetime the method uses, in order
+    order: Vec<Lifetime>,
+}
+
+impl MethodLifetimes {
+    fn new(method: &hir::Method, impl_lifetimes: &[String]) -> Self {
+        let env = &method.lifetime_env;
+        let mut names = HashMap::new();
+        let mut bounds = Vec::new();
+
+        let self_lifetimes = match method.param_self.as_ref().map(|param| &param.ty) {
+            Some(SelfType::Opaque(path)) => Some(&path.lifetimes),
+            Some(SelfType::Struct(path)) => Some(&path.lifetimes),
+            _ => None,
+        };
+        let self_lifetimes = self_lifetimes
+            .into_iter()
+            .flat_map(|lifetimes| lifetimes.lifetimes());
+        for (lifetime, impl_name) in self_lifetimes.zip(impl_lifetimes) {
+            match lifetime {
+                MaybeStatic::NonStatic(lt) => match names.get(&lt) {
+                    None => {
+                        names.insert(lt, impl_name.clone());
+                    }
+                    // `self` uses the same lifetime twice, so the two are equal
+                    Some(name) => {
+                        bounds.push(format!("'{impl_name}: '{name}"));
+                        bounds.push(format!("'{name}: '{impl_name}"));
+                    }
+                },
+                MaybeStatic::Static => bounds.push(format!("'{impl_name}: 'static")),
+            }
+        }
+
+        let mut generics = Vec::new();
+        for lt in env.all_lifetimes() {
+            if names.contains_key(&lt) {
+                continue;
+            }
+            let mut name = env.fmt_lifetime(lt).into_owned();
+            while impl_lifetimes.contains(&name) || names.values().any(|n| n == &name) {
+                name.push('_');
+            }
+            names.insert(lt, name.clone());
+            generics.push(format!("'{name}"));
+        }
+
+        for lt in env.all_lifetimes() {
+            for shorter in env.all_shorter_lifetimes(lt).skip(1) {
+                let bound = format!("'{}: '{}", names[&lt], names[&shorter]);
+                if names[&lt] != names[&shorter] && !bounds.contains(&bound) {
+                    bounds.push(bound);
+                }
+            }
+        }
+
+        Self {
+            names: LifetimeNames(names),
+            generics,
+            bounds,
+            order: env.all_lifetimes().collect(),
+        }
+    }
+
+    /// All lifetimes, for the declaration of the function the method calls
+    fn all(&self) -> Vec<String> {
+        let mut all: Vec<String> = Vec::new();
+        for lt in &self.order {
+            let name = self.names.fmt(MaybeStatic::NonStatic(*lt));
+            if !all.contains(&name) {
+                all.push(name);
+            }
+        }
+        all
+    }
+}
+
+#[derive(Template)]
+#[template(path = "rust/type.rs.jinja", escape = "none")]
+struct TypeTemplate<'a> {
+    def: String,
+    impls: Vec<ImplBlock>,
+    ffi_fns: Vec<FfiFn>,
+    native_lib: &'a str,
+    /// The function returning the API table, if the library is called through one
+    api: Option<&'a str>,
+}
+
+struct ImplBlock {
+    header: String,
+    items: Vec<String>,
+}
+
+/// A function of the C ABI
+struct FfiFn {
+    name: String,
+    generics: String,
+    params: String,
+    /// The names of the parameters
+    args: String,
+    returns: String,
+    /// The slot of the API table it's called through, if there is one
+    slot: String,
+}
+
+#[derive(Template)]
+#[template(path = "rust/method.rs.jinja", escape = "none")]
+struct MethodTemplate {
+    docs: String,
+    name: String,
+    generics: String,
+    params: String,
+    returns: String,
+    bounds: Vec<String>,
+    /// The statements of the body, which is `unsafe`
+    body: Vec<String>,
+}
+
+struct FieldInfo {
+    docs: String,
+    name: String,
+    ty: String,
+}
+
+struct EnumVariantInfo {
+    docs: String,
+    name: String,
+    discriminant: Option<isize>,
+    fields: Vec<FieldInfo>,
+}
+
+/// The slots of the table of a type, with whether each one is retired
+struct ApiTypeTable {
+    slots: Vec<(String, bool)>,
+}
+
+// Helpers used in templates (Askama has restrictions on Rust syntax)
+
+/// Format docs as a documentation comment, including the trailing newline
+fn rustdoc(docs: &str) -> String {
+    let mut out = String::new();
+    for line in docs.lines() {
+        if line.is_empty() {
+            out.push_str("///\n");
+        } else {
+            out.push_str("/// ");
+            out.push_str(line);
+            out.push('\n');
+        }
+    }
+    out
+}
+
+/// Indent every line by four spaces, leaving blank lines empty
+fn indent(text: &str) -> String {
+    text.split('\n')
+        .map(|line| {
+            if line.is_empty() {
+                String::new()
+            } else {
+                format!("    {line}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_catch_panics() {
+        test_file! {
+            #[diplomat::bridge]
+            mod ffi {
+                pub enum MyError {
+                    Panicked,
+                    Other,
+                }
+
+                #[diplomat::opaque]
+                pub struct Thing;
+
+                #[diplomat::catch_panics]
+                impl Thing {
+                    pub fn might_panic(x: u32) -> u32 {
+                        unimplemented!()
+                    }
+                    pub fn might_panic_unit(&mut self) {}
+                    pub fn might_fail(x: u32) -> Result<u32, MyError> {
+                        unimplemented!()
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_api_table() {
+        test_file! {
+            api
+
+            #[diplomat::bridge]
+            mod ffi {
+                pub enum Unit {
+                    Meters,
+                    Feet,
+                }
+
+                #[diplomat::opaque]
+                pub struct Sensor;
+
+                impl Sensor {
+                    pub fn new(unit: Unit) -> Box<Sensor> {
+                        unimplemented!()
+                    }
+                    pub fn read(&self) -> f64 {
+                        unimplemented!()
+                    }
+                    pub fn describe(&self, write: &mut DiplomatWriteable) {}
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,79 @@
+/// A macro to test that the rust files match the output from diplomat. It checks the output
+/// against an [insta] snapshot, leaving out the runtime unless the bindings use an API table.
+///
+/// # Usage
+/// ```
+/// test_file! {
+///     #[diplomat::bridge]
+///     mod ffi {
+///         enum MyEnum {
+///             A, B, C
+///         }
+///     }
+/// }
+/// ```
+///
+/// Starting with `api`, the bindings call the library through the table of an API, and the
+/// runtime is checked as well:
+/// ```
+/// test_file! {
+///     api
+///
+///     #[diplomat::bridge]
+///     mod ffi {
+///         #[diplomat::opaque]
+///         struct Opaque;
+///     }
+/// }
+/// ```
+macro_rules! test_file {
+    (api $($file:tt)*) => {
+        let api_info = crate::ApiInfo {
+            apiname: "TestApi",
+            refresh_api_fn: "refresh_test_api",
+            get_api_fn: "get_test_api",
+            additional_includes: &[],
+            layout_file: None,
+            loader: false,
+        };
+        test_file!(@api Some(&api_info); $($file)*);
+    };
+    (@api $api_info:expr; $($file:tt)*) => {
+        let parsed: syn::File = syn::parse_quote! { $($file)* };
+        let custom_types = diplomat_core::ast::File::from(&parsed);
+        let env = custom_types.all_types();
+
+        let registry = crate::backend::Registry::new();
+        let backend = registry.get("rust").unwrap();
+        let tcx = diplomat_core::hir::TypeContext::from_ast(&env, backend.attr_validator())
+            .unwrap_or_else(|e| panic!("lowering failed: {e:?}"));
+        let api_info: Option<&crate::ApiInfo> = $api_info;
+        let api_layout = api_info.map(|info| crate::api_layout::load(&env, info).unwrap());
+        let config = crate::backend::Config {
+            env: &env,
+            library_config: None,
+            docs_url_gen: &Default::default(),
+            strip_prefix: None,
+            crate_name: None,
+            api_info,
+            api_layout: api_layout.as_ref(),
+        };
+
+        let mut out_texts = backend.generate(&tcx, &config).unwrap().take_files();
+
+        out_texts.retain(|k, _| k.ends_with(".rs"));
+        // The runtime only depends on the bridge when it calls the library through a table
+        if api_info.is_none() {
+            out_texts.remove("diplomat_runtime.rs");
+        }
+
+        for out in out_texts.keys() {
+            insta::with_settings!({ snapshot_suffix => out.clone() }, {
+                insta::assert_snapshot!(out_texts.get(out).unwrap())
+            });
+        }
+    };
+    ($($file:tt)*) => {
+        test_file!(@api None; $($file)*);
+    };
+}
@@ -0,0 +1,19 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct LibraryConfig {
+    /// The name of the Swift module holding the wrapper types. The C headers
+    /// are exposed as the module `{module}FFI`.
+    pub module: String,
+    /// The name of the native library the C module links against
+    pub native_lib: String,
+}
+
+impl Default for LibraryConfig {
+    fn default() -> LibraryConfig {
+        LibraryConfig {
+            module: "Diplomat".to_owned(),
+            native_lib: "rust".to_owned(),
+        }
+    }
+}
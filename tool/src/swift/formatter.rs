@@ -0,0 +1,336 @@
+//! This module contains functions for formatting types
+
+use crate::c2::CFormatter;
+use diplomat_core::ast::{DocsUrlGenerator, MarkdownStyle};
+use diplomat_core::hir::{self, TypeContext, TypeId};
+use heck::ToLowerCamelCase;
+use std::borrow::Cow;
+
+/// This type mediates all formatting
+///
+/// All identifiers from the HIR should go through here before being formatted
+/// into the output: This makes it easy to handle reserved words or add rename support
+///
+/// If you find yourself needing an identifier formatted in a context not yet available here, please add a new method
+pub(super) struct SwiftFormatter<'tcx> {
+    c: CFormatter<'tcx>,
+    docs_url_generator: &'tcx DocsUrlGenerator,
+    strip_prefix: Option<String>,
+    module: String,
+}
+
+/// Swift's keywords, which have to be escaped with backticks
+const KEYWORDS: &[&str] = &[
+    "Any",
+    "Self",
+    "as",
+    "associatedtype",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "continue",
+    "default",
+    "defer",
+    "deinit",
+    "do",
+    "else",
+    "enum",
+    "extension",
+    "fallthrough",
+    "false",
+    "fileprivate",
+    "for",
+    "func",
+    "guard",
+    "if",
+    "import",
+    "in",
+    "init",
+    "inout",
+    "internal",
+    "is",
+    "let",
+    "nil",
+    "open",
+    "operator",
+    "private",
+    "protocol",
+    "public",
+    "repeat",
+    "rethrows",
+    "return",
+    "self",
+    "static",
+    "struct",
+    "subscript",
+    "super",
+    "switch",
+    "throw",
+    "throws",
+    "true",
+    "try",
+    "typealias",
+    "var",
+    "where",
+    "while",
+];
+/// Names used by the generated code itself
+const RESERVED_NAMES: &[&str] = &[
+    "keep",
+    "result",
+    "writeable",
+    "edges",
+    "handle",
+    "native",
+    "owned",
+    "newValue",
+];
+
+impl<'tcx> SwiftFormatter<'tcx> {
+    pub fn new(
+        tcx: &'tcx TypeContext,
+        docs_url_generator: &'tcx DocsUrlGenerator,
+        strip_prefix: Option<String>,
+        module: String,
+    ) -> Self {
+        Self {
+            c: CFormatter::new(tcx),
+            docs_url_generator,
+            strip_prefix,
+            module,
+        }
+    }
+
+    /// Format docs as the contents of a documentation comment
+    pub fn fmt_docs(&self, docs: &hir::Docs) -> String {
+        docs.to_markdown(self.docs_url_generator, MarkdownStyle::Normal)
+            .trim()
+            .replace(" \n", "\n")
+            .replace(
+                &format!("`{}", self.strip_prefix.as_deref().unwrap_or("")),
+                "`",
+            )
+    }
+
+    /// The module holding the C declarations
+    pub fn fmt_ffi_module(&self) -> String {
+        format!("{}FFI", self.module)
+    }
+
+    /// The path of the file holding a type
+    pub fn fmt_file_path(&self, id: TypeId) -> String {
+        self.fmt_base_file_path(&format!("{}.swift", self.fmt_type_name(id)))
+    }
+
+    /// The path of a file in the Swift module
+    pub fn fmt_base_file_path(&self, file_name: &str) -> String {
+        format!("{}/{file_name}", self.module)
+    }
+
+    /// The path of a file in the C module
+    pub fn fmt_ffi_file_path(&self, file_name: &str) -> String {
+        format!("{}/{file_name}", self.fmt_ffi_module())
+    }
+
+    pub fn fmt_destructor_name(&self, id: TypeId) -> String {
+        self.c.fmt_dtor_name(id)
+    }
+
+    pub fn fmt_c_method_name<'a>(&self, ty: TypeId, method: &'a hir::Method) -> Cow<'a, str> {
+        self.c.fmt_method_name(ty, method, true).into()
+    }
+
+    /// Resolve and format a named type for use in code
+    pub fn fmt_type_name(&self, id: TypeId) -> Cow<'tcx, str> {
+        let resolved = self.c.tcx().resolve_type(id);
+
+        let candidate: Cow<str> = if let Some(strip_prefix) = self.strip_prefix.as_ref() {
+            resolved
+                .name()
+                .as_str()
+                .strip_prefix(strip_prefix)
+                .unwrap_or(resolved.name().as_str())
+                .into()
+        } else {
+            resolved.name().as_str().into()
+        };
+
+        resolved.attrs().rename.apply(candidate)
+    }
+
+    /// Resolve and format a named type for use in diagnostics
+    /// (don't apply rename rules and such)
+    pub fn fmt_type_name_diagnostics(&self, id: TypeId) -> Cow<'tcx, str> {
+        self.c.fmt_type_name_diagnostics(id)
+    }
+
+    /// Format the C type of a struct or enum, qualified since it usually
+    /// shares its name with the Swift type
+    pub fn fmt_ffi_type_name(&self, id: TypeId) -> String {
+        format!("{}.{}", self.fmt_ffi_module(), self.c.fmt_type_name(id))
+    }
+
+    /// Format an enum case, which Swift spells in lowerCamelCase
+    pub fn fmt_enum_variant(&self, variant: &'tcx hir::EnumVariant) -> String {
+        let name = variant.attrs.rename.apply(variant.name.as_str().into());
+        escape(name.to_lower_camel_case())
+    }
+
+    /// Format the name of the C union member holding a variant's payload
+    pub fn fmt_enum_payload_name<'a>(&self, variant: &'a hir::EnumVariant) -> Cow<'a, str> {
+        self.c.fmt_enum_payload_name(variant)
+    }
+
+    /// Format a field name
+    pub fn fmt_field_name(&self, ident: &str) -> String {
+        escape(ident.to_lower_camel_case())
+    }
+
+    /// Format the name of a field of a C struct
+    pub fn fmt_ffi_field_name<'a>(&self, ident: &'a str) -> Cow<'a, str> {
+        self.c.fmt_param_name(ident)
+    }
+
+    /// Format a parameter name, avoiding the names of locals in generated methods
+    pub fn fmt_param_name(&self, ident: &str) -> String {
+        let name = ident.to_lower_camel_case();
+        if RESERVED_NAMES.contains(&name.as_str()) {
+            format!("{name}_")
+        } else {
+            escape(name)
+        }
+    }
+
+    /// Format a method
+    pub fn fmt_method_name(&self, method: &hir::Method) -> String {
+        escape(
+            method
+                .attrs
+                .rename
+                .apply(method.name.as_str().into())
+                .to_lower_camel_case(),
+        )
+    }
+
+    pub fn fmt_constructor_name(&self, name: &Option<String>, method: &hir::Method) -> String {
+        escape(
+            method
+                .attrs
+                .rename
+                .apply(name.as_deref().unwrap_or(method.name.as_str()).into())
+                .to_lower_camel_case(),
+        )
+    }
+
+    pub fn fmt_accessor_name(&self, name: &Option<String>, method: &hir::Method) -> String {
+        escape(
+            method
+                .attrs
+                .rename
+                .apply(name.as_deref().unwrap_or(method.name.as_str()).into())
+                .to_lower_camel_case(),
+        )
+    }
+
+    /// Format the name of a const, which Swift spells in lowerCamelCase
+    pub fn fmt_const_name(&self, def: &hir::ConstDef) -> String {
+        escape(
+            def.attrs
+                .rename
+                .apply(def.name.as_str().into())
+                .to_lower_camel_case(),
+        )
+    }
+
+    /// Format the value of a const as a Swift literal
+    ///
+    /// Integer literals can initialize any numeric type, so numbers need no suffixes.
+    pub fn fmt_const_value(&self, def: &hir::ConstDef) -> String {
+        match def.value {
+            hir::ConstValue::Bool(b) => b.to_string(),
+            hir::ConstValue::Str(ref s) => self.fmt_string_literal(s),
+            hir::ConstValue::Int(ref n) | hir::ConstValue::Float(ref n) => n.clone(),
+            _ => unreachable!("unknown AST/HIR variant"),
+        }
+    }
+
+    /// The Swift type of a const
+    pub fn fmt_const_type(&self, def: &hir::ConstDef) -> &'static str {
+        match def.ty {
+            hir::ConstType::Primitive(p) => self.fmt_primitive_as_swift(p),
+            hir::ConstType::Str => self.fmt_string(),
+            _ => unreachable!("unknown AST/HIR variant"),
+        }
+    }
+
+    /// Format a string as a Swift string literal
+    pub fn fmt_string_literal(&self, s: &str) -> String {
+        let mut out = String::with_capacity(s.len() + 2);
+        out.push('"');
+        for c in s.chars() {
+            match c {
+                '"' | '\\' => {
+                    out.push('\\');
+                    out.push(c);
+                }
+                '\n' => out.push_str("\\n"),
+                '\r' => out.push_str("\\r"),
+                '\t' => out.push_str("\\t"),
+                c if c.is_control() => out.push_str(&format!("\\u{{{:x}}}", c as u32)),
+                c => out.push(c),
+            }
+        }
+        out.push('"');
+        out
+    }
+
+    /// The Swift type of a primitive
+    pub fn fmt_primitive_as_swift(&self, prim: hir::PrimitiveType) -> &'static str {
+        use diplomat_core::hir::{FloatType, IntSizeType, IntType, PrimitiveType};
+        match prim {
+            PrimitiveType::Bool => "Bool",
+            PrimitiveType::Char => "Unicode.Scalar",
+            PrimitiveType::Int(IntType::I8) => "Int8",
+            PrimitiveType::Int(IntType::U8) | PrimitiveType::Byte => "UInt8",
+            PrimitiveType::Int(IntType::I16) => "Int16",
+            PrimitiveType::Int(IntType::U16) => "UInt16",
+            PrimitiveType::Int(IntType::I32) => "Int32",
+            PrimitiveType::Int(IntType::U32) => "UInt32",
+            PrimitiveType::Int(IntType::I64) => "Int64",
+            PrimitiveType::Int(IntType::U64) => "UInt64",
+            PrimitiveType::IntSize(IntSizeType::Isize) => "Int",
+            PrimitiveType::IntSize(IntSizeType::Usize) => "UInt",
+            PrimitiveType::Float(FloatType::F32) => "Float",
+            PrimitiveType::Float(FloatType::F64) => "Double",
+            PrimitiveType::Int128(_) => panic!("i128 not supported in Swift"),
+        }
+    }
+
+    /// The type Swift imports a primitive's C type as
+    pub fn fmt_primitive_as_ffi(&self, prim: hir::PrimitiveType) -> &'static str {
+        use diplomat_core::hir::{IntSizeType, PrimitiveType};
+        match prim {
+            PrimitiveType::Char => "UInt32",
+            // Both `intptr_t` and `size_t` are imported as `Int`
+            PrimitiveType::IntSize(IntSizeType::Usize) => "Int",
+            _ => self.fmt_primitive_as_swift(prim),
+        }
+    }
+
+    pub fn fmt_string(&self) -> &'static str {
+        "String"
+    }
+
+    pub fn fmt_optional(&self, ty: &str) -> String {
+        format!("{ty}?")
+    }
+}
+
+fn escape(name: String) -> String {
+    if KEYWORDS.contains(&name.as_str()) {
+        format!("`{name}`")
+    } else {
+        name
+    }
+}
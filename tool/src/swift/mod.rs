@@ -0,0 +1,1318 @@
+use crate::common::{ErrorStore, FileMap};
+use askama::Template;
+use config::LibraryConfig;
+use diplomat_core::ast::DocsUrlGenerator;
+use diplomat_core::hir::TypeContext;
+use diplomat_core::hir::{
+    self, OpaqueOwner, ReturnType, SelfType, SpecialMethod, StructPathLike, SuccessType,
+    TyPosition, Type, TypeDef, TypeId,
+};
+use formatter::SwiftFormatter;
+use heck::ToUpperCamelCase;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Display;
+
+#[cfg(test)]
+#[macro_use]
+mod test_util;
+
+pub mod config;
+mod formatter;
+
+/// The header declaring the parts of the Rust runtime the C headers leave out
+const RUNTIME_HEADER: &str = "diplomat_swift_runtime.h";
+
+/// Run file generation
+///
+/// `c_files` are the headers generated by the C backend, which become the
+/// `{module}FFI` module the Swift wrappers are built on.
+pub fn run<'cx>(
+    tcx: &'cx TypeContext,
+    c_files: HashMap<String, String>,
+    library_config: LibraryConfig,
+    docs_url_generator: &'cx DocsUrlGenerator,
+    strip_prefix: Option<String>,
+) -> Result<FileMap, Vec<(impl Display + 'cx, String)>> {
+    let formatter =
+        SwiftFormatter::new(tcx, docs_url_generator, strip_prefix, library_config.module);
+    let ffi_module = formatter.fmt_ffi_module();
+
+    let files = FileMap::default();
+    let errors = ErrorStore::default();
+
+    // The module map lists the headers declaring functions, which include the
+    // declaration-only `.d.h` headers themselves
+    let mut headers = c_files
+        .keys()
+        .filter(|name| name.ends_with(".h") && !name.ends_with(".d.h"))
+        .cloned()
+        .collect::<Vec<_>>();
+    headers.sort();
+    headers.insert(0, RUNTIME_HEADER.into());
+    for (name, contents) in c_files {
+        files.add_file(formatter.fmt_ffi_file_path(&name), contents);
+    }
+    files.add_file(
+        formatter.fmt_ffi_file_path(RUNTIME_HEADER),
+        include_str!("../../templates/swift/diplomat_swift_runtime.h").into(),
+    );
+
+    #[derive(Template)]
+    #[template(path = "swift/module.modulemap.jinja", escape = "none")]
+    struct ModuleMapTemplate<'a> {
+        ffi_module: &'a str,
+        headers: Vec<String>,
+        native_lib: &'a str,
+    }
+
+    files.add_file(
+        formatter.fmt_ffi_file_path("module.modulemap"),
+        ModuleMapTemplate {
+            ffi_module: &ffi_module,
+            headers,
+            native_lib: &library_config.native_lib,
+        }
+        .render()
+        .unwrap(),
+    );
+
+    let mut tgcx = TyGenContext {
+        tcx,
+        errors: &errors,
+        formatter: &formatter,
+    };
+
+    for (id, ty) in tcx.all_types() {
+        if ty.attrs().disable {
+            continue;
+        }
+
+        let body = tgcx.gen(id);
+        files.add_file(
+            formatter.fmt_file_path(id),
+            FileTemplate {
+                ffi_module: &ffi_module,
+                body,
+            }
+            .render()
+            .unwrap(),
+        );
+    }
+
+    for (_, trt) in tcx.all_traits() {
+        if !trt.attrs.disable {
+            let _guard = errors.set_context_ty(trt.name.as_str().into());
+            errors.push_error(format!(
+                "Traits are not supported in Swift, found {}",
+                trt.name
+            ));
+        }
+    }
+
+    if !tcx.consts().is_empty() {
+        files.add_file(
+            formatter.fmt_base_file_path("Consts.swift"),
+            FileTemplate {
+                ffi_module: &ffi_module,
+                body: tgcx.gen_consts(tcx.consts(), false).join("\n\n"),
+            }
+            .render()
+            .unwrap(),
+        );
+    }
+
+    #[derive(Template)]
+    #[template(path = "swift/Runtime.swift.jinja", escape = "none")]
+    struct RuntimeTemplate<'a> {
+        ffi_module: &'a str,
+        catches_panics: bool,
+    }
+
+    files.add_file(
+        formatter.fmt_base_file_path("DiplomatRuntime.swift"),
+        RuntimeTemplate {
+            ffi_module: &ffi_module,
+            catches_panics: tcx.catches_panics(),
+        }
+        .render()
+        .unwrap(),
+    );
+
+    let errors = errors.take_all();
+    if !errors.is_empty() {
+        Err(errors)
+    } else {
+        Ok(files)
+    }
+}
+
+#[derive(Template)]
+#[template(path = "swift/file.swift.jinja", escape = "none")]
+struct FileTemplate<'a> {
+    ffi_module: &'a str,
+    body: String,
+}
+
+struct TyGenContext<'a, 'cx> {
+    tcx: &'cx TypeContext,
+    formatter: &'a SwiftFormatter<'cx>,
+    errors: &'a ErrorStore<'cx, String>,
+}
+
+impl<'a, 'cx> TyGenContext<'a, 'cx> {
+    /// Generates the Swift code of a type
+    fn gen(&mut self, id: TypeId) -> String {
+        let ty = self.tcx.resolve_type(id);
+
+        let _guard = self.errors.set_context_ty(ty.name().as_str().into());
+
+        let name = self.formatter.fmt_type_name(id);
+        match ty {
+            TypeDef::Enum(e) if e.is_data_carrying() => self.gen_tagged_union(e, id, &name),
+            TypeDef::Enum(e) => self.gen_enum(e, id, &name),
+            TypeDef::Opaque(o) => self.gen_opaque_def(o, id, &name),
+            TypeDef::Struct(s) => self.gen_struct_def(s, id, &name),
+            TypeDef::OutStruct(s) => self.gen_struct_def(s, id, &name),
+            _ => unreachable!("unknown AST/HIR variant"),
+        }
+    }
+
+    /// Generates consts, which are `static` members of types or globals of the module
+    fn gen_consts(&self, consts: &[hir::ConstDef], is_static: bool) -> Vec<String> {
+        let modifier = if is_static { "static " } else { "" };
+        consts
+            .iter()
+            .map(|def| {
+                format!(
+                    "{}public {modifier}let {}: {} = {}",
+                    swiftdoc(&self.formatter.fmt_docs(&def.docs)),
+                    self.formatter.fmt_const_name(def),
+                    self.formatter.fmt_const_type(def),
+                    self.formatter.fmt_const_value(def)
+                )
+            })
+            .collect()
+    }
+
+    fn gen_enum(&mut self, ty: &'cx hir::EnumDef, id: TypeId, type_name: &str) -> String {
+        let variants = ty
+            .variants
+            .iter()
+            .map(|variant| EnumVariantInfo {
+                name: self.formatter.fmt_enum_variant(variant),
+                discriminant: variant.discriminant,
+                docs: self.formatter.fmt_docs(&variant.docs),
+            })
+            .collect::<Vec<_>>();
+
+        let mut members = self.gen_members(id, &ty.methods, type_name, true);
+        members.add_consts(self.gen_consts(&ty.consts, true));
+        // C enums are `int`s
+        let mut conformances = vec!["Int32".to_owned()];
+        conformances.extend(members.conformances);
+
+        #[derive(Template)]
+        #[template(path = "swift/enum.swift.jinja", escape = "none")]
+        struct ImplTemplate<'a> {
+            type_name: &'a str,
+            docs: String,
+            conformances: Vec<String>,
+            ffi_name: String,
+            variants: Vec<EnumVariantInfo>,
+            members: Vec<String>,
+        }
+
+        ImplTemplate {
+            type_name,
+            docs: self.formatter.fmt_docs(&ty.docs),
+            conformances,
+            ffi_name: self.formatter.fmt_ffi_type_name(id),
+            variants,
+            members: members.members,
+        }
+        .render()
+        .unwrap()
+    }
+
+    /// Data-carrying enums become enums with associated values, passed as a
+    /// struct holding the tag and a union of the variant payloads.
+    fn gen_tagged_union(&mut self, ty: &'cx hir::EnumDef, id: TypeId, type_name: &str) -> String {
+        let variants = ty
+            .variants
+            .iter()
+            .map(|variant| {
+                let payload_name = self.formatter.fmt_enum_payload_name(variant);
+                let mut bindings = Vec::new();
+                let fields = variant
+                    .fields
+                    .iter()
+                    .map(|field| {
+                        if field.ty.lifetimes().next().is_some() {
+                            self.errors.push_error(format!(
+                                "Borrowed data in enum payloads is not supported in Swift: {}::{}",
+                                variant.name, field.name
+                            ));
+                        }
+                        let binding = self.formatter.fmt_param_name(field.name.as_str());
+                        let ffi_field = format!(
+                            "native.{payload_name}.{}",
+                            self.formatter.fmt_ffi_field_name(field.name.as_str())
+                        );
+                        let info = FieldInfo {
+                            name: self.formatter.fmt_field_name(field.name.as_str()),
+                            docs: String::new(),
+                            swift_type: self.gen_type_name(&field.ty),
+                            swift_to_native: self
+                                .gen_field_to_native(&field.ty, &binding, &ffi_field),
+                            native_to_swift: self
+                                .gen_native_to_swift(&field.ty, &ffi_field, "edges"),
+                        };
+                        bindings.push(binding);
+                        info
+                    })
+                    .collect();
+
+                TaggedVariantInfo {
+                    name: self.formatter.fmt_enum_variant(variant),
+                    docs: self.formatter.fmt_docs(&variant.docs),
+                    discriminant: variant.discriminant,
+                    bindings,
+                    fields,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let mut members = self.gen_members(id, &ty.methods, type_name, true);
+        members.add_consts(self.gen_consts(&ty.consts, true));
+
+        #[derive(Template)]
+        #[template(path = "swift/tagged_union.swift.jinja", escape = "none")]
+        struct ImplTemplate<'a> {
+            type_name: &'a str,
+            docs: String,
+            conformances: Vec<String>,
+            ffi_name: String,
+            variants: Vec<TaggedVariantInfo>,
+            members: Vec<String>,
+        }
+
+        ImplTemplate {
+            type_name,
+            docs: self.formatter.fmt_docs(&ty.docs),
+            conformances: members.conformances,
+            ffi_name: self.formatter.fmt_ffi_type_name(id),
+            variants,
+            members: members.members,
+        }
+        .render()
+        .unwrap()
+    }
+
+    fn gen_opaque_def(&mut self, ty: &'cx hir::OpaqueDef, id: TypeId, type_name: &str) -> String {
+        let mut members = self.gen_members(id, &ty.methods, type_name, false);
+        members.add_consts(self.gen_consts(&ty.consts, true));
+
+        #[derive(Template)]
+        #[template(path = "swift/opaque.swift.jinja", escape = "none")]
+        struct ImplTemplate<'a> {
+            type_name: &'a str,
+            docs: String,
+            conformances: Vec<String>,
+            destructor: String,
+            members: Vec<String>,
+        }
+
+        ImplTemplate {
+            type_name,
+            docs: self.formatter.fmt_docs(&ty.docs),
+            conformances: members.conformances,
+            destructor: self.formatter.fmt_destructor_name(id),
+            members: members.members,
+        }
+        .render()
+        .unwrap()
+    }
+
+    fn gen_struct_def<P: TyPosition>(
+        &mut self,
+        ty: &'cx hir::StructDef<P>,
+        id: TypeId,
+        type_name: &str,
+    ) -> String {
+        let fields = ty
+            .fields
+            .iter()
+            .map(|field| {
+                let name = self.formatter.fmt_field_name(field.name.as_str());
+                let ffi_field = format!(
+                    "native.{}",
+                    self.formatter.fmt_ffi_field_name(field.name.as_str())
+                );
+                FieldInfo {
+                    docs: self.formatter.fmt_docs(&field.docs),
+                    swift_type: self.gen_type_name(&field.ty),
+                    swift_to_native: self.gen_field_to_native(
+                        &field.ty,
+                        &format!("self.{name}"),
+                        &ffi_field,
+                    ),
+                    native_to_swift: self.gen_native_to_swift(&field.ty, &ffi_field, "edges"),
+                    name,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let mut members = self.gen_members(id, &ty.methods, type_name, true);
+        members.add_consts(self.gen_consts(&ty.consts, true));
+
+        #[derive(Template)]
+        #[template(path = "swift/struct.swift.jinja", escape = "none")]
+        struct ImplTemplate<'a> {
+            type_name: &'a str,
+            docs: String,
+            conformances: Vec<String>,
+            ffi_name: String,
+            fields: Vec<FieldInfo>,
+            members: Vec<String>,
+        }
+
+        ImplTemplate {
+            type_name,
+            docs: self.formatter.fmt_docs(&ty.docs),
+            conformances: members.conformances,
+            ffi_name: self.formatter.fmt_ffi_type_name(id),
+            fields,
+            members: members.members,
+        }
+        .render()
+        .unwrap()
+    }
+
+    /// Generates the members of a type, and the protocols its special methods
+    /// conform it to
+    fn gen_members(
+        &mut self,
+        id: TypeId,
+        methods: &'cx [hir::Method],
+        type_name: &str,
+        is_value_type: bool,
+    ) -> MembersInfo {
+        let mut out = MembersInfo::default();
+        let mut properties = BTreeMap::<String, PropertyInfo>::new();
+
+        for method in methods {
+            let info = match self.gen_method_info(id, method) {
+                Some(info) => info,
+                None => continue,
+            };
+            let static_ = if info.is_static { "static " } else { "" };
+            let throws = if info.throws { " throws" } else { "" };
+            let returns = if info.return_ty == "Void" {
+                String::new()
+            } else {
+                format!(" -> {}", info.return_ty)
+            };
+            let params = info.params.join(", ");
+            // Setters can't throw, so throwing ones stay methods
+            let is_throwing_setter = info.throws
+                && matches!(method.attrs.special_method, Some(SpecialMethod::Setter(_)));
+            // The standard protocols are qualified, since types may share their names
+            let decl = match method.attrs.special_method {
+                Some(SpecialMethod::Getter(ref name) | SpecialMethod::Setter(ref name))
+                    if !is_throwing_setter =>
+                {
+                    let name = self.formatter.fmt_accessor_name(name, method);
+                    let property = properties.entry(name.clone()).or_insert(PropertyInfo {
+                        name,
+                        is_static: info.is_static,
+                        getter: None,
+                        setter: None,
+                    });
+                    if let Some(SpecialMethod::Getter(_)) = method.attrs.special_method {
+                        property.getter = Some(info);
+                    } else {
+                        property.setter = Some(info);
+                    }
+                    continue;
+                }
+                Some(SpecialMethod::Constructor) => {
+                    let convenience = if is_value_type { "" } else { "convenience " };
+                    let failable = if info.return_ty.ends_with('?') {
+                        "?"
+                    } else {
+                        ""
+                    };
+                    format!("public {convenience}init{failable}({params}){throws}")
+                }
+                Some(SpecialMethod::Stringifier) if !info.throws => {
+                    out.conformances
+                        .push("Swift.CustomStringConvertible".into());
+                    out.members.push(
+                        PropertyTemplate {
+                            p: &PropertyGenInfo {
+                                decl: "public var description: String".into(),
+                                docs: info.docs,
+                                throws: false,
+                                getter_body: info.body,
+                                setter: None,
+                            },
+                        }
+                        .render()
+                        .unwrap(),
+                    );
+                    continue;
+                }
+                Some(SpecialMethod::Indexer) => {
+                    out.members.push(
+                        PropertyTemplate {
+                            p: &PropertyGenInfo {
+                                decl: format!("public subscript({params}){returns}"),
+                                docs: info.docs,
+                                throws: info.throws,
+                                getter_body: info.body,
+                                setter: None,
+                            },
+                        }
+                        .render()
+                        .unwrap(),
+                    );
+                    continue;
+                }
+                Some(SpecialMethod::Comparison) => {
+                    out.conformances.push("Swift.Comparable".into());
+                    // The operators can't throw
+                    let call = format!(
+                        "{}lhs.{}({}: rhs)",
+                        if info.throws { "try! " } else { "" },
+                        info.name,
+                        info.param_names.first().map(|p| p.as_str()).unwrap_or("_")
+                    );
+                    for (op, cmp) in [("<", "<"), ("==", "==")] {
+                        out.operators.push(format!(
+                            "public static func {op} (lhs: {type_name}, rhs: {type_name}) -> Bool {{\n    return {call} {cmp} 0\n}}"
+                        ));
+                    }
+                    format!(
+                        "public {static_}func {}({params}){throws}{returns}",
+                        info.name
+                    )
+                }
+                Some(SpecialMethod::Iterator) => {
+                    out.conformances.push("Swift.IteratorProtocol".into());
+                    format!(
+                        "public {static_}func {}({params}){throws}{returns}",
+                        info.name
+                    )
+                }
+                Some(SpecialMethod::Iterable) => {
+                    out.conformances.push("Swift.Sequence".into());
+                    format!(
+                        "public {static_}func {}({params}){throws}{returns}",
+                        info.name
+                    )
+                }
+                _ => format!(
+                    "public {static_}func {}({params}){throws}{returns}",
+                    info.name
+                ),
+            };
+            out.members.push(
+                MethodTemplate {
+                    m: &info,
+                    decl: &decl,
+                }
+                .render()
+                .unwrap(),
+            );
+        }
+
+        for (_, property) in properties {
+            let static_ = if property.is_static { "static " } else { "" };
+            let rendered = match property.getter {
+                Some(getter) => {
+                    let docs = if getter.docs.is_empty() {
+                        property
+                            .setter
+                            .as_ref()
+                            .map(|s| s.docs.clone())
+                            .unwrap_or_default()
+                    } else {
+                        getter.docs
+                    };
+                    PropertyTemplate {
+                        p: &PropertyGenInfo {
+                            decl: format!(
+                                "public {static_}var {}: {}",
+                                property.name, getter.return_ty
+                            ),
+                            docs,
+                            throws: getter.throws,
+                            getter_body: getter.body,
+                            setter: property.setter.map(|setter| SetterInfo {
+                                // Setters of value types are passed a copy of `self`
+                                modifiers: if is_value_type && !property.is_static {
+                                    "nonmutating "
+                                } else {
+                                    ""
+                                },
+                                param: setter.param_names.into_iter().next().unwrap_or_default(),
+                                body: setter.body,
+                            }),
+                        },
+                    }
+                    .render()
+                    .unwrap()
+                }
+                // Without a getter, setters stay methods
+                None => match property.setter {
+                    Some(setter) => {
+                        let decl = format!(
+                            "public {static_}func {}({})",
+                            setter.name,
+                            setter.params.join(", ")
+                        );
+                        MethodTemplate {
+                            m: &setter,
+                            decl: &decl,
+                        }
+                        .render()
+                        .unwrap()
+                    }
+                    None => continue,
+                },
+            };
+            out.members.push(rendered);
+        }
+
+        out.members.append(&mut out.operators);
+        out
+    }
+
+    fn gen_method_info(&mut self, id: TypeId, method: &'cx hir::Method) -> Option<MethodInfo> {
+        if method.attrs.disable {
+            return None;
+        }
+
+        let _guard = self.errors.set_context_method(
+            self.formatter.fmt_type_name_diagnostics(id),
+            method.name.as_str().into(),
+        );
+
+        let c_method_name = self.formatter.fmt_c_method_name(id, method);
+        let special_method = method.attrs.special_method.as_ref();
+
+        let mut params = Vec::new();
+        let mut param_names = Vec::new();
+        let mut args = Vec::new();
+        // Slices are converted before the call, and mutable ones copied back after it
+        let mut before = Vec::new();
+        let mut after = Vec::new();
+        // Everything the return value may borrow from
+        let mut edges = Vec::new();
+        let mut needs_keep = false;
+
+        if let Some(param_self) = method.param_self.as_ref() {
+            let arg = match param_self.ty {
+                SelfType::Opaque(_) => "handle",
+                SelfType::Struct(_) => {
+                    needs_keep = true;
+                    "toNative(keep)"
+                }
+                SelfType::Enum(ref e) if e.resolve(self.tcx).is_data_carrying() => {
+                    needs_keep = true;
+                    "toNative(keep)"
+                }
+                SelfType::Enum(_) => "toNative()",
+                _ => unreachable!("unknown AST/HIR variant"),
+            };
+            args.push(arg.to_owned());
+            edges.push("self".to_owned());
+        }
+
+        for param in method.params.iter() {
+            let param_name = self.formatter.fmt_param_name(param.name.as_str());
+            let param_ty = self.gen_type_name(&param.ty);
+
+            if let Type::Slice(slice) = param.ty {
+                // Two args on the ABI: pointer and length
+                let local = format!("{}Slice", unescape(&param_name));
+                before.push(format!(
+                    "let {local} = {}",
+                    self.gen_slice_to_native(&slice, &param_name)
+                ));
+                args.push(format!("{local}.data"));
+                args.push(format!("{local}.len"));
+                match slice {
+                    hir::Slice::Primitive(Some(borrow), _) if borrow.mutability.is_mutable() => {
+                        after.push(format!("DiplomatRuntime.copyBack({local}, &{param_name})"));
+                        params.push(format!("{param_name}: inout {param_ty}"));
+                    }
+                    _ => params.push(format!("{param_name}: {param_ty}")),
+                }
+                needs_keep |= slice.lifetime().is_some();
+            } else {
+                needs_keep |= self.needs_keep(&param.ty);
+                args.push(self.gen_swift_to_native(&param.ty, &param_name));
+                params.push(format!("{param_name}: {param_ty}"));
+            }
+
+            match param.ty {
+                Type::Primitive(_) | Type::Enum(_) => {}
+                // Optionals would be wrapped implicitly, with a warning
+                Type::Opaque(ref op) if op.is_optional() => {
+                    edges.push(format!("{param_name} as Any"))
+                }
+                _ => edges.push(param_name.clone()),
+            }
+            param_names.push(param_name);
+        }
+
+        if method.output.is_writeable() {
+            args.push("writeable.handle".into());
+        }
+
+        let borrows = method
+            .output
+            .success_type()
+            .as_type()
+            .map(|o| o.lifetimes().next().is_some())
+            .unwrap_or(false);
+        let edges = if borrows {
+            if needs_keep {
+                edges.push("keep".into());
+            }
+            format!("[{}]", edges.join(", "))
+        } else {
+            "[]".into()
+        };
+
+        let mut body = Vec::new();
+        if needs_keep {
+            body.push("let keep = DiplomatKeep()".to_owned());
+        }
+        if method.output.is_writeable() {
+            body.push("let writeable = DiplomatWriteableBuffer()".into());
+        }
+        body.extend(before);
+        let mut call = format!("{c_method_name}({})", args.join(", "));
+        if needs_keep {
+            // `keep` would otherwise be freed after its last use, before the call
+            call = format!("withExtendedLifetime(keep) {{ {call} }}");
+        }
+        if method.output.is_ffi_unit() {
+            body.push(call);
+        } else {
+            body.push(format!("let result = {call}"));
+        }
+        // Methods returning a `Result` get the panic as their error
+        let checks_panic =
+            method.attrs.catch_panics && !matches!(method.output, ReturnType::Fallible(..));
+        if checks_panic {
+            body.push("try DiplomatRuntime.checkPanic()".into());
+        }
+        body.extend(after);
+
+        let is_constructor = matches!(special_method, Some(SpecialMethod::Constructor));
+        let sink = match id {
+            _ if !is_constructor => Sink::Return,
+            TypeId::Opaque(_) => Sink::InitClass,
+            _ => Sink::InitValue,
+        };
+        body.extend(self.gen_return(&method.output, &edges, sink));
+
+        let name = match special_method {
+            Some(SpecialMethod::Constructor) => "init".into(),
+            Some(SpecialMethod::NamedConstructor(name)) => {
+                self.formatter.fmt_constructor_name(name, method)
+            }
+            Some(SpecialMethod::Getter(name)) => format!(
+                "get{}",
+                self.formatter
+                    .fmt_accessor_name(name, method)
+                    .to_upper_camel_case()
+            ),
+            Some(SpecialMethod::Setter(name)) => format!(
+                "set{}",
+                self.formatter
+                    .fmt_accessor_name(name, method)
+                    .to_upper_camel_case()
+            ),
+            Some(SpecialMethod::Iterator) => "next".into(),
+            Some(SpecialMethod::Iterable) => "makeIterator".into(),
+            Some(SpecialMethod::Indexer) => "subscript".into(),
+            Some(SpecialMethod::Stringifier | SpecialMethod::Comparison) | None => {
+                self.formatter.fmt_method_name(method)
+            }
+            Some(special) => unimplemented!("Found unknown special method type {special:?}"),
+        };
+
+        let mut docs = self.formatter.fmt_docs(&method.docs);
+        if let ReturnType::Fallible(_, e) = &method.output {
+            if !docs.is_empty() {
+                docs.push_str("\n\n");
+            }
+            match e {
+                Some(e) => docs.push_str(&format!(
+                    "- Throws: `DiplomatError` holding a `{}` on failure",
+                    self.gen_type_name(e)
+                )),
+                None => docs.push_str("- Throws: `DiplomatError` on failure"),
+            }
+        }
+        if checks_panic {
+            if !docs.is_empty() {
+                docs.push_str("\n\n");
+            }
+            docs.push_str("- Throws: `DiplomatPanic` if it panics");
+        }
+
+        Some(MethodInfo {
+            name,
+            is_static: method.param_self.is_none() && !is_constructor,
+            params,
+            param_names,
+            return_ty: self.gen_return_type_name(&method.output),
+            throws: checks_panic || matches!(method.output, ReturnType::Fallible(..)),
+            docs,
+            body,
+        })
+    }
+
+    /// Generates the statements converting the native return value of a
+    /// method, which is saved in `result` (or `writeable`), into the Swift one.
+    fn gen_return(&mut self, output: &'cx ReturnType, edges: &str, sink: Sink) -> Vec<String> {
+        let mut out = Vec::new();
+        let ok_value = match output {
+            ReturnType::Infallible(SuccessType::Unit) => None,
+            ReturnType::Infallible(SuccessType::Writeable) => {
+                Some(ReturnValue::Swift("writeable.finalizeString()".into()))
+            }
+            ReturnType::Infallible(SuccessType::OutType(o)) => {
+                Some(ReturnValue::Native(o, "result".into()))
+            }
+            ReturnType::Nullable(SuccessType::Unit) => {
+                Some(ReturnValue::Swift("result.is_ok".into()))
+            }
+            ReturnType::Fallible(ok, _) | ReturnType::Nullable(ok) => {
+                out.push("if !result.is_ok {".into());
+                out.push(match output {
+                    ReturnType::Fallible(_, Some(e)) => format!(
+                        "    throw DiplomatError(error: {})",
+                        self.gen_native_to_swift(e, "result.err", edges)
+                    ),
+                    ReturnType::Fallible(_, None) => "    throw DiplomatError(error: nil)".into(),
+                    _ => "    return nil".into(),
+                });
+                out.push("}".into());
+                match ok {
+                    SuccessType::Unit => None,
+                    SuccessType::Writeable => {
+                        Some(ReturnValue::Swift("writeable.finalizeString()".into()))
+                    }
+                    SuccessType::OutType(o) => Some(ReturnValue::Native(o, "result.ok".into())),
+                    _ => unreachable!("unknown AST/HIR variant"),
+                }
+            }
+            _ => unreachable!("unknown AST/HIR variant"),
+        };
+
+        match (ok_value, sink) {
+            (None, _) => {}
+            // Classes can't assign to `self`, so their constructors delegate
+            // to the initializer taking a handle
+            (Some(ReturnValue::Native(Type::Opaque(op), value)), Sink::InitClass) => {
+                let handle = if op.is_optional() {
+                    out.push(format!("guard let handle = {value} else {{"));
+                    out.push("    return nil".into());
+                    out.push("}".into());
+                    "handle".into()
+                } else {
+                    value
+                };
+                out.push(format!(
+                    "self.init(handle: {handle}, owned: {}, edges: {})",
+                    op.owner.is_owned(),
+                    self.gen_opaque_edges(
+                        op.owner.is_owned(),
+                        op.lifetimes.lifetimes().len() > 0,
+                        edges
+                    )
+                ));
+            }
+            (Some(value), sink) => {
+                let value = match value {
+                    ReturnValue::Swift(value) => value,
+                    ReturnValue::Native(o, value) => self.gen_native_to_swift(o, &value, edges),
+                };
+                match sink {
+                    Sink::InitValue => out.push(format!("self = {value}")),
+                    _ => out.push(format!("return {value}")),
+                }
+            }
+        }
+        out
+    }
+
+    /// Whether passing this type needs temporary allocations
+    fn needs_keep<P: TyPosition>(&self, ty: &Type<P>) -> bool {
+        match ty {
+            Type::Struct(_) => true,
+            Type::Enum(e) => e.resolve(self.tcx).is_data_carrying(),
+            Type::Slice(s) => s.lifetime().is_some(),
+            _ => false,
+        }
+    }
+
+    /// Generates a type's Swift type.
+    fn gen_type_name<P: TyPosition>(&mut self, ty: &Type<P>) -> String {
+        match *ty {
+            Type::Primitive(prim) => self.formatter.fmt_primitive_as_swift(prim).into(),
+            Type::Opaque(ref op) => {
+                let type_name = self.gen_named_type(op.tcx_id.into());
+                if op.is_optional() {
+                    self.formatter.fmt_optional(&type_name)
+                } else {
+                    type_name
+                }
+            }
+            Type::Struct(ref st) => self.gen_named_type(st.id()),
+            Type::Enum(ref e) => self.gen_named_type(e.tcx_id.into()),
+            Type::Slice(hir::Slice::Str(..)) => self.formatter.fmt_string().into(),
+            Type::Slice(hir::Slice::Primitive(_, p)) => {
+                format!("[{}]", self.formatter.fmt_primitive_as_swift(p))
+            }
+            Type::Slice(hir::Slice::Strs(..)) => format!("[{}]", self.formatter.fmt_string()),
+            Type::Trait(ref t) => {
+                let def = t.resolve(self.tcx);
+                self.errors.push_error(format!(
+                    "Traits are not supported in Swift, found {}",
+                    def.name
+                ));
+                "Any?".into()
+            }
+            Type::Func(..) => {
+                self.errors
+                    .push_error("Callbacks are not supported in Swift".into());
+                "Any?".into()
+            }
+            _ => unreachable!("unknown AST/HIR variant"),
+        }
+    }
+
+    fn gen_named_type(&mut self, id: TypeId) -> String {
+        let type_name = self.formatter.fmt_type_name(id);
+        if self.tcx.resolve_type(id).attrs().disable {
+            self.errors
+                .push_error(format!("Found usage of disabled type {type_name}"))
+        }
+        type_name.into_owned()
+    }
+
+    fn gen_type_name_for_success(&mut self, success: &SuccessType) -> String {
+        match success {
+            SuccessType::Unit => "Void".into(),
+            SuccessType::Writeable => self.formatter.fmt_string().into(),
+            SuccessType::OutType(o) => self.gen_type_name(o),
+            _ => unreachable!("unknown AST/HIR variant"),
+        }
+    }
+
+    /// Generates a return type's Swift type.
+    fn gen_return_type_name(&mut self, output: &ReturnType) -> String {
+        match output {
+            ReturnType::Infallible(ok) | ReturnType::Fallible(ok, _) => {
+                self.gen_type_name_for_success(ok)
+            }
+            ReturnType::Nullable(SuccessType::Unit) => "Bool".into(),
+            ReturnType::Nullable(ok) => {
+                let ok = self.gen_type_name_for_success(ok);
+                if ok.ends_with('?') {
+                    ok
+                } else {
+                    self.formatter.fmt_optional(&ok)
+                }
+            }
+        }
+    }
+
+    /// Generates the Swift expression copying a slice into memory Rust can
+    /// read, which evaluates to a `(data, len)` tuple.
+    fn gen_slice_to_native(&self, slice: &hir::Slice, value: &str) -> String {
+        // Owned slices are allocated by Rust, everything else lives in `keep`
+        let keep = if slice.lifetime().is_some() {
+            "keep"
+        } else {
+            "nil"
+        };
+        match *slice {
+            hir::Slice::Str(_, hir::StringEncoding::UnvalidatedUtf16) => {
+                format!("DiplomatRuntime.str16({value}, {keep})")
+            }
+            hir::Slice::Str(..) => format!("DiplomatRuntime.str8({value}, {keep})"),
+            hir::Slice::Primitive(_, p) => format!(
+                "DiplomatRuntime.slice({value}, as: {}.self, {keep})",
+                self.formatter.fmt_primitive_as_ffi(p)
+            ),
+            hir::Slice::Strs(hir::StringEncoding::UnvalidatedUtf16) => {
+                format!("DiplomatRuntime.strs16({value}, {keep})")
+            }
+            hir::Slice::Strs(_) => format!("DiplomatRuntime.strs8({value}, {keep})"),
+            _ => unreachable!("unknown AST/HIR variant"),
+        }
+    }
+
+    /// Generates the statements setting the field `field` of a C struct to `value`
+    fn gen_field_to_native<P: TyPosition>(
+        &mut self,
+        ty: &Type<P>,
+        value: &str,
+        field: &str,
+    ) -> String {
+        match *ty {
+            Type::Slice(ref s) => {
+                let local = format!("{}Slice", unescape(value.trim_start_matches("self.")));
+                format!(
+                    "let {local} = {}\n{field} = .init(data: {local}.data, len: {local}.len)",
+                    self.gen_slice_to_native(s, value)
+                )
+            }
+            _ => format!("{field} = {}", self.gen_swift_to_native(ty, value)),
+        }
+    }
+
+    /// Generates the Swift expression converting a Swift value to its C type.
+    ///
+    /// Temporary allocations are added to `keep`.
+    fn gen_swift_to_native<P: TyPosition>(&mut self, ty: &Type<P>, value: &str) -> String {
+        use diplomat_core::hir::{IntSizeType, PrimitiveType};
+        match *ty {
+            Type::Primitive(PrimitiveType::Char) => format!("{value}.value"),
+            Type::Primitive(PrimitiveType::IntSize(IntSizeType::Usize)) => {
+                format!("Int(bitPattern: {value})")
+            }
+            Type::Primitive(_) => value.into(),
+            Type::Opaque(ref op) if op.is_optional() => format!("{value}?.handle"),
+            Type::Opaque(_) => format!("{value}.handle"),
+            Type::Struct(_) => format!("{value}.toNative(keep)"),
+            Type::Enum(ref e) if e.resolve(self.tcx).is_data_carrying() => {
+                format!("{value}.toNative(keep)")
+            }
+            Type::Enum(_) => format!("{value}.toNative()"),
+            Type::Slice(ref s) => self.gen_slice_to_native(s, value),
+            Type::Trait(_) | Type::Func(_) => {
+                // Already reported by gen_type_name
+                "nil".into()
+            }
+            _ => unreachable!("unknown AST/HIR variant"),
+        }
+    }
+
+    /// Generates the edges of a returned opaque
+    fn gen_opaque_edges<'e>(&self, owned: bool, has_lifetimes: bool, edges: &'e str) -> &'e str {
+        // Owned opaques only need edges for their own lifetimes
+        if owned && !has_lifetimes {
+            "[]"
+        } else {
+            edges
+        }
+    }
+
+    /// Generates the Swift expression converting a C value to its Swift type.
+    ///
+    /// `edges` is a Swift array of everything borrowed values may borrow from.
+    fn gen_native_to_swift<P: TyPosition>(
+        &mut self,
+        ty: &Type<P>,
+        value: &str,
+        edges: &str,
+    ) -> String {
+        use diplomat_core::hir::{IntSizeType, PrimitiveType};
+        match *ty {
+            Type::Primitive(PrimitiveType::Char) => format!("Unicode.Scalar({value})!"),
+            Type::Primitive(PrimitiveType::IntSize(IntSizeType::Usize)) => {
+                format!("UInt(bitPattern: {value})")
+            }
+            Type::Primitive(_) => value.into(),
+            Type::Opaque(ref op) => {
+                let type_name = self.formatter.fmt_type_name(op.tcx_id.into());
+                let owned = op.owner.is_owned();
+                let edges = self.gen_opaque_edges(owned, op.lifetimes.lifetimes().len() > 0, edges);
+                if op.is_optional() {
+                    format!(
+                        "{value}.map {{ {type_name}(handle: $0, owned: {owned}, edges: {edges}) }}"
+                    )
+                } else {
+                    format!("{type_name}(handle: {value}, owned: {owned}, edges: {edges})")
+                }
+            }
+            Type::Struct(ref st) => {
+                let type_name = self.formatter.fmt_type_name(st.id());
+                format!("{type_name}(native: {value}, edges: {edges})")
+            }
+            Type::Enum(ref e) => {
+                let type_name = self.formatter.fmt_type_name(e.tcx_id.into());
+                if e.resolve(self.tcx).is_data_carrying() {
+                    format!("{type_name}(native: {value}, edges: {edges})")
+                } else {
+                    format!("{type_name}(native: {value})")
+                }
+            }
+            Type::Slice(ref s) => {
+                // Slices without a lifetime are owned, so they are freed after copying
+                let owned = s.lifetime().is_none();
+                match s {
+                    hir::Slice::Str(_, hir::StringEncoding::UnvalidatedUtf16) => {
+                        format!("DiplomatRuntime.readStr16({value}.data, {value}.len, {owned})")
+                    }
+                    hir::Slice::Str(..) => {
+                        format!("DiplomatRuntime.readStr8({value}.data, {value}.len, {owned})")
+                    }
+                    hir::Slice::Primitive(_, p) => format!(
+                        "DiplomatRuntime.read({value}.data, {value}.len, {owned}, as: {}.self)",
+                        self.formatter.fmt_primitive_as_swift(*p)
+                    ),
+                    _ => {
+                        self.errors
+                            .push_error("Returning string lists is not supported".into());
+                        "[]".into()
+                    }
+                }
+            }
+            Type::Trait(_) | Type::Func(_) => "nil".into(),
+            _ => unreachable!("unknown AST/HIR variant"),
+        }
+    }
+}
+
+/// Where the value a method returns goes
+#[derive(Clone, Copy)]
+enum Sink {
+    Return,
+    /// Constructors of structs and enums assign to `self`
+    InitValue,
+    /// Constructors of classes delegate to another initializer
+    InitClass,
+}
+
+/// The value a method returns, before it is passed to its `Sink`
+enum ReturnValue<'cx> {
+    Swift(String),
+    /// A C value, which still needs converting
+    Native(&'cx hir::OutType, String),
+}
+
+/// The rendered members of a type.
+#[derive(Default)]
+struct MembersInfo {
+    members: Vec<String>,
+    /// The operators comparators define, which go after all other members
+    operators: Vec<String>,
+    /// The protocols special methods conform the type to
+    conformances: Vec<String>,
+}
+
+impl MembersInfo {
+    fn add_consts(&mut self, consts: Vec<String>) {
+        self.members.splice(0..0, consts);
+    }
+}
+
+/// Everything needed for rendering a method.
+struct MethodInfo {
+    /// The Swift method name
+    name: String,
+    is_static: bool,
+    /// The parameter declarations
+    params: Vec<String>,
+    param_names: Vec<String>,
+    return_ty: String,
+    throws: bool,
+    docs: String,
+    /// The statements of the method body
+    body: Vec<String>,
+}
+
+#[derive(Template)]
+#[template(path = "swift/method.swift.jinja", escape = "none")]
+struct MethodTemplate<'a> {
+    m: &'a MethodInfo,
+    /// The declaration, up to the body
+    decl: &'a str,
+}
+
+/// The getter and/or setter methods making up a property.
+struct PropertyInfo {
+    name: String,
+    is_static: bool,
+    getter: Option<MethodInfo>,
+    setter: Option<MethodInfo>,
+}
+
+/// Everything needed for rendering a computed property or subscript.
+struct PropertyGenInfo {
+    /// The declaration, up to the body
+    decl: String,
+    docs: String,
+    throws: bool,
+    getter_body: Vec<String>,
+    setter: Option<SetterInfo>,
+}
+
+struct SetterInfo {
+    /// Modifiers such as `nonmutating `, including the trailing space
+    modifiers: &'static str,
+    /// The name of the new value
+    param: String,
+    body: Vec<String>,
+}
+
+#[derive(Template)]
+#[template(path = "swift/property.swift.jinja", escape = "none")]
+struct PropertyTemplate<'a> {
+    p: &'a PropertyGenInfo,
+}
+
+struct FieldInfo {
+    name: String,
+    docs: String,
+    swift_type: String,
+    /// The statements setting the field of the C struct `native`
+    swift_to_native: String,
+    native_to_swift: String,
+}
+
+struct EnumVariantInfo {
+    name: String,
+    discriminant: isize,
+    docs: String,
+}
+
+/// Everything needed for rendering a case of a data-carrying enum.
+struct TaggedVariantInfo {
+    name: String,
+    docs: String,
+    discriminant: isize,
+    /// The names the payload is bound to when matching the case
+    bindings: Vec<String>,
+    fields: Vec<FieldInfo>,
+}
+
+// Helpers used in templates (Askama has restrictions on Rust syntax)
+
+/// Format docs as a documentation comment, including the trailing newline
+fn swiftdoc(docs: &str) -> String {
+    let mut out = String::new();
+    for line in docs.lines() {
+        if line.is_empty() {
+            out.push_str("///\n");
+        } else {
+            out.push_str("/// ");
+            out.push_str(line);
+            out.push('\n');
+        }
+    }
+    out
+}
+
+/// Indent every line, leaving blank lines empty
+fn indent(text: &str, width: usize) -> String {
+    let indent = " ".repeat(width);
+    text.split('\n')
+        .map(|line| {
+            if line.is_empty() {
+                String::new()
+            } else {
+                format!("{indent}{line}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// A name without the backticks escaping keywords
+fn unescape(name: &str) -> &str {
+    name.trim_matches('`')
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_strings() {
+        test_file! {
+            #[diplomat::bridge]
+            mod ffi {
+                #[diplomat::opaque]
+                pub struct Name(String);
+
+                impl Name {
+                    pub fn from_utf8(text: &DiplomatStr) -> Box<Name> {
+                        unimplemented!()
+                    }
+                    pub fn from_utf16(text: &DiplomatStr16) -> Box<Name> {
+                        unimplemented!()
+                    }
+                    pub fn from_parts(parts: &[&DiplomatStr]) -> Box<Name> {
+                        unimplemented!()
+                    }
+                    pub fn as_str<'a>(&'a self) -> &'a DiplomatStr {
+                        unimplemented!()
+                    }
+                    pub fn format(&self, write: &mut DiplomatWriteable) {}
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_slices() {
+        test_file! {
+            #[diplomat::bridge]
+            mod ffi {
+                #[diplomat::opaque]
+                pub struct Samples(Vec<f64>);
+
+                impl Samples {
+                    pub fn from_values(values: &[f64]) -> Box<Samples> {
+                        unimplemented!()
+                    }
+                    pub fn scale(values: &mut [f64], factor: f64) {}
+                    pub fn count_set(flags: &[bool]) -> usize {
+                        unimplemented!()
+                    }
+                    pub fn values<'a>(&'a self) -> &'a [f64] {
+                        unimplemented!()
+                    }
+                    pub fn to_bytes(&self) -> Box<[u8]> {
+                        unimplemented!()
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_catch_panics() {
+        test_file! {
+            #[diplomat::bridge]
+            mod ffi {
+                pub enum MyError {
+                    Panicked,
+                    Other,
+                }
+
+                #[diplomat::opaque]
+                pub struct Thing;
+
+                #[diplomat::catch_panics]
+                impl Thing {
+                    pub fn might_panic(x: u32) -> u32 {
+                        unimplemented!()
+                    }
+                    pub fn might_fail(x: u32) -> Result<u32, MyError> {
+                        unimplemented!()
+                    }
+                }
+            }
+        }
+    }
+}
@@ -4,18 +4,125 @@ use core::hash::Hash;
 
 use diplomat_core::{ast, Env};
 
+use crate::backend::Errors;
+
 pub fn get_all_custom_types(env: &Env) -> SetOfAstTypes<&ast::CustomType> {
     let mut all_types = SetOfAstTypes::default();
 
     for (path, _name, symbol) in env.iter_items() {
         if let ast::ModSymbol::CustomType(c) = symbol {
-            all_types.insert((path.clone(), c));
+            if !c.attrs().skip_if_ast {
+                all_types.insert((path.clone(), c));
+            }
         }
     }
 
     all_types
 }
 
+/// Check that the bridge only uses features the AST backends can generate bindings for,
+/// so that they fail with an error rather than panicking or generating the wrong ABI.
+///
+/// Types and methods marked `#[diplomat::skip_if_ast]` aren't checked, since AST backends
+/// don't generate them.
+pub fn check_ast_support(env: &Env, backend: &str) -> Result<(), Errors> {
+    let mut errors = Errors::new();
+    for (in_path, typ) in get_all_custom_types(env) {
+        let context = typ.name().to_string();
+        match typ {
+            ast::CustomType::Struct(strct) => {
+                for (name, ty, _) in strct.fields.iter() {
+                    check_ast_type(
+                        ty,
+                        &in_path,
+                        env,
+                        backend,
+                        &format!("{context}::{name}"),
+                        &mut errors,
+                    );
+                }
+            }
+            ast::CustomType::Enum(enm) if enm.is_data_carrying() => errors.push((
+                context.clone(),
+                format!(
+                    "Enums whose variants carry fields are not supported by the {backend} backend"
+                ),
+            )),
+            _ => {}
+        }
+        for method in typ.methods().iter().filter(|m| !m.attrs.skip_if_ast) {
+            let context = format!("{context}::{}", method.name);
+            for param in method.params.iter() {
+                check_ast_type(&param.ty, &in_path, env, backend, &context, &mut errors);
+            }
+            if let Some(return_type) = &method.return_type {
+                check_ast_type(return_type, &in_path, env, backend, &context, &mut errors);
+            }
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+fn check_ast_type(
+    ty: &ast::TypeName,
+    in_path: &ast::Path,
+    env: &Env,
+    backend: &str,
+    context: &str,
+    errors: &mut Errors,
+) {
+    match ty {
+        ast::TypeName::Named(path_type) => {
+            if let ast::CustomType::Enum(enm) = path_type.resolve(in_path, env) {
+                if enm.is_data_carrying() {
+                    errors.push((
+                        context.to_string(),
+                        format!(
+                            "{} carries fields in its variants, which the {backend} backend does not support",
+                            enm.name
+                        ),
+                    ));
+                }
+            }
+        }
+        ast::TypeName::DynTrait(path_type) => errors.push((
+            context.to_string(),
+            format!(
+                "Traits are not supported by the {backend} backend, found trait {}",
+                path_type.path
+            ),
+        )),
+        ast::TypeName::Reference(_, _, inner)
+        | ast::TypeName::Box(inner)
+        | ast::TypeName::Option(inner) => {
+            check_ast_type(inner, in_path, env, backend, context, errors)
+        }
+        ast::TypeName::Result(ok, err, _) => {
+            check_ast_type(ok, in_path, env, backend, context, errors);
+            check_ast_type(err, in_path, env, backend, context, errors);
+        }
+        _ => {}
+    }
+}
+
+/// All consts declared at the top level of a module, in name order.
+pub fn get_all_consts(env: &Env) -> Vec<(ast::Path, &ast::Const)> {
+    let mut all_consts: Vec<(ast::Path, &ast::Const)> = env
+        .iter_items()
+        .filter_map(|(path, _name, symbol)| match symbol {
+            ast::ModSymbol::Const(c) if !c.attrs.skip_if_ast => Some((path.clone(), c)),
+            _ => None,
+        })
+        .collect();
+    all_consts.sort_by(|a, b| a.1.name.cmp(&b.1.name));
+    all_consts
+}
+
 pub struct CodeWriter<'io> {
     writer: &'io mut dyn fmt::Write,
     ind_level: usize,
@@ -165,3 +272,86 @@ impl<T> IntoIterator for SetOfAstTypes<T> {
         self.order.into_iter()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::check_ast_support;
+
+    #[test]
+    fn test_ast_rejects_data_carrying_enums() {
+        let parsed: syn::File = syn::parse_quote! {
+            #[diplomat::bridge]
+            mod ffi {
+                enum Shape {
+                    Circle { radius: f64 },
+                    Empty,
+                }
+
+                #[diplomat::skip_if_ast]
+                enum Hidden {
+                    A { x: u8 },
+                }
+
+                #[diplomat::opaque]
+                struct Canvas;
+
+                impl Canvas {
+                    pub fn draw(&self, shape: Shape) {}
+
+                    #[diplomat::skip_if_ast]
+                    pub fn draw_hidden(&self, hidden: Hidden) {}
+                }
+            }
+        };
+        let env = diplomat_core::ast::File::from(&parsed).all_types();
+
+        let mut errors = check_ast_support(&env, "c").unwrap_err();
+        errors.sort();
+        assert_eq!(
+            errors,
+            vec![
+                (
+                    "Canvas::draw".to_string(),
+                    "Shape carries fields in its variants, which the c backend does not support"
+                        .to_string()
+                ),
+                (
+                    "Shape".to_string(),
+                    "Enums whose variants carry fields are not supported by the c backend"
+                        .to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ast_rejects_traits() {
+        let parsed: syn::File = syn::parse_quote! {
+            #[diplomat::bridge]
+            mod ffi {
+                pub trait Logger {
+                    fn log(&self, level: u8);
+                }
+
+                #[diplomat::opaque]
+                struct Service;
+
+                impl Service {
+                    pub fn run(&self, logger: &dyn Logger) {}
+
+                    #[diplomat::skip_if_ast]
+                    pub fn run_quietly(&self, logger: &dyn Logger) {}
+                }
+            }
+        };
+        let env = diplomat_core::ast::File::from(&parsed).all_types();
+
+        assert_eq!(
+            check_ast_support(&env, "js").unwrap_err(),
+            vec![(
+                "Service::run".to_string(),
+                "Traits are not supported by the js backend, found trait Logger".to_string()
+            )]
+        );
+    }
+}
@@ -0,0 +1,176 @@
+//! `diplomat-tool watch`, which regenerates the bindings configured in a `diplomat.toml`
+//! whenever the bridge crate changes.
+
+use crate::{Error, Event, MessageFormat, Registry};
+use notify::{EventKind, RecursiveMode, Watcher};
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::time::Duration;
+
+/// How long to wait for more changes before regenerating, since editors and
+/// `git checkout` touch files several times in a row
+const DEBOUNCE: Duration = Duration::from_millis(100);
+
+/// Generate the bindings configured in `project_file`, then generate them again
+/// each time the project file or one of the files of the bridge crate changes.
+///
+/// The files are the ones [`gen_project`](crate::gen_project) inlined the bridge crate
+/// from, so modules that are added or removed are picked up on the next run. Errors are
+/// passed to `report` instead of stopping the watch, which only returns if watching fails.
+pub fn watch_project(
+    project_file: &Path,
+    targets: Option<&[String]>,
+    registry: &Registry,
+    mut on_event: impl FnMut(Event),
+    message_format: MessageFormat,
+    mut report: impl FnMut(Error),
+) -> Result<(), Error> {
+    let (sender, receiver) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(sender)?;
+    loop {
+        let watched = regenerate(
+            project_file,
+            targets,
+            registry,
+            &mut on_event,
+            message_format,
+            &mut report,
+        );
+
+        // Editors often save by replacing the file, which drops a watch on the
+        // file itself, so the folders the files are in are watched instead
+        let folders: BTreeSet<&Path> = watched.iter().filter_map(|p| p.parent()).collect();
+        for folder in &folders {
+            watcher.watch(folder, RecursiveMode::NonRecursive)?;
+        }
+        on_event(Event::Watching(watched.len()));
+
+        wait_for_change(&receiver, &watched)?;
+        for folder in &folders {
+            // Fails if the folder was removed, which is fine
+            let _ = watcher.unwatch(folder);
+        }
+    }
+}
+
+/// Generate the bindings once, passing an error to `report`, and return the files
+/// to watch for the next round. These are known even if generating failed, so that
+/// fixing the error starts the next round.
+fn regenerate(
+    project_file: &Path,
+    targets: Option<&[String]>,
+    registry: &Registry,
+    on_event: &mut dyn FnMut(Event),
+    message_format: MessageFormat,
+    report: &mut dyn FnMut(Error),
+) -> BTreeSet<PathBuf> {
+    let mut sources = vec![project_file.to_owned()];
+    if let Err(error) = crate::gen_project_from_sources(
+        project_file,
+        targets,
+        registry,
+        on_event,
+        false,
+        message_format,
+        &mut sources,
+    ) {
+        report(error);
+    }
+    sources.iter().filter_map(|p| canonical(p)).collect()
+}
+
+/// Block until one of the `watched` files is changed, created or removed
+fn wait_for_change(
+    receiver: &mpsc::Receiver<notify::Result<notify::Event>>,
+    watched: &BTreeSet<PathBuf>,
+) -> Result<(), Error> {
+    loop {
+        let event = receiver
+            .recv()
+            .expect("the watcher holds the sender for as long as we watch")?;
+        let is_change = matches!(
+            event.kind,
+            EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+        );
+        if is_change && event.paths.iter().any(|path| watched.contains(path)) {
+            break;
+        }
+    }
+    while receiver.recv_timeout(DEBOUNCE).is_ok() {}
+    Ok(())
+}
+
+/// `path` with its folder made canonical, so that it compares equal to the paths
+/// of the events. The file itself might not exist.
+fn canonical(path: &Path) -> Option<PathBuf> {
+    let folder = match path.parent() {
+        Some(folder) if folder != Path::new("") => folder,
+        _ => Path::new("."),
+    };
+    Some(fs::canonicalize(folder).ok()?.join(path.file_name()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{canonical, regenerate};
+    use crate::{Error, Event, MessageFormat, Registry};
+    use std::fs;
+
+    #[test]
+    fn test_error_is_reported_and_watching_continues() {
+        let dir = std::env::temp_dir().join(format!("diplomat-watch-{}", std::process::id()));
+        fs::create_dir_all(dir.join("src")).unwrap();
+        fs::create_dir_all(dir.join("out")).unwrap();
+        let project_file = dir.join("diplomat.toml");
+        fs::write(&project_file, "[targets.c2]\nout = \"out\"\n").unwrap();
+        let lib = dir.join("src/lib.rs");
+        // Opaque types can't be passed by value
+        fs::write(
+            &lib,
+            "#[diplomat::bridge]\nmod ffi {\n    #[diplomat::opaque]\n    pub struct Foo(u8);\n\n    impl Foo {\n        pub fn by_val(x: Foo) {}\n    }\n}\n",
+        )
+        .unwrap();
+
+        let registry = Registry::new();
+        let round = || {
+            let mut errors = Vec::new();
+            let mut written = 0;
+            let watched = regenerate(
+                &project_file,
+                None,
+                &registry,
+                &mut |event| {
+                    if let Event::Written(_) = event {
+                        written += 1;
+                    }
+                },
+                MessageFormat::Human,
+                &mut |error| errors.push(error),
+            );
+            (errors, written, watched)
+        };
+
+        let (errors, written, watched) = round();
+        assert!(
+            matches!(&errors[..], [Error::Lowering { .. }]),
+            "{errors:?}"
+        );
+        assert_eq!(written, 0);
+        assert!(watched.contains(&canonical(&lib).unwrap()));
+        assert!(watched.contains(&canonical(&project_file).unwrap()));
+
+        fs::write(
+            &lib,
+            "#[diplomat::bridge]\nmod ffi {\n    #[diplomat::opaque]\n    pub struct Foo(u8);\n\n    impl Foo {\n        pub fn by_ref(&self) {}\n    }\n}\n",
+        )
+        .unwrap();
+        let (errors, written, watched_again) = round();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert!(errors.is_empty(), "{errors:?}");
+        assert!(written > 0);
+        assert_eq!(watched, watched_again);
+    }
+}
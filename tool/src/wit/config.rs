@@ -0,0 +1,16 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct LibraryConfig {
+    /// The Cargo package name of the library, which `#[diplomat::bridge]`
+    /// names the exports of its component model adapters after
+    pub package: String,
+}
+
+impl Default for LibraryConfig {
+    fn default() -> LibraryConfig {
+        LibraryConfig {
+            package: "diplomat".to_owned(),
+        }
+    }
+}
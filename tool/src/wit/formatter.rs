@@ -0,0 +1,148 @@
+//! This module contains functions for formatting types
+
+use diplomat_core::ast::{DocsUrlGenerator, MarkdownStyle};
+use diplomat_core::hir::{self, TypeContext, TypeId};
+use heck::ToKebabCase;
+
+/// This type mediates all formatting
+///
+/// All identifiers from the HIR should go through here before being formatted
+/// into the output: This makes it easy to handle reserved words
+///
+/// If you find yourself needing an identifier formatted in a context not yet available here, please add a new method
+pub(super) struct WitFormatter<'tcx> {
+    tcx: &'tcx TypeContext,
+    docs_url_generator: &'tcx DocsUrlGenerator,
+}
+
+/// WIT keywords, which identifiers have to be escaped with a `%` to use
+const KEYWORDS: &[&str] = &[
+    "as",
+    "async",
+    "bool",
+    "borrow",
+    "char",
+    "constructor",
+    "enum",
+    "error-context",
+    "export",
+    "f32",
+    "f64",
+    "flags",
+    "func",
+    "future",
+    "import",
+    "include",
+    "interface",
+    "list",
+    "option",
+    "own",
+    "package",
+    "record",
+    "resource",
+    "result",
+    "s8",
+    "s16",
+    "s32",
+    "s64",
+    "static",
+    "stream",
+    "string",
+    "tuple",
+    "type",
+    "u8",
+    "u16",
+    "u32",
+    "u64",
+    "use",
+    "variant",
+    "with",
+    "world",
+];
+
+impl<'tcx> WitFormatter<'tcx> {
+    pub fn new(tcx: &'tcx TypeContext, docs_url_generator: &'tcx DocsUrlGenerator) -> Self {
+        Self {
+            tcx,
+            docs_url_generator,
+        }
+    }
+
+    /// Format docs as the contents of `///` comments
+    pub fn fmt_docs(&self, docs: &hir::Docs) -> String {
+        docs.to_markdown(self.docs_url_generator, MarkdownStyle::Normal)
+            .trim()
+            .replace(" \n", "\n")
+    }
+
+    /// The path of the file holding the package
+    pub fn fmt_file_path(&self, package: &str) -> String {
+        format!("{}.wit", package.to_kebab_case())
+    }
+
+    /// The name of the package, interface and world, which the bridge macro
+    /// derives from the Cargo package name in the same way
+    pub fn fmt_package_name(&self, package: &str) -> String {
+        package.to_kebab_case()
+    }
+
+    /// Format a type name for use in code
+    ///
+    /// The names are part of the names of the exports, so renames can't apply to them
+    pub fn fmt_type_name(&self, id: TypeId) -> String {
+        self.fmt_identifier(self.tcx.resolve_type(id).name().as_str())
+    }
+
+    /// Resolve and format a named type for use in diagnostics
+    pub fn fmt_type_name_diagnostics(&self, id: TypeId) -> &'tcx str {
+        self.tcx.resolve_type(id).name().as_str()
+    }
+
+    /// Format the name of a method, a field, a parameter or an enum case
+    pub fn fmt_identifier(&self, ident: &str) -> String {
+        let name = ident.to_kebab_case();
+        if KEYWORDS.contains(&name.as_str()) {
+            format!("%{name}")
+        } else {
+            name
+        }
+    }
+
+    /// Methods of records and enums become functions of the interface prefixed with the type name
+    pub fn fmt_free_function_name(&self, id: TypeId, method: &hir::Method) -> String {
+        self.fmt_identifier(&format!(
+            "{}-{}",
+            self.fmt_type_name_diagnostics(id).to_kebab_case(),
+            method.name.as_str().to_kebab_case()
+        ))
+    }
+
+    /// The WIT type of a primitive
+    pub fn fmt_primitive(&self, prim: hir::PrimitiveType) -> &'static str {
+        use diplomat_core::hir::{FloatType, IntSizeType, IntType, PrimitiveType};
+        match prim {
+            PrimitiveType::Bool => "bool",
+            PrimitiveType::Char => "char",
+            PrimitiveType::Int(IntType::I8) => "s8",
+            PrimitiveType::Int(IntType::U8) | PrimitiveType::Byte => "u8",
+            PrimitiveType::Int(IntType::I16) => "s16",
+            PrimitiveType::Int(IntType::U16) => "u16",
+            PrimitiveType::Int(IntType::I32) | PrimitiveType::IntSize(IntSizeType::Isize) => "s32",
+            PrimitiveType::Int(IntType::U32) | PrimitiveType::IntSize(IntSizeType::Usize) => "u32",
+            PrimitiveType::Int(IntType::I64) => "s64",
+            PrimitiveType::Int(IntType::U64) => "u64",
+            PrimitiveType::Float(FloatType::F32) => "f32",
+            PrimitiveType::Float(FloatType::F64) => "f64",
+            PrimitiveType::Int128(_) => panic!("i128 not supported in WIT"),
+        }
+    }
+
+    /// The WIT type of a string: the canonical ABI only passes valid UTF-8 as a `string`,
+    /// which unvalidated UTF-8 parameters accept as well
+    pub fn fmt_string(&self, encoding: hir::StringEncoding) -> &'static str {
+        match encoding {
+            hir::StringEncoding::UnvalidatedUtf16 => "list<u16>",
+            _ => "string",
+        }
+    }
+}
@@ -0,0 +1,544 @@
+//! Generates a [WIT] world for the WebAssembly component model.
+//!
+//! The world exports a single interface, in which opaques become resources, structs
+//! become records and enums stay enums. Methods of records and enums become functions
+//! of the interface, prefixed with the name of their type.
+//!
+//! The component model adapters these names are exported under are generated by
+//! `#[diplomat::bridge]` when its `wit` feature is enabled. The two sides never see
+//! each other's output, so the rules for what gets exported here must be kept in sync
+//! with `macro/src/wit.rs`. Items that aren't exported are left as comments saying why.
+//!
+//! [WIT]: https://component-model.bytecodealliance.org/design/wit.html
+
+use crate::common::FileMap;
+use askama::Template;
+use config::LibraryConfig;
+use diplomat_core::ast::{self, DocsUrlGenerator};
+use diplomat_core::hir::{
+    self, OpaqueOwner, ReturnType, SelfType, SpecialMethod, StructPathLike, SuccessType,
+    TyPosition, Type, TypeContext, TypeDef, TypeId,
+};
+use diplomat_core::Env;
+use formatter::WitFormatter;
+use std::collections::HashMap;
+
+pub mod config;
+mod formatter;
+
+/// The canonical ABI passes parameters through memory past this many flat values,
+/// which the adapters don't support
+const MAX_FLAT_PARAMS: usize = 16;
+
+/// Run file generation
+///
+/// `env` is needed to find out which module each type was declared in, since the
+/// bridge macro can only resolve types passed by value within a module.
+pub fn run<'cx>(
+    tcx: &'cx TypeContext,
+    env: &'cx Env,
+    library_config: LibraryConfig,
+    docs_url_generator: &'cx DocsUrlGenerator,
+) -> FileMap {
+    let formatter = WitFormatter::new(tcx, docs_url_generator);
+    let modules = env
+        .iter_items()
+        .filter_map(|(path, _, symbol)| match symbol {
+            ast::ModSymbol::CustomType(custom_type) => Some((custom_type.name().as_str(), path)),
+            _ => None,
+        })
+        .collect();
+    let cx = WitContext {
+        tcx,
+        formatter: &formatter,
+        modules,
+    };
+
+    let mut items = vec![];
+    for (id, ty) in tcx.all_types() {
+        if ty.attrs().disable {
+            continue;
+        }
+        items.extend(cx.gen_type(id, ty));
+    }
+    for (_, trt) in tcx.all_traits() {
+        if !trt.attrs.disable {
+            items.push(format!(
+                "// `{}` is not exported: traits are not supported",
+                trt.name
+            ));
+        }
+    }
+
+    #[derive(Template)]
+    #[template(path = "wit/package.wit.jinja", escape = "none")]
+    struct PackageTemplate {
+        package: String,
+        items: Vec<String>,
+    }
+
+    let files = FileMap::default();
+    files.add_file(
+        formatter.fmt_file_path(&library_config.package),
+        PackageTemplate {
+            package: formatter.fmt_package_name(&library_config.package),
+            items,
+        }
+        .render()
+        .unwrap(),
+    );
+    files
+}
+
+/// A WIT type, with the number of flat core wasm values the canonical ABI lowers it to
+struct WitType {
+    name: String,
+    flat: usize,
+}
+
+impl WitType {
+    fn new(name: impl Into<String>, flat: usize) -> Self {
+        WitType {
+            name: name.into(),
+            flat,
+        }
+    }
+
+    fn option(inner: WitType) -> Self {
+        WitType::new(format!("option<{}>", inner.name), 1 + inner.flat)
+    }
+
+    fn result(ok: Option<WitType>, err: Option<WitType>) -> Self {
+        let flat = 1 + ok
+            .as_ref()
+            .map(|ty| ty.flat)
+            .unwrap_or(0)
+            .max(err.as_ref().map(|ty| ty.flat).unwrap_or(0));
+        let name = match (ok, err) {
+            (Some(ok), Some(err)) => format!("result<{}, {}>", ok.name, err.name),
+            (None, Some(err)) => format!("result<_, {}>", err.name),
+            (Some(ok), None) => format!("result<{}>", ok.name),
+            (None, None) => "result".into(),
+        };
+        WitType::new(name, flat)
+    }
+}
+
+struct WitContext<'a, 'cx> {
+    tcx: &'cx TypeContext,
+    formatter: &'a WitFormatter<'cx>,
+    /// The module each type was declared in, by name
+    modules: HashMap<&'cx str, &'cx ast::Path>,
+}
+
+impl<'a, 'cx> WitContext<'a, 'cx> {
+    fn module(&self, id: TypeId) -> Option<&'cx ast::Path> {
+        self.modules
+            .get(self.formatter.fmt_type_name_diagnostics(id))
+            .copied()
+    }
+
+    /// Why a type can't be part of the world, if it can't
+    fn check_type(&self, id: TypeId) -> Result<(), &'static str> {
+        let ty = self.tcx.resolve_type(id);
+        if ty.attrs().disable {
+            return Err("it is disabled");
+        }
+        match ty {
+            TypeDef::Struct(strct) => self.check_struct(id, strct),
+            TypeDef::OutStruct(strct) => self.check_struct(id, strct),
+            TypeDef::Opaque(opaque) if opaque.lifetimes.num_lifetimes() != 0 => {
+                Err("types with lifetimes are not supported")
+            }
+            TypeDef::Enum(enm) if enm.is_data_carrying() => {
+                Err("enums with fields are not supported")
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn check_struct<P: TyPosition>(
+        &self,
+        id: TypeId,
+        strct: &hir::StructDef<P>,
+    ) -> Result<(), &'static str> {
+        if strct.lifetimes.num_lifetimes() != 0 {
+            Err("types with lifetimes are not supported")
+        } else if strct.fields.is_empty() {
+            Err("records can't be empty")
+        } else if self.record_fields(id, strct).is_none() {
+            Err("records can only hold primitives and records or enums from the same module")
+        } else {
+            Ok(())
+        }
+    }
+
+    /// The types of the fields of a struct, if they are all plain types
+    fn record_fields<P: TyPosition>(
+        &self,
+        id: TypeId,
+        strct: &hir::StructDef<P>,
+    ) -> Option<Vec<WitType>> {
+        let module = self.module(id);
+        strct
+            .fields
+            .iter()
+            .map(|field| self.plain_type(&field.ty, module))
+            .collect()
+    }
+
+    /// Types that are copied by value in both directions. The bridge macro can only
+    /// resolve records and enums declared in the same module as the method using them.
+    fn plain_type<P: TyPosition>(
+        &self,
+        ty: &Type<P>,
+        module: Option<&ast::Path>,
+    ) -> Option<WitType> {
+        let id = match ty {
+            Type::Primitive(hir::PrimitiveType::Int128(_)) => return None,
+            Type::Primitive(prim) => {
+                return Some(WitType::new(self.formatter.fmt_primitive(*prim), 1))
+            }
+            Type::Struct(path) => path.id(),
+            Type::Enum(path) => path.tcx_id.into(),
+            _ => return None,
+        };
+        if self.module(id) != module || self.check_type(id).is_err() {
+            return None;
+        }
+        let flat = match self.tcx.resolve_type(id) {
+            TypeDef::Struct(strct) => self.record_fields(id, strct)?.iter().map(|f| f.flat).sum(),
+            TypeDef::OutStruct(strct) => {
+                self.record_fields(id, strct)?.iter().map(|f| f.flat).sum()
+            }
+            _ => 1,
+        };
+        Some(WitType::new(self.formatter.fmt_type_name(id), flat))
+    }
+
+    /// An opaque behind a pointer, which may come from another module
+    fn opaque(&self, path: &hir::OpaquePath<hir::Optional, impl OpaqueOwner>) -> Option<String> {
+        let id = path.tcx_id.into();
+        if path.lifetimes.lifetimes().len() != 0 || self.check_type(id).is_err() {
+            return None;
+        }
+        Some(self.formatter.fmt_type_name(id))
+    }
+
+    fn param_type(&self, ty: &Type, module: Option<&ast::Path>) -> Option<WitType> {
+        match ty {
+            Type::Opaque(path) => {
+                let borrow = WitType::new(format!("borrow<{}>", self.opaque(path)?), 1);
+                if path.is_optional() {
+                    Some(WitType::option(borrow))
+                } else {
+                    Some(borrow)
+                }
+            }
+            Type::Slice(hir::Slice::Str(_, encoding)) => {
+                Some(WitType::new(self.formatter.fmt_string(*encoding), 2))
+            }
+            Type::Slice(hir::Slice::Primitive(borrow, prim)) => {
+                if borrow.map(|b| b.mutability.is_mutable()).unwrap_or(false)
+                    || matches!(prim, hir::PrimitiveType::Int128(_))
+                {
+                    return None;
+                }
+                Some(WitType::new(
+                    format!("list<{}>", self.formatter.fmt_primitive(*prim)),
+                    2,
+                ))
+            }
+            Type::Slice(hir::Slice::Strs(encoding)) => Some(WitType::new(
+                format!("list<{}>", self.formatter.fmt_string(*encoding)),
+                2,
+            )),
+            _ => self.plain_type(ty, module),
+        }
+    }
+
+    /// Types that can be returned. The component model can't express results borrowing
+    /// from parameters, so those are left out.
+    fn output_type(&self, ty: &hir::OutType, module: Option<&ast::Path>) -> Option<WitType> {
+        match ty {
+            Type::Opaque(path) if path.owner.is_owned() => {
+                let own = WitType::new(self.opaque(path)?, 1);
+                if path.is_optional() {
+                    Some(WitType::option(own))
+                } else {
+                    Some(own)
+                }
+            }
+            _ => self.plain_type(ty, module),
+        }
+    }
+
+    /// The type of a case of a `result`, which may be empty
+    fn result_case(&self, ty: &SuccessType, module: Option<&ast::Path>) -> Option<Option<WitType>> {
+        match ty {
+            SuccessType::Unit => Some(None),
+            SuccessType::Writeable => Some(Some(WitType::new("string", 2))),
+            SuccessType::OutType(ty) => self.case_type(ty, module).map(Some),
+            _ => None,
+        }
+    }
+
+    /// Optional opaques can't be nested in a `result`
+    fn case_type(&self, ty: &hir::OutType, module: Option<&ast::Path>) -> Option<WitType> {
+        match ty {
+            Type::Opaque(path) if path.is_optional() => None,
+            _ => self.output_type(ty, module),
+        }
+    }
+
+    fn output(&self, output: &ReturnType, module: Option<&ast::Path>) -> Option<Option<WitType>> {
+        match output {
+            ReturnType::Infallible(SuccessType::OutType(ty)) => {
+                self.output_type(ty, module).map(Some)
+            }
+            ReturnType::Infallible(ty) => self.result_case(ty, module),
+            ReturnType::Nullable(SuccessType::OutType(ty)) => self
+                .plain_type(ty, module)
+                .map(|ty| Some(WitType::option(ty))),
+            ReturnType::Nullable(_) => None,
+            ReturnType::Fallible(ok, err) => {
+                let ok = self.result_case(ok, module)?;
+                let err = match err {
+                    Some(err) => Some(self.case_type(err, module)?),
+                    None => None,
+                };
+                Some(Some(WitType::result(ok, err)))
+            }
+        }
+    }
+
+    /// The signature of a method, or why it isn't exported
+    fn gen_method(&self, id: TypeId, method: &hir::Method) -> Result<String, String> {
+        let module = self.module(id);
+        let mut flat_params = 0;
+        let mut params = vec![];
+        if let Some(param_self) = &method.param_self {
+            let ty = match &param_self.ty {
+                SelfType::Opaque(_) => None,
+                SelfType::Struct(path) => Some(Type::<hir::Everywhere>::Struct(path.clone())),
+                SelfType::Enum(path) => Some(Type::Enum(path.clone())),
+                _ => unreachable!("unknown AST/HIR variant"),
+            };
+            match ty {
+                Some(ty) => {
+                    let ty = self
+                        .plain_type(&ty, module)
+                        .ok_or_else(|| "`self` can't be passed to components".to_owned())?;
+                    flat_params += ty.flat;
+                    params.push(format!("self: {}", ty.name));
+                }
+                None => flat_params += 1,
+            }
+        }
+        for param in &method.params {
+            let ty = self.param_type(&param.ty, module).ok_or_else(|| {
+                format!("the type of `{}` can't be passed to components", param.name)
+            })?;
+            flat_params += ty.flat;
+            params.push(format!(
+                "{}: {}",
+                self.formatter.fmt_identifier(param.name.as_str()),
+                ty.name
+            ));
+        }
+        let output = self
+            .output(&method.output, module)
+            .ok_or_else(|| "its return type can't be returned from components".to_owned())?;
+        if flat_params > MAX_FLAT_PARAMS {
+            return Err(format!(
+                "its parameters take more than {MAX_FLAT_PARAMS} core wasm values"
+            ));
+        }
+
+        let params = params.join(", ");
+        let output = output
+            .map(|ty| format!(" -> {}", ty.name))
+            .unwrap_or_default();
+        let method_name = self.formatter.fmt_identifier(method.name.as_str());
+        Ok(match self.tcx.resolve_type(id) {
+            TypeDef::Opaque(_) if method.param_self.is_some() => {
+                format!("{method_name}: func({params}){output};")
+            }
+            TypeDef::Opaque(_) if self.is_constructor(id, method) => {
+                format!("constructor({params});")
+            }
+            TypeDef::Opaque(_) => format!("{method_name}: static func({params}){output};"),
+            _ => format!(
+                "{}: func({params}){output};",
+                self.formatter.fmt_free_function_name(id, method)
+            ),
+        })
+    }
+
+    /// Constructors have to return their own type, and can't fail
+    fn is_constructor(&self, id: TypeId, method: &hir::Method) -> bool {
+        matches!(
+            method.attrs.special_method,
+            Some(SpecialMethod::Constructor)
+        ) && matches!(
+            &method.output,
+            ReturnType::Infallible(SuccessType::OutType(Type::Opaque(path)))
+                if TypeId::from(path.tcx_id) == id && path.owner.is_owned() && !path.is_optional()
+        )
+    }
+
+    /// The signatures of the methods of a type, with the ones that aren't exported commented out
+    fn gen_methods(&self, id: TypeId, ty: TypeDef<'cx>) -> Vec<String> {
+        let type_name = self.formatter.fmt_type_name_diagnostics(id);
+        ty.methods()
+            .iter()
+            .filter(|method| !method.attrs.disable)
+            .map(|method| {
+                let docs = wit_docs(&self.formatter.fmt_docs(&method.docs));
+                match self.gen_method(id, method) {
+                    Ok(signature) => format!("{docs}{signature}"),
+                    Err(reason) => {
+                        format!(
+                            "// `{type_name}::{}` is not exported: {reason}",
+                            method.name
+                        )
+                    }
+                }
+            })
+            .collect()
+    }
+
+    /// The items a type adds to the interface
+    fn gen_type(&self, id: TypeId, ty: TypeDef<'cx>) -> Vec<String> {
+        let type_name = self.formatter.fmt_type_name(id);
+        if let Err(reason) = self.check_type(id) {
+            return vec![format!(
+                "// `{}` is not exported: {reason}",
+                self.formatter.fmt_type_name_diagnostics(id)
+            )];
+        }
+        let docs = wit_docs(&self.formatter.fmt_docs(ty.docs()));
+
+        let body = match ty {
+            TypeDef::Enum(enm) => {
+                #[derive(Template)]
+                #[template(path = "wit/enum.wit.jinja", escape = "none")]
+                struct EnumTemplate<'a> {
+                    name: &'a str,
+                    docs: String,
+                    cases: Vec<String>,
+                }
+
+                EnumTemplate {
+                    name: &type_name,
+                    docs,
+                    cases: enm
+                        .variants
+                        .iter()
+                        .map(|variant| {
+                            format!(
+                                "{}{}",
+                                wit_docs(&self.formatter.fmt_docs(&variant.docs)),
+                                self.formatter.fmt_identifier(variant.name.as_str())
+                            )
+                        })
+                        .collect(),
+                }
+                .render()
+                .unwrap()
+            }
+            TypeDef::Struct(strct) => self.gen_record(id, &type_name, docs, strct),
+            TypeDef::OutStruct(strct) => self.gen_record(id, &type_name, docs, strct),
+            TypeDef::Opaque(_) => {
+                #[derive(Template)]
+                #[template(path = "wit/resource.wit.jinja", escape = "none")]
+                struct ResourceTemplate<'a> {
+                    name: &'a str,
+                    docs: String,
+                    funcs: Vec<String>,
+                }
+
+                return vec![ResourceTemplate {
+                    name: &type_name,
+                    docs,
+                    funcs: self.gen_methods(id, ty),
+                }
+                .render()
+                .unwrap()];
+            }
+            _ => unreachable!("unknown AST/HIR variant"),
+        };
+
+        let mut items = vec![body];
+        items.extend(self.gen_methods(id, ty));
+        items
+    }
+
+    fn gen_record<P: TyPosition>(
+        &self,
+        id: TypeId,
+        type_name: &str,
+        docs: String,
+        strct: &hir::StructDef<P>,
+    ) -> String {
+        #[derive(Template)]
+        #[template(path = "wit/record.wit.jinja", escape = "none")]
+        struct RecordTemplate<'a> {
+            name: &'a str,
+            docs: String,
+            fields: Vec<String>,
+        }
+
+        let types = self
+            .record_fields(id, strct)
+            .expect("checked by check_type()");
+        RecordTemplate {
+            name: type_name,
+            docs,
+            fields: strct
+                .fields
+                .iter()
+                .zip(types)
+                .map(|(field, ty)| {
+                    format!(
+                        "{}{}: {}",
+                        wit_docs(&self.formatter.fmt_docs(&field.docs)),
+                        self.formatter.fmt_identifier(field.name.as_str()),
+                        ty.name
+                    )
+                })
+                .collect(),
+        }
+        .render()
+        .unwrap()
+    }
+}
+
+/// Turn docs into `///` comments, each line ending with a newline
+fn wit_docs(docs: &str) -> String {
+    let mut out = String::new();
+    for line in docs.lines() {
+        if line.is_empty() {
+            out.push_str("///\n");
+        } else {
+            out.push_str("/// ");
+            out.push_str(line);
+            out.push('\n');
+        }
+    }
+    out
+}
+
+/// Indent every non-empty line by one level
+fn indent(text: &str) -> String {
+    text.split('\n')
+        .map(|line| {
+            if line.is_empty() {
+                String::new()
+            } else {
+                format!("    {line}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,18 @@
+@meta.ResourceIdentifier('diplomat_panicked')
+@ffi.Native<ffi.Bool Function()>(symbol: 'diplomat_panicked', isLeaf: true)
+// ignore: non_constant_identifier_names
+external bool _diplomat_panicked();
+
+@meta.ResourceIdentifier('diplomat_take_panic')
+@ffi.Native<ffi.Void Function(ffi.Pointer<ffi.Opaque>)>(symbol: 'diplomat_take_panic', isLeaf: true)
+// ignore: non_constant_identifier_names
+external void _diplomat_take_panic(ffi.Pointer<ffi.Opaque> out);
+
+/// Throws a [StateError] with the message of the panic of the last call, if it panicked.
+void _checkPanic() {
+  if (_diplomat_panicked()) {
+    final writeable = _Writeable();
+    _diplomat_take_panic(writeable._ffi);
+    throw StateError(writeable.finalize());
+  }
+}
\ No newline at end of file
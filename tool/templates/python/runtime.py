@@ -0,0 +1,296 @@
+"""Runtime support for the generated bindings.
+
+This loads the native library and moves strings, slices and writeables across
+the FFI boundary.
+"""
+
+import ctypes
+import os
+
+
+class DiplomatError(Exception):
+    """Raised when a Rust method returns an error.
+
+    The error value, if the method returns one, is available as `value`.
+    """
+
+    def __init__(self, value=None):
+        super().__init__(value)
+        self.value = value
+
+
+class DiplomatPanic(Exception):
+    """Raised when a Rust method that catches panics panicked, with the panic's message."""
+
+
+_library = None
+_declarations = []
+
+
+def register_prototypes(declare):
+    """Register a function declaring argument and return types on the library."""
+    _declarations.append(declare)
+    if _library is not None:
+        declare(_library)
+
+
+def load_library(path):
+    """Load the native library from `path`.
+
+    If this isn't called, the library is loaded from the path in the
+    `DIPLOMAT_LIBRARY_PATH` environment variable when it is first used.
+    """
+    global _library
+    library = ctypes.CDLL(path)
+    _declare_runtime(library)
+    for declare in _declarations:
+        declare(library)
+    _library = library
+    return library
+
+
+def lib():
+    """The native library, loading it if that hasn't happened yet."""
+    if _library is None:
+        path = os.environ.get("DIPLOMAT_LIBRARY_PATH")
+        if path is None:
+            raise RuntimeError(
+                "The native library has not been loaded, call load_library() "
+                "or set DIPLOMAT_LIBRARY_PATH"
+            )
+        load_library(path)
+    return _library
+
+
+def _declare_runtime(library):
+    library.diplomat_alloc.argtypes = [ctypes.c_size_t, ctypes.c_size_t]
+    library.diplomat_alloc.restype = ctypes.c_void_p
+    library.diplomat_free.argtypes = [ctypes.c_void_p, ctypes.c_size_t, ctypes.c_size_t]
+    library.diplomat_free.restype = None
+    library.diplomat_buffer_writeable_create.argtypes = [ctypes.c_size_t]
+    library.diplomat_buffer_writeable_create.restype = ctypes.c_void_p
+    library.diplomat_buffer_writeable_get_bytes.argtypes = [ctypes.c_void_p]
+    library.diplomat_buffer_writeable_get_bytes.restype = ctypes.c_void_p
+    library.diplomat_buffer_writeable_len.argtypes = [ctypes.c_void_p]
+    library.diplomat_buffer_writeable_len.restype = ctypes.c_size_t
+    library.diplomat_buffer_writeable_destroy.argtypes = [ctypes.c_void_p]
+    library.diplomat_buffer_writeable_destroy.restype = None
+    # Only libraries built with the `catch_panics` feature of the runtime have these
+    if hasattr(library, "diplomat_panicked"):
+        library.diplomat_panicked.argtypes = []
+        library.diplomat_panicked.restype = ctypes.c_bool
+        library.diplomat_take_panic.argtypes = [ctypes.c_void_p]
+        library.diplomat_take_panic.restype = None
+
+
+class Writeable:
+    """A buffer in Rust's memory that methods returning strings write into."""
+
+    def __init__(self):
+        self.ffi = lib().diplomat_buffer_writeable_create(0)
+
+    def finalize(self):
+        """Decode what was written to the buffer."""
+        library = lib()
+        length = library.diplomat_buffer_writeable_len(self.ffi)
+        if length == 0:
+            return ""
+        data = library.diplomat_buffer_writeable_get_bytes(self.ffi)
+        return ctypes.string_at(data, length).decode("utf-8")
+
+    def __del__(self):
+        if getattr(self, "ffi", None) is not None:
+            lib().diplomat_buffer_writeable_destroy(self.ffi)
+
+
+def check_panic():
+    """Raise a `DiplomatPanic` if the last call on this thread panicked."""
+    library = lib()
+    if library.diplomat_panicked():
+        writeable = Writeable()
+        library.diplomat_take_panic(writeable.ffi)
+        raise DiplomatPanic(writeable.finalize())
+
+
+_slice_types = {}
+
+
+def slice_type(element):
+    """The structure of a slice of `element`s, as returned by Rust or held in a struct."""
+    ty = _slice_types.get(element)
+    if ty is None:
+
+        class Slice(ctypes.Structure):
+            _fields_ = [("data", ctypes.POINTER(element)), ("len", ctypes.c_size_t)]
+
+        ty = _slice_types[element] = Slice
+    return ty
+
+
+def _byte_classes(ty, offset, classes):
+    """Mark the bytes of `ty` at `offset` as holding floating point or other data."""
+    if issubclass(ty, ctypes.Structure):
+        for field in ty._fields_:
+            _byte_classes(field[1], offset + getattr(ty, field[0]).offset, classes)
+    elif issubclass(ty, ctypes.Array):
+        for i in range(ty._length_):
+            _byte_classes(ty._type_, offset + i * ctypes.sizeof(ty._type_), classes)
+    else:
+        kind = "f" if getattr(ty, "_type_", None) in ("f", "d") else "i"
+        for i in range(offset, offset + ctypes.sizeof(ty)):
+            if classes[i] != "i":
+                classes[i] = kind
+
+
+def union_type(members):
+    """A structure passed across the FFI boundary like a C union of `members`.
+
+    ctypes can't pass unions by value, so this is made up of chunks of the
+    union's alignment, each of which is a float if only floats overlap it. That
+    gives it the size, alignment and register classes of the C union. Members
+    are read and written with `read_union` and `make_union`.
+    """
+    align = max(ctypes.alignment(m) for m in members)
+    size = max(ctypes.sizeof(m) for m in members)
+    size = (size + align - 1) // align * align
+    classes = [None] * size
+    for member in members:
+        _byte_classes(member, 0, classes)
+
+    ints = {1: ctypes.c_uint8, 2: ctypes.c_uint16, 4: ctypes.c_uint32, 8: ctypes.c_uint64}
+    floats = {4: ctypes.c_float, 8: ctypes.c_double}
+    fields = []
+    for i in range(0, size, align):
+        chunk = classes[i : i + align]
+        is_float = align in floats and "f" in chunk and "i" not in chunk
+        fields.append((f"_{i}", (floats if is_float else ints)[align]))
+
+    class Union(ctypes.Structure):
+        _fields_ = fields
+
+    return Union
+
+
+def read_union(union, member):
+    """Read the `member` type out of a union."""
+    value = member.from_buffer_copy(union)
+    if issubclass(member, ctypes._SimpleCData):
+        return value.value
+    return value
+
+
+def make_union(union_type, value):
+    """Create a union holding `value`."""
+    union = union_type()
+    ctypes.memmove(ctypes.addressof(union), ctypes.addressof(value), ctypes.sizeof(value))
+    return union
+
+
+def _alloc(element, values, keep):
+    """Copy `values` into an array of `element`s, returning a pointer and a length.
+
+    Borrowed slices are allocated by Python and appended to `keep`, which the
+    caller holds on to for as long as Rust may use them. If `keep` is None, the
+    slice is owned by Rust, so it is allocated with Rust's allocator.
+    """
+    length = len(values)
+    pointer_type = ctypes.POINTER(element)
+    if keep is None:
+        align = ctypes.alignment(element)
+        if length == 0:
+            # Rust expects a dangling, aligned pointer for empty allocations
+            return ctypes.cast(ctypes.c_void_p(align), pointer_type), 0
+        address = lib().diplomat_alloc(ctypes.sizeof(element) * length, align)
+        array = (element * length).from_address(address)
+        array[:] = values
+        return ctypes.cast(array, pointer_type), length
+
+    if isinstance(values, ctypes.Array) and values._type_ is element:
+        # Shares memory, so Rust can write into mutable slices
+        array = values
+    elif isinstance(values, bytearray) and ctypes.sizeof(element) == 1:
+        array = (element * length).from_buffer(values)
+    elif isinstance(values, bytes) and ctypes.sizeof(element) == 1:
+        array = (element * length).from_buffer_copy(values)
+    else:
+        array = (element * length)(*values)
+    keep.append(array)
+    return ctypes.cast(array, pointer_type), length
+
+
+def prim_slice(element, values, keep=None):
+    """Pass a list of primitives as a slice."""
+    if isinstance(values, str):
+        values = [ord(c) for c in values]
+    return _alloc(element, values, keep)
+
+
+def str8(value, keep=None):
+    """Pass a `str` (or UTF-8 `bytes`) as a UTF-8 string slice."""
+    if isinstance(value, str):
+        value = value.encode("utf-8")
+    return _alloc(ctypes.c_uint8, value, keep)
+
+
+def str16(value, keep=None):
+    """Pass a `str` as a UTF-16 string slice."""
+    if isinstance(value, str):
+        value = list(memoryview(value.encode("utf-16-le")).cast("H"))
+    return _alloc(ctypes.c_uint16, value, keep)
+
+
+def strs8(values, keep):
+    """Pass a list of strings as a slice of UTF-8 string slices."""
+    view = slice_type(ctypes.c_uint8)
+    return _alloc(view, [view(*str8(v, keep)) for v in values], keep)
+
+
+def strs16(values, keep):
+    """Pass a list of strings as a slice of UTF-16 string slices."""
+    view = slice_type(ctypes.c_uint16)
+    return _alloc(view, [view(*str16(v, keep)) for v in values], keep)
+
+
+def _free(view):
+    element = view._fields_[0][1]._type_
+    lib().diplomat_free(
+        ctypes.cast(view.data, ctypes.c_void_p),
+        ctypes.sizeof(element) * view.len,
+        ctypes.alignment(element),
+    )
+
+
+def _read(view, owned, read):
+    if view.len == 0:
+        return read(None, 0)
+    try:
+        return read(view.data, view.len)
+    finally:
+        if owned:
+            _free(view)
+
+
+def read_slice(view, owned):
+    """Copy a slice returned by Rust into a list, freeing it if it is owned."""
+    return _read(view, owned, lambda data, length: data[:length] if length else [])
+
+
+def read_bytes(view, owned):
+    """Copy a byte slice returned by Rust into `bytes`, freeing it if it is owned."""
+    return _read(view, owned, lambda data, length: ctypes.string_at(data, length) if length else b"")
+
+
+def read_str8(view, owned):
+    """Decode a UTF-8 string slice returned by Rust, freeing it if it is owned."""
+    return read_bytes(view, owned).decode("utf-8", errors="replace")
+
+
+def read_str16(view, owned):
+    """Decode a UTF-16 string slice returned by Rust, freeing it if it is owned."""
+    return _read(
+        view,
+        owned,
+        lambda data, length: ctypes.string_at(data, 2 * length).decode("utf-16-le", errors="replace")
+        if length
+        else "",
+    )
@@ -0,0 +1,95 @@
+//! A backend that lives outside of diplomat-tool, registered next to the built-in ones.
+
+use diplomat_core::ast;
+use diplomat_core::hir::{BackendAttrSupport, TypeContext};
+use diplomat_tool::backend::Errors;
+use diplomat_tool::{Backend, Config, Diagnostics, FileMap, MessageFormat, Registry};
+use std::fs;
+
+/// Lists the types of the bridge crate, one per line
+struct Listing;
+
+impl Backend for Listing {
+    fn name(&self) -> &str {
+        "listing"
+    }
+    fn aliases(&self) -> &[&str] {
+        &["ls"]
+    }
+    fn attrs_supported(&self) -> BackendAttrSupport {
+        let mut support = BackendAttrSupport::default();
+        support.renaming = true;
+        support
+    }
+    fn generate(&self, tcx: &TypeContext, _config: &Config) -> Result<FileMap, Errors> {
+        let mut names: Vec<String> = tcx
+            .all_types()
+            .map(|(_, ty)| {
+                ty.attrs()
+                    .rename
+                    .apply(ty.name().as_str().into())
+                    .into_owned()
+            })
+            .collect();
+        names.sort();
+        let files = FileMap::default();
+        files.add_file("types.txt".into(), names.join("\n") + "\n");
+        Ok(files)
+    }
+}
+
+fn generate(
+    registry: &Registry,
+    target: &str,
+    out: &std::path::Path,
+) -> Result<(), diplomat_tool::Error> {
+    let file = ast::File::from(&syn::parse_quote! {
+        #[diplomat::bridge]
+        mod ffi {
+            #[diplomat::opaque]
+            #[diplomat::attr(listing, rename = "Renamed")]
+            pub struct Original;
+
+            pub struct Point {
+                pub x: i32,
+            }
+        }
+    });
+    diplomat_tool::gen_from_file(
+        file,
+        &[],
+        None,
+        target,
+        registry,
+        out,
+        None,
+        &Default::default(),
+        None,
+        |_| {},
+        false,
+        None,
+        None,
+        &Diagnostics::new(MessageFormat::Human),
+    )
+}
+
+#[test]
+fn test_custom_backend() {
+    let out = std::env::temp_dir().join(format!("diplomat-custom-backend-{}", std::process::id()));
+    fs::create_dir_all(&out).unwrap();
+
+    let mut registry = Registry::new();
+    registry.register(Listing);
+    let generated = generate(&registry, "ls", &out);
+    let types = fs::read_to_string(out.join("types.txt"));
+    let unknown = generate(&Registry::new(), "listing", &out);
+    fs::remove_dir_all(&out).unwrap();
+
+    generated.unwrap();
+    assert_eq!(types.unwrap(), "Point\nRenamed\n");
+    assert_eq!(registry.names().last(), Some("listing"));
+    assert!(matches!(
+        unknown,
+        Err(diplomat_tool::Error::UnknownTarget { target, .. }) if target == "listing"
+    ));
+}